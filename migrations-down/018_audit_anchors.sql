@@ -0,0 +1,6 @@
+-- Rollback 018: Audit Log Anchoring
+-- Anchor records are dropped; proof files on disk are left in place.
+
+DROP INDEX IF EXISTS idx_audit_anchors_status;
+DROP INDEX IF EXISTS idx_audit_anchors_anchored_at;
+DROP TABLE IF EXISTS audit_anchors;
@@ -0,0 +1,10 @@
+-- Rollback 019: Node Keys
+-- Node public keys, deregistrations and rotation history are lost; deregistered
+-- nodes stay inactive.
+
+DROP INDEX IF EXISTS idx_node_key_rotations_old_key;
+DROP INDEX IF EXISTS idx_node_key_rotations_node;
+DROP TABLE IF EXISTS node_key_rotations;
+
+ALTER TABLE node_registry DROP COLUMN deregistered_at;
+ALTER TABLE node_registry DROP COLUMN public_key;
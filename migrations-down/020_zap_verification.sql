@@ -0,0 +1,7 @@
+-- Rollback 020: Zap Payment Verification
+-- zap_contributions predates this migration and is kept; only the verification
+-- columns are dropped.
+
+DROP INDEX IF EXISTS idx_zap_verification_status;
+ALTER TABLE zap_contributions DROP COLUMN verified_at;
+ALTER TABLE zap_contributions DROP COLUMN verification_status;
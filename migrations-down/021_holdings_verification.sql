@@ -0,0 +1,5 @@
+-- Rollback 021: Holdings Verification
+-- Measured balances are dropped; nodes are weighted by their claimed holdings again.
+
+ALTER TABLE node_registry DROP COLUMN balance_verified_at;
+ALTER TABLE node_registry DROP COLUMN verified_balance_btc;
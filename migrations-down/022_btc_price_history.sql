@@ -0,0 +1,5 @@
+-- Rollback 022: BTC Price History
+-- The moving average starts empty again after 022 is re-applied.
+
+DROP INDEX IF EXISTS idx_btc_price_history_timestamp;
+DROP TABLE IF EXISTS btc_price_history;
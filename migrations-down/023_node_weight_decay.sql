@@ -0,0 +1,4 @@
+-- Rollback 023: Node Weight Decay
+-- Decayed weights are dropped; the next weight update recomputes them.
+
+ALTER TABLE node_registry DROP COLUMN effective_weight;
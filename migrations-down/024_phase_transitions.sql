@@ -0,0 +1,5 @@
+-- Rollback 024: Governance Phase Transitions
+-- Transition history is lost; the current phase is recorded again on the next evaluation.
+
+DROP INDEX IF EXISTS idx_phase_transitions_at;
+DROP TABLE IF EXISTS phase_transitions;
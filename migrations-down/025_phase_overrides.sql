@@ -0,0 +1,4 @@
+-- Rollback 025: Governance Phase Overrides
+-- Any pinned phase is released.
+
+DROP TABLE IF EXISTS phase_overrides;
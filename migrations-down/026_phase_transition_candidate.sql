@@ -0,0 +1,4 @@
+-- Rollback 026: Phase Transition Hysteresis
+-- A pending candidate phase starts its hold period again after 026 is re-applied.
+
+DROP TABLE IF EXISTS phase_transition_candidate;
@@ -0,0 +1,5 @@
+-- Rollback 027: Incremental Contribution Aggregation
+-- Running totals are dropped; the next aggregation rebuilds them from the start.
+
+DROP TABLE IF EXISTS contribution_aggregation_state;
+DROP TABLE IF EXISTS contributor_running_totals;
@@ -0,0 +1,66 @@
+-- Rollback 028: Governance review case lifecycle
+-- Appealed cases go back to 'resolved' (their appeals are kept) and the
+-- transition history is dropped.
+
+PRAGMA defer_foreign_keys = ON;
+
+DROP INDEX IF EXISTS idx_case_transitions_case;
+DROP TABLE IF EXISTS case_transitions;
+
+CREATE TEMPORARY TABLE governance_review_cases_backup AS
+SELECT * FROM governance_review_cases;
+
+DROP TABLE governance_review_cases;
+
+CREATE TABLE governance_review_cases (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    case_number TEXT UNIQUE NOT NULL, -- Format: GR-YYYY-MMDD-NNNN
+    subject_maintainer_id INTEGER NOT NULL,
+    reporter_maintainer_id INTEGER NOT NULL,
+    case_type TEXT NOT NULL,
+    severity TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'open', -- 'open', 'under_review', 'mediation', 'warning_issued', 'removal_pending', 'removed', 'resolved', 'dismissed', 'expired'
+    description TEXT NOT NULL,
+    evidence JSON NOT NULL DEFAULT '{}',
+    on_platform BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    response_deadline TIMESTAMP,
+    resolution_deadline TIMESTAMP,
+    resolved_at TIMESTAMP,
+    resolution_reason TEXT,
+    github_issue_number INTEGER,
+
+    FOREIGN KEY (subject_maintainer_id) REFERENCES maintainers(id),
+    FOREIGN KEY (reporter_maintainer_id) REFERENCES maintainers(id),
+    CHECK (status IN ('open', 'under_review', 'mediation', 'warning_issued', 'removal_pending', 'removed', 'resolved', 'dismissed', 'expired')),
+    CHECK (severity IN ('minor', 'moderate', 'serious', 'gross_misconduct')),
+    CHECK (case_type IN ('abuse', 'harassment', 'malicious_code', 'collusion', 'conflict_of_interest', 'technical_errors', 'security_violation', 'false_report', 'retaliation'))
+);
+
+INSERT INTO governance_review_cases (
+    id, case_number, subject_maintainer_id, reporter_maintainer_id,
+    case_type, severity, status, description, evidence, on_platform,
+    created_at, response_deadline, resolution_deadline,
+    resolved_at, resolution_reason, github_issue_number
+)
+SELECT
+    id, case_number, subject_maintainer_id, reporter_maintainer_id,
+    case_type, severity,
+    CASE WHEN status = 'appealed' THEN 'resolved' ELSE status END,
+    description, evidence, on_platform,
+    created_at, response_deadline, resolution_deadline,
+    resolved_at, resolution_reason, github_issue_number
+FROM governance_review_cases_backup;
+
+DROP TABLE governance_review_cases_backup;
+
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_subject ON governance_review_cases(subject_maintainer_id);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_reporter ON governance_review_cases(reporter_maintainer_id);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_status ON governance_review_cases(status);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_resolution_deadline ON governance_review_cases(resolution_deadline);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_github_issue ON governance_review_cases(github_issue_number);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_created_at ON governance_review_cases(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_type ON governance_review_cases(case_type);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_severity ON governance_review_cases(severity);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_status_deadline ON governance_review_cases(status, resolution_deadline);
+CREATE INDEX IF NOT EXISTS idx_governance_review_cases_number ON governance_review_cases(case_number);
@@ -0,0 +1,5 @@
+-- Rollback 029: Contributor Identities
+-- Linked identities are counted as separate contributors again.
+
+DROP INDEX IF EXISTS idx_contributor_identities_contributor;
+DROP TABLE IF EXISTS contributor_identities;
@@ -0,0 +1,4 @@
+-- Rollback 030: Deadline Notification Tracking
+-- Sent reminders are forgotten, so they may be sent again if 030 is re-applied.
+
+DROP TABLE IF EXISTS deadline_notifications_sent;
@@ -0,0 +1,7 @@
+-- Rollback 031: Anonymous Whistleblower Reports
+-- Anonymous reports, their escrowed identities and unlock requests are deleted.
+
+DROP INDEX IF EXISTS idx_anonymous_report_unlock_requests_report;
+DROP INDEX IF EXISTS idx_anonymous_reports_status;
+DROP TABLE IF EXISTS anonymous_report_unlock_requests;
+DROP TABLE IF EXISTS anonymous_reports;
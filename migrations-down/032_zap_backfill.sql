@@ -0,0 +1,7 @@
+-- Rollback 032: Zap Backfill
+-- Recorded zaps are kept without their receipt event ids; backfill resumes from
+-- scratch if 032 is re-applied.
+
+DROP TABLE IF EXISTS zap_tracker_cursors;
+DROP INDEX IF EXISTS idx_zap_event_id;
+ALTER TABLE zap_contributions DROP COLUMN event_id;
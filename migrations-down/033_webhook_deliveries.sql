@@ -0,0 +1,5 @@
+-- Rollback 033: GitHub webhook delivery replay protection
+-- Deliveries seen so far are forgotten and would be accepted again if replayed.
+
+DROP INDEX IF EXISTS idx_github_webhook_deliveries_received;
+DROP TABLE IF EXISTS github_webhook_deliveries;
@@ -0,0 +1,5 @@
+-- Rollback 034: Persisted GitHub webhook event queue
+-- Pending events are discarded; drain the queue before rolling back.
+
+DROP INDEX IF EXISTS idx_webhook_events_status_next;
+DROP TABLE IF EXISTS webhook_events;
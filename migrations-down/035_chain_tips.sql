@@ -0,0 +1,8 @@
+-- Rollback 035: Chain tip tracking from verified block headers
+-- Recorded blocks and reorgs are lost; the tip is rebuilt from new block webhooks.
+
+DROP INDEX IF EXISTS idx_chain_reorgs_detected;
+DROP TABLE IF EXISTS chain_reorgs;
+DROP INDEX IF EXISTS idx_chain_tips_height;
+DROP INDEX IF EXISTS idx_chain_tips_is_tip;
+DROP TABLE IF EXISTS chain_tips;
@@ -0,0 +1,5 @@
+-- Rollback 036: OTS proofs for individual governance events
+-- Proof files under <proofs_path>/events are left on disk.
+
+DROP INDEX IF EXISTS idx_ots_event_proofs_pending;
+DROP TABLE IF EXISTS ots_event_proofs;
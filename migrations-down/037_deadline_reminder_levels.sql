@@ -0,0 +1,26 @@
+-- Rollback 037: Deadline Reminder Lead Times
+-- Restores the original level CHECK; lead-time and overdue records are dropped,
+-- so those reminders may be sent again if 037 is re-applied.
+
+CREATE TABLE deadline_notifications_sent_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    deadline_type TEXT NOT NULL,  -- 'response', 'resolution', 'appeal'
+    record_id INTEGER NOT NULL,  -- Case id (response, resolution) or appeal id
+    maintainer_id INTEGER NOT NULL,  -- Recipient
+    level TEXT NOT NULL,  -- 'upcoming', 'urgent'
+    deadline TIMESTAMP NOT NULL,  -- An extended deadline is notified again
+    sent_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    UNIQUE(deadline_type, record_id, maintainer_id, level, deadline),
+    CHECK (deadline_type IN ('response', 'resolution', 'appeal')),
+    CHECK (level IN ('upcoming', 'urgent'))
+);
+
+INSERT INTO deadline_notifications_sent_old
+    (id, deadline_type, record_id, maintainer_id, level, deadline, sent_at)
+SELECT id, deadline_type, record_id, maintainer_id, level, deadline, sent_at
+FROM deadline_notifications_sent
+WHERE level IN ('upcoming', 'urgent');
+
+DROP TABLE deadline_notifications_sent;
+ALTER TABLE deadline_notifications_sent_old RENAME TO deadline_notifications_sent;
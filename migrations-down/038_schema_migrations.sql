@@ -0,0 +1,3 @@
+-- Rollback 038: Schema Migrations View
+
+DROP VIEW IF EXISTS schema_migrations;
@@ -0,0 +1,5 @@
+-- Rollback 039: Node Registration Challenges
+-- Outstanding nonces are discarded; clients request new ones after 039 is re-applied.
+
+DROP INDEX IF EXISTS idx_registration_challenges_expires;
+DROP TABLE IF EXISTS registration_challenges;
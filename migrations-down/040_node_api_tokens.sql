@@ -0,0 +1,5 @@
+-- Rollback 040: Node API Tokens
+-- Every issued node token stops working; nodes request new ones after 040 is re-applied.
+
+DROP INDEX IF EXISTS idx_node_api_tokens_node;
+DROP TABLE IF EXISTS node_api_tokens;
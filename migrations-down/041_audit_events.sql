@@ -0,0 +1,7 @@
+-- Rollback 041: Audit Events Index
+-- Only the index is lost; re-apply 041 and backfill from the audit log files.
+
+DROP INDEX IF EXISTS idx_audit_events_subject;
+DROP INDEX IF EXISTS idx_audit_events_actor;
+DROP INDEX IF EXISTS idx_audit_events_timestamp;
+DROP TABLE IF EXISTS audit_events;
@@ -0,0 +1,5 @@
+-- Rollback 042: Governance PRs
+-- Overlap detection starts empty again; open PRs reappear as their webhooks arrive.
+
+DROP INDEX IF EXISTS idx_governance_prs_open;
+DROP TABLE IF EXISTS governance_prs;
@@ -0,0 +1,6 @@
+-- Rollback 043: Review Timeline
+-- Review periods go back to counting calendar days from when the PR opened.
+
+ALTER TABLE governance_prs DROP COLUMN diff_lines;
+DROP INDEX IF EXISTS idx_pr_timeline_events_pr;
+DROP TABLE IF EXISTS pr_timeline_events;
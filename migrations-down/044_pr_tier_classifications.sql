@@ -0,0 +1,4 @@
+-- Rollback 044: PR Tier Classifications
+-- Stored classifications are dropped; PRs are classified again on their next webhook.
+
+DROP TABLE IF EXISTS pr_tier_classifications;
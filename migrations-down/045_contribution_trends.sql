@@ -0,0 +1,5 @@
+-- Rollback 045: Contribution Trends
+-- Trend snapshots are dropped; trends can still be computed from contributions.
+
+DROP INDEX IF EXISTS idx_contribution_trends_contributor;
+DROP TABLE IF EXISTS contribution_trends;
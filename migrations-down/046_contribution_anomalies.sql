@@ -0,0 +1,6 @@
+-- Rollback 046: Contribution Anomalies
+-- Flags and review decisions are lost; held weights update on the next run.
+
+DROP INDEX IF EXISTS idx_contribution_anomalies_status;
+DROP INDEX IF EXISTS idx_contribution_anomalies_contributor;
+DROP TABLE IF EXISTS contribution_anomalies;
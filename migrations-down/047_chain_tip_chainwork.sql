@@ -0,0 +1,4 @@
+-- Rollback 047: Chain Tip Chainwork
+-- Recorded blocks are kept; the tip is again the highest block.
+
+ALTER TABLE chain_tips DROP COLUMN chainwork;
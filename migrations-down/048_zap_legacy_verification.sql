@@ -0,0 +1,3 @@
+-- Rollback 048: Zap Legacy Verification
+-- Grandfathered zaps are left verified; they were counted before migration 020
+-- and marking them unverified again would let re-verification count them twice.
@@ -0,0 +1,3 @@
+-- Rollback 049: Zap Cursor Seed
+-- Seeded cursors are left in place; they only move backfill forward past zaps
+-- that are already recorded.
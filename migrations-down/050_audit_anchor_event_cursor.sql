@@ -0,0 +1,5 @@
+-- Rollback 050: Audit Anchor Event Cursor
+-- The recorded event ids are lost; new tier actions are again found by
+-- comparing timestamps with anchored_at.
+
+ALTER TABLE audit_anchors DROP COLUMN last_event_id;
@@ -0,0 +1,9 @@
+-- Migration 038: Schema Migrations View (PostgreSQL)
+-- Applied migrations with their checksums, as recorded by the migrator in
+-- _sqlx_migrations. Database::migration_status compares these against the
+-- migrations embedded in the binary before running new ones.
+
+CREATE OR REPLACE VIEW schema_migrations AS
+SELECT version, description, encode(checksum, 'hex') AS checksum, installed_on AS applied_at
+FROM _sqlx_migrations
+WHERE success = TRUE;
@@ -0,0 +1,17 @@
+-- Migration 018: Audit Log Anchoring
+-- Tracks OTS anchors of the audit log hash chain head
+
+CREATE TABLE IF NOT EXISTS audit_anchors (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    head_hash TEXT NOT NULL,  -- Audit log head hash that was timestamped
+    entry_count INTEGER NOT NULL,  -- Number of audit entries covered by the head
+    proof_path TEXT NOT NULL,  -- Path to OTS proof file
+    trigger TEXT NOT NULL,  -- 'monthly' or 'tier_action'
+    status TEXT NOT NULL DEFAULT 'pending',  -- 'pending' or 'complete'
+    block_height INTEGER,  -- Bitcoin block height once attested
+    anchored_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    upgraded_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_anchors_anchored_at ON audit_anchors(anchored_at DESC);
+CREATE INDEX IF NOT EXISTS idx_audit_anchors_status ON audit_anchors(status);
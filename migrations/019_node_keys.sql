@@ -0,0 +1,17 @@
+-- Migration 019: Node Keys
+-- Node public keys, voluntary deregistration, and key rotation history
+
+ALTER TABLE node_registry ADD COLUMN public_key TEXT;  -- Hex-encoded secp256k1 public key
+ALTER TABLE node_registry ADD COLUMN deregistered_at TIMESTAMP;  -- Set when the operator deregisters
+
+CREATE TABLE IF NOT EXISTS node_key_rotations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    node_id TEXT NOT NULL,
+    old_public_key TEXT NOT NULL,
+    new_public_key TEXT NOT NULL,
+    rotated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (node_id) REFERENCES node_registry(node_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_node_key_rotations_node ON node_key_rotations(node_id);
+CREATE INDEX IF NOT EXISTS idx_node_key_rotations_old_key ON node_key_rotations(old_public_key);
@@ -0,0 +1,23 @@
+-- Migration 020: Zap Payment Verification
+-- Zaps are recorded unverified until their bolt11 payment is confirmed
+
+-- zap_contributions was created outside this migration set; ensure it exists
+CREATE TABLE IF NOT EXISTS zap_contributions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    recipient_pubkey TEXT NOT NULL,
+    sender_pubkey TEXT,
+    amount_msat INTEGER NOT NULL,
+    amount_btc REAL NOT NULL,
+    timestamp DATETIME NOT NULL,
+    invoice_hash TEXT,
+    message TEXT,
+    zapped_event_id TEXT,
+    is_proposal_zap BOOLEAN DEFAULT FALSE,
+    governance_event_id TEXT,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE zap_contributions ADD COLUMN verification_status TEXT NOT NULL DEFAULT 'unverified';  -- 'unverified' or 'verified'
+ALTER TABLE zap_contributions ADD COLUMN verified_at TIMESTAMP;
+
+CREATE INDEX IF NOT EXISTS idx_zap_verification_status ON zap_contributions(verification_status);
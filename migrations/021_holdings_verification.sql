@@ -0,0 +1,6 @@
+-- Migration 021: Holdings Verification
+-- Measured on-chain balance from the most recent holdings proof, used for
+-- weighting instead of the claimed total
+
+ALTER TABLE node_registry ADD COLUMN verified_balance_btc REAL;  -- Sum of confirmed address balances
+ALTER TABLE node_registry ADD COLUMN balance_verified_at TIMESTAMP;  -- When the balance was measured
@@ -0,0 +1,10 @@
+-- Migration 022: BTC Price History
+-- Recorded BTC/USD spot prices for the moving average used in USD conversions
+
+CREATE TABLE IF NOT EXISTS btc_price_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    price_usd REAL NOT NULL,
+    timestamp TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_btc_price_history_timestamp ON btc_price_history(timestamp);
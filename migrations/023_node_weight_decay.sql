@@ -0,0 +1,5 @@
+-- Migration 023: Node Weight Decay
+-- Effective weight after decay since the last holdings verification; the
+-- base weight stays in verified_balance_btc
+
+ALTER TABLE node_registry ADD COLUMN effective_weight REAL;  -- Recomputed by the periodic weight update
@@ -0,0 +1,14 @@
+-- Migration 024: Governance Phase Transitions
+-- Records each change of governance phase with the metrics that caused it
+
+CREATE TABLE IF NOT EXISTS phase_transitions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    old_phase TEXT,  -- NULL for the first recorded phase
+    new_phase TEXT NOT NULL,
+    transitioned_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    block_height INTEGER NOT NULL,
+    economic_nodes INTEGER NOT NULL,
+    contributors INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_phase_transitions_at ON phase_transitions(transitioned_at);
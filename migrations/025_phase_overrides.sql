@@ -0,0 +1,10 @@
+-- Migration 025: Governance Phase Overrides
+-- Operator-set phase pins; the latest row wins and a NULL phase clears the override
+
+CREATE TABLE IF NOT EXISTS phase_overrides (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    phase TEXT,  -- NULL clears the override
+    reason TEXT NOT NULL,
+    set_by TEXT NOT NULL,
+    set_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,9 @@
+-- Migration 026: Phase Transition Hysteresis
+-- The phase currently differing from the recorded one, and how long it has held
+
+CREATE TABLE IF NOT EXISTS phase_transition_candidate (
+    id INTEGER PRIMARY KEY CHECK (id = 1),  -- Single row
+    candidate_phase TEXT NOT NULL,
+    first_seen_at TIMESTAMP NOT NULL,
+    consecutive_evaluations INTEGER NOT NULL
+);
@@ -0,0 +1,17 @@
+-- Migration 027: Incremental Contribution Aggregation
+-- Per-contributor running totals and the last unified_contributions row folded into them
+
+CREATE TABLE IF NOT EXISTS contributor_running_totals (
+    contributor_id TEXT PRIMARY KEY,
+    contributor_type TEXT NOT NULL,
+    total_btc REAL NOT NULL DEFAULT 0.0,
+    zaps_btc REAL NOT NULL DEFAULT 0.0,
+    contribution_count INTEGER NOT NULL DEFAULT 0,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS contribution_aggregation_state (
+    id INTEGER PRIMARY KEY CHECK (id = 1),  -- Single row
+    last_contribution_id INTEGER NOT NULL DEFAULT 0,  -- High-water mark in unified_contributions
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,12 @@
+-- Migration 029: Contributor Identities
+-- Links the identities a contributor appears under (Nostr pubkey, Bitcoin
+-- address, GitHub login, ...) to one canonical contributor_id
+
+CREATE TABLE IF NOT EXISTS contributor_identities (
+    identity TEXT PRIMARY KEY,  -- contributor_id as recorded in unified_contributions
+    contributor_id TEXT NOT NULL,  -- Canonical contributor the identity belongs to
+    identity_type TEXT NOT NULL,  -- 'nostr_pubkey', 'bitcoin_address', 'github', ...
+    linked_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_contributor_identities_contributor ON contributor_identities(contributor_id);
@@ -0,0 +1,16 @@
+-- Migration 030: Deadline Notification Tracking
+-- Records deadline reminders sent to maintainers so each is only sent once per level
+
+CREATE TABLE IF NOT EXISTS deadline_notifications_sent (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    deadline_type TEXT NOT NULL,  -- 'response', 'resolution', 'appeal'
+    record_id INTEGER NOT NULL,  -- Case id (response, resolution) or appeal id
+    maintainer_id INTEGER NOT NULL,  -- Recipient
+    level TEXT NOT NULL,  -- 'upcoming', 'urgent'
+    deadline TIMESTAMP NOT NULL,  -- An extended deadline is notified again
+    sent_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    UNIQUE(deadline_type, record_id, maintainer_id, level, deadline),
+    CHECK (deadline_type IN ('response', 'resolution', 'appeal')),
+    CHECK (level IN ('upcoming', 'urgent'))
+);
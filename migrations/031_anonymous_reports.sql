@@ -0,0 +1,34 @@
+-- Migration 031: Anonymous Whistleblower Reports
+-- Reports submitted without revealing the reporter; the reporter's identity is
+-- stored encrypted and only released once the report is escalated and enough
+-- escrow maintainers have requested it
+
+CREATE TABLE IF NOT EXISTS anonymous_reports (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    encrypted_identity_blob BLOB NOT NULL,  -- Opaque; encrypted by the reporter
+    report_content TEXT NOT NULL,
+    escrowed_for_maintainers JSON NOT NULL DEFAULT '[]',  -- Maintainers who may request the identity
+    escrow_threshold INTEGER NOT NULL,  -- Unlock requests needed, fixed at submission
+    status TEXT NOT NULL DEFAULT 'submitted',  -- 'submitted', 'escalated'
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    escalated_at TIMESTAMP,
+    escalated_by TEXT,
+
+    CHECK (status IN ('submitted', 'escalated')),
+    CHECK (escrow_threshold > 0)
+);
+
+-- One unlock request per escrow maintainer per report
+CREATE TABLE IF NOT EXISTS anonymous_report_unlock_requests (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    report_id INTEGER NOT NULL,
+    requesting_maintainer TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    requested_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    FOREIGN KEY (report_id) REFERENCES anonymous_reports(id),
+    UNIQUE(report_id, requesting_maintainer)
+);
+
+CREATE INDEX IF NOT EXISTS idx_anonymous_reports_status ON anonymous_reports(status);
+CREATE INDEX IF NOT EXISTS idx_anonymous_report_unlock_requests_report ON anonymous_report_unlock_requests(report_id);
@@ -0,0 +1,14 @@
+-- Migration 032: Zap Backfill
+-- Zaps are keyed by their receipt event id so historical receipts fetched after
+-- downtime (and relays re-sending receipts) are only recorded once
+
+ALTER TABLE zap_contributions ADD COLUMN event_id TEXT;  -- NIP-57 zap receipt event id
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_zap_event_id ON zap_contributions(event_id) WHERE event_id IS NOT NULL;
+
+-- Latest recorded zap per tracked bot; backfill resumes from here on start
+CREATE TABLE IF NOT EXISTS zap_tracker_cursors (
+    recipient_pubkey TEXT PRIMARY KEY,
+    last_zap_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
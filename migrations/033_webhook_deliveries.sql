@@ -0,0 +1,11 @@
+-- Migration 033: GitHub webhook delivery replay protection
+-- Records each accepted X-GitHub-Delivery GUID; rows older than the replay
+-- window are pruned as new deliveries arrive
+
+CREATE TABLE IF NOT EXISTS github_webhook_deliveries (
+    delivery_id TEXT PRIMARY KEY,
+    event_type TEXT NOT NULL,
+    received_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_github_webhook_deliveries_received ON github_webhook_deliveries(received_at);
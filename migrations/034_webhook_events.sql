@@ -0,0 +1,20 @@
+-- Migration 034: Persisted GitHub webhook event queue
+-- The webhook handler stores each verified event here and returns; a background
+-- worker processes events in order, retrying failures with backoff
+
+CREATE TABLE IF NOT EXISTS webhook_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    delivery_id TEXT UNIQUE NOT NULL, -- X-GitHub-Delivery GUID
+    event_type TEXT NOT NULL,
+    payload JSON NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending', -- 'pending', 'processing', 'done', 'dead'
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    received_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    next_attempt_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    processed_at TIMESTAMP,
+
+    CHECK (status IN ('pending', 'processing', 'done', 'dead'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_events_status_next ON webhook_events(status, next_attempt_at, id);
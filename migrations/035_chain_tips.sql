@@ -0,0 +1,27 @@
+-- Migration 035: Chain tip tracking from verified block headers
+-- Every block whose header passed proof-of-work verification is recorded;
+-- exactly one row is the best tip. Reorgs (a new best block whose parent was
+-- not the previous tip) are recorded in chain_reorgs.
+
+CREATE TABLE IF NOT EXISTS chain_tips (
+    block_hash TEXT PRIMARY KEY,
+    height INTEGER NOT NULL,
+    prev_block_hash TEXT NOT NULL,
+    is_tip BOOLEAN NOT NULL DEFAULT FALSE,
+    received_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_chain_tips_is_tip ON chain_tips(is_tip);
+CREATE INDEX IF NOT EXISTS idx_chain_tips_height ON chain_tips(height);
+
+CREATE TABLE IF NOT EXISTS chain_reorgs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    old_tip_hash TEXT NOT NULL,
+    old_height INTEGER NOT NULL,
+    new_tip_hash TEXT NOT NULL,
+    new_height INTEGER NOT NULL,
+    fork_height INTEGER, -- NULL when the common ancestor isn't known
+    detected_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_chain_reorgs_detected ON chain_reorgs(detected_at DESC);
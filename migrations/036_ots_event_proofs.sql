@@ -0,0 +1,16 @@
+-- Migration 036: OTS proofs for individual governance events
+-- Anchored on demand via /internal/ots/anchor, alongside the monthly
+-- registry anchoring. upgraded_at is set once the proof is confirmed.
+
+CREATE TABLE IF NOT EXISTS ots_event_proofs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_type TEXT NOT NULL,
+    event_id TEXT NOT NULL,
+    digest_hex TEXT NOT NULL,
+    proof_bytes BLOB NOT NULL,
+    submitted_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    upgraded_at TIMESTAMP,
+    UNIQUE(event_type, event_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_ots_event_proofs_pending ON ots_event_proofs(upgraded_at);
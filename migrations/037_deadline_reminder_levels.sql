@@ -0,0 +1,26 @@
+-- Migration 037: Deadline Reminder Lead Times
+-- The daily reminder task records one row per lead time ('lead_14d', 'lead_7d', ...)
+-- and one 'overdue' escalation per deadline, so the level CHECK is widened.
+-- SQLite can't alter a CHECK constraint, so the table is rebuilt.
+
+CREATE TABLE deadline_notifications_sent_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    deadline_type TEXT NOT NULL,  -- 'response', 'resolution', 'appeal'
+    record_id INTEGER NOT NULL,  -- Case id (response, resolution) or appeal id
+    maintainer_id INTEGER NOT NULL,  -- Recipient
+    level TEXT NOT NULL,  -- 'upcoming', 'urgent', 'lead_<days>d', 'overdue'
+    deadline TIMESTAMP NOT NULL,  -- An extended deadline is notified again
+    sent_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    UNIQUE(deadline_type, record_id, maintainer_id, level, deadline),
+    CHECK (deadline_type IN ('response', 'resolution', 'appeal')),
+    CHECK (level IN ('upcoming', 'urgent', 'overdue') OR level GLOB 'lead_[0-9]*d')
+);
+
+INSERT INTO deadline_notifications_sent_new
+    (id, deadline_type, record_id, maintainer_id, level, deadline, sent_at)
+SELECT id, deadline_type, record_id, maintainer_id, level, deadline, sent_at
+FROM deadline_notifications_sent;
+
+DROP TABLE deadline_notifications_sent;
+ALTER TABLE deadline_notifications_sent_new RENAME TO deadline_notifications_sent;
@@ -0,0 +1,9 @@
+-- Migration 038: Schema Migrations View
+-- Applied migrations with their checksums, as recorded by the migrator in
+-- _sqlx_migrations. Database::migration_status compares these against the
+-- migrations embedded in the binary before running new ones.
+
+CREATE VIEW IF NOT EXISTS schema_migrations AS
+SELECT version, description, lower(hex(checksum)) AS checksum, installed_on AS applied_at
+FROM _sqlx_migrations
+WHERE success = TRUE;
@@ -0,0 +1,14 @@
+-- Migration 039: Node Registration Challenges
+-- A registration that claims a public key must sign a nonce issued for that
+-- key, so nobody can register (and squat) a key they don't control.
+-- Each nonce expires after a few minutes and can be used once.
+
+CREATE TABLE IF NOT EXISTS registration_challenges (
+    nonce TEXT PRIMARY KEY,  -- 32 random bytes, hex
+    public_key TEXT NOT NULL,  -- Key the nonce was issued for
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+    consumed_at TIMESTAMP  -- Set when a registration uses the nonce
+);
+
+CREATE INDEX IF NOT EXISTS idx_registration_challenges_expires ON registration_challenges(expires_at);
@@ -0,0 +1,16 @@
+-- Migration 040: Node API Tokens
+-- Bearer tokens scoped to one registered node, for its self-service
+-- endpoints (/nodes/me). Only the SHA-256 of each token is stored.
+
+CREATE TABLE IF NOT EXISTS node_api_tokens (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    node_id TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,  -- SHA-256 of the token, hex
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP,  -- NULL: never expires
+    revoked_at TIMESTAMP,
+    last_used_at TIMESTAMP,
+    FOREIGN KEY (node_id) REFERENCES node_registry(node_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_node_api_tokens_node ON node_api_tokens(node_id);
@@ -0,0 +1,22 @@
+-- Migration 041: Audit Events Index
+-- Queryable copy of the audit log. The JSONL file stays the canonical,
+-- tamper-evident record; rows here can be rebuilt from it at any time.
+
+CREATE TABLE IF NOT EXISTS audit_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    entry_hash TEXT NOT NULL UNIQUE,  -- this_log_hash of the file entry
+    previous_hash TEXT NOT NULL,
+    event_id TEXT NOT NULL,           -- event ID, or job ID for non-event entries
+    timestamp TEXT NOT NULL,          -- RFC 3339, UTC, microseconds
+    actor TEXT NOT NULL,
+    action TEXT NOT NULL,
+    category TEXT,
+    severity TEXT,
+    subject_type TEXT,                -- "review_case" in "review_case:12"
+    subject_id TEXT,
+    details TEXT NOT NULL DEFAULT '{}'
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_events_timestamp ON audit_events(timestamp);
+CREATE INDEX IF NOT EXISTS idx_audit_events_actor ON audit_events(actor, timestamp);
+CREATE INDEX IF NOT EXISTS idx_audit_events_subject ON audit_events(subject_type, subject_id, timestamp);
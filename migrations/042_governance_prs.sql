@@ -0,0 +1,17 @@
+-- Migration 042: Governance PRs
+-- Open and closed governance PRs with their tier, maintained from pull
+-- request webhooks, for detecting same-layer PRs whose review periods overlap.
+
+CREATE TABLE IF NOT EXISTS governance_prs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    repo_name TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    layer INTEGER NOT NULL,
+    tier INTEGER NOT NULL,
+    opened_at TIMESTAMP NOT NULL,
+    closed_at TIMESTAMP,  -- NULL while open
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(repo_name, pr_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_governance_prs_open ON governance_prs(layer, tier) WHERE closed_at IS NULL;
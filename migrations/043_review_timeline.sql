@@ -0,0 +1,20 @@
+-- Migration 043: Review Timeline
+-- Pause, resume and reset events for governance PR review periods, recorded
+-- from review and pull request webhooks. The review period only counts time
+-- while no pause is active, and restarts on a reset.
+
+CREATE TABLE IF NOT EXISTS pr_timeline_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    repo_name TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    event_type TEXT NOT NULL,  -- 'pause', 'resume', 'reset'
+    reason TEXT NOT NULL,  -- e.g. 'changes_requested:alice', 'major_revision'
+    occurred_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (event_type IN ('pause', 'resume', 'reset'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_pr_timeline_events_pr ON pr_timeline_events(repo_name, pr_number, occurred_at);
+
+-- Diff size (additions + deletions) at the last push, for detecting major revisions
+ALTER TABLE governance_prs ADD COLUMN diff_lines INTEGER;
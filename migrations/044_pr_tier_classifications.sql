@@ -0,0 +1,16 @@
+-- Migration 044: PR Tier Classifications
+-- The latest tier classification of each PR, with the rules that matched and
+-- why, written by the pull request webhook and by maintainer tier overrides.
+
+CREATE TABLE IF NOT EXISTS pr_tier_classifications (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    repo_name TEXT NOT NULL,
+    pr_number INTEGER NOT NULL,
+    tier INTEGER NOT NULL,
+    matched_rules TEXT NOT NULL,  -- JSON array of rule descriptions
+    explanation TEXT NOT NULL,
+    overridden BOOLEAN NOT NULL DEFAULT FALSE,  -- Set by a maintainer override
+    head_sha TEXT,  -- Head commit when last classified
+    classified_at TIMESTAMP NOT NULL,
+    UNIQUE(repo_name, pr_number)
+);
@@ -0,0 +1,17 @@
+-- Migration 045: Contribution Trends
+-- Snapshots of a contributor's rolling-window contribution statistics, taken
+-- whenever a trend is computed, so trends can be compared over time.
+
+CREATE TABLE IF NOT EXISTS contribution_trends (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    contributor_id TEXT NOT NULL,  -- Canonical contributor (linked identities merged)
+    window_days INTEGER NOT NULL,
+    current_period_total REAL NOT NULL,  -- BTC in the last window_days
+    previous_period_total REAL NOT NULL,  -- BTC in the window_days before that
+    growth_rate REAL,  -- NULL when the previous period had no contributions
+    moving_average_7d REAL NOT NULL,  -- Average BTC per day over the last 7 days
+    moving_average_30d REAL NOT NULL,  -- Average BTC per day over the last 30 days
+    snapshot_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_contribution_trends_contributor ON contribution_trends(contributor_id, snapshot_at);
@@ -0,0 +1,21 @@
+-- Migration 046: Contribution Anomalies
+-- Contributions far above the contributor's 90-day rolling average, flagged for
+-- maintainer review. Contributors with pending anomalies have large weight
+-- changes held back until the anomaly is reviewed or dismissed.
+
+CREATE TABLE IF NOT EXISTS contribution_anomalies (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    contribution_id INTEGER NOT NULL UNIQUE,  -- unified_contributions row that was flagged
+    contributor_id TEXT NOT NULL,
+    contribution_type TEXT NOT NULL,
+    amount_btc REAL NOT NULL,
+    rolling_average_btc REAL NOT NULL,  -- Average contribution over the 90 days before it
+    threshold_multiplier REAL NOT NULL,  -- Multiple of the average that triggered the flag
+    status TEXT NOT NULL DEFAULT 'pending',  -- 'pending', 'reviewed' or 'dismissed'
+    detected_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    reviewed_at TIMESTAMP,
+    reviewed_by TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_contribution_anomalies_contributor ON contribution_anomalies(contributor_id, status);
+CREATE INDEX IF NOT EXISTS idx_contribution_anomalies_status ON contribution_anomalies(status);
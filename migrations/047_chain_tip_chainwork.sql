@@ -0,0 +1,10 @@
+-- Migration 047: Select the chain tip by cumulative work
+-- Blocks must now build on a known block back to a configured checkpoint, and
+-- the tip is the block with the most work since it. Blocks recorded under the
+-- old rules took their height from the notification and may not connect to
+-- the checkpoint, so they are dropped; reorg history is kept.
+
+DELETE FROM chain_tips;
+
+-- Work since the checkpoint, 64 big-endian hex digits (compares as text)
+ALTER TABLE chain_tips ADD COLUMN chainwork TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,10 @@
+-- Migration 048: Grandfather zaps recorded before payment verification
+-- Migration 020 marked every existing zap 'unverified', but zaps recorded
+-- before it had already been counted as contributions. Re-verifying them would
+-- count them again, so they are marked verified as of when they were recorded.
+
+UPDATE zap_contributions
+SET verification_status = 'verified',
+    verified_at = created_at
+WHERE verification_status = 'unverified'
+  AND created_at < (SELECT installed_on FROM _sqlx_migrations WHERE version = 20);
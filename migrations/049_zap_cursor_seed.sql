@@ -0,0 +1,12 @@
+-- Migration 049: Seed zap backfill cursors from recorded zaps
+-- Migration 032 added the cursors empty, so the first backfill fetched each
+-- bot's full receipt history and re-recorded zaps that already had rows (they
+-- predate event ids). Start each bot's cursor at its latest recorded zap.
+
+INSERT INTO zap_tracker_cursors (recipient_pubkey, last_zap_at, updated_at)
+SELECT recipient_pubkey, MAX(timestamp), CURRENT_TIMESTAMP
+FROM zap_contributions
+GROUP BY recipient_pubkey
+ON CONFLICT(recipient_pubkey) DO UPDATE SET
+    last_zap_at = MAX(last_zap_at, excluded.last_zap_at),
+    updated_at = CURRENT_TIMESTAMP;
@@ -0,0 +1,12 @@
+-- Migration 050: Track governance events covered by each audit anchor
+-- anchored_at has one-second resolution, so tier actions logged in the same
+-- second as an anchor were never picked up by the next check. Record the
+-- highest governance event id at anchor time and compare ids instead.
+
+ALTER TABLE audit_anchors ADD COLUMN last_event_id INTEGER NOT NULL DEFAULT 0;
+
+-- Existing anchors cover the events logged up to their timestamp
+UPDATE audit_anchors SET last_event_id = COALESCE(
+    (SELECT MAX(id) FROM governance_events WHERE timestamp <= audit_anchors.anchored_at),
+    0
+);
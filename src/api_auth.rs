@@ -0,0 +1,94 @@
+//! Internal API Authentication
+//!
+//! Middleware guarding the /internal endpoints with the shared secret from
+//! `AppConfig::internal_api_key`, sent as `X-API-Key` or a bearer token.
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use tracing::warn;
+
+use crate::config::AppConfig;
+use crate::database::Database;
+
+/// Extract the API key from `X-API-Key` or `Authorization: Bearer`
+pub(crate) fn presented_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get("x-api-key")
+        .and_then(|value| value.to_str().ok())
+        .or_else(|| {
+            headers
+                .get("authorization")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.strip_prefix("Bearer "))
+        })
+}
+
+/// Compare without short-circuiting on the first differing byte
+pub(crate) fn keys_match(presented: &str, expected: &str) -> bool {
+    presented.len() == expected.len()
+        && presented
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Reject requests without the configured internal API key
+pub async fn require_internal_api_key(
+    State((config, _)): State<(AppConfig, Database)>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(expected) = config.internal_api_key.as_deref() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({"error": "internal API is disabled"})),
+        )
+            .into_response();
+    };
+
+    match presented_key(request.headers()) {
+        Some(key) if keys_match(key, expected) => next.run(request).await,
+        _ => {
+            warn!(
+                "Rejected unauthenticated request to {}",
+                request.uri().path()
+            );
+            (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({"error": "invalid or missing API key"})),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presented_key() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(presented_key(&headers), None);
+
+        headers.insert("authorization", "Bearer secret".parse().unwrap());
+        assert_eq!(presented_key(&headers), Some("secret"));
+
+        headers.insert("x-api-key", "other".parse().unwrap());
+        assert_eq!(presented_key(&headers), Some("other"));
+    }
+
+    #[test]
+    fn test_keys_match() {
+        assert!(keys_match("secret", "secret"));
+        assert!(!keys_match("secreT", "secret"));
+        assert!(!keys_match("secret2", "secret"));
+        assert!(!keys_match("", "secret"));
+    }
+}
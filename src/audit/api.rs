@@ -0,0 +1,148 @@
+//! Internal audit log endpoints
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::path::Path;
+use tracing::warn;
+
+use crate::api_auth::require_internal_api_key;
+use crate::audit::index::{audit_log_files, AuditIndex, AuditPage, AuditQuery, BackfillReport};
+use crate::audit::logger::{shared_logger, AuditLogger};
+use crate::audit::verify::AuditVerificationReport;
+use crate::config::AppConfig;
+use crate::database::Database;
+
+/// Page size when the client doesn't specify one
+const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Largest page a client may request
+const MAX_PAGE_SIZE: i64 = 500;
+
+/// Audit query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditQueryParams {
+    pub actor: Option<String>,
+    pub action: Option<String>,
+    pub category: Option<String>,
+    pub subject_type: Option<String>,
+    pub subject_id: Option<String>,
+    /// Events at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Events before this time
+    pub until: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+impl AuditQueryParams {
+    fn to_query(&self) -> AuditQuery {
+        AuditQuery {
+            actor: self.actor.clone(),
+            action: self.action.clone(),
+            category: self.category.clone(),
+            subject_type: self.subject_type.clone(),
+            subject_id: self.subject_id.clone(),
+            since: self.since,
+            until: self.until,
+            limit: Some(
+                self.limit
+                    .unwrap_or(DEFAULT_PAGE_SIZE)
+                    .clamp(1, MAX_PAGE_SIZE),
+            ),
+            offset: self.offset.unwrap_or(0).max(0),
+        }
+    }
+}
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+fn audit_logger() -> Result<&'static AuditLogger, ApiError> {
+    shared_logger().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Audit logging is not enabled",
+        )
+    })
+}
+
+/// Verify the signature of every entry in the audit log
+pub async fn verify_audit_log() -> Result<Json<AuditVerificationReport>, ApiError> {
+    let logger = audit_logger()?;
+    logger
+        .verify_log_file(Path::new(logger.log_path()))
+        .map(Json)
+        .map_err(|e| {
+            warn!("Failed to verify audit log: {}", e);
+            api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        })
+}
+
+fn audit_index(database: &Database) -> Result<AuditIndex, ApiError> {
+    database
+        .get_sqlite_pool()
+        .map(AuditIndex::new)
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Database pool not available",
+            )
+        })
+}
+
+/// Search indexed audit events, newest first
+pub async fn query_audit_events(
+    State((_, database)): State<(AppConfig, Database)>,
+    Query(params): Query<AuditQueryParams>,
+) -> Result<Json<AuditPage>, ApiError> {
+    audit_index(&database)?
+        .query(&params.to_query())
+        .await
+        .map(Json)
+        .map_err(|e| {
+            warn!("Failed to query audit events: {}", e);
+            api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        })
+}
+
+/// Re-index the audit log and its rotated files
+pub async fn backfill_audit_index(
+    State((_, database)): State<(AppConfig, Database)>,
+) -> Result<Json<BackfillReport>, ApiError> {
+    let logger = audit_logger()?;
+    let index = audit_index(&database)?;
+    let result = match audit_log_files(Path::new(logger.log_path())) {
+        Ok(files) => index.backfill(&files).await,
+        Err(e) => Err(e),
+    };
+    result.map(Json).map_err(|e| {
+        warn!("Failed to backfill audit index: {}", e);
+        api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+    })
+}
+
+/// Create router for audit API; all routes require the internal API key
+pub fn create_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/internal/audit", get(query_audit_events))
+        .route("/internal/audit/verify", get(verify_audit_log))
+        .route("/internal/audit/backfill", post(backfill_audit_index))
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ))
+}
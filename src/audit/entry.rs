@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 
+use crate::audit::event::AuditEvent;
 use crate::audit::logger::AuditLogger;
 
 /// Audit log entry with cryptographic hash chain
@@ -23,6 +24,12 @@ pub struct AuditLogEntry {
     pub previous_log_hash: String,
     pub this_log_hash: String,
     pub metadata: HashMap<String, String>,
+    /// Structured event, for entries written by `AuditLogger::log_event`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event: Option<AuditEvent>,
+    /// Hex Schnorr signature over `signing_digest`, by the server's Nostr key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
 }
 
 impl AuditLogEntry {
@@ -48,6 +55,8 @@ impl AuditLogEntry {
             previous_log_hash,
             this_log_hash: String::new(), // Will be calculated
             metadata,
+            event: None,
+            signature: None,
         };
 
         // Calculate this entry's hash
@@ -55,9 +64,39 @@ impl AuditLogEntry {
         entry
     }
 
+    /// Create an entry recording a structured audit event
+    ///
+    /// The entry takes the event's ID and timestamp; its inputs hash is the
+    /// hash of the serialized event.
+    pub fn from_event(event: AuditEvent, server_id: String, previous_log_hash: String) -> Self {
+        let event_json = serde_json::to_vec(&event).unwrap_or_default();
+        let mut hasher = Sha256::new();
+        hasher.update(&event_json);
+
+        let mut entry = Self {
+            job_id: event.event_id.clone(),
+            job_type: "audit_event".to_string(),
+            timestamp: event.timestamp,
+            server_id,
+            inputs_hash: format!("sha256:{}", hex::encode(hasher.finalize())),
+            outputs_hash: "sha256:0000000000000000000000000000000000000000000000000000000000000000"
+                .to_string(),
+            previous_log_hash,
+            this_log_hash: String::new(),
+            metadata: HashMap::new(),
+            event: Some(event),
+            signature: None,
+        };
+        entry.this_log_hash = entry.calculate_hash();
+        entry
+    }
+
     /// Create canonical string representation for hashing
+    ///
+    /// The event is only included when present, so entries without one hash
+    /// as they always have.
     pub fn canonical_string(&self) -> String {
-        format!(
+        let mut canonical = format!(
             "job_id:{}|job_type:{}|timestamp:{}|server_id:{}|inputs_hash:{}|outputs_hash:{}|previous_log_hash:{}|metadata:{}",
             self.job_id,
             self.job_type,
@@ -67,7 +106,12 @@ impl AuditLogEntry {
             self.outputs_hash,
             self.previous_log_hash,
             self.serialize_metadata()
-        )
+        );
+        if let Some(event) = &self.event {
+            canonical.push_str("|event:");
+            canonical.push_str(&serde_json::to_string(event).unwrap_or_default());
+        }
+        canonical
     }
 
     /// Calculate SHA256 hash of this entry
@@ -90,6 +134,14 @@ impl AuditLogEntry {
         items.join(",")
     }
 
+    /// Digest signed by the server: SHA-256 of the canonical string
+    ///
+    /// The canonical string covers every field except the signature. The raw
+    /// JSON is not hashed because the metadata map has no stable key order.
+    pub fn signing_digest(&self) -> [u8; 32] {
+        Sha256::digest(self.canonical_string().as_bytes()).into()
+    }
+
     /// Verify this entry's hash
     pub fn verify_hash(&self) -> bool {
         self.this_log_hash == self.calculate_hash()
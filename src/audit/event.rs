@@ -0,0 +1,101 @@
+//! Structured Audit Events
+//!
+//! Typed audit events (category, severity, actor, target, action) recorded
+//! in the hash-chained audit log, and filters for searching them.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Area of the system an audit event belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AuditCategory {
+    GovernanceAction,
+    NodeRegistry,
+    ConfigChange,
+    VetoSignal,
+    Authentication,
+    Security,
+    /// Side effect skipped in dry-run mode
+    DryRun,
+}
+
+/// Severity of an audit event, least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum AuditSeverity {
+    Info,
+    Warning,
+    Error,
+    Critical,
+}
+
+/// Structured audit event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub timestamp: DateTime<Utc>,
+    pub event_id: String, // UUIDv4
+    pub category: AuditCategory,
+    pub severity: AuditSeverity,
+    /// Username or public key that performed the action
+    pub actor: String,
+    /// Node ID, PR number, etc. the action applied to
+    pub target: Option<String>,
+    pub action: String,
+    pub metadata: serde_json::Value,
+}
+
+impl AuditEvent {
+    /// Create an event timestamped now with a fresh event ID
+    pub fn new(
+        category: AuditCategory,
+        severity: AuditSeverity,
+        actor: impl Into<String>,
+        action: impl Into<String>,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            event_id: Uuid::new_v4().to_string(),
+            category,
+            severity,
+            actor: actor.into(),
+            target: None,
+            action: action.into(),
+            metadata: serde_json::Value::Null,
+        }
+    }
+
+    pub fn with_target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn with_metadata(mut self, metadata: serde_json::Value) -> Self {
+        self.metadata = metadata;
+        self
+    }
+}
+
+/// Audit event search criteria; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    /// Events at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Events at or before this time
+    pub until: Option<DateTime<Utc>>,
+    pub category: Option<AuditCategory>,
+    pub actor: Option<String>,
+}
+
+impl AuditFilter {
+    pub fn matches(&self, event: &AuditEvent) -> bool {
+        self.since.is_none_or(|since| event.timestamp >= since)
+            && self.until.is_none_or(|until| event.timestamp <= until)
+            && self
+                .category
+                .is_none_or(|category| event.category == category)
+            && self
+                .actor
+                .as_ref()
+                .is_none_or(|actor| &event.actor == actor)
+    }
+}
@@ -0,0 +1,446 @@
+//! Audit Event Index
+//!
+//! Queryable copy of the audit log in the `audit_events` table. The JSONL file
+//! remains the canonical, tamper-evident record: the logger writes the file
+//! first and indexes the entry afterwards, and [`AuditIndex::backfill`] can
+//! rebuild the table from the files at any time. Rows are keyed by the entry's
+//! chain hash, so indexing an entry twice is a no-op.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::Serialize;
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::audit::entry::AuditLogEntry;
+
+/// Indexed audit entry
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEventRecord {
+    pub id: i64,
+    pub entry_hash: String,
+    pub previous_hash: String,
+    pub event_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub actor: String,
+    pub action: String,
+    pub category: Option<String>,
+    pub severity: Option<String>,
+    pub subject_type: Option<String>,
+    pub subject_id: Option<String>,
+    pub details: serde_json::Value,
+}
+
+#[derive(sqlx::FromRow)]
+struct AuditEventRow {
+    id: i64,
+    entry_hash: String,
+    previous_hash: String,
+    event_id: String,
+    timestamp: String,
+    actor: String,
+    action: String,
+    category: Option<String>,
+    severity: Option<String>,
+    subject_type: Option<String>,
+    subject_id: Option<String>,
+    details: String,
+}
+
+impl AuditEventRow {
+    fn into_record(self) -> Result<AuditEventRecord> {
+        Ok(AuditEventRecord {
+            id: self.id,
+            entry_hash: self.entry_hash,
+            previous_hash: self.previous_hash,
+            event_id: self.event_id,
+            timestamp: DateTime::parse_from_rfc3339(&self.timestamp)
+                .map_err(|e| anyhow!("Invalid audit event timestamp: {}", e))?
+                .with_timezone(&Utc),
+            actor: self.actor,
+            action: self.action,
+            category: self.category,
+            severity: self.severity,
+            subject_type: self.subject_type,
+            subject_id: self.subject_id,
+            details: serde_json::from_str(&self.details)?,
+        })
+    }
+}
+
+/// Audit event search criteria; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct AuditQuery {
+    pub actor: Option<String>,
+    pub action: Option<String>,
+    pub category: Option<String>,
+    /// e.g. "review_case" or "emergency"
+    pub subject_type: Option<String>,
+    pub subject_id: Option<String>,
+    /// Events at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Events before this time
+    pub until: Option<DateTime<Utc>>,
+    /// None returns every match
+    pub limit: Option<i64>,
+    pub offset: i64,
+}
+
+impl AuditQuery {
+    fn push_conditions(&self, query: &mut QueryBuilder<'_, Sqlite>) {
+        query.push(" WHERE 1 = 1");
+        for (column, value) in [
+            ("actor", &self.actor),
+            ("action", &self.action),
+            ("category", &self.category),
+            ("subject_type", &self.subject_type),
+            ("subject_id", &self.subject_id),
+        ] {
+            if let Some(value) = value {
+                query
+                    .push(format!(" AND {} = ", column))
+                    .push_bind(value.clone());
+            }
+        }
+        if let Some(since) = self.since {
+            query
+                .push(" AND timestamp >= ")
+                .push_bind(format_timestamp(since));
+        }
+        if let Some(until) = self.until {
+            query
+                .push(" AND timestamp < ")
+                .push_bind(format_timestamp(until));
+        }
+    }
+}
+
+/// One page of indexed events, newest first, plus the total number of matches
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditPage {
+    pub events: Vec<AuditEventRecord>,
+    pub total: i64,
+    pub limit: Option<i64>,
+    pub offset: i64,
+}
+
+/// Outcome of [`AuditIndex::backfill`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BackfillReport {
+    pub files: usize,
+    /// Entries read from the files
+    pub entries: u64,
+    /// Entries that were not yet indexed
+    pub inserted: u64,
+}
+
+/// Fixed-width timestamps, so text comparison orders them chronologically
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+/// Writes and queries the `audit_events` table
+#[derive(Clone)]
+pub struct AuditIndex {
+    pool: SqlitePool,
+}
+
+impl AuditIndex {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Index one log entry; false if it was already indexed
+    ///
+    /// Structured events keep their actor, action and target (split on the
+    /// first `:` into subject type and ID). Other entries are recorded as
+    /// actions of the writing server, with their metadata as details.
+    pub async fn insert(&self, entry: &AuditLogEntry) -> Result<bool> {
+        let (actor, action, category, severity, target, details) = match &entry.event {
+            Some(event) => (
+                event.actor.clone(),
+                event.action.clone(),
+                serde_json::to_value(event.category)?
+                    .as_str()
+                    .map(str::to_string),
+                serde_json::to_value(event.severity)?
+                    .as_str()
+                    .map(str::to_string),
+                event.target.clone(),
+                event.metadata.clone(),
+            ),
+            None => (
+                entry.server_id.clone(),
+                entry.job_type.clone(),
+                None,
+                None,
+                None,
+                serde_json::to_value(&entry.metadata)?,
+            ),
+        };
+        let (subject_type, subject_id) = match target.as_deref().map(|t| t.split_once(':')) {
+            Some(Some((kind, id))) => (Some(kind.to_string()), Some(id.to_string())),
+            Some(None) => (None, target.clone()),
+            None => (None, None),
+        };
+
+        let inserted = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO audit_events
+            (entry_hash, previous_hash, event_id, timestamp, actor, action, category,
+             severity, subject_type, subject_id, details)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&entry.this_log_hash)
+        .bind(&entry.previous_log_hash)
+        .bind(&entry.job_id)
+        .bind(format_timestamp(entry.timestamp))
+        .bind(actor)
+        .bind(action)
+        .bind(category)
+        .bind(severity)
+        .bind(subject_type)
+        .bind(subject_id)
+        .bind(serde_json::to_string(&details)?)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        Ok(inserted > 0)
+    }
+
+    /// Indexed events matching `query`, newest first
+    ///
+    /// Events with the same timestamp are ordered by insertion, so pages are stable.
+    pub async fn query(&self, query: &AuditQuery) -> Result<AuditPage> {
+        let mut count_query = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM audit_events");
+        query.push_conditions(&mut count_query);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut select = QueryBuilder::<Sqlite>::new("SELECT * FROM audit_events");
+        query.push_conditions(&mut select);
+        select.push(" ORDER BY timestamp DESC, id DESC LIMIT ");
+        // SQLite treats a negative limit as no limit
+        select.push_bind(query.limit.unwrap_or(-1));
+        select.push(" OFFSET ");
+        select.push_bind(query.offset);
+
+        let rows: Vec<AuditEventRow> = select.build_query_as().fetch_all(&self.pool).await?;
+        Ok(AuditPage {
+            events: rows
+                .into_iter()
+                .map(AuditEventRow::into_record)
+                .collect::<Result<_>>()?,
+            total,
+            limit: query.limit,
+            offset: query.offset,
+        })
+    }
+
+    /// Index every entry in the given log files, in order
+    ///
+    /// Already indexed entries are skipped, so this can be re-run safely.
+    pub async fn backfill(&self, paths: &[PathBuf]) -> Result<BackfillReport> {
+        let mut report = BackfillReport::default();
+        for path in paths {
+            let file = File::open(path)
+                .map_err(|e| anyhow!("Failed to open log file {:?}: {}", path, e))?;
+            for line in BufReader::new(file).lines() {
+                let line = line.map_err(|e| anyhow!("Failed to read log line: {}", e))?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let entry: AuditLogEntry = serde_json::from_str(&line)
+                    .map_err(|e| anyhow!("Failed to parse log entry: {}", e))?;
+                report.entries += 1;
+                if self.insert(&entry).await? {
+                    report.inserted += 1;
+                }
+            }
+            report.files += 1;
+        }
+
+        info!(
+            "Backfilled audit index: {} of {} entries from {} files were new",
+            report.inserted, report.entries, report.files
+        );
+        Ok(report)
+    }
+}
+
+/// The log file and its rotated predecessors, oldest first
+pub fn audit_log_files(log_path: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if let Some(dir) = log_path.parent().filter(|dir| dir.is_dir()) {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            // Rotation timestamps sort chronologically
+            if name.starts_with("governance_audit_") && name.ends_with(".jsonl") && path != log_path
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    if log_path.exists() {
+        files.push(log_path.to_path_buf());
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::event::{AuditCategory, AuditEvent, AuditSeverity};
+    use crate::audit::logger::AuditLogger;
+    use crate::database::Database;
+
+    async fn index() -> AuditIndex {
+        let database = Database::new_in_memory().await.unwrap();
+        AuditIndex::new(database.get_sqlite_pool().unwrap())
+    }
+
+    /// Logger writing to a temp file and to a fresh index
+    async fn logger(dir: &tempfile::TempDir) -> (AuditLogger, AuditIndex) {
+        let index = index().await;
+        let path = dir.path().join("audit.jsonl");
+        let logger = AuditLogger::new(path.to_string_lossy().into_owned())
+            .unwrap()
+            .with_index(index.clone());
+        (logger, index)
+    }
+
+    async fn write_events(logger: &AuditLogger) -> DateTime<Utc> {
+        let march = DateTime::parse_from_rfc3339("2026-03-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let events = [
+            ("alice", "review_case:123", "case_opened", march),
+            ("bob", "review_case:123", "case_commented", march),
+            ("alice", "emergency:7", "emergency_activated", march),
+            (
+                "alice",
+                "review_case:123",
+                "case_closed",
+                march + chrono::Duration::days(30),
+            ),
+        ];
+        for (actor, target, action, timestamp) in events {
+            let mut event = AuditEvent::new(
+                AuditCategory::GovernanceAction,
+                AuditSeverity::Info,
+                actor,
+                action,
+            )
+            .with_target(target)
+            .with_metadata(serde_json::json!({ "note": action }));
+            event.timestamp = timestamp;
+            logger.log_event(event).await.unwrap();
+        }
+        march
+    }
+
+    #[tokio::test]
+    async fn test_query_filters() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let (logger, index) = logger(&dir).await;
+        let march = write_events(&logger).await;
+
+        let all = index.query(&AuditQuery::default()).await.unwrap();
+        assert_eq!(all.total, 4);
+        assert_eq!(all.events[0].action, "case_closed");
+
+        let by_actor = index
+            .query(&AuditQuery {
+                actor: Some("bob".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(by_actor.total, 1);
+        assert_eq!(by_actor.events[0].details["note"], "case_commented");
+
+        let by_action = index
+            .query(&AuditQuery {
+                action: Some("emergency_activated".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            by_action.events[0].subject_type.as_deref(),
+            Some("emergency")
+        );
+        assert_eq!(
+            by_action.events[0].category.as_deref(),
+            Some("GovernanceAction")
+        );
+
+        // Everything affecting case 123 in March
+        let in_march = index
+            .query(&AuditQuery {
+                subject_type: Some("review_case".to_string()),
+                subject_id: Some("123".to_string()),
+                since: Some(march - chrono::Duration::days(9)),
+                until: Some(march + chrono::Duration::days(21)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(in_march.total, 2);
+
+        // Same-timestamp events page in a stable order
+        let first = index
+            .query(&AuditQuery {
+                limit: Some(2),
+                offset: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let second = index
+            .query(&AuditQuery {
+                limit: Some(2),
+                offset: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(first.events, second.events);
+        let actions: Vec<&str> = first.events.iter().map(|e| e.action.as_str()).collect();
+        assert_eq!(actions, vec!["emergency_activated", "case_commented"]);
+        assert_eq!(first.total, 4);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_matches_live_writes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let (logger, live) = logger(&dir).await;
+        write_events(&logger).await;
+
+        let backfilled = index().await;
+        let files = audit_log_files(Path::new(logger.log_path())).unwrap();
+        let report = backfilled.backfill(&files).await.unwrap();
+        assert_eq!(report.entries, 4);
+        assert_eq!(report.inserted, 4);
+
+        let live_rows = live.query(&AuditQuery::default()).await.unwrap().events;
+        let backfilled_rows = backfilled
+            .query(&AuditQuery::default())
+            .await
+            .unwrap()
+            .events;
+        assert_eq!(live_rows, backfilled_rows);
+
+        // Re-running adds nothing
+        let report = backfilled.backfill(&files).await.unwrap();
+        assert_eq!(report.inserted, 0);
+    }
+}
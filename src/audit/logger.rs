@@ -4,22 +4,72 @@
 //! for tamper-evident logging of governance operations.
 
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use secp256k1::{Keypair, Message, Secp256k1, XOnlyPublicKey};
+use serde::Serialize;
 use std::fs::{File, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 use tokio::sync::Mutex;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
 use crate::audit::entry::AuditLogEntry;
+use crate::audit::event::{AuditCategory, AuditEvent, AuditFilter, AuditSeverity};
+use crate::audit::index::{audit_log_files, AuditIndex};
+use crate::audit::verify::{verify_entry_signature, AuditVerificationReport};
+
+/// Server ID recorded in entries when none is configured
+const DEFAULT_SERVER_ID: &str = "governance-01";
+
+/// Writes between re-reading the log file size from disk
+const SIZE_CHECK_INTERVAL: u32 = 100;
+
+/// Log file statistics
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditLogStats {
+    pub current_file_size_bytes: u64,
+    /// Entries in the current log file and its rotated predecessors
+    pub total_entries: u64,
+    pub oldest_entry_at: Option<DateTime<Utc>>,
+    pub newest_entry_at: Option<DateTime<Utc>>,
+}
+
+/// Cached log file size, refreshed from disk every `SIZE_CHECK_INTERVAL` writes
+#[derive(Debug, Default)]
+struct SizeCache {
+    bytes: u64,
+    writes_since_check: u32,
+}
+
+/// The server's audit logger, for API handlers that read or verify the log
+static SHARED_LOGGER: OnceLock<AuditLogger> = OnceLock::new();
+
+/// Register the server's audit logger (only the first registration is kept)
+pub fn set_shared_logger(logger: AuditLogger) {
+    let _ = SHARED_LOGGER.set(logger);
+}
+
+/// The server's audit logger, if audit logging is enabled
+pub fn shared_logger() -> Option<&'static AuditLogger> {
+    SHARED_LOGGER.get()
+}
 
 /// Audit logger managing append-only JSONL file
 #[derive(Clone)]
 pub struct AuditLogger {
     log_path: String,
+    server_id: String,
     file: Arc<Mutex<Option<File>>>,
     head_hash: Arc<Mutex<String>>,
     entry_count: Arc<Mutex<u64>>,
+    /// Server key used to sign entries as they are appended
+    signing_key: Option<Keypair>,
+    /// Rotate the log file once it reaches this many bytes
+    max_file_size: Option<u64>,
+    size_cache: Arc<Mutex<SizeCache>>,
+    /// Queryable copy of appended entries; the file stays canonical
+    index: Option<AuditIndex>,
 }
 
 impl AuditLogger {
@@ -38,11 +88,20 @@ impl AuditLogger {
             .open(&log_path)
             .map_err(|e| anyhow!("Failed to open audit log file: {}", e))?;
 
+        let file_size = file.metadata().map(|m| m.len()).unwrap_or(0);
         let logger = Self {
             log_path: log_path.clone(),
+            server_id: DEFAULT_SERVER_ID.to_string(),
             file: Arc::new(Mutex::new(Some(file))),
             head_hash: Arc::new(Mutex::new(String::new())),
             entry_count: Arc::new(Mutex::new(0)),
+            signing_key: None,
+            max_file_size: None,
+            size_cache: Arc::new(Mutex::new(SizeCache {
+                bytes: file_size,
+                writes_since_check: 0,
+            })),
+            index: None,
         };
 
         // Initialize if file is new (synchronous initialization)
@@ -54,19 +113,161 @@ impl AuditLogger {
         Ok(logger)
     }
 
+    /// Record entries as written by this server
+    pub fn with_server_id(mut self, server_id: impl Into<String>) -> Self {
+        self.server_id = server_id.into();
+        self
+    }
+
+    /// Rotate the log file before a write once it exceeds `max_log_size_mb`
+    /// (0 disables size-based rotation)
+    pub fn with_max_log_size_mb(mut self, max_log_size_mb: u64) -> Self {
+        self.max_file_size = (max_log_size_mb > 0).then_some(max_log_size_mb * 1024 * 1024);
+        self
+    }
+
+    /// Also record appended entries in the `audit_events` table
+    pub fn with_index(mut self, index: AuditIndex) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// The `audit_events` index, if enabled
+    pub fn index(&self) -> Option<&AuditIndex> {
+        self.index.as_ref()
+    }
+
+    /// Sign appended entries with the server's Nostr key
+    pub fn with_signing_keys(mut self, keys: &nostr_sdk::Keys) -> Result<Self> {
+        let secret = keys
+            .secret_key()
+            .map_err(|e| anyhow!("Signing keys have no secret key: {}", e))?;
+        let keypair = Keypair::from_seckey_slice(&Secp256k1::new(), &secret.secret_bytes())
+            .map_err(|e| anyhow!("Invalid signing key: {}", e))?;
+        self.signing_key = Some(keypair);
+        Ok(self)
+    }
+
+    /// Public key entries are signed with, if signing is enabled
+    pub fn signing_pubkey(&self) -> Option<XOnlyPublicKey> {
+        self.signing_key
+            .as_ref()
+            .map(|keypair| keypair.x_only_public_key().0)
+    }
+
+    /// Sign an entry with the server key (Schnorr over its signing digest)
+    pub fn sign_entry(&self, entry: &mut AuditLogEntry) -> Result<()> {
+        let keypair = self
+            .signing_key
+            .as_ref()
+            .ok_or_else(|| anyhow!("Audit log signing key not configured"))?;
+        let message = Message::from_digest_slice(&entry.signing_digest())
+            .map_err(|e| anyhow!("Invalid signing digest: {}", e))?;
+        let signature = Secp256k1::new().sign_schnorr_no_aux_rand(&message, keypair);
+        entry.signature = Some(signature.to_string());
+        Ok(())
+    }
+
+    /// Re-read a log file and check every entry's signature against the server key
+    pub fn verify_log_file(&self, path: &Path) -> Result<AuditVerificationReport> {
+        let pubkey = self
+            .signing_pubkey()
+            .ok_or_else(|| anyhow!("Audit log signing key not configured"))?;
+        let file = File::open(path).map_err(|e| anyhow!("Failed to open log file: {}", e))?;
+
+        let mut report = AuditVerificationReport::default();
+        for line in BufReader::new(file).lines() {
+            let line = line.map_err(|e| anyhow!("Failed to read log line: {}", e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let entry: AuditLogEntry = serde_json::from_str(&line)
+                .map_err(|e| anyhow!("Failed to parse log entry: {}", e))?;
+            report.total_entries += 1;
+            match verify_entry_signature(&entry, &pubkey) {
+                Some(true) => report.valid_signatures += 1,
+                Some(false) => {
+                    report.invalid_signatures += 1;
+                    report.invalid_entries.push(entry.job_id);
+                }
+                None => report.missing_signatures += 1,
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Path of the log file
+    pub fn log_path(&self) -> &str {
+        &self.log_path
+    }
+
+    /// Append a structured audit event to the log
+    ///
+    /// The entry is chained to the head and written under the file lock, so
+    /// concurrent events can't both claim the same previous hash.
+    pub async fn log_event(&self, event: AuditEvent) -> Result<()> {
+        let mut file_guard = self.file.lock().await;
+        let entry =
+            AuditLogEntry::from_event(event, self.server_id.clone(), self.get_head_hash().await);
+        self.write_entry(&mut file_guard, entry).await
+    }
+
+    /// Log an informational event
+    pub async fn log_action(
+        &self,
+        category: AuditCategory,
+        actor: &str,
+        target: Option<&str>,
+        action: &str,
+        metadata: serde_json::Value,
+    ) -> Result<()> {
+        let mut event =
+            AuditEvent::new(category, AuditSeverity::Info, actor, action).with_metadata(metadata);
+        if let Some(target) = target {
+            event = event.with_target(target);
+        }
+        self.log_event(event).await
+    }
+
+    /// Structured events matching `filter` across rotated files, oldest first
+    pub async fn search(&self, filter: AuditFilter) -> Result<Vec<AuditEvent>> {
+        Ok(self
+            .get_all_entries_including_rotated()
+            .await?
+            .into_iter()
+            .filter_map(|entry| entry.event)
+            .filter(|event| filter.matches(event))
+            .collect())
+    }
+
     /// Append new entry to audit log
     pub async fn append_entry(&self, entry: AuditLogEntry) -> Result<()> {
+        let mut file_guard = self.file.lock().await;
+        self.write_entry(&mut file_guard, entry).await
+    }
+
+    /// Write an entry and advance the head; the caller holds the file lock
+    async fn write_entry(&self, file: &mut Option<File>, mut entry: AuditLogEntry) -> Result<()> {
         // Verify entry hash
         if !entry.verify_hash() {
             return Err(anyhow!("Invalid entry hash"));
         }
 
+        if self.signing_key.is_some() {
+            self.sign_entry(&mut entry)?;
+        }
+
         // Serialize entry to JSON
         let json = serde_json::to_string(&entry)
             .map_err(|e| anyhow!("Failed to serialize entry: {}", e))?;
 
-        // Write to file
-        if let Some(file) = self.file.lock().await.as_mut() {
+        // Write to file, rotating it first if it has grown too large
+        if self.should_rotate().await {
+            self.rotate(file).await?;
+        }
+        if let Some(file) = file.as_mut() {
             writeln!(file, "{}", json)
                 .map_err(|e| anyhow!("Failed to write to audit log: {}", e))?;
             file.flush()
@@ -74,6 +275,18 @@ impl AuditLogger {
         } else {
             return Err(anyhow!("Audit log file not available"));
         }
+        // Indexed under the file lock so the table keeps the file's order. The
+        // entry is already durable in the file; a backfill can repair the index.
+        if let Some(index) = &self.index {
+            if let Err(e) = index.insert(&entry).await {
+                warn!("Failed to index audit entry {}: {}", entry.job_id, e);
+            }
+        }
+        {
+            let mut size_cache = self.size_cache.lock().await;
+            size_cache.bytes += json.len() as u64 + 1;
+            size_cache.writes_since_check += 1;
+        }
 
         // Update head hash and count
         {
@@ -90,6 +303,67 @@ impl AuditLogger {
         Ok(())
     }
 
+    /// Whether the log file has reached its maximum size
+    async fn should_rotate(&self) -> bool {
+        let Some(max_file_size) = self.max_file_size else {
+            return false;
+        };
+
+        let mut size_cache = self.size_cache.lock().await;
+        if size_cache.writes_since_check >= SIZE_CHECK_INTERVAL {
+            if let Ok(metadata) = Path::new(&self.log_path).metadata() {
+                size_cache.bytes = metadata.len();
+            }
+            size_cache.writes_since_check = 0;
+        }
+        size_cache.bytes >= max_file_size
+    }
+
+    /// Move the current log aside as `governance_audit_<timestamp>.jsonl` and
+    /// start a new file; the hash chain continues from the current head
+    async fn rotate(&self, file: &mut Option<File>) -> Result<PathBuf> {
+        if let Some(current) = file.as_mut() {
+            current
+                .flush()
+                .map_err(|e| anyhow!("Failed to flush audit log before rotation: {}", e))?;
+        }
+
+        let log_path = Path::new(&self.log_path);
+        let rotated_path = log_path.with_file_name(format!(
+            "governance_audit_{}.jsonl",
+            Utc::now().format("%Y%m%dT%H%M%S%6fZ")
+        ));
+        std::fs::rename(log_path, &rotated_path)
+            .map_err(|e| anyhow!("Failed to rotate audit log: {}", e))?;
+        *file = Some(
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(log_path)
+                .map_err(|e| anyhow!("Failed to open new audit log file: {}", e))?,
+        );
+        *self.size_cache.lock().await = SizeCache::default();
+
+        info!("Rotated audit log to {}", rotated_path.display());
+        Ok(rotated_path)
+    }
+
+    /// Size of the current log file and entry range across rotated files
+    pub async fn get_log_stats(&self) -> Result<AuditLogStats> {
+        let current_file_size_bytes = Path::new(&self.log_path)
+            .metadata()
+            .map(|m| m.len())
+            .unwrap_or(0);
+        let entries = self.get_all_entries_including_rotated().await?;
+
+        Ok(AuditLogStats {
+            current_file_size_bytes,
+            total_entries: entries.len() as u64,
+            oldest_entry_at: entries.iter().map(|entry| entry.timestamp).min(),
+            newest_entry_at: entries.iter().map(|entry| entry.timestamp).max(),
+        })
+    }
+
     /// Get current head hash
     pub async fn get_head_hash(&self) -> String {
         self.head_hash.lock().await.clone()
@@ -162,24 +436,15 @@ impl AuditLogger {
         if !path.exists() {
             return Ok(vec![]);
         }
+        read_log_file(path)
+    }
 
-        let file = File::open(path).map_err(|e| anyhow!("Failed to open log file: {}", e))?;
-
-        let reader = BufReader::new(file);
+    /// Get all entries from the rotated log files and the current one, oldest first
+    pub async fn get_all_entries_including_rotated(&self) -> Result<Vec<AuditLogEntry>> {
         let mut entries = Vec::new();
-
-        for line in reader.lines() {
-            let line = line.map_err(|e| anyhow!("Failed to read log line: {}", e))?;
-            if line.trim().is_empty() {
-                continue;
-            }
-
-            let entry: AuditLogEntry = serde_json::from_str(&line)
-                .map_err(|e| anyhow!("Failed to parse log entry: {}", e))?;
-
-            entries.push(entry);
+        for path in audit_log_files(Path::new(&self.log_path))? {
+            entries.extend(read_log_file(&path)?);
         }
-
         Ok(entries)
     }
 
@@ -221,6 +486,27 @@ impl AuditLogger {
     }
 }
 
+fn read_log_file(path: &Path) -> Result<Vec<AuditLogEntry>> {
+    let file = File::open(path).map_err(|e| anyhow!("Failed to open log file: {}", e))?;
+
+    let reader = BufReader::new(file);
+    let mut entries = Vec::new();
+
+    for line in reader.lines() {
+        let line = line.map_err(|e| anyhow!("Failed to read log line: {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let entry: AuditLogEntry =
+            serde_json::from_str(&line).map_err(|e| anyhow!("Failed to parse log entry: {}", e))?;
+
+        entries.push(entry);
+    }
+
+    Ok(entries)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,4 +598,228 @@ mod tests {
             assert_eq!(entries[i].previous_log_hash, entries[i - 1].this_log_hash);
         }
     }
+
+    #[tokio::test]
+    async fn test_log_and_search_events() {
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir
+            .path()
+            .join("audit.log")
+            .to_string_lossy()
+            .to_string();
+
+        let logger = AuditLogger::new(log_path.clone()).unwrap();
+        logger.load_existing_entries().await.unwrap();
+
+        let mut old_event = AuditEvent::new(
+            AuditCategory::Authentication,
+            AuditSeverity::Warning,
+            "mallory",
+            "invalid_api_key",
+        );
+        old_event.timestamp = chrono::Utc::now() - chrono::Duration::days(2);
+        logger.log_event(old_event).await.unwrap();
+        logger
+            .log_event(
+                AuditEvent::new(
+                    AuditCategory::NodeRegistry,
+                    AuditSeverity::Info,
+                    "alice",
+                    "register_node",
+                )
+                .with_target("node-1"),
+            )
+            .await
+            .unwrap();
+        logger
+            .log_action(
+                AuditCategory::GovernanceAction,
+                "alice",
+                Some("42"),
+                "merge_pr",
+                serde_json::json!({ "repo": "blvm-consensus" }),
+            )
+            .await
+            .unwrap();
+
+        // Each event is a hash-chained JSON line
+        let entries = logger.get_all_entries().await.unwrap();
+        assert_eq!(entries.len(), 4); // Genesis + 3 events
+        assert!(entries.iter().all(|entry| entry.verify_hash()));
+        for pair in entries.windows(2) {
+            assert_eq!(pair[1].previous_log_hash, pair[0].this_log_hash);
+        }
+
+        let by_actor = logger
+            .search(AuditFilter {
+                actor: Some("alice".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(by_actor.len(), 2);
+        assert_eq!(by_actor[1].target.as_deref(), Some("42"));
+        assert_eq!(by_actor[1].severity, AuditSeverity::Info);
+
+        let by_category = logger
+            .search(AuditFilter {
+                category: Some(AuditCategory::NodeRegistry),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(by_category.len(), 1);
+        assert_eq!(by_category[0].action, "register_node");
+
+        let recent = logger
+            .search(AuditFilter {
+                since: Some(chrono::Utc::now() - chrono::Duration::days(1)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(recent.len(), 2);
+        assert!(recent
+            .iter()
+            .all(|event| event.category != AuditCategory::Authentication));
+    }
+
+    #[tokio::test]
+    async fn test_signed_entries_verify() {
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.log");
+
+        // One unsigned entry written before signing was enabled
+        let unsigned = AuditLogger::new(log_path.to_string_lossy().to_string()).unwrap();
+        unsigned.load_existing_entries().await.unwrap();
+
+        let keys = nostr_sdk::Keys::generate();
+        let logger = AuditLogger::new(log_path.to_string_lossy().to_string())
+            .unwrap()
+            .with_signing_keys(&keys)
+            .unwrap();
+        logger.load_existing_entries().await.unwrap();
+        for action in ["merge_pr", "register_node"] {
+            logger
+                .log_action(
+                    AuditCategory::GovernanceAction,
+                    "alice",
+                    None,
+                    action,
+                    serde_json::json!({ "pr": 42 }),
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(
+            logger.signing_pubkey().unwrap().serialize(),
+            keys.public_key().serialize()
+        );
+
+        let report = logger.verify_log_file(&log_path).unwrap();
+        assert_eq!(report.total_entries, 3);
+        assert_eq!(report.valid_signatures, 2);
+        assert_eq!(report.missing_signatures, 1);
+        assert_eq!(report.invalid_signatures, 0);
+
+        // Editing a signed line invalidates its signature
+        let content = std::fs::read_to_string(&log_path).unwrap();
+        std::fs::write(&log_path, content.replace("register_node", "remove_node")).unwrap();
+        let report = logger.verify_log_file(&log_path).unwrap();
+        assert_eq!(report.valid_signatures, 1);
+        assert_eq!(report.invalid_signatures, 1);
+        assert_eq!(report.invalid_entries.len(), 1);
+
+        // Without a key there is nothing to verify against
+        assert!(unsigned.verify_log_file(&log_path).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_size_based_rotation() {
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.log");
+        let logger = AuditLogger::new(log_path.to_string_lossy().to_string())
+            .unwrap()
+            .with_max_log_size_mb(1);
+
+        let padding = "x".repeat(64 * 1024);
+        for i in 0..20 {
+            logger
+                .log_action(
+                    AuditCategory::ConfigChange,
+                    "alice",
+                    None,
+                    &format!("update_{}", i),
+                    serde_json::json!({ "padding": padding }),
+                )
+                .await
+                .unwrap();
+        }
+
+        let rotated: Vec<_> = std::fs::read_dir(temp_dir.path())
+            .unwrap()
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .filter(|name| name.starts_with("governance_audit_") && name.ends_with(".jsonl"))
+            .collect();
+        assert_eq!(rotated.len(), 1);
+
+        // The new file picks up the chain where the rotated one left off
+        let stats = logger.get_log_stats().await.unwrap();
+        assert!(stats.current_file_size_bytes < 1024 * 1024);
+        assert!(stats.oldest_entry_at <= stats.newest_entry_at);
+        assert_eq!(stats.total_entries, 20); // Rotated entries still count
+
+        // Searches reach into the rotated file
+        let found = logger
+            .search(AuditFilter {
+                actor: Some("alice".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 20);
+        assert_eq!(found[0].action, "update_0");
+
+        let entries = logger.get_all_entries().await.unwrap();
+        assert_eq!(entries.len(), 4); // 16 x ~64 KiB reached 1 MiB
+        let rotated_log = std::fs::read_to_string(temp_dir.path().join(&rotated[0])).unwrap();
+        let last_rotated: AuditLogEntry =
+            serde_json::from_str(rotated_log.lines().last().unwrap()).unwrap();
+        assert_eq!(entries[0].previous_log_hash, last_rotated.this_log_hash);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_events_form_one_chain() {
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir.path().join("audit.log");
+        let logger = AuditLogger::new(log_path.to_string_lossy().to_string()).unwrap();
+        logger.load_existing_entries().await.unwrap();
+
+        let tasks: Vec<_> = (0..20)
+            .map(|i| {
+                let logger = logger.clone();
+                tokio::spawn(async move {
+                    logger
+                        .log_action(
+                            AuditCategory::GovernanceAction,
+                            "alice",
+                            None,
+                            &format!("action_{}", i),
+                            serde_json::json!({}),
+                        )
+                        .await
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+
+        let entries = logger.get_all_entries().await.unwrap();
+        assert_eq!(entries.len(), 21);
+        for pair in entries.windows(2) {
+            assert_eq!(pair[1].previous_log_hash, pair[0].this_log_hash);
+        }
+    }
 }
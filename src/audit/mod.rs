@@ -3,12 +3,20 @@
 //! Provides tamper-evident logging for all governance operations
 //! with cryptographic hash chains and Merkle tree anchoring.
 
+pub mod api;
 pub mod entry;
+pub mod event;
+pub mod index;
 pub mod logger;
 pub mod merkle;
 pub mod verify;
 
 pub use entry::AuditLogEntry;
-pub use logger::AuditLogger;
+pub use event::{AuditCategory, AuditEvent, AuditFilter, AuditSeverity};
+pub use index::{AuditEventRecord, AuditIndex, AuditQuery};
+pub use logger::{set_shared_logger, shared_logger, AuditLogStats, AuditLogger};
 pub use merkle::{build_merkle_tree, verify_merkle_root};
-pub use verify::{load_audit_log_from_file, verify_audit_log, verify_audit_log_file};
+pub use verify::{
+    load_audit_log_from_file, verify_audit_log, verify_audit_log_file, verify_entry_signature,
+    AuditVerificationReport,
+};
@@ -2,9 +2,41 @@
 //!
 //! Provides functions to verify the integrity of audit logs using cryptographic hashing
 
+use secp256k1::{schnorr, Message, Secp256k1, XOnlyPublicKey};
+use serde::Serialize;
+use std::str::FromStr;
+
 use crate::audit::entry::AuditLogEntry;
 use crate::error::GovernanceError;
 
+/// Signature check results for an audit log file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AuditVerificationReport {
+    pub total_entries: u64,
+    pub valid_signatures: u64,
+    pub invalid_signatures: u64,
+    pub missing_signatures: u64,
+    /// Job IDs of entries whose signature did not verify
+    pub invalid_entries: Vec<String>,
+}
+
+/// Check an entry's signature against the server's public key
+///
+/// Returns `None` for unsigned entries.
+pub fn verify_entry_signature(entry: &AuditLogEntry, pubkey: &XOnlyPublicKey) -> Option<bool> {
+    let signature = entry.signature.as_ref()?;
+    let valid = match (
+        schnorr::Signature::from_str(signature),
+        Message::from_digest_slice(&entry.signing_digest()),
+    ) {
+        (Ok(signature), Ok(message)) => Secp256k1::verification_only()
+            .verify_schnorr(&signature, &message, pubkey)
+            .is_ok(),
+        _ => false,
+    };
+    Some(valid)
+}
+
 /// Verify the integrity of an audit log entry
 pub fn verify_entry(entry: &AuditLogEntry) -> Result<bool, GovernanceError> {
     // Recalculate the hash
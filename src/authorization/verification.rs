@@ -27,6 +27,8 @@ mod ots_stub {
         pub authorized_servers: Vec<AuthorizedServer>,
         pub audit_logs: HashMap<String, AuditLogSummary>,
         pub multisig_config: MultisigConfig,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub node_registry_hash: Option<String>,
     }
 
     #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -470,6 +472,7 @@ mod tests {
                 required_signatures: 3,
                 total_maintainers: 5,
             },
+            node_registry_hash: None,
         }
     }
 
@@ -659,6 +662,7 @@ mod tests {
                 required_signatures: 3,
                 total_maintainers: 5,
             },
+            node_registry_hash: None,
         };
         let empty_stats = get_server_statistics(&empty_registry);
         assert_eq!(empty_stats.health_percentage(), 0.0);
@@ -4,11 +4,14 @@
 
 use crate::database::Database;
 use crate::error::GovernanceError;
+use crate::execution_mode::{record_suppressed, ExecutionMode, Subsystem};
+use crate::shutdown::{self, Shutdown};
 use chrono::Utc;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tokio::fs;
 use tokio::time::interval;
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 
 /// Backup configuration
@@ -42,16 +45,59 @@ impl Default for BackupConfig {
 pub struct BackupManager {
     database: Database,
     config: BackupConfig,
+    execution_mode: ExecutionMode,
+    shutdown: CancellationToken,
 }
 
 impl BackupManager {
     /// Create a new backup manager
     pub fn new(database: Database, config: BackupConfig) -> Self {
-        Self { database, config }
+        Self {
+            database,
+            config,
+            execution_mode: ExecutionMode::Live,
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    /// In dry-run mode no backup files are written or deleted
+    pub fn with_execution_mode(mut self, execution_mode: ExecutionMode) -> Self {
+        self.execution_mode = execution_mode;
+        self
+    }
+
+    /// Stop between backup phases once `token` is cancelled
+    pub fn with_shutdown(mut self, token: CancellationToken) -> Self {
+        self.shutdown = token;
+        self
     }
 
     /// Create a backup of the database
+    ///
+    /// In dry-run mode nothing is written and the path the backup would have
+    /// had is returned.
     pub async fn create_backup(&self) -> Result<PathBuf, GovernanceError> {
+        if self.execution_mode.is_dry_run() {
+            let backup_path = self.config.directory.join(format!(
+                "governance_backup_{}.db",
+                Utc::now().format("%Y%m%d_%H%M%S_%3f")
+            ));
+            record_suppressed(
+                Subsystem::Backup,
+                "create_backup",
+                Some(&backup_path.to_string_lossy()),
+                serde_json::json!({ "compression": self.config.compression }),
+            )
+            .await;
+            return Ok(backup_path);
+        }
+
+        if self.shutdown.is_cancelled() {
+            return Err(GovernanceError::ConfigError(
+                "Backup skipped: shutting down".to_string(),
+            ));
+        }
+
         // Ensure backup directory exists
         fs::create_dir_all(&self.config.directory)
             .await
@@ -84,14 +130,20 @@ impl BackupManager {
         // Verify backup
         self.verify_backup(&backup_path).await?;
 
-        // Compress if enabled
-        let final_path = if self.config.compression {
+        // Compress if enabled; a verified uncompressed backup is kept if
+        // shutdown began while it was being written
+        let final_path = if self.config.compression && !self.shutdown.is_cancelled() {
             self.compress_backup(&backup_path).await?
         } else {
             backup_path
         };
 
         info!("Backup created successfully: {}", final_path.display());
+        crate::metrics::set_gauge(
+            crate::metrics::BACKUP_LAST_SUCCESS,
+            &[],
+            Utc::now().timestamp() as f64,
+        );
 
         Ok(final_path)
     }
@@ -100,7 +152,7 @@ impl BackupManager {
     async fn backup_sqlite(&self, backup_path: &Path) -> Result<(), GovernanceError> {
         // SQLite backup using VACUUM INTO (SQLite 3.27+)
         // This creates a clean copy of the database
-        if let Some(pool) = self.database.get_sqlite_pool() {
+        if let Some(pool) = &self.database.get_sqlite_pool() {
             // Check if this is an in-memory database
             // VACUUM INTO doesn't work with :memory: databases, so we need a different approach
             // Try VACUUM INTO first, and if it fails, fall back to ATTACH method
@@ -392,6 +444,11 @@ impl BackupManager {
 
         let mut deleted_count = 0;
 
+        // A dry run never created the directory
+        if self.execution_mode.is_dry_run() && !self.config.directory.exists() {
+            return Ok(0);
+        }
+
         // List all backup files
         let mut entries = fs::read_dir(&self.config.directory).await.map_err(|e| {
             GovernanceError::ConfigError(format!("Failed to read backup directory: {}", e))
@@ -411,6 +468,17 @@ impl BackupManager {
                 if let Ok(modified) = metadata.modified() {
                     let modified_time: chrono::DateTime<Utc> = modified.into();
                     if modified_time < cutoff_time {
+                        if self.execution_mode.is_dry_run() {
+                            record_suppressed(
+                                Subsystem::Backup,
+                                "delete_old_backup",
+                                Some(&path.to_string_lossy()),
+                                serde_json::json!({ "modified": modified_time }),
+                            )
+                            .await;
+                            deleted_count += 1;
+                            continue;
+                        }
                         fs::remove_file(&path).await.map_err(|e| {
                             GovernanceError::ConfigError(format!(
                                 "Failed to delete old backup: {}",
@@ -431,9 +499,9 @@ impl BackupManager {
         Ok(deleted_count)
     }
 
-    /// Start periodic backup task
-    pub fn start_backup_task(self: Arc<Self>) {
-        tokio::spawn(async move {
+    /// Start periodic backup task; it stops after the current backup on shutdown
+    pub fn start_backup_task(self: Arc<Self>, shutdown: &Shutdown) {
+        shutdown.spawn("backup", |token| async move {
             if !self.config.enabled {
                 info!("Automated backups are disabled");
                 return;
@@ -445,11 +513,12 @@ impl BackupManager {
                 self.config.interval
             );
 
-            loop {
-                interval.tick().await;
-
+            while shutdown::next_tick(&token, &mut interval).await {
                 // Create backup
-                match self.create_backup().await {
+                let started = std::time::Instant::now();
+                let result = self.create_backup().await;
+                crate::metrics::record_task_run("backup", started.elapsed(), result.is_ok());
+                match result {
                     Ok(backup_path) => {
                         info!("Automated backup created: {}", backup_path.display());
                     }
@@ -458,6 +527,10 @@ impl BackupManager {
                     }
                 }
 
+                if token.is_cancelled() {
+                    break;
+                }
+
                 // Clean up old backups
                 if let Err(e) = self.cleanup_old_backups().await {
                     warn!("Failed to cleanup old backups: {}", e);
@@ -589,6 +662,31 @@ mod tests {
         assert!(subdir.is_dir());
     }
 
+    #[tokio::test]
+    async fn test_create_backup_dry_run() {
+        let db = Database::new_in_memory().await.unwrap();
+        let temp_dir = TempDir::new().unwrap();
+        let directory = temp_dir.path().join("backups");
+        let config = BackupConfig {
+            directory: directory.clone(),
+            compression: false,
+            ..BackupConfig::default()
+        };
+        let manager = BackupManager::new(db, config).with_execution_mode(ExecutionMode::DryRun);
+
+        let backup_path = manager.create_backup().await.unwrap();
+        assert!(!backup_path.exists());
+        assert!(
+            !directory.exists(),
+            "dry run must not create the backup directory"
+        );
+        assert_eq!(manager.cleanup_old_backups().await.unwrap(), 0);
+
+        let recorded = crate::execution_mode::suppressed_for(&backup_path.to_string_lossy());
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].action, "create_backup");
+    }
+
     #[tokio::test]
     async fn test_cleanup_old_backups_no_backups() {
         let (manager, _, _temp_dir) = setup_test_backup_manager().await;
@@ -693,7 +791,7 @@ mod tests {
 
         // Start task (should exit immediately if disabled)
         let manager_arc = Arc::new(manager);
-        manager_arc.clone().start_backup_task();
+        manager_arc.clone().start_backup_task(&Shutdown::new());
 
         // Give it a moment to start and check if disabled
         tokio::time::sleep(Duration::from_millis(100)).await;
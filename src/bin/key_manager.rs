@@ -114,7 +114,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         encryption_enabled: false,
         rotation_policies: vec![],
     };
-    let key_manager = KeyManager::new(db.pool().unwrap().clone(), config);
+    let key_manager = KeyManager::new(db.pool().unwrap(), config);
 
     // Execute command
     match cli.command {
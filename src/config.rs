@@ -1,14 +1,28 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::validation::pr_title::PrTitleRules;
 
 pub mod loader;
 
+/// Webhook secret shipped in example configs; never accepted as a real secret
+pub const PLACEHOLDER_WEBHOOK_SECRET: &str = "your_webhook_secret_here";
+
+/// PR title rules read so far, by file path
+static PR_TITLE_RULES: OnceLock<Mutex<HashMap<String, Arc<PrTitleRules>>>> = OnceLock::new();
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub database_url: String,
     pub github_app_id: u64,
     pub github_private_key_path: String,
     pub github_webhook_secret: String,
+    /// Maintainer governance public key -> GitHub username, used to request reviews
+    #[serde(default)]
+    pub maintainer_github_handles: std::collections::HashMap<String, String>,
     pub governance_repo: String,
     pub server_host: String,
     pub server_port: u16,
@@ -20,6 +34,27 @@ pub struct AppConfig {
     pub ots: OtsConfig,
     pub audit: AuditConfig,
     pub governance: GovernanceConfig,
+    #[serde(default)]
+    pub bitcoin_rpc: Option<BitcoinRpcConfig>,
+    #[serde(default)]
+    pub esplora: Option<EsploraConfig>,
+    #[serde(default)]
+    pub lightning_node: Option<LightningNodeConfig>,
+    #[serde(default)]
+    pub btc_price: Option<BtcPriceConfig>,
+    /// Shared secret for /internal endpoints; they are disabled when unset
+    #[serde(default)]
+    pub internal_api_key: Option<String>,
+    #[serde(default)]
+    pub governance_review: GovernanceReviewConfig,
+    #[serde(default)]
+    pub database: DatabaseConfig,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
+    #[serde(default)]
+    pub webhooks: WebhookConfig,
+    #[serde(default)]
+    pub node_registry: NodeRegistryConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +68,21 @@ pub struct NostrConfig {
     pub logo_url: Option<String>,    // URL to Bitcoin Commons logo
     #[serde(default)]
     pub bots: std::collections::HashMap<String, BotConfig>, // Multi-bot support
+    /// Relays that must confirm a status event (default: 1)
+    #[serde(default = "default_publish_min_quorum")]
+    pub publish_min_quorum: usize,
+    /// Hours between zap reconciliation passes; 0 disables them (default: 24)
+    #[serde(default = "default_zap_reconcile_interval_hours")]
+    pub zap_reconcile_interval_hours: u64,
+    /// How far back each zap reconciliation pass re-queries relays (default: 48 hours)
+    #[serde(default = "default_zap_reconcile_window_hours")]
+    pub zap_reconcile_window_hours: u64,
+    /// Consecutive publish failures before a relay's circuit opens (default: 5)
+    #[serde(default = "default_relay_failure_threshold")]
+    pub relay_failure_threshold: u32,
+    /// Seconds an open relay circuit waits before a trial publish (default: 300)
+    #[serde(default = "default_relay_reset_timeout_seconds")]
+    pub relay_reset_timeout_seconds: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,6 +107,9 @@ pub struct OtsConfig {
     pub monthly_anchor_day: u8,
     pub registry_path: String,
     pub proofs_path: String,
+    /// nsec file signing the node registry export (default: the server's Nostr key)
+    #[serde(default)]
+    pub registry_signing_key_path: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,6 +117,145 @@ pub struct AuditConfig {
     pub enabled: bool,
     pub log_path: String,
     pub rotation_interval_days: u32,
+    /// Rotate the log once it grows past this size (0 disables size-based rotation)
+    #[serde(default = "default_audit_max_log_size_mb")]
+    pub max_log_size_mb: u64,
+}
+
+fn default_audit_max_log_size_mb() -> u64 {
+    100
+}
+
+/// Prometheus scrape endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    /// Bearer token required by `GET /metrics`; the endpoint is open when unset
+    #[serde(default)]
+    pub auth_token: Option<String>,
+}
+
+/// GitHub webhook delivery handling
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    /// Hours a delivery ID is remembered for replay detection (default: 72)
+    #[serde(default = "default_dedup_retention_hours")]
+    pub dedup_retention_hours: u64,
+    /// Recent delivery IDs kept in memory in front of the database
+    /// (default: 10000)
+    #[serde(default = "default_dedup_cache_capacity")]
+    pub dedup_cache_capacity: usize,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            dedup_retention_hours: default_dedup_retention_hours(),
+            dedup_cache_capacity: default_dedup_cache_capacity(),
+        }
+    }
+}
+
+/// Node registry maintenance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeRegistryConfig {
+    /// Hours a registration may stay pending verification, unseen, before
+    /// the admin purge removes it (default: 168)
+    #[serde(default = "default_pending_max_age_hours")]
+    pub pending_max_age_hours: u64,
+    /// Days a node API token stays valid; 0 means tokens never expire (default: 90)
+    #[serde(default = "default_api_token_ttl_days")]
+    pub api_token_ttl_days: u64,
+}
+
+impl Default for NodeRegistryConfig {
+    fn default() -> Self {
+        Self {
+            pending_max_age_hours: default_pending_max_age_hours(),
+            api_token_ttl_days: default_api_token_ttl_days(),
+        }
+    }
+}
+
+impl NodeRegistryConfig {
+    /// Lifetime of newly issued node API tokens, if they expire
+    pub fn api_token_ttl(&self) -> Option<chrono::Duration> {
+        (self.api_token_ttl_days > 0)
+            .then(|| chrono::Duration::days(self.api_token_ttl_days as i64))
+    }
+}
+
+/// SQLite tuning
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseConfig {
+    /// WAL pages written before SQLite checkpoints automatically (default: 1000)
+    #[serde(default = "default_wal_autocheckpoint_pages")]
+    pub wal_autocheckpoint_pages: u32,
+    /// Minutes between forced WAL checkpoints; 0 disables them (default: 15)
+    #[serde(default = "default_wal_checkpoint_interval_minutes")]
+    pub wal_checkpoint_interval_minutes: u64,
+    /// SQLite replica that serves read-only dashboard and registry queries
+    #[serde(default)]
+    pub read_replica_url: Option<String>,
+    /// Pages the replica may fall behind the primary before a warning is logged
+    /// (default: 1000)
+    #[serde(default = "default_replica_lag_warn_pages")]
+    pub replica_lag_warn_pages: i64,
+    /// Queries taking at least this many milliseconds are logged as slow
+    /// (default: 100)
+    #[serde(default = "default_slow_query_threshold_ms")]
+    pub slow_query_threshold_ms: u64,
+    /// Failed reconnection attempts in a row before the server exits; 0 keeps
+    /// retrying forever (default: 0)
+    #[serde(default)]
+    pub max_reconnect_attempts: u32,
+}
+
+impl Default for DatabaseConfig {
+    fn default() -> Self {
+        Self {
+            wal_autocheckpoint_pages: default_wal_autocheckpoint_pages(),
+            wal_checkpoint_interval_minutes: default_wal_checkpoint_interval_minutes(),
+            read_replica_url: None,
+            replica_lag_warn_pages: default_replica_lag_warn_pages(),
+            slow_query_threshold_ms: default_slow_query_threshold_ms(),
+            max_reconnect_attempts: 0,
+        }
+    }
+}
+
+/// Governance review settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GovernanceReviewConfig {
+    /// Maintainer GitHub username -> npub that receives deadline reminders as Nostr DMs
+    #[serde(default)]
+    pub maintainer_npubs: std::collections::HashMap<String, String>,
+    /// Public case record fields shown as "[redacted]" (default: reporter,
+    /// description, evidence, responses)
+    #[serde(default = "default_public_record_redact_fields")]
+    pub public_record_redact_fields: Vec<String>,
+    /// Days after a case closes during which the subject may appeal
+    #[serde(default = "default_appeal_window_days")]
+    pub appeal_window_days: i64,
+    /// Days before a deadline that reminders are posted to the case issue and Nostr
+    /// (default: 14, 7, 1)
+    #[serde(default = "default_reminder_lead_days")]
+    pub reminder_lead_days: Vec<u32>,
+    /// Escrow maintainers who must request an anonymous reporter's identity
+    /// before it is revealed (default: 3)
+    #[serde(default = "default_anonymous_escrow_threshold")]
+    pub anonymous_escrow_threshold: i32,
+}
+
+impl Default for GovernanceReviewConfig {
+    fn default() -> Self {
+        Self {
+            maintainer_npubs: std::collections::HashMap::new(),
+            public_record_redact_fields: default_public_record_redact_fields(),
+            appeal_window_days: default_appeal_window_days(),
+            reminder_lead_days: default_reminder_lead_days(),
+            anonymous_escrow_threshold: default_anonymous_escrow_threshold(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +280,203 @@ pub struct GovernanceConfig {
     /// Weight update interval (seconds, default: 86400 = daily)
     #[serde(default = "default_weight_update_interval")]
     pub weight_update_interval_secs: u64,
+
+    /// Half-life for decaying the weight of nodes that stop re-verifying
+    /// (days, default: 0 = use the governance weight calculation config's
+    /// `decay_half_life_days`)
+    #[serde(default)]
+    pub node_weight_decay_half_life_days: f64,
+
+    /// Governance phase evaluation interval (seconds, default: 3600 = hourly)
+    #[serde(default = "default_phase_evaluation_interval")]
+    pub phase_evaluation_interval_secs: u64,
+
+    /// Consecutive evaluations a new phase must hold before it is recorded
+    /// (default: 3)
+    #[serde(default = "default_phase_hysteresis_evaluations")]
+    pub phase_hysteresis_evaluations: u32,
+
+    /// Alternatively, days a new phase must hold before it is recorded
+    /// (default: 0 = evaluations only)
+    #[serde(default)]
+    pub phase_hysteresis_days: f64,
+    /// Per-tier delay between approving and activating a governance change
+    #[serde(default)]
+    pub time_lock: crate::governance::time_lock::TimeLockConfig,
+    /// YAML file overriding the governance PR title rules (default: built-in rules)
+    #[serde(default)]
+    pub pr_title_rules_path: Option<String>,
+    /// Restart a PR's review period when a push changes its diff substantially
+    /// (default: false)
+    #[serde(default)]
+    pub reset_review_on_major_revision: bool,
+    /// Multiple of a contributor's 90-day average at which a contribution is
+    /// flagged for review (default: 5)
+    #[serde(default = "default_anomaly_threshold")]
+    pub contribution_anomaly_threshold: f64,
+    /// Growth in a contributor's total beyond which a pending anomaly holds
+    /// back their weight update (default: 2)
+    #[serde(default = "default_anomaly_weight_multiplier")]
+    pub contribution_anomaly_weight_multiplier: f64,
+    /// Trusted block the chain tip tracker starts from; block notifications
+    /// must connect to it (default: the network's genesis block)
+    #[serde(default)]
+    pub chain_checkpoint_hash: Option<String>,
+    /// Height of `chain_checkpoint_hash` (default: 0)
+    #[serde(default)]
+    pub chain_checkpoint_height: i64,
+}
+
+/// Bitcoin Core JSON-RPC connection settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitcoinRpcConfig {
+    pub url: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    /// Request timeout (seconds, default: 30)
+    #[serde(default = "default_rpc_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_rpc_timeout() -> u64 {
+    30
+}
+
+/// Esplora HTTP API settings (used when no Bitcoin Core RPC is configured)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EsploraConfig {
+    /// Base URL, e.g. "https://blockstream.info/api"
+    pub url: String,
+    /// Request timeout (seconds, default: 30)
+    #[serde(default = "default_rpc_timeout")]
+    pub timeout_secs: u64,
+}
+
+/// Lightning node REST API flavour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LightningBackend {
+    Lnd,
+    Cln,
+}
+
+/// Lightning node used to verify zap payments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LightningNodeConfig {
+    pub backend: LightningBackend,
+    /// REST base URL, e.g. "https://localhost:8080"
+    pub url: String,
+    /// Hex macaroon (LND) or rune (CLN)
+    #[serde(default)]
+    pub auth_token: Option<String>,
+    /// Request timeout (seconds, default: 30)
+    #[serde(default = "default_rpc_timeout")]
+    pub timeout_secs: u64,
+}
+
+/// HTTP endpoint returning a BTC/USD price in JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceSource {
+    pub url: String,
+    /// JSON pointer to the price, e.g. "/bitcoin/usd"
+    pub json_pointer: String,
+}
+
+/// BTC price feed used to convert USD-denominated amounts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BtcPriceConfig {
+    /// Sources queried on each refresh; the median of the responses is recorded
+    pub sources: Vec<PriceSource>,
+    /// Moving average window (days, default: 30)
+    #[serde(default = "default_price_window_days")]
+    pub moving_average_days: u32,
+    /// Refresh interval (seconds, default: 3600)
+    #[serde(default = "default_price_refresh_interval")]
+    pub refresh_interval_secs: u64,
+    /// Request timeout (seconds, default: 30)
+    #[serde(default = "default_rpc_timeout")]
+    pub timeout_secs: u64,
+}
+
+fn default_price_window_days() -> u32 {
+    30
+}
+
+fn default_price_refresh_interval() -> u64 {
+    3600
+}
+
+fn default_publish_min_quorum() -> usize {
+    1
+}
+
+fn default_zap_reconcile_interval_hours() -> u64 {
+    24 // Daily
+}
+
+fn default_zap_reconcile_window_hours() -> u64 {
+    48
+}
+
+fn default_relay_failure_threshold() -> u32 {
+    5
+}
+
+fn default_relay_reset_timeout_seconds() -> u64 {
+    300
+}
+
+fn default_public_record_redact_fields() -> Vec<String> {
+    crate::governance_review::models::policy::PUBLIC_RECORD_REDACT_FIELDS
+        .iter()
+        .map(|field| field.to_string())
+        .collect()
+}
+
+fn default_appeal_window_days() -> i64 {
+    crate::governance_review::models::policy::APPEAL_DEADLINE_DAYS
+}
+
+fn default_reminder_lead_days() -> Vec<u32> {
+    crate::governance_review::deadline_notifications::DEFAULT_REMINDER_LEAD_DAYS.to_vec()
+}
+
+fn default_anonymous_escrow_threshold() -> i32 {
+    crate::governance_review::models::policy::ANONYMOUS_ESCROW_THRESHOLD
+}
+
+fn default_wal_autocheckpoint_pages() -> u32 {
+    1000
+}
+
+fn default_wal_checkpoint_interval_minutes() -> u64 {
+    15
+}
+
+fn default_replica_lag_warn_pages() -> i64 {
+    1000
+}
+
+fn default_slow_query_threshold_ms() -> u64 {
+    crate::database::slow_query::DEFAULT_SLOW_QUERY_THRESHOLD_MS
+}
+
+fn default_dedup_retention_hours() -> u64 {
+    crate::webhooks::dedup::DEFAULT_RETENTION_HOURS
+}
+
+fn default_dedup_cache_capacity() -> usize {
+    crate::webhooks::dedup::DEFAULT_CACHE_CAPACITY
+}
+
+fn default_pending_max_age_hours() -> u64 {
+    168
+}
+
+fn default_api_token_ttl_days() -> u64 {
+    90
 }
 
 fn default_true() -> bool {
@@ -98,10 +487,76 @@ fn default_weight_update_interval() -> u64 {
     86400 // Daily
 }
 
+fn default_anomaly_threshold() -> f64 {
+    crate::governance::DEFAULT_ANOMALY_THRESHOLD
+}
+
+fn default_anomaly_weight_multiplier() -> f64 {
+    crate::governance::DEFAULT_ANOMALY_WEIGHT_MULTIPLIER
+}
+
+fn default_phase_evaluation_interval() -> u64 {
+    3600 // Hourly
+}
+
+fn default_phase_hysteresis_evaluations() -> u32 {
+    3
+}
+
 fn default_network() -> String {
     "mainnet".to_string()
 }
 
+impl GovernanceConfig {
+    /// `network` as a `bitcoin::Network`; unrecognized names fall back to mainnet
+    pub fn bitcoin_network(&self) -> bitcoin::Network {
+        crate::validation::bitcoin_address::parse_network(&self.network).unwrap_or_else(|| {
+            tracing::warn!(
+                "Unknown governance network {:?}, validating addresses for mainnet",
+                self.network
+            );
+            bitcoin::Network::Bitcoin
+        })
+    }
+
+    /// Trusted (block hash, height) for the chain tip tracker
+    pub fn chain_checkpoint(&self) -> (String, i64) {
+        match &self.chain_checkpoint_hash {
+            Some(block_hash) => (block_hash.clone(), self.chain_checkpoint_height),
+            None => (
+                bitcoin::blockdata::constants::genesis_block(self.bitcoin_network())
+                    .block_hash()
+                    .to_string(),
+                0,
+            ),
+        }
+    }
+
+    /// PR title rules from `pr_title_rules_path`; the built-in rules if unset or unreadable
+    ///
+    /// The file is read on first use and cached for the life of the process;
+    /// the server calls this at startup so webhooks never touch the file.
+    pub fn pr_title_rules(&self) -> Arc<PrTitleRules> {
+        let path = match &self.pr_title_rules_path {
+            Some(path) => path,
+            None => return Arc::new(PrTitleRules::default()),
+        };
+        let mut cache = PR_TITLE_RULES
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        cache
+            .entry(path.clone())
+            .or_insert_with(|| {
+                Arc::new(PrTitleRules::load(path).unwrap_or_else(|e| {
+                    tracing::warn!("{}; using the default PR title rules", e);
+                    PrTitleRules::default()
+                }))
+            })
+            .clone()
+    }
+}
+
 impl Default for GovernanceConfig {
     fn default() -> Self {
         Self {
@@ -110,6 +565,17 @@ impl Default for GovernanceConfig {
             contribution_tracking_enabled: true,
             weight_updates_enabled: true,
             weight_update_interval_secs: 86400,
+            node_weight_decay_half_life_days: 0.0,
+            phase_evaluation_interval_secs: 3600,
+            phase_hysteresis_evaluations: 3,
+            phase_hysteresis_days: 0.0,
+            time_lock: crate::governance::time_lock::TimeLockConfig::default(),
+            pr_title_rules_path: None,
+            reset_review_on_major_revision: false,
+            contribution_anomaly_threshold: default_anomaly_threshold(),
+            contribution_anomaly_weight_multiplier: default_anomaly_weight_multiplier(),
+            chain_checkpoint_hash: None,
+            chain_checkpoint_height: 0,
         }
     }
 }
@@ -126,8 +592,22 @@ impl AppConfig {
         let github_private_key_path = env::var("GITHUB_PRIVATE_KEY_PATH")
             .unwrap_or_else(|_| "/path/to/private-key.pem".to_string());
 
-        let github_webhook_secret = env::var("GITHUB_WEBHOOK_SECRET")
-            .unwrap_or_else(|_| "your_webhook_secret_here".to_string());
+        let github_webhook_secret = env::var("GITHUB_WEBHOOK_SECRET").unwrap_or_default();
+        if github_webhook_secret.is_empty() || github_webhook_secret == PLACEHOLDER_WEBHOOK_SECRET {
+            return Err(
+                "GITHUB_WEBHOOK_SECRET must be set to the GitHub App's webhook secret".into(),
+            );
+        }
+
+        // GITHUB_MAINTAINER_HANDLES: comma-separated "public_key=github_username" entries
+        let maintainer_github_handles = env::var("GITHUB_MAINTAINER_HANDLES")
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|entry| entry.trim().split_once('='))
+            .map(|(public_key, username)| {
+                (public_key.trim().to_string(), username.trim().to_string())
+            })
+            .collect();
 
         let governance_repo =
             env::var("GOVERNANCE_REPO").unwrap_or_else(|_| "BTCDecoded/governance".to_string());
@@ -152,6 +632,10 @@ impl AppConfig {
 
         let server_id = env::var("SERVER_ID").unwrap_or_else(|_| "governance-01".to_string());
 
+        let internal_api_key = env::var("INTERNAL_API_KEY")
+            .ok()
+            .filter(|key| !key.is_empty());
+
         let nostr_enabled = env::var("NOSTR_ENABLED")
             .unwrap_or_else(|_| "false".to_string())
             .parse()
@@ -171,6 +655,29 @@ impl AppConfig {
             .parse()
             .unwrap_or(3600);
 
+        let nostr_publish_min_quorum = env::var("NOSTR_PUBLISH_MIN_QUORUM")
+            .unwrap_or_else(|_| "1".to_string())
+            .parse()
+            .unwrap_or(1);
+
+        let nostr_zap_reconcile_interval_hours = env::var("NOSTR_ZAP_RECONCILE_INTERVAL_HOURS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(default_zap_reconcile_interval_hours);
+
+        let nostr_zap_reconcile_window_hours = env::var("NOSTR_ZAP_RECONCILE_WINDOW_HOURS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(default_zap_reconcile_window_hours);
+        let nostr_relay_failure_threshold = env::var("NOSTR_RELAY_FAILURE_THRESHOLD")
+            .ok()
+            .and_then(|threshold| threshold.parse().ok())
+            .unwrap_or_else(default_relay_failure_threshold);
+        let nostr_relay_reset_timeout_seconds = env::var("NOSTR_RELAY_RESET_TIMEOUT_SECONDS")
+            .ok()
+            .and_then(|seconds| seconds.parse().ok())
+            .unwrap_or_else(default_relay_reset_timeout_seconds);
+
         let governance_config =
             env::var("GOVERNANCE_CONFIG").unwrap_or_else(|_| "commons_mainnet".to_string());
 
@@ -214,11 +721,140 @@ impl AppConfig {
             .parse()
             .unwrap_or(30);
 
+        let audit_max_log_size_mb = env::var("AUDIT_MAX_LOG_SIZE_MB")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_else(default_audit_max_log_size_mb);
+
+        let bitcoin_rpc = env::var("BITCOIN_RPC_URL")
+            .ok()
+            .map(|url| BitcoinRpcConfig {
+                url,
+                username: env::var("BITCOIN_RPC_USER").ok(),
+                password: env::var("BITCOIN_RPC_PASSWORD").ok(),
+                timeout_secs: env::var("BITCOIN_RPC_TIMEOUT_SECS")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .unwrap_or(30),
+            });
+
+        let esplora = env::var("ESPLORA_URL").ok().map(|url| EsploraConfig {
+            url,
+            timeout_secs: env::var("ESPLORA_TIMEOUT_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .unwrap_or(30),
+        });
+
+        let lightning_node = env::var("LIGHTNING_NODE_URL")
+            .ok()
+            .map(|url| LightningNodeConfig {
+                backend: match env::var("LIGHTNING_NODE_BACKEND")
+                    .unwrap_or_else(|_| "lnd".to_string())
+                    .to_lowercase()
+                    .as_str()
+                {
+                    "cln" => LightningBackend::Cln,
+                    _ => LightningBackend::Lnd,
+                },
+                url,
+                auth_token: env::var("LIGHTNING_NODE_AUTH_TOKEN").ok(),
+                timeout_secs: env::var("LIGHTNING_NODE_TIMEOUT_SECS")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .unwrap_or(30),
+            });
+
+        // BTC_PRICE_SOURCES: comma-separated "url|json_pointer" entries
+        let btc_price = env::var("BTC_PRICE_SOURCES")
+            .ok()
+            .map(|sources| BtcPriceConfig {
+                sources: sources
+                    .split(',')
+                    .filter_map(|entry| entry.trim().split_once('|'))
+                    .map(|(url, json_pointer)| PriceSource {
+                        url: url.to_string(),
+                        json_pointer: json_pointer.to_string(),
+                    })
+                    .collect(),
+                moving_average_days: env::var("BTC_PRICE_MA_DAYS")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .unwrap_or(30),
+                refresh_interval_secs: env::var("BTC_PRICE_REFRESH_INTERVAL_SECS")
+                    .unwrap_or_else(|_| "3600".to_string())
+                    .parse()
+                    .unwrap_or(3600),
+                timeout_secs: env::var("BTC_PRICE_TIMEOUT_SECS")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .unwrap_or(30),
+            });
+
+        // GOVERNANCE_REVIEW_MAINTAINER_NPUBS: comma-separated "github_username=npub" entries
+        let maintainer_npubs = env::var("GOVERNANCE_REVIEW_MAINTAINER_NPUBS")
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|entry| entry.trim().split_once('='))
+            .map(|(username, npub)| (username.trim().to_string(), npub.trim().to_string()))
+            .collect();
+
+        // GOVERNANCE_REVIEW_PUBLIC_REDACT_FIELDS: comma-separated public record field names
+        let public_record_redact_fields = env::var("GOVERNANCE_REVIEW_PUBLIC_REDACT_FIELDS")
+            .map(|fields| {
+                fields
+                    .split(',')
+                    .map(|field| field.trim().to_string())
+                    .filter(|field| !field.is_empty())
+                    .collect()
+            })
+            .unwrap_or_else(|_| default_public_record_redact_fields());
+        let appeal_window_days = env::var("GOVERNANCE_REVIEW_APPEAL_WINDOW_DAYS")
+            .ok()
+            .and_then(|days| days.parse().ok())
+            .unwrap_or_else(default_appeal_window_days);
+        // GOVERNANCE_REVIEW_REMINDER_LEAD_DAYS: comma-separated days, e.g. "14,7,1"
+        let reminder_lead_days = env::var("GOVERNANCE_REVIEW_REMINDER_LEAD_DAYS")
+            .map(|days| {
+                days.split(',')
+                    .filter_map(|day| day.trim().parse().ok())
+                    .collect()
+            })
+            .unwrap_or_else(|_| default_reminder_lead_days());
+        let anonymous_escrow_threshold = env::var("GOVERNANCE_REVIEW_ANONYMOUS_ESCROW_THRESHOLD")
+            .ok()
+            .and_then(|threshold| threshold.parse().ok())
+            .unwrap_or_else(default_anonymous_escrow_threshold);
+        let database = DatabaseConfig {
+            wal_autocheckpoint_pages: env::var("DATABASE_WAL_AUTOCHECKPOINT_PAGES")
+                .ok()
+                .and_then(|pages| pages.parse().ok())
+                .unwrap_or_else(default_wal_autocheckpoint_pages),
+            wal_checkpoint_interval_minutes: env::var("DATABASE_WAL_CHECKPOINT_INTERVAL_MINUTES")
+                .ok()
+                .and_then(|minutes| minutes.parse().ok())
+                .unwrap_or_else(default_wal_checkpoint_interval_minutes),
+            read_replica_url: env::var("DATABASE_READ_REPLICA_URL").ok(),
+            replica_lag_warn_pages: env::var("DATABASE_REPLICA_LAG_WARN_PAGES")
+                .ok()
+                .and_then(|pages| pages.parse().ok())
+                .unwrap_or_else(default_replica_lag_warn_pages),
+            slow_query_threshold_ms: env::var("DATABASE_SLOW_QUERY_THRESHOLD_MS")
+                .ok()
+                .and_then(|ms| ms.parse().ok())
+                .unwrap_or_else(default_slow_query_threshold_ms),
+            max_reconnect_attempts: env::var("DATABASE_MAX_RECONNECT_ATTEMPTS")
+                .ok()
+                .and_then(|attempts| attempts.parse().ok())
+                .unwrap_or(0),
+        };
+
         Ok(AppConfig {
             database_url,
             github_app_id,
             github_private_key_path,
             github_webhook_secret,
+            maintainer_github_handles,
             governance_repo,
             server_host,
             server_port,
@@ -235,6 +871,11 @@ impl AppConfig {
                 zap_address,
                 logo_url,
                 bots: std::collections::HashMap::new(), // Loaded from config file or env vars
+                publish_min_quorum: nostr_publish_min_quorum,
+                zap_reconcile_interval_hours: nostr_zap_reconcile_interval_hours,
+                zap_reconcile_window_hours: nostr_zap_reconcile_window_hours,
+                relay_failure_threshold: nostr_relay_failure_threshold,
+                relay_reset_timeout_seconds: nostr_relay_reset_timeout_seconds,
             },
             ots: OtsConfig {
                 enabled: ots_enabled,
@@ -242,11 +883,13 @@ impl AppConfig {
                 monthly_anchor_day: ots_monthly_anchor_day,
                 registry_path: ots_registry_path,
                 proofs_path: ots_proofs_path,
+                registry_signing_key_path: env::var("OTS_REGISTRY_SIGNING_KEY_PATH").ok(),
             },
             audit: AuditConfig {
                 enabled: audit_enabled,
                 log_path: audit_log_path,
                 rotation_interval_days: audit_rotation_interval,
+                max_log_size_mb: audit_max_log_size_mb,
             },
             governance: {
                 let commons_addresses = env::var("GOVERNANCE_COMMONS_ADDRESSES")
@@ -276,10 +919,110 @@ impl AppConfig {
                         .unwrap_or_else(|_| "86400".to_string())
                         .parse()
                         .unwrap_or(86400),
+                    node_weight_decay_half_life_days: env::var(
+                        "GOVERNANCE_NODE_WEIGHT_DECAY_HALF_LIFE_DAYS",
+                    )
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .unwrap_or(0.0),
+                    phase_evaluation_interval_secs: env::var(
+                        "GOVERNANCE_PHASE_EVALUATION_INTERVAL_SECS",
+                    )
+                    .unwrap_or_else(|_| "3600".to_string())
+                    .parse()
+                    .unwrap_or(3600),
+                    phase_hysteresis_evaluations: env::var(
+                        "GOVERNANCE_PHASE_HYSTERESIS_EVALUATIONS",
+                    )
+                    .unwrap_or_else(|_| "3".to_string())
+                    .parse()
+                    .unwrap_or(3),
+                    phase_hysteresis_days: env::var("GOVERNANCE_PHASE_HYSTERESIS_DAYS")
+                        .unwrap_or_else(|_| "0".to_string())
+                        .parse()
+                        .unwrap_or(0.0),
+                    time_lock: crate::governance::time_lock::TimeLockConfig {
+                        auto_activate: env::var("GOVERNANCE_TIME_LOCK_AUTO_ACTIVATE")
+                            .unwrap_or_else(|_| "false".to_string())
+                            .parse()
+                            .unwrap_or(false),
+                        ..Default::default()
+                    },
+                    pr_title_rules_path: env::var("GOVERNANCE_PR_TITLE_RULES_PATH").ok(),
+                    reset_review_on_major_revision: env::var(
+                        "GOVERNANCE_RESET_REVIEW_ON_MAJOR_REVISION",
+                    )
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .unwrap_or(false),
+                    contribution_anomaly_threshold: env::var(
+                        "GOVERNANCE_CONTRIBUTION_ANOMALY_THRESHOLD",
+                    )
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .unwrap_or(5.0),
+                    contribution_anomaly_weight_multiplier: env::var(
+                        "GOVERNANCE_CONTRIBUTION_ANOMALY_WEIGHT_MULTIPLIER",
+                    )
+                    .unwrap_or_else(|_| "2".to_string())
+                    .parse()
+                    .unwrap_or(2.0),
+                    chain_checkpoint_hash: env::var("GOVERNANCE_CHAIN_CHECKPOINT_HASH").ok(),
+                    chain_checkpoint_height: env::var("GOVERNANCE_CHAIN_CHECKPOINT_HEIGHT")
+                        .unwrap_or_else(|_| "0".to_string())
+                        .parse()
+                        .unwrap_or(0),
                 }
             },
+            bitcoin_rpc,
+            esplora,
+            lightning_node,
+            btc_price,
+            internal_api_key,
+            governance_review: GovernanceReviewConfig {
+                maintainer_npubs,
+                public_record_redact_fields,
+                appeal_window_days,
+                reminder_lead_days,
+                anonymous_escrow_threshold,
+            },
+            database,
+            metrics: MetricsConfig {
+                auth_token: env::var("METRICS_AUTH_TOKEN").ok(),
+            },
+            webhooks: WebhookConfig {
+                dedup_retention_hours: env::var("WEBHOOK_DEDUP_RETENTION_HOURS")
+                    .ok()
+                    .and_then(|hours| hours.parse().ok())
+                    .unwrap_or_else(default_dedup_retention_hours),
+                dedup_cache_capacity: env::var("WEBHOOK_DEDUP_CACHE_CAPACITY")
+                    .ok()
+                    .and_then(|capacity| capacity.parse().ok())
+                    .unwrap_or_else(default_dedup_cache_capacity),
+            },
+            node_registry: NodeRegistryConfig {
+                pending_max_age_hours: env::var("NODE_REGISTRY_PENDING_MAX_AGE_HOURS")
+                    .ok()
+                    .and_then(|hours| hours.parse().ok())
+                    .unwrap_or_else(default_pending_max_age_hours),
+                api_token_ttl_days: env::var("NODE_REGISTRY_API_TOKEN_TTL_DAYS")
+                    .ok()
+                    .and_then(|days| days.parse().ok())
+                    .unwrap_or_else(default_api_token_ttl_days),
+            },
         })
     }
+
+    /// The GitHub webhook secret, unless it is unset or still the placeholder
+    pub fn webhook_secret(&self) -> Option<&str> {
+        let secret = self.github_webhook_secret.as_str();
+        (!secret.is_empty() && secret != PLACEHOLDER_WEBHOOK_SECRET).then_some(secret)
+    }
+
+    /// Execution mode for side-effecting subsystems, from `dry_run_mode`
+    pub fn execution_mode(&self) -> crate::execution_mode::ExecutionMode {
+        crate::execution_mode::ExecutionMode::from_dry_run(self.dry_run_mode)
+    }
 }
 
 impl Default for AppConfig {
@@ -288,7 +1031,8 @@ impl Default for AppConfig {
             database_url: "sqlite://governance.db".to_string(),
             github_app_id: 0,
             github_private_key_path: "/path/to/private-key.pem".to_string(),
-            github_webhook_secret: "your_webhook_secret_here".to_string(),
+            github_webhook_secret: String::new(),
+            maintainer_github_handles: std::collections::HashMap::new(),
             governance_repo: "BTCDecoded/governance".to_string(),
             server_host: "0.0.0.0".to_string(),
             server_port: 3000,
@@ -300,6 +1044,16 @@ impl Default for AppConfig {
             ots: OtsConfig::default(),
             audit: AuditConfig::default(),
             governance: GovernanceConfig::default(),
+            bitcoin_rpc: None,
+            esplora: None,
+            lightning_node: None,
+            btc_price: None,
+            internal_api_key: None,
+            governance_review: GovernanceReviewConfig::default(),
+            database: DatabaseConfig::default(),
+            metrics: MetricsConfig::default(),
+            webhooks: WebhookConfig::default(),
+            node_registry: NodeRegistryConfig::default(),
         }
     }
 }
@@ -318,6 +1072,11 @@ impl Default for NostrConfig {
             zap_address: None,
             logo_url: Some("https://btcdecoded.org/assets/bitcoin-commons-logo.png".to_string()),
             bots: std::collections::HashMap::new(),
+            publish_min_quorum: 1,
+            zap_reconcile_interval_hours: default_zap_reconcile_interval_hours(),
+            zap_reconcile_window_hours: default_zap_reconcile_window_hours(),
+            relay_failure_threshold: default_relay_failure_threshold(),
+            relay_reset_timeout_seconds: default_relay_reset_timeout_seconds(),
         }
     }
 }
@@ -330,6 +1089,7 @@ impl Default for OtsConfig {
             monthly_anchor_day: 1,
             registry_path: "/var/lib/governance/registries".to_string(),
             proofs_path: "/var/lib/governance/ots-proofs".to_string(),
+            registry_signing_key_path: None,
         }
     }
 }
@@ -340,6 +1100,62 @@ impl Default for AuditConfig {
             enabled: true,
             log_path: "/var/lib/governance/audit-log.jsonl".to_string(),
             rotation_interval_days: 30,
+            max_log_size_mb: default_audit_max_log_size_mb(),
         }
     }
 }
+
+/// Point `[nostr] server_nsec_path` in a TOML config at a new file
+///
+/// Edits the line in place so comments and layout are preserved, and writes
+/// through a temp file so the config is never left half-written. Used to
+/// record a rotated Nostr server key.
+pub fn update_nsec_path(config_path: &Path, nsec_path: &Path) -> anyhow::Result<()> {
+    let content = std::fs::read_to_string(config_path)?;
+    let new_line = format!(
+        "server_nsec_path = {}",
+        toml::Value::String(nsec_path.to_string_lossy().to_string())
+    );
+
+    let mut in_nostr = false;
+    let mut replaced = false;
+    let mut lines = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('[') {
+            if in_nostr && !replaced {
+                lines.push(new_line.clone());
+                replaced = true;
+            }
+            in_nostr = trimmed.starts_with("[nostr]");
+        } else if in_nostr
+            && !replaced
+            && trimmed
+                .split('=')
+                .next()
+                .map(|key| key.trim() == "server_nsec_path")
+                .unwrap_or(false)
+        {
+            lines.push(new_line.clone());
+            replaced = true;
+            continue;
+        }
+        lines.push(line.to_string());
+    }
+    if !replaced {
+        if !in_nostr {
+            lines.push("[nostr]".to_string());
+        }
+        lines.push(new_line);
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    toml::from_str::<toml::Table>(&updated)
+        .map_err(|e| anyhow::anyhow!("Updated config is not valid TOML: {}", e))?;
+
+    let tmp_path = config_path.with_extension("toml.tmp");
+    std::fs::write(&tmp_path, updated)?;
+    std::fs::rename(&tmp_path, config_path)?;
+    Ok(())
+}
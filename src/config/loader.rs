@@ -57,6 +57,9 @@ pub struct ClassificationRule {
     pub file_patterns: Vec<String>,
     pub keywords: KeywordConfig,
     pub confidence_boost: f32,
+    /// PR labels that put a PR in at least this rule's tier
+    #[serde(default)]
+    pub labels: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -108,6 +111,12 @@ pub struct WeightCalculationConfig {
     pub minimum_weight: f64,
     pub contribution_types: Vec<String>,
     pub total_calculation: String,
+    /// Half-life for weight decay of participants that stop re-verifying (0 = disabled)
+    #[serde(default)]
+    pub decay_half_life_days: f64,
+    /// Days after last verification before decay begins
+    #[serde(default)]
+    pub decay_grace_period_days: f64,
 }
 
 fn default_or_logic() -> String {
@@ -178,6 +187,22 @@ pub struct TierRequirement {
     pub description: String,
 }
 
+/// PGP keys maintainers sign commits with (maintainer_keys.yml)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MaintainerKeysConfig {
+    pub maintainer_keys: Vec<MaintainerKey>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MaintainerKey {
+    pub github_username: String,
+    /// Full OpenPGP key fingerprint (40 hex characters, spaces allowed)
+    pub fingerprint: String,
+    /// Revoked keys stay listed so their signatures are rejected explicitly
+    #[serde(default)]
+    pub revoked: bool,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct GovernanceConfigFiles {
     pub action_tiers: ActionTiersConfig,
@@ -187,6 +212,8 @@ pub struct GovernanceConfigFiles {
     pub commons_contributor_thresholds: Option<CommonsContributorThresholdsConfig>,
     #[serde(default)]
     pub teams: Option<TeamsConfig>,
+    #[serde(default)]
+    pub maintainer_keys: Option<MaintainerKeysConfig>,
 }
 
 impl GovernanceConfigFiles {
@@ -205,6 +232,9 @@ impl GovernanceConfigFiles {
         // Load teams configuration (optional - may not exist initially)
         let teams = Self::load_yaml_optional(path.join("maintainers/teams.yml")).ok();
 
+        // Load maintainer PGP keyring (optional - only needed for signed commits)
+        let maintainer_keys = Self::load_yaml_optional(path.join("maintainer_keys.yml")).ok();
+
         info!("Successfully loaded all governance configuration files");
 
         Ok(Self {
@@ -213,6 +243,7 @@ impl GovernanceConfigFiles {
             tier_classification,
             commons_contributor_thresholds,
             teams,
+            maintainer_keys,
         })
     }
 
@@ -500,6 +531,7 @@ mod tests {
             tier_classification,
             commons_contributor_thresholds: None,
             teams: None,
+            maintainer_keys: None,
         };
 
         // This should fail because repository_layers is empty
@@ -540,6 +572,7 @@ mod tests {
             tier_classification,
             commons_contributor_thresholds: None,
             teams: None,
+            maintainer_keys: None,
         };
 
         let tier_1 = config.get_tier_config(1);
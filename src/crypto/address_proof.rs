@@ -0,0 +1,334 @@
+//! Bitcoin Address Ownership Proofs
+//!
+//! Verifies that a message was signed by the key controlling a Bitcoin address.
+//! Supports legacy `signmessage` signatures (BIP-137, P2PKH and P2WPKH) and
+//! BIP-322 "simple" signatures for P2WPKH and P2TR (key path) addresses.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use bitcoin::hashes::{hash160, Hash};
+use secp256k1::ecdsa::{RecoverableSignature, RecoveryId, Signature};
+use secp256k1::{schnorr, Message, PublicKey, Secp256k1, XOnlyPublicKey};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+
+use crate::error::GovernanceError;
+
+/// Verify a base64 message signature against a Bitcoin address
+///
+/// 65-byte signatures are treated as legacy `signmessage` signatures, anything
+/// else as a BIP-322 simple signature (a serialized witness stack).
+pub fn verify_address_signature(
+    address: &str,
+    message: &str,
+    signature: &str,
+) -> Result<bool, GovernanceError> {
+    let script_pubkey = address_script_pubkey(address)?;
+    let signature = STANDARD
+        .decode(signature.trim())
+        .map_err(|e| GovernanceError::CryptoError(format!("Invalid signature base64: {}", e)))?;
+
+    if signature.len() == 65 && (27..=42).contains(&signature[0]) {
+        verify_legacy_signature(&script_pubkey, message, &signature)
+    } else {
+        verify_bip322_simple(&script_pubkey, message, &signature)
+    }
+}
+
+/// Resolve an address to its output script
+fn address_script_pubkey(address: &str) -> Result<Vec<u8>, GovernanceError> {
+    let address = bitcoin::Address::from_str(address)
+        .map_err(|e| GovernanceError::CryptoError(format!("Invalid Bitcoin address: {}", e)))?
+        .assume_checked();
+    Ok(address.script_pubkey().as_bytes().to_vec())
+}
+
+fn sha256d(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+fn tagged_hash(tag: &str, data: &[u8]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+fn write_compact_size(out: &mut Vec<u8>, n: usize) {
+    match n {
+        0..=0xfc => out.push(n as u8),
+        0xfd..=0xffff => {
+            out.push(0xfd);
+            out.extend_from_slice(&(n as u16).to_le_bytes());
+        }
+        _ => {
+            out.push(0xfe);
+            out.extend_from_slice(&(n as u32).to_le_bytes());
+        }
+    }
+}
+
+fn read_compact_size(data: &[u8], pos: &mut usize) -> Option<usize> {
+    let first = *data.get(*pos)?;
+    *pos += 1;
+    let (len, value) = match first {
+        0xfd => (
+            2,
+            u16::from_le_bytes(data.get(*pos..*pos + 2)?.try_into().ok()?) as usize,
+        ),
+        0xfe => (
+            4,
+            u32::from_le_bytes(data.get(*pos..*pos + 4)?.try_into().ok()?) as usize,
+        ),
+        0xff => return None,
+        n => (0, n as usize),
+    };
+    *pos += len;
+    Some(value)
+}
+
+fn p2pkh_script(pubkey_hash: &[u8]) -> Vec<u8> {
+    [&[0x76, 0xa9, 0x14][..], pubkey_hash, &[0x88, 0xac]].concat()
+}
+
+fn p2wpkh_script(pubkey_hash: &[u8]) -> Vec<u8> {
+    [&[0x00, 0x14][..], pubkey_hash].concat()
+}
+
+/// Legacy `signmessage` (BIP-137) verification via public key recovery
+fn verify_legacy_signature(
+    script_pubkey: &[u8],
+    message: &str,
+    signature: &[u8],
+) -> Result<bool, GovernanceError> {
+    let header = signature[0];
+    let recovery_id = RecoveryId::from_i32(((header - 27) & 3) as i32)
+        .map_err(|e| GovernanceError::CryptoError(format!("Invalid recovery id: {}", e)))?;
+    let compressed = header >= 31;
+    let signature = RecoverableSignature::from_compact(&signature[1..], recovery_id)
+        .map_err(|e| GovernanceError::CryptoError(format!("Invalid signature: {}", e)))?;
+
+    let mut data = Vec::new();
+    let prefix = b"Bitcoin Signed Message:\n";
+    write_compact_size(&mut data, prefix.len());
+    data.extend_from_slice(prefix);
+    write_compact_size(&mut data, message.len());
+    data.extend_from_slice(message.as_bytes());
+    let digest = Message::from_digest_slice(&sha256d(&data))
+        .map_err(|e| GovernanceError::CryptoError(format!("Invalid message hash: {}", e)))?;
+
+    let public_key = match Secp256k1::new().recover_ecdsa(&digest, &signature) {
+        Ok(public_key) => public_key,
+        Err(_) => return Ok(false),
+    };
+    let serialized = if compressed {
+        public_key.serialize().to_vec()
+    } else {
+        public_key.serialize_uncompressed().to_vec()
+    };
+    let pubkey_hash = hash160::Hash::hash(&serialized);
+    let pubkey_hash = pubkey_hash.as_byte_array();
+
+    Ok(script_pubkey == p2pkh_script(pubkey_hash).as_slice()
+        || (compressed && script_pubkey == p2wpkh_script(pubkey_hash).as_slice()))
+}
+
+/// BIP-322 `to_spend` transaction id for a message and output script
+fn bip322_to_spend_txid(script_pubkey: &[u8], message: &str) -> [u8; 32] {
+    let message_hash = tagged_hash("BIP0322-signed-message", message.as_bytes());
+
+    let mut tx = Vec::new();
+    tx.extend_from_slice(&0u32.to_le_bytes()); // version
+    tx.push(1); // input count
+    tx.extend_from_slice(&[0u8; 32]); // prevout txid
+    tx.extend_from_slice(&0xffff_ffffu32.to_le_bytes()); // prevout index
+    tx.push(34); // scriptSig: OP_0 PUSH32 <message_hash>
+    tx.extend_from_slice(&[0x00, 0x20]);
+    tx.extend_from_slice(&message_hash);
+    tx.extend_from_slice(&0u32.to_le_bytes()); // sequence
+    tx.push(1); // output count
+    tx.extend_from_slice(&0u64.to_le_bytes()); // value
+    write_compact_size(&mut tx, script_pubkey.len());
+    tx.extend_from_slice(script_pubkey);
+    tx.extend_from_slice(&0u32.to_le_bytes()); // locktime
+    sha256d(&tx)
+}
+
+/// Serialized single OP_RETURN output of the BIP-322 `to_sign` transaction
+const TO_SIGN_OUTPUT: [u8; 10] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 0x6a];
+
+/// BIP-322 simple signature verification (P2WPKH and P2TR key path)
+fn verify_bip322_simple(
+    script_pubkey: &[u8],
+    message: &str,
+    signature: &[u8],
+) -> Result<bool, GovernanceError> {
+    // Decode witness stack
+    let mut pos = 0;
+    let invalid = || GovernanceError::CryptoError("Invalid BIP-322 witness".to_string());
+    let count = read_compact_size(signature, &mut pos).ok_or_else(invalid)?;
+    let mut witness = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = read_compact_size(signature, &mut pos).ok_or_else(invalid)?;
+        witness.push(signature.get(pos..pos + len).ok_or_else(invalid)?.to_vec());
+        pos += len;
+    }
+    if pos != signature.len() {
+        return Err(invalid());
+    }
+
+    let to_spend_txid = bip322_to_spend_txid(script_pubkey, message);
+    let mut outpoint = to_spend_txid.to_vec();
+    outpoint.extend_from_slice(&0u32.to_le_bytes());
+
+    let secp = Secp256k1::verification_only();
+    match script_pubkey {
+        // P2WPKH: witness is [signature, pubkey]
+        [0x00, 0x14, program @ ..] if program.len() == 20 => {
+            if witness.len() != 2 || witness[0].is_empty() {
+                return Ok(false);
+            }
+            let (sighash_type, der) = witness[0].split_last().ok_or_else(invalid)?;
+            if *sighash_type != 0x01 {
+                return Ok(false);
+            }
+            if hash160::Hash::hash(&witness[1]).as_byte_array() != program {
+                return Ok(false);
+            }
+
+            // BIP-143 sighash
+            let mut preimage = Vec::new();
+            preimage.extend_from_slice(&0u32.to_le_bytes());
+            preimage.extend_from_slice(&sha256d(&outpoint));
+            preimage.extend_from_slice(&sha256d(&0u32.to_le_bytes()));
+            preimage.extend_from_slice(&outpoint);
+            preimage.push(0x19);
+            preimage.extend_from_slice(&p2pkh_script(program));
+            preimage.extend_from_slice(&0u64.to_le_bytes());
+            preimage.extend_from_slice(&0u32.to_le_bytes());
+            preimage.extend_from_slice(&sha256d(&TO_SIGN_OUTPUT));
+            preimage.extend_from_slice(&0u32.to_le_bytes());
+            preimage.extend_from_slice(&1u32.to_le_bytes());
+
+            let digest = Message::from_digest_slice(&sha256d(&preimage))
+                .map_err(|e| GovernanceError::CryptoError(format!("Invalid sighash: {}", e)))?;
+            let (signature, public_key) =
+                match (Signature::from_der(der), PublicKey::from_slice(&witness[1])) {
+                    (Ok(signature), Ok(public_key)) => (signature, public_key),
+                    _ => return Ok(false),
+                };
+            Ok(secp.verify_ecdsa(&digest, &signature, &public_key).is_ok())
+        }
+        // P2TR key path: witness is [schnorr signature]
+        [0x51, 0x20, program @ ..] if program.len() == 32 => {
+            if witness.len() != 1 {
+                return Ok(false);
+            }
+            let (sig_bytes, sighash_type) = match witness[0].len() {
+                64 => (&witness[0][..], 0x00u8),
+                65 if witness[0][64] == 0x01 => (&witness[0][..64], 0x01u8),
+                _ => return Ok(false),
+            };
+
+            // BIP-341 sighash (SIGHASH_DEFAULT / ALL, key path, no annex)
+            let mut amounts = Vec::new();
+            amounts.extend_from_slice(&0u64.to_le_bytes());
+            let mut script_pubkeys = Vec::new();
+            write_compact_size(&mut script_pubkeys, script_pubkey.len());
+            script_pubkeys.extend_from_slice(script_pubkey);
+
+            let mut sig_msg = vec![0x00, sighash_type];
+            sig_msg.extend_from_slice(&0u32.to_le_bytes()); // version
+            sig_msg.extend_from_slice(&0u32.to_le_bytes()); // locktime
+            sig_msg.extend_from_slice(&Sha256::digest(&outpoint));
+            sig_msg.extend_from_slice(&Sha256::digest(&amounts));
+            sig_msg.extend_from_slice(&Sha256::digest(&script_pubkeys));
+            sig_msg.extend_from_slice(&Sha256::digest(0u32.to_le_bytes()));
+            sig_msg.extend_from_slice(&Sha256::digest(TO_SIGN_OUTPUT));
+            sig_msg.push(0x00); // spend type: key path, no annex
+            sig_msg.extend_from_slice(&0u32.to_le_bytes()); // input index
+
+            let digest = Message::from_digest_slice(&tagged_hash("TapSighash", &sig_msg))
+                .map_err(|e| GovernanceError::CryptoError(format!("Invalid sighash: {}", e)))?;
+            let (signature, output_key) = match (
+                schnorr::Signature::from_slice(sig_bytes),
+                XOnlyPublicKey::from_slice(program),
+            ) {
+                (Ok(signature), Ok(output_key)) => (signature, output_key),
+                _ => return Ok(false),
+            };
+            Ok(secp
+                .verify_schnorr(&signature, &digest, &output_key)
+                .is_ok())
+        }
+        _ => Err(GovernanceError::CryptoError(
+            "BIP-322 verification only supports P2WPKH and P2TR addresses".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // BIP-322 test vectors
+    const BIP322_ADDRESS: &str = "bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l";
+    const BIP322_SIG_EMPTY: &str = "AkcwRAIgM2gBAQqvZX15ZiysmKmQpDrG83avLIT492QBzLnQIxYCIBaTpOaD20qRlEylyxFSeEA2ba9YOixpX8z46TSDtS40ASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+    const BIP322_SIG_HELLO: &str = "AkcwRAIgZRfIY3p7/DoVTty6YZbWS71bc5Vct9p9Fia83eRmw2QCICK/ENGfwLtptFluMGs2KsqoNSk89pO7F29zJLUx9a/sASECx/EgAxlkQpQ9hYjgGu6EBCPMVPwVIVJqO4XCsMvViHI=";
+
+    #[test]
+    fn test_bip322_to_spend_txid() {
+        let script_pubkey = address_script_pubkey(BIP322_ADDRESS).unwrap();
+        let mut txid = bip322_to_spend_txid(&script_pubkey, "");
+        txid.reverse();
+        assert_eq!(
+            hex::encode(txid),
+            "c5680aa69bb8d860bf82d4e9cd3504b55dde018de765a91bb566283c545a99a7"
+        );
+    }
+
+    #[test]
+    fn test_bip322_p2wpkh_vectors() {
+        assert!(verify_address_signature(BIP322_ADDRESS, "", BIP322_SIG_EMPTY).unwrap());
+        assert!(verify_address_signature(BIP322_ADDRESS, "Hello World", BIP322_SIG_HELLO).unwrap());
+        // Signature for a different message does not verify
+        assert!(
+            !verify_address_signature(BIP322_ADDRESS, "Hello World", BIP322_SIG_EMPTY).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_legacy_signature_roundtrip() {
+        let secp = Secp256k1::new();
+        let (secret_key, public_key) = secp.generate_keypair(&mut secp256k1::rand::thread_rng());
+        let pubkey_hash = hash160::Hash::hash(&public_key.serialize());
+        let address = bitcoin::Address::p2pkh(
+            &bitcoin::PublicKey::new(public_key),
+            bitcoin::Network::Bitcoin,
+        )
+        .to_string();
+        assert_eq!(
+            address_script_pubkey(&address).unwrap(),
+            p2pkh_script(pubkey_hash.as_byte_array())
+        );
+
+        let message = "blvm-commons holdings proof";
+        let mut data = Vec::new();
+        write_compact_size(&mut data, 24);
+        data.extend_from_slice(b"Bitcoin Signed Message:\n");
+        write_compact_size(&mut data, message.len());
+        data.extend_from_slice(message.as_bytes());
+        let digest = Message::from_digest_slice(&sha256d(&data)).unwrap();
+        let (recovery_id, compact) = secp
+            .sign_ecdsa_recoverable(&digest, &secret_key)
+            .serialize_compact();
+
+        let mut signature = vec![31 + recovery_id.to_i32() as u8];
+        signature.extend_from_slice(&compact);
+        let signature = STANDARD.encode(signature);
+
+        assert!(verify_address_signature(&address, message, &signature).unwrap());
+        assert!(!verify_address_signature(&address, "other message", &signature).unwrap());
+    }
+}
@@ -0,0 +1,482 @@
+//! Bitcoin Core JSON-RPC Client
+//!
+//! Minimal JSON-RPC client for looking up block headers, blocks and address
+//! balances, used to verify hashpower and holdings claims against the actual
+//! chain (see `blockchain_verifier`).
+
+use crate::config::BitcoinRpcConfig;
+use crate::error::GovernanceError;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tracing::debug;
+
+/// Bitcoin Core RPC error code for an unknown block
+const RPC_INVALID_ADDRESS_OR_KEY: i64 = -5;
+
+/// Bitcoin Core JSON-RPC client
+#[derive(Clone)]
+pub struct BitcoinRpcClient {
+    url: String,
+    username: Option<String>,
+    password: Option<String>,
+    http_client: reqwest::Client,
+}
+
+/// Block header as returned by `getblockheader`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockHeaderInfo {
+    pub hash: String,
+    pub height: u64,
+    pub confirmations: i64,
+    pub time: u64,
+    #[serde(default)]
+    pub previousblockhash: Option<String>,
+}
+
+/// Block as returned by `getblock` with verbosity 2
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockInfo {
+    pub hash: String,
+    pub height: u64,
+    pub confirmations: i64,
+    pub tx: Vec<RpcTransaction>,
+}
+
+/// Decoded transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcTransaction {
+    pub txid: String,
+    pub vin: Vec<RpcTxIn>,
+    pub vout: Vec<RpcTxOut>,
+}
+
+/// Decoded transaction input
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcTxIn {
+    /// Coinbase script (hex), only present on coinbase inputs
+    #[serde(default)]
+    pub coinbase: Option<String>,
+    #[serde(default)]
+    pub txid: Option<String>,
+    #[serde(default)]
+    pub vout: Option<u32>,
+}
+
+/// Decoded transaction output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcTxOut {
+    /// Output value in BTC
+    pub value: f64,
+    pub n: u32,
+    #[serde(rename = "scriptPubKey")]
+    pub script_pub_key: RpcScriptPubKey,
+}
+
+/// Decoded output script
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RpcScriptPubKey {
+    pub hex: String,
+    #[serde(default)]
+    pub address: Option<String>,
+}
+
+impl RpcTransaction {
+    /// Check whether this is a coinbase transaction
+    pub fn is_coinbase(&self) -> bool {
+        self.vin
+            .first()
+            .map(|i| i.coinbase.is_some())
+            .unwrap_or(false)
+    }
+
+    /// Check whether the coinbase script or any output commits to the pattern
+    ///
+    /// The pattern may be a pool tag (e.g. "/Foundry USA/", matched as a whole
+    /// delimited tag), a payout address, or a hex-encoded public key / script
+    /// commitment of at least [`MIN_SCRIPT_COMMITMENT_BYTES`]. An empty
+    /// pattern matches nothing.
+    pub fn coinbase_matches(&self, pattern: &str) -> bool {
+        if pattern.trim().is_empty() {
+            return false;
+        }
+
+        let script_sig_match = self
+            .vin
+            .first()
+            .and_then(|i| i.coinbase.as_deref())
+            .is_some_and(|script| script_sig_has_pool_tag(script, pattern));
+        if script_sig_match {
+            return true;
+        }
+
+        let commitment = script_commitment(pattern);
+        self.vout.iter().any(|out| {
+            out.script_pub_key.address.as_deref() == Some(pattern)
+                || commitment.as_deref().is_some_and(|commitment| {
+                    hex::decode(&out.script_pub_key.hex)
+                        .is_ok_and(|script| contains_bytes(&script, commitment))
+                })
+        })
+    }
+}
+
+/// Shortest public key / script commitment accepted as a pool pattern
+pub const MIN_SCRIPT_COMMITMENT_BYTES: usize = 20;
+
+/// Whether a coinbase scriptSig (hex) carries the pool tag
+///
+/// Pool tags are `/`-delimited in the coinbase (`/Foundry USA/`). The tag must
+/// appear whole between delimiters, with or without them in `tag`, so "Foundry"
+/// does not match "/Foundry USA/". An empty tag matches nothing.
+pub fn script_sig_has_pool_tag(script_sig_hex: &str, tag: &str) -> bool {
+    let name = tag.trim_matches('/');
+    if name.trim().is_empty() {
+        return false;
+    }
+    hex::decode(script_sig_hex)
+        .is_ok_and(|script| contains_bytes(&script, format!("/{}/", name).as_bytes()))
+}
+
+/// The pattern as raw bytes, if it is a hex commitment long enough to be specific
+fn script_commitment(pattern: &str) -> Option<Vec<u8>> {
+    hex::decode(pattern)
+        .ok()
+        .filter(|bytes| bytes.len() >= MIN_SCRIPT_COMMITMENT_BYTES)
+}
+
+fn contains_bytes(haystack: &[u8], needle: &[u8]) -> bool {
+    !needle.is_empty()
+        && haystack
+            .windows(needle.len())
+            .any(|window| window == needle)
+}
+
+/// UTXO set scan result as returned by `scantxoutset`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UtxoScanResult {
+    pub success: bool,
+    /// Total value of matching outputs in BTC
+    pub total_amount: f64,
+}
+
+/// Result of verifying a set of claimed mined blocks
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HashpowerVerification {
+    /// Blocks found on chain whose coinbase commits to the pool
+    pub verified_blocks: Vec<String>,
+    /// Blocks not found on chain
+    pub missing_blocks: Vec<String>,
+    /// Blocks found on chain but not attributable to the pool
+    pub unattributed_blocks: Vec<String>,
+}
+
+impl HashpowerVerification {
+    /// All claimed blocks exist and are attributable to the pool
+    pub fn is_valid(&self) -> bool {
+        !self.verified_blocks.is_empty()
+            && self.missing_blocks.is_empty()
+            && self.unattributed_blocks.is_empty()
+    }
+}
+
+impl BitcoinRpcClient {
+    /// Create new RPC client from configuration
+    pub fn new(config: &BitcoinRpcConfig) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+
+        Self {
+            url: config.url.clone(),
+            username: config.username.clone(),
+            password: config.password.clone(),
+            http_client,
+        }
+    }
+
+    /// Perform a JSON-RPC call
+    ///
+    /// Returns `Ok(None)` when the node reports the requested object does not exist.
+    async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> Result<Option<T>, GovernanceError> {
+        debug!("Bitcoin RPC call: {}", method);
+
+        let mut request = self.http_client.post(&self.url).json(&json!({
+            "jsonrpc": "1.0",
+            "id": "blvm-commons",
+            "method": method,
+            "params": params,
+        }));
+        if let Some(username) = &self.username {
+            request = request.basic_auth(username, self.password.as_ref());
+        }
+
+        let response: Value = request
+            .send()
+            .await
+            .map_err(|e| GovernanceError::CryptoError(format!("Bitcoin RPC unavailable: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| {
+                GovernanceError::CryptoError(format!("Invalid Bitcoin RPC response: {}", e))
+            })?;
+
+        if let Some(error) = response.get("error").filter(|e| !e.is_null()) {
+            if error.get("code").and_then(|c| c.as_i64()) == Some(RPC_INVALID_ADDRESS_OR_KEY) {
+                return Ok(None);
+            }
+            return Err(GovernanceError::CryptoError(format!(
+                "Bitcoin RPC {} failed: {}",
+                method, error
+            )));
+        }
+
+        let result = response.get("result").cloned().unwrap_or(Value::Null);
+        if result.is_null() {
+            return Ok(None);
+        }
+
+        serde_json::from_value(result).map(Some).map_err(|e| {
+            GovernanceError::CryptoError(format!("Failed to decode {} result: {}", method, e))
+        })
+    }
+
+    /// Get a block header by hash (`getblockheader`)
+    pub async fn get_block_header(
+        &self,
+        block_hash: &str,
+    ) -> Result<Option<BlockHeaderInfo>, GovernanceError> {
+        self.call("getblockheader", json!([block_hash, true])).await
+    }
+
+    /// Get a block with decoded transactions by hash (`getblock` verbosity 2)
+    pub async fn get_block(&self, block_hash: &str) -> Result<Option<BlockInfo>, GovernanceError> {
+        self.call("getblock", json!([block_hash, 2])).await
+    }
+
+    /// Confirmed balance of an address in satoshis (`scantxoutset`)
+    pub async fn scan_address_balance(&self, address: &str) -> Result<u64, GovernanceError> {
+        let scan: UtxoScanResult = self
+            .call(
+                "scantxoutset",
+                json!(["start", [format!("addr({})", address)]]),
+            )
+            .await?
+            .filter(|scan: &UtxoScanResult| scan.success)
+            .ok_or_else(|| {
+                GovernanceError::CryptoError(format!("UTXO scan for {} failed", address))
+            })?;
+        Ok((scan.total_amount * 100_000_000.0).round() as u64)
+    }
+
+    /// Verify that each claimed block exists on the main chain and that its
+    /// coinbase commits to the pool's known pattern
+    pub async fn verify_blocks_mined(
+        &self,
+        blocks_mined: &[String],
+        pool_pattern: &str,
+    ) -> Result<HashpowerVerification, GovernanceError> {
+        let mut result = HashpowerVerification::default();
+
+        for block_hash in blocks_mined {
+            // Negative confirmations mean the block is no longer on the main chain
+            let on_chain = self
+                .get_block_header(block_hash)
+                .await?
+                .map(|header| header.confirmations >= 0)
+                .unwrap_or(false);
+            if !on_chain {
+                result.missing_blocks.push(block_hash.clone());
+                continue;
+            }
+
+            let attributed = self
+                .get_block(block_hash)
+                .await?
+                .and_then(|block| block.tx.into_iter().next())
+                .map(|coinbase| coinbase.is_coinbase() && coinbase.coinbase_matches(pool_pattern))
+                .unwrap_or(false);
+
+            if attributed {
+                result.verified_blocks.push(block_hash.clone());
+            } else {
+                result.unattributed_blocks.push(block_hash.clone());
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Structural validation of claimed block hashes (non-empty, 64 hex characters)
+pub fn validate_block_hashes_structure(blocks_mined: &[String]) -> bool {
+    !blocks_mined.is_empty()
+        && blocks_mined
+            .iter()
+            .all(|h| h.len() == 64 && h.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const BLOCK_A: &str = "00000000000000000001a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0";
+    const BLOCK_B: &str = "00000000000000000001b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0";
+
+    fn rpc_config(url: String) -> BitcoinRpcConfig {
+        BitcoinRpcConfig {
+            url,
+            username: Some("user".to_string()),
+            password: Some("pass".to_string()),
+            timeout_secs: 5,
+        }
+    }
+
+    async fn mock_block(server: &MockServer, hash: &str, coinbase_script: &str) {
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                json!({"method": "getblockheader", "params": [hash, true]}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": {"hash": hash, "height": 840000, "confirmations": 10, "time": 1713571767},
+                "error": null,
+                "id": "blvm-commons"
+            })))
+            .mount(server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "getblock", "params": [hash, 2]})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": {
+                    "hash": hash,
+                    "height": 840000,
+                    "confirmations": 10,
+                    "tx": [{
+                        "txid": "cb",
+                        "vin": [{"coinbase": coinbase_script}],
+                        "vout": [{"value": 3.125, "n": 0, "scriptPubKey": {"hex": "0014abcd", "address": "bc1qpool"}}]
+                    }]
+                },
+                "error": null,
+                "id": "blvm-commons"
+            })))
+            .mount(server)
+            .await;
+    }
+
+    #[test]
+    fn test_structural_validation() {
+        assert!(validate_block_hashes_structure(&[BLOCK_A.to_string()]));
+        assert!(!validate_block_hashes_structure(&[]));
+        assert!(!validate_block_hashes_structure(&["block1".to_string()]));
+    }
+
+    #[tokio::test]
+    async fn test_verify_blocks_mined_with_pool_tag() {
+        let server = MockServer::start().await;
+        let tag_hex = hex::encode("/TestPool/".as_bytes());
+        mock_block(&server, BLOCK_A, &format!("03c0cd0c{}", tag_hex)).await;
+        mock_block(&server, BLOCK_B, "03c0cd0c00").await;
+
+        let client = BitcoinRpcClient::new(&rpc_config(server.uri()));
+
+        let result = client
+            .verify_blocks_mined(&[BLOCK_A.to_string()], "/TestPool/")
+            .await
+            .unwrap();
+        assert!(result.is_valid());
+
+        let result = client
+            .verify_blocks_mined(&[BLOCK_A.to_string(), BLOCK_B.to_string()], "/TestPool/")
+            .await
+            .unwrap();
+        assert!(!result.is_valid());
+        assert_eq!(result.unattributed_blocks, vec![BLOCK_B.to_string()]);
+
+        // Payout address commitment also attributes the block
+        let result = client
+            .verify_blocks_mined(&[BLOCK_B.to_string()], "bc1qpool")
+            .await
+            .unwrap();
+        assert!(result.is_valid());
+    }
+
+    #[test]
+    fn test_pool_tag_must_match_whole() {
+        let script = format!("03c0cd0c{}", hex::encode("/Foundry USA/".as_bytes()));
+        assert!(script_sig_has_pool_tag(&script, "/Foundry USA/"));
+        assert!(script_sig_has_pool_tag(&script, "Foundry USA"));
+        for tag in ["", "/", "//", " ", "Foundry", "/Foundry/", "USA", "F"] {
+            assert!(!script_sig_has_pool_tag(&script, tag), "{:?} matched", tag);
+        }
+
+        let coinbase: RpcTransaction = serde_json::from_value(json!({
+            "txid": "cb",
+            "vin": [{"coinbase": script}],
+            "vout": [{"value": 3.125, "n": 0, "scriptPubKey": {
+                "hex": format!("0014{}", "ab".repeat(20)),
+                "address": "bc1qpool"
+            }}]
+        }))
+        .unwrap();
+        assert!(coinbase.coinbase_matches("bc1qpool"));
+        assert!(coinbase.coinbase_matches(&"ab".repeat(20)));
+        // Empty patterns and short hex fragments match nothing
+        for pattern in ["", "00", "14ab", "bc1q"] {
+            assert!(!coinbase.coinbase_matches(pattern), "{:?} matched", pattern);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unknown_block_is_missing() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": null,
+                "error": {"code": -5, "message": "Block not found"},
+                "id": "blvm-commons"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = BitcoinRpcClient::new(&rpc_config(server.uri()));
+        let result = client
+            .verify_blocks_mined(&[BLOCK_A.to_string()], "/TestPool/")
+            .await
+            .unwrap();
+
+        assert_eq!(result.missing_blocks, vec![BLOCK_A.to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_scan_address_balance() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "method": "scantxoutset",
+                "params": ["start", ["addr(bc1qholder)"]]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "result": {"success": true, "total_amount": 12.5},
+                "error": null,
+                "id": "blvm-commons"
+            })))
+            .mount(&server)
+            .await;
+
+        let client = BitcoinRpcClient::new(&rpc_config(server.uri()));
+        assert_eq!(
+            client.scan_address_balance("bc1qholder").await.unwrap(),
+            1_250_000_000
+        );
+    }
+}
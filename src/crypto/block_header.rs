@@ -0,0 +1,113 @@
+//! Block Header Verification
+//!
+//! Checks raw 80-byte block headers locally: the header must hash to the
+//! claimed block hash, its encoded target must be no easier than the network's
+//! proof-of-work limit, and the hash must meet that target.
+
+use bitcoin::block::Header;
+use bitcoin::consensus::deserialize;
+use bitcoin::consensus::Params;
+use bitcoin::{BlockHash, Network, Work};
+use std::str::FromStr;
+
+use crate::error::GovernanceError;
+
+/// A header that passed verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedHeader {
+    pub block_hash: String,
+    pub prev_block_hash: String,
+    /// Work proven by this header alone
+    pub work: Work,
+}
+
+/// Verify a hex-encoded block header for `network` against the claimed block hash
+pub fn verify_block_header(
+    header_hex: &str,
+    claimed_hash: &str,
+    network: Network,
+) -> Result<VerifiedHeader, GovernanceError> {
+    let header_bytes = hex::decode(header_hex.trim()).map_err(|e| {
+        GovernanceError::ValidationError(format!("Invalid block header hex: {}", e))
+    })?;
+    let header: Header = deserialize(&header_bytes)
+        .map_err(|e| GovernanceError::ValidationError(format!("Invalid block header: {}", e)))?;
+    let claimed = BlockHash::from_str(claimed_hash).map_err(|e| {
+        GovernanceError::ValidationError(format!("Invalid block hash {}: {}", claimed_hash, e))
+    })?;
+
+    let block_hash = header.block_hash();
+    if block_hash != claimed {
+        return Err(GovernanceError::ValidationError(format!(
+            "Header hashes to {}, not the claimed {}",
+            block_hash, claimed
+        )));
+    }
+    // validate_pow only checks the hash against the header's own nBits
+    let pow_limit = Params::new(network).pow_limit;
+    if header.target() > pow_limit {
+        return Err(GovernanceError::ValidationError(format!(
+            "Block {} target is easier than the {} proof-of-work limit",
+            block_hash, network
+        )));
+    }
+    header.validate_pow(header.target()).map_err(|e| {
+        GovernanceError::ValidationError(format!("Block {} fails proof of work: {}", block_hash, e))
+    })?;
+
+    Ok(VerifiedHeader {
+        block_hash: block_hash.to_string(),
+        prev_block_hash: header.prev_blockhash.to_string(),
+        work: header.work(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENESIS_HEADER: &str = "0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c";
+    const GENESIS_HASH: &str = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+    #[test]
+    fn test_valid_header_accepted() {
+        let verified = verify_block_header(GENESIS_HEADER, GENESIS_HASH, Network::Bitcoin).unwrap();
+        assert_eq!(verified.block_hash, GENESIS_HASH);
+        assert_eq!(
+            verified.prev_block_hash,
+            "0000000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_corrupted_header_rejected() {
+        // Change the last nonce byte
+        let corrupted = format!("{}7d", &GENESIS_HEADER[..GENESIS_HEADER.len() - 2]);
+        assert!(verify_block_header(&corrupted, GENESIS_HASH, Network::Bitcoin).is_err());
+
+        // Even when the claimed hash matches, the corrupted hash misses the target
+        let corrupted_hash = deserialize::<Header>(&hex::decode(&corrupted).unwrap())
+            .unwrap()
+            .block_hash()
+            .to_string();
+        assert!(verify_block_header(&corrupted, &corrupted_hash, Network::Bitcoin).is_err());
+
+        assert!(verify_block_header("00ff", GENESIS_HASH, Network::Bitcoin).is_err());
+    }
+
+    #[test]
+    fn test_easy_target_rejected_on_mainnet() {
+        // Regtest difficulty: valid against its own nBits, but not on mainnet
+        let mut header: Header = deserialize(&hex::decode(GENESIS_HEADER).unwrap()).unwrap();
+        header.bits = bitcoin::CompactTarget::from_consensus(0x207fffff);
+        while header.validate_pow(header.target()).is_err() {
+            header.nonce += 1;
+        }
+        let header_hex = bitcoin::consensus::encode::serialize_hex(&header);
+        let hash = header.block_hash().to_string();
+
+        assert!(verify_block_header(&header_hex, &hash, Network::Regtest).is_ok());
+        let err = verify_block_header(&header_hex, &hash, Network::Bitcoin).unwrap_err();
+        assert!(err.to_string().contains("proof-of-work limit"));
+    }
+}
@@ -0,0 +1,532 @@
+//! Blockchain Verification
+//!
+//! Backend-agnostic verification of on-chain claims (coinbase attribution and
+//! address balances), implemented for Bitcoin Core RPC and Esplora HTTP APIs,
+//! with a per-block result cache so repeated registrations don't re-query the
+//! backend.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use crate::config::{AppConfig, EsploraConfig};
+use crate::crypto::bitcoin_rpc::{
+    script_sig_has_pool_tag, validate_block_hashes_structure, BitcoinRpcClient,
+};
+use crate::error::GovernanceError;
+
+/// Verifies claims against the Bitcoin blockchain
+#[async_trait]
+pub trait BlockchainVerifier: Send + Sync {
+    /// Check that a block is on the main chain and its coinbase commits to
+    /// the expected pool tag or payout address
+    ///
+    /// Returns `Ok(false)` for unknown or unattributed blocks and `Err` only
+    /// when the backend could not be queried.
+    async fn verify_coinbase_attribution(
+        &self,
+        block_hash: &str,
+        expected_pool_tag: &str,
+    ) -> Result<bool, GovernanceError>;
+
+    /// Confirmed balance of an address in satoshis
+    async fn get_address_balance(&self, address: &str) -> Result<u64, GovernanceError>;
+}
+
+#[async_trait]
+impl BlockchainVerifier for BitcoinRpcClient {
+    async fn verify_coinbase_attribution(
+        &self,
+        block_hash: &str,
+        expected_pool_tag: &str,
+    ) -> Result<bool, GovernanceError> {
+        let verification = self
+            .verify_blocks_mined(&[block_hash.to_string()], expected_pool_tag)
+            .await?;
+        Ok(verification.is_valid())
+    }
+
+    async fn get_address_balance(&self, address: &str) -> Result<u64, GovernanceError> {
+        self.scan_address_balance(address).await
+    }
+}
+
+/// Esplora (Blockstream/mempool.space style) HTTP API client
+#[derive(Clone)]
+pub struct EsploraClient {
+    base_url: String,
+    http_client: reqwest::Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct EsploraBlockStatus {
+    in_best_chain: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct EsploraAddress {
+    chain_stats: EsploraAddressStats,
+}
+
+#[derive(Debug, Deserialize)]
+struct EsploraAddressStats {
+    funded_txo_sum: u64,
+    spent_txo_sum: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct EsploraTx {
+    vin: Vec<EsploraTxIn>,
+    vout: Vec<EsploraTxOut>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EsploraTxIn {
+    #[serde(default)]
+    is_coinbase: bool,
+    #[serde(default)]
+    scriptsig: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct EsploraTxOut {
+    #[serde(default)]
+    scriptpubkey_address: Option<String>,
+}
+
+impl EsploraClient {
+    /// Create a new Esplora client
+    pub fn new(config: &EsploraConfig) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            base_url: config.url.trim_end_matches('/').to_string(),
+            http_client,
+        }
+    }
+
+    /// GET a path, returning `Ok(None)` on 404
+    async fn get(&self, path: &str) -> Result<Option<reqwest::Response>, GovernanceError> {
+        let url = format!("{}{}", self.base_url, path);
+        debug!("Esplora request: {}", url);
+
+        let response =
+            self.http_client.get(&url).send().await.map_err(|e| {
+                GovernanceError::CryptoError(format!("Esplora request failed: {}", e))
+            })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            return Err(GovernanceError::CryptoError(format!(
+                "Esplora request to {} returned {}",
+                path,
+                response.status()
+            )));
+        }
+        Ok(Some(response))
+    }
+
+    async fn get_json<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+    ) -> Result<Option<T>, GovernanceError> {
+        match self.get(path).await? {
+            Some(response) => response.json::<T>().await.map(Some).map_err(|e| {
+                GovernanceError::CryptoError(format!("Invalid Esplora response: {}", e))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    async fn get_text(&self, path: &str) -> Result<Option<String>, GovernanceError> {
+        match self.get(path).await? {
+            Some(response) => response.text().await.map(Some).map_err(|e| {
+                GovernanceError::CryptoError(format!("Invalid Esplora response: {}", e))
+            }),
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait]
+impl BlockchainVerifier for EsploraClient {
+    async fn verify_coinbase_attribution(
+        &self,
+        block_hash: &str,
+        expected_pool_tag: &str,
+    ) -> Result<bool, GovernanceError> {
+        let in_best_chain = self
+            .get_json::<EsploraBlockStatus>(&format!("/block/{}/status", block_hash))
+            .await?
+            .map(|status| status.in_best_chain)
+            .unwrap_or(false);
+        if !in_best_chain {
+            return Ok(false);
+        }
+
+        let coinbase_txid = match self
+            .get_text(&format!("/block/{}/txid/0", block_hash))
+            .await?
+        {
+            Some(txid) => txid.trim().to_string(),
+            None => return Ok(false),
+        };
+        let coinbase = match self
+            .get_json::<EsploraTx>(&format!("/tx/{}", coinbase_txid))
+            .await?
+        {
+            Some(tx) => tx,
+            None => return Ok(false),
+        };
+
+        let is_coinbase = coinbase.vin.first().map(|i| i.is_coinbase).unwrap_or(false);
+        if !is_coinbase {
+            return Ok(false);
+        }
+
+        let script_matches = coinbase
+            .vin
+            .first()
+            .is_some_and(|input| script_sig_has_pool_tag(&input.scriptsig, expected_pool_tag));
+        let address_matches = !expected_pool_tag.is_empty()
+            && coinbase
+                .vout
+                .iter()
+                .any(|out| out.scriptpubkey_address.as_deref() == Some(expected_pool_tag));
+
+        Ok(script_matches || address_matches)
+    }
+
+    async fn get_address_balance(&self, address: &str) -> Result<u64, GovernanceError> {
+        let stats = self
+            .get_json::<EsploraAddress>(&format!("/address/{}", address))
+            .await?
+            .ok_or_else(|| {
+                GovernanceError::CryptoError(format!("Esplora does not know address {}", address))
+            })?
+            .chain_stats;
+        Ok(stats.funded_txo_sum.saturating_sub(stats.spent_txo_sum))
+    }
+}
+
+/// Caches definitive verification results per (block hash, pool tag)
+///
+/// Backend errors are never cached.
+pub struct CachedBlockchainVerifier {
+    inner: Arc<dyn BlockchainVerifier>,
+    cache: Mutex<HashMap<(String, String), bool>>,
+}
+
+impl CachedBlockchainVerifier {
+    /// Wrap a verifier with a result cache
+    pub fn new(inner: Arc<dyn BlockchainVerifier>) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl BlockchainVerifier for CachedBlockchainVerifier {
+    async fn verify_coinbase_attribution(
+        &self,
+        block_hash: &str,
+        expected_pool_tag: &str,
+    ) -> Result<bool, GovernanceError> {
+        let key = (block_hash.to_string(), expected_pool_tag.to_string());
+        let cached = self.cache.lock().unwrap().get(&key).copied();
+        if let Some(result) = cached {
+            return Ok(result);
+        }
+
+        let result = self
+            .inner
+            .verify_coinbase_attribution(block_hash, expected_pool_tag)
+            .await?;
+        self.cache.lock().unwrap().insert(key, result);
+        Ok(result)
+    }
+
+    /// Balances change, so they are never cached
+    async fn get_address_balance(&self, address: &str) -> Result<u64, GovernanceError> {
+        self.inner.get_address_balance(address).await
+    }
+}
+
+/// Build the configured blockchain verifier, preferring Bitcoin Core RPC over Esplora
+pub fn blockchain_verifier_from_config(config: &AppConfig) -> Option<Arc<dyn BlockchainVerifier>> {
+    let backend: Arc<dyn BlockchainVerifier> = if let Some(rpc) = &config.bitcoin_rpc {
+        Arc::new(BitcoinRpcClient::new(rpc))
+    } else if let Some(esplora) = &config.esplora {
+        Arc::new(EsploraClient::new(esplora))
+    } else {
+        return None;
+    };
+    Some(Arc::new(CachedBlockchainVerifier::new(backend)))
+}
+
+/// Outcome of verifying a hashpower proof
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashpowerProofStatus {
+    /// Every claimed block is on chain and attributed to the pool
+    Verified,
+    /// Malformed proof, or a block is unknown or not attributed to the pool
+    Rejected,
+    /// The verification backend was unreachable; retry later
+    Pending,
+}
+
+/// Verify every block in a hashpower proof
+///
+/// Without a configured verifier only the block hash structure is checked; an
+/// empty pool tag is always rejected.
+/// An unreachable backend yields `Pending` rather than accepting the claim.
+pub async fn verify_hashpower_proof(
+    verifier: Option<&dyn BlockchainVerifier>,
+    blocks_mined: &[String],
+    pool_tag: &str,
+) -> HashpowerProofStatus {
+    if !validate_block_hashes_structure(blocks_mined) || pool_tag.trim().is_empty() {
+        return HashpowerProofStatus::Rejected;
+    }
+
+    let verifier = match verifier {
+        Some(verifier) => verifier,
+        None => {
+            warn!("No blockchain verifier configured - hashpower proof checked structurally only");
+            return HashpowerProofStatus::Verified;
+        }
+    };
+
+    for block_hash in blocks_mined {
+        match verifier
+            .verify_coinbase_attribution(block_hash, pool_tag)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                warn!(
+                    "Hashpower proof rejected: block {} not attributable to {}",
+                    block_hash, pool_tag
+                );
+                return HashpowerProofStatus::Rejected;
+            }
+            Err(e) => {
+                warn!(
+                    "Blockchain verification unavailable ({}) - hashpower proof left pending",
+                    e
+                );
+                return HashpowerProofStatus::Pending;
+            }
+        }
+    }
+
+    HashpowerProofStatus::Verified
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const BLOCK_A: &str = "00000000000000000001a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0";
+    const BLOCK_B: &str = "00000000000000000001b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0";
+
+    /// Mock chain: BLOCK_A is mined by /TestPool/, BLOCK_B is unknown
+    struct MockVerifier {
+        online: bool,
+        calls: AtomicUsize,
+    }
+
+    impl MockVerifier {
+        fn new(online: bool) -> Self {
+            Self {
+                online,
+                calls: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl BlockchainVerifier for MockVerifier {
+        async fn verify_coinbase_attribution(
+            &self,
+            block_hash: &str,
+            expected_pool_tag: &str,
+        ) -> Result<bool, GovernanceError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            if !self.online {
+                return Err(GovernanceError::CryptoError("backend offline".to_string()));
+            }
+            Ok(block_hash == BLOCK_A && expected_pool_tag == "/TestPool/")
+        }
+
+        async fn get_address_balance(&self, _address: &str) -> Result<u64, GovernanceError> {
+            Err(GovernanceError::CryptoError("not supported".to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_valid_blocks_verified() {
+        let verifier = MockVerifier::new(true);
+        let status =
+            verify_hashpower_proof(Some(&verifier), &[BLOCK_A.to_string()], "/TestPool/").await;
+        assert_eq!(status, HashpowerProofStatus::Verified);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_block_rejected() {
+        let verifier = MockVerifier::new(true);
+        let status = verify_hashpower_proof(
+            Some(&verifier),
+            &[BLOCK_A.to_string(), BLOCK_B.to_string()],
+            "/TestPool/",
+        )
+        .await;
+        assert_eq!(status, HashpowerProofStatus::Rejected);
+
+        let status =
+            verify_hashpower_proof(Some(&verifier), &["bad".to_string()], "/TestPool/").await;
+        assert_eq!(status, HashpowerProofStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_backend_outage_is_pending() {
+        let verifier = MockVerifier::new(false);
+        let status =
+            verify_hashpower_proof(Some(&verifier), &[BLOCK_A.to_string()], "/TestPool/").await;
+        assert_eq!(status, HashpowerProofStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_rpc_is_pending() {
+        // Nothing listens on this port
+        let client = BitcoinRpcClient::new(&crate::config::BitcoinRpcConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            username: None,
+            password: None,
+            timeout_secs: 5,
+        });
+        let status =
+            verify_hashpower_proof(Some(&client), &[BLOCK_A.to_string()], "/TestPool/").await;
+        assert_eq!(status, HashpowerProofStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_cache_avoids_requery() {
+        let inner = Arc::new(MockVerifier::new(true));
+        let cached = CachedBlockchainVerifier::new(inner.clone());
+
+        for _ in 0..3 {
+            assert!(cached
+                .verify_coinbase_attribution(BLOCK_A, "/TestPool/")
+                .await
+                .unwrap());
+        }
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cache_does_not_store_errors() {
+        let inner = Arc::new(MockVerifier::new(false));
+        let cached = CachedBlockchainVerifier::new(inner.clone());
+
+        assert!(cached
+            .verify_coinbase_attribution(BLOCK_A, "/TestPool/")
+            .await
+            .is_err());
+        assert!(cached
+            .verify_coinbase_attribution(BLOCK_A, "/TestPool/")
+            .await
+            .is_err());
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_esplora_coinbase_attribution() {
+        let server = MockServer::start().await;
+        let tag_hex = hex::encode("/TestPool/".as_bytes());
+
+        Mock::given(method("GET"))
+            .and(path(format!("/block/{}/status", BLOCK_A)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "in_best_chain": true, "height": 840000
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/block/{}/txid/0", BLOCK_A)))
+            .respond_with(ResponseTemplate::new(200).set_body_string("cbtxid"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/tx/cbtxid"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "vin": [{"is_coinbase": true, "scriptsig": format!("03c0cd0c{}", tag_hex)}],
+                "vout": [{"scriptpubkey_address": "bc1qpool", "value": 312500000}]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(format!("/block/{}/status", BLOCK_B)))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+
+        let client = EsploraClient::new(&EsploraConfig {
+            url: server.uri(),
+            timeout_secs: 5,
+        });
+
+        assert!(client
+            .verify_coinbase_attribution(BLOCK_A, "/TestPool/")
+            .await
+            .unwrap());
+        assert!(client
+            .verify_coinbase_attribution(BLOCK_A, "bc1qpool")
+            .await
+            .unwrap());
+        assert!(!client
+            .verify_coinbase_attribution(BLOCK_A, "/OtherPool/")
+            .await
+            .unwrap());
+        assert!(!client
+            .verify_coinbase_attribution(BLOCK_B, "/TestPool/")
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_esplora_address_balance() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/address/bc1qholder"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "address": "bc1qholder",
+                "chain_stats": {"funded_txo_sum": 250000000u64, "spent_txo_sum": 50000000u64}
+            })))
+            .mount(&server)
+            .await;
+
+        let client = EsploraClient::new(&EsploraConfig {
+            url: server.uri(),
+            timeout_secs: 5,
+        });
+        assert_eq!(
+            client.get_address_balance("bc1qholder").await.unwrap(),
+            200_000_000
+        );
+        assert!(client.get_address_balance("bc1qunknown").await.is_err());
+    }
+}
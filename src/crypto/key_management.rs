@@ -667,7 +667,7 @@ mod tests {
             encryption_enabled: false,
             rotation_policies: vec![],
         };
-        let key_manager = KeyManager::new(db.pool().unwrap().clone(), config);
+        let key_manager = KeyManager::new(db.pool().unwrap(), config);
 
         let metadata = key_manager
             .generate_key_pair(KeyType::Maintainer, "test@example.com", None)
@@ -693,7 +693,7 @@ mod tests {
             encryption_enabled: false,
             rotation_policies: vec![],
         };
-        let key_manager = KeyManager::new(db.pool().unwrap().clone(), config);
+        let key_manager = KeyManager::new(db.pool().unwrap(), config);
 
         let metadata = key_manager
             .generate_key_pair(KeyType::Maintainer, "test@example.com", None)
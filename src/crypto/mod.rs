@@ -1,3 +1,7 @@
+pub mod address_proof;
+pub mod bitcoin_rpc;
+pub mod block_header;
+pub mod blockchain_verifier;
 pub mod key_management;
 pub mod multisig;
 pub mod signatures;
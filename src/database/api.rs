@@ -0,0 +1,64 @@
+//! Internal database endpoints for operators
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use tracing::warn;
+
+use crate::api_auth::require_internal_api_key;
+use crate::config::AppConfig;
+use crate::database::{Database, MigrationRecord, MigrationRollback};
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+/// Applied migrations with their applied_at timestamps
+pub async fn list_migrations(
+    State((_config, database)): State<(AppConfig, Database)>,
+) -> Result<Json<Vec<MigrationRecord>>, ApiError> {
+    database
+        .get_migration_history()
+        .await
+        .map(Json)
+        .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+}
+
+/// Roll back the latest migration; in dry-run mode only the SQL is returned
+pub async fn rollback_migration(
+    State((config, database)): State<(AppConfig, Database)>,
+    Path(version): Path<i64>,
+) -> Result<Json<MigrationRollback>, ApiError> {
+    database
+        .rollback_migration(version, config.dry_run_mode)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            warn!("Failed to roll back migration {}: {}", version, e);
+            api_error(StatusCode::CONFLICT, e)
+        })
+}
+
+/// Create router for database API; all routes require the internal API key
+pub fn create_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/internal/database/migrations", get(list_migrations))
+        .route(
+            "/internal/database/migrations/:version/rollback",
+            post(rollback_migration),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ))
+}
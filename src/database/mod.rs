@@ -1,10 +1,21 @@
+pub mod api;
 pub mod models;
 pub mod queries;
+pub mod reconnect;
 pub mod schema;
+pub mod slow_query;
 
 use crate::error::GovernanceError;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use sqlx::{sqlite::SqliteConnectOptions, sqlite::SqlitePoolOptions, PgPool, Row, SqlitePool};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use tokio_util::sync::CancellationToken;
+
+use reconnect::{ConnectionHealth, ReconnectBackoff, ReconnectStatus};
+use slow_query::{QueryTiming, SlowQueryEntry, SlowQueryLog};
 
 #[derive(Clone)]
 pub enum DatabaseBackend {
@@ -14,9 +25,41 @@ pub enum DatabaseBackend {
 
 #[derive(Clone)]
 pub struct Database {
-    backend: DatabaseBackend,
+    /// Shared by clones, so a reconnect swaps the pool for every handle
+    backend: Arc<RwLock<DatabaseBackend>>,
     /// Original database URL for reconnection
     database_url: String,
+    /// Replica that serves high-volume read-only queries, if configured
+    read_replica: Option<ReadOnly>,
+    /// Slow queries seen through this handle, shared by its clones
+    slow_queries: Arc<SlowQueryLog>,
+    /// Cached health, carried over to reconnected handles
+    connection: Arc<ConnectionHealth>,
+}
+
+/// SQLite pool whose connections run with `PRAGMA query_only=1`
+///
+/// Any INSERT, UPDATE, DELETE or DDL through it fails, so a read path routed
+/// to the replica can't silently write to it.
+#[derive(Clone)]
+pub struct ReadOnly(SqlitePool);
+
+impl ReadOnly {
+    /// Open a read-only pool on an existing SQLite database
+    pub async fn connect(database_url: &str) -> Result<Self, GovernanceError> {
+        let options = SqliteConnectOptions::from_str(database_url)
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .pragma("query_only", "1");
+        let pool = SqlitePoolOptions::new()
+            .connect_with(options)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+        Ok(Self(pool))
+    }
+
+    pub fn pool(&self) -> &SqlitePool {
+        &self.0
+    }
 }
 
 /// Database connection pool statistics
@@ -34,8 +77,11 @@ impl Database {
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
             Ok(Self {
-                backend: DatabaseBackend::Sqlite(pool),
+                backend: Arc::new(RwLock::new(DatabaseBackend::Sqlite(pool))),
                 database_url: database_url.to_string(),
+                read_replica: None,
+                slow_queries: Arc::default(),
+                connection: Arc::default(),
             })
         } else if database_url.starts_with("postgres://")
             || database_url.starts_with("postgresql://")
@@ -44,8 +90,11 @@ impl Database {
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
             Ok(Self {
-                backend: DatabaseBackend::Postgres(pool),
+                backend: Arc::new(RwLock::new(DatabaseBackend::Postgres(pool))),
                 database_url: database_url.to_string(),
+                read_replica: None,
+                slow_queries: Arc::default(),
+                connection: Arc::default(),
             })
         } else {
             Err(GovernanceError::DatabaseError(
@@ -63,8 +112,11 @@ impl Database {
             .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
 
         let db = Self {
-            backend: DatabaseBackend::Sqlite(pool),
+            backend: Arc::new(RwLock::new(DatabaseBackend::Sqlite(pool))),
             database_url: "sqlite::memory:".to_string(),
+            read_replica: None,
+            slow_queries: Arc::default(),
+            connection: Arc::default(),
         };
         db.run_migrations().await?;
         Ok(db)
@@ -92,8 +144,11 @@ impl Database {
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
 
             let db = Database {
-                backend: DatabaseBackend::Sqlite(pool),
+                backend: Arc::new(RwLock::new(DatabaseBackend::Sqlite(pool))),
                 database_url: database_url.to_string(),
+                read_replica: None,
+                slow_queries: Arc::default(),
+                connection: Arc::default(),
             };
             db.run_migrations().await?;
             Ok(db)
@@ -104,8 +159,11 @@ impl Database {
                 .await
                 .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
             let db = Database {
-                backend: DatabaseBackend::Postgres(pool),
+                backend: Arc::new(RwLock::new(DatabaseBackend::Postgres(pool))),
                 database_url: database_url.to_string(),
+                read_replica: None,
+                slow_queries: Arc::default(),
+                connection: Arc::default(),
             };
             db.run_migrations().await?;
             Ok(db)
@@ -116,8 +174,21 @@ impl Database {
         }
     }
 
+    /// Run embedded migrations after checking the applied ones against them
+    ///
+    /// Refuses to run, and so refuses startup, when the database has
+    /// migrations this binary doesn't know (it was migrated by a newer build)
+    /// or an applied migration's checksum no longer matches. Setting
+    /// `DATABASE_FORCE_SKIP_INTEGRITY=1` starts anyway without migrating.
     pub async fn run_migrations(&self) -> Result<(), GovernanceError> {
-        match &self.backend {
+        if !self
+            .check_migration_integrity(force_skip_integrity())
+            .await?
+        {
+            return Ok(());
+        }
+
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 let result = sqlx::migrate!("./migrations").run(pool).await;
 
@@ -177,39 +248,383 @@ impl Database {
         }
     }
 
-    pub fn get_sqlite_pool(&self) -> Option<&SqlitePool> {
-        match &self.backend {
+    fn embedded_migrator(&self) -> sqlx::migrate::Migrator {
+        match &self.backend() {
+            DatabaseBackend::Sqlite(_) => sqlx::migrate!("./migrations"),
+            DatabaseBackend::Postgres(_) => sqlx::migrate!("./migrations-postgres"),
+        }
+    }
+
+    /// Applied migrations compared with the ones embedded in this binary
+    pub async fn migration_status(&self) -> Result<MigrationStatus, GovernanceError> {
+        let query =
+            "SELECT version, checksum FROM _sqlx_migrations WHERE success = TRUE ORDER BY version";
+        let applied: Result<Vec<(i64, Vec<u8>)>, sqlx::Error> = match &self.backend() {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_as(query).fetch_all(pool).await,
+            DatabaseBackend::Postgres(pool) => sqlx::query_as(query).fetch_all(pool).await,
+        };
+        let applied = match applied {
+            Ok(applied) => applied,
+            // A fresh database has no migrations table yet
+            Err(e) if e.to_string().contains("_sqlx_migrations") => Vec::new(),
+            Err(e) => return Err(GovernanceError::DatabaseError(e.to_string())),
+        };
+
+        let migrator = self.embedded_migrator();
+        let embedded: std::collections::BTreeMap<i64, &[u8]> = migrator
+            .iter()
+            .map(|migration| (migration.version, migration.checksum.as_ref()))
+            .collect();
+
+        let mut status = MigrationStatus {
+            current_version: applied.last().map(|(version, _)| *version),
+            latest_known_version: embedded.keys().next_back().copied(),
+            pending: Vec::new(),
+            unknown: Vec::new(),
+            checksum_mismatches: Vec::new(),
+        };
+        for (version, checksum) in &applied {
+            match embedded.get(version) {
+                None => status.unknown.push(*version),
+                Some(expected) if *expected != checksum.as_slice() => {
+                    status.checksum_mismatches.push(*version)
+                }
+                Some(_) => {}
+            }
+        }
+        status.pending = embedded
+            .keys()
+            .filter(|version| !applied.iter().any(|(applied, _)| applied == *version))
+            .copied()
+            .collect();
+        Ok(status)
+    }
+
+    /// Ok(true) if migrations may run; Ok(false) if a failed check was skipped
+    pub async fn check_migration_integrity(
+        &self,
+        force_skip: bool,
+    ) -> Result<bool, GovernanceError> {
+        let status = self.migration_status().await?;
+        if status.unknown.is_empty() && status.checksum_mismatches.is_empty() {
+            return Ok(true);
+        }
+
+        let mut problems = Vec::new();
+        if !status.unknown.is_empty() {
+            problems.push(format!(
+                "database has migrations unknown to this binary: {:?} (downgrade?)",
+                status.unknown
+            ));
+        }
+        if !status.checksum_mismatches.is_empty() {
+            problems.push(format!(
+                "applied migrations changed since they ran (checksum mismatch): {:?}",
+                status.checksum_mismatches
+            ));
+        }
+        let problems = problems.join("; ");
+
+        if force_skip {
+            tracing::warn!(
+                "Migration integrity check failed: {}; starting without migrating because {} is set",
+                problems,
+                FORCE_SKIP_INTEGRITY_ENV
+            );
+            return Ok(false);
+        }
+        Err(GovernanceError::DatabaseError(format!(
+            "Migration integrity check failed: {}; set {}=1 to start anyway",
+            problems, FORCE_SKIP_INTEGRITY_ENV
+        )))
+    }
+
+    /// Applied migrations, oldest first
+    pub async fn get_migration_history(&self) -> Result<Vec<MigrationRecord>, GovernanceError> {
+        let query = r#"
+            SELECT version, description, installed_on, success, execution_time
+            FROM _sqlx_migrations
+            ORDER BY version ASC
+        "#;
+        let rows: Vec<(i64, String, DateTime<Utc>, bool, i64)> = match &self.backend() {
+            DatabaseBackend::Sqlite(pool) => sqlx::query_as(query).fetch_all(pool).await,
+            DatabaseBackend::Postgres(pool) => sqlx::query_as(query).fetch_all(pool).await,
+        }
+        .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(version, description, applied_at, success, execution_time)| MigrationRecord {
+                    version,
+                    description,
+                    applied_at,
+                    success,
+                    execution_time_ms: execution_time / 1_000_000,
+                },
+            )
+            .collect())
+    }
+
+    /// Revert the latest applied migration using its down migration (SQLite only)
+    ///
+    /// The down SQL is read from `DOWN_MIGRATIONS_DIR` (default
+    /// `migrations-down`) and runs in one transaction with the removal of the
+    /// `_sqlx_migrations` entry. With `dry_run` the SQL is only returned.
+    /// Rolling back anything but the latest migration is refused, since later
+    /// migrations may depend on it.
+    pub async fn rollback_migration(
+        &self,
+        version: i64,
+        dry_run: bool,
+    ) -> Result<MigrationRollback, GovernanceError> {
+        let DatabaseBackend::Sqlite(pool) = self.backend() else {
+            return Err(GovernanceError::DatabaseError(
+                "Migration rollback is only supported for SQLite".to_string(),
+            ));
+        };
+
+        let history = self.get_migration_history().await?;
+        let latest = history.last().ok_or_else(|| {
+            GovernanceError::DatabaseError("No migrations have been applied".to_string())
+        })?;
+        if latest.version != version {
+            return Err(GovernanceError::DatabaseError(format!(
+                "Only the latest migration ({}) can be rolled back, not {}",
+                latest.version, version
+            )));
+        }
+
+        let down_sql = read_down_migration(&down_migrations_dir(), version)?;
+        let rollback = MigrationRollback {
+            version,
+            description: latest.description.clone(),
+            down_sql,
+            applied: !dry_run,
+        };
+        if dry_run {
+            tracing::info!(
+                "Dry run: would roll back migration {} ({})",
+                version,
+                rollback.description
+            );
+            return Ok(rollback);
+        }
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+        // One statement at a time: `raw_sql` on a transaction isn't `Send`, which
+        // the rollback API handler needs
+        for statement in split_sql_statements(&rollback.down_sql) {
+            sqlx::query(&statement)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    GovernanceError::DatabaseError(format!(
+                        "Down migration {} failed: {}",
+                        version, e
+                    ))
+                })?;
+        }
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ?")
+            .bind(version)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+        tracing::warn!(
+            "Rolled back migration {} ({})",
+            version,
+            rollback.description
+        );
+        Ok(rollback)
+    }
+
+    /// Current backend; a clone, so the lock isn't held across queries
+    fn backend(&self) -> DatabaseBackend {
+        self.backend
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub fn get_sqlite_pool(&self) -> Option<SqlitePool> {
+        match self.backend() {
             DatabaseBackend::Sqlite(pool) => Some(pool),
             _ => None,
         }
     }
 
-    pub fn get_postgres_pool(&self) -> Option<&PgPool> {
-        match &self.backend {
+    /// Route read-only queries to a replica of the SQLite database
+    ///
+    /// The replica is opened through [`ReadOnly`], so queries sent to it
+    /// can't write.
+    pub async fn with_read_replica(mut self, replica_url: &str) -> Result<Self, GovernanceError> {
+        if !self.is_sqlite() {
+            return Err(GovernanceError::DatabaseError(
+                "Read replicas are only supported for SQLite".to_string(),
+            ));
+        }
+        self.read_replica = Some(ReadOnly::connect(replica_url).await?);
+        Ok(self)
+    }
+
+    /// Log queries that take at least `threshold_ms` as slow
+    pub fn set_slow_query_threshold(&self, threshold_ms: u64) {
+        self.slow_queries.set_threshold_ms(threshold_ms);
+    }
+
+    /// Start timing `sql`; the caller's source location is recorded with it
+    #[track_caller]
+    pub fn time_query(&self, sql: &str) -> QueryTiming {
+        QueryTiming::start(Arc::clone(&self.slow_queries), sql)
+    }
+
+    /// Slow query log of this handle, for [`slow_query::set_shared_log`]
+    pub fn slow_query_log(&self) -> Arc<SlowQueryLog> {
+        Arc::clone(&self.slow_queries)
+    }
+
+    /// Slowest queries over the threshold since start, slowest first
+    pub fn get_slow_query_report(&self) -> Vec<SlowQueryEntry> {
+        self.slow_queries.report()
+    }
+
+    /// Pool for read-only queries: the replica if configured, otherwise the primary
+    pub fn get_read_pool(&self) -> Option<SqlitePool> {
+        match &self.read_replica {
+            Some(replica) => Some(replica.pool().clone()),
+            None => self.get_sqlite_pool(),
+        }
+    }
+
+    /// Pages the replica is behind the primary, by database size
+    ///
+    /// Returns `None` when no replica is configured.
+    pub async fn replica_lag_pages(&self) -> Result<Option<i64>, GovernanceError> {
+        let (Some(replica), Some(primary)) = (&self.read_replica, self.get_sqlite_pool()) else {
+            return Ok(None);
+        };
+        let primary_pages = Self::page_count(&primary).await?;
+        let replica_pages = Self::page_count(replica.pool()).await?;
+        Ok(Some((primary_pages - replica_pages).max(0)))
+    }
+
+    async fn page_count(pool: &SqlitePool) -> Result<i64, GovernanceError> {
+        sqlx::query_scalar::<_, i64>("PRAGMA page_count")
+            .fetch_one(pool)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))
+    }
+
+    /// Warn when the replica falls more than `max_lag_pages` behind the primary
+    pub async fn check_replica_lag(&self, max_lag_pages: i64) -> Result<(), GovernanceError> {
+        if let Some(lag) = self.replica_lag_pages().await? {
+            if lag > max_lag_pages {
+                tracing::warn!(
+                    "Read replica is {} pages behind the primary (threshold {})",
+                    lag,
+                    max_lag_pages
+                );
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_postgres_pool(&self) -> Option<PgPool> {
+        match self.backend() {
             DatabaseBackend::Postgres(pool) => Some(pool),
             _ => None,
         }
     }
 
     pub fn is_sqlite(&self) -> bool {
-        matches!(self.backend, DatabaseBackend::Sqlite(_))
+        matches!(self.backend(), DatabaseBackend::Sqlite(_))
     }
 
     pub fn is_postgres(&self) -> bool {
-        matches!(self.backend, DatabaseBackend::Postgres(_))
+        matches!(self.backend(), DatabaseBackend::Postgres(_))
     }
 
     /// Reconnect to database using stored database URL
     /// Useful when connection pool is closed or unhealthy
-    /// Returns a new Database instance with fresh connection pool
-    pub async fn reconnect(&self) -> Result<Self, GovernanceError> {
-        Self::new(&self.database_url).await
+    ///
+    /// The fresh pool replaces the old one in place, so this handle and every
+    /// clone of it (router state, background tasks) use it for their next
+    /// query. Pools taken out with [`Database::get_sqlite_pool`] beforehand
+    /// keep pointing at the old one.
+    pub async fn reconnect(&self) -> Result<(), GovernanceError> {
+        match Self::new(&self.database_url).await {
+            Ok(database) => {
+                *self.backend.write().unwrap_or_else(|e| e.into_inner()) = database.backend();
+                self.connection.record_reconnect_success();
+                Ok(())
+            }
+            Err(e) => {
+                self.connection.record_reconnect_failure();
+                Err(e)
+            }
+        }
+    }
+
+    /// Reconnect, waiting between failed attempts as `backoff` says
+    ///
+    /// Gives up with the last error once `max_attempts` attempts in a row have
+    /// failed (0 keeps trying). Returns `Ok(false)` if shutdown begins while
+    /// waiting. Success resets `backoff`.
+    pub async fn reconnect_with_backoff(
+        &self,
+        backoff: &mut ReconnectBackoff,
+        max_attempts: u32,
+        token: &CancellationToken,
+    ) -> Result<bool, GovernanceError> {
+        loop {
+            let Err(e) = self.reconnect().await else {
+                backoff.reset();
+                return Ok(true);
+            };
+            let attempts = self.reconnect_status().reconnect_attempts;
+            if max_attempts > 0 && attempts >= max_attempts {
+                return Err(e);
+            }
+            let delay = backoff.next_delay();
+            tracing::warn!(
+                "Database reconnection attempt {} failed: {} - retrying in {:?}",
+                attempts,
+                e,
+                delay
+            );
+            if !crate::shutdown::sleep(token, delay).await {
+                return Ok(false);
+            }
+        }
+    }
+
+    /// Result of the last health check or reconnect, without a round trip
+    pub fn is_healthy(&self) -> bool {
+        self.connection.is_healthy()
+    }
+
+    /// Cached health and reconnection attempts, for `/status`
+    pub fn reconnect_status(&self) -> ReconnectStatus {
+        self.connection.status()
     }
 
     /// Check database connection health
     /// Returns true if connection is healthy, false otherwise
     pub async fn check_health(&self) -> Result<bool, GovernanceError> {
-        match &self.backend {
+        let result = self.query_health().await;
+        self.connection.set_healthy(matches!(result, Ok(true)));
+        result
+    }
+
+    async fn query_health(&self) -> Result<bool, GovernanceError> {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // Simple query to test connection
                 sqlx::query("SELECT 1")
@@ -235,7 +650,7 @@ impl Database {
 
     /// Get database connection pool statistics
     pub async fn get_pool_stats(&self) -> Result<PoolStats, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => Ok(PoolStats {
                 size: pool.size(),
                 idle: pool.num_idle(),
@@ -249,6 +664,17 @@ impl Database {
         }
     }
 
+    /// Close the pool and read replica, waiting for checked-out connections
+    pub async fn close(&self) {
+        if let Some(replica) = &self.read_replica {
+            replica.pool().close().await;
+        }
+        match &self.backend() {
+            DatabaseBackend::Sqlite(pool) => pool.close().await,
+            DatabaseBackend::Postgres(pool) => pool.close().await,
+        }
+    }
+
     pub async fn create_pull_request(
         &self,
         repo_name: &str,
@@ -256,7 +682,7 @@ impl Database {
         head_sha: &str,
         layer: i32,
     ) -> Result<(), GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 sqlx::query(
                     r#"
@@ -329,7 +755,7 @@ impl Database {
             reasoning: reasoning.map(|s| s.to_string()),
         };
 
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // Get current signatures
                 let signatures_json: Option<String> = sqlx::query_scalar(
@@ -427,7 +853,7 @@ impl Database {
         maintainer: Option<&str>,
         details: &serde_json::Value,
     ) -> Result<(), GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 sqlx::query(
                     r#"
@@ -470,12 +896,15 @@ impl Database {
         repo_name: &str,
         pr_number: i32,
     ) -> Result<Option<crate::database::models::PullRequest>, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 use crate::database::queries::Queries;
-                Queries::get_pull_request(pool, repo_name, pr_number)
+                let timing = self.time_query(Queries::GET_PULL_REQUEST_SQL);
+                let pr = Queries::get_pull_request(pool, repo_name, pr_number)
                     .await
-                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))
+                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                timing.finish(pr.is_some() as u64);
+                Ok(pr)
             }
             DatabaseBackend::Postgres(pool) => {
                 // Postgres implementation - similar to SQLite but with $1, $2 placeholders
@@ -584,10 +1013,9 @@ impl Database {
         &self,
         limit: i64,
     ) -> Result<Vec<crate::database::models::GovernanceEvent>, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
-                let rows = sqlx::query(
-                    r#"
+                let sql = r#"
                     SELECT 
                         id,
                         event_type,
@@ -599,12 +1027,14 @@ impl Database {
                     FROM governance_events
                     ORDER BY timestamp DESC
                     LIMIT ?
-                    "#,
-                )
-                .bind(limit)
-                .fetch_all(pool)
-                .await
-                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                    "#;
+                let timing = self.time_query(sql);
+                let rows = sqlx::query(sql)
+                    .bind(limit)
+                    .fetch_all(pool)
+                    .await
+                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                timing.finish(rows.len() as u64);
 
                 let mut events = Vec::new();
                 for row in rows {
@@ -714,20 +1144,21 @@ impl Database {
     pub async fn get_last_merged_pr(
         &self,
     ) -> Result<Option<(Option<i32>, Option<chrono::DateTime<chrono::Utc>>)>, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
-                let row = sqlx::query(
-                    r#"
+                let sql = r#"
                     SELECT pr_number, timestamp
                     FROM governance_events
                     WHERE event_type IN ('merge', 'merged', 'pr_merged')
                     ORDER BY timestamp DESC
                     LIMIT 1
-                    "#,
-                )
-                .fetch_optional(pool)
-                .await
-                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                    "#;
+                let timing = self.time_query(sql);
+                let row = sqlx::query(sql)
+                    .fetch_optional(pool)
+                    .await
+                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                timing.finish(row.is_some() as u64);
 
                 if let Some(row) = row {
                     let pr_number: Option<i32> = row
@@ -772,20 +1203,21 @@ impl Database {
 
     /// Count merges today
     pub async fn count_merges_today(&self) -> Result<u64, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // Use SQLite date functions for reliable date comparison
-                let count: i64 = sqlx::query_scalar(
-                    r#"
+                let sql = r#"
                     SELECT COUNT(*)
                     FROM governance_events
                     WHERE event_type IN ('merge', 'merged', 'pr_merged')
                     AND date(timestamp) = date('now')
-                    "#,
-                )
-                .fetch_one(pool)
-                .await
-                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                    "#;
+                let timing = self.time_query(sql);
+                let count: i64 = sqlx::query_scalar(sql)
+                    .fetch_one(pool)
+                    .await
+                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                timing.finish(1);
                 Ok(count as u64)
             }
             DatabaseBackend::Postgres(pool) => {
@@ -815,7 +1247,7 @@ impl Database {
         justification: &str,
         overridden_by: &str,
     ) -> Result<(), GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // SQLite doesn't support ON CONFLICT with named columns in older versions
                 // Use REPLACE INTO instead (works with UNIQUE constraint)
@@ -866,7 +1298,7 @@ impl Database {
         pr_number: i32,
     ) -> Result<Option<crate::database::models::TierOverride>, GovernanceError> {
         use sqlx::Row;
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 let row = sqlx::query(
                     r#"
@@ -957,7 +1389,7 @@ impl Database {
         &self,
         username: &str,
     ) -> Result<Option<crate::database::models::Maintainer>, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 let maintainer = sqlx::query_as::<_, crate::database::models::Maintainer>(
                     "SELECT id, github_username, public_key, layer, active, last_updated FROM maintainers WHERE github_username = ? AND active = true"
@@ -984,7 +1416,7 @@ impl Database {
     pub async fn get_emergency_keyholders(
         &self,
     ) -> Result<Vec<crate::database::models::EmergencyKeyholder>, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 use crate::database::queries::Queries;
                 Queries::get_emergency_keyholders(pool)
@@ -1041,13 +1473,13 @@ impl Database {
     }
 
     /// Get the database pool for testing purposes (SQLite only)
-    pub fn pool(&self) -> Option<&SqlitePool> {
+    pub fn pool(&self) -> Option<SqlitePool> {
         self.get_sqlite_pool()
     }
 
     /// Perform database health check
     pub async fn health_check(&self) -> Result<DatabaseHealth, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // Check database connectivity
                 let connection_count = pool.size();
@@ -1115,7 +1547,7 @@ impl Database {
 
     /// Get performance statistics
     pub async fn get_performance_stats(&self) -> Result<PerformanceStats, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // Get cache size
                 let cache_size = sqlx::query_scalar::<_, i64>("PRAGMA cache_size")
@@ -1130,16 +1562,19 @@ impl Database {
                         .await
                         .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
 
-                // Get compile options (as a proxy for slow queries)
-                let compile_options = sqlx::query_scalar::<_, String>("PRAGMA compile_options")
-                    .fetch_all(pool)
-                    .await
-                    .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+                // Passive checkpoint never blocks writers; both counts are -1 outside WAL mode
+                let (_, wal_frames, wal_checkpointed): (i64, i64, i64) =
+                    sqlx::query_as("PRAGMA wal_checkpoint(PASSIVE)")
+                        .fetch_one(pool)
+                        .await
+                        .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
 
                 Ok(PerformanceStats {
                     cache_size,
                     wal_checkpoint_threshold,
-                    slow_queries_count: compile_options.len() as i64,
+                    slow_queries_count: self.slow_queries.slow_count() as i64,
+                    wal_frames: wal_frames.max(0),
+                    wal_checkpointed: wal_checkpointed.max(0),
                 })
             }
             DatabaseBackend::Postgres(_pool) => {
@@ -1148,7 +1583,9 @@ impl Database {
                 Ok(PerformanceStats {
                     cache_size: 0,
                     wal_checkpoint_threshold: 0,
-                    slow_queries_count: 0,
+                    slow_queries_count: self.slow_queries.slow_count() as i64,
+                    wal_frames: 0,
+                    wal_checkpointed: 0,
                 })
             }
         }
@@ -1156,7 +1593,7 @@ impl Database {
 
     /// Optimize database performance
     pub async fn optimize_database(&self) -> Result<(), GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // Run VACUUM to reclaim space and optimize database
                 sqlx::query("VACUUM")
@@ -1181,9 +1618,68 @@ impl Database {
         Ok(())
     }
 
+    /// Switch SQLite to WAL mode with automatic checkpoints every `checkpoint_interval_pages`
+    ///
+    /// `wal_autocheckpoint` is a per-connection setting, so the pool is rebuilt
+    /// with both pragmas applied on connect. In-memory and PostgreSQL databases
+    /// are left unchanged.
+    pub async fn configure_wal(
+        &mut self,
+        checkpoint_interval_pages: u32,
+    ) -> Result<(), GovernanceError> {
+        let DatabaseBackend::Sqlite(pool) = self.backend() else {
+            return Ok(());
+        };
+        if self.database_url.contains(":memory:") {
+            return Ok(());
+        }
+
+        let options = SqliteConnectOptions::from_str(&self.database_url)
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal)
+            .pragma("wal_autocheckpoint", checkpoint_interval_pages.to_string());
+        let wal_pool = SqlitePoolOptions::new()
+            .connect_with(options)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+        pool.close().await;
+        *self.backend.write().unwrap_or_else(|e| e.into_inner()) =
+            DatabaseBackend::Sqlite(wal_pool);
+        Ok(())
+    }
+
+    /// Checkpoint the WAL into the main database and truncate it (SQLite only)
+    ///
+    /// Logs the frames written back and any left behind because a reader held
+    /// them; PostgreSQL checkpoints itself and reports zeroes.
+    pub async fn run_wal_checkpoint(&self) -> Result<WalCheckpoint, GovernanceError> {
+        let DatabaseBackend::Sqlite(pool) = self.backend() else {
+            return Ok(WalCheckpoint::default());
+        };
+        let (busy, wal_frames, checkpointed): (i64, i64, i64) =
+            sqlx::query_as("PRAGMA wal_checkpoint(TRUNCATE)")
+                .fetch_one(&pool)
+                .await
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+        let checkpoint = WalCheckpoint {
+            busy: busy != 0,
+            wal_frames,
+            checkpointed,
+        };
+        tracing::info!(
+            "WAL checkpoint: {} pages written, {} remaining{}",
+            checkpoint.checkpointed.max(0),
+            checkpoint.remaining(),
+            if checkpoint.busy { " (busy)" } else { "" }
+        );
+        Ok(checkpoint)
+    }
+
     /// Checkpoint WAL file to main database (SQLite only)
     pub async fn checkpoint_wal(&self) -> Result<(), GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // Checkpoint WAL file to main database
                 sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
@@ -1207,7 +1703,7 @@ impl Database {
         workflow_run_id: Option<u64>,
         status: &str,
     ) -> Result<i64, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 let build_id = sqlx::query_scalar::<_, i64>(
                     r#"
@@ -1261,7 +1757,7 @@ impl Database {
         new_status: &str,
         error_message: Option<&str>,
     ) -> Result<(), GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 // Get current status
                 let current_status: Option<String> = sqlx::query_scalar(
@@ -1438,7 +1934,7 @@ impl Database {
         &self,
         release_version: &str,
     ) -> Result<Vec<(String, String)>, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 let rows = sqlx::query(
                     "SELECT repo_name, status FROM build_runs WHERE release_version = ?",
@@ -1475,7 +1971,7 @@ impl Database {
         &self,
         release_version: &str,
     ) -> Result<Vec<(String, Option<u64>, String)>, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 let rows = sqlx::query(
                     "SELECT repo_name, workflow_run_id, status FROM build_runs WHERE release_version = ?"
@@ -1524,7 +2020,7 @@ impl Database {
         &self,
         release_version: &str,
     ) -> Result<bool, GovernanceError> {
-        match &self.backend {
+        match &self.backend() {
             DatabaseBackend::Sqlite(pool) => {
                 let incomplete_count: i64 = sqlx::query_scalar(
                     r#"
@@ -1575,12 +2071,173 @@ pub struct PerformanceStats {
     pub cache_size: i64,
     pub wal_checkpoint_threshold: i64,
     pub slow_queries_count: i64,
+    /// Frames currently in the WAL file
+    pub wal_frames: i64,
+    /// WAL frames already copied back into the database
+    pub wal_checkpointed: i64,
+}
+
+/// Result of `PRAGMA wal_checkpoint`
+#[derive(Debug, Clone, Default)]
+pub struct WalCheckpoint {
+    /// A reader or writer prevented the checkpoint from completing
+    pub busy: bool,
+    /// Frames in the WAL when the checkpoint ran; -1 outside WAL mode
+    pub wal_frames: i64,
+    /// Frames written back into the database
+    pub checkpointed: i64,
+}
+
+impl WalCheckpoint {
+    /// Frames still waiting to be checkpointed
+    pub fn remaining(&self) -> i64 {
+        (self.wal_frames - self.checkpointed).max(0)
+    }
+}
+
+/// Set to `1` to start despite a failed migration integrity check
+pub const FORCE_SKIP_INTEGRITY_ENV: &str = "DATABASE_FORCE_SKIP_INTEGRITY";
+
+fn force_skip_integrity() -> bool {
+    std::env::var(FORCE_SKIP_INTEGRITY_ENV)
+        .map(|value| value == "1" || value.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Outcome of [`Database::migration_status`]
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationStatus {
+    /// Latest applied migration, if any
+    pub current_version: Option<i64>,
+    /// Latest migration embedded in this binary
+    pub latest_known_version: Option<i64>,
+    /// Embedded migrations not yet applied
+    pub pending: Vec<i64>,
+    /// Applied migrations this binary doesn't have
+    pub unknown: Vec<i64>,
+    /// Applied migrations whose checksum differs from the embedded one
+    pub checksum_mismatches: Vec<i64>,
+}
+
+/// An applied migration from `_sqlx_migrations`
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationRecord {
+    pub version: i64,
+    pub description: String,
+    pub applied_at: DateTime<Utc>,
+    pub success: bool,
+    pub execution_time_ms: i64,
+}
+
+/// Outcome of [`Database::rollback_migration`]
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationRollback {
+    pub version: i64,
+    pub description: String,
+    /// SQL that was (or, in a dry run, would be) executed
+    pub down_sql: String,
+    /// False for a dry run
+    pub applied: bool,
+}
+
+fn down_migrations_dir() -> PathBuf {
+    std::env::var("DOWN_MIGRATIONS_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("migrations-down"))
+}
+
+/// Read `<dir>/<version>_*.sql`, matching the version prefix numerically like sqlx
+fn read_down_migration(dir: &Path, version: i64) -> Result<String, GovernanceError> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        GovernanceError::DatabaseError(format!(
+            "Cannot read down migrations in {}: {}",
+            dir.display(),
+            e
+        ))
+    })?;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("sql") {
+            continue;
+        }
+        let file_version = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split('_').next())
+            .and_then(|prefix| prefix.parse::<i64>().ok());
+        if file_version == Some(version) {
+            return std::fs::read_to_string(&path)
+                .map_err(|e| GovernanceError::DatabaseError(e.to_string()));
+        }
+    }
+
+    Err(GovernanceError::DatabaseError(format!(
+        "No down migration for version {} in {}",
+        version,
+        dir.display()
+    )))
+}
+
+/// Split SQL into statements on `;`, dropping comments
+///
+/// Quoted strings and identifiers are kept intact. Statements with a body of
+/// their own (`CREATE TRIGGER ... BEGIN ... END`) are not supported.
+fn split_sql_statements(sql: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' => {
+                current.push(c);
+                for inner in chars.by_ref() {
+                    current.push(inner);
+                    // A doubled quote reopens the string on the next pass
+                    if inner == c {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for inner in chars.by_ref() {
+                    if inner == '\n' {
+                        current.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for inner in chars.by_ref() {
+                    if previous == '*' && inner == '/' {
+                        break;
+                    }
+                    previous = inner;
+                }
+                current.push(' ');
+            }
+            ';' => {
+                if !current.trim().is_empty() {
+                    statements.push(current.trim().to_string());
+                }
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        statements.push(current.trim().to_string());
+    }
+    statements
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json::json;
+    use std::time::Duration;
 
     #[tokio::test]
     async fn test_database_new_in_memory() {
@@ -1820,6 +2477,72 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_configure_wal() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("sqlite://{}?mode=rwc", dir.path().join("wal.db").display());
+        let mut db = Database::new(&url).await.unwrap();
+        db.configure_wal(500).await.unwrap();
+
+        let health = db.health_check().await.unwrap();
+        assert!(health.wal_mode_active);
+        let stats = db.get_performance_stats().await.unwrap();
+        assert_eq!(stats.wal_checkpoint_threshold, 500);
+
+        sqlx::query("CREATE TABLE t (x INTEGER)")
+            .execute(&db.get_sqlite_pool().unwrap())
+            .await
+            .unwrap();
+        let checkpoint = db.run_wal_checkpoint().await.unwrap();
+        assert!(!checkpoint.busy);
+        assert_eq!(checkpoint.remaining(), 0);
+        let stats = db.get_performance_stats().await.unwrap();
+        assert_eq!(stats.wal_frames, 0);
+    }
+
+    #[tokio::test]
+    async fn test_read_replica() {
+        let dir = tempfile::tempdir().unwrap();
+        let primary_url = format!(
+            "sqlite://{}?mode=rwc",
+            dir.path().join("primary.db").display()
+        );
+        let replica_url = format!(
+            "sqlite://{}?mode=rwc",
+            dir.path().join("replica.db").display()
+        );
+        for url in [&primary_url, &replica_url] {
+            let db = Database::new(url).await.unwrap();
+            sqlx::query("CREATE TABLE t (x INTEGER)")
+                .execute(&db.get_sqlite_pool().unwrap())
+                .await
+                .unwrap();
+        }
+
+        let db = Database::new(&primary_url).await.unwrap();
+        assert_eq!(
+            db.get_read_pool().unwrap().connect_options().get_filename(),
+            db.get_sqlite_pool()
+                .unwrap()
+                .connect_options()
+                .get_filename()
+        );
+        assert_eq!(db.replica_lag_pages().await.unwrap(), None);
+
+        let db = db.with_read_replica(&replica_url).await.unwrap();
+        let read_pool = &db.get_read_pool().unwrap();
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM t")
+            .fetch_one(read_pool)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+        assert!(sqlx::query("INSERT INTO t (x) VALUES (1)")
+            .execute(read_pool)
+            .await
+            .is_err());
+        assert_eq!(db.replica_lag_pages().await.unwrap(), Some(0));
+    }
+
     #[tokio::test]
     async fn test_upsert_build_run() {
         let db = Database::new_in_memory().await.unwrap();
@@ -1913,4 +2636,245 @@ mod tests {
         assert!(db.get_sqlite_pool().is_some());
         assert!(db.get_postgres_pool().is_none());
     }
+
+    #[tokio::test]
+    async fn test_get_migration_history() {
+        let db = Database::new_in_memory().await.unwrap();
+        let history = db.get_migration_history().await.unwrap();
+        assert!(!history.is_empty());
+        assert!(history.iter().all(|m| m.success));
+        assert!(history.windows(2).all(|w| w[0].version < w[1].version));
+    }
+
+    #[tokio::test]
+    async fn test_rollback_migration() {
+        // Every migration is expected to ship a down migration
+        let db = Database::new_in_memory().await.unwrap();
+        let history = db.get_migration_history().await.unwrap();
+        let latest = history.last().unwrap().version;
+        let previous = history[history.len() - 2].version;
+
+        // Only the latest migration can be rolled back
+        assert!(db.rollback_migration(previous, false).await.is_err());
+
+        let preview = db.rollback_migration(latest, true).await.unwrap();
+        assert!(!preview.applied);
+        assert!(!preview.down_sql.is_empty());
+        let after_preview = db.get_migration_history().await.unwrap();
+        assert_eq!(after_preview.last().unwrap().version, latest);
+
+        let rollback = db.rollback_migration(latest, false).await.unwrap();
+        assert!(rollback.applied);
+        let history = db.get_migration_history().await.unwrap();
+        assert_eq!(history.last().unwrap().version, previous);
+
+        // Re-running migrations re-applies the rolled back one
+        db.run_migrations().await.unwrap();
+        let history = db.get_migration_history().await.unwrap();
+        assert_eq!(history.last().unwrap().version, latest);
+    }
+
+    #[test]
+    fn test_split_sql_statements() {
+        let sql = "-- Rollback 999: Test; not a statement\n\
+                   DROP TABLE a;\n\
+                   /* ; */ INSERT INTO b VALUES ('x;y', 'it''s');\n\
+                   UPDATE \"c;d\" SET e = 1 -- trailing; comment\n";
+        assert_eq!(
+            split_sql_statements(sql),
+            vec![
+                "DROP TABLE a",
+                "INSERT INTO b VALUES ('x;y', 'it''s')",
+                "UPDATE \"c;d\" SET e = 1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_down_migrations_roll_back_to_017() {
+        let db = Database::new_in_memory().await.unwrap();
+        let history = db.get_migration_history().await.unwrap();
+        let latest = history.last().unwrap().version;
+
+        // Each down migration applies on top of the ones rolled back after it
+        for version in (18..=latest).rev() {
+            db.rollback_migration(version, false).await.unwrap();
+        }
+        let history = db.get_migration_history().await.unwrap();
+        assert_eq!(history.last().unwrap().version, 17);
+
+        db.run_migrations().await.unwrap();
+        let history = db.get_migration_history().await.unwrap();
+        assert_eq!(history.last().unwrap().version, latest);
+    }
+
+    #[tokio::test]
+    async fn test_slow_query_report() {
+        let db = Database::new_in_memory().await.unwrap();
+        assert!(db.get_slow_query_report().is_empty());
+
+        db.set_slow_query_threshold(0);
+        for _ in 0..12 {
+            db.get_governance_events(5).await.unwrap();
+        }
+        db.count_merges_today().await.unwrap();
+
+        let report = db.get_slow_query_report();
+        assert_eq!(report.len(), slow_query::SLOW_QUERY_REPORT_SIZE);
+        assert!(report
+            .iter()
+            .all(|entry| entry.caller_location.contains("src/database/mod.rs")));
+        assert!(report.iter().all(|entry| entry.query_hash.len() == 16));
+        let stats = db.get_performance_stats().await.unwrap();
+        assert_eq!(stats.slow_queries_count, 13);
+    }
+
+    #[tokio::test]
+    async fn test_health_is_cached_and_carried_over_on_reconnect() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!(
+            "sqlite://{}?mode=rwc",
+            dir.path().join("reconnect.db").display()
+        );
+        let db = Database::new(&url).await.unwrap();
+        assert!(db.check_health().await.unwrap());
+        assert!(db.is_healthy());
+
+        db.close().await;
+        assert!(db.check_health().await.is_err());
+        assert!(!db.is_healthy());
+
+        db.reconnect().await.unwrap();
+        assert!(db.check_health().await.unwrap());
+        assert!(db.is_healthy());
+        assert!(db.reconnect_status().last_reconnect_at.is_some());
+
+        // Reconnecting fails once the database's directory is gone
+        db.close().await;
+        drop(dir);
+        assert!(db.reconnect().await.is_err());
+        let status = db.reconnect_status();
+        assert!(!status.healthy);
+        assert_eq!(status.reconnect_attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_swaps_pool_for_clones() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("sqlite://{}?mode=rwc", dir.path().join("swap.db").display());
+        let db = Database::new(&url).await.unwrap();
+        let shared = db.clone();
+        sqlx::query("CREATE TABLE t (x INTEGER)")
+            .execute(&db.get_sqlite_pool().unwrap())
+            .await
+            .unwrap();
+
+        db.close().await;
+        assert!(shared.check_health().await.is_err());
+
+        // Only the health task's handle reconnects; the clone sees the new pool
+        db.reconnect().await.unwrap();
+        assert!(shared.check_health().await.unwrap());
+        let pool = shared.get_sqlite_pool().unwrap();
+        assert!(!pool.is_closed());
+        sqlx::query("INSERT INTO t (x) VALUES (1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM t")
+            .fetch_one(&db.get_sqlite_pool().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_with_backoff() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!(
+            "sqlite://{}?mode=rwc",
+            dir.path().join("backoff.db").display()
+        );
+        let db = Database::new(&url).await.unwrap();
+        let serving = db.clone();
+        let token = CancellationToken::new();
+        let mut backoff =
+            ReconnectBackoff::new(Duration::from_millis(1), Duration::from_millis(2), 2.0);
+
+        db.close().await;
+        assert!(db
+            .reconnect_with_backoff(&mut backoff, 3, &token)
+            .await
+            .unwrap());
+        assert!(serving.check_health().await.unwrap());
+
+        // Gives up after the configured number of failed attempts
+        db.close().await;
+        drop(dir);
+        assert!(db
+            .reconnect_with_backoff(&mut backoff, 3, &token)
+            .await
+            .is_err());
+        assert_eq!(db.reconnect_status().reconnect_attempts, 3);
+        assert!(!serving.check_health().await.unwrap_or(false));
+
+        // Stops waiting once shutdown begins
+        token.cancel();
+        assert!(!db
+            .reconnect_with_backoff(&mut backoff, 0, &token)
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_migration_status() {
+        let db = Database::new_in_memory().await.unwrap();
+        let status = db.migration_status().await.unwrap();
+        assert!(status.pending.is_empty());
+        assert!(status.unknown.is_empty());
+        assert!(status.checksum_mismatches.is_empty());
+        assert_eq!(status.current_version, status.latest_known_version);
+
+        let recorded: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM schema_migrations")
+            .fetch_one(&db.get_sqlite_pool().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            recorded as usize,
+            db.get_migration_history().await.unwrap().len()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_migrations_refuses_checksum_drift() {
+        let db = Database::new_in_memory().await.unwrap();
+        sqlx::query("UPDATE _sqlx_migrations SET checksum = X'00' WHERE version = 1")
+            .execute(&db.get_sqlite_pool().unwrap())
+            .await
+            .unwrap();
+
+        let err = db.run_migrations().await.unwrap_err();
+        assert!(err.to_string().contains("checksum mismatch"));
+        assert_eq!(
+            db.migration_status().await.unwrap().checksum_mismatches,
+            vec![1]
+        );
+        // The escape hatch starts without migrating
+        assert!(!db.check_migration_integrity(true).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_run_migrations_refuses_unknown_migration() {
+        let db = Database::new_in_memory().await.unwrap();
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) VALUES (9999, 'from a newer build', TRUE, X'00', 0)",
+        )
+        .execute(&db.get_sqlite_pool().unwrap())
+        .await
+        .unwrap();
+
+        let err = db.run_migrations().await.unwrap_err();
+        assert!(err.to_string().contains("unknown to this binary"));
+        assert_eq!(db.migration_status().await.unwrap().unknown, vec![9999]);
+    }
 }
@@ -5,13 +5,8 @@ use sqlx::{FromRow, Row, SqlitePool};
 pub struct Queries;
 
 impl Queries {
-    pub async fn get_pull_request(
-        pool: &SqlitePool,
-        repo_name: &str,
-        pr_number: i32,
-    ) -> Result<Option<PullRequest>, sqlx::Error> {
-        let row = sqlx::query(
-            r#"
+    /// SQL run by [`Queries::get_pull_request`]
+    pub const GET_PULL_REQUEST_SQL: &'static str = r#"
             SELECT 
                 id,
                 repo_name,
@@ -27,12 +22,18 @@ impl Queries {
                 updated_at
             FROM pull_requests
             WHERE repo_name = ? AND pr_number = ?
-            "#,
-        )
-        .bind(repo_name)
-        .bind(pr_number)
-        .fetch_optional(pool)
-        .await?;
+            "#;
+
+    pub async fn get_pull_request(
+        pool: &SqlitePool,
+        repo_name: &str,
+        pr_number: i32,
+    ) -> Result<Option<PullRequest>, sqlx::Error> {
+        let row = sqlx::query(Self::GET_PULL_REQUEST_SQL)
+            .bind(repo_name)
+            .bind(pr_number)
+            .fetch_optional(pool)
+            .await?;
 
         match row {
             Some(r) => {
@@ -0,0 +1,172 @@
+//! Connection health and reconnection backoff
+//!
+//! The health monitor in `main` checks the pool every minute. After repeated
+//! failures it reconnects with exponential backoff plus jitter, so a restarted
+//! database isn't hit by every instance at the same moment. The outcome is
+//! cached in [`ConnectionHealth`] for [`Database::is_healthy`] and `/status`.
+//!
+//! [`Database::is_healthy`]: crate::database::Database::is_healthy
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// First reconnection delay, and the upper bound of the jitter
+pub const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(5);
+
+/// Longest reconnection delay before jitter
+pub const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(300);
+
+/// Factor the delay grows by after each failed attempt
+pub const RECONNECT_MULTIPLIER: f64 = 2.0;
+
+/// Last known state of a database handle, shared by its clones and by the
+/// handles it reconnects to
+#[derive(Debug)]
+pub struct ConnectionHealth {
+    healthy: AtomicBool,
+    reconnect_attempts: AtomicU32,
+    last_reconnect_at: Mutex<Option<DateTime<Utc>>>,
+}
+
+impl Default for ConnectionHealth {
+    fn default() -> Self {
+        Self {
+            healthy: AtomicBool::new(true),
+            reconnect_attempts: AtomicU32::new(0),
+            last_reconnect_at: Mutex::new(None),
+        }
+    }
+}
+
+/// Reconnection state reported on `/status`
+#[derive(Debug, Clone, Serialize)]
+pub struct ReconnectStatus {
+    pub healthy: bool,
+    /// Failed reconnection attempts since the last successful one
+    pub reconnect_attempts: u32,
+    pub last_reconnect_at: Option<DateTime<Utc>>,
+}
+
+impl ConnectionHealth {
+    pub fn is_healthy(&self) -> bool {
+        self.healthy.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_healthy(&self, healthy: bool) {
+        self.healthy.store(healthy, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_reconnect_failure(&self) {
+        self.set_healthy(false);
+        self.reconnect_attempts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_reconnect_success(&self) {
+        self.set_healthy(true);
+        self.reconnect_attempts.store(0, Ordering::Relaxed);
+        *self
+            .last_reconnect_at
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(Utc::now());
+    }
+
+    pub fn status(&self) -> ReconnectStatus {
+        ReconnectStatus {
+            healthy: self.is_healthy(),
+            reconnect_attempts: self.reconnect_attempts.load(Ordering::Relaxed),
+            last_reconnect_at: *self
+                .last_reconnect_at
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()),
+        }
+    }
+}
+
+/// Exponential backoff between reconnection attempts
+#[derive(Debug, Clone)]
+pub struct ReconnectBackoff {
+    base: Duration,
+    max: Duration,
+    multiplier: f64,
+    current: Duration,
+}
+
+impl Default for ReconnectBackoff {
+    fn default() -> Self {
+        Self::new(
+            RECONNECT_BASE_DELAY,
+            RECONNECT_MAX_DELAY,
+            RECONNECT_MULTIPLIER,
+        )
+    }
+}
+
+impl ReconnectBackoff {
+    pub fn new(base: Duration, max: Duration, multiplier: f64) -> Self {
+        Self {
+            base,
+            max,
+            multiplier,
+            current: base,
+        }
+    }
+
+    /// Delay before the next attempt: the current step plus up to `base` of jitter
+    pub fn next_delay(&mut self) -> Duration {
+        let jitter_ms = self.base.as_millis() as u64;
+        let jitter = if jitter_ms == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_millis(rand::thread_rng().gen_range(0..jitter_ms))
+        };
+        let delay = self.current + jitter;
+        self.current = self.current.mul_f64(self.multiplier).min(self.max);
+        delay
+    }
+
+    /// Start again from the base delay after a successful reconnect
+    pub fn reset(&mut self) {
+        self.current = self.base;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_max_with_jitter() {
+        let mut backoff = ReconnectBackoff::default();
+        for step in [5, 10, 20, 40, 80, 160, 300, 300] {
+            let delay = backoff.next_delay();
+            assert!(delay >= Duration::from_secs(step));
+            assert!(delay < Duration::from_secs(step) + RECONNECT_BASE_DELAY);
+        }
+
+        backoff.reset();
+        assert!(backoff.next_delay() < RECONNECT_BASE_DELAY * 2);
+    }
+
+    #[test]
+    fn test_reconnect_status() {
+        let health = ConnectionHealth::default();
+        assert!(health.is_healthy());
+
+        health.record_reconnect_failure();
+        health.record_reconnect_failure();
+        let status = health.status();
+        assert!(!status.healthy);
+        assert_eq!(status.reconnect_attempts, 2);
+        assert!(status.last_reconnect_at.is_none());
+
+        health.record_reconnect_success();
+        let status = health.status();
+        assert!(status.healthy);
+        assert_eq!(status.reconnect_attempts, 0);
+        assert!(status.last_reconnect_at.is_some());
+    }
+}
@@ -0,0 +1,229 @@
+//! Slow query logging
+//!
+//! Query sites time themselves with [`Database::time_query`], which records
+//! the caller's source location. Code that only holds a pool (the node
+//! registry, contribution tracking) uses [`time_query`], which records into
+//! the log registered with [`set_shared_log`]. Queries over the configured
+//! threshold are logged as structured warnings, and the slowest are kept for
+//! [`Database::get_slow_query_report`]. A query that errors is recorded too,
+//! marked as failed.
+//!
+//! [`Database::time_query`]: crate::database::Database::time_query
+//! [`Database::get_slow_query_report`]: crate::database::Database::get_slow_query_report
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::panic::Location;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+use tracing::warn;
+
+/// Slowest queries kept for the report
+pub const SLOW_QUERY_REPORT_SIZE: usize = 10;
+
+/// Threshold used until one is configured
+pub const DEFAULT_SLOW_QUERY_THRESHOLD_MS: u64 = 100;
+
+/// The server database's slow query log, for code that only holds a pool
+static SHARED_LOG: OnceLock<Arc<SlowQueryLog>> = OnceLock::new();
+
+/// Register the server database's slow query log (only the first registration is kept)
+pub fn set_shared_log(log: Arc<SlowQueryLog>) {
+    let _ = SHARED_LOG.set(log);
+}
+
+/// Start timing `sql` against the shared slow query log
+///
+/// Before a log is registered, queries are timed against a default log that
+/// nothing reports.
+#[track_caller]
+pub fn time_query(sql: &str) -> QueryTiming {
+    QueryTiming::start(Arc::clone(SHARED_LOG.get_or_init(Arc::default)), sql)
+}
+
+/// A query that took longer than the slow query threshold
+#[derive(Debug, Clone, Serialize)]
+pub struct SlowQueryEntry {
+    /// First 16 hex digits of the SHA-256 of the SQL
+    pub query_hash: String,
+    pub duration_ms: u64,
+    pub rows_returned: u64,
+    /// `file:line:column` of the code that ran the query
+    pub caller_location: String,
+    /// The query returned an error (or was cancelled) instead of finishing
+    pub failed: bool,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Slow queries seen by one database handle and its clones
+#[derive(Debug)]
+pub struct SlowQueryLog {
+    threshold_ms: AtomicU64,
+    slow_count: AtomicU64,
+    slowest: Mutex<Vec<SlowQueryEntry>>,
+}
+
+impl Default for SlowQueryLog {
+    fn default() -> Self {
+        Self {
+            threshold_ms: AtomicU64::new(DEFAULT_SLOW_QUERY_THRESHOLD_MS),
+            slow_count: AtomicU64::new(0),
+            slowest: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl SlowQueryLog {
+    pub fn set_threshold_ms(&self, threshold_ms: u64) {
+        self.threshold_ms.store(threshold_ms, Ordering::Relaxed);
+    }
+
+    /// Queries over the threshold since start
+    pub fn slow_count(&self) -> u64 {
+        self.slow_count.load(Ordering::Relaxed)
+    }
+
+    /// Slowest queries seen, slowest first
+    pub fn report(&self) -> Vec<SlowQueryEntry> {
+        self.slowest
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn record(&self, entry: SlowQueryEntry) {
+        if entry.duration_ms < self.threshold_ms.load(Ordering::Relaxed) {
+            return;
+        }
+        self.slow_count.fetch_add(1, Ordering::Relaxed);
+        warn!(
+            query_hash = %entry.query_hash,
+            duration_ms = entry.duration_ms,
+            rows_returned = entry.rows_returned,
+            caller_location = %entry.caller_location,
+            failed = entry.failed,
+            "Slow database query"
+        );
+
+        let mut slowest = self.slowest.lock().unwrap_or_else(|e| e.into_inner());
+        slowest.push(entry);
+        slowest.sort_by(|a, b| b.duration_ms.cmp(&a.duration_ms));
+        slowest.truncate(SLOW_QUERY_REPORT_SIZE);
+    }
+}
+
+/// First 16 hex digits of the SHA-256 of `sql`, with whitespace normalized
+pub fn query_hash(sql: &str) -> String {
+    let normalized = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+    hex::encode(Sha256::digest(normalized.as_bytes()))[..16].to_string()
+}
+
+/// An in-flight query; call [`QueryTiming::finish`] once its rows are fetched
+///
+/// Dropping it without finishing (e.g. returning early on the query's error)
+/// records the query as failed.
+#[must_use = "call finish() so the query duration is recorded"]
+pub struct QueryTiming {
+    log: Arc<SlowQueryLog>,
+    query_hash: String,
+    caller: &'static Location<'static>,
+    started: Instant,
+    finished: bool,
+}
+
+impl QueryTiming {
+    #[track_caller]
+    pub(crate) fn start(log: Arc<SlowQueryLog>, sql: &str) -> Self {
+        Self {
+            log,
+            query_hash: query_hash(sql),
+            caller: Location::caller(),
+            started: Instant::now(),
+            finished: false,
+        }
+    }
+
+    pub fn finish(mut self, rows_returned: u64) {
+        self.finished = true;
+        self.record(rows_returned, false);
+    }
+
+    fn record(&self, rows_returned: u64, failed: bool) {
+        self.log.record(SlowQueryEntry {
+            query_hash: self.query_hash.clone(),
+            duration_ms: self.started.elapsed().as_millis() as u64,
+            rows_returned,
+            caller_location: self.caller.to_string(),
+            failed,
+            recorded_at: Utc::now(),
+        });
+    }
+}
+
+impl Drop for QueryTiming {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.record(0, true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(duration_ms: u64) -> SlowQueryEntry {
+        SlowQueryEntry {
+            query_hash: query_hash("SELECT 1"),
+            duration_ms,
+            rows_returned: 1,
+            caller_location: "src/lib.rs:1:1".to_string(),
+            failed: false,
+            recorded_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_keeps_slowest_over_threshold() {
+        let log = SlowQueryLog::default();
+        log.set_threshold_ms(50);
+        log.record(entry(10));
+        for ms in 50..70 {
+            log.record(entry(ms));
+        }
+
+        let report = log.report();
+        assert_eq!(log.slow_count(), 20);
+        assert_eq!(report.len(), SLOW_QUERY_REPORT_SIZE);
+        assert_eq!(report[0].duration_ms, 69);
+        assert_eq!(report[SLOW_QUERY_REPORT_SIZE - 1].duration_ms, 60);
+    }
+
+    #[test]
+    fn test_unfinished_query_is_recorded_as_failed() {
+        let log = Arc::new(SlowQueryLog::default());
+        log.set_threshold_ms(0);
+
+        QueryTiming::start(Arc::clone(&log), "SELECT 1").finish(1);
+        // Dropped without finishing, as when `?` returns the query's error
+        drop(QueryTiming::start(Arc::clone(&log), "SELECT broken"));
+
+        let report = log.report();
+        assert_eq!(log.slow_count(), 2);
+        let failed: Vec<_> = report.iter().filter(|entry| entry.failed).collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].query_hash, query_hash("SELECT broken"));
+        assert_eq!(failed[0].rows_returned, 0);
+    }
+
+    #[test]
+    fn test_query_hash_ignores_whitespace() {
+        assert_eq!(
+            query_hash("SELECT *\n    FROM t"),
+            query_hash("SELECT * FROM t")
+        );
+        assert_eq!(query_hash("SELECT 1").len(), 16);
+    }
+}
@@ -0,0 +1,126 @@
+//! Internal emergency API endpoints
+//!
+//! Keyholders' signed activations are submitted here; the manager checks them
+//! against the registered keyholder keys before declaring the emergency.
+
+use axum::{
+    extract::Extension,
+    http::StatusCode,
+    middleware,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::api_auth::require_internal_api_key;
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::enforcement::emergency_manager::EmergencyManager;
+use crate::error::GovernanceError;
+use crate::validation::emergency::{ActiveEmergency, EmergencyActivation};
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+/// The active emergency, if any
+pub async fn get_active_emergency(
+    Extension(manager): Extension<Arc<EmergencyManager>>,
+) -> Result<Json<Option<ActiveEmergency>>, ApiError> {
+    manager.active_emergency().await.map(Json).map_err(|e| {
+        warn!("Failed to load active emergency: {}", e);
+        api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+    })
+}
+
+/// Declare an emergency from a keyholder-signed activation
+pub async fn activate_emergency(
+    Extension(manager): Extension<Arc<EmergencyManager>>,
+    Json(activation): Json<EmergencyActivation>,
+) -> Result<(StatusCode, Json<ActiveEmergency>), ApiError> {
+    match manager.activate(&activation).await {
+        Ok(emergency) => Ok((StatusCode::CREATED, Json(emergency))),
+        Err(e @ (GovernanceError::ValidationError(_) | GovernanceError::SignatureError(_))) => {
+            warn!(
+                "Rejected emergency activation by {}: {}",
+                activation.activated_by, e
+            );
+            Err(api_error(StatusCode::BAD_REQUEST, e))
+        }
+        Err(e) => {
+            warn!("Failed to activate emergency: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+/// Create router for the emergency API; all routes require the internal API key
+pub fn create_router(
+    manager: Arc<EmergencyManager>,
+    state: (AppConfig, Database),
+) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/internal/emergency", get(get_active_emergency))
+        .route("/internal/emergency/activate", post(activate_emergency))
+        .layer(Extension(manager))
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use chrono::Utc;
+    use tower::ServiceExt;
+
+    use crate::validation::emergency::{EmergencyTier, KeyholderSignature};
+
+    #[tokio::test]
+    async fn test_activation_with_unregistered_keys_rejected() {
+        let database = Database::new_in_memory().await.unwrap();
+        let config = AppConfig {
+            internal_api_key: Some("internal-key".to_string()),
+            ..Default::default()
+        };
+        let state = (config, database.clone());
+        let app = create_router(Arc::new(EmergencyManager::new(database)), state.clone())
+            .with_state(state);
+
+        let activation = EmergencyActivation {
+            tier: EmergencyTier::Critical,
+            activated_by: "mallory".to_string(),
+            reason: "Not an emergency".to_string(),
+            evidence: "x".repeat(200),
+            signatures: (0..5)
+                .map(|i| KeyholderSignature {
+                    keyholder: format!("mallory{}", i),
+                    public_key: format!("02{:064x}", i + 1),
+                    signature: "00".repeat(64),
+                    timestamp: Utc::now(),
+                })
+                .collect(),
+        };
+        let response = app
+            .oneshot(
+                Request::post("/internal/emergency/activate")
+                    .header("content-type", "application/json")
+                    .header("x-api-key", "internal-key")
+                    .body(Body::from(serde_json::to_vec(&activation).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,665 @@
+//! Emergency mode activation, extension and expiry
+//!
+//! Persists emergencies declared by the emergency keyholders in the
+//! `emergency_tiers` table. While an emergency is active, PR status checks
+//! use the emergency tier's shorter review period; once it expires or is
+//! deactivated they fall back to the normal layer/tier requirements.
+
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use crate::audit::{shared_logger, AuditCategory};
+use crate::database::Database;
+use crate::error::GovernanceError;
+use crate::nostr::{
+    CombinedRequirement, EconomicVetoStatus, GovernanceActionPublisher, LayerRequirement,
+    TierRequirement,
+};
+use crate::shutdown::{self, Shutdown};
+use crate::validation::emergency::{
+    ActiveEmergency, EmergencyActivation, EmergencyTier, EmergencyValidator, KeyholderSignature,
+};
+
+/// How often the background task looks for expired emergencies
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(300);
+
+pub struct EmergencyManager {
+    database: Database,
+    publisher: Option<Arc<GovernanceActionPublisher>>,
+}
+
+impl EmergencyManager {
+    pub fn new(database: Database) -> Self {
+        Self {
+            database,
+            publisher: None,
+        }
+    }
+
+    /// Publish activations, extensions and expiries to Nostr
+    pub fn with_publisher(mut self, publisher: Arc<GovernanceActionPublisher>) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
+    fn pool(&self) -> Result<SqlitePool, GovernanceError> {
+        self.database
+            .get_sqlite_pool()
+            .ok_or_else(|| GovernanceError::DatabaseError("SQLite pool not available".to_string()))
+    }
+
+    /// Count the distinct registered keyholder keys behind a set of signatures
+    ///
+    /// Every signature must name an active keyholder from
+    /// `emergency_keyholders` and carry that keyholder's registered public key;
+    /// a key supplied only by the signer proves nothing.
+    async fn registered_signers(
+        &self,
+        signatures: &[KeyholderSignature],
+    ) -> Result<usize, GovernanceError> {
+        let keyholders = self.database.get_emergency_keyholders().await?;
+        let mut keys = HashSet::new();
+        for signature in signatures {
+            let keyholder = keyholders
+                .iter()
+                .find(|k| k.github_username == signature.keyholder)
+                .ok_or_else(|| {
+                    GovernanceError::SignatureError(format!(
+                        "{} is not an active emergency keyholder",
+                        signature.keyholder
+                    ))
+                })?;
+            let registered_key = normalize_key(&keyholder.public_key);
+            if normalize_key(&signature.public_key) != registered_key {
+                return Err(GovernanceError::SignatureError(format!(
+                    "Signature from {} does not use their registered key",
+                    signature.keyholder
+                )));
+            }
+            keys.insert(registered_key);
+        }
+        Ok(keys.len())
+    }
+
+    /// Declare an emergency
+    ///
+    /// Requires the tier's N-of-M activation threshold, counted over distinct
+    /// registered keyholder keys, and valid signatures by those keys. Only one
+    /// emergency can be active at a time.
+    pub async fn activate(
+        &self,
+        activation: &EmergencyActivation,
+    ) -> Result<ActiveEmergency, GovernanceError> {
+        let (required, total) = activation.tier.activation_threshold();
+        let signers = self.registered_signers(&activation.signatures).await?;
+        if signers < required as usize {
+            return Err(GovernanceError::insufficient_signatures(
+                required as usize,
+                signers,
+                format!("{}-of-{}", required, total),
+            ));
+        }
+        EmergencyValidator::validate_activation(activation)?;
+
+        if let Some(active) = self.active_emergency().await? {
+            return Err(GovernanceError::ValidationError(format!(
+                "Emergency {} ({}) is already active until {}",
+                active.id,
+                active.tier.name(),
+                active.expires_at.to_rfc3339()
+            )));
+        }
+
+        let now = Utc::now();
+        let expires_at = EmergencyValidator::calculate_expiration(activation.tier);
+        let id: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO emergency_tiers
+            (tier, activated_by, reason, evidence, signatures, activation_threshold,
+             activated_at, expires_at, active, post_mortem_deadline, security_audit_deadline)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, true, ?, ?)
+            RETURNING id
+            "#,
+        )
+        .bind(activation.tier.to_i32())
+        .bind(&activation.activated_by)
+        .bind(&activation.reason)
+        .bind(&activation.evidence)
+        .bind(serde_json::to_string(&activation.signatures)?)
+        .bind(format!("{}-of-{}", required, total))
+        .bind(now)
+        .bind(expires_at)
+        .bind(EmergencyValidator::calculate_post_mortem_deadline(
+            activation.tier,
+            now,
+        ))
+        .bind(EmergencyValidator::calculate_security_audit_deadline(
+            activation.tier,
+            now,
+        ))
+        .fetch_one(&self.pool()?)
+        .await?;
+
+        let emergency = ActiveEmergency {
+            id: id as i32,
+            tier: activation.tier,
+            activated_by: activation.activated_by.clone(),
+            reason: activation.reason.clone(),
+            activated_at: now,
+            expires_at,
+            extended: false,
+            extension_count: 0,
+        };
+        info!(
+            "{} {} activated by {} until {}",
+            emergency.tier.emoji(),
+            emergency.tier.name(),
+            emergency.activated_by,
+            emergency.expires_at
+        );
+        self.record_event(&emergency, "activated", &activation.activated_by)
+            .await;
+        Ok(emergency)
+    }
+
+    /// Extend the active emergency, within the tier's extension limits
+    pub async fn extend(
+        &self,
+        emergency_id: i32,
+        requested_by: &str,
+        justification: &str,
+        signatures: &[KeyholderSignature],
+    ) -> Result<ActiveEmergency, GovernanceError> {
+        let mut emergency = self.get_active(emergency_id).await?;
+        EmergencyValidator::validate_extension(&emergency, signatures)?;
+        let new_expiration = emergency
+            .calculate_extension_expiration()
+            .ok_or_else(|| GovernanceError::extension_not_allowed(emergency.tier.name().into()))?;
+
+        let (required, total) = emergency.tier.extension_threshold();
+        let signers = self.registered_signers(signatures).await?;
+        if signers < required as usize {
+            return Err(GovernanceError::insufficient_signatures(
+                required as usize,
+                signers,
+                format!("{}-of-{}", required, total),
+            ));
+        }
+        let now = Utc::now();
+        let pool = &self.pool()?;
+        sqlx::query(
+            r#"
+            INSERT INTO emergency_extensions
+            (emergency_tier_id, requested_by, justification, extension_duration_days,
+             signatures, approval_threshold, approved, approved_at, new_expiration)
+            VALUES (?, ?, ?, ?, ?, ?, true, ?, ?)
+            "#,
+        )
+        .bind(emergency_id)
+        .bind(requested_by)
+        .bind(justification)
+        .bind(emergency.tier.extension_duration_days() as i64)
+        .bind(serde_json::to_string(signatures)?)
+        .bind(format!("{}-of-{}", required, total))
+        .bind(now)
+        .bind(new_expiration)
+        .execute(pool)
+        .await?;
+        sqlx::query(
+            r#"
+            UPDATE emergency_tiers
+            SET expires_at = ?, extended = true, extension_count = extension_count + 1,
+                updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(new_expiration)
+        .bind(now)
+        .bind(emergency_id)
+        .execute(pool)
+        .await?;
+
+        emergency.expires_at = new_expiration;
+        emergency.extended = true;
+        emergency.extension_count += 1;
+        info!(
+            "Emergency {} extended until {} ({} of {} extensions used)",
+            emergency_id,
+            new_expiration,
+            emergency.extension_count,
+            emergency.tier.max_extensions()
+        );
+        self.record_event(&emergency, "extended", requested_by)
+            .await;
+        Ok(emergency)
+    }
+
+    /// End an active emergency early
+    pub async fn deactivate(
+        &self,
+        emergency_id: i32,
+        actor: &str,
+        reason: &str,
+    ) -> Result<(), GovernanceError> {
+        let emergency = self.get_active(emergency_id).await?;
+        self.mark_inactive(emergency_id, reason).await?;
+        info!(
+            "Emergency {} deactivated by {}: {}",
+            emergency_id, actor, reason
+        );
+        self.record_event(&emergency, "deactivated", actor).await;
+        Ok(())
+    }
+
+    /// Deactivate every emergency past its expiry; returns the expired ids
+    pub async fn expire_overdue(&self) -> Result<Vec<i32>, GovernanceError> {
+        let active = self.load_active().await?;
+        let expired = EmergencyValidator::check_expiration(&active);
+        for emergency in active.iter().filter(|e| expired.contains(&e.id)) {
+            self.mark_inactive(emergency.id, "expired").await?;
+            info!(
+                "Emergency {} ({}) expired at {}",
+                emergency.id,
+                emergency.tier.name(),
+                emergency.expires_at
+            );
+            self.record_event(emergency, "expired", "system").await;
+        }
+        Ok(expired)
+    }
+
+    /// The currently active, unexpired emergency, if any
+    pub async fn active_emergency(&self) -> Result<Option<ActiveEmergency>, GovernanceError> {
+        Ok(self
+            .load_active()
+            .await?
+            .into_iter()
+            .find(|e| !e.is_expired()))
+    }
+
+    /// Review period to enforce given the active emergency (if any)
+    ///
+    /// An emergency only ever shortens the normal requirement; an expired
+    /// emergency no longer applies even before the expiry task has run.
+    pub fn effective_review_days(required_days: i64, emergency: Option<&ActiveEmergency>) -> i64 {
+        match emergency {
+            Some(emergency) if !emergency.is_expired() => {
+                required_days.min(emergency.tier.review_period_days() as i64)
+            }
+            _ => required_days,
+        }
+    }
+
+    async fn get_active(&self, emergency_id: i32) -> Result<ActiveEmergency, GovernanceError> {
+        let emergency = self
+            .load_active()
+            .await?
+            .into_iter()
+            .find(|e| e.id == emergency_id)
+            .ok_or_else(|| {
+                GovernanceError::ValidationError(format!(
+                    "Emergency {} is not active",
+                    emergency_id
+                ))
+            })?;
+        if emergency.is_expired() {
+            return Err(GovernanceError::emergency_expired(emergency_id));
+        }
+        Ok(emergency)
+    }
+
+    async fn load_active(&self) -> Result<Vec<ActiveEmergency>, GovernanceError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, tier, activated_by, reason, activated_at, expires_at,
+                   extended, extension_count
+            FROM emergency_tiers
+            WHERE active = true
+            ORDER BY activated_at DESC
+            "#,
+        )
+        .fetch_all(&self.pool()?)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(ActiveEmergency {
+                    id: row.get::<i64, _>("id") as i32,
+                    tier: EmergencyTier::from_i32(row.get::<i64, _>("tier") as i32)?,
+                    activated_by: row.get("activated_by"),
+                    reason: row.get("reason"),
+                    activated_at: row.get::<DateTime<Utc>, _>("activated_at"),
+                    expires_at: row.get::<DateTime<Utc>, _>("expires_at"),
+                    extended: row.get::<Option<bool>, _>("extended").unwrap_or(false),
+                    extension_count: row.get::<Option<i64>, _>("extension_count").unwrap_or(0)
+                        as u32,
+                })
+            })
+            .collect()
+    }
+
+    async fn mark_inactive(&self, emergency_id: i32, reason: &str) -> Result<(), GovernanceError> {
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            UPDATE emergency_tiers
+            SET active = false, deactivated_at = ?, deactivation_reason = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(now)
+        .bind(reason)
+        .bind(now)
+        .bind(emergency_id)
+        .execute(&self.pool()?)
+        .await?;
+        Ok(())
+    }
+
+    /// Record a lifecycle event in the emergency and audit logs and on Nostr
+    ///
+    /// Failures are logged rather than returned: the state change has
+    /// already been committed.
+    async fn record_event(&self, emergency: &ActiveEmergency, event: &str, actor: &str) {
+        let metadata = serde_json::json!({
+            "tier": emergency.tier.to_i32(),
+            "tier_name": emergency.tier.name(),
+            "reason": emergency.reason,
+            "activated_by": emergency.activated_by,
+            "expires_at": emergency.expires_at,
+            "extension_count": emergency.extension_count,
+        });
+
+        if let Ok(pool) = &self.pool() {
+            if let Err(e) = sqlx::query(
+                "INSERT INTO emergency_audit_log (emergency_tier_id, event_type, event_data, actor) VALUES (?, ?, ?, ?)",
+            )
+            .bind(emergency.id)
+            .bind(event)
+            .bind(metadata.to_string())
+            .bind(actor)
+            .execute(pool)
+            .await
+            {
+                warn!("Failed to record emergency {} {}: {}", emergency.id, event, e);
+            }
+        }
+
+        if let Some(logger) = shared_logger() {
+            if let Err(e) = logger
+                .log_action(
+                    AuditCategory::GovernanceAction,
+                    actor,
+                    Some(&format!("emergency:{}", emergency.id)),
+                    &format!("emergency_{}", event),
+                    metadata,
+                )
+                .await
+            {
+                warn!(
+                    "Failed to audit-log emergency {} {}: {}",
+                    emergency.id, event, e
+                );
+            }
+        }
+
+        if let Some(publisher) = &self.publisher {
+            if let Err(e) = Self::publish(publisher, emergency, event).await {
+                warn!(
+                    "Failed to publish emergency {} {}: {}",
+                    emergency.id, event, e
+                );
+            }
+        }
+    }
+
+    async fn publish(
+        publisher: &GovernanceActionPublisher,
+        emergency: &ActiveEmergency,
+        event: &str,
+    ) -> anyhow::Result<()> {
+        let (required, total) = emergency.tier.signature_threshold();
+        let signatures = format!("{}-of-{}", required, total);
+        let review_days = emergency.tier.review_period_days();
+        let tier = emergency.tier.to_i32() as u32;
+
+        publisher
+            .publish_action(
+                &format!("emergency_{}", event),
+                tier,
+                0,
+                "governance",
+                &signatures,
+                review_days,
+                None,
+                None,
+                &format!(
+                    "{} {} {}: {}",
+                    emergency.tier.emoji(),
+                    emergency.tier.name(),
+                    event,
+                    emergency.reason
+                ),
+                LayerRequirement {
+                    layer: 0,
+                    signatures: signatures.clone(),
+                    review_days,
+                },
+                TierRequirement {
+                    tier,
+                    signatures: signatures.clone(),
+                    review_days,
+                    economic_veto: false,
+                },
+                CombinedRequirement {
+                    signatures: signatures.clone(),
+                    review_days,
+                    economic_veto: false,
+                    source: "emergency".to_string(),
+                },
+                Vec::new(),
+                EconomicVetoStatus::NotRequired,
+                Some(emergency.expires_at),
+            )
+            .await
+    }
+}
+
+/// Hex public key in a comparable form
+fn normalize_key(key: &str) -> String {
+    key.trim().trim_start_matches("0x").to_lowercase()
+}
+
+/// Periodically deactivate emergencies that have passed their expiry
+pub fn spawn_expiry_task(manager: Arc<EmergencyManager>, shutdown: &Shutdown) {
+    shutdown.spawn("emergency_expiry", |token| async move {
+        let mut interval = tokio::time::interval(EXPIRY_CHECK_INTERVAL);
+        while shutdown::next_tick(&token, &mut interval).await {
+            if let Err(e) = manager.expire_overdue().await {
+                error!("Failed to expire emergencies: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+
+    fn keyholder_key(i: usize) -> String {
+        format!("02{:064x}", i + 1)
+    }
+
+    fn signatures(count: usize) -> Vec<KeyholderSignature> {
+        (0..count)
+            .map(|i| KeyholderSignature {
+                keyholder: format!("keyholder{}", i),
+                public_key: keyholder_key(i),
+                signature: "00".repeat(64),
+                timestamp: Utc::now(),
+            })
+            .collect()
+    }
+
+    /// Manager over a database with seven registered keyholders
+    async fn manager() -> EmergencyManager {
+        let manager = EmergencyManager::new(Database::new_in_memory().await.unwrap());
+        for i in 0..7 {
+            sqlx::query(
+                "INSERT INTO emergency_keyholders (github_username, public_key) VALUES (?, ?)",
+            )
+            .bind(format!("keyholder{}", i))
+            .bind(keyholder_key(i))
+            .execute(&manager.pool().unwrap())
+            .await
+            .unwrap();
+        }
+        manager
+    }
+
+    async fn insert_emergency(
+        manager: &EmergencyManager,
+        tier: EmergencyTier,
+        expires_at: DateTime<Utc>,
+        extension_count: i64,
+    ) -> i32 {
+        let id: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO emergency_tiers
+            (tier, activated_by, reason, evidence, activated_at, expires_at, active, extension_count)
+            VALUES (?, 'alice', 'test', 'evidence', ?, ?, true, ?)
+            RETURNING id
+            "#,
+        )
+        .bind(tier.to_i32())
+        .bind(Utc::now() - ChronoDuration::days(1))
+        .bind(expires_at)
+        .bind(extension_count)
+        .fetch_one(&manager.pool().unwrap())
+        .await
+        .unwrap();
+        id as i32
+    }
+
+    #[tokio::test]
+    async fn test_activation_below_threshold_rejected() {
+        let manager = manager().await;
+        let mut activation = EmergencyActivation {
+            tier: EmergencyTier::Critical,
+            activated_by: "alice".to_string(),
+            reason: "Inflation bug".to_string(),
+            evidence: "x".repeat(200),
+            signatures: signatures(4),
+        };
+        let err = manager.activate(&activation).await.unwrap_err();
+        assert!(err.to_string().contains("5-of-7"));
+
+        // Duplicate signatures from one keyholder only count once
+        activation.signatures = vec![signatures(1)[0].clone(); 5];
+        assert!(manager.activate(&activation).await.is_err());
+        assert!(manager.active_emergency().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_activation_requires_registered_keys() {
+        let manager = manager().await;
+        let mut activation = EmergencyActivation {
+            tier: EmergencyTier::Critical,
+            activated_by: "alice".to_string(),
+            reason: "Inflation bug".to_string(),
+            evidence: "x".repeat(200),
+            signatures: signatures(5),
+        };
+
+        // A keyholder's name with a key of the signer's choosing
+        activation.signatures[0].public_key = "03".repeat(33);
+        let err = manager.activate(&activation).await.unwrap_err();
+        assert!(err.to_string().contains("registered key"));
+
+        // Someone who isn't a keyholder
+        activation.signatures[0] = KeyholderSignature {
+            keyholder: "mallory".to_string(),
+            ..signatures(1)[0].clone()
+        };
+        let err = manager.activate(&activation).await.unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("not an active emergency keyholder"));
+        assert!(manager.active_emergency().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_extension_limits_enforced() {
+        let manager = manager().await;
+        let expires_at = Utc::now() + ChronoDuration::days(10);
+
+        let critical = insert_emergency(&manager, EmergencyTier::Critical, expires_at, 0).await;
+        let err = manager
+            .extend(critical, "alice", "more time", &signatures(6))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("not allowed"));
+        manager
+            .deactivate(critical, "alice", "resolved")
+            .await
+            .unwrap();
+
+        let urgent = insert_emergency(&manager, EmergencyTier::Urgent, expires_at, 0).await;
+        assert!(manager
+            .extend(urgent, "alice", "more time", &signatures(5))
+            .await
+            .is_err());
+        let extended = manager
+            .extend(urgent, "alice", "more time", &signatures(6))
+            .await
+            .unwrap();
+        assert_eq!(extended.extension_count, 1);
+        assert_eq!(extended.expires_at, expires_at + ChronoDuration::days(30));
+
+        // Urgent emergencies allow a single extension
+        let err = manager
+            .extend(urgent, "alice", "more time", &signatures(6))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Maximum extensions reached"));
+    }
+
+    #[tokio::test]
+    async fn test_auto_expiry_restores_normal_review_period() {
+        let manager = manager().await;
+        let id = insert_emergency(
+            &manager,
+            EmergencyTier::Critical,
+            Utc::now() + ChronoDuration::days(1),
+            0,
+        )
+        .await;
+
+        let active = manager.active_emergency().await.unwrap();
+        assert_eq!(active.as_ref().map(|e| e.id), Some(id));
+        assert_eq!(
+            EmergencyManager::effective_review_days(90, active.as_ref()),
+            0
+        );
+
+        sqlx::query("UPDATE emergency_tiers SET expires_at = ? WHERE id = ?")
+            .bind(Utc::now() - ChronoDuration::minutes(1))
+            .bind(id)
+            .execute(&manager.pool().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(manager.expire_overdue().await.unwrap(), vec![id]);
+
+        let active = manager.active_emergency().await.unwrap();
+        assert!(active.is_none());
+        assert_eq!(
+            EmergencyManager::effective_review_days(90, active.as_ref()),
+            90
+        );
+    }
+}
@@ -1,3 +1,5 @@
+pub mod api;
 pub mod decision_log;
+pub mod emergency_manager;
 pub mod merge_block;
 pub mod status_checks;
@@ -1,5 +1,5 @@
-use crate::validation::review_period::ReviewPeriodValidator;
-use crate::validation::threshold::ThresholdValidator;
+use crate::validation::review_period::{ReviewPeriodValidator, ReviewTimeline};
+use crate::validation::threshold::{SignatureApprovals, ThresholdValidator};
 use chrono::{DateTime, Utc};
 
 pub struct StatusCheckGenerator;
@@ -48,6 +48,49 @@ impl StatusCheckGenerator {
         }
     }
 
+    /// Review period status counting only unpaused time on the PR's timeline
+    pub fn generate_review_clock_status(
+        timeline: &ReviewTimeline,
+        required_days: i64,
+        emergency_mode: bool,
+        dry_run: bool,
+    ) -> String {
+        let now = Utc::now();
+        let prefix = if dry_run { "[DRY-RUN] " } else { "" };
+        // Emergency mode reduces review period to 30 days
+        let required_days = if emergency_mode { 30 } else { required_days };
+        let required = chrono::Duration::try_days(required_days).unwrap_or_default();
+        let accumulated = timeline.accumulated(now);
+        let accumulated_days = accumulated.num_days();
+
+        if accumulated >= required {
+            return format!(
+                "{}✅ Governance: Review Period Met\nAccumulated: {} of {} unpaused days",
+                prefix, accumulated_days, required_days
+            );
+        }
+
+        let pauses = timeline.active_pauses(now);
+        if pauses.is_empty() {
+            let earliest_merge = timeline
+                .earliest_merge_date(required, now)
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            format!(
+                "{}❌ Governance: Review Period Not Met\nRequired: {} unpaused days | Accumulated: {} days\nEarliest merge: {}",
+                prefix, required_days, accumulated_days, earliest_merge
+            )
+        } else {
+            format!(
+                "{}⏸️ Governance: Review Period Paused\nRequired: {} unpaused days | Accumulated: {} days\nPaused for: {}",
+                prefix,
+                required_days,
+                accumulated_days,
+                pauses.join(", ")
+            )
+        }
+    }
+
     pub fn generate_signature_status(
         current_signatures: usize,
         required_signatures: usize,
@@ -89,6 +132,34 @@ impl StatusCheckGenerator {
         }
     }
 
+    /// Signature status counting both comment and signed-commit approvals
+    ///
+    /// `rejected_commits` are status lines for commit signatures that didn't
+    /// count (unverified, unknown or revoked key), so maintainers can see why.
+    pub fn generate_signature_status_with_sources(
+        approvals: &SignatureApprovals,
+        required_signatures: usize,
+        total_maintainers: usize,
+        pending: &[String],
+        rejected_commits: &[String],
+    ) -> String {
+        let mut status = if approvals.count() >= required_signatures {
+            "✅ Governance: Signatures Complete".to_string()
+        } else {
+            ThresholdValidator::format_threshold_status_with_sources(
+                approvals,
+                required_signatures,
+                total_maintainers,
+                pending,
+            )
+        };
+        for rejected in rejected_commits {
+            status.push('\n');
+            status.push_str(rejected);
+        }
+        status
+    }
+
     pub fn generate_combined_status(
         review_period_met: bool,
         signatures_met: bool,
@@ -235,6 +306,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_signature_status_with_sources() {
+        let approvals = SignatureApprovals {
+            comment_signers: vec!["alice".to_string(), "bob".to_string()],
+            commit_signers: vec!["bob".to_string(), "carol".to_string()],
+        };
+        let status = StatusCheckGenerator::generate_signature_status_with_sources(
+            &approvals,
+            4,
+            5,
+            &["dave".to_string()],
+            &["❌ Commit abc1234 signed with revoked key ABCD (erin)".to_string()],
+        );
+
+        assert!(status.contains("Current: 3/5"), "Signers counted once");
+        assert!(status.contains("alice (via comment)"));
+        assert!(status.contains("bob (via comment, signed commit)"));
+        assert!(status.contains("carol (via signed commit)"));
+        assert!(status.contains("revoked key"));
+    }
+
     #[test]
     fn test_generate_combined_status_all_met() {
         let status = StatusCheckGenerator::generate_combined_status(
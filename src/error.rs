@@ -67,6 +67,9 @@ pub enum GovernanceError {
 
     #[error("Build orchestration error: {0}")]
     BuildError(String),
+
+    #[error("BTC price unavailable: {0}")]
+    PriceUnavailable(String),
 }
 
 // Type alias for compatibility with emergency module
@@ -113,6 +116,19 @@ impl GovernanceError {
     pub fn emergency_expired(id: i32) -> Self {
         Self::ValidationError(format!("Emergency tier {} has expired", id))
     }
+
+    pub fn time_lock_active(
+        change_id: &str,
+        remaining: &str,
+        lock_end: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        Self::ValidationError(format!(
+            "Change {} is time-locked for another {} (until {})",
+            change_id,
+            remaining,
+            lock_end.to_rfc3339()
+        ))
+    }
 }
 
 // Helper functions that match emergency.rs error constructors
@@ -0,0 +1,211 @@
+//! Live vs dry-run execution
+//!
+//! With `dry_run_mode` set, side-effecting subsystems (backups, OTS anchoring,
+//! Nostr publishing, GitHub writes) skip the action and record what they would
+//! have done instead: a `DryRun` audit event, a per-subsystem counter shown on
+//! `/status`, and an entry in a short in-memory history.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::audit::{shared_logger, AuditCategory};
+
+/// Suppressed actions kept in memory for inspection
+const RECENT_SUPPRESSED_LIMIT: usize = 100;
+
+/// Whether side effects are performed or only recorded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionMode {
+    #[default]
+    Live,
+    DryRun,
+}
+
+impl ExecutionMode {
+    pub fn from_dry_run(dry_run: bool) -> Self {
+        if dry_run {
+            Self::DryRun
+        } else {
+            Self::Live
+        }
+    }
+
+    pub fn is_dry_run(self) -> bool {
+        self == Self::DryRun
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Live => "live",
+            Self::DryRun => "dry_run",
+        }
+    }
+}
+
+/// Subsystem whose side effects are suppressed in dry-run mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Subsystem {
+    Backup,
+    Ots,
+    Nostr,
+    GitHub,
+}
+
+impl Subsystem {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Backup => "backup",
+            Self::Ots => "ots",
+            Self::Nostr => "nostr",
+            Self::GitHub => "github",
+        }
+    }
+}
+
+/// An action skipped in dry-run mode
+#[derive(Debug, Clone, Serialize)]
+pub struct SuppressedAction {
+    pub timestamp: DateTime<Utc>,
+    pub subsystem: Subsystem,
+    pub action: String,
+    pub target: Option<String>,
+    pub details: serde_json::Value,
+}
+
+struct SuppressedLog {
+    counts: BTreeMap<Subsystem, u64>,
+    recent: VecDeque<SuppressedAction>,
+}
+
+static SUPPRESSED: Mutex<SuppressedLog> = Mutex::new(SuppressedLog {
+    counts: BTreeMap::new(),
+    recent: VecDeque::new(),
+});
+
+/// Record that `subsystem` would have performed `action` on `target`
+///
+/// The intent is written to the audit log when one is registered; failing to
+/// write it is logged but never fails the caller.
+pub async fn record_suppressed(
+    subsystem: Subsystem,
+    action: &str,
+    target: Option<&str>,
+    details: serde_json::Value,
+) {
+    info!(
+        "[DRY-RUN] {} would have performed {}{}",
+        subsystem.as_str(),
+        action,
+        target.map(|t| format!(" on {}", t)).unwrap_or_default()
+    );
+
+    {
+        let mut log = SUPPRESSED.lock().unwrap_or_else(|e| e.into_inner());
+        *log.counts.entry(subsystem).or_insert(0) += 1;
+        if log.recent.len() == RECENT_SUPPRESSED_LIMIT {
+            log.recent.pop_front();
+        }
+        log.recent.push_back(SuppressedAction {
+            timestamp: Utc::now(),
+            subsystem,
+            action: action.to_string(),
+            target: target.map(str::to_string),
+            details: details.clone(),
+        });
+    }
+
+    if let Some(logger) = shared_logger() {
+        let metadata = serde_json::json!({
+            "subsystem": subsystem,
+            "details": details,
+        });
+        if let Err(e) = logger
+            .log_action(
+                AuditCategory::DryRun,
+                "dry-run",
+                target,
+                &format!("{}.{}", subsystem.as_str(), action),
+                metadata,
+            )
+            .await
+        {
+            warn!("Failed to audit suppressed {} action: {}", action, e);
+        }
+    }
+}
+
+/// Actions suppressed since start, per subsystem
+pub fn suppressed_counts() -> BTreeMap<&'static str, u64> {
+    let log = SUPPRESSED.lock().unwrap_or_else(|e| e.into_inner());
+    log.counts
+        .iter()
+        .map(|(subsystem, count)| (subsystem.as_str(), *count))
+        .collect()
+}
+
+/// Most recently suppressed actions, oldest first
+pub fn recent_suppressed() -> Vec<SuppressedAction> {
+    let log = SUPPRESSED.lock().unwrap_or_else(|e| e.into_inner());
+    log.recent.iter().cloned().collect()
+}
+
+/// Suppressed actions recorded against `target`, oldest first
+pub fn suppressed_for(target: &str) -> Vec<SuppressedAction> {
+    recent_suppressed()
+        .into_iter()
+        .filter(|action| action.target.as_deref() == Some(target))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{set_shared_logger, AuditFilter, AuditLogger};
+
+    #[test]
+    fn test_from_dry_run() {
+        assert_eq!(ExecutionMode::from_dry_run(true), ExecutionMode::DryRun);
+        assert_eq!(ExecutionMode::from_dry_run(false), ExecutionMode::Live);
+        assert_eq!(ExecutionMode::default(), ExecutionMode::Live);
+    }
+
+    #[tokio::test]
+    async fn test_record_suppressed_is_audited() {
+        let log_path =
+            std::env::temp_dir().join(format!("dry-run-audit-{}.jsonl", uuid::Uuid::new_v4()));
+        set_shared_logger(AuditLogger::new(log_path.to_string_lossy().to_string()).unwrap());
+        let target = format!("test-target-{}", uuid::Uuid::new_v4());
+        let before = suppressed_counts().get("nostr").copied().unwrap_or(0);
+
+        record_suppressed(
+            Subsystem::Nostr,
+            "publish_action",
+            Some(&target),
+            serde_json::json!({ "action": "merge" }),
+        )
+        .await;
+
+        assert!(suppressed_counts()["nostr"] > before);
+        let recorded = suppressed_for(&target);
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].action, "publish_action");
+
+        let events = shared_logger()
+            .unwrap()
+            .search(AuditFilter {
+                category: Some(AuditCategory::DryRun),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(events
+            .iter()
+            .any(|event| event.target.as_deref() == Some(target.as_str())
+                && event.action == "nostr.publish_action"));
+    }
+}
@@ -332,7 +332,7 @@ mod tests {
     async fn test_fork_detection() {
         let _temp_dir = tempdir().unwrap();
         let db = Database::new_in_memory().await.unwrap();
-        let pool = db.pool().expect("Database should have SQLite pool").clone();
+        let pool = db.pool().expect("Database should have SQLite pool");
         let adoption_tracker = AdoptionTracker::new(pool);
         let mut detector = ForkDetector::new(adoption_tracker, None);
 
@@ -345,7 +345,7 @@ mod tests {
     async fn test_threshold_checking() {
         let _temp_dir = tempdir().unwrap();
         let db = Database::new_in_memory().await.unwrap();
-        let pool = db.pool().expect("Database should have SQLite pool").clone();
+        let pool = db.pool().expect("Database should have SQLite pool");
         let adoption_tracker = AdoptionTracker::new(pool);
         let detector = ForkDetector::new(adoption_tracker, None);
 
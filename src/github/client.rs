@@ -5,6 +5,7 @@ use std::sync::Arc;
 use tracing::{error, info, warn};
 
 use crate::error::GovernanceError;
+use crate::execution_mode::{record_suppressed, ExecutionMode, Subsystem};
 use crate::github::types::{CheckRun, WorkflowStatus};
 
 #[derive(Clone)]
@@ -14,6 +15,7 @@ pub struct GitHubClient {
     http_client: ReqwestClient,
     /// Circuit breaker for GitHub API calls
     circuit_breaker: Arc<crate::resilience::CircuitBreaker>,
+    execution_mode: ExecutionMode,
 }
 
 impl GitHubClient {
@@ -48,6 +50,7 @@ impl GitHubClient {
                 success_threshold: 2,
                 timeout: std::time::Duration::from_secs(60),
                 window_duration: std::time::Duration::from_secs(60),
+                reset_failures_on_success: false,
             },
         ));
 
@@ -56,9 +59,17 @@ impl GitHubClient {
             app_id,
             http_client,
             circuit_breaker,
+            execution_mode: ExecutionMode::Live,
         })
     }
 
+    /// In dry-run mode write calls (statuses, comments, labels, reviewers,
+    /// branch protection, dispatches, release assets) are recorded instead of sent
+    pub fn with_execution_mode(mut self, execution_mode: ExecutionMode) -> Self {
+        self.execution_mode = execution_mode;
+        self
+    }
+
     /// Post a status check to GitHub
     pub async fn post_status_check(
         &self,
@@ -81,6 +92,17 @@ impl GitHubClient {
             warn!("SHA '{}' may be invalid (expected 40 hex characters)", sha);
         }
 
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::GitHub,
+                "post_status_check",
+                Some(&format!("{}/{}@{}", owner, repo, sha)),
+                json!({ "state": state, "description": description, "context": context }),
+            )
+            .await;
+            return Ok(());
+        }
+
         info!(
             "Posting status check for {}/{}@{}: {} - {} ({})",
             owner, repo, sha, state, description, context
@@ -150,6 +172,17 @@ impl GitHubClient {
         state: &str,
         description: &str,
     ) -> Result<(), GovernanceError> {
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::GitHub,
+                "update_status_check",
+                Some(&format!("{}/{}/checks/{}", owner, repo, check_run_id)),
+                json!({ "state": state, "description": description }),
+            )
+            .await;
+            return Ok(());
+        }
+
         info!(
             "Updating status check for {}/{} (ID: {}): {} - {}",
             owner, repo, check_run_id, state, description
@@ -315,6 +348,184 @@ impl GitHubClient {
         }))
     }
 
+    /// List the paths changed by a pull request
+    pub async fn list_pull_request_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+    ) -> Result<Vec<String>, GovernanceError> {
+        info!("Listing changed files for {}/{}#{}", owner, repo, pr_number);
+
+        let first_page = self
+            .client
+            .pulls(owner, repo)
+            .list_files(pr_number)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to list files for {}/{}#{}: {}",
+                    owner, repo, pr_number, e
+                );
+                GovernanceError::GitHubError(format!(
+                    "Failed to list files for {}/{}#{}: {}",
+                    owner, repo, pr_number, e
+                ))
+            })?;
+
+        let files = self.client.all_pages(first_page).await.map_err(|e| {
+            GovernanceError::GitHubError(format!(
+                "Failed to page through files for {}/{}#{}: {}",
+                owner, repo, pr_number, e
+            ))
+        })?;
+
+        Ok(files.into_iter().map(|f| f.filename).collect())
+    }
+
+    /// Request reviews on a pull request from the given GitHub users
+    pub async fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        reviewers: Vec<String>,
+    ) -> Result<(), GovernanceError> {
+        if reviewers.is_empty() {
+            return Ok(());
+        }
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::GitHub,
+                "request_reviewers",
+                Some(&format!("{}/{}#{}", owner, repo, pr_number)),
+                json!({ "reviewers": reviewers }),
+            )
+            .await;
+            return Ok(());
+        }
+
+        info!(
+            "Requesting reviews on {}/{}#{} from {:?}",
+            owner, repo, pr_number, reviewers
+        );
+
+        self.client
+            .pulls(owner, repo)
+            .request_reviews(pr_number, reviewers, Vec::<String>::new())
+            .await
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!(
+                    "Failed to request reviews on {}/{}#{}: {}",
+                    owner, repo, pr_number, e
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    /// Comment on an issue or pull request
+    pub async fn create_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: u64,
+        body: &str,
+    ) -> Result<(), GovernanceError> {
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::GitHub,
+                "create_issue_comment",
+                Some(&format!("{}/{}#{}", owner, repo, issue_number)),
+                json!({ "body": body }),
+            )
+            .await;
+            return Ok(());
+        }
+
+        self.client
+            .issues(owner, repo)
+            .create_comment(issue_number, body)
+            .await
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!(
+                    "Failed to comment on {}/{}#{}: {}",
+                    owner, repo, issue_number, e
+                ))
+            })?;
+
+        Ok(())
+    }
+
+    /// Label a pull request with its governance tier
+    ///
+    /// Any stale `tier-*` labels from an earlier classification are removed so
+    /// the PR only ever carries one tier label.
+    pub async fn set_tier_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        tier: u32,
+    ) -> Result<(), GovernanceError> {
+        let label = crate::validation::tier_classification::tier_label(tier);
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::GitHub,
+                "set_tier_label",
+                Some(&format!("{}/{}#{}", owner, repo, pr_number)),
+                json!({ "label": label }),
+            )
+            .await;
+            return Ok(());
+        }
+        let issues = self.client.issues(owner, repo);
+
+        let current = issues
+            .list_labels_for_issue(pr_number)
+            .send()
+            .await
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!(
+                    "Failed to list labels for {}/{}#{}: {}",
+                    owner, repo, pr_number, e
+                ))
+            })?;
+
+        let mut has_label = false;
+        for existing in current.items {
+            if existing.name == label {
+                has_label = true;
+            } else if existing.name.starts_with("tier-") {
+                issues
+                    .remove_label(pr_number, &existing.name)
+                    .await
+                    .map_err(|e| {
+                        GovernanceError::GitHubError(format!(
+                            "Failed to remove label {} from {}/{}#{}: {}",
+                            existing.name, owner, repo, pr_number, e
+                        ))
+                    })?;
+            }
+        }
+
+        if !has_label {
+            issues
+                .add_labels(pr_number, &[label.clone()])
+                .await
+                .map_err(|e| {
+                    error!("Failed to label {}/{}#{}: {}", owner, repo, pr_number, e);
+                    GovernanceError::GitHubError(format!(
+                        "Failed to add label {} to {}/{}#{}: {}",
+                        label, owner, repo, pr_number, e
+                    ))
+                })?;
+        }
+
+        info!("Labelled {}/{}#{} as {}", owner, repo, pr_number, label);
+        Ok(())
+    }
+
     /// Set required status checks for a branch
     pub async fn set_required_status_checks(
         &self,
@@ -323,6 +534,17 @@ impl GitHubClient {
         branch: &str,
         contexts: &[String],
     ) -> Result<(), GovernanceError> {
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::GitHub,
+                "set_required_status_checks",
+                Some(&format!("{}/{}:{}", owner, repo, branch)),
+                json!({ "contexts": contexts }),
+            )
+            .await;
+            return Ok(());
+        }
+
         info!(
             "Setting required status checks for {}/{} branch '{}': {:?}",
             owner, repo, branch, contexts
@@ -417,6 +639,28 @@ impl GitHubClient {
         Ok(can_merge)
     }
 
+    /// Get a commit, including GitHub's signature verification data
+    pub async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+    ) -> Result<serde_json::Value, GovernanceError> {
+        info!("Getting commit {}/{}@{}", owner, repo, sha);
+
+        let route = format!("/repos/{}/{}/commits/{}", owner, repo, sha);
+        self.client
+            .get::<serde_json::Value, _, ()>(route, None)
+            .await
+            .map_err(|e| {
+                error!("Failed to get commit {}/{}@{}: {}", owner, repo, sha, e);
+                GovernanceError::GitHubError(format!(
+                    "Failed to get commit {}/{}@{}: {}",
+                    owner, repo, sha, e
+                ))
+            })
+    }
+
     /// Get check runs for a commit SHA
     pub async fn get_check_runs(
         &self,
@@ -537,6 +781,17 @@ impl GitHubClient {
         event_type: &str,
         client_payload: &serde_json::Value,
     ) -> Result<u64, GovernanceError> {
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::GitHub,
+                "trigger_workflow",
+                Some(&format!("{}/{}", owner, repo)),
+                json!({ "event_type": event_type, "client_payload": client_payload }),
+            )
+            .await;
+            return Ok(0);
+        }
+
         info!(
             "Triggering workflow for {}/{} via repository_dispatch (event: {})",
             owner, repo, event_type
@@ -792,6 +1047,21 @@ impl GitHubClient {
         asset_data: &[u8],
         content_type: &str,
     ) -> Result<(), GovernanceError> {
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::GitHub,
+                "upload_release_asset",
+                Some(&format!("{}/{}/releases/{}", owner, repo, release_id)),
+                json!({
+                    "asset_name": asset_name,
+                    "bytes": asset_data.len(),
+                    "content_type": content_type,
+                }),
+            )
+            .await;
+            return Ok(());
+        }
+
         info!(
             "Uploading asset '{}' to release {} in {}/{} ({} bytes, type: {})",
             asset_name,
@@ -2,11 +2,25 @@
 //!
 //! This module provides utilities for fetching file content and directory structures
 //! from GitHub repositories via the GitHub API.
+//!
+//! Requests are retried on transient failures (5xx, rate limits) and revalidated
+//! with ETags so repeated fetches of unchanged content don't consume rate limit.
 
 use crate::error::GovernanceError;
+use crate::resilience::{retry_with_backoff, RetryAction, RetryConfig};
 use base64::{engine::general_purpose, Engine as _};
-use octocrab::Octocrab;
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, ACCEPT, ETAG, IF_NONE_MATCH, USER_AGENT};
+use reqwest::StatusCode;
+use serde::Serialize;
+use serde_json::Value;
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::Semaphore;
 use tracing::{debug, error, info, warn};
 
 /// Represents a file in a GitHub repository
@@ -28,6 +42,92 @@ pub struct GitHubDirectory {
     pub total_size: u64,
 }
 
+impl GitHubDirectory {
+    /// All files in this directory and its subdirectories, depth-first
+    pub fn flatten_files(&self) -> Vec<&GitHubFile> {
+        let mut files: Vec<&GitHubFile> = self.files.iter().collect();
+        for subdirectory in &self.subdirectories {
+            files.extend(subdirectory.flatten_files());
+        }
+        files
+    }
+}
+
+/// Limits applied when recursively fetching a directory tree
+#[derive(Debug, Clone, Copy)]
+pub struct TreeFetchLimits {
+    /// Deepest nesting level to descend into (0 = only the requested directory)
+    pub max_depth: usize,
+    /// Maximum combined size in bytes of all files in the tree
+    pub max_total_size: u64,
+}
+
+impl Default for TreeFetchLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 10,
+            max_total_size: 100 * 1024 * 1024,
+        }
+    }
+}
+
+/// File entry from the Git Trees API
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TreeEntry {
+    pub path: String,
+    pub sha: String,
+    pub size: u64,
+}
+
+/// Differences between two repository trees, each list sorted by path
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TreeDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<String>,
+}
+
+impl TreeDiff {
+    /// Whether the two trees are identical
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Diff two trees by path and blob SHA
+///
+/// Paths only in `b` are added, paths only in `a` are removed, and paths in
+/// both with different SHAs are changed.
+pub fn compare_trees(a: &[TreeEntry], b: &[TreeEntry]) -> TreeDiff {
+    let a_shas: HashMap<&str, &str> = a
+        .iter()
+        .map(|e| (e.path.as_str(), e.sha.as_str()))
+        .collect();
+    let b_shas: HashMap<&str, &str> = b
+        .iter()
+        .map(|e| (e.path.as_str(), e.sha.as_str()))
+        .collect();
+
+    let mut diff = TreeDiff::default();
+    for (path, sha) in &b_shas {
+        match a_shas.get(path) {
+            None => diff.added.push(path.to_string()),
+            Some(old_sha) if old_sha != sha => diff.changed.push(path.to_string()),
+            Some(_) => {}
+        }
+    }
+    diff.removed = a_shas
+        .keys()
+        .filter(|path| !b_shas.contains_key(*path))
+        .map(|path| path.to_string())
+        .collect();
+
+    diff.added.sort();
+    diff.removed.sort();
+    diff.changed.sort();
+    diff
+}
+
 /// GitHub repository information
 #[derive(Debug, Clone)]
 pub struct GitHubRepo {
@@ -48,21 +148,294 @@ pub struct FileComparison {
     pub content_diff: Option<String>,
 }
 
+/// Default GitHub REST API endpoint
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Maximum number of in-flight requests made by `fetch_multiple_files`
+const MAX_CONCURRENT_FETCHES: usize = 8;
+
+/// Maximum number of responses kept in the ETag cache
+const MAX_CACHED_RESPONSES: usize = 1024;
+
+/// Last rate limit reported by GitHub
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct RateLimitInfo {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset_at: DateTime<Utc>,
+}
+
+/// Point-in-time view of GitHub API usage
+#[derive(Debug, Clone, Serialize)]
+pub struct GitHubApiStatsSnapshot {
+    pub requests: u64,
+    pub retries: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+    pub rate_limit: Option<RateLimitInfo>,
+}
+
+/// GitHub API usage counters, shared by every file operations client
+#[derive(Debug, Default)]
+pub struct GitHubApiStats {
+    requests: AtomicU64,
+    retries: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    rate_limit: Mutex<Option<RateLimitInfo>>,
+}
+
+impl GitHubApiStats {
+    /// Snapshot the current counters
+    pub fn snapshot(&self) -> GitHubApiStatsSnapshot {
+        GitHubApiStatsSnapshot {
+            requests: self.requests.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            cache_misses: self.cache_misses.load(Ordering::Relaxed),
+            rate_limit: *self.rate_limit.lock().unwrap(),
+        }
+    }
+
+    /// Record the rate limit headers from a response, if present
+    fn record_rate_limit(&self, headers: &HeaderMap) {
+        let (Some(limit), Some(remaining), Some(reset)) = (
+            header_u64(headers, "x-ratelimit-limit"),
+            header_u64(headers, "x-ratelimit-remaining"),
+            header_u64(headers, "x-ratelimit-reset"),
+        ) else {
+            return;
+        };
+        let Some(reset_at) = DateTime::from_timestamp(reset as i64, 0) else {
+            return;
+        };
+        *self.rate_limit.lock().unwrap() = Some(RateLimitInfo {
+            limit,
+            remaining,
+            reset_at,
+        });
+    }
+}
+
+/// State carried through one recursive directory fetch
+struct TreeWalk {
+    owner: String,
+    repo: String,
+    branch: String,
+    max_depth: usize,
+    fetch_content: bool,
+    remaining_size: u64,
+    /// Directory listings already fetched during this walk, keyed by path
+    listings: HashMap<String, Value>,
+}
+
+/// Cached response bodies keyed by request URL, with the ETag they were served with
+type EtagCache = Mutex<HashMap<String, (String, Value)>>;
+
+static SHARED_STATS: OnceLock<Arc<GitHubApiStats>> = OnceLock::new();
+static SHARED_CACHE: OnceLock<Arc<EtagCache>> = OnceLock::new();
+
+/// Process-wide GitHub API usage counters
+pub fn shared_api_stats() -> Arc<GitHubApiStats> {
+    SHARED_STATS.get_or_init(Default::default).clone()
+}
+
+fn shared_cache() -> Arc<EtagCache> {
+    SHARED_CACHE.get_or_init(Default::default).clone()
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// How long GitHub asked us to wait, from `Retry-After` or an exhausted rate limit
+fn server_retry_delay(headers: &HeaderMap) -> Option<Duration> {
+    if let Some(secs) = header_u64(headers, "retry-after") {
+        return Some(Duration::from_secs(secs));
+    }
+    if header_u64(headers, "x-ratelimit-remaining") == Some(0) {
+        let reset = header_u64(headers, "x-ratelimit-reset")?;
+        let now = Utc::now().timestamp().max(0) as u64;
+        return Some(Duration::from_secs(reset.saturating_sub(now)));
+    }
+    None
+}
+
+/// Failure of a single GitHub API request
+#[derive(Debug)]
+enum RequestError {
+    /// Transient failure worth retrying, with an optional server-provided delay
+    Transient {
+        message: String,
+        retry_after: Option<Duration>,
+    },
+    /// Permanent failure
+    Fatal(String),
+}
+
+impl std::fmt::Display for RequestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestError::Transient { message, .. } | RequestError::Fatal(message) => {
+                write!(f, "{}", message)
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct GitHubFileOperations {
-    client: Octocrab,
+    http_client: reqwest::Client,
+    token: String,
+    base_url: String,
+    retry_config: RetryConfig,
+    tree_limits: TreeFetchLimits,
+    cache: Arc<EtagCache>,
+    stats: Arc<GitHubApiStats>,
 }
 
 impl GitHubFileOperations {
     /// Create a new GitHub file operations client
     pub fn new(token: String) -> Result<Self, GovernanceError> {
-        let client = Octocrab::builder()
-            .personal_token(token)
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
             .build()
             .map_err(|e| {
                 GovernanceError::GitHubError(format!("Failed to create GitHub client: {}", e))
             })?;
 
-        Ok(Self { client })
+        Ok(Self {
+            http_client,
+            token,
+            base_url: GITHUB_API_URL.to_string(),
+            retry_config: RetryConfig::default(),
+            tree_limits: TreeFetchLimits::default(),
+            cache: shared_cache(),
+            stats: shared_api_stats(),
+        })
+    }
+
+    /// Use a different API endpoint (e.g. GitHub Enterprise)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Set the depth and size limits for recursive directory fetches
+    pub fn with_tree_limits(mut self, tree_limits: TreeFetchLimits) -> Self {
+        self.tree_limits = tree_limits;
+        self
+    }
+
+    /// Set the retry policy for transient failures
+    pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// GET a JSON resource, retrying transient failures
+    ///
+    /// Responses carrying an ETag are cached and revalidated with
+    /// `If-None-Match`; GitHub doesn't count 304 responses against the rate limit.
+    async fn get_json(&self, path: &str, query: &[(&str, &str)]) -> Result<Value, GovernanceError> {
+        let url = reqwest::Url::parse_with_params(&format!("{}{}", self.base_url, path), query)
+            .map_err(|e| GovernanceError::GitHubError(format!("Invalid GitHub URL: {}", e)))?;
+
+        retry_with_backoff(
+            &self.retry_config,
+            || self.try_get_json(&url),
+            |e| match e {
+                RequestError::Transient { retry_after, .. } => RetryAction::Retry(*retry_after),
+                RequestError::Fatal(_) => RetryAction::Fail,
+            },
+            |_, _| {
+                self.stats.retries.fetch_add(1, Ordering::Relaxed);
+            },
+        )
+        .await
+        .map_err(|e| GovernanceError::GitHubError(e.to_string()))
+    }
+
+    async fn try_get_json(&self, url: &reqwest::Url) -> Result<Value, RequestError> {
+        let key = url.as_str();
+        let cached_etag = self
+            .cache
+            .lock()
+            .unwrap()
+            .get(key)
+            .map(|(etag, _)| etag.clone());
+
+        let mut request = self
+            .http_client
+            .get(url.clone())
+            .bearer_auth(&self.token)
+            .header(ACCEPT, "application/vnd.github+json")
+            .header(USER_AGENT, "blvm-commons");
+        if let Some(etag) = &cached_etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        self.stats.requests.fetch_add(1, Ordering::Relaxed);
+        let response = request.send().await.map_err(|e| RequestError::Transient {
+            message: format!("Request to {} failed: {}", key, e),
+            retry_after: None,
+        })?;
+        self.stats.record_rate_limit(response.headers());
+
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED {
+            if let Some((_, body)) = self.cache.lock().unwrap().get(key) {
+                debug!("GitHub cache hit: {}", key);
+                self.stats.cache_hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(body.clone());
+            }
+            // Evicted while the request was in flight; the retry goes out unconditionally
+            return Err(RequestError::Transient {
+                message: format!("Cached response for {} was evicted", key),
+                retry_after: Some(Duration::ZERO),
+            });
+        }
+
+        if status.is_success() {
+            let etag = response
+                .headers()
+                .get(ETAG)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
+            let body: Value = response
+                .json()
+                .await
+                .map_err(|e| RequestError::Fatal(format!("Invalid JSON from {}: {}", key, e)))?;
+            self.stats.cache_misses.fetch_add(1, Ordering::Relaxed);
+
+            if let Some(etag) = etag {
+                let mut cache = self.cache.lock().unwrap();
+                if cache.len() >= MAX_CACHED_RESPONSES && !cache.contains_key(key) {
+                    cache.clear();
+                }
+                cache.insert(key.to_string(), (etag, body.clone()));
+            }
+            return Ok(body);
+        }
+
+        let retry_after = server_retry_delay(response.headers());
+        let message = format!("GitHub API returned {} for {}", status, key);
+        Err(match status.as_u16() {
+            // Primary and secondary rate limits: wait as instructed
+            403 | 429 if retry_after.is_some() => RequestError::Transient {
+                message,
+                retry_after,
+            },
+            429 => RequestError::Transient {
+                message,
+                retry_after: None,
+            },
+            500..=599 => RequestError::Transient {
+                message,
+                retry_after,
+            },
+            _ => RequestError::Fatal(message),
+        })
     }
 
     /// Fetch file content from GitHub repository
@@ -77,33 +450,29 @@ impl GitHubFileOperations {
 
         let branch = branch.unwrap_or("main");
 
-        let response = self
-            .client
-            .repos(owner, repo)
-            .get_content()
-            .path(file_path)
-            .r#ref(branch)
-            .send()
+        let content = self
+            .get_json(
+                &format!("/repos/{}/{}/contents/{}", owner, repo, file_path),
+                &[("ref", branch)],
+            )
             .await
             .map_err(|e| GovernanceError::GitHubError(format!("Failed to fetch file: {}", e)))?;
 
-        // Handle the response - octocrab 0.38 returns ContentItems with items: Vec<Content>
-        // For a single file, items should contain one Content with type "file"
-        let items = response.items;
-        if items.len() != 1 {
+        // The contents API returns an array for directories and an object otherwise
+        if content.is_array() {
             return Err(GovernanceError::GitHubError(format!(
-                "Expected single file, got {} items",
-                items.len()
+                "Path '{}' is a directory, not a file",
+                file_path
             )));
         }
 
-        let content = &items[0];
-        match content.r#type.as_str() {
+        let content_type = content.get("type").and_then(|t| t.as_str()).unwrap_or("");
+        match content_type {
             "file" => {
-                // Decode base64 content
-                let content_bytes = match &content.content {
+                // Decode base64 content (GitHub wraps it at 60 columns)
+                let content_bytes = match content.get("content").and_then(|c| c.as_str()) {
                     Some(encoded) => general_purpose::STANDARD
-                        .decode(encoded.trim_end_matches('\n'))
+                        .decode(encoded.replace('\n', ""))
                         .map_err(|e| {
                             GovernanceError::GitHubError(format!(
                                 "Failed to decode base64 content: {}",
@@ -117,13 +486,7 @@ impl GitHubFileOperations {
                     }
                 };
 
-                Ok(GitHubFile {
-                    path: content.path.clone(),
-                    content: content_bytes,
-                    sha: content.sha.clone(),
-                    size: content.size as u64,
-                    download_url: content.download_url.as_ref().map(|u| u.to_string()),
-                })
+                Ok(github_file(&content, content_bytes))
             }
             "dir" => Err(GovernanceError::GitHubError(format!(
                 "Path '{}' is a directory, not a file",
@@ -139,88 +502,220 @@ impl GitHubFileOperations {
             ))),
             _ => Err(GovernanceError::GitHubError(format!(
                 "Unknown content type: {}",
-                content.r#type
+                content_type
             ))),
         }
     }
 
     /// Fetch directory tree from GitHub repository
+    ///
+    /// Subdirectories are fetched recursively down to the configured maximum
+    /// depth; the fetch fails if the files found exceed the size budget.
+    /// File content is not loaded (see `fetch_directory_tree_with_options`).
     pub async fn fetch_directory_tree(
         &self,
         owner: &str,
         repo: &str,
         directory_path: &str,
         branch: Option<&str>,
+    ) -> Result<GitHubDirectory, GovernanceError> {
+        self.fetch_directory_tree_with_options(
+            owner,
+            repo,
+            directory_path,
+            branch,
+            self.tree_limits.max_depth,
+            false,
+        )
+        .await
+    }
+
+    /// Fetch directory tree with an explicit depth limit, optionally loading file content
+    pub async fn fetch_directory_tree_with_options(
+        &self,
+        owner: &str,
+        repo: &str,
+        directory_path: &str,
+        branch: Option<&str>,
+        max_depth: usize,
+        fetch_content: bool,
     ) -> Result<GitHubDirectory, GovernanceError> {
         info!(
-            "Fetching directory tree: {}/{}:{}",
-            owner, repo, directory_path
+            "Fetching directory tree: {}/{}:{} (max depth {}, content: {})",
+            owner, repo, directory_path, max_depth, fetch_content
         );
 
-        let branch = branch.unwrap_or("main");
+        let mut walk = TreeWalk {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            branch: branch.unwrap_or("main").to_string(),
+            max_depth,
+            fetch_content,
+            remaining_size: self.tree_limits.max_total_size,
+            listings: HashMap::new(),
+        };
 
-        let response = self
-            .client
-            .repos(owner, repo)
-            .get_content()
-            .path(directory_path)
-            .r#ref(branch)
-            .send()
+        self.fetch_directory_level(&mut walk, directory_path, 0)
             .await
-            .map_err(|e| {
-                GovernanceError::GitHubError(format!("Failed to fetch directory: {}", e))
-            })?;
+    }
 
-        // Handle the response - octocrab 0.38 returns ContentItems with items: Vec<Content>
-        // For a directory, items contains multiple Content items
-        let items = response.items;
-        let mut files = Vec::new();
-        let subdirectories = Vec::new();
-        let mut total_size = 0u64;
-
-        // Process each item in the directory
-        for item in items {
-            match item.r#type.as_str() {
-                "file" => {
-                    // For files, create GitHubFile with metadata
-                    // Content can be fetched later if needed via fetch_file_content()
-                    let size = item.size as u64;
-                    total_size += size;
-
-                    files.push(GitHubFile {
-                        path: item.path.clone(),
-                        content: Vec::new(), // Content not loaded by default (can fetch later)
-                        sha: item.sha.clone(),
-                        size,
-                        download_url: item.download_url.as_ref().map(|u| u.to_string()),
-                    });
-                }
-                "dir" => {
-                    // For subdirectories, we can recursively fetch them if needed
-                    // For now, skip nested directories - they can be fetched separately if needed
-                    debug!(
-                        "Skipping nested directory: {} - fetch separately if needed",
-                        item.path
-                    );
-                }
-                "symlink" | "submodule" => {
-                    // Skip symlinks and submodules
-                    debug!("Skipping symlink/submodule in directory: {}", item.path);
+    /// Fetch one directory listing and recurse into its subdirectories
+    fn fetch_directory_level<'a>(
+        &'a self,
+        walk: &'a mut TreeWalk,
+        directory_path: &'a str,
+        depth: usize,
+    ) -> Pin<Box<dyn Future<Output = Result<GitHubDirectory, GovernanceError>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = match walk.listings.get(directory_path) {
+                Some(listing) => listing.clone(),
+                None => {
+                    let listing = self
+                        .get_json(
+                            &format!(
+                                "/repos/{}/{}/contents/{}",
+                                walk.owner, walk.repo, directory_path
+                            ),
+                            &[("ref", walk.branch.as_str())],
+                        )
+                        .await
+                        .map_err(|e| {
+                            GovernanceError::GitHubError(format!(
+                                "Failed to fetch directory: {}",
+                                e
+                            ))
+                        })?;
+                    walk.listings
+                        .insert(directory_path.to_string(), listing.clone());
+                    listing
                 }
-                _ => {
-                    warn!("Unknown content type in directory: {}", item.r#type);
+            };
+
+            let items = response.as_array().ok_or_else(|| {
+                GovernanceError::GitHubError(format!(
+                    "Path '{}' is not a directory",
+                    directory_path
+                ))
+            })?;
+            let mut files = Vec::new();
+            let mut subdirectories = Vec::new();
+            let mut total_size = 0u64;
+
+            // Process each item in the directory
+            for item in items {
+                let path = item.get("path").and_then(|p| p.as_str()).unwrap_or("");
+                match item.get("type").and_then(|t| t.as_str()).unwrap_or("") {
+                    "file" => {
+                        let mut file = github_file(item, Vec::new());
+                        walk.remaining_size =
+                            walk.remaining_size.checked_sub(file.size).ok_or_else(|| {
+                                GovernanceError::GitHubError(format!(
+                                    "Directory tree {}/{}:{} exceeds the {} byte size budget",
+                                    walk.owner,
+                                    walk.repo,
+                                    directory_path,
+                                    self.tree_limits.max_total_size
+                                ))
+                            })?;
+                        if walk.fetch_content {
+                            file.content = self
+                                .fetch_file_content(
+                                    &walk.owner,
+                                    &walk.repo,
+                                    &file.path,
+                                    Some(&walk.branch),
+                                )
+                                .await?
+                                .content;
+                        }
+                        total_size += file.size;
+                        files.push(file);
+                    }
+                    "dir" if depth < walk.max_depth => {
+                        let subdirectory =
+                            self.fetch_directory_level(walk, path, depth + 1).await?;
+                        total_size += subdirectory.total_size;
+                        subdirectories.push(subdirectory);
+                    }
+                    "dir" => {
+                        warn!(
+                            "Not descending into {}: maximum depth {} reached",
+                            path, walk.max_depth
+                        );
+                    }
+                    "symlink" | "submodule" => {
+                        // Skip symlinks and submodules
+                        debug!("Skipping symlink/submodule in directory: {}", path);
+                    }
+                    other => {
+                        warn!("Unknown content type in directory: {}", other);
+                    }
                 }
             }
-        }
 
-        Ok(GitHubDirectory {
-            path: directory_path.to_string(),
-            files,
-            subdirectories,
-            total_size,
+            Ok(GitHubDirectory {
+                path: directory_path.to_string(),
+                files,
+                subdirectories,
+                total_size,
+            })
         })
     }
 
+    /// Fetch the full file listing of a repository in a single call
+    ///
+    /// Uses the Git Trees API with `recursive=1`, which costs one request
+    /// regardless of repository size. Only blobs are returned.
+    pub async fn fetch_repo_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        git_ref: &str,
+    ) -> Result<Vec<TreeEntry>, GovernanceError> {
+        info!("Fetching repository tree: {}/{}@{}", owner, repo, git_ref);
+
+        let response = self
+            .get_json(
+                &format!("/repos/{}/{}/git/trees/{}", owner, repo, git_ref),
+                &[("recursive", "1")],
+            )
+            .await
+            .map_err(|e| GovernanceError::GitHubError(format!("Failed to fetch tree: {}", e)))?;
+
+        // GitHub truncates very large trees; a partial listing would produce bogus diffs
+        if response.get("truncated").and_then(|t| t.as_bool()) == Some(true) {
+            return Err(GovernanceError::GitHubError(format!(
+                "Tree for {}/{}@{} was truncated by GitHub; use fetch_directory_tree instead",
+                owner, repo, git_ref
+            )));
+        }
+
+        let entries = response
+            .get("tree")
+            .and_then(|t| t.as_array())
+            .ok_or_else(|| {
+                GovernanceError::GitHubError("Tree response has no entries".to_string())
+            })?
+            .iter()
+            .filter(|entry| entry.get("type").and_then(|t| t.as_str()) == Some("blob"))
+            .map(|entry| TreeEntry {
+                path: entry
+                    .get("path")
+                    .and_then(|p| p.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+                sha: entry
+                    .get("sha")
+                    .and_then(|s| s.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+                size: entry.get("size").and_then(|s| s.as_u64()).unwrap_or(0),
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
     /// Compute hash of entire repository state
     /// Returns the SHA of the latest commit on the specified branch
     pub async fn compute_repo_hash(
@@ -233,26 +728,22 @@ impl GitHubFileOperations {
 
         let branch = branch.unwrap_or("main");
 
-        // Get the branch reference to get the latest commit SHA
-        // Try using repos().get_branch() - if it doesn't exist, use commits API
-        // For now, use a workaround: get the latest commit from the default branch
         let commits = self
-            .client
-            .repos(owner, repo)
-            .list_commits()
-            .branch(branch)
-            .per_page(1)
-            .send()
+            .get_json(
+                &format!("/repos/{}/{}/commits", owner, repo),
+                &[("sha", branch), ("per_page", "1")],
+            )
             .await
             .map_err(|e| GovernanceError::GitHubError(format!("Failed to get branch: {}", e)))?;
 
         // Extract commit SHA from first commit
         let commit_sha = commits
-            .items
-            .first()
+            .as_array()
+            .and_then(|commits| commits.first())
+            .and_then(|commit| commit.get("sha"))
+            .and_then(|sha| sha.as_str())
             .ok_or_else(|| GovernanceError::GitHubError("No commits found".to_string()))?
-            .sha
-            .clone();
+            .to_string();
 
         info!(
             "Repository hash for {}/{}:{} = {}",
@@ -339,34 +830,40 @@ impl GitHubFileOperations {
     ) -> Result<GitHubRepo, GovernanceError> {
         info!("Getting repository info: {}/{}", owner, repo);
 
-        // Get repository information using octocrab API
-        let repository = self.client.repos(owner, repo).get().await.map_err(|e| {
-            GovernanceError::GitHubError(format!("Failed to get repository info: {}", e))
-        })?;
+        let repository = self
+            .get_json(&format!("/repos/{}/{}", owner, repo), &[])
+            .await
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!("Failed to get repository info: {}", e))
+            })?;
 
         // Get the default branch's latest commit SHA
-        let default_branch = repository.default_branch.as_deref().unwrap_or("main");
+        let default_branch = repository
+            .get("default_branch")
+            .and_then(|b| b.as_str())
+            .unwrap_or("main");
         let last_commit_sha = self
             .compute_repo_hash(owner, repo, Some(default_branch))
             .await?;
 
-        // In octocrab 0.38, owner structure - extract login
-        // Owner is Option<Author>, need to unwrap first
         let owner_name = repository
-            .owner
-            .as_ref()
-            .map(|author| author.login.clone())
-            .unwrap_or_else(|| "unknown".to_string());
+            .pointer("/owner/login")
+            .and_then(|login| login.as_str())
+            .unwrap_or("unknown");
 
         Ok(GitHubRepo {
-            owner: owner_name,
-            name: repository.name.clone(),
+            owner: owner_name.to_string(),
+            name: repository
+                .get("name")
+                .and_then(|n| n.as_str())
+                .unwrap_or(repo)
+                .to_string(),
             default_branch: default_branch.to_string(),
             last_commit_sha,
         })
     }
 
-    /// Fetch multiple files in parallel
+    /// Fetch multiple files in parallel, with a bounded number of requests in flight
     pub async fn fetch_multiple_files(
         &self,
         owner: &str,
@@ -378,23 +875,21 @@ impl GitHubFileOperations {
 
         let mut results = HashMap::new();
         let mut tasks = Vec::new();
+        let permits = Arc::new(Semaphore::new(MAX_CONCURRENT_FETCHES));
 
         for file_path in file_paths {
-            let client = self.client.clone();
+            let ops = self.clone();
+            let permits = permits.clone();
             let owner = owner.to_string();
             let repo = repo.to_string();
             let file_path = file_path.clone();
             let branch = branch.map(|s| s.to_string());
 
             let task = tokio::spawn(async move {
-                match Self::fetch_file_content_static(
-                    &client,
-                    &owner,
-                    &repo,
-                    &file_path,
-                    branch.as_deref(),
-                )
-                .await
+                let _permit = permits.acquire_owned().await.ok()?;
+                match ops
+                    .fetch_file_content(&owner, &repo, &file_path, branch.as_deref())
+                    .await
                 {
                     Ok(file) => Some((file_path, file)),
                     Err(e) => {
@@ -416,87 +911,60 @@ impl GitHubFileOperations {
 
         Ok(results)
     }
+}
 
-    /// Static method for fetching file content (used in async tasks)
-    async fn fetch_file_content_static(
-        client: &Octocrab,
-        owner: &str,
-        repo: &str,
-        file_path: &str,
-        branch: Option<&str>,
-    ) -> Result<GitHubFile, GovernanceError> {
-        let branch = branch.unwrap_or("main");
-
-        let response = client
-            .repos(owner, repo)
-            .get_content()
-            .path(file_path)
-            .r#ref(branch)
-            .send()
-            .await
-            .map_err(|e| GovernanceError::GitHubError(format!("Failed to fetch file: {}", e)))?;
+/// Build a `GitHubFile` from a contents API entry
+fn github_file(entry: &Value, content: Vec<u8>) -> GitHubFile {
+    let text = |field: &str| {
+        entry
+            .get(field)
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string()
+    };
 
-        // Handle the response - octocrab 0.38 returns ContentItems with items: Vec<Content>
-        // For a single file, items should contain one Content with type "file"
-        let items = response.items;
-        if items.len() != 1 {
-            return Err(GovernanceError::GitHubError(format!(
-                "Expected single file, got {} items",
-                items.len()
-            )));
-        }
-
-        let content = &items[0];
-        match content.r#type.as_str() {
-            "file" => {
-                // Decode base64 content
-                let content_bytes = match &content.content {
-                    Some(encoded) => general_purpose::STANDARD
-                        .decode(encoded.trim_end_matches('\n'))
-                        .map_err(|e| {
-                            GovernanceError::GitHubError(format!(
-                                "Failed to decode base64 content: {}",
-                                e
-                            ))
-                        })?,
-                    None => {
-                        return Err(GovernanceError::GitHubError(
-                            "File content is empty".to_string(),
-                        ));
-                    }
-                };
-
-                Ok(GitHubFile {
-                    path: content.path.clone(),
-                    content: content_bytes,
-                    sha: content.sha.clone(),
-                    size: content.size as u64,
-                    download_url: content.download_url.as_ref().map(|u| u.to_string()),
-                })
-            }
-            "dir" => Err(GovernanceError::GitHubError(format!(
-                "Path '{}' is a directory, not a file",
-                file_path
-            ))),
-            "symlink" => Err(GovernanceError::GitHubError(format!(
-                "Path '{}' is a symlink, not a file",
-                file_path
-            ))),
-            "submodule" => Err(GovernanceError::GitHubError(format!(
-                "Path '{}' is a submodule, not a file",
-                file_path
-            ))),
-            _ => Err(GovernanceError::GitHubError(format!(
-                "Unknown content type: {}",
-                content.r#type
-            ))),
-        }
+    GitHubFile {
+        path: text("path"),
+        content,
+        sha: text("sha"),
+        size: entry.get("size").and_then(|s| s.as_u64()).unwrap_or(0),
+        download_url: entry
+            .get("download_url")
+            .and_then(|u| u.as_str())
+            .map(str::to_string),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const README_PATH: &str = "/repos/owner/repo/contents/README.md";
+
+    fn mock_ops(server: &MockServer) -> GitHubFileOperations {
+        GitHubFileOperations::new("test_token".to_string())
+            .unwrap()
+            .with_base_url(server.uri())
+            .with_retry_config(RetryConfig {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(50),
+            })
+    }
+
+    fn readme_body() -> Value {
+        json!({
+            "type": "file",
+            "path": "README.md",
+            "sha": "abc123",
+            "size": 5,
+            "content": general_purpose::STANDARD.encode("hello"),
+            "download_url": null
+        })
+    }
 
     #[tokio::test]
     async fn test_github_file_operations_creation() {
@@ -539,4 +1007,230 @@ mod tests {
         assert_eq!(repo.default_branch, "main");
         assert_eq!(repo.last_commit_sha, "abc123def456");
     }
+
+    #[tokio::test]
+    async fn test_rate_limit_is_retried() {
+        let server = MockServer::start().await;
+        let reset = Utc::now().timestamp().to_string();
+        Mock::given(method("GET"))
+            .and(path(README_PATH))
+            .respond_with(
+                ResponseTemplate::new(403)
+                    .insert_header("x-ratelimit-limit", "5000")
+                    .insert_header("x-ratelimit-remaining", "0")
+                    .insert_header("x-ratelimit-reset", reset.as_str()),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(README_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(readme_body()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let file = mock_ops(&server)
+            .fetch_file_content("owner", "repo", "README.md", None)
+            .await
+            .unwrap();
+        assert_eq!(file.content, b"hello");
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_server_error_then_success() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(README_PATH))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(README_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(readme_body()))
+            .mount(&server)
+            .await;
+
+        let file = mock_ops(&server)
+            .fetch_file_content("owner", "repo", "README.md", None)
+            .await
+            .unwrap();
+        assert_eq!(file.sha, "abc123");
+
+        // Client errors are not retried
+        let missing = mock_ops(&server)
+            .fetch_file_content("owner", "repo", "MISSING.md", None)
+            .await;
+        assert!(missing.is_err());
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_etag_cache_hit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(README_PATH))
+            .and(header("if-none-match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(README_PATH))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("etag", "\"v1\"")
+                    .set_body_json(readme_body()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let ops = mock_ops(&server);
+        let hits_before = ops.stats.snapshot().cache_hits;
+        let first = ops
+            .fetch_file_content("owner", "repo", "README.md", None)
+            .await
+            .unwrap();
+        let second = ops
+            .fetch_file_content("owner", "repo", "README.md", None)
+            .await
+            .unwrap();
+
+        assert_eq!(first.content, second.content);
+        assert!(ops.stats.snapshot().cache_hits > hits_before);
+    }
+
+    fn dir_entry(path: &str, kind: &str, size: u64) -> Value {
+        json!({"type": kind, "path": path, "sha": format!("sha-{}", path), "size": size})
+    }
+
+    #[tokio::test]
+    async fn test_recursive_directory_tree() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/contents/src"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                dir_entry("src/lib.rs", "file", 10),
+                dir_entry("src/nested", "dir", 0),
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/contents/src/nested"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([dir_entry(
+                "src/nested/mod.rs",
+                "file",
+                5
+            ),])))
+            .mount(&server)
+            .await;
+
+        let tree = mock_ops(&server)
+            .fetch_directory_tree("owner", "repo", "src", None)
+            .await
+            .unwrap();
+        assert_eq!(tree.files.len(), 1);
+        assert_eq!(tree.subdirectories.len(), 1);
+        assert_eq!(tree.subdirectories[0].files[0].path, "src/nested/mod.rs");
+        assert_eq!(tree.total_size, 15);
+        let flat: Vec<&str> = tree
+            .flatten_files()
+            .iter()
+            .map(|f| f.path.as_str())
+            .collect();
+        assert_eq!(flat, vec!["src/lib.rs", "src/nested/mod.rs"]);
+
+        // Depth 0 stays in the requested directory
+        let shallow = mock_ops(&server)
+            .with_tree_limits(TreeFetchLimits {
+                max_depth: 0,
+                ..Default::default()
+            })
+            .fetch_directory_tree("owner", "repo", "src", None)
+            .await
+            .unwrap();
+        assert!(shallow.subdirectories.is_empty());
+
+        // Exceeding the size budget fails rather than returning a partial tree
+        let over_budget = mock_ops(&server)
+            .with_tree_limits(TreeFetchLimits {
+                max_depth: 10,
+                max_total_size: 12,
+            })
+            .fetch_directory_tree("owner", "repo", "src", None)
+            .await;
+        assert!(over_budget.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_repo_tree_and_compare() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/git/trees/main"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "sha": "root",
+                "truncated": false,
+                "tree": [
+                    {"path": "src", "type": "tree", "sha": "t1"},
+                    {"path": "src/lib.rs", "type": "blob", "sha": "a1", "size": 10},
+                    {"path": "README.md", "type": "blob", "sha": "b1", "size": 4},
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let main = mock_ops(&server)
+            .fetch_repo_tree("owner", "repo", "main")
+            .await
+            .unwrap();
+        assert_eq!(main.len(), 2);
+
+        let fork = vec![
+            TreeEntry {
+                path: "src/lib.rs".to_string(),
+                sha: "a2".to_string(),
+                size: 12,
+            },
+            TreeEntry {
+                path: "src/fork.rs".to_string(),
+                sha: "c1".to_string(),
+                size: 3,
+            },
+        ];
+        let diff = compare_trees(&main, &fork);
+        assert_eq!(diff.added, vec!["src/fork.rs"]);
+        assert_eq!(diff.removed, vec!["README.md"]);
+        assert_eq!(diff.changed, vec!["src/lib.rs"]);
+        assert!(compare_trees(&main, &main).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_directory_tree_with_content() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/contents/docs"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([dir_entry(
+                "docs/README.md",
+                "file",
+                5
+            )])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/repo/contents/docs/README.md"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(readme_body()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let tree = mock_ops(&server)
+            .fetch_directory_tree_with_options("owner", "repo", "docs", None, 3, true)
+            .await
+            .unwrap();
+        assert_eq!(tree.flatten_files()[0].content, b"hello");
+    }
 }
@@ -3,3 +3,4 @@ pub mod cross_layer_status;
 pub mod file_operations;
 pub mod types;
 pub mod webhooks;
+pub mod write_operations;
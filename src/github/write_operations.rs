@@ -0,0 +1,521 @@
+//! GitHub Write Operations
+//!
+//! Creates branches, commits files and opens pull requests via the GitHub
+//! REST API, e.g. to propose governance YAML changes to the governance repo.
+//! In dry-run mode nothing is written; each operation logs what it would do.
+
+use crate::error::GovernanceError;
+use crate::execution_mode::{record_suppressed, ExecutionMode, Subsystem};
+use base64::{engine::general_purpose, Engine as _};
+use reqwest::header::{ACCEPT, USER_AGENT};
+use reqwest::{Method, StatusCode};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tracing::info;
+
+/// Default GitHub REST API endpoint
+const GITHUB_API_URL: &str = "https://api.github.com";
+
+/// Branch created by `create_branch`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedBranch {
+    pub name: String,
+    /// Commit the branch points at (empty in dry-run mode)
+    pub sha: String,
+    pub dry_run: bool,
+}
+
+/// File written by `put_file`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileCommit {
+    pub path: String,
+    /// Whether the file was newly created rather than updated
+    pub created: bool,
+    /// Commit containing the change (empty in dry-run mode)
+    pub commit_sha: String,
+    /// Blob SHA of the new content (empty in dry-run mode)
+    pub content_sha: String,
+    pub dry_run: bool,
+}
+
+/// Pull request opened by `open_pull_request`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenedPullRequest {
+    /// PR number (0 in dry-run mode)
+    pub number: u64,
+    pub html_url: String,
+    pub dry_run: bool,
+}
+
+/// Write access to GitHub repositories
+#[derive(Clone)]
+pub struct GitHubWriteOperations {
+    http_client: reqwest::Client,
+    token: String,
+    base_url: String,
+    dry_run: bool,
+}
+
+impl GitHubWriteOperations {
+    /// Create a new GitHub write operations client
+    pub fn new(token: String) -> Result<Self, GovernanceError> {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .map_err(|e| {
+                GovernanceError::GitHubError(format!("Failed to create GitHub client: {}", e))
+            })?;
+
+        Ok(Self {
+            http_client,
+            token,
+            base_url: GITHUB_API_URL.to_string(),
+            dry_run: false,
+        })
+    }
+
+    /// Use a different API endpoint (e.g. GitHub Enterprise)
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Skip all writes, logging what would have been done instead
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn with_execution_mode(self, execution_mode: ExecutionMode) -> Self {
+        self.with_dry_run(execution_mode.is_dry_run())
+    }
+
+    /// Send a request, returning the status and JSON body (null if empty)
+    ///
+    /// Writes aren't idempotent, so unlike file fetches they are not retried.
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<(StatusCode, Value), GovernanceError> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request = self
+            .http_client
+            .request(method.clone(), &url)
+            .bearer_auth(&self.token)
+            .header(ACCEPT, "application/vnd.github+json")
+            .header(USER_AGENT, "blvm-commons");
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let response = request.send().await.map_err(|e| {
+            GovernanceError::GitHubError(format!("{} {} failed: {}", method, url, e))
+        })?;
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+        let body = if text.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_str(&text).unwrap_or(Value::String(text))
+        };
+        Ok((status, body))
+    }
+
+    /// Send a request and fail unless it succeeded
+    async fn request_ok(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, GovernanceError> {
+        let (status, response) = self.request(method.clone(), path, body).await?;
+        if !status.is_success() {
+            let message = response
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("no message");
+            return Err(GovernanceError::GitHubError(format!(
+                "{} {} returned {}: {}",
+                method, path, status, message
+            )));
+        }
+        Ok(response)
+    }
+
+    /// Create `branch` pointing at the current head of `from_ref`
+    pub async fn create_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        from_ref: &str,
+    ) -> Result<CreatedBranch, GovernanceError> {
+        if self.dry_run {
+            record_suppressed(
+                Subsystem::GitHub,
+                "create_branch",
+                Some(&format!("{}/{}:{}", owner, repo, branch)),
+                json!({ "from_ref": from_ref }),
+            )
+            .await;
+            return Ok(CreatedBranch {
+                name: branch.to_string(),
+                sha: String::new(),
+                dry_run: true,
+            });
+        }
+
+        let base = self
+            .request_ok(
+                Method::GET,
+                &format!("/repos/{}/{}/git/ref/heads/{}", owner, repo, from_ref),
+                None,
+            )
+            .await?;
+        let sha = base
+            .pointer("/object/sha")
+            .and_then(|s| s.as_str())
+            .ok_or_else(|| {
+                GovernanceError::GitHubError(format!("No commit found for ref {}", from_ref))
+            })?
+            .to_string();
+
+        self.request_ok(
+            Method::POST,
+            &format!("/repos/{}/{}/git/refs", owner, repo),
+            Some(&json!({"ref": format!("refs/heads/{}", branch), "sha": sha})),
+        )
+        .await?;
+
+        info!("Created branch {} in {}/{} at {}", branch, owner, repo, sha);
+        Ok(CreatedBranch {
+            name: branch.to_string(),
+            sha,
+            dry_run: false,
+        })
+    }
+
+    /// Create or update a file on `branch`
+    ///
+    /// Updating requires the SHA of the blob being replaced, so the current
+    /// file (if any) is looked up first.
+    pub async fn put_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        content: &[u8],
+        message: &str,
+        branch: &str,
+    ) -> Result<FileCommit, GovernanceError> {
+        if self.dry_run {
+            record_suppressed(
+                Subsystem::GitHub,
+                "put_file",
+                Some(&format!("{}/{}:{}/{}", owner, repo, branch, path)),
+                json!({ "bytes": content.len(), "message": message }),
+            )
+            .await;
+            return Ok(FileCommit {
+                path: path.to_string(),
+                created: false,
+                commit_sha: String::new(),
+                content_sha: String::new(),
+                dry_run: true,
+            });
+        }
+
+        let contents_path = format!("/repos/{}/{}/contents/{}", owner, repo, path);
+        let (status, existing) = self
+            .request(
+                Method::GET,
+                &format!("{}?ref={}", contents_path, branch),
+                None,
+            )
+            .await?;
+        let existing_sha = match status {
+            StatusCode::NOT_FOUND => None,
+            s if s.is_success() => existing
+                .get("sha")
+                .and_then(|s| s.as_str())
+                .map(str::to_string),
+            s => {
+                return Err(GovernanceError::GitHubError(format!(
+                    "Failed to look up {} in {}/{}: {}",
+                    path, owner, repo, s
+                )))
+            }
+        };
+
+        let mut body = json!({
+            "message": message,
+            "content": general_purpose::STANDARD.encode(content),
+            "branch": branch,
+        });
+        if let Some(sha) = &existing_sha {
+            body["sha"] = json!(sha);
+        }
+
+        let response = self
+            .request_ok(Method::PUT, &contents_path, Some(&body))
+            .await?;
+
+        let created = existing_sha.is_none();
+        info!(
+            "{} {}/{}:{} on {}",
+            if created { "Created" } else { "Updated" },
+            owner,
+            repo,
+            path,
+            branch
+        );
+        Ok(FileCommit {
+            path: path.to_string(),
+            created,
+            commit_sha: response
+                .pointer("/commit/sha")
+                .and_then(|s| s.as_str())
+                .unwrap_or("")
+                .to_string(),
+            content_sha: response
+                .pointer("/content/sha")
+                .and_then(|s| s.as_str())
+                .unwrap_or("")
+                .to_string(),
+            dry_run: false,
+        })
+    }
+
+    /// Open a pull request merging `head` into `base`
+    pub async fn open_pull_request(
+        &self,
+        owner: &str,
+        repo: &str,
+        title: &str,
+        body: &str,
+        head: &str,
+        base: &str,
+    ) -> Result<OpenedPullRequest, GovernanceError> {
+        if self.dry_run {
+            record_suppressed(
+                Subsystem::GitHub,
+                "open_pull_request",
+                Some(&format!("{}/{}:{}", owner, repo, head)),
+                json!({ "title": title, "base": base }),
+            )
+            .await;
+            return Ok(OpenedPullRequest {
+                number: 0,
+                html_url: String::new(),
+                dry_run: true,
+            });
+        }
+
+        let response = self
+            .request_ok(
+                Method::POST,
+                &format!("/repos/{}/{}/pulls", owner, repo),
+                Some(&json!({"title": title, "body": body, "head": head, "base": base})),
+            )
+            .await?;
+
+        let number = response
+            .get("number")
+            .and_then(|n| n.as_u64())
+            .ok_or_else(|| {
+                GovernanceError::GitHubError("Pull request response has no number".to_string())
+            })?;
+        let html_url = response
+            .get("html_url")
+            .and_then(|u| u.as_str())
+            .unwrap_or("")
+            .to_string();
+
+        info!("Opened PR #{} in {}/{}: {}", number, owner, repo, title);
+        Ok(OpenedPullRequest {
+            number,
+            html_url,
+            dry_run: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const FILE_PATH: &str = "/repos/owner/governance/contents/config/action-tiers.yml";
+
+    fn ops(server: &MockServer) -> GitHubWriteOperations {
+        GitHubWriteOperations::new("test_token".to_string())
+            .unwrap()
+            .with_base_url(server.uri())
+    }
+
+    fn put_response() -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(json!({
+            "content": {"sha": "blob-2"},
+            "commit": {"sha": "commit-2"}
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_put_file_creates_new_file() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(FILE_PATH))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({"message": "Not Found"})))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path(FILE_PATH))
+            .and(body_partial_json(json!({"branch": "config-update"})))
+            .respond_with(put_response())
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let commit = ops(&server)
+            .put_file(
+                "owner",
+                "governance",
+                "config/action-tiers.yml",
+                b"tiers: {}\n",
+                "Add action tiers",
+                "config-update",
+            )
+            .await
+            .unwrap();
+        assert!(commit.created);
+        assert_eq!(commit.commit_sha, "commit-2");
+
+        // Creating a file must not send a sha
+        let requests = server.received_requests().await.unwrap();
+        let put: Value = serde_json::from_slice(&requests.last().unwrap().body).unwrap();
+        assert!(put.get("sha").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_put_file_updates_existing_file() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(FILE_PATH))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "type": "file",
+                "sha": "blob-1"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path(FILE_PATH))
+            .and(body_partial_json(json!({"sha": "blob-1"})))
+            .respond_with(put_response())
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let commit = ops(&server)
+            .put_file(
+                "owner",
+                "governance",
+                "config/action-tiers.yml",
+                b"tiers: {}\n",
+                "Update action tiers",
+                "config-update",
+            )
+            .await
+            .unwrap();
+        assert!(!commit.created);
+        assert_eq!(commit.content_sha, "blob-2");
+    }
+
+    #[tokio::test]
+    async fn test_create_branch_and_open_pull_request() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/owner/governance/git/ref/heads/main"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"object": {"sha": "base-sha"}})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/governance/git/refs"))
+            .and(body_partial_json(
+                json!({"ref": "refs/heads/config-update", "sha": "base-sha"}),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/repos/owner/governance/pulls"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                "number": 42,
+                "html_url": "https://github.com/owner/governance/pull/42"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let ops = ops(&server);
+        let branch = ops
+            .create_branch("owner", "governance", "config-update", "main")
+            .await
+            .unwrap();
+        assert_eq!(branch.sha, "base-sha");
+
+        let pr = ops
+            .open_pull_request(
+                "owner",
+                "governance",
+                "Update",
+                "Body",
+                "config-update",
+                "main",
+            )
+            .await
+            .unwrap();
+        assert_eq!(pr.number, 42);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_skips_writes() {
+        let server = MockServer::start().await;
+        let ops = ops(&server).with_dry_run(true);
+
+        let branch = ops
+            .create_branch("owner", "governance", "config-update", "main")
+            .await
+            .unwrap();
+        let commit = ops
+            .put_file(
+                "owner",
+                "governance",
+                "config/action-tiers.yml",
+                b"tiers: {}\n",
+                "Update action tiers",
+                "config-update",
+            )
+            .await
+            .unwrap();
+        let pr = ops
+            .open_pull_request(
+                "owner",
+                "governance",
+                "Update",
+                "Body",
+                "config-update",
+                "main",
+            )
+            .await
+            .unwrap();
+
+        assert!(branch.dry_run && commit.dry_run && pr.dry_run);
+        assert!(server.received_requests().await.unwrap().is_empty());
+    }
+}
@@ -4,17 +4,132 @@
 //! NOTE: Governance is maintainer-only multisig - contributions do NOT affect governance.
 //! This aggregator is kept for public reporting/dashboards.
 
+use crate::database::slow_query::time_query;
 use crate::governance::{ContributionTracker, WeightCalculator};
-use anyhow::Result;
-use chrono::Utc;
+use crate::node_registry::Page;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
 use sqlx::SqlitePool;
-use tracing::info;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{debug, info, warn};
+
+/// Outcome of an aggregation run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AggregationStats {
+    /// New unified_contributions rows folded into running totals
+    pub processed_contributions: u64,
+    /// Contributors whose totals (and participation weights) were updated
+    pub updated_contributors: u64,
+    /// True if another run was in progress and this one did nothing
+    pub skipped: bool,
+    /// Contributors whose weight update was held back by a pending anomaly
+    pub held_contributors: u64,
+}
+
+/// A canonical contributor's ids: itself plus its linked identities (bind the id twice)
+const CONTRIBUTOR_IDENTITIES: &str =
+    "SELECT ? UNION SELECT identity FROM contributor_identities WHERE contributor_id = ?";
+
+/// Longest rolling window a trend can cover (ten years)
+pub const MAX_TREND_WINDOW_DAYS: u32 = 3650;
+
+/// Days of earlier contributions averaged when checking for anomalies
+pub const ANOMALY_BASELINE_DAYS: i64 = 90;
+
+/// Multiple of the rolling average at which a contribution is flagged
+pub const DEFAULT_ANOMALY_THRESHOLD: f64 = 5.0;
+
+/// Growth in a contributor's total beyond which a pending anomaly holds back
+/// their weight update
+pub const DEFAULT_ANOMALY_WEIGHT_MULTIPLIER: f64 = 2.0;
+
+/// Review state of a flagged contribution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyStatus {
+    /// Awaiting review; holds back large weight changes
+    Pending,
+    /// Reviewed and accepted as genuine
+    Reviewed,
+    /// Dismissed as not a concern
+    Dismissed,
+}
+
+impl AnomalyStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnomalyStatus::Pending => "pending",
+            AnomalyStatus::Reviewed => "reviewed",
+            AnomalyStatus::Dismissed => "dismissed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(AnomalyStatus::Pending),
+            "reviewed" => Some(AnomalyStatus::Reviewed),
+            "dismissed" => Some(AnomalyStatus::Dismissed),
+            _ => None,
+        }
+    }
+}
+
+const ANOMALY_COLUMNS: &str = "id, contribution_id, contributor_id, contribution_type, amount_btc, rolling_average_btc, threshold_multiplier, status, detected_at, reviewed_at, reviewed_by";
+
+/// Ordering for contributor listings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContributorSort {
+    #[default]
+    Weight,
+    TotalBtc,
+    Contributions,
+    LastContribution,
+}
+
+impl ContributorSort {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "weight" => Some(ContributorSort::Weight),
+            "total_btc" => Some(ContributorSort::TotalBtc),
+            "contributions" => Some(ContributorSort::Contributions),
+            "last_contribution" => Some(ContributorSort::LastContribution),
+            _ => None,
+        }
+    }
+
+    fn order_by(&self) -> &'static str {
+        match self {
+            ContributorSort::Weight => "weight DESC, total_btc DESC",
+            ContributorSort::TotalBtc => "total_btc DESC",
+            ContributorSort::Contributions => "contribution_count DESC",
+            ContributorSort::LastContribution => "last_contribution_at DESC",
+        }
+    }
+}
+
+/// New contributions for one contributor since the last aggregation run
+#[derive(sqlx::FromRow)]
+struct ContributorDelta {
+    contributor_id: String,
+    contributor_type: String,
+    total_btc: f64,
+    zaps_btc: f64,
+    contribution_count: i64,
+    max_id: i64,
+}
 
 /// Contribution aggregator for monthly aggregation
 pub struct ContributionAggregator {
     pool: SqlitePool,
     contribution_tracker: ContributionTracker,
     weight_calculator: WeightCalculator,
+    /// Held for the duration of a run so overlapping runs skip
+    run_lock: Arc<Mutex<()>>,
+    /// See [`DEFAULT_ANOMALY_WEIGHT_MULTIPLIER`]
+    anomaly_weight_multiplier: f64,
 }
 
 impl ContributionAggregator {
@@ -24,47 +139,898 @@ impl ContributionAggregator {
             pool: pool.clone(),
             contribution_tracker: ContributionTracker::new(pool.clone()),
             weight_calculator: WeightCalculator::new(pool),
+            run_lock: Arc::new(Mutex::new(())),
+            anomaly_weight_multiplier: DEFAULT_ANOMALY_WEIGHT_MULTIPLIER,
         }
     }
 
+    /// Hold back weight updates for contributors with pending anomalies whose
+    /// total has grown by more than `multiplier` times since before the anomaly
+    pub fn with_anomaly_weight_multiplier(mut self, multiplier: f64) -> Self {
+        self.anomaly_weight_multiplier = multiplier;
+        self
+    }
 
     /// Aggregate cumulative zap contributions (all-time) - for reporting only
     /// NOTE: Zaps do NOT affect governance (maintainer-only multisig)
     /// Returns total BTC zapped (cumulative) for transparency/reporting
     pub async fn aggregate_zaps_cumulative(&self, contributor_id: &str) -> Result<f64> {
-        let total: Option<f64> = sqlx::query_scalar(
-            r#"
+        let sql = r#"
             SELECT COALESCE(SUM(amount_btc), 0.0) as total
             FROM unified_contributions
             WHERE contributor_id = ?
               AND contribution_type LIKE 'zap:%'
-            "#,
-        )
-        .bind(contributor_id)
-        .fetch_one(&self.pool)
-        .await?;
+            "#;
+        let timing = time_query(sql);
+        let total: Option<f64> = sqlx::query_scalar(sql)
+            .bind(contributor_id)
+            .fetch_one(&self.pool)
+            .await?;
+        timing.finish(1);
 
         Ok(total.unwrap_or(0.0))
     }
 
-    /// Update all participation weights (for reporting only)
+    /// Update participation weights incrementally (for reporting only)
     /// NOTE: Governance is maintainer-only - weights are 0.0 and don't affect governance
-    /// This is kept for reporting/transparency purposes
-    pub async fn update_all_weights(&self) -> Result<()> {
-        info!("Starting participation weight update (for reporting only)");
+    ///
+    /// Only contributions added since the last run are folded into the running
+    /// totals, and only the contributors they belong to get their weights
+    /// updated. Contributors with pending anomalies keep their old weight if
+    /// their total grew beyond the anomaly weight multiplier since before the
+    /// anomaly. Returns
+    /// immediately with `skipped` set if a run is in progress.
+    pub async fn update_all_weights(&self) -> Result<AggregationStats> {
+        let Ok(_guard) = self.run_lock.try_lock() else {
+            info!("Participation weight update already running, skipping");
+            return Ok(AggregationStats {
+                skipped: true,
+                ..Default::default()
+            });
+        };
 
         // Update contribution ages first (for reporting)
         self.contribution_tracker.update_contribution_ages().await?;
 
-        // Update all participation weights (all will be 0.0 since governance is maintainer-only)
+        let stats = self.aggregate_new_contributions().await?;
+        info!(
+            "Completed participation weight update: {} new contributions, {} contributors updated, {} held for anomaly review",
+            stats.processed_contributions, stats.updated_contributors, stats.held_contributors
+        );
+        Ok(stats)
+    }
+
+    /// Discard running totals and rebuild them from every contribution
+    ///
+    /// Escape hatch for repairing totals, e.g. after contributions were
+    /// deleted or edited in place. Waits for a run in progress to finish.
+    pub async fn force_full_rebuild(&self) -> Result<AggregationStats> {
+        let _guard = self.run_lock.lock().await;
+        info!("Rebuilding contribution running totals from scratch");
+
+        let mut tx = self.pool.begin().await?;
+        let sql = "DELETE FROM contributor_running_totals";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql).execute(&mut *tx).await?;
+        timing.finish(result.rows_affected());
+        let sql = "DELETE FROM contribution_aggregation_state";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql).execute(&mut *tx).await?;
+        timing.finish(result.rows_affected());
+        tx.commit().await?;
+
+        self.contribution_tracker.update_contribution_ages().await?;
+        let stats = self.aggregate_new_contributions().await?;
+
+        // Contributors with no remaining contributions still need their weight refreshed
         self.weight_calculator
             .update_participation_weights()
             .await?;
 
-        info!("Completed participation weight update (all weights are 0.0 - maintainer-only governance)");
+        Ok(stats)
+    }
+
+    /// Fold contributions past the high-water mark into the running totals
+    async fn aggregate_new_contributions(&self) -> Result<AggregationStats> {
+        let last_contribution_id = self.last_aggregated_id().await?;
+
+        let sql = r#"
+            SELECT contributor_id,
+                   MAX(contributor_type) as contributor_type,
+                   COALESCE(SUM(amount_btc), 0.0) as total_btc,
+                   COALESCE(SUM(CASE WHEN contribution_type LIKE 'zap:%' THEN amount_btc ELSE 0.0 END), 0.0) as zaps_btc,
+                   COUNT(*) as contribution_count,
+                   MAX(id) as max_id
+            FROM unified_contributions
+            WHERE id > ?
+            GROUP BY contributor_id
+            "#;
+        let timing = time_query(sql);
+        let deltas = sqlx::query_as::<_, ContributorDelta>(sql)
+            .bind(last_contribution_id)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(deltas.len() as u64);
+
+        if deltas.is_empty() {
+            debug!("No new contributions since id {}", last_contribution_id);
+            return Ok(AggregationStats::default());
+        }
+
+        let high_water_mark = deltas
+            .iter()
+            .map(|delta| delta.max_id)
+            .max()
+            .unwrap_or(last_contribution_id);
+        let processed_contributions: i64 = deltas.iter().map(|d| d.contribution_count).sum();
+        let held = self.held_for_review(&deltas, high_water_mark).await?;
+
+        let mut tx = self.pool.begin().await?;
+        for delta in &deltas {
+            let sql = r#"
+                INSERT INTO contributor_running_totals
+                (contributor_id, contributor_type, total_btc, zaps_btc, contribution_count, updated_at)
+                VALUES (?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+                ON CONFLICT(contributor_id) DO UPDATE SET
+                    contributor_type = excluded.contributor_type,
+                    total_btc = total_btc + excluded.total_btc,
+                    zaps_btc = zaps_btc + excluded.zaps_btc,
+                    contribution_count = contribution_count + excluded.contribution_count,
+                    updated_at = CURRENT_TIMESTAMP
+                "#;
+            let timing = time_query(sql);
+            let result = sqlx::query(sql)
+                .bind(&delta.contributor_id)
+                .bind(&delta.contributor_type)
+                .bind(delta.total_btc)
+                .bind(delta.zaps_btc)
+                .bind(delta.contribution_count)
+                .execute(&mut *tx)
+                .await?;
+            timing.finish(result.rows_affected());
+        }
+        let sql = r#"
+            INSERT INTO contribution_aggregation_state (id, last_contribution_id, updated_at)
+            VALUES (1, ?, CURRENT_TIMESTAMP)
+            ON CONFLICT(id) DO UPDATE SET
+                last_contribution_id = excluded.last_contribution_id,
+                updated_at = CURRENT_TIMESTAMP
+            "#;
+        let timing = time_query(sql);
+        let result = sqlx::query(sql)
+            .bind(high_water_mark)
+            .execute(&mut *tx)
+            .await?;
+        timing.finish(result.rows_affected());
+        tx.commit().await?;
+
+        let changed: Vec<(String, String)> = deltas
+            .into_iter()
+            .filter(|delta| !held.contains(&delta.contributor_id))
+            .map(|delta| (delta.contributor_id, delta.contributor_type))
+            .collect();
+        self.weight_calculator
+            .update_participation_weights_for(&changed)
+            .await?;
+
+        Ok(AggregationStats {
+            processed_contributions: processed_contributions as u64,
+            updated_contributors: changed.len() as u64,
+            skipped: false,
+            held_contributors: held.len() as u64,
+        })
+    }
+
+    /// Highest unified_contributions id already folded into running totals
+    async fn last_aggregated_id(&self) -> Result<i64> {
+        let sql = "SELECT last_contribution_id FROM contribution_aggregation_state WHERE id = 1";
+        let timing = time_query(sql);
+        let last_contribution_id: Option<i64> =
+            sqlx::query_scalar(sql).fetch_optional(&self.pool).await?;
+        timing.finish(last_contribution_id.is_some() as u64);
+        Ok(last_contribution_id.unwrap_or(0))
+    }
+
+    /// Contributors among `deltas` whose weight update waits for anomaly review
+    ///
+    /// A contributor is held while any of their linked identities has a
+    /// pending anomaly and their combined total, up to `high_water_mark`, is
+    /// more than the anomaly weight multiplier times their total before the
+    /// first pending anomaly. Comparing against the total before the anomaly
+    /// (rather than the last run's) keeps them held when smaller contributions
+    /// arrive before the review.
+    async fn held_for_review(
+        &self,
+        deltas: &[ContributorDelta],
+        high_water_mark: i64,
+    ) -> Result<HashSet<String>> {
+        let mut held = HashSet::new();
+        for delta in deltas {
+            let canonical = self.resolve_contributor(&delta.contributor_id).await?;
+            let sql = format!(
+                "SELECT MIN(contribution_id) FROM contribution_anomalies WHERE status = 'pending' AND contributor_id IN ({})",
+                CONTRIBUTOR_IDENTITIES
+            );
+            let timing = time_query(&sql);
+            let first_pending: Option<i64> = sqlx::query_scalar(&sql)
+                .bind(&canonical)
+                .bind(&canonical)
+                .fetch_one(&self.pool)
+                .await?;
+            timing.finish(1);
+            let Some(first_pending) = first_pending else {
+                continue;
+            };
+
+            let sql = format!(
+                r#"
+                SELECT COALESCE(SUM(CASE WHEN id < ? THEN amount_btc ELSE 0.0 END), 0.0),
+                       COALESCE(SUM(amount_btc), 0.0)
+                FROM unified_contributions
+                WHERE id <= ? AND contributor_id IN ({})
+                "#,
+                CONTRIBUTOR_IDENTITIES
+            );
+            let timing = time_query(&sql);
+            let (before_anomaly, total): (f64, f64) = sqlx::query_as(&sql)
+                .bind(first_pending)
+                .bind(high_water_mark)
+                .bind(&canonical)
+                .bind(&canonical)
+                .fetch_one(&self.pool)
+                .await?;
+            timing.finish(1);
+            if total > before_anomaly * self.anomaly_weight_multiplier {
+                info!(
+                    "Holding weight update for {} pending anomaly review",
+                    delta.contributor_id
+                );
+                held.insert(delta.contributor_id.clone());
+            }
+        }
+        Ok(held)
+    }
+
+    /// Running totals for a contributor: (total BTC, zaps BTC, contribution count)
+    pub async fn get_running_totals(
+        &self,
+        contributor_id: &str,
+    ) -> Result<Option<(f64, f64, i64)>> {
+        let sql = "SELECT total_btc, zaps_btc, contribution_count FROM contributor_running_totals WHERE contributor_id = ?";
+        let timing = time_query(sql);
+        let totals: Option<(f64, f64, i64)> = sqlx::query_as(sql)
+            .bind(contributor_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        timing.finish(totals.is_some() as u64);
+
+        Ok(totals)
+    }
+
+    /// Canonical contributor id for an identity (the identity itself if unlinked)
+    pub async fn resolve_contributor(&self, identity: &str) -> Result<String> {
+        let sql = "SELECT contributor_id FROM contributor_identities WHERE identity = ?";
+        let timing = time_query(sql);
+        let contributor_id: Option<String> = sqlx::query_scalar(sql)
+            .bind(identity)
+            .fetch_optional(&self.pool)
+            .await?;
+        timing.finish(contributor_id.is_some() as u64);
+
+        Ok(contributor_id.unwrap_or_else(|| identity.to_string()))
+    }
+
+    /// Link an identity (e.g. a Bitcoin address) to a contributor (e.g. a Nostr pubkey)
+    ///
+    /// Contributions recorded under either are merged in breakdowns and listings.
+    /// Linking to an already-linked identity links to its canonical contributor.
+    pub async fn link_identity(
+        &self,
+        contributor_id: &str,
+        identity: &str,
+        identity_type: &str,
+    ) -> Result<()> {
+        let canonical = self.resolve_contributor(contributor_id).await?;
+        if canonical == identity {
+            bail!("Identity {} is already contributor {}", identity, canonical);
+        }
+
+        let existing = self.resolve_contributor(identity).await?;
+        if existing == canonical {
+            return Ok(());
+        }
+        if existing != identity {
+            bail!(
+                "Identity {} is already linked to contributor {}",
+                identity,
+                existing
+            );
+        }
+
+        let sql = "SELECT COUNT(*) FROM contributor_identities WHERE contributor_id = ?";
+        let timing = time_query(sql);
+        let has_linked: i64 = sqlx::query_scalar(sql)
+            .bind(identity)
+            .fetch_one(&self.pool)
+            .await?;
+        timing.finish(1);
+        if has_linked > 0 {
+            bail!(
+                "Identity {} has linked identities of its own; link them to {} first",
+                identity,
+                canonical
+            );
+        }
+
+        let sql = "INSERT INTO contributor_identities (identity, contributor_id, identity_type, linked_at) VALUES (?, ?, ?, ?)";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql)
+            .bind(identity)
+            .bind(&canonical)
+            .bind(identity_type)
+            .bind(Utc::now())
+            .execute(&self.pool)
+            .await?;
+        timing.finish(result.rows_affected());
+
+        info!(
+            "Linked {} identity {} to contributor {}",
+            identity_type, identity, canonical
+        );
         Ok(())
     }
 
+    /// Weight and per-source contribution breakdown for a contributor, merged
+    /// across linked identities; `None` if nothing is recorded for them
+    pub async fn get_contributor_breakdown(
+        &self,
+        contributor_id: &str,
+    ) -> Result<Option<ContributorBreakdown>> {
+        #[derive(sqlx::FromRow)]
+        struct Totals {
+            total_btc: f64,
+            contribution_count: i64,
+            first_contribution_at: Option<DateTime<Utc>>,
+            last_contribution_at: Option<DateTime<Utc>>,
+        }
+
+        #[derive(sqlx::FromRow)]
+        struct WeightRow {
+            total_contribution_btc: f64,
+            base_weight: f64,
+            capped_weight: f64,
+            total_system_weight: f64,
+            weighted_identities: i64,
+        }
+
+        let _timer = crate::metrics::query_timer("aggregator.get_contributor_breakdown");
+        let canonical = self.resolve_contributor(contributor_id).await?;
+
+        let sql = r#"
+            SELECT identity, identity_type, linked_at
+            FROM contributor_identities
+            WHERE contributor_id = ?
+            ORDER BY linked_at, identity
+            "#;
+        let timing = time_query(sql);
+        let identities = sqlx::query_as::<_, ContributorIdentity>(sql)
+            .bind(&canonical)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(identities.len() as u64);
+
+        let sql = format!(
+            r#"
+            SELECT COALESCE(SUM(amount_btc), 0.0) as total_btc,
+                   COUNT(*) as contribution_count,
+                   MIN(timestamp) as first_contribution_at,
+                   MAX(timestamp) as last_contribution_at
+            FROM unified_contributions
+            WHERE contributor_id IN ({})
+            "#,
+            CONTRIBUTOR_IDENTITIES
+        );
+        let timing = time_query(&sql);
+        let totals = sqlx::query_as::<_, Totals>(&sql)
+            .bind(&canonical)
+            .bind(&canonical)
+            .fetch_one(&self.pool)
+            .await?;
+        timing.finish(1);
+
+        let sql = format!(
+            r#"
+            SELECT CASE WHEN instr(contribution_type, ':') > 0
+                        THEN substr(contribution_type, 1, instr(contribution_type, ':') - 1)
+                        ELSE contribution_type END as source,
+                   COALESCE(SUM(amount_btc), 0.0) as total_btc,
+                   COUNT(*) as contribution_count
+            FROM unified_contributions
+            WHERE contributor_id IN ({})
+            GROUP BY source
+            ORDER BY total_btc DESC, source
+            "#,
+            CONTRIBUTOR_IDENTITIES
+        );
+        let timing = time_query(&sql);
+        let sources = sqlx::query_as::<_, SourceBreakdown>(&sql)
+            .bind(&canonical)
+            .bind(&canonical)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(sources.len() as u64);
+
+        let sql = format!(
+            r#"
+            SELECT COALESCE(SUM(total_contribution_btc), 0.0) as total_contribution_btc,
+                   COALESCE(SUM(base_weight), 0.0) as base_weight,
+                   COALESCE(SUM(capped_weight), 0.0) as capped_weight,
+                   COALESCE(MAX(total_system_weight), 0.0) as total_system_weight,
+                   COUNT(*) as weighted_identities
+            FROM participation_weights
+            WHERE contributor_id IN ({})
+            "#,
+            CONTRIBUTOR_IDENTITIES
+        );
+        let timing = time_query(&sql);
+        let weights = sqlx::query_as::<_, WeightRow>(&sql)
+            .bind(&canonical)
+            .bind(&canonical)
+            .fetch_one(&self.pool)
+            .await?;
+        timing.finish(1);
+
+        if totals.contribution_count == 0
+            && identities.is_empty()
+            && weights.weighted_identities == 0
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(ContributorBreakdown {
+            contributor_id: canonical,
+            identities,
+            weight: weights.capped_weight,
+            total_btc: totals.total_btc,
+            contribution_count: totals.contribution_count,
+            first_contribution_at: totals.first_contribution_at,
+            last_contribution_at: totals.last_contribution_at,
+            sources,
+            weight_inputs: WeightInputs {
+                total_contribution_btc: weights.total_contribution_btc,
+                base_weight: weights.base_weight,
+                capped_weight: weights.capped_weight,
+                total_system_weight: weights.total_system_weight,
+            },
+        }))
+    }
+
+    /// List contributors (linked identities merged), paginated
+    pub async fn list_contributors(
+        &self,
+        sort: ContributorSort,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Page<ContributorSummary>> {
+        let _timer = crate::metrics::query_timer("aggregator.list_contributors");
+        let sql = r#"
+            SELECT COUNT(DISTINCT COALESCE(ci.contributor_id, uc.contributor_id))
+            FROM unified_contributions uc
+            LEFT JOIN contributor_identities ci ON ci.identity = uc.contributor_id
+            "#;
+        let timing = time_query(sql);
+        let total: i64 = sqlx::query_scalar(sql).fetch_one(&self.pool).await?;
+        timing.finish(1);
+
+        let sql = format!(
+            r#"
+            WITH resolved AS (
+                SELECT COALESCE(ci.contributor_id, uc.contributor_id) as contributor_id,
+                       uc.amount_btc, uc.timestamp
+                FROM unified_contributions uc
+                LEFT JOIN contributor_identities ci ON ci.identity = uc.contributor_id
+            ),
+            weights AS (
+                SELECT COALESCE(ci.contributor_id, pw.contributor_id) as contributor_id,
+                       SUM(pw.capped_weight) as weight
+                FROM participation_weights pw
+                LEFT JOIN contributor_identities ci ON ci.identity = pw.contributor_id
+                GROUP BY 1
+            )
+            SELECT r.contributor_id,
+                   COALESCE(MAX(w.weight), 0.0) as weight,
+                   SUM(r.amount_btc) as total_btc,
+                   COUNT(*) as contribution_count,
+                   MIN(r.timestamp) as first_contribution_at,
+                   MAX(r.timestamp) as last_contribution_at
+            FROM resolved r
+            LEFT JOIN weights w ON w.contributor_id = r.contributor_id
+            GROUP BY r.contributor_id
+            ORDER BY {}, r.contributor_id
+            LIMIT ? OFFSET ?
+            "#,
+            sort.order_by()
+        );
+        let timing = time_query(&sql);
+        let items = sqlx::query_as::<_, ContributorSummary>(&sql)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(items.len() as u64);
+
+        Ok(Page {
+            items,
+            total,
+            limit: Some(limit),
+            offset,
+        })
+    }
+
+    /// Rolling-window contribution statistics for a contributor (linked
+    /// identities merged), stored as a snapshot in `contribution_trends`
+    pub async fn compute_trend(
+        &self,
+        contributor_id: &str,
+        window_days: u32,
+    ) -> Result<ContributionTrend> {
+        let canonical = self.resolve_contributor(contributor_id).await?;
+        let trend = self.trend_at(&canonical, window_days, Utc::now()).await?;
+
+        let sql = r#"
+            INSERT INTO contribution_trends
+            (contributor_id, window_days, current_period_total, previous_period_total,
+             growth_rate, moving_average_7d, moving_average_30d, snapshot_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#;
+        let timing = time_query(sql);
+        let result = sqlx::query(sql)
+            .bind(&trend.contributor_id)
+            .bind(trend.window_days)
+            .bind(trend.current_period_total)
+            .bind(trend.previous_period_total)
+            .bind(trend.growth_rate)
+            .bind(trend.moving_average_7d)
+            .bind(trend.moving_average_30d)
+            .bind(trend.snapshot_at)
+            .execute(&self.pool)
+            .await?;
+        timing.finish(result.rows_affected());
+
+        Ok(trend)
+    }
+
+    /// Trend for a canonical contributor as of `now`
+    async fn trend_at(
+        &self,
+        canonical: &str,
+        window_days: u32,
+        now: DateTime<Utc>,
+    ) -> Result<ContributionTrend> {
+        #[derive(sqlx::FromRow)]
+        struct WindowTotals {
+            current_period_total: f64,
+            previous_period_total: f64,
+            last_7d: f64,
+            last_30d: f64,
+        }
+
+        if !(1..=MAX_TREND_WINDOW_DAYS).contains(&window_days) {
+            bail!(
+                "Trend window must be between 1 and {} days",
+                MAX_TREND_WINDOW_DAYS
+            );
+        }
+        let _timer = crate::metrics::query_timer("aggregator.compute_trend");
+        let days = |n: u32| now - chrono::Duration::days(n as i64);
+        let current_start = days(window_days);
+        let previous_start = days(window_days.saturating_mul(2));
+
+        let sql = format!(
+            r#"
+            SELECT COALESCE(SUM(CASE WHEN datetime(timestamp) >= datetime(?) THEN amount_btc END), 0.0) as current_period_total,
+                   COALESCE(SUM(CASE WHEN datetime(timestamp) >= datetime(?) AND datetime(timestamp) < datetime(?) THEN amount_btc END), 0.0) as previous_period_total,
+                   COALESCE(SUM(CASE WHEN datetime(timestamp) >= datetime(?) THEN amount_btc END), 0.0) as last_7d,
+                   COALESCE(SUM(CASE WHEN datetime(timestamp) >= datetime(?) THEN amount_btc END), 0.0) as last_30d
+            FROM unified_contributions
+            WHERE contributor_id IN ({})
+              AND datetime(timestamp) >= datetime(?)
+              AND datetime(timestamp) <= datetime(?)
+            "#,
+            CONTRIBUTOR_IDENTITIES
+        );
+        let timing = time_query(&sql);
+        let totals = sqlx::query_as::<_, WindowTotals>(&sql)
+            .bind(current_start)
+            .bind(previous_start)
+            .bind(current_start)
+            .bind(days(7))
+            .bind(days(30))
+            .bind(canonical)
+            .bind(canonical)
+            .bind(previous_start.min(days(30)))
+            .bind(now)
+            .fetch_one(&self.pool)
+            .await?;
+        timing.finish(1);
+
+        let growth_rate = (totals.previous_period_total > 0.0).then(|| {
+            (totals.current_period_total - totals.previous_period_total)
+                / totals.previous_period_total
+        });
+        Ok(ContributionTrend {
+            contributor_id: canonical.to_string(),
+            window_days,
+            current_period_total: totals.current_period_total,
+            previous_period_total: totals.previous_period_total,
+            growth_rate,
+            moving_average_7d: totals.last_7d / 7.0,
+            moving_average_30d: totals.last_30d / 30.0,
+            snapshot_at: now,
+        })
+    }
+
+    /// Contributors with the most BTC contributed in the last `window_days`
+    /// (linked identities merged), with that total and their trend
+    ///
+    /// Leaderboard trends are not stored as snapshots.
+    pub async fn get_top_contributors(
+        &self,
+        limit: usize,
+        window_days: u32,
+    ) -> Result<Vec<(String, f64, ContributionTrend)>> {
+        if !(1..=MAX_TREND_WINDOW_DAYS).contains(&window_days) {
+            bail!(
+                "Trend window must be between 1 and {} days",
+                MAX_TREND_WINDOW_DAYS
+            );
+        }
+        let _timer = crate::metrics::query_timer("aggregator.get_top_contributors");
+        let now = Utc::now();
+        let sql = r#"
+            SELECT COALESCE(ci.contributor_id, uc.contributor_id) as contributor_id,
+                   SUM(uc.amount_btc) as total_btc
+            FROM unified_contributions uc
+            LEFT JOIN contributor_identities ci ON ci.identity = uc.contributor_id
+            WHERE datetime(uc.timestamp) >= datetime(?)
+              AND datetime(uc.timestamp) <= datetime(?)
+            GROUP BY 1
+            ORDER BY total_btc DESC, contributor_id
+            LIMIT ?
+            "#;
+        let timing = time_query(sql);
+        let top: Vec<(String, f64)> = sqlx::query_as(sql)
+            .bind(now - chrono::Duration::days(window_days as i64))
+            .bind(now)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(top.len() as u64);
+
+        let mut leaderboard = Vec::with_capacity(top.len());
+        for (contributor_id, total_btc) in top {
+            let trend = self.trend_at(&contributor_id, window_days, now).await?;
+            leaderboard.push((contributor_id, total_btc, trend));
+        }
+        Ok(leaderboard)
+    }
+
+    /// Flag contributions above `threshold_multiplier` times the contributor's
+    /// average over the preceding [`ANOMALY_BASELINE_DAYS`] days
+    ///
+    /// The average covers all of the contributor's linked identities, so a
+    /// large contribution from a newly linked address is compared with the
+    /// contributor's history.
+    ///
+    /// Only contributions not yet folded into running totals are checked, so
+    /// run this before [`Self::update_all_weights`]. Contributions with no
+    /// earlier ones in the baseline window have nothing to compare against and
+    /// are not flagged. Returns the newly flagged contributions.
+    pub async fn detect_anomalies(
+        &self,
+        threshold_multiplier: f64,
+    ) -> Result<Vec<ContributionAnomaly>> {
+        #[derive(sqlx::FromRow)]
+        struct Candidate {
+            id: i64,
+            contributor_id: String,
+            contribution_type: String,
+            amount_btc: f64,
+            rolling_average_btc: Option<f64>,
+        }
+
+        if threshold_multiplier.is_nan() || threshold_multiplier <= 0.0 {
+            bail!("Anomaly threshold multiplier must be positive");
+        }
+        let _timer = crate::metrics::query_timer("aggregator.detect_anomalies");
+        let last_contribution_id = self.last_aggregated_id().await?;
+
+        let sql = format!(
+            r#"
+            SELECT uc.id, uc.contributor_id, uc.contribution_type, uc.amount_btc,
+                   (SELECT AVG(prior.amount_btc)
+                    FROM unified_contributions prior
+                    LEFT JOIN contributor_identities pci ON pci.identity = prior.contributor_id
+                    WHERE COALESCE(pci.contributor_id, prior.contributor_id)
+                          = COALESCE(ci.contributor_id, uc.contributor_id)
+                      AND prior.id < uc.id
+                      AND datetime(prior.timestamp) >= datetime(uc.timestamp, '-{} days')) as rolling_average_btc
+            FROM unified_contributions uc
+            LEFT JOIN contributor_identities ci ON ci.identity = uc.contributor_id
+            WHERE uc.id > ?
+            ORDER BY uc.id
+            "#,
+            ANOMALY_BASELINE_DAYS
+        );
+        let timing = time_query(&sql);
+        let candidates = sqlx::query_as::<_, Candidate>(&sql)
+            .bind(last_contribution_id)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(candidates.len() as u64);
+
+        let mut flagged = Vec::new();
+        for candidate in candidates {
+            let Some(average) = candidate.rolling_average_btc.filter(|avg| *avg > 0.0) else {
+                continue;
+            };
+            if candidate.amount_btc <= average * threshold_multiplier {
+                continue;
+            }
+            let sql = format!(
+                r#"
+                INSERT INTO contribution_anomalies
+                (contribution_id, contributor_id, contribution_type, amount_btc,
+                 rolling_average_btc, threshold_multiplier, status, detected_at)
+                VALUES (?, ?, ?, ?, ?, ?, 'pending', ?)
+                ON CONFLICT(contribution_id) DO NOTHING
+                RETURNING {}
+                "#,
+                ANOMALY_COLUMNS
+            );
+            let timing = time_query(&sql);
+            let anomaly = sqlx::query_as::<_, ContributionAnomaly>(&sql)
+                .bind(candidate.id)
+                .bind(&candidate.contributor_id)
+                .bind(&candidate.contribution_type)
+                .bind(candidate.amount_btc)
+                .bind(average)
+                .bind(threshold_multiplier)
+                .bind(Utc::now())
+                .fetch_optional(&self.pool)
+                .await?;
+            timing.finish(anomaly.is_some() as u64);
+            if let Some(anomaly) = anomaly {
+                warn!(
+                    "Contribution {} from {} ({} BTC) is over {}x its {}-day average of {} BTC",
+                    anomaly.contribution_id,
+                    anomaly.contributor_id,
+                    anomaly.amount_btc,
+                    threshold_multiplier,
+                    ANOMALY_BASELINE_DAYS,
+                    average
+                );
+                flagged.push(anomaly);
+            }
+        }
+        Ok(flagged)
+    }
+
+    /// Flagged contributions, newest first, optionally filtered by status
+    pub async fn list_anomalies(
+        &self,
+        status: Option<AnomalyStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Page<ContributionAnomaly>> {
+        let status = status.map(|status| status.as_str());
+        let sql = "SELECT COUNT(*) FROM contribution_anomalies WHERE ? IS NULL OR status = ?";
+        let timing = time_query(sql);
+        let total: i64 = sqlx::query_scalar(sql)
+            .bind(status)
+            .bind(status)
+            .fetch_one(&self.pool)
+            .await?;
+        timing.finish(1);
+        let sql = format!(
+            r#"
+            SELECT {}
+            FROM contribution_anomalies
+            WHERE ? IS NULL OR status = ?
+            ORDER BY id DESC
+            LIMIT ? OFFSET ?
+            "#,
+            ANOMALY_COLUMNS
+        );
+        let timing = time_query(&sql);
+        let items = sqlx::query_as::<_, ContributionAnomaly>(&sql)
+            .bind(status)
+            .bind(status)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(items.len() as u64);
+
+        Ok(Page {
+            items,
+            total,
+            limit: Some(limit),
+            offset,
+        })
+    }
+
+    /// Mark an anomaly reviewed or dismissed; None if it doesn't exist
+    ///
+    /// Once none of a contributor's linked identities has a pending anomaly
+    /// left, their held weights are brought up to date.
+    pub async fn review_anomaly(
+        &self,
+        anomaly_id: i64,
+        status: AnomalyStatus,
+        reviewed_by: &str,
+    ) -> Result<Option<ContributionAnomaly>> {
+        if status == AnomalyStatus::Pending {
+            bail!("An anomaly can only be marked reviewed or dismissed");
+        }
+        let sql = format!(
+            r#"
+            UPDATE contribution_anomalies
+            SET status = ?, reviewed_at = ?, reviewed_by = ?
+            WHERE id = ?
+            RETURNING {}
+            "#,
+            ANOMALY_COLUMNS
+        );
+        let timing = time_query(&sql);
+        let anomaly = sqlx::query_as::<_, ContributionAnomaly>(&sql)
+            .bind(status.as_str())
+            .bind(Utc::now())
+            .bind(reviewed_by)
+            .bind(anomaly_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        timing.finish(anomaly.is_some() as u64);
+        let Some(anomaly) = anomaly else {
+            return Ok(None);
+        };
+
+        let canonical = self.resolve_contributor(&anomaly.contributor_id).await?;
+        let sql = format!(
+            "SELECT COUNT(*) FROM contribution_anomalies WHERE status = 'pending' AND contributor_id IN ({})",
+            CONTRIBUTOR_IDENTITIES
+        );
+        let timing = time_query(&sql);
+        let still_pending: i64 = sqlx::query_scalar(&sql)
+            .bind(&canonical)
+            .bind(&canonical)
+            .fetch_one(&self.pool)
+            .await?;
+        timing.finish(1);
+        if still_pending == 0 {
+            let sql = format!(
+                "SELECT contributor_id, contributor_type FROM contributor_running_totals WHERE contributor_id IN ({})",
+                CONTRIBUTOR_IDENTITIES
+            );
+            let timing = time_query(&sql);
+            let held: Vec<(String, String)> = sqlx::query_as(&sql)
+                .bind(&canonical)
+                .bind(&canonical)
+                .fetch_all(&self.pool)
+                .await?;
+            timing.finish(held.len() as u64);
+            self.weight_calculator
+                .update_participation_weights_for(&held)
+                .await?;
+        }
+
+        info!(
+            "Anomaly {} for {} marked {} by {}",
+            anomaly.id, anomaly.contributor_id, anomaly.status, reviewed_by
+        );
+        Ok(Some(anomaly))
+    }
+
     /// Get aggregated contributions for a contributor (zaps only)
     pub async fn get_contributor_aggregates(
         &self,
@@ -95,3 +1061,648 @@ pub struct ContributorAggregates {
     pub total_contribution_btc: f64,
     pub participation_weight: f64, // Always 0.0 (maintainer-only governance)
 }
+
+/// A contributor's contributions over a rolling window, compared with the
+/// window before it (for reporting/transparency only)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContributionTrend {
+    /// Canonical contributor id
+    pub contributor_id: String,
+    pub window_days: u32,
+    /// BTC contributed in the last `window_days`
+    pub current_period_total: f64,
+    /// BTC contributed in the `window_days` before that
+    pub previous_period_total: f64,
+    /// Relative change from the previous period; None if it had no contributions
+    pub growth_rate: Option<f64>,
+    /// Average BTC per day over the last 7 days
+    pub moving_average_7d: f64,
+    /// Average BTC per day over the last 30 days
+    pub moving_average_30d: f64,
+    pub snapshot_at: DateTime<Utc>,
+}
+
+/// A contribution flagged as far above the contributor's rolling average
+#[derive(Debug, Clone, PartialEq, Serialize, sqlx::FromRow)]
+pub struct ContributionAnomaly {
+    pub id: i64,
+    /// `unified_contributions` row that was flagged
+    pub contribution_id: i64,
+    pub contributor_id: String,
+    pub contribution_type: String,
+    pub amount_btc: f64,
+    /// Average contribution over the baseline window before this one
+    pub rolling_average_btc: f64,
+    /// Multiple of the average that triggered the flag
+    pub threshold_multiplier: f64,
+    /// "pending", "reviewed" or "dismissed"
+    pub status: String,
+    pub detected_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub reviewed_by: Option<String>,
+}
+
+/// An identity linked to a canonical contributor
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct ContributorIdentity {
+    pub identity: String,
+    pub identity_type: String,
+    pub linked_at: DateTime<Utc>,
+}
+
+/// Contributions from one source ('zap', 'fee_forwarding', 'marketplace', ...)
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct SourceBreakdown {
+    pub source: String,
+    pub total_btc: f64,
+    pub contribution_count: i64,
+}
+
+/// Participation weight formula inputs, summed across linked identities
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WeightInputs {
+    pub total_contribution_btc: f64,
+    pub base_weight: f64,
+    pub capped_weight: f64,
+    pub total_system_weight: f64,
+}
+
+/// A contributor's weight and where it came from
+#[derive(Debug, Clone, Serialize)]
+pub struct ContributorBreakdown {
+    /// Canonical contributor id
+    pub contributor_id: String,
+    /// Identities linked to the contributor (excluding the canonical id)
+    pub identities: Vec<ContributorIdentity>,
+    pub weight: f64,
+    pub total_btc: f64,
+    pub contribution_count: i64,
+    pub first_contribution_at: Option<DateTime<Utc>>,
+    pub last_contribution_at: Option<DateTime<Utc>>,
+    pub sources: Vec<SourceBreakdown>,
+    pub weight_inputs: WeightInputs,
+}
+
+/// Contributor listing entry
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct ContributorSummary {
+    pub contributor_id: String,
+    pub weight: f64,
+    pub total_btc: f64,
+    pub contribution_count: i64,
+    pub first_contribution_at: Option<DateTime<Utc>>,
+    pub last_contribution_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Setup the contribution and participation weight tables
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query(
+            r#"
+            CREATE TABLE unified_contributions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                contributor_id TEXT NOT NULL,
+                contributor_type TEXT NOT NULL,
+                contribution_type TEXT NOT NULL,
+                amount_btc REAL NOT NULL,
+                timestamp DATETIME NOT NULL,
+                contribution_age_days INTEGER DEFAULT 0,
+                period_type TEXT NOT NULL,
+                verified BOOLEAN DEFAULT FALSE,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE participation_weights (
+                contributor_id TEXT PRIMARY KEY,
+                contributor_type TEXT NOT NULL,
+                merge_mining_btc REAL DEFAULT 0.0,
+                fee_forwarding_btc REAL DEFAULT 0.0,
+                cumulative_zaps_btc REAL DEFAULT 0.0,
+                total_contribution_btc REAL NOT NULL,
+                base_weight REAL NOT NULL,
+                capped_weight REAL NOT NULL,
+                total_system_weight REAL NOT NULL,
+                last_updated TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool
+    }
+
+    /// Setup tables the incremental aggregator keeps its state in
+    async fn setup_aggregation_tables(pool: &SqlitePool) {
+        sqlx::query(
+            r#"
+            CREATE TABLE contributor_running_totals (
+                contributor_id TEXT PRIMARY KEY,
+                contributor_type TEXT NOT NULL,
+                total_btc REAL NOT NULL DEFAULT 0.0,
+                zaps_btc REAL NOT NULL DEFAULT 0.0,
+                contribution_count INTEGER NOT NULL DEFAULT 0,
+                updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            );
+            "#,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE contribution_aggregation_state (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                last_contribution_id INTEGER NOT NULL DEFAULT 0,
+                updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            );
+            "#,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE contribution_anomalies (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                contribution_id INTEGER NOT NULL UNIQUE,
+                contributor_id TEXT NOT NULL,
+                contribution_type TEXT NOT NULL,
+                amount_btc REAL NOT NULL,
+                rolling_average_btc REAL NOT NULL,
+                threshold_multiplier REAL NOT NULL,
+                status TEXT NOT NULL DEFAULT 'pending',
+                detected_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                reviewed_at TIMESTAMP,
+                reviewed_by TEXT
+            );
+            "#,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    /// Setup the contributor identity table
+    async fn setup_identity_table(pool: &SqlitePool) {
+        sqlx::query(
+            r#"
+            CREATE TABLE contributor_identities (
+                identity TEXT PRIMARY KEY,
+                contributor_id TEXT NOT NULL,
+                identity_type TEXT NOT NULL,
+                linked_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            );
+            "#,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    /// Insert `count` zap contributions spread over `contributors` contributors
+    async fn insert_zaps(pool: &SqlitePool, count: usize, contributors: usize) {
+        let mut tx = pool.begin().await.unwrap();
+        for i in 0..count {
+            sqlx::query(
+                "INSERT INTO unified_contributions (contributor_id, contributor_type, contribution_type, amount_btc, timestamp, period_type) \
+                 VALUES (?, 'zap_user', 'zap:general', ?, CURRENT_TIMESTAMP, 'cumulative')",
+            )
+            .bind(format!("zapper-{}", i % contributors))
+            .bind(0.0001 * ((i % 7) + 1) as f64)
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+        }
+        tx.commit().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_aggregator_incremental_run_touches_only_delta() {
+        let pool = setup_test_db().await;
+        setup_aggregation_tables(&pool).await;
+        setup_identity_table(&pool).await;
+        let aggregator = ContributionAggregator::new(pool.clone());
+
+        insert_zaps(&pool, 10_000, 500).await;
+        let first = aggregator.update_all_weights().await.unwrap();
+        assert_eq!(first.processed_contributions, 10_000);
+        assert_eq!(first.updated_contributors, 500);
+
+        // Nothing new: nothing touched
+        assert_eq!(
+            aggregator.update_all_weights().await.unwrap(),
+            AggregationStats::default()
+        );
+
+        // 50 new contributions from 5 contributors
+        insert_zaps(&pool, 50, 5).await;
+        let second = aggregator.update_all_weights().await.unwrap();
+        assert_eq!(second.processed_contributions, 50);
+        assert_eq!(second.updated_contributors, 5);
+    }
+
+    #[tokio::test]
+    async fn test_aggregator_incremental_matches_full_rebuild() {
+        let pool = setup_test_db().await;
+        setup_aggregation_tables(&pool).await;
+        setup_identity_table(&pool).await;
+        let aggregator = ContributionAggregator::new(pool.clone());
+
+        // Several incremental runs over batches that overlap contributors
+        for batch in [120, 37, 1, 300] {
+            insert_zaps(&pool, batch, 13).await;
+            aggregator.update_all_weights().await.unwrap();
+        }
+
+        let mut incremental = Vec::new();
+        for i in 0..13 {
+            let id = format!("zapper-{}", i);
+            incremental.push(aggregator.get_running_totals(&id).await.unwrap().unwrap());
+            // Running totals agree with summing the raw rows
+            let (_, zaps_btc, _) = incremental[i];
+            let summed = aggregator.aggregate_zaps_cumulative(&id).await.unwrap();
+            assert!((zaps_btc - summed).abs() < 1e-9);
+        }
+
+        let rebuild = aggregator.force_full_rebuild().await.unwrap();
+        assert_eq!(rebuild.processed_contributions, 458);
+        for (i, before) in incremental.iter().enumerate() {
+            let after = aggregator
+                .get_running_totals(&format!("zapper-{}", i))
+                .await
+                .unwrap()
+                .unwrap();
+            assert!((after.0 - before.0).abs() < 1e-9);
+            assert!((after.1 - before.1).abs() < 1e-9);
+            assert_eq!(after.2, before.2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_contributor_breakdown_merges_linked_identities() {
+        let pool = setup_test_db().await;
+        setup_identity_table(&pool).await;
+        let tracker = ContributionTracker::new(pool.clone());
+        let aggregator = ContributionAggregator::new(pool.clone());
+
+        let pubkey = "npub1contributor";
+        let address = "bc1qcontributor";
+        let first = Utc::now() - chrono::Duration::days(10);
+        let last = Utc::now() - chrono::Duration::days(1);
+
+        // Zaps under the Nostr pubkey, fee forwarding under the Bitcoin address
+        tracker
+            .record_zap_contribution(pubkey, 0.01, first, false)
+            .await
+            .unwrap();
+        tracker
+            .record_zap_contribution(pubkey, 0.02, last, true)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO unified_contributions (contributor_id, contributor_type, contribution_type, amount_btc, timestamp, period_type) \
+             VALUES (?, 'fee_forwarder', 'fee_forwarding', 0.5, ?, 'cumulative')",
+        )
+        .bind(address)
+        .bind(Utc::now() - chrono::Duration::days(5))
+        .execute(&pool)
+        .await
+        .unwrap();
+        tracker
+            .record_zap_contribution("npub1other", 0.1, last, false)
+            .await
+            .unwrap();
+
+        for (contributor_id, weight) in [(pubkey, 1.5), (address, 2.5), ("npub1other", 3.0)] {
+            sqlx::query(
+                "INSERT INTO participation_weights (contributor_id, contributor_type, total_contribution_btc, base_weight, capped_weight, total_system_weight) \
+                 VALUES (?, 'zap_user', 0.0, ?, ?, 7.0)",
+            )
+            .bind(contributor_id)
+            .bind(weight)
+            .bind(weight)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        // Unlinked, the pubkey only sees its zaps
+        let unlinked = aggregator
+            .get_contributor_breakdown(pubkey)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(unlinked.contribution_count, 2);
+        assert_eq!(unlinked.weight, 1.5);
+
+        aggregator
+            .link_identity(pubkey, address, "bitcoin_address")
+            .await
+            .unwrap();
+        // Relinking is idempotent; linking elsewhere is rejected
+        aggregator
+            .link_identity(pubkey, address, "bitcoin_address")
+            .await
+            .unwrap();
+        assert!(aggregator
+            .link_identity("npub1other", address, "bitcoin_address")
+            .await
+            .is_err());
+
+        // Either identity resolves to the merged breakdown
+        for lookup in [pubkey, address] {
+            let breakdown = aggregator
+                .get_contributor_breakdown(lookup)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(breakdown.contributor_id, pubkey);
+            assert_eq!(breakdown.identities.len(), 1);
+            assert_eq!(breakdown.identities[0].identity, address);
+            assert_eq!(breakdown.contribution_count, 3);
+            assert!((breakdown.total_btc - 0.53).abs() < 1e-9);
+            assert_eq!(breakdown.weight, 4.0);
+            assert_eq!(breakdown.weight_inputs.base_weight, 4.0);
+            assert_eq!(breakdown.weight_inputs.total_system_weight, 7.0);
+            assert_eq!(
+                breakdown.first_contribution_at.unwrap().timestamp(),
+                first.timestamp()
+            );
+            assert_eq!(
+                breakdown.last_contribution_at.unwrap().timestamp(),
+                last.timestamp()
+            );
+
+            let sources: Vec<(&str, i64)> = breakdown
+                .sources
+                .iter()
+                .map(|s| (s.source.as_str(), s.contribution_count))
+                .collect();
+            assert_eq!(sources, vec![("fee_forwarding", 1), ("zap", 2)]);
+        }
+
+        // Listing merges the identities and sorts by weight
+        let page = aggregator
+            .list_contributors(ContributorSort::Weight, 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(page.total, 2);
+        let listed: Vec<(&str, f64)> = page
+            .items
+            .iter()
+            .map(|c| (c.contributor_id.as_str(), c.weight))
+            .collect();
+        assert_eq!(listed, vec![(pubkey, 4.0), ("npub1other", 3.0)]);
+
+        assert!(aggregator
+            .get_contributor_breakdown("npub1unknown")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_contribution_trend_and_top_contributors() {
+        let pool = setup_test_db().await;
+        setup_identity_table(&pool).await;
+        sqlx::query(
+            r#"
+            CREATE TABLE contribution_trends (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                contributor_id TEXT NOT NULL,
+                window_days INTEGER NOT NULL,
+                current_period_total REAL NOT NULL,
+                previous_period_total REAL NOT NULL,
+                growth_rate REAL,
+                moving_average_7d REAL NOT NULL,
+                moving_average_30d REAL NOT NULL,
+                snapshot_at TIMESTAMP NOT NULL
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let aggregator = ContributionAggregator::new(pool.clone());
+
+        // 0.7 BTC in the last 30 days (0.1 of it this week), 0.35 BTC the 30 days before
+        for (contributor_id, amount, days_ago) in [
+            ("npub1rising", 0.1, 3),
+            ("npub1rising", 0.6, 20),
+            ("npub1rising", 0.35, 40),
+            ("npub1steady", 0.2, 10),
+            ("npub1lapsed", 5.0, 90),
+        ] {
+            sqlx::query(
+                "INSERT INTO unified_contributions (contributor_id, contributor_type, contribution_type, amount_btc, timestamp, period_type) \
+                 VALUES (?, 'zap_user', 'zap', ?, ?, 'cumulative')",
+            )
+            .bind(contributor_id)
+            .bind(amount)
+            .bind(Utc::now() - chrono::Duration::days(days_ago))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let trend = aggregator.compute_trend("npub1rising", 30).await.unwrap();
+        assert_eq!(trend.contributor_id, "npub1rising");
+        assert!((trend.current_period_total - 0.7).abs() < 1e-9);
+        assert!((trend.previous_period_total - 0.35).abs() < 1e-9);
+        assert!((trend.growth_rate.unwrap() - 1.0).abs() < 1e-9);
+        assert!((trend.moving_average_7d - 0.1 / 7.0).abs() < 1e-9);
+        assert!((trend.moving_average_30d - 0.7 / 30.0).abs() < 1e-9);
+
+        // No previous contributions: growth is undefined
+        let steady = aggregator.compute_trend("npub1steady", 30).await.unwrap();
+        assert_eq!(steady.growth_rate, None);
+
+        let snapshots: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM contribution_trends WHERE contributor_id = 'npub1rising'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(snapshots, 1);
+
+        // Only contributions inside the window rank
+        let top = aggregator.get_top_contributors(10, 30).await.unwrap();
+        let ranked: Vec<&str> = top.iter().map(|(id, _, _)| id.as_str()).collect();
+        assert_eq!(ranked, vec!["npub1rising", "npub1steady"]);
+        assert!((top[0].1 - 0.7).abs() < 1e-9);
+        assert_eq!(top[0].2.window_days, 30);
+        assert_eq!(
+            aggregator.get_top_contributors(1, 30).await.unwrap().len(),
+            1
+        );
+
+        assert!(aggregator.compute_trend("npub1rising", 0).await.is_err());
+        assert!(aggregator.get_top_contributors(10, 0).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_contribution_anomalies_hold_weight_updates() {
+        let pool = setup_test_db().await;
+        setup_aggregation_tables(&pool).await;
+        setup_identity_table(&pool).await;
+        let aggregator = ContributionAggregator::new(pool.clone());
+
+        let insert = |contributor_id: &'static str, amount: f64, days_ago: i64| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query(
+                    "INSERT INTO unified_contributions (contributor_id, contributor_type, contribution_type, amount_btc, timestamp, period_type) \
+                     VALUES (?, 'zap_user', 'zap:general', ?, ?, 'cumulative')",
+                )
+                .bind(contributor_id)
+                .bind(amount)
+                .bind(Utc::now() - chrono::Duration::days(days_ago))
+                .execute(&pool)
+                .await
+                .unwrap();
+            }
+        };
+
+        // Baseline: 0.001 BTC every ten days, already folded into the weights.
+        // A contribution from before the 90-day window doesn't count.
+        insert("npub1steady", 0.0001, 200).await;
+        for days_ago in [30, 20, 10] {
+            insert("npub1steady", 0.001, days_ago).await;
+        }
+        insert("npub1regular", 0.001, 15).await;
+        aggregator.update_all_weights().await.unwrap();
+        assert!(aggregator.detect_anomalies(5.0).await.unwrap().is_empty());
+
+        // 10x the average is flagged; 2x and a first contribution are not
+        insert("npub1steady", 0.01, 0).await;
+        insert("npub1regular", 0.002, 0).await;
+        insert("npub1newcomer", 1.0, 0).await;
+        let flagged = aggregator.detect_anomalies(5.0).await.unwrap();
+        assert_eq!(flagged.len(), 1);
+        let anomaly = &flagged[0];
+        assert_eq!(anomaly.contributor_id, "npub1steady");
+        assert_eq!(anomaly.status, "pending");
+        assert!((anomaly.rolling_average_btc - 0.001).abs() < 1e-9);
+        assert!(aggregator.detect_anomalies(5.0).await.unwrap().is_empty());
+        assert!(aggregator.detect_anomalies(0.0).await.is_err());
+
+        // The pending anomaly holds back the flagged contributor's weight update
+        let stats = aggregator.update_all_weights().await.unwrap();
+        assert_eq!(stats.processed_contributions, 3);
+        assert_eq!(stats.updated_contributors, 2);
+        assert_eq!(stats.held_contributors, 1);
+
+        let pending = aggregator
+            .list_anomalies(Some(AnomalyStatus::Pending), 10, 0)
+            .await
+            .unwrap();
+        assert_eq!(pending.total, 1);
+
+        assert!(aggregator
+            .review_anomaly(anomaly.id, AnomalyStatus::Pending, "alice")
+            .await
+            .is_err());
+        let reviewed = aggregator
+            .review_anomaly(anomaly.id, AnomalyStatus::Dismissed, "alice")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reviewed.status, "dismissed");
+        assert_eq!(reviewed.reviewed_by.as_deref(), Some("alice"));
+        assert!(aggregator
+            .review_anomaly(9999, AnomalyStatus::Reviewed, "alice")
+            .await
+            .unwrap()
+            .is_none());
+
+        // Once reviewed, large changes go through again
+        insert("npub1steady", 0.05, 0).await;
+        let stats = aggregator.update_all_weights().await.unwrap();
+        assert_eq!(stats.updated_contributors, 1);
+        assert_eq!(stats.held_contributors, 0);
+        assert_eq!(
+            aggregator.list_anomalies(None, 10, 0).await.unwrap().total,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_contribution_anomalies_hold_until_reviewed() {
+        let pool = setup_test_db().await;
+        setup_aggregation_tables(&pool).await;
+        setup_identity_table(&pool).await;
+        let aggregator = ContributionAggregator::new(pool.clone());
+
+        let insert = |contributor_id: &'static str, amount: f64, days_ago: i64| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query(
+                    "INSERT INTO unified_contributions (contributor_id, contributor_type, contribution_type, amount_btc, timestamp, period_type) \
+                     VALUES (?, 'zap_user', 'zap:general', ?, ?, 'cumulative')",
+                )
+                .bind(contributor_id)
+                .bind(amount)
+                .bind(Utc::now() - chrono::Duration::days(days_ago))
+                .execute(&pool)
+                .await
+                .unwrap();
+            }
+        };
+
+        for days_ago in [30, 20, 10] {
+            insert("npub1steady", 0.001, days_ago).await;
+        }
+        aggregator.update_all_weights().await.unwrap();
+
+        // A large contribution from a newly linked address is compared with the
+        // contributor's history under their pubkey
+        aggregator
+            .link_identity("npub1steady", "bc1qsteady", "bitcoin_address")
+            .await
+            .unwrap();
+        insert("bc1qsteady", 0.01, 0).await;
+        let flagged = aggregator.detect_anomalies(5.0).await.unwrap();
+        assert_eq!(flagged.len(), 1);
+        assert_eq!(flagged[0].contributor_id, "bc1qsteady");
+        let stats = aggregator.update_all_weights().await.unwrap();
+        assert_eq!(stats.held_contributors, 1);
+
+        // A small contribution before the review stays held: the total is still
+        // compared with the one before the anomaly
+        insert("bc1qsteady", 0.0001, 0).await;
+        assert!(aggregator.detect_anomalies(5.0).await.unwrap().is_empty());
+        let stats = aggregator.update_all_weights().await.unwrap();
+        assert_eq!(stats.updated_contributors, 0);
+        assert_eq!(stats.held_contributors, 1);
+
+        // So does a small contribution under the linked pubkey
+        insert("npub1steady", 0.0001, 0).await;
+        let stats = aggregator.update_all_weights().await.unwrap();
+        assert_eq!(stats.updated_contributors, 0);
+        assert_eq!(stats.held_contributors, 1);
+
+        aggregator
+            .review_anomaly(flagged[0].id, AnomalyStatus::Reviewed, "alice")
+            .await
+            .unwrap()
+            .unwrap();
+        insert("bc1qsteady", 0.0001, 0).await;
+        let stats = aggregator.update_all_weights().await.unwrap();
+        assert_eq!(stats.updated_contributors, 1);
+        assert_eq!(stats.held_contributors, 0);
+    }
+}
@@ -0,0 +1,535 @@
+//! Internal governance API endpoints
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::api_auth::require_internal_api_key;
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::error::GovernanceError;
+use crate::governance::{
+    AdaptiveParameters, AnomalyStatus, ContributionAggregator, ContributionAnomaly,
+    ContributionTrend, ContributorBreakdown, ContributorSort, ContributorSummary, GovernancePhase,
+    GovernancePhaseCalculator, PhaseMetrics, PhaseOverride, PhaseTransition, MAX_TREND_WINDOW_DAYS,
+};
+use crate::node_registry::api::Pagination;
+use crate::validation::review_overlap::{find_concurrent_reviews, ConcurrentReview};
+
+/// Page size when the client doesn't specify one
+const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Largest page a client may request
+const MAX_PAGE_SIZE: i64 = 500;
+
+/// Trend window when the client doesn't specify one
+const DEFAULT_TREND_WINDOW_DAYS: u32 = 30;
+
+/// Public governance phase response
+#[derive(Debug, Serialize)]
+pub struct GovernancePhaseResponse {
+    pub phase: String,
+    pub adaptive_parameters: AdaptiveParameters,
+    pub metrics: PhaseMetrics,
+    pub last_transition: Option<PhaseTransition>,
+}
+
+/// Current phase response (internal, includes the override)
+#[derive(Debug, Serialize)]
+pub struct PhaseStatusResponse {
+    /// Phase in effect (the override if one is set)
+    pub phase: String,
+    /// Phase derived from the metrics alone
+    pub computed_phase: String,
+    pub metrics: PhaseMetrics,
+    #[serde(rename = "override")]
+    pub phase_override: Option<PhaseOverride>,
+}
+
+/// List contributors query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct ListContributorsQuery {
+    /// "weight" (default), "total_btc", "contributions" or "last_contribution"
+    pub sort: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// List contributors response
+#[derive(Debug, Serialize)]
+pub struct ListContributorsResponse {
+    pub contributors: Vec<ContributorSummary>,
+    pub pagination: Pagination,
+}
+
+/// Contribution trend query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct TrendQuery {
+    /// Rolling window in days (default 30)
+    pub window: Option<u32>,
+}
+
+/// Top contributors query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct TopContributorsQuery {
+    /// Rolling window in days (default 30)
+    pub window: Option<u32>,
+    pub limit: Option<i64>,
+}
+
+/// Leaderboard entry
+#[derive(Debug, Serialize)]
+pub struct TopContributor {
+    pub contributor_id: String,
+    /// BTC contributed in the window
+    pub total_btc: f64,
+    pub trend: ContributionTrend,
+}
+
+/// List contribution anomalies query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct ListAnomaliesQuery {
+    /// "pending", "reviewed" or "dismissed" (default: all)
+    pub status: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// List contribution anomalies response
+#[derive(Debug, Serialize)]
+pub struct ListAnomaliesResponse {
+    pub anomalies: Vec<ContributionAnomaly>,
+    pub pagination: Pagination,
+}
+
+/// Review anomaly request
+#[derive(Debug, Deserialize)]
+pub struct ReviewAnomalyRequest {
+    /// "reviewed" or "dismissed"
+    pub status: String,
+    pub reviewed_by: String,
+}
+
+/// Set phase override request; a null phase clears the override
+#[derive(Debug, Deserialize)]
+pub struct SetPhaseOverrideRequest {
+    pub phase: Option<String>,
+    pub reason: String,
+    pub set_by: String,
+}
+
+/// Phase override response
+#[derive(Debug, Serialize)]
+pub struct PhaseOverrideResponse {
+    pub success: bool,
+    pub message: String,
+}
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+fn phase_calculator(database: &Database) -> Result<GovernancePhaseCalculator, ApiError> {
+    database
+        .get_sqlite_pool()
+        .map(GovernancePhaseCalculator::new)
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Database pool not available",
+            )
+        })
+}
+
+/// Get the governance phase, its adaptive parameters and the last transition
+pub async fn get_governance_phase(
+    State((_, database)): State<(AppConfig, Database)>,
+) -> Result<Json<GovernancePhaseResponse>, ApiError> {
+    let calculator = phase_calculator(&database)?;
+    let result = async {
+        let phase = calculator.get_current_phase().await?;
+        let adaptive_parameters = calculator.get_adaptive_parameters().await?;
+        let metrics = calculator.get_phase_metrics().await?;
+        let last_transition = calculator.get_last_transition().await?;
+        Ok::<_, GovernanceError>(GovernancePhaseResponse {
+            phase: phase.as_str().to_string(),
+            adaptive_parameters,
+            metrics,
+            last_transition,
+        })
+    }
+    .await;
+
+    result.map(Json).map_err(|e| {
+        warn!("Failed to get governance phase: {}", e);
+        api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+    })
+}
+
+/// Get the current governance phase and any override
+pub async fn get_phase(
+    State((_, database)): State<(AppConfig, Database)>,
+) -> Result<Json<PhaseStatusResponse>, ApiError> {
+    let calculator = phase_calculator(&database)?;
+    let result = async {
+        let metrics = calculator.get_phase_metrics().await?;
+        let phase_override = calculator.get_phase_override().await?;
+        Ok::<_, GovernanceError>((metrics, phase_override))
+    }
+    .await;
+
+    match result {
+        Ok((metrics, phase_override)) => {
+            let computed_phase = GovernancePhaseCalculator::phase_for_metrics(&metrics);
+            let phase = phase_override
+                .as_ref()
+                .map(|phase_override| phase_override.phase.clone())
+                .unwrap_or_else(|| computed_phase.as_str().to_string());
+            Ok(Json(PhaseStatusResponse {
+                phase,
+                computed_phase: computed_phase.as_str().to_string(),
+                metrics,
+                phase_override,
+            }))
+        }
+        Err(e) => {
+            warn!("Failed to get governance phase: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+/// Set or clear the governance phase override
+pub async fn set_phase_override(
+    State((_, database)): State<(AppConfig, Database)>,
+    Json(request): Json<SetPhaseOverrideRequest>,
+) -> Result<Json<PhaseOverrideResponse>, ApiError> {
+    let phase = match request.phase.as_deref() {
+        None => None,
+        Some(name) => Some(GovernancePhase::parse(&name.to_lowercase()).ok_or_else(|| {
+            api_error(
+                StatusCode::BAD_REQUEST,
+                format!("Unknown governance phase: {}", name),
+            )
+        })?),
+    };
+    if request.set_by.trim().is_empty() {
+        return Err(api_error(StatusCode::BAD_REQUEST, "set_by is required"));
+    }
+
+    let calculator = phase_calculator(&database)?;
+    match calculator
+        .set_phase_override(phase, &request.reason, &request.set_by)
+        .await
+    {
+        Ok(()) => Ok(Json(PhaseOverrideResponse {
+            success: true,
+            message: match phase {
+                Some(phase) => format!("Governance phase pinned to {}", phase.as_str()),
+                None => "Governance phase override cleared".to_string(),
+            },
+        })),
+        Err(GovernanceError::ValidationError(message)) => {
+            Err(api_error(StatusCode::BAD_REQUEST, message))
+        }
+        Err(e) => {
+            warn!("Failed to set governance phase override: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+fn contribution_aggregator(database: &Database) -> Result<ContributionAggregator, ApiError> {
+    database
+        .get_sqlite_pool()
+        .map(ContributionAggregator::new)
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Database pool not available",
+            )
+        })
+}
+
+/// Get a contributor's weight and per-source breakdown (linked identities merged)
+pub async fn get_contributor(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(contributor_id): Path<String>,
+) -> Result<Json<ContributorBreakdown>, ApiError> {
+    let aggregator = contribution_aggregator(&database)?;
+    match aggregator.get_contributor_breakdown(&contributor_id).await {
+        Ok(Some(breakdown)) => Ok(Json(breakdown)),
+        Ok(None) => Err(api_error(
+            StatusCode::NOT_FOUND,
+            format!("Unknown contributor: {}", contributor_id),
+        )),
+        Err(e) => {
+            warn!("Failed to get contributor {}: {}", contributor_id, e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+/// List contributors, paginated and sorted (by weight by default)
+pub async fn list_contributors(
+    State((_, database)): State<(AppConfig, Database)>,
+    Query(query): Query<ListContributorsQuery>,
+) -> Result<Json<ListContributorsResponse>, ApiError> {
+    let sort = match query.sort.as_deref() {
+        None => ContributorSort::default(),
+        Some(name) => ContributorSort::from_str(&name.to_lowercase()).ok_or_else(|| {
+            api_error(
+                StatusCode::BAD_REQUEST,
+                format!("Unknown sort order: {}", name),
+            )
+        })?,
+    };
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let aggregator = contribution_aggregator(&database)?;
+    match aggregator.list_contributors(sort, limit, offset).await {
+        Ok(page) => Ok(Json(ListContributorsResponse {
+            pagination: Pagination {
+                total: page.total,
+                limit,
+                offset: page.offset,
+                has_more: page.offset + (page.items.len() as i64) < page.total,
+            },
+            contributors: page.items,
+        })),
+        Err(e) => {
+            warn!("Failed to list contributors: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+fn trend_window(window: Option<u32>) -> Result<u32, ApiError> {
+    let window = window.unwrap_or(DEFAULT_TREND_WINDOW_DAYS);
+    if !(1..=MAX_TREND_WINDOW_DAYS).contains(&window) {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "window must be between 1 and {} days",
+                MAX_TREND_WINDOW_DAYS
+            ),
+        ));
+    }
+    Ok(window)
+}
+
+/// A contributor's rolling-window contribution trend (recorded as a snapshot)
+pub async fn get_contributor_trend(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(contributor_id): Path<String>,
+    Query(query): Query<TrendQuery>,
+) -> Result<Json<ContributionTrend>, ApiError> {
+    let window = trend_window(query.window)?;
+    let aggregator = contribution_aggregator(&database)?;
+    let known = aggregator
+        .get_contributor_breakdown(&contributor_id)
+        .await
+        .map_err(|e| {
+            warn!("Failed to get contributor {}: {}", contributor_id, e);
+            api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        })?
+        .is_some();
+    if !known {
+        return Err(api_error(
+            StatusCode::NOT_FOUND,
+            format!("Unknown contributor: {}", contributor_id),
+        ));
+    }
+
+    aggregator
+        .compute_trend(&contributor_id, window)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            warn!(
+                "Failed to compute trend for contributor {}: {}",
+                contributor_id, e
+            );
+            api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        })
+}
+
+/// Contributors with the most BTC contributed in the window, with their trends
+pub async fn get_top_contributors(
+    State((_, database)): State<(AppConfig, Database)>,
+    Query(query): Query<TopContributorsQuery>,
+) -> Result<Json<Vec<TopContributor>>, ApiError> {
+    let window = trend_window(query.window)?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+
+    let aggregator = contribution_aggregator(&database)?;
+    match aggregator
+        .get_top_contributors(limit as usize, window)
+        .await
+    {
+        Ok(top) => Ok(Json(
+            top.into_iter()
+                .map(|(contributor_id, total_btc, trend)| TopContributor {
+                    contributor_id,
+                    total_btc,
+                    trend,
+                })
+                .collect(),
+        )),
+        Err(e) => {
+            warn!("Failed to get top contributors: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+fn anomaly_status(name: &str) -> Result<AnomalyStatus, ApiError> {
+    AnomalyStatus::parse(&name.to_lowercase()).ok_or_else(|| {
+        api_error(
+            StatusCode::BAD_REQUEST,
+            format!("Unknown anomaly status: {}", name),
+        )
+    })
+}
+
+/// List flagged contributions, newest first
+pub async fn list_anomalies(
+    State((_, database)): State<(AppConfig, Database)>,
+    Query(query): Query<ListAnomaliesQuery>,
+) -> Result<Json<ListAnomaliesResponse>, ApiError> {
+    let status = query.status.as_deref().map(anomaly_status).transpose()?;
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let aggregator = contribution_aggregator(&database)?;
+    match aggregator.list_anomalies(status, limit, offset).await {
+        Ok(page) => Ok(Json(ListAnomaliesResponse {
+            pagination: Pagination {
+                total: page.total,
+                limit,
+                offset: page.offset,
+                has_more: page.offset + (page.items.len() as i64) < page.total,
+            },
+            anomalies: page.items,
+        })),
+        Err(e) => {
+            warn!("Failed to list contribution anomalies: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+/// Mark a flagged contribution reviewed or dismissed
+pub async fn review_anomaly(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(anomaly_id): Path<i64>,
+    Json(request): Json<ReviewAnomalyRequest>,
+) -> Result<Json<ContributionAnomaly>, ApiError> {
+    let status = anomaly_status(&request.status)?;
+    if status == AnomalyStatus::Pending {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "status must be reviewed or dismissed",
+        ));
+    }
+    if request.reviewed_by.trim().is_empty() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "reviewed_by is required",
+        ));
+    }
+
+    let aggregator = contribution_aggregator(&database)?;
+    match aggregator
+        .review_anomaly(anomaly_id, status, &request.reviewed_by)
+        .await
+    {
+        Ok(Some(anomaly)) => Ok(Json(anomaly)),
+        Ok(None) => Err(api_error(
+            StatusCode::NOT_FOUND,
+            format!("Unknown anomaly: {}", anomaly_id),
+        )),
+        Err(e) => {
+            warn!("Failed to review anomaly {}: {}", anomaly_id, e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+/// Open Tier 3+ PRs whose review periods overlap another in the same layer
+pub async fn get_concurrent_reviews(
+    State((_, database)): State<(AppConfig, Database)>,
+) -> Result<Json<Vec<ConcurrentReview>>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    find_concurrent_reviews(pool).await.map(Json).map_err(|e| {
+        warn!("Failed to find concurrent reviews: {}", e);
+        api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+    })
+}
+
+/// Create router for governance API; /internal routes require the internal API key
+pub fn create_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    let internal = Router::new()
+        .route("/internal/governance/phase", get(get_phase))
+        .route(
+            "/internal/governance/phase/override",
+            post(set_phase_override),
+        )
+        .route(
+            "/internal/governance/concurrent-reviews",
+            get(get_concurrent_reviews),
+        )
+        .route(
+            "/internal/governance/contributors/top",
+            get(get_top_contributors),
+        )
+        .route(
+            "/internal/governance/contributors/:contributor_id/trend",
+            get(get_contributor_trend),
+        )
+        .route("/internal/governance/anomalies", get(list_anomalies))
+        .route(
+            "/internal/governance/anomalies/:anomaly_id/review",
+            post(review_anomaly),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ));
+
+    Router::new()
+        .route("/governance/phase", get(get_governance_phase))
+        .route("/governance/contributors", get(list_contributors))
+        .route(
+            "/governance/contributors/:contributor_id",
+            get(get_contributor),
+        )
+        .merge(internal)
+}
@@ -8,6 +8,8 @@ use chrono::{DateTime, Utc};
 use sqlx::SqlitePool;
 use tracing::info;
 
+use crate::database::slow_query::time_query;
+
 /// Contribution tracking service
 pub struct ContributionTracker {
     pool: SqlitePool,
@@ -37,22 +39,23 @@ impl ContributionTracker {
         } else {
             "zap:general"
         };
-        sqlx::query(
-            r#"
+        let sql = r#"
             INSERT INTO unified_contributions
             (contributor_id, contributor_type, contribution_type, amount_btc, timestamp, contribution_age_days, period_type, verified)
             VALUES (?, ?, ?, ?, ?, 0, ?, ?)
-            "#,
-        )
-        .bind(contributor_id)
-        .bind("zap_user")
-        .bind(contribution_type)
-        .bind(amount_btc)
-        .bind(timestamp)
-        .bind("cumulative")
-        .bind(true)  // Verified (Nostr event)
-        .execute(&self.pool)
-        .await?;
+            "#;
+        let timing = time_query(sql);
+        let result = sqlx::query(sql)
+            .bind(contributor_id)
+            .bind("zap_user")
+            .bind(contribution_type)
+            .bind(amount_btc)
+            .bind(timestamp)
+            .bind("cumulative")
+            .bind(true) // Verified (Nostr event)
+            .execute(&self.pool)
+            .await?;
+        timing.finish(result.rows_affected());
 
         Ok(())
     }
@@ -64,8 +67,7 @@ impl ContributionTracker {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
     ) -> Result<ContributorTotal> {
-        let rows = sqlx::query_as::<_, (String, Option<f64>)>(
-            r#"
+        let sql = r#"
             SELECT 
                 contribution_type,
                 SUM(amount_btc) as total_btc
@@ -74,13 +76,15 @@ impl ContributionTracker {
               AND timestamp >= ?
               AND timestamp <= ?
             GROUP BY contribution_type
-            "#,
-        )
-        .bind(contributor_id)
-        .bind(start_time)
-        .bind(end_time)
-        .fetch_all(&self.pool)
-        .await?;
+            "#;
+        let timing = time_query(sql);
+        let rows = sqlx::query_as::<_, (String, Option<f64>)>(sql)
+            .bind(contributor_id)
+            .bind(start_time)
+            .bind(end_time)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(rows.len() as u64);
 
         let mut zaps_btc = 0.0;
 
@@ -99,8 +103,7 @@ impl ContributionTracker {
 
     /// Update contribution age for cooling-off period calculation
     pub async fn update_contribution_ages(&self) -> Result<()> {
-        sqlx::query(
-            r#"
+        let sql = r#"
             UPDATE unified_contributions
             SET contribution_age_days = CAST(
                 (julianday('now') - julianday(timestamp)) AS INTEGER
@@ -108,10 +111,10 @@ impl ContributionTracker {
             WHERE contribution_age_days != CAST(
                 (julianday('now') - julianday(timestamp)) AS INTEGER
             )
-            "#,
-        )
-        .execute(&self.pool)
-        .await?;
+            "#;
+        let timing = time_query(sql);
+        let result = sqlx::query(sql).execute(&self.pool).await?;
+        timing.finish(result.rows_affected());
 
         Ok(())
     }
@@ -3,14 +3,25 @@
 //! Handles governance contribution tracking, weight calculation, and voting.
 
 pub mod aggregator;
+pub mod api;
 pub mod contributions;
 pub mod phase_calculator;
 pub mod time_lock;
 pub mod vote_aggregator;
 pub mod weight_calculator;
+pub mod yaml_writer;
 
-pub use aggregator::{ContributionAggregator, ContributorAggregates};
+pub use aggregator::{
+    AggregationStats, AnomalyStatus, ContributionAggregator, ContributionAnomaly,
+    ContributionTrend, ContributorAggregates, ContributorBreakdown, ContributorIdentity,
+    ContributorSort, ContributorSummary, SourceBreakdown, WeightInputs, ANOMALY_BASELINE_DAYS,
+    DEFAULT_ANOMALY_THRESHOLD, DEFAULT_ANOMALY_WEIGHT_MULTIPLIER, MAX_TREND_WINDOW_DAYS,
+};
 pub use contributions::{ContributionTracker, ContributorTotal};
-pub use phase_calculator::{AdaptiveParameters, GovernancePhase, GovernancePhaseCalculator};
+pub use phase_calculator::{
+    AdaptiveParameters, GovernancePhase, GovernancePhaseCalculator, PhaseHysteresis, PhaseMetrics,
+    PhaseOverride, PhaseTransition,
+};
 pub use vote_aggregator::{ProposalVoteResult, VoteAggregator};
-pub use weight_calculator::WeightCalculator;
+pub use weight_calculator::{DecayConfig, WeightCalculator};
+pub use yaml_writer::{ConfigPrTarget, YamlConfigWriter, YamlKeyMapping};
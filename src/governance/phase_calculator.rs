@@ -7,10 +7,16 @@
 //!
 //! Uses conservative logic: takes most conservative (earliest) phase from all metrics.
 
+use chrono::{DateTime, Utc};
+use nostr_sdk::prelude::{EventBuilder, Kind, Tag, TagKind};
+use serde::Serialize;
 use sqlx::SqlitePool;
-use tracing::info;
+use tracing::{debug, info, warn};
 
+use crate::audit::entry::execute_with_audit;
+use crate::audit::AuditLogger;
 use crate::error::GovernanceError;
+use crate::nostr::NostrClient;
 
 /// Governance maturity phases
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -31,54 +37,510 @@ impl GovernancePhase {
             GovernancePhase::Mature => "mature",
         }
     }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "early" => Some(GovernancePhase::Early),
+            "growth" => Some(GovernancePhase::Growth),
+            "mature" => Some(GovernancePhase::Mature),
+            _ => None,
+        }
+    }
+}
+
+/// Metrics the phase was derived from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PhaseMetrics {
+    pub block_height: u64,
+    pub economic_nodes: u32,
+    pub contributors: u32,
+}
+
+/// Operator-set phase that takes precedence over the computed phase
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseOverride {
+    pub phase: String,
+    pub reason: String,
+    pub set_by: String,
+    pub set_at: DateTime<Utc>,
+}
+
+/// A recorded change of governance phase
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseTransition {
+    pub old_phase: Option<String>,
+    pub new_phase: String,
+    pub transitioned_at: DateTime<Utc>,
+    pub metrics: PhaseMetrics,
+}
+
+#[derive(sqlx::FromRow)]
+struct PhaseTransitionRow {
+    old_phase: Option<String>,
+    new_phase: String,
+    transitioned_at: DateTime<Utc>,
+    block_height: i64,
+    economic_nodes: i64,
+    contributors: i64,
+}
+
+impl From<PhaseTransitionRow> for PhaseTransition {
+    fn from(row: PhaseTransitionRow) -> Self {
+        Self {
+            old_phase: row.old_phase,
+            new_phase: row.new_phase,
+            transitioned_at: row.transitioned_at,
+            metrics: PhaseMetrics {
+                block_height: row.block_height as u64,
+                economic_nodes: row.economic_nodes as u32,
+                contributors: row.contributors as u32,
+            },
+        }
+    }
+}
+
+/// How long a new phase must hold before it is recorded as a transition
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseHysteresis {
+    /// Consecutive evaluations the new phase must hold for
+    pub min_consecutive_evaluations: u32,
+    /// Alternatively, days the new phase must hold for (0 disables)
+    pub min_days: f64,
+}
+
+impl Default for PhaseHysteresis {
+    /// No hysteresis: a change is recorded on the first evaluation that sees it
+    fn default() -> Self {
+        Self {
+            min_consecutive_evaluations: 1,
+            min_days: 0.0,
+        }
+    }
+}
+
+impl PhaseHysteresis {
+    fn is_satisfied(&self, consecutive_evaluations: u32, first_seen_at: DateTime<Utc>) -> bool {
+        let held_days = (Utc::now() - first_seen_at).num_seconds() as f64 / 86400.0;
+        consecutive_evaluations >= self.min_consecutive_evaluations.max(1)
+            || (self.min_days > 0.0 && held_days >= self.min_days)
+    }
 }
 
 /// Governance phase calculator
 pub struct GovernancePhaseCalculator {
     pool: SqlitePool,
+    hysteresis: PhaseHysteresis,
+    audit: Option<(AuditLogger, String)>,
 }
 
 impl GovernancePhaseCalculator {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            hysteresis: PhaseHysteresis::default(),
+            audit: None,
+        }
+    }
+
+    /// Require a new phase to hold before recording the transition
+    pub fn with_hysteresis(mut self, hysteresis: PhaseHysteresis) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    /// Write an audit log entry for each recorded transition
+    pub fn with_audit_logger(mut self, logger: AuditLogger, server_id: impl Into<String>) -> Self {
+        self.audit = Some((logger, server_id.into()));
+        self
     }
 
     /// Get current governance phase based on measurable metrics
     /// Uses conservative logic: takes most conservative (earliest) phase
     pub async fn get_current_phase(&self) -> Result<GovernancePhase, GovernanceError> {
-        let block_height = self.get_block_height().await?;
-        let economic_nodes = self.get_economic_node_count().await?;
-        let contributors = self.get_contributor_count().await?;
+        if let Some(phase_override) = self.get_phase_override().await? {
+            info!(
+                "Governance phase override active: {} (set by {}: {})",
+                phase_override.phase, phase_override.set_by, phase_override.reason
+            );
+            if let Some(phase) = GovernancePhase::parse(&phase_override.phase) {
+                return Ok(phase);
+            }
+        }
+
+        let metrics = self.get_phase_metrics().await?;
+        let phase = Self::phase_for_metrics(&metrics);
+
+        info!(
+            "Governance phase: {} (height: {}, nodes: {}, contributors: {})",
+            phase.as_str(),
+            metrics.block_height,
+            metrics.economic_nodes,
+            metrics.contributors
+        );
+
+        Ok(phase)
+    }
+
+    /// Pin the phase, or clear the pin with `None`
+    ///
+    /// Every change is kept in `phase_overrides`; the latest row is in effect.
+    pub async fn set_phase_override(
+        &self,
+        phase: Option<GovernancePhase>,
+        reason: &str,
+        set_by: &str,
+    ) -> Result<(), GovernanceError> {
+        if reason.trim().is_empty() {
+            return Err(GovernanceError::ValidationError(
+                "Phase override requires a reason".to_string(),
+            ));
+        }
+
+        sqlx::query(
+            "INSERT INTO phase_overrides (phase, reason, set_by, set_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(phase.map(|phase| phase.as_str()))
+        .bind(reason.trim())
+        .bind(set_by)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await?;
+
+        match phase {
+            Some(phase) => info!(
+                "Governance phase pinned to {} by {}: {}",
+                phase.as_str(),
+                set_by,
+                reason
+            ),
+            None => info!(
+                "Governance phase override cleared by {}: {}",
+                set_by, reason
+            ),
+        }
+        Ok(())
+    }
+
+    /// The phase override in effect, if any
+    pub async fn get_phase_override(&self) -> Result<Option<PhaseOverride>, GovernanceError> {
+        let row: Option<(Option<String>, String, String, DateTime<Utc>)> = sqlx::query_as(
+            "SELECT phase, reason, set_by, set_at FROM phase_overrides ORDER BY id DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.and_then(|(phase, reason, set_by, set_at)| {
+            phase.map(|phase| PhaseOverride {
+                phase,
+                reason,
+                set_by,
+                set_at,
+            })
+        }))
+    }
+
+    /// Collect the metrics the phase is derived from
+    pub async fn get_phase_metrics(&self) -> Result<PhaseMetrics, GovernanceError> {
+        Ok(PhaseMetrics {
+            block_height: self.get_block_height().await?,
+            economic_nodes: self.get_economic_node_count().await?,
+            contributors: self.get_contributor_count().await?,
+        })
+    }
 
+    /// Most conservative phase across all metrics
+    pub fn phase_for_metrics(metrics: &PhaseMetrics) -> GovernancePhase {
         // Calculate phase for each metric
-        let height_phase = Self::determine_phase_by_height(block_height);
-        let node_phase = Self::determine_phase_by_nodes(economic_nodes);
-        let contributor_phase = Self::determine_phase_by_contributors(contributors);
+        let height_phase = Self::determine_phase_by_height(metrics.block_height);
+        let node_phase = Self::determine_phase_by_nodes(metrics.economic_nodes);
+        let contributor_phase = Self::determine_phase_by_contributors(metrics.contributors);
 
         // Take most conservative (earliest) phase
-        let phase = [height_phase, node_phase, contributor_phase]
+        [height_phase, node_phase, contributor_phase]
             .iter()
             .min()
             .copied()
-            .unwrap_or(GovernancePhase::Early);
+            .unwrap_or(GovernancePhase::Early)
+    }
+
+    /// Most recently recorded phase, if any
+    pub async fn get_last_recorded_phase(
+        &self,
+    ) -> Result<Option<GovernancePhase>, GovernanceError> {
+        let phase: Option<String> =
+            sqlx::query_scalar("SELECT new_phase FROM phase_transitions ORDER BY id DESC LIMIT 1")
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(phase.as_deref().and_then(GovernancePhase::parse))
+    }
+
+    /// Most recent change of phase (the initial baseline doesn't count)
+    pub async fn get_last_transition(&self) -> Result<Option<PhaseTransition>, GovernanceError> {
+        let row = sqlx::query_as::<_, PhaseTransitionRow>(
+            r#"
+            SELECT old_phase, new_phase, transitioned_at, block_height, economic_nodes, contributors
+            FROM phase_transitions
+            WHERE old_phase IS NOT NULL
+            ORDER BY id DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(row.map(PhaseTransition::from))
+    }
+
+    /// Recorded phase transitions, newest first
+    pub async fn get_phase_transitions(&self) -> Result<Vec<PhaseTransition>, GovernanceError> {
+        let rows = sqlx::query_as::<_, PhaseTransitionRow>(
+            r#"
+            SELECT old_phase, new_phase, transitioned_at, block_height, economic_nodes, contributors
+            FROM phase_transitions
+            ORDER BY id DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(PhaseTransition::from).collect())
+    }
+
+    async fn record_phase_transition(
+        &self,
+        old_phase: Option<GovernancePhase>,
+        new_phase: GovernancePhase,
+        metrics: &PhaseMetrics,
+    ) -> Result<(), GovernanceError> {
+        sqlx::query(
+            r#"
+            INSERT INTO phase_transitions
+            (old_phase, new_phase, transitioned_at, block_height, economic_nodes, contributors)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(old_phase.map(|phase| phase.as_str()))
+        .bind(new_phase.as_str())
+        .bind(Utc::now())
+        .bind(metrics.block_height as i64)
+        .bind(metrics.economic_nodes as i64)
+        .bind(metrics.contributors as i64)
+        .execute(&self.pool)
+        .await?;
+        crate::metrics::set_gauge(
+            crate::metrics::GOVERNANCE_PHASE,
+            &[],
+            new_phase as i32 as f64,
+        );
+
+        Ok(())
+    }
+
+    /// Count another evaluation for a candidate phase; true once hysteresis is met
+    async fn track_phase_candidate(&self, phase: GovernancePhase) -> Result<bool, GovernanceError> {
+        let existing: Option<(String, DateTime<Utc>, i64)> = sqlx::query_as(
+            "SELECT candidate_phase, first_seen_at, consecutive_evaluations FROM phase_transition_candidate WHERE id = 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let (first_seen_at, consecutive_evaluations) = match existing {
+            Some((candidate, first_seen_at, count)) if candidate == phase.as_str() => {
+                (first_seen_at, count as u32 + 1)
+            }
+            _ => (Utc::now(), 1),
+        };
+
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO phase_transition_candidate
+            (id, candidate_phase, first_seen_at, consecutive_evaluations)
+            VALUES (1, ?, ?, ?)
+            "#,
+        )
+        .bind(phase.as_str())
+        .bind(first_seen_at)
+        .bind(consecutive_evaluations as i64)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(self
+            .hysteresis
+            .is_satisfied(consecutive_evaluations, first_seen_at))
+    }
+
+    async fn clear_phase_candidate(&self) -> Result<(), GovernanceError> {
+        sqlx::query("DELETE FROM phase_transition_candidate")
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Append a phase_transition entry to the audit log, if one is configured
+    async fn audit_phase_transition(
+        &self,
+        old_phase: GovernancePhase,
+        new_phase: GovernancePhase,
+        metrics: &PhaseMetrics,
+    ) {
+        let Some((logger, server_id)) = &self.audit else {
+            return;
+        };
+
+        let mut logger = logger.clone();
+        let inputs = serde_json::to_vec(metrics).unwrap_or_default();
+        let result =
+            execute_with_audit(&mut logger, "phase_transition", server_id, &inputs, || {
+                Ok::<_, std::convert::Infallible>((old_phase.as_str(), new_phase.as_str()))
+            })
+            .await;
+        if let Err(e) = result {
+            warn!("Failed to audit governance phase transition: {}", e);
+        }
+    }
+
+    /// Evaluate the phase and record and announce a change once it has held
+    ///
+    /// An active override counts as the current phase. The first evaluation
+    /// only records a baseline. A new phase must satisfy the configured
+    /// hysteresis; evaluating the old phase again resets it. Once satisfied, a
+    /// kind 30023 announcement is published (when a client is given) before
+    /// the transition is recorded, so a failed publish is retried on the next
+    /// evaluation. Returns the new phase if a transition was recorded.
+    pub async fn check_and_announce_phase_transition(
+        &self,
+        nostr_client: Option<&NostrClient>,
+    ) -> Result<Option<GovernancePhase>, GovernanceError> {
+        let metrics = self.get_phase_metrics().await?;
+        let phase = match self.get_phase_override().await? {
+            Some(phase_override) => GovernancePhase::parse(&phase_override.phase)
+                .unwrap_or_else(|| Self::phase_for_metrics(&metrics)),
+            None => Self::phase_for_metrics(&metrics),
+        };
 
+        let last_phase = match self.get_last_recorded_phase().await? {
+            Some(last_phase) => {
+                crate::metrics::set_gauge(
+                    crate::metrics::GOVERNANCE_PHASE,
+                    &[],
+                    last_phase as i32 as f64,
+                );
+                last_phase
+            }
+            None => {
+                info!("Recording initial governance phase: {}", phase.as_str());
+                self.record_phase_transition(None, phase, &metrics).await?;
+                return Ok(None);
+            }
+        };
+        if last_phase == phase {
+            self.clear_phase_candidate().await?;
+            return Ok(None);
+        }
+        if !self.track_phase_candidate(phase).await? {
+            debug!(
+                "Governance phase {} -> {} pending hysteresis",
+                last_phase.as_str(),
+                phase.as_str()
+            );
+            return Ok(None);
+        }
+
+        if let Some(nostr_client) = nostr_client {
+            let event = Self::phase_transition_event(last_phase, phase, &metrics)
+                .to_event(&nostr_client.keys)
+                .map_err(|e| {
+                    GovernanceError::CryptoError(format!(
+                        "Failed to sign phase announcement: {}",
+                        e
+                    ))
+                })?;
+            nostr_client.publish_event(event).await?;
+        }
+
+        self.record_phase_transition(Some(last_phase), phase, &metrics)
+            .await?;
+        self.clear_phase_candidate().await?;
+        self.audit_phase_transition(last_phase, phase, &metrics)
+            .await;
         info!(
-            "Governance phase: {} (height: {}, nodes: {}, contributors: {})",
-            phase.as_str(),
-            block_height,
-            economic_nodes,
-            contributors
+            "Governance phase transitioned: {} -> {}",
+            last_phase.as_str(),
+            phase.as_str()
         );
 
-        Ok(phase)
+        Ok(Some(phase))
+    }
+
+    /// Build the long-form (kind 30023) phase transition announcement
+    fn phase_transition_event(
+        old_phase: GovernancePhase,
+        new_phase: GovernancePhase,
+        metrics: &PhaseMetrics,
+    ) -> EventBuilder {
+        let content = format!(
+            "# Governance Phase Transition\n\n\
+            Governance has moved from the **{}** phase to the **{}** phase.\n\n\
+            **Block Height:** {}\n\
+            **Economic Nodes:** {}\n\
+            **Contributors:** {}",
+            old_phase.as_str(),
+            new_phase.as_str(),
+            metrics.block_height,
+            metrics.economic_nodes,
+            metrics.contributors
+        );
+
+        let tags = vec![
+            Tag::Generic(
+                TagKind::Custom("d".into()),
+                vec![format!("btc-commons-phase-{}", new_phase.as_str())],
+            ),
+            Tag::Generic(
+                TagKind::Custom("t".into()),
+                vec!["governance-phase".to_string()],
+            ),
+            Tag::Generic(
+                TagKind::Custom("old_phase".into()),
+                vec![old_phase.as_str().to_string()],
+            ),
+            Tag::Generic(
+                TagKind::Custom("new_phase".into()),
+                vec![new_phase.as_str().to_string()],
+            ),
+            Tag::Generic(
+                TagKind::Custom("block_height".into()),
+                vec![metrics.block_height.to_string()],
+            ),
+            Tag::Generic(
+                TagKind::Custom("economic_nodes".into()),
+                vec![metrics.economic_nodes.to_string()],
+            ),
+            Tag::Generic(
+                TagKind::Custom("contributors".into()),
+                vec![metrics.contributors.to_string()],
+            ),
+        ];
+
+        EventBuilder::new(Kind::LongFormTextNote, content, tags)
     }
 
     /// Get block height from chain state
     async fn get_block_height(&self) -> Result<u64, GovernanceError> {
-        // Try to get from chain state table if it exists
+        // Height of the tip tracked from verified block headers
+        let has_chain_tips: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'chain_tips'",
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        if has_chain_tips == 0 {
+            return Ok(0);
+        }
+
         let height: Option<i64> = sqlx::query_scalar(
             r#"
-            SELECT MAX(height) FROM blocks
+            SELECT height FROM chain_tips WHERE is_tip = TRUE
             "#,
         )
         .fetch_optional(&self.pool)
@@ -90,11 +552,11 @@ impl GovernancePhaseCalculator {
         Ok(height.unwrap_or(0) as u64)
     }
 
-    /// Get active economic node count
+    /// Get active economic node count (from the node registry)
     async fn get_economic_node_count(&self) -> Result<u32, GovernanceError> {
         let count: Option<i64> = sqlx::query_scalar(
             r#"
-            SELECT COUNT(*) FROM economic_nodes WHERE status = 'active'
+            SELECT COUNT(*) FROM node_registry WHERE active = TRUE
             "#,
         )
         .fetch_optional(&self.pool)
@@ -185,7 +647,7 @@ impl GovernancePhaseCalculator {
 }
 
 /// Adaptive parameters that adjust based on governance phase
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AdaptiveParameters {
     /// Maximum weight cap for mining pools (percentage of total)
     pub mining_pool_weight_cap: f64,
@@ -196,3 +658,328 @@ pub struct AdaptiveParameters {
     /// Tier 4 threshold (number of nodes that can block)
     pub tier_4_threshold: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Setup the contribution table the contributor count is read from
+    async fn setup_test_db() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query(
+            r#"
+            CREATE TABLE unified_contributions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                contributor_id TEXT NOT NULL,
+                contributor_type TEXT NOT NULL,
+                contribution_type TEXT NOT NULL,
+                amount_btc REAL NOT NULL,
+                timestamp DATETIME NOT NULL,
+                contribution_age_days INTEGER DEFAULT 0,
+                period_type TEXT NOT NULL,
+                verified BOOLEAN DEFAULT FALSE,
+                created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool
+    }
+
+    /// Setup tables the phase calculator reads and writes
+    async fn setup_phase_tables(pool: &SqlitePool) {
+        sqlx::query(
+            r#"
+            CREATE TABLE node_registry (
+                node_id TEXT PRIMARY KEY,
+                active BOOLEAN NOT NULL DEFAULT TRUE
+            );
+            "#,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE phase_transitions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                old_phase TEXT,
+                new_phase TEXT NOT NULL,
+                transitioned_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                block_height INTEGER NOT NULL,
+                economic_nodes INTEGER NOT NULL,
+                contributors INTEGER NOT NULL
+            );
+            "#,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE phase_transition_candidate (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                candidate_phase TEXT NOT NULL,
+                first_seen_at TIMESTAMP NOT NULL,
+                consecutive_evaluations INTEGER NOT NULL
+            );
+            "#,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE phase_overrides (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                phase TEXT,
+                reason TEXT NOT NULL,
+                set_by TEXT NOT NULL,
+                set_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            );
+            "#,
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn test_phase_for_metrics_is_conservative() {
+        let metrics = PhaseMetrics {
+            block_height: 250_000,
+            economic_nodes: 40,
+            contributors: 5,
+        };
+        assert_eq!(
+            GovernancePhaseCalculator::phase_for_metrics(&metrics),
+            GovernancePhase::Early
+        );
+
+        let metrics = PhaseMetrics {
+            block_height: 250_000,
+            economic_nodes: 15,
+            contributors: 150,
+        };
+        assert_eq!(
+            GovernancePhaseCalculator::phase_for_metrics(&metrics),
+            GovernancePhase::Growth
+        );
+    }
+
+    #[tokio::test]
+    async fn test_phase_transition_recorded_and_announced() {
+        let pool = setup_test_db().await;
+        setup_phase_tables(&pool).await;
+        let calculator = GovernancePhaseCalculator::new(pool.clone());
+
+        let keys = nostr_sdk::prelude::Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        // Nothing listens on this port, so publishing always fails
+        let client = NostrClient::new(nsec, vec!["ws://127.0.0.1:1".to_string()])
+            .await
+            .unwrap();
+
+        // First check records the baseline without announcing
+        assert_eq!(
+            calculator
+                .check_and_announce_phase_transition(Some(&client))
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            calculator.get_last_recorded_phase().await.unwrap(),
+            Some(GovernancePhase::Early)
+        );
+
+        // No change, nothing to announce
+        assert_eq!(
+            calculator
+                .check_and_announce_phase_transition(Some(&client))
+                .await
+                .unwrap(),
+            None
+        );
+
+        // Pretend governance was previously in the growth phase: the change
+        // can't be announced, so it isn't recorded and will be retried
+        sqlx::query(
+            "INSERT INTO phase_transitions (old_phase, new_phase, transitioned_at, block_height, economic_nodes, contributors) \
+             VALUES ('early', 'growth', datetime('now'), 60000, 12, 20)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert!(calculator
+            .check_and_announce_phase_transition(Some(&client))
+            .await
+            .is_err());
+
+        let transitions = calculator.get_phase_transitions().await.unwrap();
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].new_phase, "growth");
+        assert_eq!(transitions[1].old_phase, None);
+        assert_eq!(transitions[1].metrics.economic_nodes, 0);
+    }
+
+    #[tokio::test]
+    async fn test_phase_override() {
+        let pool = setup_test_db().await;
+        setup_phase_tables(&pool).await;
+        let calculator = GovernancePhaseCalculator::new(pool);
+
+        assert_eq!(
+            calculator.get_current_phase().await.unwrap(),
+            GovernancePhase::Early
+        );
+
+        // A reason is required
+        assert!(calculator
+            .set_phase_override(Some(GovernancePhase::Mature), "  ", "operator")
+            .await
+            .is_err());
+        assert!(calculator.get_phase_override().await.unwrap().is_none());
+
+        calculator
+            .set_phase_override(
+                Some(GovernancePhase::Mature),
+                "testnet rehearsal",
+                "operator",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            calculator.get_current_phase().await.unwrap(),
+            GovernancePhase::Mature
+        );
+        let phase_override = calculator.get_phase_override().await.unwrap().unwrap();
+        assert_eq!(phase_override.phase, "mature");
+        assert_eq!(phase_override.set_by, "operator");
+
+        calculator
+            .set_phase_override(None, "rehearsal finished", "operator")
+            .await
+            .unwrap();
+        assert!(calculator.get_phase_override().await.unwrap().is_none());
+        assert_eq!(
+            calculator.get_current_phase().await.unwrap(),
+            GovernancePhase::Early
+        );
+    }
+
+    /// Push every phase metric over the growth boundary
+    async fn reach_growth_metrics(pool: &SqlitePool) {
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS chain_tips (block_hash TEXT PRIMARY KEY, height INTEGER NOT NULL, is_tip BOOLEAN NOT NULL)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO chain_tips (block_hash, height, is_tip) VALUES ('tip', 60000, TRUE)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        for i in 0..12 {
+            sqlx::query("INSERT INTO node_registry (node_id, active) VALUES (?, TRUE)")
+                .bind(format!("node-{}", i))
+                .execute(pool)
+                .await
+                .unwrap();
+            sqlx::query(
+                "INSERT INTO unified_contributions (contributor_id, contributor_type, contribution_type, amount_btc, timestamp, period_type) \
+                 VALUES (?, 'zap_user', 'zap', 0.001, CURRENT_TIMESTAMP, 'cumulative')",
+            )
+            .bind(format!("contributor-{}", i))
+            .execute(pool)
+            .await
+            .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_phase_transition_hysteresis() {
+        let pool = setup_test_db().await;
+        setup_phase_tables(&pool).await;
+        let calculator =
+            GovernancePhaseCalculator::new(pool.clone()).with_hysteresis(PhaseHysteresis {
+                min_consecutive_evaluations: 3,
+                min_days: 0.0,
+            });
+
+        // Baseline
+        assert_eq!(
+            calculator
+                .check_and_announce_phase_transition(None)
+                .await
+                .unwrap(),
+            None
+        );
+
+        // Growth seen once, then metrics drop back: the candidate is discarded
+        reach_growth_metrics(&pool).await;
+        assert_eq!(
+            calculator
+                .check_and_announce_phase_transition(None)
+                .await
+                .unwrap(),
+            None
+        );
+        sqlx::query("UPDATE node_registry SET active = FALSE")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            calculator
+                .check_and_announce_phase_transition(None)
+                .await
+                .unwrap(),
+            None
+        );
+        sqlx::query("UPDATE node_registry SET active = TRUE")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // Growth must now hold for three consecutive evaluations
+        for _ in 0..2 {
+            assert_eq!(
+                calculator
+                    .check_and_announce_phase_transition(None)
+                    .await
+                    .unwrap(),
+                None
+            );
+        }
+        assert_eq!(
+            calculator
+                .check_and_announce_phase_transition(None)
+                .await
+                .unwrap(),
+            Some(GovernancePhase::Growth)
+        );
+        assert_eq!(
+            calculator
+                .check_and_announce_phase_transition(None)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let transitions = calculator.get_phase_transitions().await.unwrap();
+        assert_eq!(transitions.len(), 2);
+        let last = calculator.get_last_transition().await.unwrap().unwrap();
+        assert_eq!(last.old_phase.as_deref(), Some("early"));
+        assert_eq!(last.new_phase, "growth");
+        assert_eq!(last.metrics.block_height, 60000);
+        assert_eq!(last.metrics.economic_nodes, 12);
+        assert_eq!(last.metrics.contributors, 12);
+    }
+}
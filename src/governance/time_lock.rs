@@ -6,9 +6,21 @@
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use tracing::{debug, info, warn};
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
 
 use crate::database::Database;
+use crate::enforcement::emergency_manager::EmergencyManager;
+use crate::error::GovernanceError;
+use crate::nostr::{
+    CombinedRequirement, EconomicVetoStatus, GovernanceActionPublisher, LayerRequirement,
+    TierRequirement,
+};
+use crate::shutdown::{self, Shutdown};
+use crate::validation::emergency::EmergencyTier;
+
+/// How often the auto-activation task looks for elapsed time locks
+const AUTO_ACTIVATE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(300);
 
 /// Time lock status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -34,6 +46,9 @@ pub struct TimeLockConfig {
     pub tier5_min_hours: i64,
     /// User override threshold (percentage of active nodes)
     pub user_override_threshold: f64,
+    /// Activate changes automatically once their time lock elapses (default: off)
+    #[serde(default)]
+    pub auto_activate: bool,
 }
 
 impl Default for TimeLockConfig {
@@ -43,6 +58,7 @@ impl Default for TimeLockConfig {
             tier4_min_hours: 336,          // 14 days
             tier5_min_hours: 720,          // 30 days
             user_override_threshold: 0.75, // 75% of active nodes
+            auto_activate: false,
         }
     }
 }
@@ -142,12 +158,33 @@ impl<'r> sqlx::FromRow<'r, sqlx::postgres::PgRow> for TimeLockedChange {
 pub struct TimeLockManager {
     db: Database,
     config: TimeLockConfig,
+    /// Source of active emergencies, which override an unexpired time lock
+    emergency_manager: Option<Arc<EmergencyManager>>,
+    publisher: Option<Arc<GovernanceActionPublisher>>,
 }
 
 impl TimeLockManager {
     /// Create a new time lock manager
     pub fn new(db: Database, config: TimeLockConfig) -> Self {
-        Self { db, config }
+        Self {
+            db,
+            config,
+            emergency_manager: None,
+            publisher: None,
+        }
+    }
+
+    /// Let an active Critical emergency (Tier 4 action) activate changes before
+    /// their lock ends
+    pub fn with_emergency_manager(mut self, emergency_manager: Arc<EmergencyManager>) -> Self {
+        self.emergency_manager = Some(emergency_manager);
+        self
+    }
+
+    /// Publish a Nostr governance action event when a change is activated
+    pub fn with_publisher(mut self, publisher: Arc<GovernanceActionPublisher>) -> Self {
+        self.publisher = Some(publisher);
+        self
     }
 
     /// Create a time lock for a governance change
@@ -201,7 +238,8 @@ impl TimeLockManager {
         .bind(lock_start)
         .bind(lock_start)
         .fetch_one(
-            self.db
+            &self
+                .db
                 .get_sqlite_pool()
                 .ok_or_else(|| sqlx::Error::PoolClosed)?,
         )
@@ -218,7 +256,7 @@ impl TimeLockManager {
 
     /// Check if a time lock has elapsed
     pub async fn check_time_lock(&self, change_id: &str) -> Result<TimeLockStatus, sqlx::Error> {
-        let pool = self
+        let pool = &self
             .db
             .get_sqlite_pool()
             .ok_or_else(|| sqlx::Error::PoolClosed)?;
@@ -258,7 +296,7 @@ impl TimeLockManager {
         &self,
         change_id: &str,
     ) -> Result<Option<Duration>, sqlx::Error> {
-        let pool = self
+        let pool = &self
             .db
             .get_sqlite_pool()
             .ok_or_else(|| sqlx::Error::PoolClosed)?;
@@ -299,7 +337,8 @@ impl TimeLockManager {
         )
         .bind(change_id)
         .fetch_optional(
-            self.db
+            &self
+                .db
                 .get_sqlite_pool()
                 .ok_or_else(|| sqlx::Error::PoolClosed)?,
         )
@@ -328,7 +367,7 @@ impl TimeLockManager {
             .bind(serde_json::to_string(&signals).unwrap())
             .bind(Utc::now())
             .bind(change_id)
-            .execute(self.db.pool().unwrap())
+            .execute(&self.db.pool().unwrap())
             .await?;
         } else {
             // PostgreSQL uses jsonb_set
@@ -349,7 +388,7 @@ impl TimeLockManager {
             .bind(Utc::now().to_rfc3339())
             .bind(Utc::now())
             .bind(change_id)
-            .execute(self.db.pool().unwrap())
+            .execute(&self.db.pool().unwrap())
             .await?;
         }
 
@@ -362,7 +401,7 @@ impl TimeLockManager {
         change_id: &str,
         active_node_count: usize,
     ) -> Result<bool, sqlx::Error> {
-        let pool = self
+        let pool = &self
             .db
             .get_sqlite_pool()
             .ok_or_else(|| sqlx::Error::PoolClosed)?;
@@ -386,23 +425,149 @@ impl TimeLockManager {
     }
 
     /// Activate a time-locked change
-    pub async fn activate_change(&self, change_id: &str) -> Result<(), sqlx::Error> {
-        info!("Activating time-locked change: {}", change_id);
+    ///
+    /// Refuses while the time lock is still running, reporting the time left,
+    /// unless a Critical emergency is active. Only pending changes can be
+    /// activated.
+    pub async fn activate_change(&self, change_id: &str) -> Result<(), GovernanceError> {
+        let change = self
+            .get_change(change_id)
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .ok_or_else(|| {
+                GovernanceError::ValidationError(format!(
+                    "Unknown time-locked change: {}",
+                    change_id
+                ))
+            })?;
+        if change.status != "pending" {
+            return Err(GovernanceError::ValidationError(format!(
+                "Change {} is {} and can't be activated",
+                change_id, change.status
+            )));
+        }
 
+        let now = Utc::now();
+        if now < change.lock_end {
+            match self.active_emergency_override().await? {
+                Some(emergency_id) => warn!(
+                    "Activating {} {} before its time lock ends: critical emergency {} is active",
+                    change_id,
+                    format_remaining(change.lock_end - now),
+                    emergency_id
+                ),
+                None => {
+                    return Err(GovernanceError::time_lock_active(
+                        change_id,
+                        &format_remaining(change.lock_end - now),
+                        change.lock_end,
+                    ))
+                }
+            }
+        }
+
+        info!("Activating time-locked change: {}", change_id);
         sqlx::query(
-            "UPDATE time_locked_changes SET status = 'activated', updated_at = $1 WHERE change_id = $2",
+            "UPDATE time_locked_changes SET status = 'activated', updated_at = $1 WHERE change_id = $2 AND status = 'pending'",
         )
-        .bind(Utc::now())
+        .bind(now)
         .bind(change_id)
         .execute(
-            self.db.get_sqlite_pool()
-                .ok_or_else(|| sqlx::Error::PoolClosed)?
+            &self.db
+                .get_sqlite_pool()
+                .ok_or_else(|| GovernanceError::DatabaseError("Database pool not available".to_string()))?,
         )
-        .await?;
+        .await
+        .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?;
+
+        if let Some(publisher) = &self.publisher {
+            if let Err(e) = Self::publish_activation(publisher, &change).await {
+                warn!("Failed to publish activation of {}: {}", change_id, e);
+            }
+        }
 
         Ok(())
     }
 
+    /// Activate every pending change whose time lock has elapsed
+    ///
+    /// Returns the number of changes activated.
+    pub async fn activate_ready_changes(&self) -> Result<usize, GovernanceError> {
+        let now = Utc::now();
+        let ready: Vec<TimeLockedChange> = self
+            .list_pending()
+            .await
+            .map_err(|e| GovernanceError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .filter(|change| change.lock_end <= now)
+            .collect();
+
+        let mut activated = 0;
+        for change in ready {
+            match self.activate_change(&change.change_id).await {
+                Ok(()) => activated += 1,
+                Err(e) => warn!("Failed to auto-activate {}: {}", change.change_id, e),
+            }
+        }
+        Ok(activated)
+    }
+
+    /// Id of the active emergency that overrides time locks, if any
+    ///
+    /// Only the highest tier (Critical) does; Urgent and Elevated emergencies
+    /// shorten review periods but leave time locks running.
+    async fn active_emergency_override(&self) -> Result<Option<i32>, GovernanceError> {
+        let Some(emergency_manager) = &self.emergency_manager else {
+            return Ok(None);
+        };
+        Ok(emergency_manager
+            .active_emergency()
+            .await?
+            .filter(|emergency| emergency.tier == EmergencyTier::Critical)
+            .map(|emergency| emergency.id))
+    }
+
+    async fn publish_activation(
+        publisher: &GovernanceActionPublisher,
+        change: &TimeLockedChange,
+    ) -> anyhow::Result<()> {
+        let tier = change.tier as u32;
+        let lock_days = (change.min_duration_hours / 24) as u32;
+        publisher
+            .publish_action(
+                "time_lock_activation",
+                tier,
+                0,
+                "governance",
+                "",
+                lock_days,
+                None,
+                change.pr_number.map(|n| n as i32),
+                &format!("Activated {}: {}", change.change_id, change.description),
+                LayerRequirement {
+                    layer: 0,
+                    signatures: String::new(),
+                    review_days: lock_days,
+                },
+                TierRequirement {
+                    tier,
+                    signatures: String::new(),
+                    review_days: lock_days,
+                    economic_veto: false,
+                },
+                CombinedRequirement {
+                    signatures: String::new(),
+                    review_days: lock_days,
+                    economic_veto: false,
+                    source: "time_lock".to_string(),
+                },
+                Vec::new(),
+                EconomicVetoStatus::NotRequired,
+                Some(change.lock_end),
+            )
+            .await
+    }
+
     /// Cancel a time-locked change
     pub async fn cancel_change(&self, change_id: &str) -> Result<(), sqlx::Error> {
         info!("Cancelling time-locked change: {}", change_id);
@@ -413,7 +578,7 @@ impl TimeLockManager {
         .bind(Utc::now())
         .bind(change_id)
         .execute(
-            self.db.get_sqlite_pool()
+            &self.db.get_sqlite_pool()
                 .ok_or_else(|| sqlx::Error::PoolClosed)?
         )
         .await?;
@@ -423,7 +588,7 @@ impl TimeLockManager {
 
     /// List all pending time locks
     pub async fn list_pending(&self) -> Result<Vec<TimeLockedChange>, sqlx::Error> {
-        let pool = self
+        let pool = &self
             .db
             .get_sqlite_pool()
             .ok_or_else(|| sqlx::Error::PoolClosed)?;
@@ -434,6 +599,19 @@ impl TimeLockManager {
         .await
     }
 
+    /// Time-locked changes counted by status (`pending`, `activated`, ...)
+    pub async fn count_by_status(&self) -> Result<HashMap<String, i64>, sqlx::Error> {
+        let pool = &self
+            .db
+            .get_sqlite_pool()
+            .ok_or_else(|| sqlx::Error::PoolClosed)?;
+        let counts: Vec<(String, i64)> =
+            sqlx::query_as("SELECT status, COUNT(*) FROM time_locked_changes GROUP BY status")
+                .fetch_all(pool)
+                .await?;
+        Ok(counts.into_iter().collect())
+    }
+
     /// Get time lock details
     pub async fn get_change(
         &self,
@@ -443,11 +621,40 @@ impl TimeLockManager {
             "SELECT * FROM time_locked_changes WHERE change_id = $1",
         )
         .bind(change_id)
-        .fetch_optional(self.db.pool().unwrap())
+        .fetch_optional(&self.db.pool().unwrap())
         .await
     }
 }
 
+/// Time left on a lock, e.g. "3d 4h 10m"
+fn format_remaining(remaining: Duration) -> String {
+    format!(
+        "{}d {}h {}m",
+        remaining.num_days(),
+        remaining.num_hours() % 24,
+        remaining.num_minutes() % 60
+    )
+}
+
+/// Periodically activate changes whose time lock has elapsed, if enabled
+pub fn spawn_auto_activation_task(manager: Arc<TimeLockManager>, shutdown: &Shutdown) {
+    if !manager.config.auto_activate {
+        return;
+    }
+    shutdown.spawn("time_lock_activation", |token| async move {
+        let mut interval = tokio::time::interval(AUTO_ACTIVATE_INTERVAL);
+        while shutdown::next_tick(&token, &mut interval).await {
+            match manager.activate_ready_changes().await {
+                Ok(activated) if activated > 0 => {
+                    info!("Auto-activated {} time-locked changes", activated)
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to auto-activate time-locked changes: {}", e),
+            }
+        }
+    });
+}
+
 /// Database migration for time lock tables
 pub async fn migrate_time_lock_tables(db: &Database) -> Result<(), sqlx::Error> {
     sqlx::query(
@@ -468,7 +675,7 @@ pub async fn migrate_time_lock_tables(db: &Database) -> Result<(), sqlx::Error>
         "#,
     )
     .execute(
-        db.get_sqlite_pool()
+        &db.get_sqlite_pool()
             .ok_or_else(|| sqlx::Error::PoolClosed)?,
     )
     .await?;
@@ -478,7 +685,7 @@ pub async fn migrate_time_lock_tables(db: &Database) -> Result<(), sqlx::Error>
         "CREATE INDEX IF NOT EXISTS idx_time_locked_changes_status ON time_locked_changes(status)",
     )
     .execute(
-        db.get_sqlite_pool()
+        &db.get_sqlite_pool()
             .ok_or_else(|| sqlx::Error::PoolClosed)?,
     )
     .await?;
@@ -487,7 +694,7 @@ pub async fn migrate_time_lock_tables(db: &Database) -> Result<(), sqlx::Error>
         "CREATE INDEX IF NOT EXISTS idx_time_locked_changes_lock_end ON time_locked_changes(lock_end)",
     )
     .execute(
-        db.get_sqlite_pool()
+        &db.get_sqlite_pool()
             .ok_or_else(|| sqlx::Error::PoolClosed)?
     )
     .await?;
@@ -508,6 +715,16 @@ mod tests {
         (manager, db)
     }
 
+    /// Move a change's lock end into the past
+    async fn expire_lock(db: &Database, change_id: &str) {
+        sqlx::query("UPDATE time_locked_changes SET lock_end = ? WHERE change_id = ?")
+            .bind(Utc::now() - Duration::minutes(1))
+            .bind(change_id)
+            .execute(&db.get_sqlite_pool().unwrap())
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn test_time_lock_manager_new() {
         let db = Database::new_in_memory().await.unwrap();
@@ -593,11 +810,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_check_time_lock_activated() {
-        let (manager, _) = setup_test_manager().await;
+        let (manager, db) = setup_test_manager().await;
         manager
             .create_time_lock("test-activate", 3, "Test", None)
             .await
             .unwrap();
+        expire_lock(&db, "test-activate").await;
         manager.activate_change("test-activate").await.unwrap();
 
         let status = manager.check_time_lock("test-activate").await.unwrap();
@@ -740,12 +958,13 @@ mod tests {
 
     #[tokio::test]
     async fn test_activate_change() {
-        let (manager, _) = setup_test_manager().await;
+        let (manager, db) = setup_test_manager().await;
         manager
             .create_time_lock("test-activate-2", 3, "Test", None)
             .await
             .unwrap();
 
+        expire_lock(&db, "test-activate-2").await;
         manager.activate_change("test-activate-2").await.unwrap();
 
         let change = manager
@@ -756,6 +975,89 @@ mod tests {
         assert_eq!(change.status, "activated");
     }
 
+    #[tokio::test]
+    async fn test_activate_tier5_change_respects_time_lock() {
+        let (manager, db) = setup_test_manager().await;
+        manager
+            .create_time_lock("tier5-change", 5, "Constitutional change", Some(7))
+            .await
+            .unwrap();
+
+        let err = manager.activate_change("tier5-change").await.unwrap_err();
+        assert!(err.to_string().contains("29d 23h"), "{}", err);
+        assert_eq!(
+            manager
+                .get_change("tier5-change")
+                .await
+                .unwrap()
+                .unwrap()
+                .status,
+            "pending"
+        );
+        assert_eq!(manager.activate_ready_changes().await.unwrap(), 0);
+
+        expire_lock(&db, "tier5-change").await;
+        assert_eq!(manager.activate_ready_changes().await.unwrap(), 1);
+        assert_eq!(
+            manager.check_time_lock("tier5-change").await.unwrap(),
+            TimeLockStatus::Activated
+        );
+        assert!(manager.activate_change("tier5-change").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_active_emergency_overrides_time_lock() {
+        let (manager, db) = setup_test_manager().await;
+        let emergency_manager = Arc::new(EmergencyManager::new(db.clone()));
+        let manager = manager.with_emergency_manager(emergency_manager.clone());
+        manager
+            .create_time_lock("tier5-urgent", 5, "Consensus fix", None)
+            .await
+            .unwrap();
+        assert!(manager.activate_change("tier5-urgent").await.is_err());
+
+        // A lower-tier emergency leaves the time lock in place
+        let pool = db.get_sqlite_pool().unwrap();
+        let declare = |tier: EmergencyTier| {
+            sqlx::query_scalar::<_, i64>(
+                r#"
+                INSERT INTO emergency_tiers
+                (tier, activated_by, reason, evidence, activated_at, expires_at, active, extension_count)
+                VALUES (?, 'alice', 'Inflation bug', 'evidence', ?, ?, true, 0)
+                RETURNING id
+                "#,
+            )
+            .bind(tier.to_i32())
+            .bind(Utc::now())
+            .bind(Utc::now() + Duration::days(1))
+            .fetch_one(&pool)
+        };
+        let urgent = declare(EmergencyTier::Urgent).await.unwrap();
+        assert!(manager.activate_change("tier5-urgent").await.is_err());
+        emergency_manager
+            .deactivate(urgent as i32, "alice", "downgraded")
+            .await
+            .unwrap();
+
+        declare(EmergencyTier::Critical).await.unwrap();
+        assert!(emergency_manager
+            .active_emergency()
+            .await
+            .unwrap()
+            .is_some());
+
+        manager.activate_change("tier5-urgent").await.unwrap();
+        assert_eq!(
+            manager
+                .get_change("tier5-urgent")
+                .await
+                .unwrap()
+                .unwrap()
+                .status,
+            "activated"
+        );
+    }
+
     #[tokio::test]
     async fn test_cancel_change() {
         let (manager, _) = setup_test_manager().await;
@@ -772,7 +1074,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_list_pending() {
-        let (manager, _) = setup_test_manager().await;
+        let (manager, db) = setup_test_manager().await;
 
         // Create multiple time locks
         manager
@@ -789,6 +1091,7 @@ mod tests {
             .unwrap();
 
         // Activate one, so it shouldn't appear in pending list
+        expire_lock(&db, "pending-2").await;
         manager.activate_change("pending-2").await.unwrap();
 
         let pending = manager.list_pending().await.unwrap();
@@ -837,7 +1140,7 @@ mod tests {
         // Verify table exists by trying to query it
         let result: Result<Vec<(String, String)>, _> =
             sqlx::query_as("SELECT change_id, status FROM time_locked_changes LIMIT 1")
-                .fetch_all(db.pool().unwrap())
+                .fetch_all(&db.pool().unwrap())
                 .await;
 
         // Should not error (table exists), even if empty
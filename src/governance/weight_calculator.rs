@@ -5,10 +5,35 @@
 //! All weight calculations return 0.0 since contributions no longer affect governance.
 
 use anyhow::Result;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 use tracing::{debug, info};
 
+use crate::config::loader::WeightCalculationConfig;
+
+/// Weight decay for participants that stop re-verifying
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecayConfig {
+    /// Days for weight to halve once decay begins (0 disables decay)
+    pub half_life_days: f64,
+    /// Decay never takes weight below this floor
+    pub minimum_weight: f64,
+    /// Days after last verification before decay begins
+    pub grace_period_days: f64,
+}
+
+impl DecayConfig {
+    /// Build decay configuration from governance weight calculation config
+    pub fn from_weight_calculation(config: &WeightCalculationConfig) -> Self {
+        Self {
+            half_life_days: config.decay_half_life_days,
+            minimum_weight: config.minimum_weight,
+            grace_period_days: config.decay_grace_period_days,
+        }
+    }
+}
+
 /// Weight calculator (for reporting/transparency only)
 /// All weights are 0.0 since governance is maintainer-only
 pub struct WeightCalculator {
@@ -28,6 +53,31 @@ impl WeightCalculator {
         0.0
     }
 
+    /// Apply time-based decay to a weight since its last verification
+    ///
+    /// Once the grace period has passed, weight halves every `half_life_days`:
+    /// `w * 0.5^(days_past_grace / half_life)`, floored at `minimum_weight`
+    /// (a weight already below the floor is left unchanged).
+    pub fn apply_decay(
+        current_weight: f64,
+        last_verified_at: DateTime<Utc>,
+        decay_config: &DecayConfig,
+    ) -> f64 {
+        if decay_config.half_life_days <= 0.0 {
+            return current_weight;
+        }
+
+        let days_since_verification =
+            (Utc::now() - last_verified_at).num_seconds() as f64 / 86400.0;
+        let decaying_days = days_since_verification - decay_config.grace_period_days;
+        if decaying_days <= 0.0 {
+            return current_weight;
+        }
+
+        let decayed = current_weight * 0.5_f64.powf(decaying_days / decay_config.half_life_days);
+        decayed.max(decay_config.minimum_weight.min(current_weight))
+    }
+
     /// Apply weight cap to prevent whale dominance
     pub fn apply_weight_cap(&self, calculated_weight: f64, total_system_weight: f64) -> f64 {
         let max_weight = total_system_weight * self.cap_percentage;
@@ -94,6 +144,18 @@ impl WeightCalculator {
         .fetch_all(&self.pool)
         .await?;
 
+        let contributors: Vec<(String, String)> = contributors
+            .into_iter()
+            .map(|row| (row.contributor_id, row.contributor_type))
+            .collect();
+        self.update_participation_weights_for(&contributors).await
+    }
+
+    /// Update participation weights for the given (contributor_id, contributor_type) pairs
+    pub async fn update_participation_weights_for(
+        &self,
+        contributors: &[(String, String)],
+    ) -> Result<()> {
         let contributor_count = contributors.len();
 
         // First pass: calculate all base weights and store contribution data
@@ -107,8 +169,8 @@ impl WeightCalculator {
 
         let mut contributor_data = Vec::new();
 
-        for contributor in contributors {
-            let contributor_id = contributor.contributor_id.clone();
+        for (contributor_id, contributor_type) in contributors {
+            let contributor_id = contributor_id.clone();
 
             // Governance is maintainer-only - no contribution-based weight
             // All contributions are tracked for reporting/transparency only
@@ -117,7 +179,7 @@ impl WeightCalculator {
 
             contributor_data.push(ContributorData {
                 contributor_id,
-                contributor_type: contributor.contributor_type,
+                contributor_type: contributor_type.clone(),
                 total_contribution_btc,
                 base_weight,
             });
@@ -199,3 +261,48 @@ impl WeightCalculator {
         Ok(weight)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight_calculator_decay() {
+        let decay_config = DecayConfig {
+            half_life_days: 30.0,
+            minimum_weight: 0.5,
+            grace_period_days: 10.0,
+        };
+
+        // Within grace period: no decay
+        let recent = Utc::now() - chrono::Duration::days(5);
+        assert_eq!(
+            WeightCalculator::apply_decay(8.0, recent, &decay_config),
+            8.0
+        );
+
+        // One half-life past the grace period: halved
+        let one_half_life = Utc::now() - chrono::Duration::days(40);
+        let decayed = WeightCalculator::apply_decay(8.0, one_half_life, &decay_config);
+        assert!((decayed - 4.0).abs() < 0.01);
+
+        // Two half-lives past the grace period: quartered
+        let two_half_lives = Utc::now() - chrono::Duration::days(70);
+        let decayed = WeightCalculator::apply_decay(8.0, two_half_lives, &decay_config);
+        assert!((decayed - 2.0).abs() < 0.01);
+
+        // Long inactivity: floored at minimum weight
+        let ancient = Utc::now() - chrono::Duration::days(3650);
+        assert_eq!(
+            WeightCalculator::apply_decay(8.0, ancient, &decay_config),
+            0.5
+        );
+
+        // Half-life of 0 disables decay
+        let disabled = DecayConfig {
+            half_life_days: 0.0,
+            ..decay_config
+        };
+        assert_eq!(WeightCalculator::apply_decay(8.0, ancient, &disabled), 8.0);
+    }
+}
@@ -0,0 +1,477 @@
+//! Governance YAML Write-Back
+//!
+//! Writes activated governance configuration values back into the YAML
+//! files loaded by `config::loader`, so the files stay the source of truth.
+
+use serde_yaml::{Mapping, Value as YamlValue};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info};
+
+use crate::error::GovernanceError;
+use crate::github::write_operations::{GitHubWriteOperations, OpenedPullRequest};
+
+/// Mapping from a flat governance config key to its location in the YAML files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YamlKeyMapping {
+    /// Flat config key (e.g. "tier_1_signatures_required")
+    pub config_key: &'static str,
+    /// YAML file relative to the governance config directory
+    pub file: &'static str,
+    /// Dotted path to the nested key within the file
+    pub yaml_path: &'static str,
+}
+
+/// Known flat config keys and where they live in the governance YAML files
+pub static YAML_KEY_MAPPINGS: &[YamlKeyMapping] = &[
+    YamlKeyMapping {
+        config_key: "tier_1_signatures_required",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_1.signatures_required",
+    },
+    YamlKeyMapping {
+        config_key: "tier_1_signatures_total",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_1.signatures_total",
+    },
+    YamlKeyMapping {
+        config_key: "tier_1_review_period_days",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_1.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "tier_2_signatures_required",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_2.signatures_required",
+    },
+    YamlKeyMapping {
+        config_key: "tier_2_signatures_total",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_2.signatures_total",
+    },
+    YamlKeyMapping {
+        config_key: "tier_2_review_period_days",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_2.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "tier_3_signatures_required",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_3.signatures_required",
+    },
+    YamlKeyMapping {
+        config_key: "tier_3_signatures_total",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_3.signatures_total",
+    },
+    YamlKeyMapping {
+        config_key: "tier_3_review_period_days",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_3.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "tier_4_signatures_required",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_4.signatures_required",
+    },
+    YamlKeyMapping {
+        config_key: "tier_4_signatures_total",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_4.signatures_total",
+    },
+    YamlKeyMapping {
+        config_key: "tier_4_review_period_days",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_4.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "tier_5_signatures_required",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_5.signatures_required",
+    },
+    YamlKeyMapping {
+        config_key: "tier_5_signatures_total",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_5.signatures_total",
+    },
+    YamlKeyMapping {
+        config_key: "tier_5_review_period_days",
+        file: "action-tiers.yml",
+        yaml_path: "tiers.tier_5.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "layer_1_2_signatures_required",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_1_2_constitutional.signatures.required",
+    },
+    YamlKeyMapping {
+        config_key: "layer_1_2_signatures_total",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_1_2_constitutional.signatures.total",
+    },
+    YamlKeyMapping {
+        config_key: "layer_1_2_review_period_days",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_1_2_constitutional.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "layer_3_signatures_required",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_3_implementation.signatures.required",
+    },
+    YamlKeyMapping {
+        config_key: "layer_3_signatures_total",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_3_implementation.signatures.total",
+    },
+    YamlKeyMapping {
+        config_key: "layer_3_review_period_days",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_3_implementation.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "layer_4_signatures_required",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_4_application.signatures.required",
+    },
+    YamlKeyMapping {
+        config_key: "layer_4_signatures_total",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_4_application.signatures.total",
+    },
+    YamlKeyMapping {
+        config_key: "layer_4_review_period_days",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_4_application.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "layer_5_signatures_required",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_5_extension.signatures.required",
+    },
+    YamlKeyMapping {
+        config_key: "layer_5_signatures_total",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_5_extension.signatures.total",
+    },
+    YamlKeyMapping {
+        config_key: "layer_5_review_period_days",
+        file: "repository-layers.yml",
+        yaml_path: "layers.layer_5_extension.review_period_days",
+    },
+    YamlKeyMapping {
+        config_key: "commons_contributor_weight_decay_half_life_days",
+        file: "commons-contributor-thresholds.yml",
+        yaml_path: "weight_calculation.decay_half_life_days",
+    },
+];
+
+impl YamlKeyMapping {
+    /// Look up the YAML location for a flat config key
+    pub fn for_key(config_key: &str) -> Option<&'static YamlKeyMapping> {
+        YAML_KEY_MAPPINGS
+            .iter()
+            .find(|mapping| mapping.config_key == config_key)
+    }
+}
+
+/// Governance repository that config change PRs are opened against
+#[derive(Debug, Clone)]
+pub struct ConfigPrTarget {
+    pub owner: String,
+    pub repo: String,
+    /// Branch the PR merges into
+    pub base_branch: String,
+    /// Directory holding the YAML files within the repository
+    pub config_path: String,
+}
+
+/// Writes governance config values back into YAML files
+pub struct YamlConfigWriter;
+
+impl YamlConfigWriter {
+    /// Update a flat config key in the governance config directory
+    ///
+    /// Returns the path of the YAML file that was modified.
+    pub fn update_config_key(
+        config_dir: &Path,
+        config_key: &str,
+        new_value: &serde_json::Value,
+    ) -> Result<PathBuf, GovernanceError> {
+        let mapping = YamlKeyMapping::for_key(config_key).ok_or_else(|| {
+            GovernanceError::ConfigError(format!("No YAML mapping for config key: {}", config_key))
+        })?;
+
+        let path = config_dir.join(mapping.file);
+        Self::update_key(&path, mapping.yaml_path, new_value)?;
+        Ok(path)
+    }
+
+    /// Update a flat config key locally, then open a PR with the YAML change
+    ///
+    /// The modified file is committed to a new branch of the governance repo
+    /// and `metadata` (e.g. who approved the change) is included in the PR body.
+    pub async fn update_config_key_with_pr(
+        config_dir: &Path,
+        config_key: &str,
+        new_value: &serde_json::Value,
+        metadata: &serde_json::Value,
+        github: &GitHubWriteOperations,
+        target: &ConfigPrTarget,
+    ) -> Result<OpenedPullRequest, GovernanceError> {
+        let path = Self::update_config_key(config_dir, config_key, new_value)?;
+        let contents = fs::read(&path).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to read {:?}: {}", path, e))
+        })?;
+
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+        let repo_path = match target.config_path.trim_matches('/') {
+            "" => file_name.to_string(),
+            dir => format!("{}/{}", dir, file_name),
+        };
+        let branch = format!(
+            "config/{}-{}",
+            config_key.replace('_', "-"),
+            chrono::Utc::now().format("%Y%m%d%H%M%S")
+        );
+
+        github
+            .create_branch(&target.owner, &target.repo, &branch, &target.base_branch)
+            .await?;
+        github
+            .put_file(
+                &target.owner,
+                &target.repo,
+                &repo_path,
+                &contents,
+                &format!("Set {} to {}", config_key, new_value),
+                &branch,
+            )
+            .await?;
+
+        let body = format!(
+            "Governance config change\n\n\
+            **Key:** `{}`\n\
+            **New value:** `{}`\n\
+            **File:** `{}`\n\n\
+            ## Change metadata\n\n\
+            ```json\n{}\n```\n",
+            config_key,
+            new_value,
+            repo_path,
+            serde_json::to_string_pretty(metadata).unwrap_or_default()
+        );
+        github
+            .open_pull_request(
+                &target.owner,
+                &target.repo,
+                &format!("Governance config: set {}", config_key),
+                &body,
+                &branch,
+                &target.base_branch,
+            )
+            .await
+    }
+
+    /// Update a nested key (e.g. "tiers.tier_1.signatures_required") in a YAML file
+    ///
+    /// Missing intermediate mappings are created. The file is written to a
+    /// `.tmp` sibling and renamed into place so readers never see a partial write.
+    pub fn update_key(
+        path: &Path,
+        dotted_key: &str,
+        new_value: &serde_json::Value,
+    ) -> Result<(), GovernanceError> {
+        let segments: Vec<&str> = dotted_key.split('.').collect();
+        if segments.iter().any(|s| s.is_empty()) {
+            return Err(GovernanceError::ConfigError(format!(
+                "Invalid YAML key path: {}",
+                dotted_key
+            )));
+        }
+
+        let contents = fs::read_to_string(path).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to read {:?}: {}", path, e))
+        })?;
+        let mut document: YamlValue = serde_yaml::from_str(&contents).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to parse {:?}: {}", path, e))
+        })?;
+        if document.is_null() {
+            document = YamlValue::Mapping(Mapping::new());
+        }
+
+        let yaml_value = serde_yaml::to_value(new_value).map_err(|e| {
+            GovernanceError::ConfigError(format!(
+                "Failed to convert value for {}: {}",
+                dotted_key, e
+            ))
+        })?;
+
+        let mut current = &mut document;
+        for segment in &segments[..segments.len() - 1] {
+            let mapping = current.as_mapping_mut().ok_or_else(|| {
+                GovernanceError::ConfigError(format!(
+                    "Cannot set {} in {:?}: '{}' is not a mapping",
+                    dotted_key, path, segment
+                ))
+            })?;
+            let key = YamlValue::String(segment.to_string());
+            if !mapping.contains_key(&key) {
+                mapping.insert(key.clone(), YamlValue::Mapping(Mapping::new()));
+            }
+            current = mapping.get_mut(&key).expect("key inserted above");
+        }
+
+        let last = segments[segments.len() - 1];
+        let mapping = current.as_mapping_mut().ok_or_else(|| {
+            GovernanceError::ConfigError(format!(
+                "Cannot set {} in {:?}: parent is not a mapping",
+                dotted_key, path
+            ))
+        })?;
+        mapping.insert(YamlValue::String(last.to_string()), yaml_value);
+
+        let serialized = serde_yaml::to_string(&document).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to serialize {:?}: {}", path, e))
+        })?;
+
+        let tmp_path = path.with_extension("yml.tmp");
+        fs::write(&tmp_path, serialized).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to write {:?}: {}", tmp_path, e))
+        })?;
+        fs::rename(&tmp_path, path).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to replace {:?}: {}", path, e))
+        })?;
+
+        debug!("Updated {} in {:?}", dotted_key, path);
+        info!("Wrote governance config change to {:?}", path);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    const ACTION_TIERS: &str = r#"
+tiers:
+  tier_1:
+    name: Routine
+    signatures_required: 3
+    signatures_total: 5
+    review_period_days: 7
+    economic_veto_required: false
+    description: Routine maintenance
+"#;
+
+    #[test]
+    fn test_update_nested_key() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("action-tiers.yml");
+        fs::write(&path, ACTION_TIERS).unwrap();
+
+        YamlConfigWriter::update_key(&path, "tiers.tier_1.signatures_required", &json!(4)).unwrap();
+
+        let document: YamlValue =
+            serde_yaml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            document["tiers"]["tier_1"]["signatures_required"],
+            YamlValue::from(4)
+        );
+        // Siblings are preserved
+        assert_eq!(
+            document["tiers"]["tier_1"]["name"],
+            YamlValue::from("Routine")
+        );
+        assert!(!path.with_extension("yml.tmp").exists());
+    }
+
+    #[test]
+    fn test_update_creates_missing_mappings() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("action-tiers.yml");
+        fs::write(&path, ACTION_TIERS).unwrap();
+
+        YamlConfigWriter::update_key(&path, "tiers.tier_2.review_period_days", &json!(30)).unwrap();
+
+        let document: YamlValue =
+            serde_yaml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            document["tiers"]["tier_2"]["review_period_days"],
+            YamlValue::from(30)
+        );
+    }
+
+    #[test]
+    fn test_update_through_scalar_fails() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("action-tiers.yml");
+        fs::write(&path, ACTION_TIERS).unwrap();
+
+        let result = YamlConfigWriter::update_key(&path, "tiers.tier_1.name.required", &json!(1));
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), ACTION_TIERS);
+    }
+
+    #[test]
+    fn test_update_config_key_uses_mapping() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("action-tiers.yml"), ACTION_TIERS).unwrap();
+
+        let path =
+            YamlConfigWriter::update_config_key(dir.path(), "tier_1_signatures_total", &json!(7))
+                .unwrap();
+        assert_eq!(path, dir.path().join("action-tiers.yml"));
+
+        let document: YamlValue =
+            serde_yaml::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            document["tiers"]["tier_1"]["signatures_total"],
+            YamlValue::from(7)
+        );
+
+        assert!(YamlConfigWriter::update_config_key(dir.path(), "unknown_key", &json!(1)).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_update_with_pr_dry_run() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("action-tiers.yml"), ACTION_TIERS).unwrap();
+        let github = GitHubWriteOperations::new("test_token".to_string())
+            .unwrap()
+            .with_base_url("http://127.0.0.1:9")
+            .with_dry_run(true);
+        let target = ConfigPrTarget {
+            owner: "BTCDecoded".to_string(),
+            repo: "governance".to_string(),
+            base_branch: "main".to_string(),
+            config_path: "config".to_string(),
+        };
+
+        let pr = YamlConfigWriter::update_config_key_with_pr(
+            dir.path(),
+            "tier_1_signatures_required",
+            &json!(4),
+            &json!({"approved_by": ["alice", "bob"]}),
+            &github,
+            &target,
+        )
+        .await
+        .unwrap();
+
+        // The local file is still updated; only the GitHub writes are skipped
+        assert!(pr.dry_run);
+        let document: YamlValue =
+            serde_yaml::from_str(&fs::read_to_string(dir.path().join("action-tiers.yml")).unwrap())
+                .unwrap();
+        assert_eq!(
+            document["tiers"]["tier_1"]["signatures_required"],
+            YamlValue::from(4)
+        );
+    }
+}
@@ -0,0 +1,830 @@
+//! Governance review API endpoints
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use tracing::warn;
+
+use crate::api_auth::require_internal_api_key;
+use crate::audit::{shared_logger, AuditCategory};
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::governance_review::lifecycle::{
+    Closed, Mediation as MediationState, Open, ResponsePending,
+};
+use crate::governance_review::models::policy;
+use crate::governance_review::{
+    Appeal, AppealManager, Case, CaseTransition, GovernanceReviewCase, GovernanceReviewCaseManager,
+    GovernanceReviewResponse, GovernanceReviewWarning, Mediation, MediationManager,
+    PublicCaseRecord, ResponseManager, SanctionManager,
+};
+
+/// Public case record response
+#[derive(Debug, Serialize)]
+pub struct PublicCasesResponse {
+    pub cases: Vec<PublicCaseRecord>,
+}
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+fn sqlite_pool(database: &Database) -> Result<SqlitePool, ApiError> {
+    database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })
+}
+
+fn internal_error(context: &str, e: impl std::fmt::Display) -> ApiError {
+    warn!("{}: {}", context, e);
+    api_error(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        format!("{}: {}", context, e),
+    )
+}
+
+/// List closed cases with sensitive fields redacted
+pub async fn get_public_cases(
+    State((config, database)): State<(AppConfig, Database)>,
+) -> Result<Json<PublicCasesResponse>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    let case_manager = GovernanceReviewCaseManager::new(pool.clone())
+        .with_public_record_redact_fields(
+            config.governance_review.public_record_redact_fields.clone(),
+        );
+
+    match case_manager.get_public_records().await {
+        Ok(cases) => Ok(Json(PublicCasesResponse { cases })),
+        Err(e) => {
+            warn!("Failed to get public governance review cases: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OpenCaseRequest {
+    pub subject_maintainer_id: i32,
+    pub reporter_maintainer_id: i32,
+    pub case_type: String,
+    pub severity: String,
+    pub description: String,
+    #[serde(default)]
+    pub evidence: serde_json::Value,
+    pub on_platform: bool,
+    /// Who is opening the case, recorded in the transition history
+    pub opened_by: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListCasesQuery {
+    pub status: Option<String>,
+    pub subject: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CasesResponse {
+    pub cases: Vec<GovernanceReviewCase>,
+}
+
+/// A case with its responses and transition history
+#[derive(Debug, Serialize)]
+pub struct CaseDetailResponse {
+    pub case: GovernanceReviewCase,
+    pub responses: Vec<GovernanceReviewResponse>,
+    pub transitions: Vec<CaseTransition>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubmitResponseRequest {
+    pub maintainer_id: i32,
+    pub response_text: String,
+    #[serde(default)]
+    pub counter_evidence: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SanctionRequest {
+    /// "private_warning" or "public_warning"
+    pub sanction_type: String,
+    /// Maintainer ids approving the sanction
+    pub approvals: Vec<i32>,
+    /// Published warning file, required for public warnings
+    pub warning_file_path: Option<String>,
+    pub issued_by: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AppealRequest {
+    pub maintainer_id: i32,
+    pub appeal_reason: String,
+    #[serde(default)]
+    pub new_evidence: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediationRequest {
+    pub mediator_maintainer_id: Option<i32>,
+    pub started_by: String,
+}
+
+async fn load_case(pool: &SqlitePool, case_id: i32) -> Result<GovernanceReviewCase, ApiError> {
+    GovernanceReviewCaseManager::new(pool.clone())
+        .get_case_by_id(case_id)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => {
+                api_error(StatusCode::NOT_FOUND, format!("Case {} not found", case_id))
+            }
+            e => internal_error("Failed to load case", e),
+        })
+}
+
+/// The case changed state between validation and the transition
+fn state_conflict(e: sqlx::Error) -> ApiError {
+    api_error(StatusCode::CONFLICT, e)
+}
+
+fn invalid_transition(message: impl std::fmt::Display) -> ApiError {
+    api_error(StatusCode::UNPROCESSABLE_ENTITY, message)
+}
+
+/// Reject actions on cases past their 180-day resolution deadline
+fn ensure_before_resolution_deadline(case: &GovernanceReviewCase) -> Result<(), ApiError> {
+    match case.resolution_deadline {
+        Some(deadline) if Utc::now() > deadline => Err(invalid_transition(format!(
+            "Case {} passed its resolution deadline on {}",
+            case.case_number,
+            deadline.format("%Y-%m-%d")
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Record a mutation in the audit log; failures are logged, not returned
+async fn audit(actor: &str, case_id: i32, action: &str, metadata: serde_json::Value) {
+    if let Some(logger) = shared_logger() {
+        if let Err(e) = logger
+            .log_action(
+                AuditCategory::GovernanceAction,
+                actor,
+                Some(&format!("review_case:{}", case_id)),
+                action,
+                metadata,
+            )
+            .await
+        {
+            warn!("Failed to audit-log {} for case {}: {}", action, case_id, e);
+        }
+    }
+}
+
+/// Open a new case
+pub async fn open_case(
+    State((_, database)): State<(AppConfig, Database)>,
+    Json(request): Json<OpenCaseRequest>,
+) -> Result<(StatusCode, Json<GovernanceReviewCase>), ApiError> {
+    let pool = sqlite_pool(&database)?;
+    if !policy::CASE_TYPES.contains(&request.case_type.as_str()) {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            format!("Unknown case type '{}'", request.case_type),
+        ));
+    }
+    if !policy::SEVERITY_LEVELS.contains(&request.severity.as_str()) {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            format!("Unknown severity '{}'", request.severity),
+        ));
+    }
+    // Policy: off-platform activity is disregarded
+    if !request.on_platform {
+        return Err(invalid_transition(
+            "Only on-platform activity can be the subject of a case",
+        ));
+    }
+
+    let case = Case::draft(
+        pool,
+        request.subject_maintainer_id,
+        request.reporter_maintainer_id,
+        &request.case_type,
+        &request.severity,
+        &request.description,
+        request.evidence,
+        request.on_platform,
+    )
+    .open(&request.opened_by, "Case opened")
+    .await
+    .map_err(|e| internal_error("Failed to open case", e))?;
+
+    let case = case.record().clone();
+    audit(
+        &request.opened_by,
+        case.id,
+        "review_case_opened",
+        serde_json::json!({
+            "case_number": case.case_number,
+            "case_type": case.case_type,
+            "severity": case.severity,
+            "subject_maintainer_id": case.subject_maintainer_id,
+        }),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(case)))
+}
+
+/// List cases, optionally filtered by `status` and `subject` maintainer id
+pub async fn list_cases(
+    State((_, database)): State<(AppConfig, Database)>,
+    Query(query): Query<ListCasesQuery>,
+) -> Result<Json<CasesResponse>, ApiError> {
+    let pool = sqlite_pool(&database)?;
+    let cases = GovernanceReviewCaseManager::new(pool)
+        .list_cases(query.status.as_deref(), query.subject)
+        .await
+        .map_err(|e| internal_error("Failed to list cases", e))?;
+    Ok(Json(CasesResponse { cases }))
+}
+
+/// Get a case with its responses and transition history
+pub async fn get_case(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(case_id): Path<i32>,
+) -> Result<Json<CaseDetailResponse>, ApiError> {
+    let pool = sqlite_pool(&database)?;
+    let case = load_case(&pool, case_id).await?;
+    let responses = ResponseManager::new(pool.clone())
+        .get_responses_for_case(case_id)
+        .await
+        .map_err(|e| internal_error("Failed to load responses", e))?;
+    let transitions = crate::governance_review::lifecycle::get_transitions(&pool, case_id)
+        .await
+        .map_err(|e| internal_error("Failed to load transitions", e))?;
+    Ok(Json(CaseDetailResponse {
+        case,
+        responses,
+        transitions,
+    }))
+}
+
+/// Subject's response to a case, within the response deadline
+pub async fn submit_response(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(case_id): Path<i32>,
+    Json(request): Json<SubmitResponseRequest>,
+) -> Result<(StatusCode, Json<GovernanceReviewResponse>), ApiError> {
+    let pool = sqlite_pool(&database)?;
+    let case = load_case(&pool, case_id).await?;
+    if request.maintainer_id != case.subject_maintainer_id {
+        return Err(api_error(
+            StatusCode::FORBIDDEN,
+            "Only the subject of a case can respond to it",
+        ));
+    }
+    if ![policy::STATUS_OPEN, policy::STATUS_UNDER_REVIEW].contains(&case.status.as_str()) {
+        return Err(invalid_transition(format!(
+            "Case is '{}'; responses are only accepted while it is open",
+            case.status
+        )));
+    }
+    if let Some(deadline) = case.response_deadline {
+        if Utc::now() > deadline {
+            return Err(invalid_transition(format!(
+                "Response deadline passed on {}",
+                deadline.format("%Y-%m-%d")
+            )));
+        }
+    }
+
+    let response = ResponseManager::new(pool)
+        .submit_response(
+            case_id,
+            request.maintainer_id,
+            &request.response_text,
+            request.counter_evidence,
+        )
+        .await
+        .map_err(|e| internal_error("Failed to submit response", e))?;
+    audit(
+        &format!("maintainer:{}", request.maintainer_id),
+        case_id,
+        "review_case_response",
+        serde_json::json!({ "response_id": response.id }),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// Issue a warning and close the case
+///
+/// Rejected once the case is closed or past its resolution deadline, or
+/// when the approvals don't meet the sanction's team threshold.
+pub async fn sanction_case(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(case_id): Path<i32>,
+    Json(request): Json<SanctionRequest>,
+) -> Result<(StatusCode, Json<GovernanceReviewWarning>), ApiError> {
+    let pool = sqlite_pool(&database)?;
+    let case = load_case(&pool, case_id).await?;
+    ensure_before_resolution_deadline(&case)?;
+
+    let mut approvals = request.approvals.clone();
+    approvals.sort_unstable();
+    approvals.dedup();
+    let threshold = match request.sanction_type.as_str() {
+        "private_warning" => policy::PRIVATE_WARNING_THRESHOLD,
+        "public_warning" => policy::PUBLIC_WARNING_THRESHOLD,
+        other => {
+            return Err(api_error(
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "Unknown sanction type '{}' (expected private_warning or public_warning)",
+                    other
+                ),
+            ))
+        }
+    };
+    if approvals.len() < threshold as usize {
+        return Err(invalid_transition(format!(
+            "{} requires {} team approvals, got {}",
+            request.sanction_type,
+            threshold,
+            approvals.len()
+        )));
+    }
+
+    if request.sanction_type == "public_warning" && request.warning_file_path.is_none() {
+        return Err(api_error(
+            StatusCode::BAD_REQUEST,
+            "warning_file_path is required for public warnings",
+        ));
+    }
+
+    let reason = format!("{} issued", request.sanction_type.replace('_', " "));
+    close_case(&pool, &case, &request.issued_by, &reason).await?;
+
+    let sanctions = SanctionManager::new(pool);
+    let warning = match request.warning_file_path.clone() {
+        Some(path) if request.sanction_type == "public_warning" => {
+            sanctions
+                .issue_public_warning(case_id, case.subject_maintainer_id, approvals, path)
+                .await
+        }
+        _ => {
+            sanctions
+                .issue_private_warning(case_id, case.subject_maintainer_id, approvals)
+                .await
+        }
+    }
+    .map_err(|e| internal_error("Failed to issue sanction", e))?;
+
+    audit(
+        &request.issued_by,
+        case_id,
+        "review_case_sanctioned",
+        serde_json::json!({
+            "sanction_type": request.sanction_type,
+            "warning_id": warning.id,
+            "approvals": warning.issued_by_team_approval,
+        }),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(warning)))
+}
+
+/// Close a case from whichever closable state it is in
+async fn close_case(
+    pool: &SqlitePool,
+    case: &GovernanceReviewCase,
+    closed_by: &str,
+    reason: &str,
+) -> Result<(), ApiError> {
+    let pool = pool.clone();
+    let result = match case.status.as_str() {
+        policy::STATUS_OPEN => {
+            Case::<Open>::load(pool, case.id)
+                .await
+                .map_err(state_conflict)?
+                .close(closed_by, reason)
+                .await
+        }
+        policy::STATUS_UNDER_REVIEW => {
+            Case::<ResponsePending>::load(pool, case.id)
+                .await
+                .map_err(state_conflict)?
+                .close(closed_by, reason)
+                .await
+        }
+        policy::STATUS_MEDIATION => {
+            Case::<MediationState>::load(pool, case.id)
+                .await
+                .map_err(state_conflict)?
+                .close(closed_by, reason)
+                .await
+        }
+        status => {
+            return Err(invalid_transition(format!(
+                "Case is '{}' and can no longer be sanctioned",
+                status
+            )))
+        }
+    };
+    result
+        .map(|_| ())
+        .map_err(|e| internal_error("Failed to close case", e))
+}
+
+/// Subject's appeal of a closed case, within the configured appeal window
+pub async fn appeal_case(
+    State((config, database)): State<(AppConfig, Database)>,
+    Path(case_id): Path<i32>,
+    Json(request): Json<AppealRequest>,
+) -> Result<(StatusCode, Json<Appeal>), ApiError> {
+    let pool = sqlite_pool(&database)?;
+    let case = load_case(&pool, case_id).await?;
+    if request.maintainer_id != case.subject_maintainer_id {
+        return Err(api_error(
+            StatusCode::FORBIDDEN,
+            "Only the subject of a case can appeal it",
+        ));
+    }
+    if case.status != policy::STATUS_RESOLVED {
+        return Err(invalid_transition(format!(
+            "Case is '{}'; only closed cases can be appealed",
+            case.status
+        )));
+    }
+    let closed_at = case.resolved_at.unwrap_or(case.created_at);
+    let window_ends = closed_at + Duration::days(config.governance_review.appeal_window_days);
+    if Utc::now() > window_ends {
+        return Err(invalid_transition(format!(
+            "Appeal window closed on {}",
+            window_ends.format("%Y-%m-%d")
+        )));
+    }
+
+    let actor = format!("maintainer:{}", request.maintainer_id);
+    Case::<Closed>::load(pool.clone(), case_id)
+        .await
+        .map_err(state_conflict)?
+        .appeal(&actor, &request.appeal_reason)
+        .await
+        .map_err(|e| internal_error("Failed to appeal case", e))?;
+    let appeal = AppealManager::new(pool)
+        .submit_appeal(
+            case_id,
+            request.maintainer_id,
+            &request.appeal_reason,
+            request.new_evidence,
+        )
+        .await
+        .map_err(|e| internal_error("Failed to record appeal", e))?;
+
+    audit(
+        &actor,
+        case_id,
+        "review_case_appealed",
+        serde_json::json!({ "appeal_id": appeal.id }),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(appeal)))
+}
+
+/// Move an open case into mediation
+pub async fn start_mediation(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(case_id): Path<i32>,
+    Json(request): Json<MediationRequest>,
+) -> Result<(StatusCode, Json<Mediation>), ApiError> {
+    let pool = sqlite_pool(&database)?;
+    let case = load_case(&pool, case_id).await?;
+    ensure_before_resolution_deadline(&case)?;
+
+    let reason = "Mediation started";
+    let result = match case.status.as_str() {
+        policy::STATUS_OPEN => {
+            Case::<Open>::load(pool.clone(), case_id)
+                .await
+                .map_err(state_conflict)?
+                .begin_mediation(&request.started_by, reason)
+                .await
+        }
+        policy::STATUS_UNDER_REVIEW => {
+            Case::<ResponsePending>::load(pool.clone(), case_id)
+                .await
+                .map_err(state_conflict)?
+                .begin_mediation(&request.started_by, reason)
+                .await
+        }
+        status => {
+            return Err(invalid_transition(format!(
+                "Case is '{}'; only open cases can enter mediation",
+                status
+            )))
+        }
+    };
+    result.map_err(|e| internal_error("Failed to start mediation", e))?;
+
+    let mediation = MediationManager::new(pool)
+        .start_mediation(case_id, request.mediator_maintainer_id)
+        .await
+        .map_err(|e| internal_error("Failed to record mediation", e))?;
+    audit(
+        &request.started_by,
+        case_id,
+        "review_case_mediation",
+        serde_json::json!({
+            "mediation_id": mediation.id,
+            "mediator_maintainer_id": mediation.mediator_maintainer_id,
+        }),
+    )
+    .await;
+    Ok((StatusCode::CREATED, Json(mediation)))
+}
+
+/// Create router for governance review API
+///
+/// The public case record is unauthenticated; the case lifecycle routes
+/// under /internal/review require the internal API key.
+pub fn create_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    let internal = Router::new()
+        .route("/internal/review/cases", post(open_case).get(list_cases))
+        .route("/internal/review/cases/:id", get(get_case))
+        .route("/internal/review/cases/:id/response", post(submit_response))
+        .route("/internal/review/cases/:id/sanction", post(sanction_case))
+        .route("/internal/review/cases/:id/appeal", post(appeal_case))
+        .route(
+            "/internal/review/cases/:id/mediation",
+            post(start_mediation),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ));
+
+    Router::new()
+        .route("/governance/review/cases/public", get(get_public_cases))
+        .merge(internal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    async fn test_app() -> (Router, SqlitePool) {
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.get_sqlite_pool().unwrap();
+        for i in 1..=7 {
+            sqlx::query(
+                "INSERT INTO maintainers (github_username, public_key, layer, active, last_updated) VALUES (?, ?, 1, true, CURRENT_TIMESTAMP)",
+            )
+            .bind(format!("maintainer{}", i))
+            .bind(format!("pubkey{}", i))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        let config = AppConfig {
+            internal_api_key: Some("secret".to_string()),
+            ..Default::default()
+        };
+        let state = (config, database);
+        (create_router(state.clone()).with_state(state), pool)
+    }
+
+    async fn call(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("x-api-key", "secret")
+            .header("content-type", "application/json");
+        let body = body
+            .map(|b| Body::from(b.to_string()))
+            .unwrap_or_else(Body::empty);
+        let response = app
+            .clone()
+            .oneshot(request.body(body).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_case_lifecycle() {
+        let (app, pool) = test_app().await;
+
+        let (status, _) = call(
+            &app,
+            "POST",
+            "/internal/review/cases",
+            Some(json!({
+                "subject_maintainer_id": 1, "reporter_maintainer_id": 2,
+                "case_type": "harassment", "severity": "moderate",
+                "description": "d", "on_platform": false, "opened_by": "maintainer2"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (status, case) = call(
+            &app,
+            "POST",
+            "/internal/review/cases",
+            Some(json!({
+                "subject_maintainer_id": 1, "reporter_maintainer_id": 2,
+                "case_type": "harassment", "severity": "moderate",
+                "description": "Abusive review comments", "on_platform": true,
+                "opened_by": "maintainer2"
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let id = case["id"].as_i64().unwrap();
+
+        let (status, _) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/response", id),
+            Some(json!({ "maintainer_id": 2, "response_text": "not mine to answer" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/response", id),
+            Some(json!({ "maintainer_id": 1, "response_text": "I apologise" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        // Too few approvals for a private warning
+        let (status, _) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/sanction", id),
+            Some(json!({ "sanction_type": "private_warning", "approvals": [2, 3, 3, 4], "issued_by": "maintainer3" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let (status, warning) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/sanction", id),
+            Some(json!({ "sanction_type": "private_warning", "approvals": [2, 3, 4, 5], "issued_by": "maintainer3" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(warning["warning_level"], 1);
+
+        let (status, detail) =
+            call(&app, "GET", &format!("/internal/review/cases/{}", id), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(detail["case"]["status"], policy::STATUS_RESOLVED);
+        assert_eq!(detail["responses"].as_array().unwrap().len(), 1);
+        assert_eq!(detail["transitions"].as_array().unwrap().len(), 2);
+
+        // A closed case can't be sanctioned again
+        let (status, _) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/sanction", id),
+            Some(json!({ "sanction_type": "private_warning", "approvals": [2, 3, 4, 5], "issued_by": "maintainer3" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        // Appeals are rejected once the appeal window has passed
+        sqlx::query("UPDATE governance_review_cases SET resolved_at = ? WHERE id = ?")
+            .bind(Utc::now() - Duration::days(policy::APPEAL_DEADLINE_DAYS + 1))
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let appeal = json!({ "maintainer_id": 1, "appeal_reason": "Misread context" });
+        let (status, error) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/appeal", id),
+            Some(appeal.clone()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(error["error"]
+            .as_str()
+            .unwrap()
+            .contains("Appeal window closed"));
+
+        sqlx::query("UPDATE governance_review_cases SET resolved_at = ? WHERE id = ?")
+            .bind(Utc::now())
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let (status, _) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/appeal", id),
+            Some(appeal),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, list) = call(
+            &app,
+            "GET",
+            "/internal/review/cases?status=appealed&subject=1",
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(list["cases"].as_array().unwrap().len(), 1);
+        let (_, list) = call(&app, "GET", "/internal/review/cases?subject=2", None).await;
+        assert!(list["cases"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_mediation_and_resolution_deadline() {
+        let (app, pool) = test_app().await;
+        let (_, case) = call(
+            &app,
+            "POST",
+            "/internal/review/cases",
+            Some(json!({
+                "subject_maintainer_id": 3, "reporter_maintainer_id": 4,
+                "case_type": "conflict_of_interest", "severity": "minor",
+                "description": "Undisclosed sponsor", "on_platform": true,
+                "opened_by": "maintainer4"
+            })),
+        )
+        .await;
+        let id = case["id"].as_i64().unwrap();
+
+        let (status, mediation) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/mediation", id),
+            Some(json!({ "mediator_maintainer_id": 5, "started_by": "maintainer5" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(mediation["status"], "active");
+
+        sqlx::query("UPDATE governance_review_cases SET resolution_deadline = ? WHERE id = ?")
+            .bind(Utc::now() - Duration::days(1))
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let (status, error) = call(
+            &app,
+            "POST",
+            &format!("/internal/review/cases/{}/sanction", id),
+            Some(json!({ "sanction_type": "private_warning", "approvals": [1, 2, 5, 6], "issued_by": "maintainer5" })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(error["error"]
+            .as_str()
+            .unwrap()
+            .contains("resolution deadline"));
+    }
+}
@@ -5,18 +5,33 @@
 //! - Time limits (180 days for resolution)
 //! - Response periods (30 days for subject)
 
-use crate::governance_review::models::{policy, GovernanceReviewCase};
+use crate::governance_review::models::{policy, GovernanceReviewCase, PublicCaseRecord};
 use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
+use sqlx::sqlite::SqliteRow;
 use sqlx::{Row, SqlitePool};
 use uuid::Uuid;
 
 pub struct GovernanceReviewCaseManager {
     pool: SqlitePool,
+    public_record_redact_fields: Vec<String>,
 }
 
 impl GovernanceReviewCaseManager {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            public_record_redact_fields: policy::PUBLIC_RECORD_REDACT_FIELDS
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+        }
+    }
+
+    /// Set which public record fields are redacted
+    pub fn with_public_record_redact_fields(mut self, fields: Vec<String>) -> Self {
+        self.public_record_redact_fields = fields;
+        self
     }
 
     /// Create a new governance review case
@@ -161,24 +176,7 @@ impl GovernanceReviewCaseManager {
         .fetch_one(&self.pool)
         .await?;
 
-        Ok(GovernanceReviewCase {
-            id: row.get(0),
-            case_number: row.get(1),
-            subject_maintainer_id: row.get(2),
-            reporter_maintainer_id: row.get(3),
-            case_type: row.get(4),
-            severity: row.get(5),
-            status: row.get(6),
-            description: row.get(7),
-            evidence: serde_json::from_str(row.get::<String, _>(8).as_str()).unwrap_or_default(),
-            on_platform: row.get(9),
-            created_at: row.get(10),
-            response_deadline: row.get(11),
-            resolution_deadline: row.get(12),
-            resolved_at: row.get(13),
-            resolution_reason: row.get(14),
-            github_issue_number: row.get::<Option<i64>, _>(15).map(|v| v as u64),
-        })
+        Ok(case_from_row(&row))
     }
 
     /// Get cases by maintainer (subject or reporter)
@@ -221,29 +219,124 @@ impl GovernanceReviewCaseManager {
             .await?
         };
 
-        rows.into_iter()
-            .map(|row| {
-                Ok(GovernanceReviewCase {
-                    id: row.get(0),
-                    case_number: row.get(1),
-                    subject_maintainer_id: row.get(2),
-                    reporter_maintainer_id: row.get(3),
-                    case_type: row.get(4),
-                    severity: row.get(5),
-                    status: row.get(6),
-                    description: row.get(7),
-                    evidence: serde_json::from_str(row.get::<String, _>(8).as_str())
-                        .unwrap_or_default(),
-                    on_platform: row.get(9),
-                    created_at: row.get(10),
-                    response_deadline: row.get(11),
-                    resolution_deadline: row.get(12),
-                    resolved_at: row.get(13),
-                    resolution_reason: row.get(14),
-                    github_issue_number: row.get::<Option<i64>, _>(15).map(|v| v as u64),
-                })
-            })
-            .collect()
+        Ok(rows.iter().map(case_from_row).collect())
+    }
+
+    /// List cases, newest first, optionally filtered by status and subject
+    pub async fn list_cases(
+        &self,
+        status: Option<&str>,
+        subject_maintainer_id: Option<i32>,
+    ) -> Result<Vec<GovernanceReviewCase>, sqlx::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, case_number, subject_maintainer_id, reporter_maintainer_id,
+                case_type, severity, status, description, evidence, on_platform,
+                created_at, response_deadline, resolution_deadline,
+                resolved_at, resolution_reason, github_issue_number
+            FROM governance_review_cases
+            WHERE (? IS NULL OR status = ?)
+            AND (? IS NULL OR subject_maintainer_id = ?)
+            ORDER BY created_at DESC, id DESC
+            "#,
+        )
+        .bind(status)
+        .bind(status)
+        .bind(subject_maintainer_id)
+        .bind(subject_maintainer_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(case_from_row).collect())
+    }
+
+    /// Public record of a closed case, with the configured fields redacted
+    ///
+    /// Policy: only closed cases are public; any other case is not found.
+    pub async fn get_public_record(&self, case_id: i32) -> Result<PublicCaseRecord, sqlx::Error> {
+        let case = self.get_case_by_id(case_id).await?;
+        if !policy::PUBLIC_RECORD_STATUSES.contains(&case.status.as_str()) {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        let warning_level: Option<i32> = sqlx::query_scalar(
+            "SELECT MAX(warning_level) FROM governance_review_warnings WHERE case_id = ?",
+        )
+        .bind(case_id)
+        .fetch_one(&self.pool)
+        .await?;
+        let sanction_level = if case.status == policy::STATUS_REMOVED {
+            "removal"
+        } else {
+            match warning_level {
+                Some(1) => "private_warning",
+                Some(level) if level >= 2 => "public_warning",
+                _ => "none",
+            }
+        };
+
+        let responses: Vec<String> = sqlx::query_scalar(
+            "SELECT response_text FROM governance_review_responses WHERE case_id = ? ORDER BY id",
+        )
+        .bind(case_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let redact = |field: &str, value: Value| {
+            if self
+                .public_record_redact_fields
+                .iter()
+                .any(|redacted| redacted == field)
+            {
+                Value::String(policy::REDACTED.to_string())
+            } else {
+                value
+            }
+        };
+
+        Ok(PublicCaseRecord {
+            case_id: case.id,
+            case_number: case.case_number,
+            case_type: case.case_type,
+            sanction_level: sanction_level.to_string(),
+            opened_at: case.created_at,
+            closed_at: case.resolved_at,
+            status: case.status,
+            subject: redact("subject", case.subject_maintainer_id.into()),
+            reporter: redact("reporter", case.reporter_maintainer_id.into()),
+            description: redact("description", case.description.into()),
+            evidence: redact("evidence", case.evidence),
+            responses: responses
+                .into_iter()
+                .map(|response| redact("responses", response.into()))
+                .collect(),
+            outcome_summary: redact(
+                "outcome_summary",
+                case.resolution_reason
+                    .map(Value::from)
+                    .unwrap_or(Value::Null),
+            ),
+        })
+    }
+
+    /// Public records of all closed cases, most recently closed first
+    pub async fn get_public_records(&self) -> Result<Vec<PublicCaseRecord>, sqlx::Error> {
+        let placeholders = vec!["?"; policy::PUBLIC_RECORD_STATUSES.len()].join(", ");
+        let query = format!(
+            "SELECT id FROM governance_review_cases WHERE status IN ({}) ORDER BY resolved_at DESC, id DESC",
+            placeholders
+        );
+        let mut case_ids = sqlx::query_scalar::<_, i32>(&query);
+        for status in policy::PUBLIC_RECORD_STATUSES {
+            case_ids = case_ids.bind(*status);
+        }
+
+        let mut records = Vec::new();
+        for case_id in case_ids.fetch_all(&self.pool).await? {
+            records.push(self.get_public_record(case_id).await?);
+        }
+        Ok(records)
     }
 
     /// Check if case is expired (policy: 180 days)
@@ -270,34 +363,26 @@ impl GovernanceReviewCaseManager {
 
         Ok(expired.iter().map(|row| row.get::<i32, _>(0)).collect())
     }
+}
 
-    /// Update case status
-    pub async fn update_status(
-        &self,
-        case_id: i32,
-        status: &str,
-        resolution_reason: Option<&str>,
-    ) -> Result<(), sqlx::Error> {
-        let resolved_at = if status == "resolved" || status == "removed" || status == "dismissed" {
-            Some(Utc::now())
-        } else {
-            None
-        };
-
-        sqlx::query(
-            r#"
-            UPDATE governance_review_cases
-            SET status = ?, resolution_reason = ?, resolved_at = ?
-            WHERE id = ?
-            "#,
-        )
-        .bind(status)
-        .bind(resolution_reason)
-        .bind(resolved_at)
-        .bind(case_id)
-        .execute(&self.pool)
-        .await?;
-
-        Ok(())
+/// Map a row selected with the standard case column list
+fn case_from_row(row: &SqliteRow) -> GovernanceReviewCase {
+    GovernanceReviewCase {
+        id: row.get(0),
+        case_number: row.get(1),
+        subject_maintainer_id: row.get(2),
+        reporter_maintainer_id: row.get(3),
+        case_type: row.get(4),
+        severity: row.get(5),
+        status: row.get(6),
+        description: row.get(7),
+        evidence: serde_json::from_str(row.get::<String, _>(8).as_str()).unwrap_or_default(),
+        on_platform: row.get(9),
+        created_at: row.get(10),
+        response_deadline: row.get(11),
+        resolution_deadline: row.get(12),
+        resolved_at: row.get(13),
+        resolution_reason: row.get(14),
+        github_issue_number: row.get::<Option<i64>, _>(15).map(|v| v as u64),
     }
 }
@@ -2,17 +2,146 @@
 //!
 //! Notifies maintainers about approaching deadlines for cases, appeals, mediations
 
+use crate::audit::{shared_logger, AuditCategory};
 use crate::governance_review::github_integration::GovernanceReviewGitHubIntegration;
+use crate::nostr::{
+    CombinedRequirement, EconomicVetoStatus, GovernanceActionPublisher, LayerRequirement,
+    NostrClient, TierRequirement,
+};
+use crate::shutdown::{self, Shutdown};
 use chrono::{DateTime, Duration, Utc};
+use nostr_sdk::prelude::{Tag, TagKind};
 use sqlx::{Row, SqlitePool};
-use tracing::{info, warn};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
 
 /// Days before deadline to send notification
 const NOTIFICATION_DAYS_BEFORE: i64 = 7;
 
+/// Hours before a deadline that maintainers get a Nostr DM reminder
+pub const DM_LOOKAHEAD_HOURS: u32 = 48;
+
+/// Hours before a deadline that a second, urgent reminder is sent
+pub const URGENT_HOURS: u32 = 24;
+
+/// Days before a deadline that the daily reminder task posts reminders
+pub const DEFAULT_REMINDER_LEAD_DAYS: [u32; 3] = [14, 7, 1];
+
+/// How often the reminder task scans open cases and appeals
+pub const REMINDER_SCAN_INTERVAL: std::time::Duration = std::time::Duration::from_secs(86400);
+
+/// Kind of deadline a maintainer is reminded about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeadlineType {
+    /// Subject's response to a case
+    Response,
+    /// Resolution of a case
+    Resolution,
+    /// Decision on an appeal
+    Appeal,
+}
+
+impl DeadlineType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeadlineType::Response => "response",
+            DeadlineType::Resolution => "resolution",
+            DeadlineType::Appeal => "appeal",
+        }
+    }
+}
+
+/// Reminder level; a deadline gets at most one of each
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Upcoming,
+    Urgent,
+}
+
+impl NotificationLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationLevel::Upcoming => "upcoming",
+            NotificationLevel::Urgent => "urgent",
+        }
+    }
+
+    /// Level for a deadline as of `now`
+    pub fn for_deadline(deadline: DateTime<Utc>, now: DateTime<Utc>, urgent_hours: u32) -> Self {
+        if deadline - now <= Duration::hours(urgent_hours as i64) {
+            NotificationLevel::Urgent
+        } else {
+            NotificationLevel::Upcoming
+        }
+    }
+}
+
+/// Reminder due for a deadline on the daily scan
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReminderKind {
+    /// Deadline is at most this many days away
+    Lead(u32),
+    /// Deadline has passed; escalated instead of reminded
+    Overdue,
+}
+
+impl ReminderKind {
+    /// Level recorded in `deadline_notifications_sent`
+    pub fn level(&self) -> String {
+        match self {
+            ReminderKind::Lead(days) => format!("lead_{}d", days),
+            ReminderKind::Overdue => "overdue".to_string(),
+        }
+    }
+
+    /// Reminder due for a deadline as of `now`
+    ///
+    /// Only the shortest lead time the deadline falls within is due, so a
+    /// deadline 6 days out with lead times 14/7/1 gets the 7-day reminder and
+    /// never a late 14-day one.
+    pub fn for_deadline(
+        deadline: DateTime<Utc>,
+        now: DateTime<Utc>,
+        lead_days: &[u32],
+    ) -> Option<Self> {
+        if deadline <= now {
+            return Some(ReminderKind::Overdue);
+        }
+        lead_days
+            .iter()
+            .copied()
+            .filter(|days| deadline - now <= Duration::days(*days as i64))
+            .min()
+            .map(ReminderKind::Lead)
+    }
+}
+
+/// A reminder the daily scan should send
+#[derive(Debug, Clone)]
+pub struct DueReminder {
+    pub deadline: UpcomingDeadline,
+    pub kind: ReminderKind,
+    /// Issue tracking the case, where the reminder is posted
+    pub github_issue_number: Option<u64>,
+}
+
+/// A deadline approaching for one maintainer
+#[derive(Debug, Clone)]
+pub struct UpcomingDeadline {
+    pub deadline_type: DeadlineType,
+    /// Case id (response, resolution) or appeal id
+    pub record_id: i32,
+    pub case_number: String,
+    pub maintainer_id: i32,
+    pub github_username: Option<String>,
+    pub deadline: DateTime<Utc>,
+}
+
 pub struct DeadlineNotificationManager {
     pool: SqlitePool,
     github_integration: Option<GovernanceReviewGitHubIntegration>,
+    publisher: Option<Arc<GovernanceActionPublisher>>,
 }
 
 impl DeadlineNotificationManager {
@@ -23,9 +152,16 @@ impl DeadlineNotificationManager {
         Self {
             pool,
             github_integration,
+            publisher: None,
         }
     }
 
+    /// Also publish reminders and escalations as Nostr governance action events
+    pub fn with_publisher(mut self, publisher: Arc<GovernanceActionPublisher>) -> Self {
+        self.publisher = Some(publisher);
+        self
+    }
+
     /// Check for approaching deadlines and send notifications
     pub async fn check_and_notify(&self) -> Result<DeadlineNotificationResult, sqlx::Error> {
         let mut result = DeadlineNotificationResult::default();
@@ -71,6 +207,512 @@ impl DeadlineNotificationManager {
         Ok(result)
     }
 
+    /// Response, resolution and appeal deadlines within `lookahead_hours`,
+    /// one entry per maintainer to remind
+    ///
+    /// Response deadlines go to the subject until they respond, resolution
+    /// deadlines to the subject and reporter, appeal deadlines to the appellant.
+    pub async fn get_upcoming_deadlines(
+        &self,
+        lookahead_hours: u32,
+    ) -> Result<Vec<UpcomingDeadline>, sqlx::Error> {
+        let now = Utc::now();
+        let threshold = now + Duration::hours(lookahead_hours as i64);
+
+        let queries = [
+            (
+                DeadlineType::Response,
+                r#"
+                SELECT c.id, c.case_number, c.subject_maintainer_id, m.github_username, c.response_deadline
+                FROM governance_review_cases c
+                LEFT JOIN maintainers m ON m.id = c.subject_maintainer_id
+                WHERE c.status IN ('open', 'under_review')
+                AND c.response_deadline IS NOT NULL
+                AND c.response_deadline > ?
+                AND c.response_deadline <= ?
+                AND NOT EXISTS (
+                    SELECT 1 FROM governance_review_responses r WHERE r.case_id = c.id
+                )
+                "#,
+            ),
+            (
+                DeadlineType::Resolution,
+                r#"
+                SELECT c.id, c.case_number, p.maintainer_id, m.github_username, c.resolution_deadline
+                FROM governance_review_cases c
+                JOIN (
+                    SELECT id as case_id, subject_maintainer_id as maintainer_id FROM governance_review_cases
+                    UNION
+                    SELECT id, reporter_maintainer_id FROM governance_review_cases
+                ) p ON p.case_id = c.id
+                LEFT JOIN maintainers m ON m.id = p.maintainer_id
+                WHERE c.status NOT IN ('resolved', 'dismissed', 'removed', 'expired')
+                AND c.resolution_deadline IS NOT NULL
+                AND c.resolution_deadline > ?
+                AND c.resolution_deadline <= ?
+                "#,
+            ),
+            (
+                DeadlineType::Appeal,
+                r#"
+                SELECT a.id, c.case_number, a.maintainer_id, m.github_username, a.appeal_deadline
+                FROM governance_review_appeals a
+                JOIN governance_review_cases c ON c.id = a.case_id
+                LEFT JOIN maintainers m ON m.id = a.maintainer_id
+                WHERE a.status = 'pending'
+                AND a.appeal_deadline IS NOT NULL
+                AND a.appeal_deadline > ?
+                AND a.appeal_deadline <= ?
+                "#,
+            ),
+        ];
+
+        let mut deadlines = Vec::new();
+        for (deadline_type, query) in queries {
+            let rows = sqlx::query(query)
+                .bind(now)
+                .bind(threshold)
+                .fetch_all(&self.pool)
+                .await?;
+
+            deadlines.extend(rows.iter().map(|row| UpcomingDeadline {
+                deadline_type,
+                record_id: row.get(0),
+                case_number: row.get(1),
+                maintainer_id: row.get(2),
+                github_username: row.get(3),
+                deadline: row.get(4),
+            }));
+        }
+
+        deadlines.sort_by_key(|deadline| deadline.deadline);
+        Ok(deadlines)
+    }
+
+    /// Upcoming deadlines with the reminder level now due, skipping reminders already sent
+    pub async fn pending_notifications(
+        &self,
+        lookahead_hours: u32,
+        urgent_hours: u32,
+    ) -> Result<Vec<(UpcomingDeadline, NotificationLevel)>, sqlx::Error> {
+        let now = Utc::now();
+        let mut pending = Vec::new();
+
+        for deadline in self.get_upcoming_deadlines(lookahead_hours).await? {
+            let level = NotificationLevel::for_deadline(deadline.deadline, now, urgent_hours);
+            if !self.was_sent(&deadline, level.as_str()).await? {
+                pending.push((deadline, level));
+            }
+        }
+
+        Ok(pending)
+    }
+
+    /// Record that a reminder was sent
+    pub async fn mark_notified(
+        &self,
+        deadline: &UpcomingDeadline,
+        level: NotificationLevel,
+    ) -> Result<(), sqlx::Error> {
+        self.record_sent(deadline, level.as_str()).await
+    }
+
+    /// Whether a reminder at `level` was already sent for this deadline
+    async fn was_sent(
+        &self,
+        deadline: &UpcomingDeadline,
+        level: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let sent: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM deadline_notifications_sent
+            WHERE deadline_type = ? AND record_id = ? AND maintainer_id = ?
+            AND level = ? AND deadline = ?
+            "#,
+        )
+        .bind(deadline.deadline_type.as_str())
+        .bind(deadline.record_id)
+        .bind(deadline.maintainer_id)
+        .bind(level)
+        .bind(deadline.deadline)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(sent > 0)
+    }
+
+    async fn record_sent(
+        &self,
+        deadline: &UpcomingDeadline,
+        level: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO deadline_notifications_sent
+            (deadline_type, record_id, maintainer_id, level, deadline, sent_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(deadline.deadline_type.as_str())
+        .bind(deadline.record_id)
+        .bind(deadline.maintainer_id)
+        .bind(level)
+        .bind(deadline.deadline)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Reminders and escalations due on this scan, skipping any already sent
+    ///
+    /// Covers unanswered response deadlines, resolution deadlines of open
+    /// cases and decision deadlines of pending appeals, addressed to the case
+    /// subject or the appellant.
+    pub async fn due_reminders(&self, lead_days: &[u32]) -> Result<Vec<DueReminder>, sqlx::Error> {
+        let queries = [
+            (
+                DeadlineType::Response,
+                r#"
+                SELECT c.id, c.case_number, c.subject_maintainer_id, m.github_username,
+                       c.response_deadline, c.github_issue_number
+                FROM governance_review_cases c
+                LEFT JOIN maintainers m ON m.id = c.subject_maintainer_id
+                WHERE c.status IN ('open', 'under_review')
+                AND c.response_deadline IS NOT NULL
+                AND NOT EXISTS (
+                    SELECT 1 FROM governance_review_responses r WHERE r.case_id = c.id
+                )
+                "#,
+            ),
+            (
+                DeadlineType::Resolution,
+                r#"
+                SELECT c.id, c.case_number, c.subject_maintainer_id, m.github_username,
+                       c.resolution_deadline, c.github_issue_number
+                FROM governance_review_cases c
+                LEFT JOIN maintainers m ON m.id = c.subject_maintainer_id
+                WHERE c.status NOT IN ('resolved', 'dismissed', 'removed', 'expired', 'appealed')
+                AND c.resolution_deadline IS NOT NULL
+                "#,
+            ),
+            (
+                DeadlineType::Appeal,
+                r#"
+                SELECT a.id, c.case_number, a.maintainer_id, m.github_username,
+                       a.appeal_deadline, c.github_issue_number
+                FROM governance_review_appeals a
+                JOIN governance_review_cases c ON c.id = a.case_id
+                LEFT JOIN maintainers m ON m.id = a.maintainer_id
+                WHERE a.status = 'pending'
+                AND a.appeal_deadline IS NOT NULL
+                "#,
+            ),
+        ];
+
+        let now = Utc::now();
+        let mut due = Vec::new();
+        for (deadline_type, query) in queries {
+            for row in sqlx::query(query).fetch_all(&self.pool).await? {
+                let deadline = UpcomingDeadline {
+                    deadline_type,
+                    record_id: row.get(0),
+                    case_number: row.get(1),
+                    maintainer_id: row.get(2),
+                    github_username: row.get(3),
+                    deadline: row.get(4),
+                };
+                let Some(kind) = ReminderKind::for_deadline(deadline.deadline, now, lead_days)
+                else {
+                    continue;
+                };
+                if self.was_sent(&deadline, &kind.level()).await? {
+                    continue;
+                }
+                due.push(DueReminder {
+                    deadline,
+                    kind,
+                    github_issue_number: row.get::<Option<i64>, _>(5).map(|n| n as u64),
+                });
+            }
+        }
+
+        due.sort_by_key(|reminder| reminder.deadline.deadline);
+        Ok(due)
+    }
+
+    /// Send due reminders as a GitHub issue comment and a Nostr event
+    ///
+    /// Overdue deadlines get an escalation message and an audit log entry
+    /// instead. A reminder is recorded as sent unless every configured
+    /// channel failed, in which case the next scan retries it.
+    pub async fn send_due_reminders(
+        &self,
+        lead_days: &[u32],
+    ) -> Result<DeadlineReminderResult, sqlx::Error> {
+        let mut result = DeadlineReminderResult::default();
+
+        for reminder in self.due_reminders(lead_days).await? {
+            let message = Self::reminder_message(&reminder);
+            let mut channels = 0;
+            let mut delivered = 0;
+
+            if let (Some(github), Some(issue_number)) =
+                (&self.github_integration, reminder.github_issue_number)
+            {
+                channels += 1;
+                match github.post_issue_comment(issue_number, &message).await {
+                    Ok(()) => delivered += 1,
+                    Err(e) => warn!(
+                        "Failed to post {} reminder to issue #{}: {}",
+                        reminder.kind.level(),
+                        issue_number,
+                        e
+                    ),
+                }
+            }
+            if let Some(publisher) = &self.publisher {
+                channels += 1;
+                match Self::publish_reminder(publisher, &reminder, &message).await {
+                    Ok(()) => delivered += 1,
+                    Err(e) => warn!(
+                        "Failed to publish {} reminder for case {}: {}",
+                        reminder.kind.level(),
+                        reminder.deadline.case_number,
+                        e
+                    ),
+                }
+            }
+            if channels > 0 && delivered == 0 {
+                continue;
+            }
+
+            if reminder.kind == ReminderKind::Overdue {
+                self.audit_escalation(&reminder).await;
+                result.escalations += 1;
+            } else {
+                result.reminders_sent += 1;
+            }
+            self.record_sent(&reminder.deadline, &reminder.kind.level())
+                .await?;
+            info!(
+                "Sent {} {} deadline notice for case {}",
+                reminder.kind.level(),
+                reminder.deadline.deadline_type.as_str(),
+                reminder.deadline.case_number
+            );
+        }
+
+        Ok(result)
+    }
+
+    /// Issue comment / Nostr text for a due reminder
+    fn reminder_message(reminder: &DueReminder) -> String {
+        let deadline = &reminder.deadline;
+        let mention = deadline
+            .github_username
+            .as_ref()
+            .map(|username| format!("@{} ", username))
+            .unwrap_or_default();
+        match reminder.kind {
+            ReminderKind::Lead(days) => format!(
+                r#"## ⏰ Deadline Reminder
+
+**Case:** {}
+**Deadline:** {} {} ({} remaining, reminder at {} days)
+
+{}please act before the deadline per governance review policy.
+
+---
+*Automated notification from Governance Review System*"#,
+                deadline.case_number,
+                deadline.deadline_type.as_str(),
+                deadline.deadline.format("%Y-%m-%d %H:%M:%S UTC"),
+                Self::format_remaining(deadline.deadline - Utc::now()),
+                days,
+                mention
+            ),
+            ReminderKind::Overdue => format!(
+                r#"## 🚨 Deadline Missed
+
+**Case:** {}
+**Deadline:** {} {} (overdue)
+
+{}this deadline has passed without action and has been escalated to the maintainers.
+
+---
+*Automated notification from Governance Review System*"#,
+                deadline.case_number,
+                deadline.deadline_type.as_str(),
+                deadline.deadline.format("%Y-%m-%d %H:%M:%S UTC"),
+                mention
+            ),
+        }
+    }
+
+    fn format_remaining(remaining: Duration) -> String {
+        match remaining.num_days() {
+            0 => format!("{} hours", remaining.num_hours().max(0)),
+            1 => "1 day".to_string(),
+            days => format!("{} days", days),
+        }
+    }
+
+    async fn publish_reminder(
+        publisher: &GovernanceActionPublisher,
+        reminder: &DueReminder,
+        message: &str,
+    ) -> anyhow::Result<()> {
+        let action = match reminder.kind {
+            ReminderKind::Lead(_) => "review_deadline_reminder",
+            ReminderKind::Overdue => "review_deadline_escalation",
+        };
+        publisher
+            .publish_action(
+                action,
+                0,
+                0,
+                "governance",
+                "",
+                0,
+                None,
+                None,
+                message,
+                LayerRequirement {
+                    layer: 0,
+                    signatures: String::new(),
+                    review_days: 0,
+                },
+                TierRequirement {
+                    tier: 0,
+                    signatures: String::new(),
+                    review_days: 0,
+                    economic_veto: false,
+                },
+                CombinedRequirement {
+                    signatures: String::new(),
+                    review_days: 0,
+                    economic_veto: false,
+                    source: "governance_review".to_string(),
+                },
+                Vec::new(),
+                EconomicVetoStatus::NotRequired,
+                Some(reminder.deadline.deadline),
+            )
+            .await
+    }
+
+    async fn audit_escalation(&self, reminder: &DueReminder) {
+        let Some(logger) = shared_logger() else {
+            return;
+        };
+        let deadline = &reminder.deadline;
+        if let Err(e) = logger
+            .log_action(
+                AuditCategory::GovernanceAction,
+                "deadline-reminders",
+                Some(&format!("case:{}", deadline.case_number)),
+                "review_deadline_escalated",
+                serde_json::json!({
+                    "deadline_type": deadline.deadline_type.as_str(),
+                    "record_id": deadline.record_id,
+                    "maintainer_id": deadline.maintainer_id,
+                    "deadline": deadline.deadline,
+                }),
+            )
+            .await
+        {
+            warn!(
+                "Failed to audit-log deadline escalation for case {}: {}",
+                deadline.case_number, e
+            );
+        }
+    }
+
+    /// DM maintainers about deadlines within `DM_LOOKAHEAD_HOURS`, with a
+    /// second, urgent DM inside `URGENT_HOURS`
+    ///
+    /// `maintainer_npubs` maps GitHub usernames to npubs; maintainers without
+    /// one are skipped. Failed sends are retried on the next call. Returns the
+    /// number of DMs sent.
+    pub async fn notify_upcoming_deadlines(
+        &self,
+        nostr_client: &NostrClient,
+        maintainer_npubs: &HashMap<String, String>,
+    ) -> Result<usize, sqlx::Error> {
+        let mut sent = 0;
+        for (deadline, level) in self
+            .pending_notifications(DM_LOOKAHEAD_HOURS, URGENT_HOURS)
+            .await?
+        {
+            let Some(npub) = deadline
+                .github_username
+                .as_ref()
+                .and_then(|username| maintainer_npubs.get(username))
+            else {
+                debug!(
+                    "No npub configured for maintainer {}, skipping {} deadline reminder for case {}",
+                    deadline.maintainer_id,
+                    deadline.deadline_type.as_str(),
+                    deadline.case_number
+                );
+                continue;
+            };
+
+            let mut tags = vec![Tag::Generic(
+                TagKind::Custom("t".into()),
+                vec!["governance-review".to_string()],
+            )];
+            if level == NotificationLevel::Urgent {
+                tags.push(Tag::Generic(
+                    TagKind::Custom("t".into()),
+                    vec!["urgent".to_string()],
+                ));
+            }
+
+            match nostr_client
+                .send_direct_message(npub, &Self::deadline_message(&deadline, level), tags)
+                .await
+            {
+                Ok(()) => {
+                    self.mark_notified(&deadline, level).await?;
+                    sent += 1;
+                    info!(
+                        "Sent {} {} deadline reminder for case {} to maintainer {}",
+                        level.as_str(),
+                        deadline.deadline_type.as_str(),
+                        deadline.case_number,
+                        deadline.maintainer_id
+                    );
+                }
+                Err(e) => warn!(
+                    "Failed to send {} deadline reminder for case {}: {}",
+                    deadline.deadline_type.as_str(),
+                    deadline.case_number,
+                    e
+                ),
+            }
+        }
+
+        Ok(sent)
+    }
+
+    /// Reminder text for a deadline
+    fn deadline_message(deadline: &UpcomingDeadline, level: NotificationLevel) -> String {
+        let hours_remaining = (deadline.deadline - Utc::now()).num_hours().max(0);
+        format!(
+            "{}Governance review {} deadline for case {}: {} ({} hours remaining)",
+            match level {
+                NotificationLevel::Urgent => "URGENT: ",
+                NotificationLevel::Upcoming => "",
+            },
+            deadline.deadline_type.as_str(),
+            deadline.case_number,
+            deadline.deadline.format("%Y-%m-%d %H:%M:%S UTC"),
+            hours_remaining
+        )
+    }
+
     /// Check for cases with approaching deadlines
     async fn check_case_deadlines(&self) -> Result<Vec<i32>, sqlx::Error> {
         let threshold = Utc::now() + Duration::days(NOTIFICATION_DAYS_BEFORE);
@@ -256,9 +898,156 @@ This case must be resolved within the deadline per governance review policy.
     }
 }
 
+/// Scan daily and send due deadline reminders and escalations
+pub fn spawn_reminder_task(
+    manager: Arc<DeadlineNotificationManager>,
+    lead_days: Vec<u32>,
+    shutdown: &Shutdown,
+) {
+    shutdown.spawn("deadline_reminders", |token| async move {
+        let mut interval = tokio::time::interval(REMINDER_SCAN_INTERVAL);
+        while shutdown::next_tick(&token, &mut interval).await {
+            match manager.send_due_reminders(&lead_days).await {
+                Ok(result) if result.reminders_sent + result.escalations > 0 => info!(
+                    "Sent {} deadline reminders and {} escalations",
+                    result.reminders_sent, result.escalations
+                ),
+                Ok(_) => {}
+                Err(e) => error!("Failed to send governance review deadline reminders: {}", e),
+            }
+        }
+    });
+}
+
+#[derive(Debug, Default)]
+pub struct DeadlineReminderResult {
+    pub reminders_sent: usize,
+    pub escalations: usize,
+}
+
 #[derive(Debug, Default)]
 pub struct DeadlineNotificationResult {
     pub cases_notified: usize,
     pub appeals_notified: usize,
     pub mediations_notified: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use crate::governance_review::case::GovernanceReviewCaseManager;
+
+    /// Pool with maintainers 1 and 2 and a case against maintainer 1 whose
+    /// response deadline is `response_in` from now
+    async fn setup_case(response_in: Duration) -> (SqlitePool, i32) {
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.get_sqlite_pool().unwrap();
+        for username in ["alice", "bob"] {
+            sqlx::query(
+                "INSERT INTO maintainers (github_username, public_key, layer, active, last_updated) VALUES (?, ?, 1, true, CURRENT_TIMESTAMP)",
+            )
+            .bind(username)
+            .bind(format!("{}-key", username))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        let case = GovernanceReviewCaseManager::new(pool.clone())
+            .create_case(
+                1,
+                2,
+                "harassment",
+                "minor",
+                "Hostile review comments",
+                serde_json::json!({}),
+                true,
+            )
+            .await
+            .unwrap();
+        sqlx::query("UPDATE governance_review_cases SET response_deadline = ? WHERE id = ?")
+            .bind(Utc::now() + response_in)
+            .bind(case.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        (pool, case.id)
+    }
+
+    #[test]
+    fn test_reminder_kind_for_deadline() {
+        let now = Utc::now();
+        let leads = DEFAULT_REMINDER_LEAD_DAYS;
+        assert_eq!(
+            ReminderKind::for_deadline(now + Duration::days(20), now, &leads),
+            None
+        );
+        assert_eq!(
+            ReminderKind::for_deadline(now + Duration::days(10), now, &leads),
+            Some(ReminderKind::Lead(14))
+        );
+        assert_eq!(
+            ReminderKind::for_deadline(now + Duration::hours(12), now, &leads),
+            Some(ReminderKind::Lead(1))
+        );
+        assert_eq!(
+            ReminderKind::for_deadline(now - Duration::hours(1), now, &leads),
+            Some(ReminderKind::Overdue)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_six_days_out_sends_seven_day_reminder_once() {
+        let (pool, case_id) = setup_case(Duration::days(6)).await;
+        let manager = DeadlineNotificationManager::new(pool, None);
+
+        let due = manager
+            .due_reminders(&DEFAULT_REMINDER_LEAD_DAYS)
+            .await
+            .unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].deadline.deadline_type, DeadlineType::Response);
+        assert_eq!(due[0].deadline.record_id, case_id);
+        assert_eq!(due[0].kind, ReminderKind::Lead(7));
+
+        let result = manager
+            .send_due_reminders(&DEFAULT_REMINDER_LEAD_DAYS)
+            .await
+            .unwrap();
+        assert_eq!(result.reminders_sent, 1);
+        assert_eq!(result.escalations, 0);
+
+        let result = manager
+            .send_due_reminders(&DEFAULT_REMINDER_LEAD_DAYS)
+            .await
+            .unwrap();
+        assert_eq!(result.reminders_sent, 0);
+    }
+
+    #[tokio::test]
+    async fn test_overdue_deadline_escalates() {
+        let (pool, _) = setup_case(Duration::days(-1)).await;
+        let manager = DeadlineNotificationManager::new(pool.clone(), None);
+
+        let result = manager
+            .send_due_reminders(&DEFAULT_REMINDER_LEAD_DAYS)
+            .await
+            .unwrap();
+        assert_eq!(result.escalations, 1);
+        assert_eq!(result.reminders_sent, 0);
+
+        let level: String = sqlx::query_scalar(
+            "SELECT level FROM deadline_notifications_sent WHERE deadline_type = 'response'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(level, "overdue");
+
+        let result = manager
+            .send_due_reminders(&DEFAULT_REMINDER_LEAD_DAYS)
+            .await
+            .unwrap();
+        assert_eq!(result.escalations, 0);
+    }
+}
@@ -0,0 +1,330 @@
+//! Governance review case lifecycle
+//!
+//! Typestate state machine for cases: `Case<S>` only exposes the transitions
+//! that are valid from state `S`, so an invalid transition (e.g. appealing an
+//! open case) does not compile. Each state is stored as its status string in
+//! `governance_review_cases.status`, and every transition is recorded in
+//! `case_transitions`.
+//!
+//! ```text
+//! Draft -> Open -> ResponsePending -> Mediation -> Closed -> Appealed -> Closed
+//!            |            |                          ^
+//!            +------------+-------> Mediation        |
+//!            +------------+--------------------------+
+//! ```
+
+use crate::governance_review::case::GovernanceReviewCaseManager;
+use crate::governance_review::models::{policy, CaseTransition, GovernanceReviewCase};
+use chrono::Utc;
+use sqlx::SqlitePool;
+use std::marker::PhantomData;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A case lifecycle state
+pub trait CaseState: sealed::Sealed {
+    /// Status string stored in `governance_review_cases.status`
+    const STATUS: &'static str;
+}
+
+/// States from which a case can be closed
+pub trait Closable: CaseState {}
+
+macro_rules! case_states {
+    ($($(#[$doc:meta])* $state:ident => $status:expr;)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $state;
+
+            impl sealed::Sealed for $state {}
+
+            impl CaseState for $state {
+                const STATUS: &'static str = $status;
+            }
+        )*
+    };
+}
+
+case_states! {
+    /// Not yet saved; opening the case persists it
+    Draft => policy::STATUS_DRAFT;
+    /// Reported and awaiting review
+    Open => policy::STATUS_OPEN;
+    /// Under review, waiting on the subject's response
+    ResponsePending => policy::STATUS_UNDER_REVIEW;
+    /// In mediation between the parties
+    Mediation => policy::STATUS_MEDIATION;
+    /// Resolved
+    Closed => policy::STATUS_RESOLVED;
+    /// Outcome under appeal
+    Appealed => policy::STATUS_APPEALED;
+}
+
+impl Closable for Open {}
+impl Closable for ResponsePending {}
+impl Closable for Mediation {}
+impl Closable for Appealed {}
+
+/// Governance review case in lifecycle state `S`
+#[derive(Debug)]
+pub struct Case<S: CaseState> {
+    pool: SqlitePool,
+    record: GovernanceReviewCase,
+    _state: PhantomData<S>,
+}
+
+impl<S: CaseState> Case<S> {
+    /// Current case record (for a draft, `id` and `case_number` are unassigned)
+    pub fn record(&self) -> &GovernanceReviewCase {
+        &self.record
+    }
+
+    pub fn id(&self) -> i32 {
+        self.record.id
+    }
+
+    /// Status string of this state
+    pub fn status(&self) -> &'static str {
+        S::STATUS
+    }
+
+    /// Load a case, failing unless it is currently in state `S`
+    pub async fn load(pool: SqlitePool, case_id: i32) -> Result<Self, sqlx::Error> {
+        let record = GovernanceReviewCaseManager::new(pool.clone())
+            .get_case_by_id(case_id)
+            .await?;
+        if record.status != S::STATUS {
+            return Err(sqlx::Error::Decode(
+                format!(
+                    "case {} is '{}', expected '{}'",
+                    case_id,
+                    record.status,
+                    S::STATUS
+                )
+                .into(),
+            ));
+        }
+
+        Ok(Self {
+            pool,
+            record,
+            _state: PhantomData,
+        })
+    }
+
+    /// Transition history of this case, oldest first
+    pub async fn transitions(&self) -> Result<Vec<CaseTransition>, sqlx::Error> {
+        get_transitions(&self.pool, self.record.id).await
+    }
+
+    /// Move the stored case from `S` to `T` and record the transition
+    async fn transition<T: CaseState>(
+        self,
+        transitioned_by: &str,
+        reason: &str,
+    ) -> Result<Case<T>, sqlx::Error> {
+        let closing = T::STATUS == Closed::STATUS;
+        let mut tx = self.pool.begin().await?;
+
+        // Guard on the old status so a concurrent transition can't be overwritten
+        let updated = sqlx::query(
+            r#"
+            UPDATE governance_review_cases
+            SET status = ?,
+                resolved_at = CASE WHEN ? THEN ? ELSE resolved_at END,
+                resolution_reason = CASE WHEN ? THEN ? ELSE resolution_reason END
+            WHERE id = ? AND status = ?
+            "#,
+        )
+        .bind(T::STATUS)
+        .bind(closing)
+        .bind(Utc::now())
+        .bind(closing)
+        .bind(reason)
+        .bind(self.record.id)
+        .bind(S::STATUS)
+        .execute(&mut *tx)
+        .await?;
+        if updated.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        insert_transition(
+            &mut *tx,
+            self.record.id,
+            S::STATUS,
+            T::STATUS,
+            transitioned_by,
+            reason,
+        )
+        .await?;
+        tx.commit().await?;
+
+        Case::<T>::load(self.pool, self.record.id).await
+    }
+}
+
+impl Case<Draft> {
+    /// Start a new, unsaved case
+    #[allow(clippy::too_many_arguments)]
+    pub fn draft(
+        pool: SqlitePool,
+        subject_maintainer_id: i32,
+        reporter_maintainer_id: i32,
+        case_type: &str,
+        severity: &str,
+        description: &str,
+        evidence: serde_json::Value,
+        on_platform: bool,
+    ) -> Self {
+        Self {
+            pool,
+            record: GovernanceReviewCase {
+                id: 0,
+                case_number: String::new(),
+                subject_maintainer_id,
+                reporter_maintainer_id,
+                case_type: case_type.to_string(),
+                severity: severity.to_string(),
+                status: Draft::STATUS.to_string(),
+                description: description.to_string(),
+                evidence,
+                on_platform,
+                created_at: Utc::now(),
+                response_deadline: None,
+                resolution_deadline: None,
+                resolved_at: None,
+                resolution_reason: None,
+                github_issue_number: None,
+            },
+            _state: PhantomData,
+        }
+    }
+
+    /// Persist the case and start its response and resolution deadlines
+    ///
+    /// Policy: off-platform cases are rejected.
+    pub async fn open(self, opened_by: &str, reason: &str) -> Result<Case<Open>, sqlx::Error> {
+        let record = self.record;
+        let case = GovernanceReviewCaseManager::new(self.pool.clone())
+            .create_case(
+                record.subject_maintainer_id,
+                record.reporter_maintainer_id,
+                &record.case_type,
+                &record.severity,
+                &record.description,
+                record.evidence,
+                record.on_platform,
+            )
+            .await?;
+
+        insert_transition(
+            &self.pool,
+            case.id,
+            Draft::STATUS,
+            Open::STATUS,
+            opened_by,
+            reason,
+        )
+        .await?;
+
+        Ok(Case {
+            pool: self.pool,
+            record: case,
+            _state: PhantomData,
+        })
+    }
+}
+
+impl Case<Open> {
+    /// Put the case under review and ask the subject to respond
+    pub async fn request_response(
+        self,
+        requested_by: &str,
+        reason: &str,
+    ) -> Result<Case<ResponsePending>, sqlx::Error> {
+        self.transition(requested_by, reason).await
+    }
+
+    pub async fn begin_mediation(
+        self,
+        started_by: &str,
+        reason: &str,
+    ) -> Result<Case<Mediation>, sqlx::Error> {
+        self.transition(started_by, reason).await
+    }
+}
+
+impl Case<ResponsePending> {
+    pub async fn begin_mediation(
+        self,
+        started_by: &str,
+        reason: &str,
+    ) -> Result<Case<Mediation>, sqlx::Error> {
+        self.transition(started_by, reason).await
+    }
+}
+
+impl<S: Closable> Case<S> {
+    /// Resolve the case; the reason becomes its resolution reason
+    pub async fn close(self, closed_by: &str, reason: &str) -> Result<Case<Closed>, sqlx::Error> {
+        self.transition(closed_by, reason).await
+    }
+}
+
+impl Case<Closed> {
+    pub async fn appeal(
+        self,
+        appealed_by: &str,
+        reason: &str,
+    ) -> Result<Case<Appealed>, sqlx::Error> {
+        self.transition(appealed_by, reason).await
+    }
+}
+
+async fn insert_transition(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    case_id: i32,
+    old_state: &str,
+    new_state: &str,
+    transitioned_by: &str,
+    reason: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO case_transitions (case_id, old_state, new_state, transitioned_by, reason, transitioned_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(case_id)
+    .bind(old_state)
+    .bind(new_state)
+    .bind(transitioned_by)
+    .bind(reason)
+    .bind(Utc::now())
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
+/// Transition history for a case, oldest first
+pub async fn get_transitions(
+    pool: &SqlitePool,
+    case_id: i32,
+) -> Result<Vec<CaseTransition>, sqlx::Error> {
+    sqlx::query_as::<_, CaseTransition>(
+        r#"
+        SELECT id, case_id, old_state, new_state, transitioned_by, reason, transitioned_at
+        FROM case_transitions
+        WHERE case_id = ?
+        ORDER BY id ASC
+        "#,
+    )
+    .bind(case_id)
+    .fetch_all(pool)
+    .await
+}
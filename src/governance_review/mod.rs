@@ -5,13 +5,17 @@
 //! - Time limits (180 days for cases, 90 days for appeals)
 //! - Protections (whistleblower, false reports, retaliation)
 //! - Conflict resolution/mediation
+//! - Typed case lifecycle with transition history
+//! - Public record of closed cases
 //! - On-platform only (off-platform activity disregarded)
 
+pub mod api;
 pub mod appeals;
 pub mod case;
 pub mod deadline_notifications;
 pub mod env;
 pub mod github_integration;
+pub mod lifecycle;
 pub mod mediation;
 pub mod models;
 pub mod protections;
@@ -22,9 +26,13 @@ pub mod time_limits;
 
 pub use appeals::AppealManager;
 pub use case::GovernanceReviewCaseManager;
-pub use deadline_notifications::DeadlineNotificationManager;
+pub use deadline_notifications::{
+    DeadlineNotificationManager, DeadlineType, DueReminder, NotificationLevel, ReminderKind,
+    UpcomingDeadline,
+};
 pub use env::{get_database_url, get_github_token, get_governance_repo, is_github_actions};
 pub use github_integration::GovernanceReviewGitHubIntegration;
+pub use lifecycle::{Case, CaseState};
 pub use mediation::MediationManager;
 pub use models::*;
 pub use protections::ProtectionManager;
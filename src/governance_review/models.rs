@@ -124,6 +124,51 @@ pub struct TimeLimit {
     pub extension_until: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CaseTransition {
+    pub id: i64,
+    pub case_id: i32,
+    pub old_state: String, // 'draft' for the transition that opened the case
+    pub new_state: String,
+    pub transitioned_by: String,
+    pub reason: Option<String>,
+    pub transitioned_at: DateTime<Utc>,
+}
+
+/// Anonymous whistleblower report; the encrypted identity is never included
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnonymousReport {
+    pub id: i32,
+    pub report_content: String,
+    pub escrowed_for_maintainers: Vec<String>,
+    pub escrow_threshold: i32,
+    pub status: String, // 'submitted', 'escalated'
+    pub created_at: DateTime<Utc>,
+    pub escalated_at: Option<DateTime<Utc>>,
+    pub escalated_by: Option<String>,
+}
+
+/// Public record of a closed case
+///
+/// Structured metadata is always shown. The remaining fields are replaced with
+/// "[redacted]" when named in the configured redact list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicCaseRecord {
+    pub case_id: i32,
+    pub case_number: String,
+    pub case_type: String,
+    pub sanction_level: String, // 'none', 'private_warning', 'public_warning', 'removal'
+    pub opened_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub status: String,
+    pub subject: serde_json::Value,         // Subject maintainer id
+    pub reporter: serde_json::Value,        // Reporter maintainer id
+    pub description: serde_json::Value,     // Report text
+    pub evidence: serde_json::Value,        // Reporter's evidence
+    pub responses: Vec<serde_json::Value>,  // Subject's responses, verbatim
+    pub outcome_summary: serde_json::Value, // Resolution reason
+}
+
 // Policy constants
 pub mod policy {
     use chrono::Duration;
@@ -143,6 +188,7 @@ pub mod policy {
     pub const REMOVAL_TEAM_THRESHOLD: i32 = 6; // 6-of-7 team
     pub const REMOVAL_TEAMS_THRESHOLD: i32 = 4; // 4-of-7 teams
     pub const APPEAL_OVERTURN_THRESHOLD: i32 = 5; // 5-of-7 teams
+    pub const ANONYMOUS_ESCROW_THRESHOLD: i32 = 3; // Escrow maintainers to reveal a reporter
 
     // Case types (from policy)
     pub const CASE_TYPES: &[&str] = &[
@@ -170,4 +216,19 @@ pub mod policy {
     pub const STATUS_RESOLVED: &str = "resolved";
     pub const STATUS_DISMISSED: &str = "dismissed";
     pub const STATUS_EXPIRED: &str = "expired";
+    pub const STATUS_APPEALED: &str = "appealed";
+    pub const STATUS_DRAFT: &str = "draft"; // Never stored; unsaved cases only
+
+    // Statuses whose cases appear in the public record
+    pub const PUBLIC_RECORD_STATUSES: &[&str] =
+        &[STATUS_RESOLVED, STATUS_DISMISSED, STATUS_REMOVED];
+
+    // Public record fields redacted unless configured otherwise
+    pub const PUBLIC_RECORD_REDACT_FIELDS: &[&str] =
+        &["reporter", "description", "evidence", "responses"];
+    pub const REDACTED: &str = "[redacted]";
+
+    // Anonymous report status values
+    pub const ANONYMOUS_STATUS_SUBMITTED: &str = "submitted";
+    pub const ANONYMOUS_STATUS_ESCALATED: &str = "escalated";
 }
@@ -3,19 +3,36 @@
 //! Implements policy protections:
 //! - Whistleblower protection (retaliation = immediate removal)
 //! - False report consequences
-//! - Privacy for reporters
+//! - Privacy for reporters (anonymous reports with identity escrow)
 
-use crate::governance_review::models::{FalseReport, Retaliation};
+use crate::config::GovernanceReviewConfig;
+use crate::governance_review::models::{policy, AnonymousReport, FalseReport, Retaliation};
 use chrono::{DateTime, Utc};
 use sqlx::{Row, SqlitePool};
 
 pub struct ProtectionManager {
     pool: SqlitePool,
+    escrow_threshold: i32,
 }
 
 impl ProtectionManager {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            escrow_threshold: policy::ANONYMOUS_ESCROW_THRESHOLD,
+        }
+    }
+
+    /// Manager using the configured anonymous report escrow threshold
+    pub fn from_config(pool: SqlitePool, config: &GovernanceReviewConfig) -> Self {
+        Self::new(pool).with_escrow_threshold(config.anonymous_escrow_threshold)
+    }
+
+    /// Override the number of escrow maintainers needed to reveal an anonymous
+    /// reporter (applies to reports submitted afterwards)
+    pub fn with_escrow_threshold(mut self, escrow_threshold: i32) -> Self {
+        self.escrow_threshold = escrow_threshold;
+        self
     }
 
     /// Report retaliation against a reporter
@@ -209,4 +226,201 @@ impl ProtectionManager {
             sanction_case_id: row.get(6),
         })
     }
+
+    /// Submit an anonymous report
+    ///
+    /// The identity blob is encrypted by the reporter and stored as-is; it is
+    /// only returned by `reveal_identity` once the report is escalated and
+    /// enough of `escrowed_for_maintainers` have requested it.
+    pub async fn create_anonymous_report(
+        &self,
+        encrypted_identity_blob: &[u8],
+        report_content: &str,
+        escrowed_for_maintainers: Vec<String>,
+    ) -> Result<AnonymousReport, sqlx::Error> {
+        let mut escrowed: Vec<String> = Vec::new();
+        for maintainer in escrowed_for_maintainers {
+            let maintainer = maintainer.trim().to_string();
+            if !maintainer.is_empty() && !escrowed.contains(&maintainer) {
+                escrowed.push(maintainer);
+            }
+        }
+
+        if encrypted_identity_blob.is_empty() {
+            return Err(policy_violation("encrypted identity is empty".to_string()));
+        }
+        if report_content.trim().is_empty() {
+            return Err(policy_violation("report content is empty".to_string()));
+        }
+        if self.escrow_threshold < 1 || (escrowed.len() as i32) < self.escrow_threshold {
+            return Err(policy_violation(format!(
+                "identity escrowed for {} maintainers, {} needed to reveal it",
+                escrowed.len(),
+                self.escrow_threshold
+            )));
+        }
+
+        let escrowed_json =
+            serde_json::to_string(&escrowed).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let report_id: i32 = sqlx::query_scalar::<_, i32>(
+            r#"
+            INSERT INTO anonymous_reports
+            (encrypted_identity_blob, report_content, escrowed_for_maintainers,
+             escrow_threshold, status, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+        )
+        .bind(encrypted_identity_blob)
+        .bind(report_content)
+        .bind(&escrowed_json)
+        .bind(self.escrow_threshold)
+        .bind(policy::ANONYMOUS_STATUS_SUBMITTED)
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await?;
+
+        self.get_anonymous_report(report_id).await
+    }
+
+    /// Get an anonymous report (without the encrypted identity)
+    pub async fn get_anonymous_report(
+        &self,
+        report_id: i32,
+    ) -> Result<AnonymousReport, sqlx::Error> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                id, report_content, escrowed_for_maintainers, escrow_threshold,
+                status, created_at, escalated_at, escalated_by
+            FROM anonymous_reports
+            WHERE id = ?
+            "#,
+        )
+        .bind(report_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(AnonymousReport {
+            id: row.get(0),
+            report_content: row.get(1),
+            escrowed_for_maintainers: serde_json::from_str(row.get::<String, _>(2).as_str())
+                .unwrap_or_default(),
+            escrow_threshold: row.get(3),
+            status: row.get(4),
+            created_at: row.get(5),
+            escalated_at: row.get(6),
+            escalated_by: row.get(7),
+        })
+    }
+
+    /// Escalate an anonymous report, allowing escrow maintainers to request
+    /// the reporter's identity
+    pub async fn escalate_anonymous_report(
+        &self,
+        report_id: i32,
+        escalated_by: &str,
+    ) -> Result<AnonymousReport, sqlx::Error> {
+        let updated = sqlx::query(
+            r#"
+            UPDATE anonymous_reports
+            SET status = ?, escalated_at = ?, escalated_by = ?
+            WHERE id = ? AND status = ?
+            "#,
+        )
+        .bind(policy::ANONYMOUS_STATUS_ESCALATED)
+        .bind(Utc::now())
+        .bind(escalated_by)
+        .bind(report_id)
+        .bind(policy::ANONYMOUS_STATUS_SUBMITTED)
+        .execute(&self.pool)
+        .await?;
+        if updated.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound);
+        }
+
+        self.get_anonymous_report(report_id).await
+    }
+
+    /// Request that an anonymous reporter's identity be revealed
+    ///
+    /// Policy: only escrow maintainers of an escalated report may request it,
+    /// and each counts once. Returns the number of unlock requests so far.
+    pub async fn escrow_unlock_request(
+        &self,
+        report_id: i32,
+        requesting_maintainer: &str,
+        reason: &str,
+    ) -> Result<i64, sqlx::Error> {
+        let report = self.get_anonymous_report(report_id).await?;
+        if report.status != policy::ANONYMOUS_STATUS_ESCALATED {
+            return Err(policy_violation(format!(
+                "anonymous report {} has not been escalated",
+                report_id
+            )));
+        }
+        if !report
+            .escrowed_for_maintainers
+            .iter()
+            .any(|maintainer| maintainer == requesting_maintainer)
+        {
+            return Err(policy_violation(format!(
+                "{} is not an escrow maintainer for anonymous report {}",
+                requesting_maintainer, report_id
+            )));
+        }
+        if reason.trim().is_empty() {
+            return Err(policy_violation("unlock reason is required".to_string()));
+        }
+
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO anonymous_report_unlock_requests
+            (report_id, requesting_maintainer, reason, requested_at)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(report_id)
+        .bind(requesting_maintainer)
+        .bind(reason)
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await?;
+
+        self.get_unlock_request_count(report_id).await
+    }
+
+    /// Number of distinct escrow maintainers who requested the identity
+    pub async fn get_unlock_request_count(&self, report_id: i32) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM anonymous_report_unlock_requests WHERE report_id = ?",
+        )
+        .bind(report_id)
+        .fetch_one(&self.pool)
+        .await
+    }
+
+    /// The encrypted reporter identity, or `None` until the report is escalated
+    /// and the escrow threshold is met
+    pub async fn reveal_identity(&self, report_id: i32) -> Result<Option<Vec<u8>>, sqlx::Error> {
+        let report = self.get_anonymous_report(report_id).await?;
+        if report.status != policy::ANONYMOUS_STATUS_ESCALATED
+            || self.get_unlock_request_count(report_id).await? < report.escrow_threshold as i64
+        {
+            return Ok(None);
+        }
+
+        let blob: Vec<u8> = sqlx::query_scalar(
+            "SELECT encrypted_identity_blob FROM anonymous_reports WHERE id = ?",
+        )
+        .bind(report_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(Some(blob))
+    }
+}
+
+fn policy_violation(message: String) -> sqlx::Error {
+    sqlx::Error::Decode(message.into())
 }
@@ -0,0 +1,298 @@
+//! Liveness and readiness probes
+//!
+//! `/health/live` only says the process is serving requests. `/health/ready`
+//! checks the dependencies a request needs and returns 503 with the failing
+//! ones, so a load balancer stops routing to an instance whose database pool
+//! has closed or whose Nostr relays are all gone. Readiness results are cached
+//! briefly so a burst of probes costs one round of checks.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+    routing::get,
+    Extension, Router,
+};
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+use crate::config::AppConfig;
+use crate::database::Database;
+
+/// How long a readiness result is reused
+pub const READINESS_CACHE_TTL: Duration = Duration::from_secs(5);
+
+/// Tables every governance request path relies on
+const GOVERNANCE_TABLES: [&str; 4] = [
+    "pull_requests",
+    "governance_events",
+    "unified_contributions",
+    "participation_weights",
+];
+
+/// Outcome of one dependency check
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CheckResult {
+    fn pass() -> Self {
+        Self {
+            ok: true,
+            error: None,
+        }
+    }
+
+    fn fail(error: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            error: Some(error.into()),
+        }
+    }
+}
+
+/// Readiness of every dependency, keyed by component name
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadinessReport {
+    pub ready: bool,
+    /// Names of the components whose check failed
+    pub failed: Vec<String>,
+    pub checks: BTreeMap<&'static str, CheckResult>,
+    pub checked_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Last readiness report, reused for `ttl`
+pub struct ReadinessCache {
+    ttl: Duration,
+    last: Mutex<Option<(Instant, ReadinessReport)>>,
+}
+
+impl ReadinessCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            last: Mutex::new(None),
+        }
+    }
+
+    /// The cached report if still fresh, otherwise a new one
+    ///
+    /// Concurrent probes wait on the lock rather than each running the checks.
+    pub async fn get(&self, config: &AppConfig, database: &Database) -> ReadinessReport {
+        let mut last = self.last.lock().await;
+        if let Some((checked, report)) = last.as_ref() {
+            if checked.elapsed() < self.ttl {
+                return report.clone();
+            }
+        }
+        let report = check_readiness(config, database).await;
+        *last = Some((Instant::now(), report.clone()));
+        report
+    }
+}
+
+async fn check_database(database: &Database) -> CheckResult {
+    match database.check_health().await {
+        Ok(true) => CheckResult::pass(),
+        Ok(false) => CheckResult::fail("health query failed"),
+        Err(e) => CheckResult::fail(e.to_string()),
+    }
+}
+
+async fn check_governance_tables(database: &Database) -> CheckResult {
+    let Some(pool) = database.get_sqlite_pool() else {
+        return CheckResult::pass();
+    };
+    let existing: Result<Vec<String>, _> = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name IN (?, ?, ?, ?)",
+    )
+    .bind(GOVERNANCE_TABLES[0])
+    .bind(GOVERNANCE_TABLES[1])
+    .bind(GOVERNANCE_TABLES[2])
+    .bind(GOVERNANCE_TABLES[3])
+    .fetch_all(&pool)
+    .await;
+    match existing {
+        Ok(existing) => {
+            let missing: Vec<&str> = GOVERNANCE_TABLES
+                .iter()
+                .copied()
+                .filter(|table| !existing.iter().any(|name| name == table))
+                .collect();
+            if missing.is_empty() {
+                CheckResult::pass()
+            } else {
+                CheckResult::fail(format!("missing tables: {}", missing.join(", ")))
+            }
+        }
+        Err(e) => CheckResult::fail(e.to_string()),
+    }
+}
+
+async fn check_nostr() -> CheckResult {
+    let Some(client) = crate::nostr::client::shared_client() else {
+        return CheckResult::fail("Nostr is enabled but no client is running");
+    };
+    if client
+        .relay_health()
+        .await
+        .iter()
+        .any(|relay| relay.connected)
+    {
+        CheckResult::pass()
+    } else {
+        CheckResult::fail("no connected relays")
+    }
+}
+
+/// Run every dependency check now
+pub async fn check_readiness(config: &AppConfig, database: &Database) -> ReadinessReport {
+    let mut checks = BTreeMap::new();
+    checks.insert("database", check_database(database).await);
+    checks.insert("governance_tables", check_governance_tables(database).await);
+    if config.nostr.enabled {
+        checks.insert("nostr", check_nostr().await);
+    }
+    checks.insert(
+        "internal_api_key",
+        if config.internal_api_key.is_some() {
+            CheckResult::pass()
+        } else {
+            CheckResult::fail("internal_api_key is not configured")
+        },
+    );
+
+    let failed: Vec<String> = checks
+        .iter()
+        .filter(|(_, result)| !result.ok)
+        .map(|(name, _)| name.to_string())
+        .collect();
+    ReadinessReport {
+        ready: failed.is_empty(),
+        failed,
+        checks,
+        checked_at: chrono::Utc::now(),
+    }
+}
+
+/// Liveness: the process is up and serving requests
+pub async fn live() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "alive",
+        "service": "blvm-commons",
+        "timestamp": chrono::Utc::now()
+    }))
+}
+
+/// Readiness: 200 when every dependency passes, otherwise 503 listing the failures
+pub async fn ready(
+    State((config, database)): State<(AppConfig, Database)>,
+    Extension(cache): Extension<Arc<ReadinessCache>>,
+) -> Response {
+    let report = cache.get(&config, &database).await;
+    let status = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report)).into_response()
+}
+
+/// Create router for the probe endpoints (unauthenticated, like /health)
+pub fn create_router(cache: Arc<ReadinessCache>) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/health/live", get(live))
+        .route("/health/ready", get(ready))
+        .layer(Extension(cache))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn config() -> AppConfig {
+        AppConfig {
+            internal_api_key: Some("secret".to_string()),
+            ..Default::default()
+        }
+    }
+
+    async fn probe(
+        cache: &Arc<ReadinessCache>,
+        database: &Database,
+    ) -> (StatusCode, ReadinessBody) {
+        let response = create_router(cache.clone())
+            .with_state((config(), database.clone()))
+            .oneshot(Request::get("/health/ready").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[derive(serde::Deserialize)]
+    struct ReadinessBody {
+        ready: bool,
+        failed: Vec<String>,
+    }
+
+    #[tokio::test]
+    async fn test_readiness_fails_on_closed_pool_and_recovers() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!(
+            "sqlite://{}?mode=rwc",
+            dir.path().join("ready.db").display()
+        );
+        let database = Database::new(&url).await.unwrap();
+        database.run_migrations().await.unwrap();
+        let health_task = database.clone();
+        let cache = Arc::new(ReadinessCache::new(Duration::ZERO));
+
+        let (status, body) = probe(&cache, &database).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.ready);
+
+        database.close().await;
+        let (status, body) = probe(&cache, &database).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert!(!body.ready);
+        assert!(body.failed.contains(&"database".to_string()));
+
+        health_task.reconnect().await.unwrap();
+        let (status, body) = probe(&cache, &database).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.failed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_readiness_lists_missing_api_key() {
+        let database = Database::new_in_memory().await.unwrap();
+        let report = check_readiness(&AppConfig::default(), &database).await;
+        assert!(!report.ready);
+        assert_eq!(report.failed, vec!["internal_api_key".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_is_cached() {
+        let database = Database::new_in_memory().await.unwrap();
+        let cache = ReadinessCache::new(Duration::from_secs(60));
+        let first = cache.get(&config(), &database).await;
+        database.close().await;
+        let second = cache.get(&config(), &database).await;
+        assert!(first.ready);
+        assert_eq!(first.checked_at, second.checked_at);
+    }
+}
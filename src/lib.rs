@@ -1,3 +1,4 @@
+pub mod api_auth;
 pub mod audit;
 pub mod backup;
 pub mod build;
@@ -6,14 +7,18 @@ pub mod crypto;
 pub mod database;
 pub mod enforcement;
 pub mod error;
+pub mod execution_mode;
 pub mod fork;
 pub mod github;
 pub mod governance;
 pub mod governance_review;
+pub mod health;
+pub mod metrics;
 pub mod node_registry;
 pub mod nostr;
 pub mod resilience;
 pub mod services;
+pub mod shutdown;
 pub mod validation;
 pub mod webhooks;
 
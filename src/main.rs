@@ -6,6 +6,7 @@ use axum::{
 };
 use chrono::Datelike;
 use std::net::SocketAddr;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::time::Duration;
 use tower::ServiceBuilder;
@@ -13,6 +14,7 @@ use tower_http::trace::TraceLayer;
 use tracing::{debug, error, info, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+mod api_auth;
 mod audit;
 mod authorization;
 mod backup;
@@ -22,24 +24,34 @@ mod crypto;
 mod database;
 mod enforcement;
 mod error;
+mod execution_mode;
 mod github;
 mod governance;
 mod governance_review;
+mod health;
+mod metrics;
 mod node_registry;
 mod nostr;
 #[cfg(feature = "opentimestamps")]
 mod ots;
 mod resilience;
+mod services;
+mod shutdown;
 mod validation;
 mod webhooks;
 
 use audit::AuditLogger;
+use config::loader::GovernanceConfigFiles;
 use config::AppConfig;
+use database::reconnect::ReconnectBackoff;
 use database::Database;
-use governance::ContributionAggregator;
+use governance::{
+    AnomalyStatus, ContributionAggregator, DecayConfig, GovernancePhaseCalculator, PhaseHysteresis,
+};
+use node_registry::NodeRegistry;
 use nostr::{NostrClient, StatusPublisher, ZapTracker};
 #[cfg(feature = "opentimestamps")]
-use ots::{OtsClient, RegistryAnchorer};
+use ots::{AuditAnchorer, OtsClient, RegistryAnchorer};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -56,16 +68,57 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Load configuration
     let config = AppConfig::load()?;
+    // Read the PR title rules file now rather than on the first webhook
+    config.governance.pr_title_rules();
     info!("Configuration loaded");
 
+    // Background tasks stop through this on SIGTERM/SIGINT
+    let shutdown = shutdown::Shutdown::new();
+    shutdown::set_shared(shutdown.clone());
+
     // Initialize database
-    let database = Database::new(&config.database_url).await?;
+    let mut database = Database::new(&config.database_url).await?;
     info!("Database connected");
 
     // Run migrations
     database.run_migrations().await?;
     info!("Database migrations completed");
 
+    database
+        .configure_wal(config.database.wal_autocheckpoint_pages)
+        .await?;
+    database.set_slow_query_threshold(config.database.slow_query_threshold_ms);
+    database::slow_query::set_shared_log(database.slow_query_log());
+    if let Some(replica_url) = &config.database.read_replica_url {
+        database = database.with_read_replica(replica_url).await?;
+        info!("Read replica connected");
+
+        let database_for_lag = database.clone();
+        let max_lag_pages = config.database.replica_lag_warn_pages;
+        shutdown.spawn("replica_lag", |token| async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(60));
+            while shutdown::next_tick(&token, &mut interval).await {
+                if let Err(e) = database_for_lag.check_replica_lag(max_lag_pages).await {
+                    warn!("Failed to check read replica lag: {}", e);
+                }
+            }
+        });
+    }
+    if config.database.wal_checkpoint_interval_minutes > 0 {
+        let database_for_checkpoint = database.clone();
+        let checkpoint_interval =
+            Duration::from_secs(config.database.wal_checkpoint_interval_minutes * 60);
+        shutdown.spawn("wal_checkpoint", |token| async move {
+            let mut interval = tokio::time::interval(checkpoint_interval);
+            interval.tick().await; // Skip the immediate first tick
+            while shutdown::next_tick(&token, &mut interval).await {
+                if let Err(e) = database_for_checkpoint.run_wal_checkpoint().await {
+                    warn!("WAL checkpoint failed: {}", e);
+                }
+            }
+        });
+    }
+
     // Start automated backup task
     let database_for_backup = database.clone();
     let backup_config = backup::BackupConfig {
@@ -75,26 +128,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         interval: std::time::Duration::from_secs(86400), // Daily
         enabled: true,
     };
-    let backup_manager = Arc::new(backup::BackupManager::new(
-        database_for_backup,
-        backup_config,
-    ));
-    backup_manager.clone().start_backup_task();
+    let backup_directory = backup_config.directory.clone();
+    let backup_manager = Arc::new(
+        backup::BackupManager::new(database_for_backup, backup_config)
+            .with_execution_mode(config.execution_mode())
+            .with_shutdown(shutdown.token()),
+    );
+    backup_manager.clone().start_backup_task(&shutdown);
     info!("Automated backup task started");
 
     // Start database health monitoring task with reconnection capability
     let database_for_health = database.clone();
-    let database_url_for_reconnect = config.database_url.clone();
-    tokio::spawn(async move {
+    let max_reconnect_attempts = config.database.max_reconnect_attempts;
+    let health_shutdown = shutdown.clone();
+    shutdown.spawn("database_health", |token| async move {
         let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(60)); // Check every 60 seconds
         let mut consecutive_failures = 0u32;
-        let mut current_db = database_for_health;
+        let mut backoff = ReconnectBackoff::default();
 
-        loop {
-            interval.tick().await;
+        while shutdown::next_tick(&token, &mut interval).await {
 
             // Check database health
-            match current_db.check_health().await {
+            match database_for_health.check_health().await {
                 Ok(true) => {
                     if consecutive_failures > 0 {
                         info!(
@@ -106,7 +161,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                     // Log pool stats periodically (every 10 checks = 10 minutes)
                     if consecutive_failures == 0 {
-                        if let Ok(stats) = current_db.get_pool_stats().await {
+                        if let Ok(stats) = database_for_health.get_pool_stats().await {
                             debug!(
                                 "Database pool stats: size={}, idle={}, closed={}",
                                 stats.size, stats.idle, stats.is_closed
@@ -121,29 +176,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         consecutive_failures
                     );
 
-                    // After 3 consecutive failures, attempt reconnection
-                    if consecutive_failures >= 3 {
-                        error!("Database connection unhealthy after {} consecutive failures - attempting reconnection", consecutive_failures);
-
-                        // Check if pool is closed before attempting reconnection
-                        let should_reconnect = current_db
-                            .get_pool_stats()
-                            .await
-                            .map(|stats| stats.is_closed)
-                            .unwrap_or(true);
-
-                        if should_reconnect {
-                            // Attempt to reconnect using stored database URL
-                            match Database::new(&database_url_for_reconnect).await {
-                                Ok(new_db) => {
-                                    info!("Database reconnection successful");
-                                    current_db = new_db;
-                                    consecutive_failures = 0;
-                                }
-                                Err(e) => {
-                                    error!("Database reconnection failed: {} - will retry on next health check", e);
-                                }
-                            }
+                    // After 3 consecutive failures, reconnect with exponential backoff
+                    if consecutive_failures < 3 {
+                        continue;
+                    }
+                    error!("Database connection unhealthy after {} consecutive failures - attempting reconnection", consecutive_failures);
+                    // Swaps the pool behind every clone, including the router's
+                    match database_for_health
+                        .reconnect_with_backoff(&mut backoff, max_reconnect_attempts, &token)
+                        .await
+                    {
+                        Ok(true) => {
+                            info!("Database reconnection successful");
+                            consecutive_failures = 0;
+                        }
+                        Ok(false) => return,
+                        Err(e) => {
+                            error!(
+                                "Database reconnection failed {} times, shutting down: {}",
+                                database_for_health.reconnect_status().reconnect_attempts,
+                                e
+                            );
+                            health_shutdown.trigger();
+                            return;
                         }
                     }
                 }
@@ -153,7 +208,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Initialize audit logger
     let audit_logger = if config.audit.enabled {
-        Some(AuditLogger::new(config.audit.log_path.clone())?)
+        let logger = AuditLogger::new(config.audit.log_path.clone())?
+            .with_server_id(config.server_id.clone())
+            .with_max_log_size_mb(config.audit.max_log_size_mb);
+        // Index entries for /internal/audit queries; the file stays canonical
+        Some(match &database.get_sqlite_pool() {
+            Some(pool) => logger.with_index(audit::AuditIndex::new(pool.clone())),
+            None => logger,
+        })
     } else {
         None
     };
@@ -166,28 +228,50 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         let client = NostrClient::new(nsec, config.nostr.relays.clone())
             .await
-            .map_err(|e| format!("Failed to create Nostr client: {}", e))?;
+            .map_err(|e| format!("Failed to create Nostr client: {}", e))?
+            .with_relay_circuit_breaker(
+                config.nostr.relay_failure_threshold,
+                Duration::from_secs(config.nostr.relay_reset_timeout_seconds),
+            )
+            .with_execution_mode(config.execution_mode());
+
+        // Keep relays connected and expose them to /status and the relay API
+        client.spawn_relay_monitor(nostr::client::RELAY_RECONNECT_INTERVAL, &shutdown);
+        nostr::client::set_shared_client(client.clone());
 
         Some(client)
     } else {
         None
     };
 
+    // Sign audit entries with the server's Nostr key when it is loaded
+    let audit_logger = match (audit_logger, &nostr_client) {
+        (Some(logger), Some(client)) => Some(logger.with_signing_keys(&client.keys)?),
+        (logger, _) => logger,
+    };
+    if let Some(ref logger) = audit_logger {
+        audit::set_shared_logger(logger.clone());
+    }
+
     let status_publisher = if let Some(ref client) = nostr_client {
-        Some(StatusPublisher::new(
-            client.clone(),
-            database.clone(),
-            config.server_id.clone(),
-            std::env::current_exe()
-                .map(|p| p.to_string_lossy().to_string())
-                .unwrap_or_else(|_| "blvm-commons".to_string()),
-            "config.toml".to_string(),
-            if config.audit.enabled {
-                Some(config.audit.log_path.clone())
-            } else {
-                None
-            },
-        ))
+        Some(
+            StatusPublisher::new(
+                client.clone(),
+                database.clone(),
+                config.server_id.clone(),
+                std::env::current_exe()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|_| "blvm-commons".to_string()),
+                "config.toml".to_string(),
+                if config.audit.enabled {
+                    Some(config.audit.log_path.clone())
+                } else {
+                    None
+                },
+            )
+            .with_min_quorum(config.nostr.publish_min_quorum)
+            .with_backup_dir(backup_directory),
+        )
     } else {
         None
     };
@@ -204,18 +288,49 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     #[cfg(feature = "opentimestamps")]
     let registry_anchorer = if let Some(client) = ots_client {
-        Some(RegistryAnchorer::new(
+        let mut anchorer = RegistryAnchorer::new(
             client,
             database.clone(),
             config.ots.registry_path.clone(),
             config.ots.proofs_path.clone(),
-        ))
+        )
+        .with_execution_mode(config.execution_mode());
+        // Node registry exports are signed with a dedicated key if configured,
+        // otherwise with the server's Nostr key
+        let signing_keys = match &config.ots.registry_signing_key_path {
+            Some(path) => {
+                use nostr_sdk::prelude::FromSkStr;
+                let nsec = std::fs::read_to_string(path)
+                    .map_err(|e| format!("Failed to read registry signing key: {}", e))?;
+                Some(
+                    nostr_sdk::Keys::from_sk_str(nsec.trim())
+                        .map_err(|e| format!("Invalid registry signing key: {}", e))?,
+                )
+            }
+            None => nostr_client.as_ref().map(|client| client.keys.clone()),
+        };
+        if let Some(keys) = signing_keys {
+            anchorer = anchorer.with_signing_keys(keys);
+        }
+        Some(anchorer)
     } else {
         None
     };
     #[cfg(not(feature = "opentimestamps"))]
     let registry_anchorer: Option<()> = None;
 
+    // Audit log head anchoring requires both OTS and the audit logger
+    #[cfg(feature = "opentimestamps")]
+    let audit_anchorer = match (&audit_logger, config.ots.enabled) {
+        (Some(logger), true) => Some(AuditAnchorer::new(
+            Box::new(OtsClient::new(config.ots.aggregator_url.clone())),
+            database.clone(),
+            logger.clone(),
+            config.ots.proofs_path.clone(),
+        )),
+        _ => None,
+    };
+
     // Start background tasks
     let config_clone = config.clone();
     let database_clone = database.clone();
@@ -224,10 +339,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Nostr status publisher task
     if let Some(publisher) = status_publisher {
         let publish_interval = Duration::from_secs(config.nostr.publish_interval_secs);
-        tokio::spawn(async move {
+        shutdown.spawn("nostr_status", |token| async move {
             let mut interval = tokio::time::interval(publish_interval);
-            loop {
-                interval.tick().await;
+            while shutdown::next_tick(&token, &mut interval).await {
                 if let Err(e) = publisher.publish_status().await {
                     error!("Failed to publish Nostr status: {}", e);
                 }
@@ -239,14 +353,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // OTS monthly anchoring task (only if feature enabled)
     #[cfg(feature = "opentimestamps")]
     if let Some(anchorer) = registry_anchorer {
-        tokio::spawn(async move {
+        let anchorer = Arc::new(anchorer);
+        shutdown.spawn("ots_registry_anchor", |token| async move {
             let mut interval = tokio::time::interval(Duration::from_secs(86400)); // Check daily
-            loop {
-                interval.tick().await;
+            while shutdown::next_tick(&token, &mut interval).await {
+                let started = std::time::Instant::now();
+                let upgraded = anchorer.upgrade_pending_event_proofs().await;
+                metrics::record_task_run("ots_proof_upgrade", started.elapsed(), upgraded.is_ok());
+                if let Err(e) = upgraded {
+                    error!("Failed to upgrade event proofs: {}", e);
+                }
                 let now = chrono::Utc::now();
                 if now.day() == config_clone.ots.monthly_anchor_day as u32 {
-                    if let Err(e) = anchorer.anchor_registry().await {
-                        error!("Failed to anchor registry: {}", e);
+                    let started = std::time::Instant::now();
+                    let anchored = anchorer.anchor_registry().await;
+                    metrics::record_task_run(
+                        "ots_registry_anchor",
+                        started.elapsed(),
+                        anchored.is_ok(),
+                    );
+                    match anchored {
+                        Ok(registry_id) => anchorer.schedule_verification(registry_id),
+                        Err(e) => error!("Failed to anchor registry: {}", e),
                     }
                 }
             }
@@ -254,14 +382,84 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         info!("OTS registry anchorer started");
     }
 
+    // OTS audit log anchoring task: monthly, plus after Tier 4/5 governance actions
+    #[cfg(feature = "opentimestamps")]
+    if let Some(anchorer) = audit_anchorer {
+        let monthly_anchor_day = config.ots.monthly_anchor_day as u32;
+        shutdown.spawn("ots_audit_anchor", |token| async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(300)); // Check every 5 minutes
+            let mut last_monthly_anchor: Option<chrono::NaiveDate> = None;
+            while shutdown::next_tick(&token, &mut interval).await {
+                let today = chrono::Utc::now().date_naive();
+                if today.day() == monthly_anchor_day && last_monthly_anchor != Some(today) {
+                    match anchorer.anchor_head("monthly").await {
+                        Ok(_) => last_monthly_anchor = Some(today),
+                        Err(e) => error!("Failed to anchor audit log head: {}", e),
+                    }
+                } else if let Err(e) = anchorer.anchor_if_tier_action().await {
+                    error!(
+                        "Failed to anchor audit log head after governance action: {}",
+                        e
+                    );
+                }
+
+                if let Err(e) = anchorer.upgrade_pending_proofs().await {
+                    error!("Failed to upgrade pending audit log proofs: {}", e);
+                }
+            }
+        });
+        info!("OTS audit log anchorer started");
+    }
+
+    // Emergency expiry task; activations and expiries are published when Nostr is enabled
+    let emergency_manager = match &nostr_client {
+        Some(client) => enforcement::emergency_manager::EmergencyManager::new(database.clone())
+            .with_publisher(Arc::new(
+                nostr::GovernanceActionPublisher::new(
+                    client.clone(),
+                    config.nostr.governance_config.clone(),
+                    config.nostr.zap_address.clone(),
+                )
+                .with_min_quorum(config.nostr.publish_min_quorum)
+                .with_execution_mode(config.execution_mode()),
+            )),
+        None => enforcement::emergency_manager::EmergencyManager::new(database.clone()),
+    };
+    let emergency_manager = Arc::new(emergency_manager);
+    enforcement::emergency_manager::spawn_expiry_task(emergency_manager.clone(), &shutdown);
+    info!("Emergency expiry task started");
+
+    // Time-locked governance changes; activation is refused until the lock ends
+    // unless an emergency is active
+    governance::time_lock::migrate_time_lock_tables(&database).await?;
+    let mut time_lock_manager = governance::time_lock::TimeLockManager::new(
+        database.clone(),
+        config.governance.time_lock.clone(),
+    )
+    .with_emergency_manager(emergency_manager.clone());
+    if let Some(client) = &nostr_client {
+        time_lock_manager = time_lock_manager.with_publisher(Arc::new(
+            nostr::GovernanceActionPublisher::new(
+                client.clone(),
+                config.nostr.governance_config.clone(),
+                config.nostr.zap_address.clone(),
+            )
+            .with_min_quorum(config.nostr.publish_min_quorum)
+            .with_execution_mode(config.execution_mode()),
+        ));
+    }
+    governance::time_lock::spawn_auto_activation_task(Arc::new(time_lock_manager), &shutdown);
+    if config.governance.time_lock.auto_activate {
+        info!("Time lock auto-activation task started");
+    }
+
     // Audit log rotation task
     if audit_logger.is_some() {
         let rotation_interval =
             Duration::from_secs(config.audit.rotation_interval_days as u64 * 86400);
-        tokio::spawn(async move {
+        shutdown.spawn("audit_rotation", |token| async move {
             let mut interval = tokio::time::interval(rotation_interval);
-            loop {
-                interval.tick().await;
+            while shutdown::next_tick(&token, &mut interval).await {
                 // Rotate audit log (implement rotation logic)
                 info!("Audit log rotation triggered");
             }
@@ -270,7 +468,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Initialize governance services
-    let pool = database
+    let pool = &database
         .get_sqlite_pool()
         .ok_or_else(|| "Database pool not available".to_string())?;
 
@@ -299,12 +497,52 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
 
             if !bot_pubkeys.is_empty() {
-                let zap_tracker =
+                let mut zap_tracker =
                     ZapTracker::new(pool.clone(), Arc::new(nostr_client.clone()), bot_pubkeys);
-                if let Err(e) = zap_tracker.start_tracking().await {
+                if let Some(lightning_config) = &config.lightning_node {
+                    zap_tracker = zap_tracker.with_payment_verifier(Arc::new(
+                        services::LightningNodeClient::new(lightning_config),
+                    ));
+                } else {
+                    warn!("No Lightning node configured - zaps will be recorded unverified");
+                }
+
+                if let Err(e) = zap_tracker.start_tracking(&shutdown).await {
                     error!("Failed to start zap tracking: {}", e);
                 } else {
                     info!("Zap tracker started");
+                    let zap_tracker = Arc::new(zap_tracker);
+
+                    // Periodically re-query recent receipts that reached relays late
+                    if config.nostr.zap_reconcile_interval_hours > 0 {
+                        let zap_tracker = zap_tracker.clone();
+                        let reconcile_interval =
+                            Duration::from_secs(config.nostr.zap_reconcile_interval_hours * 3600);
+                        let reconcile_window =
+                            chrono::Duration::hours(config.nostr.zap_reconcile_window_hours as i64);
+                        shutdown.spawn("zap_reconcile", |token| async move {
+                            let mut interval = tokio::time::interval(reconcile_interval);
+                            // Start tracking already backfilled; skip the immediate tick
+                            interval.tick().await;
+                            while shutdown::next_tick(&token, &mut interval).await {
+                                if let Err(e) = zap_tracker.reconcile(reconcile_window).await {
+                                    warn!("Zap reconciliation failed: {}", e);
+                                }
+                            }
+                        });
+                    }
+
+                    // Periodically re-check zaps whose payment could not be verified yet
+                    if config.lightning_node.is_some() {
+                        shutdown.spawn("zap_verification", |token| async move {
+                            let mut interval = tokio::time::interval(Duration::from_secs(600));
+                            while shutdown::next_tick(&token, &mut interval).await {
+                                if let Err(e) = zap_tracker.verify_pending_zaps().await {
+                                    warn!("Failed to verify pending zaps: {}", e);
+                                }
+                            }
+                        });
+                    }
                 }
             }
         }
@@ -312,22 +550,94 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Fee forwarding removed - no longer tracked
 
+    // Start BTC price refresh task (if price sources are configured)
+    if let Some(price_config) = &config.btc_price {
+        let price_service = Arc::new(services::BtcPriceService::new(pool.clone(), price_config));
+        let refresh_interval = Duration::from_secs(price_config.refresh_interval_secs);
+        shutdown.spawn("btc_price_refresh", |token| async move {
+            let mut interval = tokio::time::interval(refresh_interval);
+            while shutdown::next_tick(&token, &mut interval).await {
+                if let Err(e) = price_service.refresh().await {
+                    warn!("Failed to refresh BTC price: {}", e);
+                }
+            }
+        });
+        info!(
+            "BTC price refresh task started ({} sources, interval: {}s)",
+            price_config.sources.len(),
+            price_config.refresh_interval_secs
+        );
+    }
+
     // Start periodic weight update task (if enabled)
     if config.governance.weight_updates_enabled {
         let pool_for_weights = pool.clone();
         let update_interval = Duration::from_secs(config.governance.weight_update_interval_secs);
-        tokio::spawn(async move {
+        // Decay settings come from the governance weight calculation config;
+        // a non-zero half-life in the server config overrides its half-life
+        let mut node_decay =
+            match GovernanceConfigFiles::load_from_directory(Path::new("governance/config")) {
+                Ok(files) => files.commons_contributor_thresholds.map(|thresholds| {
+                    DecayConfig::from_weight_calculation(&thresholds.weight_calculation)
+                }),
+                Err(e) => {
+                    warn!(
+                        "Failed to load governance config for node weight decay: {}",
+                        e
+                    );
+                    None
+                }
+            }
+            .unwrap_or(DecayConfig {
+                half_life_days: 0.0,
+                minimum_weight: 0.0,
+                grace_period_days: 0.0,
+            });
+        if config.governance.node_weight_decay_half_life_days > 0.0 {
+            node_decay.half_life_days = config.governance.node_weight_decay_half_life_days;
+        }
+        let anomaly_threshold = config.governance.contribution_anomaly_threshold;
+        let aggregator = ContributionAggregator::new(pool_for_weights.clone())
+            .with_anomaly_weight_multiplier(
+                config.governance.contribution_anomaly_weight_multiplier,
+            );
+        shutdown.spawn("weight_update", |token| async move {
             let mut interval = tokio::time::interval(update_interval);
-            loop {
-                interval.tick().await;
+            // Ticks missed during a slow run are skipped, not run back to back
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            while shutdown::next_tick(&token, &mut interval).await {
                 info!("Starting periodic weight update");
 
-                let aggregator = ContributionAggregator::new(pool_for_weights.clone());
-                if let Err(e) = aggregator.update_all_weights().await {
+                // Flag inflated contributions before they reach the weights
+                if let Err(e) = aggregator.detect_anomalies(anomaly_threshold).await {
+                    error!("Failed to detect contribution anomalies: {}", e);
+                }
+
+                let started = std::time::Instant::now();
+                let updated = aggregator.update_all_weights().await;
+                metrics::record_task_run("weight_update", started.elapsed(), updated.is_ok());
+                metrics::observe(
+                    metrics::WEIGHT_UPDATE_DURATION,
+                    &[],
+                    started.elapsed().as_secs_f64(),
+                );
+                if let Err(e) = updated {
                     error!("Failed to update participation weights: {}", e);
                 } else {
                     info!("Periodic weight update completed");
                 }
+
+                let registry = NodeRegistry::new(pool_for_weights.clone());
+                let started = std::time::Instant::now();
+                let recalculated = registry.recalculate_all_weights(&node_decay).await;
+                metrics::record_task_run(
+                    "node_weight_recalculation",
+                    started.elapsed(),
+                    recalculated.is_ok(),
+                );
+                if let Err(e) = recalculated {
+                    error!("Failed to recalculate node weights: {}", e);
+                }
             }
         });
         info!(
@@ -336,18 +646,190 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         );
     }
 
+    // Start periodic governance phase evaluation task
+    {
+        let hysteresis = PhaseHysteresis {
+            min_consecutive_evaluations: config.governance.phase_hysteresis_evaluations,
+            min_days: config.governance.phase_hysteresis_days,
+        };
+        let mut phase_calculator =
+            GovernancePhaseCalculator::new(pool.clone()).with_hysteresis(hysteresis);
+        if let Some(ref logger) = audit_logger {
+            phase_calculator =
+                phase_calculator.with_audit_logger(logger.clone(), config.server_id.clone());
+        }
+        let phase_nostr_client = nostr_client.clone();
+        let evaluation_interval =
+            Duration::from_secs(config.governance.phase_evaluation_interval_secs);
+        shutdown.spawn("governance_phase", |token| async move {
+            let mut interval = tokio::time::interval(evaluation_interval);
+            while shutdown::next_tick(&token, &mut interval).await {
+                if let Err(e) = phase_calculator
+                    .check_and_announce_phase_transition(phase_nostr_client.as_ref())
+                    .await
+                {
+                    error!("Failed to evaluate governance phase: {}", e);
+                }
+            }
+        });
+        info!(
+            "Governance phase evaluation task started (interval: {}s, hysteresis: {} evaluations)",
+            config.governance.phase_evaluation_interval_secs,
+            config.governance.phase_hysteresis_evaluations
+        );
+    }
+
+    // Start hourly governance review deadline reminder task (Nostr DMs)
+    match &nostr_client {
+        Some(nostr_client) if !config.governance_review.maintainer_npubs.is_empty() => {
+            let notification_manager =
+                governance_review::DeadlineNotificationManager::new(pool.clone(), None);
+            let nostr_client = nostr_client.clone();
+            let maintainer_npubs = config.governance_review.maintainer_npubs.clone();
+            shutdown.spawn("deadline_dms", |token| async move {
+                let mut interval = tokio::time::interval(Duration::from_secs(3600));
+                while shutdown::next_tick(&token, &mut interval).await {
+                    match notification_manager
+                        .notify_upcoming_deadlines(&nostr_client, &maintainer_npubs)
+                        .await
+                    {
+                        Ok(sent) if sent > 0 => info!("Sent {} deadline reminders", sent),
+                        Ok(_) => {}
+                        Err(e) => error!("Failed to check governance review deadlines: {}", e),
+                    }
+                }
+            });
+            info!(
+                "Governance review deadline reminder task started ({} maintainers)",
+                config.governance_review.maintainer_npubs.len()
+            );
+        }
+        _ => info!(
+            "Governance review deadline reminders disabled (needs Nostr and maintainer npubs)"
+        ),
+    }
+
+    // Daily governance review deadline reminders: case issue comments and Nostr events
+    let review_github = match (
+        github::client::GitHubClient::new(config.github_app_id, &config.github_private_key_path),
+        config.governance_repo.split_once('/'),
+    ) {
+        (Ok(client), Some((owner, name))) => {
+            Some(governance_review::GovernanceReviewGitHubIntegration::new(
+                client.with_execution_mode(config.execution_mode()),
+                owner.to_string(),
+                name.to_string(),
+            ))
+        }
+        (Err(e), _) => {
+            warn!("Deadline reminders won't be posted to GitHub: {}", e);
+            None
+        }
+        (Ok(_), None) => None,
+    };
+    let mut reminder_manager =
+        governance_review::DeadlineNotificationManager::new(pool.clone(), review_github);
+    if let Some(client) = &nostr_client {
+        reminder_manager = reminder_manager.with_publisher(Arc::new(
+            nostr::GovernanceActionPublisher::new(
+                client.clone(),
+                config.nostr.governance_config.clone(),
+                config.nostr.zap_address.clone(),
+            )
+            .with_min_quorum(config.nostr.publish_min_quorum)
+            .with_execution_mode(config.execution_mode()),
+        ));
+    }
+    governance_review::deadline_notifications::spawn_reminder_task(
+        Arc::new(reminder_manager),
+        config.governance_review.reminder_lead_days.clone(),
+        &shutdown,
+    );
+    info!(
+        "Governance review deadline reminder scan started (lead days: {:?})",
+        config.governance_review.reminder_lead_days
+    );
+
+    // Process queued GitHub webhook events in the background
+    webhooks::queue::spawn_worker(
+        webhooks::queue::WebhookQueue::new(pool.clone()),
+        config.clone(),
+        database.clone(),
+        &shutdown,
+    );
+    info!("Webhook queue worker started");
+
+    // Reject replayed deliveries from memory, warmed from recent history
+    let deduplicator = Arc::new(webhooks::dedup::DeliveryDeduplicator::new(
+        pool.clone(),
+        &config.webhooks,
+    ));
+    match deduplicator.warm().await {
+        Ok(loaded) => info!("Loaded {} recent webhook delivery IDs", loaded),
+        Err(e) => warn!("Failed to load recent webhook delivery IDs: {}", e),
+    }
+    webhooks::dedup::set_shared_deduplicator(deduplicator.clone());
+    webhooks::dedup::spawn_cleanup_task(deduplicator, &shutdown);
+
+    // One blockchain verifier for node registration, so its cache is shared
+    let registration_verifier = node_registry::api::RegistrationVerifier::from_config(&config);
+
     // Build application
     let port = config.server_port;
+    let database_for_close = database.clone();
     // Add node registry API routes
     let app = Router::new()
         .route("/health", get(health_check))
         .route("/webhooks/github", post(webhooks::github::handle_webhook))
-        .route(
-            "/webhooks/block",
-            post(webhooks::block::handle_block_notification),
-        )
         .route("/status", get(status_endpoint))
-        .merge(node_registry::api::create_router())
+        .merge(health::create_router(Arc::new(
+            health::ReadinessCache::new(health::READINESS_CACHE_TTL),
+        )))
+        .merge(webhooks::block::create_router((
+            config.clone(),
+            database.clone(),
+        )))
+        .merge(webhooks::api::create_router((
+            config.clone(),
+            database.clone(),
+        )))
+        .merge(metrics::api::create_router())
+        .merge(node_registry::api::create_router(
+            registration_verifier.clone(),
+        ))
+        .merge(node_registry::api::create_admin_router((
+            config.clone(),
+            database.clone(),
+        )))
+        .merge(node_registry::api::create_self_service_router(
+            (config.clone(), database.clone()),
+            registration_verifier,
+        ))
+        .merge(governance_review::api::create_router((
+            config.clone(),
+            database.clone(),
+        )))
+        .merge(nostr::api::create_router((
+            config.clone(),
+            database.clone(),
+        )))
+        .merge(governance::api::create_router((
+            config.clone(),
+            database.clone(),
+        )))
+        .merge(enforcement::api::create_router(
+            emergency_manager.clone(),
+            (config.clone(), database.clone()),
+        ))
+        .merge(audit::api::create_router((
+            config.clone(),
+            database.clone(),
+        )))
+        .merge(database::api::create_router((
+            config.clone(),
+            database.clone(),
+        )))
+        .merge(ots_router(&config, &database))
         .layer(
             ServiceBuilder::new()
                 .layer(TraceLayer::new_for_http())
@@ -360,11 +842,51 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Server listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    let server_shutdown = shutdown.clone();
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            // Background tasks may also begin shutdown, e.g. when the database is lost
+            let token = server_shutdown.token();
+            tokio::select! {
+                _ = shutdown::wait_for_signal() => server_shutdown.trigger(),
+                _ = token.cancelled() => {}
+            }
+        })
+        .await?;
+    info!("HTTP server stopped");
 
+    // Let background tasks finish their current work, then release resources
+    shutdown.drain(shutdown::DRAIN_TIMEOUT).await;
+    if let Some(logger) = audit::shared_logger() {
+        if let Err(e) = logger.close().await {
+            warn!("Failed to flush audit log: {}", e);
+        }
+    }
+    let reconnect_attempts = database_for_close.reconnect_status().reconnect_attempts;
+    database_for_close.close().await;
+    info!("Database closed");
+
+    if max_reconnect_attempts > 0 && reconnect_attempts >= max_reconnect_attempts {
+        return Err(format!(
+            "database unreachable after {} reconnection attempts",
+            reconnect_attempts
+        )
+        .into());
+    }
     Ok(())
 }
 
+/// On-demand OTS anchoring routes (only if feature enabled)
+#[cfg(feature = "opentimestamps")]
+fn ots_router(config: &AppConfig, database: &Database) -> Router<(AppConfig, Database)> {
+    ots::api::create_router((config.clone(), database.clone()))
+}
+
+#[cfg(not(feature = "opentimestamps"))]
+fn ots_router(_config: &AppConfig, _database: &Database) -> Router<(AppConfig, Database)> {
+    Router::new()
+}
+
 async fn health_check() -> Json<serde_json::Value> {
     Json(serde_json::json!({
         "status": "healthy",
@@ -377,7 +899,7 @@ async fn status_endpoint(
     State((config, database)): State<(AppConfig, Database)>,
 ) -> Json<serde_json::Value> {
     let pool = database.get_sqlite_pool();
-    let governance_status = if let Some(pool) = pool {
+    let governance_status = if let Some(pool) = &pool {
         // Check governance tables exist
         let tables_exist = sqlx::query_scalar::<_, i64>(
             "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name IN ('unified_contributions', 'participation_weights', 'zap_contributions')"
@@ -395,12 +917,25 @@ async fn status_endpoint(
                 .await
                 .unwrap_or(0);
 
+        // Pending contribution anomalies, newest first
+        let anomalies = ContributionAggregator::new(pool.clone())
+            .list_anomalies(Some(AnomalyStatus::Pending), 10, 0)
+            .await
+            .ok()
+            .map(|page| {
+                serde_json::json!({
+                    "pending": page.total,
+                    "recent": page.items,
+                })
+            });
+
         serde_json::json!({
             "enabled": config.governance.contribution_tracking_enabled,
             "tables_exist": tables_exist,
             "contributor_count": contributor_count,
             "weight_updates_enabled": config.governance.weight_updates_enabled,
             "commons_addresses_count": config.governance.commons_addresses.len(),
+            "contribution_anomalies": anomalies,
         })
     } else {
         serde_json::json!({
@@ -415,26 +950,99 @@ async fn status_endpoint(
         "timestamp": chrono::Utc::now(),
         "server_id": config.server_id,
         "features": {
-            "nostr": config.nostr.enabled,
+            "nostr": { "enabled": config.nostr.enabled },
             "ots": config.ots.enabled,
             "audit": config.audit.enabled,
             "dry_run": config.dry_run_mode,
             "governance": governance_status,
+        },
+        "execution_mode": {
+            "mode": config.execution_mode(),
+            "suppressed_actions": execution_mode::suppressed_counts(),
         }
     });
 
+    // Add Nostr relay health, including each relay's publish circuit state
+    if let Some(client) = nostr::client::shared_client() {
+        let relay_health = client.relay_health().await;
+        status["features"]["nostr"]["relay_health"] = serde_json::json!(relay_health);
+        status["nostr"] = serde_json::json!({
+            "relays": relay_health,
+        });
+    }
+
+    // Add chain tip and recent reorgs from verified block headers
+    if let Some(pool) = &database.get_sqlite_pool() {
+        let tracker = webhooks::chain_tips::ChainTipTracker::new(pool.clone());
+        if let (Ok(tip), Ok(reorgs)) = (tracker.best_tip().await, tracker.recent_reorgs(5).await) {
+            status["chain"] = serde_json::json!({
+                "tip": tip,
+                "recent_reorgs": reorgs,
+            });
+        }
+    }
+
+    // Add webhook queue depth
+    if let Some(pool) = &database.get_sqlite_pool() {
+        if let Ok(stats) = webhooks::queue::WebhookQueue::new(pool.clone())
+            .stats()
+            .await
+        {
+            status["webhook_queue"] = serde_json::json!(stats);
+        }
+    }
+    if let Some(deduplicator) = webhooks::dedup::shared_deduplicator() {
+        status["webhook_dedup"] = serde_json::json!(deduplicator.stats());
+    }
+
+    // Add GitHub API rate limit and cache statistics
+    status["github_api"] =
+        serde_json::json!(github::file_operations::shared_api_stats().snapshot());
+
     // Add database status
     if let Ok(stats) = database.get_performance_stats().await {
         status["database"] = serde_json::json!({
             "status": "healthy",
             "cache_size": stats.cache_size,
-            "slow_queries": stats.slow_queries_count
+            "slow_queries": stats.slow_queries_count,
+            "slowest_queries": database.get_slow_query_report(),
+            "wal_frames": stats.wal_frames,
+            "wal_checkpointed": stats.wal_checkpointed
         });
+        if let Ok(migrations) = database.migration_status().await {
+            status["database"]["schema_version"] = serde_json::json!(migrations.current_version);
+            status["database"]["pending_migrations"] = serde_json::json!(migrations.pending.len());
+        }
     } else {
         status["database"] = serde_json::json!({
             "status": "error"
         });
     }
+    let reconnect = database.reconnect_status();
+    status["database"]["reconnect_attempts"] = serde_json::json!(reconnect.reconnect_attempts);
+    status["database"]["last_reconnect_at"] = serde_json::json!(reconnect.last_reconnect_at);
+
+    // Add audit log anchoring status
+    if let Some(pool) = &database.get_sqlite_pool() {
+        let latest_anchor = sqlx::query_as::<_, (chrono::DateTime<chrono::Utc>, String)>(
+            "SELECT anchored_at, status FROM audit_anchors ORDER BY anchored_at DESC, id DESC LIMIT 1",
+        )
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten();
+
+        status["audit_anchor"] = match latest_anchor {
+            Some((anchored_at, proof_status)) => serde_json::json!({
+                "last_audit_anchor_at": anchored_at,
+                "latest_proof_status": proof_status
+            }),
+            None => serde_json::json!({
+                "last_audit_anchor_at": null,
+                "latest_proof_status": null
+            }),
+        };
+    }
 
     Json(status)
 }
@@ -0,0 +1,177 @@
+//! Prometheus scrape endpoint
+
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use tracing::warn;
+
+use crate::api_auth::keys_match;
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::governance::time_lock::TimeLockManager;
+use crate::metrics::{
+    self, CONFIG_CHANGES_ACTIVATED, CONFIG_CHANGES_PENDING, DB_POOL_CLOSED, DB_POOL_CONNECTIONS,
+    ECONOMIC_NODES,
+};
+use crate::node_registry::NodeRegistry;
+
+/// Whether the request carries the configured bearer token (if any)
+fn authorized(config: &AppConfig, headers: &HeaderMap) -> bool {
+    let Some(expected) = config.metrics.auth_token.as_deref() else {
+        return true;
+    };
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| keys_match(token, expected))
+}
+
+/// Gauges read from the database rather than updated as events happen
+async fn sample_governance_gauges(config: &AppConfig, database: &Database) {
+    let Some(pool) = database.get_sqlite_pool() else {
+        return;
+    };
+
+    match NodeRegistry::new(pool).count_by_type_and_status().await {
+        Ok(counts) => {
+            for (node_type, status, count) in counts {
+                metrics::set_gauge(
+                    ECONOMIC_NODES,
+                    &[("type", &node_type), ("status", &status)],
+                    count as f64,
+                );
+            }
+        }
+        Err(e) => warn!("Failed to count registered nodes for metrics: {}", e),
+    }
+
+    // The time lock tables are created at startup; before that there is nothing to count
+    let time_locks = TimeLockManager::new(database.clone(), config.governance.time_lock.clone());
+    if let Ok(counts) = time_locks.count_by_status().await {
+        let count = |status: &str| counts.get(status).copied().unwrap_or(0) as f64;
+        metrics::set_gauge(CONFIG_CHANGES_PENDING, &[], count("pending"));
+        metrics::set_gauge(CONFIG_CHANGES_ACTIVATED, &[], count("activated"));
+    }
+}
+
+/// Prometheus text exposition, with database-backed gauges sampled at scrape time
+///
+/// Requires `Authorization: Bearer <metrics.auth_token>` when a token is configured.
+pub async fn metrics_endpoint(
+    State((config, database)): State<(AppConfig, Database)>,
+    headers: HeaderMap,
+) -> Response {
+    if !authorized(&config, &headers) {
+        return (StatusCode::UNAUTHORIZED, "invalid or missing metrics token").into_response();
+    }
+
+    sample_governance_gauges(&config, &database).await;
+    if let Ok(stats) = database.get_pool_stats().await {
+        metrics::set_gauge(
+            DB_POOL_CONNECTIONS,
+            &[("state", "total")],
+            stats.size as f64,
+        );
+        metrics::set_gauge(DB_POOL_CONNECTIONS, &[("state", "idle")], stats.idle as f64);
+        metrics::set_gauge(DB_POOL_CLOSED, &[], if stats.is_closed { 1.0 } else { 0.0 });
+    }
+
+    (
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        metrics::render(),
+    )
+        .into_response()
+}
+
+/// Create router for the metrics endpoint (optionally bearer-token protected)
+pub fn create_router() -> Router<(AppConfig, Database)> {
+    Router::new().route("/metrics", get(metrics_endpoint))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_renders_handler_metrics() {
+        let database = Database::new_in_memory().await.unwrap();
+        let app = Router::new()
+            .merge(crate::node_registry::api::create_router(Default::default()))
+            .merge(create_router())
+            .with_state((AppConfig::default(), database));
+
+        for uri in ["/nodes/missing-node", "/nodes?limit=5"] {
+            let response = app
+                .clone()
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = app
+            .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+
+        assert!(text.contains("# TYPE blvm_db_query_duration_seconds histogram"));
+        assert!(
+            text.contains("blvm_db_query_duration_seconds_count{query=\"node_registry.get_node\"}")
+        );
+        assert!(text
+            .contains("blvm_db_query_duration_seconds_count{query=\"node_registry.list_nodes\"}"));
+        assert!(text.contains("blvm_db_pool_connections{state=\"total\"}"));
+        assert!(text.contains("blvm_db_pool_closed 0"));
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_requires_configured_token() {
+        let database = Database::new_in_memory().await.unwrap();
+        sqlx::query(
+            "INSERT INTO node_registry (node_id, node_name, node_type, active) VALUES ('m1', 'Miner', 'miner', TRUE)",
+        )
+        .execute(&database.get_sqlite_pool().unwrap())
+        .await
+        .unwrap();
+        let mut config = AppConfig::default();
+        config.metrics.auth_token = Some("scrape-secret".to_string());
+        let app = create_router().with_state((config, database));
+
+        let response = app
+            .clone()
+            .oneshot(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .oneshot(
+                Request::get("/metrics")
+                    .header("authorization", "Bearer scrape-secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let text = String::from_utf8(body.to_vec()).unwrap();
+        assert!(text.contains("blvm_economic_nodes_total{type=\"miner\",status=\"active\"} 1"));
+    }
+}
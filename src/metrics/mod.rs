@@ -0,0 +1,285 @@
+//! Prometheus metrics
+//!
+//! A small in-process registry of counters, gauges and histograms, rendered in
+//! the Prometheus text exposition format by `GET /metrics`. There is no push
+//! gateway; Prometheus scrapes the endpoint.
+
+pub mod api;
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Database query latency, labelled by `query`
+pub const DB_QUERY_DURATION: &str = "blvm_db_query_duration_seconds";
+/// Connections in the database pool, labelled by `state` (`total`, `idle`)
+pub const DB_POOL_CONNECTIONS: &str = "blvm_db_pool_connections";
+/// 1 if the database pool has been closed
+pub const DB_POOL_CLOSED: &str = "blvm_db_pool_closed";
+/// Background task runs, labelled by `task` and `outcome` (`success`, `failure`)
+pub const TASK_RUNS: &str = "blvm_background_task_runs_total";
+/// Background task run time, labelled by `task`
+pub const TASK_DURATION: &str = "blvm_background_task_duration_seconds";
+/// GitHub webhook deliveries accepted, labelled by `event`
+pub const WEBHOOK_EVENTS: &str = "blvm_webhook_events_total";
+/// GitHub webhook deliveries rejected, labelled by `reason`
+pub const WEBHOOK_REJECTED: &str = "blvm_webhook_rejected_total";
+/// Webhook delivery ID lookups, labelled by `result` (`hit`, `miss`,
+/// `false_duplicate`)
+pub const WEBHOOK_DEDUP_LOOKUPS: &str = "blvm_webhook_dedup_lookups_total";
+/// Registered nodes, labelled by `type` and `status`; sampled at scrape time
+pub const ECONOMIC_NODES: &str = "blvm_economic_nodes_total";
+/// Time-locked governance changes still waiting to activate; sampled at scrape time
+pub const CONFIG_CHANGES_PENDING: &str = "blvm_config_changes_pending";
+/// Time-locked governance changes activated; sampled at scrape time
+pub const CONFIG_CHANGES_ACTIVATED: &str = "blvm_config_changes_activated";
+/// Unix time of the last successful backup
+pub const BACKUP_LAST_SUCCESS: &str = "blvm_backup_last_success_timestamp";
+/// Nostr events that failed to reach enough relays
+pub const NOSTR_PUBLISH_ERRORS: &str = "blvm_nostr_publish_errors_total";
+/// Current governance phase: 0 = early, 1 = growth, 2 = mature
+pub const GOVERNANCE_PHASE: &str = "blvm_governance_phase";
+/// Participation weight update run time
+pub const WEIGHT_UPDATE_DURATION: &str = "blvm_weight_update_duration_seconds";
+
+/// Histogram bucket upper bounds in seconds, from fast queries to slow tasks
+const BUCKETS: [f64; 10] = [0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 30.0, 300.0];
+
+fn help(name: &str) -> &'static str {
+    match name {
+        DB_QUERY_DURATION => "Database query latency in seconds",
+        DB_POOL_CONNECTIONS => "Connections in the database pool",
+        DB_POOL_CLOSED => "Whether the database pool is closed",
+        TASK_RUNS => "Background task runs by outcome",
+        TASK_DURATION => "Background task run time in seconds",
+        WEBHOOK_EVENTS => "GitHub webhook deliveries accepted by event type",
+        WEBHOOK_REJECTED => "GitHub webhook deliveries rejected by reason",
+        ECONOMIC_NODES => "Registered nodes by type and status",
+        CONFIG_CHANGES_PENDING => "Time-locked governance changes pending activation",
+        CONFIG_CHANGES_ACTIVATED => "Time-locked governance changes activated",
+        BACKUP_LAST_SUCCESS => "Unix time of the last successful backup",
+        NOSTR_PUBLISH_ERRORS => "Nostr events that failed to publish",
+        GOVERNANCE_PHASE => "Governance phase (0 = early, 1 = growth, 2 = mature)",
+        WEIGHT_UPDATE_DURATION => "Participation weight update run time in seconds",
+        _ => "",
+    }
+}
+
+type Labels = Vec<(&'static str, String)>;
+
+#[derive(Clone)]
+struct Histogram {
+    /// Cumulative count per bucket in `BUCKETS`
+    buckets: [u64; BUCKETS.len()],
+    sum: f64,
+    count: u64,
+}
+
+struct Registry {
+    counters: BTreeMap<(&'static str, Labels), u64>,
+    gauges: BTreeMap<(&'static str, Labels), f64>,
+    histograms: BTreeMap<(&'static str, Labels), Histogram>,
+}
+
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry {
+    counters: BTreeMap::new(),
+    gauges: BTreeMap::new(),
+    histograms: BTreeMap::new(),
+});
+
+fn key(name: &'static str, labels: &[(&'static str, &str)]) -> (&'static str, Labels) {
+    (
+        name,
+        labels
+            .iter()
+            .map(|(label, value)| (*label, value.to_string()))
+            .collect(),
+    )
+}
+
+fn with_registry<T>(f: impl FnOnce(&mut Registry) -> T) -> T {
+    let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    f(&mut registry)
+}
+
+pub fn inc_counter(name: &'static str, labels: &[(&'static str, &str)]) {
+    with_registry(|r| *r.counters.entry(key(name, labels)).or_insert(0) += 1);
+}
+
+pub fn set_gauge(name: &'static str, labels: &[(&'static str, &str)], value: f64) {
+    with_registry(|r| {
+        r.gauges.insert(key(name, labels), value);
+    });
+}
+
+pub fn observe(name: &'static str, labels: &[(&'static str, &str)], seconds: f64) {
+    with_registry(|r| {
+        let histogram = r.histograms.entry(key(name, labels)).or_insert(Histogram {
+            buckets: [0; BUCKETS.len()],
+            sum: 0.0,
+            count: 0,
+        });
+        for (bucket, bound) in histogram.buckets.iter_mut().zip(BUCKETS) {
+            if seconds <= bound {
+                *bucket += 1;
+            }
+        }
+        histogram.sum += seconds;
+        histogram.count += 1;
+    });
+}
+
+/// Current value of a counter (0 if never incremented)
+pub fn counter_value(name: &'static str, labels: &[(&'static str, &str)]) -> u64 {
+    with_registry(|r| r.counters.get(&key(name, labels)).copied().unwrap_or(0))
+}
+
+/// Records a query's latency in `DB_QUERY_DURATION` when dropped
+pub struct QueryTimer {
+    query: &'static str,
+    started: Instant,
+}
+
+/// Time the rest of the enclosing scope as database query `query`
+pub fn query_timer(query: &'static str) -> QueryTimer {
+    QueryTimer {
+        query,
+        started: Instant::now(),
+    }
+}
+
+impl Drop for QueryTimer {
+    fn drop(&mut self) {
+        observe(
+            DB_QUERY_DURATION,
+            &[("query", self.query)],
+            self.started.elapsed().as_secs_f64(),
+        );
+    }
+}
+
+/// Count a background task run and record how long it took
+pub fn record_task_run(task: &'static str, duration: Duration, success: bool) {
+    let outcome = if success { "success" } else { "failure" };
+    inc_counter(TASK_RUNS, &[("task", task), ("outcome", outcome)]);
+    observe(TASK_DURATION, &[("task", task)], duration.as_secs_f64());
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn format_labels(labels: &[(&'static str, String)], extra: Option<(&str, String)>) -> String {
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .map(|(label, value)| format!("{}=\"{}\"", label, escape_label_value(value)))
+        .collect();
+    if let Some((label, value)) = extra {
+        pairs.push(format!("{}=\"{}\"", label, value));
+    }
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!("{{{}}}", pairs.join(","))
+    }
+}
+
+fn write_header(out: &mut String, last: &mut Option<&'static str>, name: &'static str, kind: &str) {
+    if *last != Some(name) {
+        let _ = writeln!(out, "# HELP {} {}", name, help(name));
+        let _ = writeln!(out, "# TYPE {} {}", name, kind);
+        *last = Some(name);
+    }
+}
+
+/// Render every metric in the Prometheus text exposition format
+pub fn render() -> String {
+    let (counters, gauges, histograms) =
+        with_registry(|r| (r.counters.clone(), r.gauges.clone(), r.histograms.clone()));
+    let mut out = String::new();
+
+    let mut last = None;
+    for ((name, labels), value) in counters {
+        write_header(&mut out, &mut last, name, "counter");
+        let _ = writeln!(out, "{}{} {}", name, format_labels(&labels, None), value);
+    }
+
+    let mut last = None;
+    for ((name, labels), value) in gauges {
+        write_header(&mut out, &mut last, name, "gauge");
+        let _ = writeln!(out, "{}{} {}", name, format_labels(&labels, None), value);
+    }
+
+    let mut last = None;
+    for ((name, labels), histogram) in histograms {
+        write_header(&mut out, &mut last, name, "histogram");
+        for (bound, count) in BUCKETS.iter().zip(histogram.buckets) {
+            let _ = writeln!(
+                out,
+                "{}_bucket{} {}",
+                name,
+                format_labels(&labels, Some(("le", bound.to_string()))),
+                count
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{}_bucket{} {}",
+            name,
+            format_labels(&labels, Some(("le", "+Inf".to_string()))),
+            histogram.count
+        );
+        let _ = writeln!(
+            out,
+            "{}_sum{} {}",
+            name,
+            format_labels(&labels, None),
+            histogram.sum
+        );
+        let _ = writeln!(
+            out,
+            "{}_count{} {}",
+            name,
+            format_labels(&labels, None),
+            histogram.count
+        );
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_histogram_buckets() {
+        observe(TASK_DURATION, &[("task", "render-test")], 0.02);
+        observe(TASK_DURATION, &[("task", "render-test")], 2.0);
+
+        let text = render();
+        assert!(text.contains("# TYPE blvm_background_task_duration_seconds histogram"));
+        assert!(text.contains(
+            "blvm_background_task_duration_seconds_bucket{task=\"render-test\",le=\"0.01\"} 0"
+        ));
+        assert!(text.contains(
+            "blvm_background_task_duration_seconds_bucket{task=\"render-test\",le=\"0.05\"} 1"
+        ));
+        assert!(text.contains(
+            "blvm_background_task_duration_seconds_bucket{task=\"render-test\",le=\"+Inf\"} 2"
+        ));
+        assert!(
+            text.contains("blvm_background_task_duration_seconds_count{task=\"render-test\"} 2")
+        );
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        inc_counter(WEBHOOK_EVENTS, &[("event", "quote\"d")]);
+        assert!(render().contains("blvm_webhook_events_total{event=\"quote\\\"d\"}"));
+    }
+}
@@ -1,16 +1,48 @@
 //! Node Registry API endpoints
 
 use axum::{
-    extract::State,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
     response::Json,
     routing::{get, post},
-    Router,
+    Extension, Router,
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tracing::{info, warn};
 
+use crate::api_auth::require_internal_api_key;
+use crate::config::AppConfig;
+use crate::crypto::blockchain_verifier::{blockchain_verifier_from_config, BlockchainVerifier};
 use crate::database::Database;
-use crate::node_registry::{NodeRegistry, NodeType};
+use crate::governance::time_lock::TimeLockedChange;
+use crate::node_registry::tokens::{
+    require_node_token, IssuedNodeToken, NodeApiToken, NodeIdentity, NodeTokenStore,
+};
+use crate::node_registry::{
+    same_public_key, NodeFilter, NodeRegistration, NodeRegistry, NodeStatus, NodeType,
+    RegistrationChallenge, RegistrationConflict,
+};
+
+/// Page size when the client doesn't specify one
+const DEFAULT_PAGE_SIZE: i64 = 50;
+
+/// Largest page a client may request
+const MAX_PAGE_SIZE: i64 = 500;
+
+/// Blockchain verifier for registration proofs, built once per router so its
+/// result cache survives across requests
+#[derive(Clone, Default)]
+pub struct RegistrationVerifier(pub Option<Arc<dyn BlockchainVerifier>>);
+
+impl RegistrationVerifier {
+    /// The verifier configured in `config`, if any
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self(blockchain_verifier_from_config(config))
+    }
+}
 
 /// Register node request
 #[derive(Debug, Deserialize)]
@@ -20,6 +52,69 @@ pub struct RegisterNodeRequest {
     pub node_type: String,
     pub bitcoin_addresses: Vec<String>,
     pub metadata: Option<serde_json::Value>,
+    /// Hex-encoded secp256k1 public key used to authorize later changes
+    #[serde(default)]
+    pub public_key: Option<String>,
+    /// Nonce from `/nodes/register/challenge`; required with `public_key`
+    #[serde(default)]
+    pub challenge_nonce: Option<String>,
+    /// Signature by `public_key` over `register:{challenge_nonce}`
+    #[serde(default)]
+    pub challenge_signature: Option<String>,
+}
+
+/// Registration challenge request
+#[derive(Debug, Deserialize)]
+pub struct RegistrationChallengeRequest {
+    pub public_key: String,
+}
+
+/// Registration challenge response
+#[derive(Debug, Serialize)]
+pub struct RegistrationChallengeResponse {
+    pub success: bool,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<RegistrationChallenge>,
+}
+
+/// Pending node purge query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct PurgePendingQuery {
+    /// Overrides `node_registry.pending_max_age_hours`
+    pub older_than_hours: Option<u64>,
+}
+
+/// Pending node purge response
+#[derive(Debug, Serialize)]
+pub struct PurgePendingResponse {
+    pub purged: u64,
+    pub cutoff: DateTime<Utc>,
+}
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+/// Deregister node request, signed by the node's current key
+#[derive(Debug, Deserialize)]
+pub struct DeregisterNodeRequest {
+    pub timestamp: i64,
+    pub signature: String,
+}
+
+/// Rotate key request, signed by both the current and the new key
+#[derive(Debug, Deserialize)]
+pub struct RotateKeyRequest {
+    pub new_public_key: String,
+    pub timestamp: i64,
+    pub old_key_signature: String,
+    pub new_key_signature: String,
 }
 
 /// Node registration response
@@ -27,6 +122,51 @@ pub struct RegisterNodeRequest {
 pub struct RegisterNodeResponse {
     pub success: bool,
     pub message: String,
+    /// Node API token for the self-service endpoints, issued when the
+    /// registration proved control of its public key; shown only once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_token: Option<IssuedNodeToken>,
+}
+
+/// Requalification request: fresh addresses and proofs for the token's node
+#[derive(Debug, Deserialize)]
+pub struct RequalifyRequest {
+    pub bitcoin_addresses: Vec<String>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// Token request signed by the node's current key
+///
+/// Issuing signs `issue_token:{node_id}:{timestamp}`; revoking signs
+/// `revoke_token:{node_id}:{token_id}:{timestamp}`.
+#[derive(Debug, Deserialize)]
+pub struct SignedTokenRequest {
+    pub timestamp: i64,
+    pub signature: String,
+}
+
+/// Token revocation response
+#[derive(Debug, Serialize)]
+pub struct RevokeTokenResponse {
+    pub revoked: bool,
+}
+
+/// Time-locked change a node has signaled an override on
+#[derive(Debug, Serialize)]
+pub struct NodeSignal {
+    pub change_id: String,
+    pub tier: u8,
+    pub description: String,
+    pub pr_number: Option<i64>,
+    pub status: String,
+    pub signaled_at: DateTime<Utc>,
+}
+
+/// Deregistration / key rotation response
+#[derive(Debug, Serialize)]
+pub struct NodeActionResponse {
+    pub success: bool,
+    pub message: String,
 }
 
 /// Get node response
@@ -35,96 +175,1011 @@ pub struct GetNodeResponse {
     pub node: Option<crate::node_registry::NodeRegistration>,
 }
 
+/// List nodes query parameters
+#[derive(Debug, Default, Deserialize)]
+pub struct ListNodesQuery {
+    pub node_type: Option<String>,
+    /// "active" (default), "inactive", "deregistered" or "all"
+    pub status: Option<String>,
+    pub registered_after: Option<chrono::DateTime<chrono::Utc>>,
+    /// Substring match on the node name
+    pub search: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+impl ListNodesQuery {
+    fn to_filter(&self) -> NodeFilter {
+        let status = match self.status.as_deref().map(str::to_lowercase).as_deref() {
+            Some("all") => None,
+            Some("inactive") => Some(NodeStatus::Inactive),
+            Some("deregistered") => Some(NodeStatus::Deregistered),
+            _ => Some(NodeStatus::Active),
+        };
+
+        NodeFilter {
+            node_type: self.node_type.as_deref().map(NodeType::from_str),
+            status,
+            registered_after: self.registered_after,
+            search: self.search.clone(),
+            limit: Some(
+                self.limit
+                    .unwrap_or(DEFAULT_PAGE_SIZE)
+                    .clamp(1, MAX_PAGE_SIZE),
+            ),
+            offset: self.offset.unwrap_or(0).max(0),
+        }
+    }
+}
+
+/// Pagination metadata for list responses
+#[derive(Debug, Serialize)]
+pub struct Pagination {
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+    pub has_more: bool,
+}
+
 /// List nodes response
 #[derive(Debug, Serialize)]
 pub struct ListNodesResponse {
     pub nodes: Vec<crate::node_registry::NodeRegistration>,
+    pub pagination: Pagination,
 }
 
-/// Register a new node
-pub async fn register_node(
-    State((_, database)): State<(crate::config::AppConfig, Database)>,
-    Json(request): Json<RegisterNodeRequest>,
-) -> Json<RegisterNodeResponse> {
+/// Issue a nonce that a registration claiming the public key must sign
+pub async fn registration_challenge(
+    State((_, database)): State<(AppConfig, Database)>,
+    Json(request): Json<RegistrationChallengeRequest>,
+) -> Json<RegistrationChallengeResponse> {
     let pool = match database.get_sqlite_pool() {
         Some(pool) => pool,
         None => {
-            return Json(RegisterNodeResponse {
+            return Json(RegistrationChallengeResponse {
                 success: false,
                 message: "Database pool not available".to_string(),
+                challenge: None,
             });
         }
     };
 
-    let registry = NodeRegistry::new(pool.clone());
+    let registry = NodeRegistry::new(pool);
+    match registry
+        .create_registration_challenge(&request.public_key)
+        .await
+    {
+        Ok(challenge) => Json(RegistrationChallengeResponse {
+            success: true,
+            message: format!(
+                "Sign \"{}\" with the public key and register before {}",
+                RegistrationChallenge::challenge_message(&challenge.nonce),
+                challenge.expires_at.to_rfc3339()
+            ),
+            challenge: Some(challenge),
+        }),
+        Err(e) => {
+            warn!("Failed to create registration challenge: {}", e);
+            Json(RegistrationChallengeResponse {
+                success: false,
+                message: format!("Failed to create registration challenge: {}", e),
+                challenge: None,
+            })
+        }
+    }
+}
+
+fn registration_failed(
+    status: StatusCode,
+    message: impl Into<String>,
+) -> (StatusCode, Json<RegisterNodeResponse>) {
+    (
+        status,
+        Json(RegisterNodeResponse {
+            success: false,
+            message: message.into(),
+            api_token: None,
+        }),
+    )
+}
+
+/// Register a new node
+///
+/// An existing node can only be re-registered with a challenge signed by its
+/// stored key; any other key gets 409 Conflict.
+pub async fn register_node(
+    State((config, database)): State<(crate::config::AppConfig, Database)>,
+    Extension(verifier): Extension<RegistrationVerifier>,
+    Json(request): Json<RegisterNodeRequest>,
+) -> (StatusCode, Json<RegisterNodeResponse>) {
+    let pool = match database.get_sqlite_pool() {
+        Some(pool) => pool,
+        None => return registration_failed(StatusCode::OK, "Database pool not available"),
+    };
+
+    let registry = match verifier.0 {
+        Some(verifier) => NodeRegistry::with_blockchain_verifier(pool.clone(), verifier),
+        None => NodeRegistry::new(pool.clone()),
+    }
+    .with_network(config.governance.bitcoin_network());
     let node_type = NodeType::from_str(&request.node_type);
 
-    match registry
+    // Claiming a public key requires proving control of it, before any row exists
+    if let Some(public_key) = request.public_key.as_deref() {
+        let (Some(nonce), Some(signature)) = (
+            request.challenge_nonce.as_deref(),
+            request.challenge_signature.as_deref(),
+        ) else {
+            return registration_failed(
+                StatusCode::OK,
+                "Registering a public key requires a signed challenge from /nodes/register/challenge",
+            );
+        };
+        if let Err(e) = registry
+            .consume_registration_challenge(public_key, nonce, signature)
+            .await
+        {
+            warn!(
+                "Rejected registration challenge for node {}: {}",
+                request.node_id, e
+            );
+            return registration_failed(StatusCode::OK, format!("Failed to register node: {}", e));
+        }
+    }
+
+    let active = match registry
         .register_node(
             &request.node_id,
             &request.node_name,
             node_type,
             request.bitcoin_addresses,
             request.metadata,
+            request.public_key.as_deref(),
         )
         .await
     {
-        Ok(_) => {
-            info!("Node registered: {}", request.node_id);
-            Json(RegisterNodeResponse {
-                success: true,
-                message: format!("Node {} registered successfully", request.node_id),
-            })
-        }
+        Ok(active) => active,
         Err(e) => {
             warn!("Failed to register node {}: {}", request.node_id, e);
-            Json(RegisterNodeResponse {
+            let status = if e.is::<RegistrationConflict>() {
+                StatusCode::CONFLICT
+            } else {
+                StatusCode::OK
+            };
+            return registration_failed(status, format!("Failed to register node: {}", e));
+        }
+    };
+
+    // The challenge proved control of the presented key; only issue a token
+    // if that is the key the node ended up stored with
+    let api_token = match request.public_key.as_deref() {
+        Some(public_key) => match registry.get_node(&request.node_id).await {
+            Ok(Some(node))
+                if node
+                    .public_key
+                    .as_deref()
+                    .is_some_and(|stored| same_public_key(stored, public_key)) =>
+            {
+                NodeTokenStore::new(pool.clone())
+                    .issue(&request.node_id, config.node_registry.api_token_ttl())
+                    .await
+                    .map_err(|e| {
+                        warn!(
+                            "Failed to issue API token for node {}: {}",
+                            request.node_id, e
+                        )
+                    })
+                    .ok()
+            }
+            Ok(_) => {
+                warn!(
+                    "Not issuing API token for node {}: stored key differs from the presented key",
+                    request.node_id
+                );
+                None
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to issue API token for node {}: {}",
+                    request.node_id, e
+                );
+                None
+            }
+        },
+        None => None,
+    };
+
+    let message = if active {
+        info!("Node registered: {}", request.node_id);
+        format!("Node {} registered successfully", request.node_id)
+    } else {
+        info!("Node registered pending verification: {}", request.node_id);
+        format!(
+            "Node {} registered but inactive: a required proof is missing or still pending verification, re-register (signed with the node's key) to retry",
+            request.node_id
+        )
+    };
+    (
+        StatusCode::OK,
+        Json(RegisterNodeResponse {
+            success: true,
+            message,
+            api_token,
+        }),
+    )
+}
+
+/// Deregister a node
+pub async fn deregister_node(
+    State((_, database)): State<(crate::config::AppConfig, Database)>,
+    axum::extract::Path(node_id): axum::extract::Path<String>,
+    Json(request): Json<DeregisterNodeRequest>,
+) -> Json<NodeActionResponse> {
+    let pool = match database.get_sqlite_pool() {
+        Some(pool) => pool,
+        None => {
+            return Json(NodeActionResponse {
                 success: false,
-                message: format!("Failed to register node: {}", e),
+                message: "Database pool not available".to_string(),
+            });
+        }
+    };
+
+    let registry = NodeRegistry::new(pool);
+    match registry
+        .deregister_node(&node_id, request.timestamp, &request.signature)
+        .await
+    {
+        Ok(_) => Json(NodeActionResponse {
+            success: true,
+            message: format!("Node {} deregistered", node_id),
+        }),
+        Err(e) => {
+            warn!("Failed to deregister node {}: {}", node_id, e);
+            Json(NodeActionResponse {
+                success: false,
+                message: format!("Failed to deregister node: {}", e),
             })
         }
     }
 }
 
+/// Rotate a node's public key
+///
+/// A rotation racing another change to the node's key gets 409 Conflict.
+pub async fn rotate_key(
+    State((_, database)): State<(crate::config::AppConfig, Database)>,
+    axum::extract::Path(node_id): axum::extract::Path<String>,
+    Json(request): Json<RotateKeyRequest>,
+) -> (StatusCode, Json<NodeActionResponse>) {
+    let pool = match database.get_sqlite_pool() {
+        Some(pool) => pool,
+        None => {
+            return (
+                StatusCode::OK,
+                Json(NodeActionResponse {
+                    success: false,
+                    message: "Database pool not available".to_string(),
+                }),
+            );
+        }
+    };
+
+    let registry = NodeRegistry::new(pool);
+    match registry
+        .rotate_public_key(
+            &node_id,
+            &request.new_public_key,
+            request.timestamp,
+            &request.old_key_signature,
+            &request.new_key_signature,
+        )
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(NodeActionResponse {
+                success: true,
+                message: format!("Public key rotated for node {}", node_id),
+            }),
+        ),
+        Err(e) => {
+            warn!("Failed to rotate key for node {}: {}", node_id, e);
+            let status = if e.is::<RegistrationConflict>() {
+                StatusCode::CONFLICT
+            } else {
+                StatusCode::OK
+            };
+            (
+                status,
+                Json(NodeActionResponse {
+                    success: false,
+                    message: format!("Failed to rotate key: {}", e),
+                }),
+            )
+        }
+    }
+}
+
 /// Get node by ID
 pub async fn get_node(
     State((_, database)): State<(crate::config::AppConfig, Database)>,
     axum::extract::Path(node_id): axum::extract::Path<String>,
 ) -> Json<GetNodeResponse> {
-    let pool = match database.get_sqlite_pool() {
+    let pool = match database.get_read_pool() {
         Some(pool) => pool,
         None => {
             return Json(GetNodeResponse { node: None });
         }
     };
 
-    let registry = NodeRegistry::new(pool.clone());
+    let registry = NodeRegistry::new(pool);
     let node = registry.get_node(&node_id).await.ok().flatten();
 
     Json(GetNodeResponse { node })
 }
 
-/// List all active nodes
+/// List nodes, filtered and paginated (active nodes only by default)
 pub async fn list_nodes(
     State((_, database)): State<(crate::config::AppConfig, Database)>,
+    Query(query): Query<ListNodesQuery>,
 ) -> Json<ListNodesResponse> {
-    let pool = match database.get_sqlite_pool() {
+    let filter = query.to_filter();
+    let limit = filter.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    let empty = |offset| {
+        Json(ListNodesResponse {
+            nodes: Vec::new(),
+            pagination: Pagination {
+                total: 0,
+                limit,
+                offset,
+                has_more: false,
+            },
+        })
+    };
+
+    let pool = match database.get_read_pool() {
         Some(pool) => pool,
-        None => {
-            return Json(ListNodesResponse { nodes: Vec::new() });
-        }
+        None => return empty(filter.offset),
     };
 
-    let registry = NodeRegistry::new(pool.clone());
-    let nodes = registry.get_active_nodes().await.unwrap_or_default();
+    let registry = NodeRegistry::new(pool);
+    match registry.list_nodes(&filter).await {
+        Ok(page) => Json(ListNodesResponse {
+            pagination: Pagination {
+                total: page.total,
+                limit,
+                offset: page.offset,
+                has_more: page.offset + (page.items.len() as i64) < page.total,
+            },
+            nodes: page.items,
+        }),
+        Err(e) => {
+            warn!("Failed to list nodes: {}", e);
+            empty(filter.offset)
+        }
+    }
+}
+
+/// Delete registrations left pending verification, freeing their node IDs and keys
+pub async fn purge_pending_nodes(
+    State((config, database)): State<(AppConfig, Database)>,
+    Query(query): Query<PurgePendingQuery>,
+) -> Result<Json<PurgePendingResponse>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+
+    let hours = query
+        .older_than_hours
+        .unwrap_or(config.node_registry.pending_max_age_hours);
+    let cutoff = Utc::now() - chrono::Duration::hours(hours as i64);
+    match NodeRegistry::new(pool.clone())
+        .purge_pending_nodes(cutoff)
+        .await
+    {
+        Ok(purged) => Ok(Json(PurgePendingResponse { purged, cutoff })),
+        Err(e) => {
+            warn!("Failed to purge pending nodes: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+/// Registration of the node the token belongs to
+pub async fn get_my_node(
+    State((_, database)): State<(AppConfig, Database)>,
+    Extension(NodeIdentity(node_id)): Extension<NodeIdentity>,
+) -> Result<Json<NodeRegistration>, ApiError> {
+    let pool = &database.get_read_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    match NodeRegistry::new(pool.clone()).get_node(&node_id).await {
+        Ok(Some(node)) => Ok(Json(node)),
+        Ok(None) => Err(api_error(
+            StatusCode::NOT_FOUND,
+            format!("Node {} not found", node_id),
+        )),
+        Err(e) => Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e)),
+    }
+}
+
+/// Re-run qualification for the token's node with fresh addresses and proofs
+///
+/// Name, type and public key stay as registered.
+pub async fn requalify_node(
+    State((config, database)): State<(AppConfig, Database)>,
+    Extension(NodeIdentity(node_id)): Extension<NodeIdentity>,
+    Extension(verifier): Extension<RegistrationVerifier>,
+    Json(request): Json<RequalifyRequest>,
+) -> Result<Json<NodeActionResponse>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    let registry = match verifier.0 {
+        Some(verifier) => NodeRegistry::with_blockchain_verifier(pool.clone(), verifier),
+        None => NodeRegistry::new(pool.clone()),
+    }
+    .with_network(config.governance.bitcoin_network());
+
+    let node = registry
+        .get_node(&node_id)
+        .await
+        .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))?
+        .ok_or_else(|| api_error(StatusCode::NOT_FOUND, format!("Node {} not found", node_id)))?;
+
+    match registry
+        .register_node(
+            &node_id,
+            &node.node_name,
+            node.node_type,
+            request.bitcoin_addresses,
+            request.metadata,
+            node.public_key.as_deref(),
+        )
+        .await
+    {
+        Ok(true) => Ok(Json(NodeActionResponse {
+            success: true,
+            message: format!("Node {} requalified", node_id),
+        })),
+        Ok(false) => Ok(Json(NodeActionResponse {
+            success: true,
+            message: format!(
+                "Node {} requalified but inactive: a required proof is missing or still pending verification",
+                node_id
+            ),
+        })),
+        Err(e) => {
+            warn!("Failed to requalify node {}: {}", node_id, e);
+            Err(api_error(StatusCode::BAD_REQUEST, e))
+        }
+    }
+}
 
-    Json(ListNodesResponse { nodes })
+/// Override signals recorded for the token's node
+pub async fn get_my_signals(
+    state: State<(AppConfig, Database)>,
+    Extension(identity): Extension<NodeIdentity>,
+) -> Result<Json<Vec<NodeSignal>>, ApiError> {
+    let node_id = identity.0.clone();
+    get_node_signals(state, Extension(identity), Path(node_id)).await
+}
+
+/// Override signals recorded for a node; a token may only read its own
+pub async fn get_node_signals(
+    State((_, database)): State<(AppConfig, Database)>,
+    Extension(NodeIdentity(token_node_id)): Extension<NodeIdentity>,
+    Path(node_id): Path<String>,
+) -> Result<Json<Vec<NodeSignal>>, ApiError> {
+    if node_id != token_node_id {
+        return Err(api_error(
+            StatusCode::FORBIDDEN,
+            "Node token does not grant access to this node",
+        ));
+    }
+    let pool = &database.get_read_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+
+    let changes = sqlx::query_as::<_, TimeLockedChange>(
+        "SELECT * FROM time_locked_changes WHERE override_signals LIKE ? ORDER BY created_at DESC",
+    )
+    .bind(format!("%{}%", node_id))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))?;
+
+    // LIKE narrows the scan; the map lookup makes it exact
+    let signals = changes
+        .into_iter()
+        .filter_map(|change| {
+            let signaled_at = *change.override_signals.get(&node_id)?;
+            Some(NodeSignal {
+                change_id: change.change_id,
+                tier: change.tier,
+                description: change.description,
+                pr_number: change.pr_number,
+                status: change.status,
+                signaled_at,
+            })
+        })
+        .collect();
+    Ok(Json(signals))
+}
+
+/// Issue a node API token, authorized by the node's key
+pub async fn issue_node_token(
+    State((config, database)): State<(AppConfig, Database)>,
+    Path(node_id): Path<String>,
+    Json(request): Json<SignedTokenRequest>,
+) -> Result<Json<IssuedNodeToken>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    let message = format!("issue_token:{}:{}", node_id, request.timestamp);
+    if let Err(e) = NodeRegistry::new(pool.clone())
+        .verify_signed_request(&node_id, &message, request.timestamp, &request.signature)
+        .await
+    {
+        warn!("Rejected token request for node {}: {}", node_id, e);
+        return Err(api_error(StatusCode::UNAUTHORIZED, e));
+    }
+
+    NodeTokenStore::new(pool.clone())
+        .issue(&node_id, config.node_registry.api_token_ttl())
+        .await
+        .map(Json)
+        .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+}
+
+/// Revoke a node API token, authorized by the node's key
+pub async fn revoke_node_token(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path((node_id, token_id)): Path<(String, i64)>,
+    Json(request): Json<SignedTokenRequest>,
+) -> Result<Json<RevokeTokenResponse>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    let message = format!(
+        "revoke_token:{}:{}:{}",
+        node_id, token_id, request.timestamp
+    );
+    if let Err(e) = NodeRegistry::new(pool.clone())
+        .verify_signed_request(&node_id, &message, request.timestamp, &request.signature)
+        .await
+    {
+        warn!("Rejected token revocation for node {}: {}", node_id, e);
+        return Err(api_error(StatusCode::UNAUTHORIZED, e));
+    }
+
+    NodeTokenStore::new(pool.clone())
+        .revoke(&node_id, token_id)
+        .await
+        .map(|revoked| Json(RevokeTokenResponse { revoked }))
+        .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+}
+
+/// Issue a node API token on the operator's authority
+pub async fn admin_issue_node_token(
+    State((config, database)): State<(AppConfig, Database)>,
+    Path(node_id): Path<String>,
+) -> Result<Json<IssuedNodeToken>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    NodeTokenStore::new(pool.clone())
+        .issue(&node_id, config.node_registry.api_token_ttl())
+        .await
+        .map(Json)
+        .map_err(|e| api_error(StatusCode::BAD_REQUEST, e))
+}
+
+/// List a node's API tokens (metadata only)
+pub async fn admin_list_node_tokens(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(node_id): Path<String>,
+) -> Result<Json<Vec<NodeApiToken>>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    NodeTokenStore::new(pool.clone())
+        .list(&node_id)
+        .await
+        .map(Json)
+        .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+}
+
+/// Revoke a node API token on the operator's authority
+pub async fn admin_revoke_node_token(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path((node_id, token_id)): Path<(String, i64)>,
+) -> Result<Json<RevokeTokenResponse>, ApiError> {
+    let pool = &database.get_sqlite_pool().ok_or_else(|| {
+        api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Database pool not available",
+        )
+    })?;
+    NodeTokenStore::new(pool.clone())
+        .revoke(&node_id, token_id)
+        .await
+        .map(|revoked| Json(RevokeTokenResponse { revoked }))
+        .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
 }
 
 /// Create router for node registry API
-pub fn create_router() -> Router<(crate::config::AppConfig, Database)> {
+pub fn create_router(
+    verifier: RegistrationVerifier,
+) -> Router<(crate::config::AppConfig, Database)> {
     Router::new()
         .route("/nodes/register", post(register_node))
+        .route("/nodes/register/challenge", post(registration_challenge))
         .route("/nodes/:node_id", get(get_node))
+        .route("/nodes/:node_id/deregister", post(deregister_node))
+        .route("/nodes/:node_id/rotate-key", post(rotate_key))
+        .route("/nodes/:node_id/tokens", post(issue_node_token))
+        .route(
+            "/nodes/:node_id/tokens/:token_id/revoke",
+            post(revoke_node_token),
+        )
         .route("/nodes", get(list_nodes))
+        .layer(Extension(verifier))
+}
+
+/// Create router for node self-service; all routes require a node API token
+pub fn create_self_service_router(
+    state: (AppConfig, Database),
+    verifier: RegistrationVerifier,
+) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/nodes/me", get(get_my_node))
+        .route("/nodes/me/requalify", post(requalify_node))
+        .route("/nodes/me/signals", get(get_my_signals))
+        .route("/nodes/:node_id/signals", get(get_node_signals))
+        .layer(Extension(verifier))
+        .route_layer(middleware::from_fn_with_state(state, require_node_token))
+}
+
+/// Create router for node registry maintenance; all routes require the internal API key
+pub fn create_admin_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/internal/nodes/purge-pending", post(purge_pending_nodes))
+        .route(
+            "/internal/nodes/:node_id/tokens",
+            get(admin_list_node_tokens).post(admin_issue_node_token),
+        )
+        .route(
+            "/internal/nodes/:node_id/tokens/:token_id/revoke",
+            post(admin_revoke_node_token),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::signatures::SignatureManager;
+    use secp256k1::SecretKey;
+
+    async fn setup() -> (Database, String, SecretKey) {
+        let database = Database::new_in_memory().await.unwrap();
+        let keypair = SignatureManager::new().generate_keypair().unwrap();
+        (database, keypair.public_key.to_string(), keypair.secret_key)
+    }
+
+    fn state(database: &Database) -> State<(AppConfig, Database)> {
+        State((AppConfig::default(), database.clone()))
+    }
+
+    async fn challenge(database: &Database, public_key: &str) -> String {
+        let Json(response) = registration_challenge(
+            state(database),
+            Json(RegistrationChallengeRequest {
+                public_key: public_key.to_string(),
+            }),
+        )
+        .await;
+        assert!(response.success, "{}", response.message);
+        response.challenge.unwrap().nonce
+    }
+
+    fn sign(nonce: &str, secret_key: &SecretKey) -> String {
+        SignatureManager::new()
+            .create_signature(&RegistrationChallenge::challenge_message(nonce), secret_key)
+            .unwrap()
+            .to_string()
+    }
+
+    async fn register(
+        database: &Database,
+        node_id: &str,
+        public_key: &str,
+        challenge: Option<(&str, String)>,
+    ) -> RegisterNodeResponse {
+        let (challenge_nonce, challenge_signature) = match challenge {
+            Some((nonce, signature)) => (Some(nonce.to_string()), Some(signature)),
+            None => (None, None),
+        };
+        let (_, Json(response)) = register_node(
+            state(database),
+            Extension(RegistrationVerifier::default()),
+            Json(RegisterNodeRequest {
+                node_id: node_id.to_string(),
+                node_name: "Test Node".to_string(),
+                node_type: "node".to_string(),
+                bitcoin_addresses: vec![],
+                metadata: None,
+                public_key: Some(public_key.to_string()),
+                challenge_nonce,
+                challenge_signature,
+            }),
+        )
+        .await;
+        response
+    }
+
+    async fn node_exists(database: &Database, node_id: &str) -> bool {
+        NodeRegistry::new(database.get_sqlite_pool().unwrap())
+            .get_node(node_id)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    async fn register_with_token(
+        database: &Database,
+        node_id: &str,
+        secret_key: &SecretKey,
+        public_key: &str,
+    ) -> IssuedNodeToken {
+        let nonce = challenge(database, public_key).await;
+        let signature = sign(&nonce, secret_key);
+        let response = register(database, node_id, public_key, Some((&nonce, signature))).await;
+        assert!(response.success, "{}", response.message);
+        response
+            .api_token
+            .expect("registration should issue a token")
+    }
+
+    async fn get_status(
+        database: &Database,
+        uri: &str,
+        token: &str,
+    ) -> (StatusCode, serde_json::Value) {
+        use axum::body::Body;
+        use tower::ServiceExt;
+
+        let state = (AppConfig::default(), database.clone());
+        let app = create_router(RegistrationVerifier::default())
+            .merge(create_self_service_router(
+                state.clone(),
+                RegistrationVerifier::default(),
+            ))
+            .with_state(state);
+        let response = app
+            .oneshot(
+                axum::http::Request::get(uri)
+                    .header("authorization", format!("Bearer {}", token))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, serde_json::from_slice(&body).unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn test_node_token_reads_only_own_data() {
+        use crate::governance::time_lock::{migrate_time_lock_tables, TimeLockManager};
+
+        let (database, public_key, secret_key) = setup().await;
+        let token = register_with_token(&database, "node-1", &secret_key, &public_key).await;
+        let other = SignatureManager::new().generate_keypair().unwrap();
+        register_with_token(
+            &database,
+            "node-2",
+            &other.secret_key,
+            &other.public_key.to_string(),
+        )
+        .await;
+
+        migrate_time_lock_tables(&database).await.unwrap();
+        let time_locks = TimeLockManager::new(database.clone(), Default::default());
+        time_locks
+            .create_time_lock("change-1", 3, "Test change", Some(42))
+            .await
+            .unwrap();
+        time_locks
+            .record_override_signal("change-1", "node-1")
+            .await
+            .unwrap();
+
+        let (status, body) = get_status(&database, "/nodes/me", &token.token).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["node_id"], "node-1");
+
+        let (status, body) = get_status(&database, "/nodes/me/signals", &token.token).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body[0]["change_id"], "change-1");
+
+        let (status, _) = get_status(&database, "/nodes/node-2/signals", &token.token).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = get_status(&database, "/nodes/me", "bnt_not-a-token").await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_revoked_token_stops_working() {
+        let (database, public_key, secret_key) = setup().await;
+        let token = register_with_token(&database, "node-1", &secret_key, &public_key).await;
+        let (status, _) = get_status(&database, "/nodes/me", &token.token).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let timestamp = Utc::now().timestamp();
+        let message = format!("revoke_token:node-1:{}:{}", token.id, timestamp);
+        let signature = SignatureManager::new()
+            .create_signature(&message, &secret_key)
+            .unwrap()
+            .to_string();
+        let Json(response) = revoke_node_token(
+            state(&database),
+            Path(("node-1".to_string(), token.id)),
+            Json(SignedTokenRequest {
+                timestamp,
+                signature,
+            }),
+        )
+        .await
+        .unwrap();
+        assert!(response.revoked);
+
+        let (status, _) = get_status(&database, "/nodes/me", &token.token).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_registration_without_challenge_fails() {
+        let (database, public_key, _) = setup().await;
+        let response = register(&database, "node-1", &public_key, None).await;
+        assert!(!response.success);
+        assert!(!node_exists(&database, "node-1").await);
+    }
+
+    #[tokio::test]
+    async fn test_registration_with_valid_challenge_succeeds() {
+        let (database, public_key, secret_key) = setup().await;
+        let nonce = challenge(&database, &public_key).await;
+        let signature = sign(&nonce, &secret_key);
+
+        let response = register(&database, "node-1", &public_key, Some((&nonce, signature))).await;
+        assert!(response.success, "{}", response.message);
+        assert!(node_exists(&database, "node-1").await);
+    }
+
+    #[tokio::test]
+    async fn test_registration_rejects_wrong_key_signature() {
+        let (database, public_key, _) = setup().await;
+        let squatter = SignatureManager::new().generate_keypair().unwrap();
+        let nonce = challenge(&database, &public_key).await;
+        let signature = sign(&nonce, &squatter.secret_key);
+
+        let response = register(&database, "node-1", &public_key, Some((&nonce, signature))).await;
+        assert!(!response.success);
+        assert!(!node_exists(&database, "node-1").await);
+    }
+
+    #[tokio::test]
+    async fn test_expired_challenge_fails() {
+        let (database, public_key, secret_key) = setup().await;
+        let nonce = challenge(&database, &public_key).await;
+        sqlx::query("UPDATE registration_challenges SET expires_at = ? WHERE nonce = ?")
+            .bind(Utc::now() - chrono::Duration::seconds(1))
+            .bind(&nonce)
+            .execute(&database.get_sqlite_pool().unwrap())
+            .await
+            .unwrap();
+
+        let signature = sign(&nonce, &secret_key);
+        let response = register(&database, "node-1", &public_key, Some((&nonce, signature))).await;
+        assert!(!response.success);
+        assert!(response.message.contains("expired"));
+        assert!(!node_exists(&database, "node-1").await);
+    }
+
+    #[tokio::test]
+    async fn test_consumed_nonce_cannot_be_reused() {
+        let (database, public_key, secret_key) = setup().await;
+        let nonce = challenge(&database, &public_key).await;
+        let signature = sign(&nonce, &secret_key);
+
+        let first = register(
+            &database,
+            "node-1",
+            &public_key,
+            Some((&nonce, signature.clone())),
+        )
+        .await;
+        assert!(first.success, "{}", first.message);
+
+        let second = register(&database, "node-2", &public_key, Some((&nonce, signature))).await;
+        assert!(!second.success);
+        assert!(second.message.contains("already been used"));
+        assert!(!node_exists(&database, "node-2").await);
+    }
+
+    #[tokio::test]
+    async fn test_reregistration_with_other_key_conflicts() {
+        let (database, public_key, secret_key) = setup().await;
+        let owner_token = register_with_token(&database, "node-1", &secret_key, &public_key).await;
+
+        // A valid challenge for a different key cannot take over the node
+        let squatter = SignatureManager::new().generate_keypair().unwrap();
+        let squatter_public = squatter.public_key.to_string();
+        let nonce = challenge(&database, &squatter_public).await;
+        let (status, Json(response)) = register_node(
+            state(&database),
+            Extension(RegistrationVerifier::default()),
+            Json(RegisterNodeRequest {
+                node_id: "node-1".to_string(),
+                node_name: "Hijacked".to_string(),
+                node_type: "exchange".to_string(),
+                bitcoin_addresses: vec![],
+                metadata: None,
+                public_key: Some(squatter_public),
+                challenge_nonce: Some(nonce.clone()),
+                challenge_signature: Some(sign(&nonce, &squatter.secret_key)),
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(!response.success);
+        assert!(response.api_token.is_none());
+
+        let node = NodeRegistry::new(database.get_sqlite_pool().unwrap())
+            .get_node("node-1")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(node.node_name, "Test Node");
+        assert_eq!(node.public_key.as_deref(), Some(public_key.as_str()));
+        let (status, _) = get_status(&database, "/nodes/me", &owner_token.token).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // The owner can re-register with a fresh challenge
+        register_with_token(&database, "node-1", &secret_key, &public_key).await;
+    }
 }
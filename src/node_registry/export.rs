@@ -0,0 +1,284 @@
+//! Signed node registry export
+//!
+//! A reproducible snapshot of the registered nodes, written next to the
+//! monthly governance registry right before it is anchored, so the OTS proof
+//! commits to what is actually in the database. Nodes are sorted by id and
+//! fields that change without any registry action (`last_seen`, decayed
+//! weights, free-form metadata) are left out, so the same registry state and
+//! generation time always produce the same bytes. The digest of the header and
+//! nodes is signed (Schnorr, no auxiliary randomness) with the server's Nostr
+//! key or a dedicated registry key; [`verify_registry_file`] lets anyone holding
+//! the public key check it.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use secp256k1::{schnorr, Keypair, Message, Secp256k1, XOnlyPublicKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use tracing::info;
+
+use crate::node_registry::{NodeFilter, NodeRegistry};
+
+/// Current export schema version
+pub const REGISTRY_EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Export metadata covered by the signature
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryExportHeader {
+    pub schema_version: u32,
+    pub generated_at: DateTime<Utc>,
+    pub node_count: usize,
+}
+
+/// A registered node as exported
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedNode {
+    pub node_id: String,
+    pub node_name: String,
+    pub node_type: String,
+    /// Sorted
+    pub bitcoin_addresses: Vec<String>,
+    pub registered_at: DateTime<Utc>,
+    pub active: bool,
+    pub public_key: Option<String>,
+    pub deregistered_at: Option<DateTime<Utc>>,
+    pub verified_balance_btc: Option<f64>,
+}
+
+/// Signed registry export file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RegistryExport {
+    pub header: RegistryExportHeader,
+    pub nodes: Vec<ExportedNode>,
+    /// SHA-256 of the canonical JSON of `header` and `nodes`
+    pub digest: String,
+    /// x-only public key of the signer, hex
+    pub signer: String,
+    /// Schnorr signature over `digest`, hex
+    pub signature: String,
+}
+
+/// The signed part of an export, in canonical field order
+#[derive(Serialize)]
+struct SignedContent<'a> {
+    header: &'a RegistryExportHeader,
+    nodes: &'a [ExportedNode],
+}
+
+fn content_digest(header: &RegistryExportHeader, nodes: &[ExportedNode]) -> Result<[u8; 32]> {
+    let canonical = serde_json::to_vec(&SignedContent { header, nodes })
+        .map_err(|e| anyhow!("Failed to serialize registry export: {}", e))?;
+    Ok(Sha256::digest(&canonical).into())
+}
+
+/// Write a signed export of every registered node to `path`, atomically
+///
+/// `generated_at` goes in the header; exports of the same registry state with
+/// the same `generated_at` and key are byte-identical.
+pub async fn generate_registry_file(
+    pool: &SqlitePool,
+    path: &Path,
+    signing_keys: &nostr_sdk::Keys,
+    generated_at: DateTime<Utc>,
+) -> Result<RegistryExport> {
+    let secret = signing_keys
+        .secret_key()
+        .map_err(|e| anyhow!("Signing keys have no secret key: {}", e))?;
+    let keypair = Keypair::from_seckey_slice(&Secp256k1::new(), &secret.secret_bytes())
+        .map_err(|e| anyhow!("Invalid signing key: {}", e))?;
+
+    let registrations = NodeRegistry::new(pool.clone())
+        .list_nodes(&NodeFilter::default())
+        .await?
+        .items;
+    let mut nodes: Vec<ExportedNode> = registrations
+        .into_iter()
+        .map(|node| {
+            let mut bitcoin_addresses = node.bitcoin_addresses;
+            bitcoin_addresses.sort();
+            ExportedNode {
+                node_id: node.node_id,
+                node_name: node.node_name,
+                node_type: node.node_type.as_str().to_string(),
+                bitcoin_addresses,
+                registered_at: node.registered_at,
+                active: node.active,
+                public_key: node.public_key,
+                deregistered_at: node.deregistered_at,
+                verified_balance_btc: node.verified_balance_btc,
+            }
+        })
+        .collect();
+    nodes.sort_by(|a, b| a.node_id.cmp(&b.node_id));
+
+    let header = RegistryExportHeader {
+        schema_version: REGISTRY_EXPORT_SCHEMA_VERSION,
+        generated_at,
+        node_count: nodes.len(),
+    };
+    let digest = content_digest(&header, &nodes)?;
+    let signature =
+        Secp256k1::new().sign_schnorr_no_aux_rand(&Message::from_digest(digest), &keypair);
+    let export = RegistryExport {
+        header,
+        nodes,
+        digest: format!("sha256:{}", hex::encode(digest)),
+        signer: keypair.x_only_public_key().0.to_string(),
+        signature: signature.to_string(),
+    };
+
+    let mut json = serde_json::to_vec(&export)
+        .map_err(|e| anyhow!("Failed to serialize registry export: {}", e))?;
+    json.push(b'\n');
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| anyhow!("Failed to create directory: {}", e))?;
+    }
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, &json)
+        .map_err(|e| anyhow!("Failed to write {}: {}", tmp_path.display(), e))?;
+    fs::rename(&tmp_path, path)
+        .map_err(|e| anyhow!("Failed to replace {}: {}", path.display(), e))?;
+
+    info!(
+        "Exported {} registered nodes to {} ({})",
+        export.header.node_count,
+        path.display(),
+        export.digest
+    );
+    Ok(export)
+}
+
+/// Check a registry export's digest and its signature by `pubkey` (x-only, hex)
+///
+/// The signer recorded in the file is not trusted; the caller supplies the key
+/// it expects. Returns the export if it verifies.
+pub fn verify_registry_file(path: &Path, pubkey: &str) -> Result<RegistryExport> {
+    let pubkey = XOnlyPublicKey::from_str(pubkey)
+        .map_err(|e| anyhow!("Invalid public key {}: {}", pubkey, e))?;
+    let content =
+        fs::read(path).map_err(|e| anyhow!("Failed to read {}: {}", path.display(), e))?;
+    let export: RegistryExport = serde_json::from_slice(&content)
+        .map_err(|e| anyhow!("Invalid registry export {}: {}", path.display(), e))?;
+    if export.header.schema_version != REGISTRY_EXPORT_SCHEMA_VERSION {
+        return Err(anyhow!(
+            "Unsupported registry export schema version: {}",
+            export.header.schema_version
+        ));
+    }
+
+    let digest = content_digest(&export.header, &export.nodes)?;
+    if export.digest != format!("sha256:{}", hex::encode(digest)) {
+        return Err(anyhow!("Registry export digest does not match its content"));
+    }
+    let signature = schnorr::Signature::from_str(&export.signature)
+        .map_err(|e| anyhow!("Invalid registry export signature: {}", e))?;
+    Secp256k1::verification_only()
+        .verify_schnorr(&signature, &Message::from_digest(digest), &pubkey)
+        .map_err(|_| anyhow!("Registry export signature does not verify"))?;
+
+    Ok(export)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use tempfile::TempDir;
+
+    async fn seeded_pool() -> SqlitePool {
+        let pool = Database::new_in_memory()
+            .await
+            .unwrap()
+            .get_sqlite_pool()
+            .unwrap();
+        // Inserted out of id order, with volatile fields set
+        for (node_id, node_type, addresses) in [
+            ("pool-b", "pool", r#"["bc1qz", "bc1qa"]"#),
+            ("exchange-a", "exchange", r#"["bc1qx"]"#),
+        ] {
+            sqlx::query(
+                "INSERT INTO node_registry (node_id, node_name, node_type, bitcoin_addresses, effective_weight) VALUES (?, ?, ?, ?, 1.5)",
+            )
+            .bind(node_id)
+            .bind(node_id.to_uppercase())
+            .bind(node_type)
+            .bind(addresses)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_export_is_reproducible() {
+        let pool = seeded_pool().await;
+        let keys = nostr_sdk::Keys::generate();
+        let dir = TempDir::new().unwrap();
+        let generated_at = Utc::now();
+        let first = dir.path().join("first.json");
+        let second = dir.path().join("second.json");
+
+        let export = generate_registry_file(&pool, &first, &keys, generated_at)
+            .await
+            .unwrap();
+        let ids: Vec<&str> = export.nodes.iter().map(|n| n.node_id.as_str()).collect();
+        assert_eq!(ids, vec!["exchange-a", "pool-b"]);
+        assert_eq!(export.nodes[1].bitcoin_addresses, vec!["bc1qa", "bc1qz"]);
+
+        // Volatile fields change; the export doesn't
+        sqlx::query("UPDATE node_registry SET last_seen = ?, effective_weight = 0.5")
+            .bind(Utc::now() + chrono::Duration::hours(1))
+            .execute(&pool)
+            .await
+            .unwrap();
+        generate_registry_file(&pool, &second, &keys, generated_at)
+            .await
+            .unwrap();
+        assert_eq!(fs::read(&first).unwrap(), fs::read(&second).unwrap());
+        assert!(!dir.path().join("first.json.tmp").exists());
+    }
+
+    #[tokio::test]
+    async fn test_verify_detects_tampering() {
+        let pool = seeded_pool().await;
+        let keys = nostr_sdk::Keys::generate();
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("nodes.json");
+        let export = generate_registry_file(&pool, &path, &keys, Utc::now())
+            .await
+            .unwrap();
+
+        let verified = verify_registry_file(&path, &export.signer).unwrap();
+        assert_eq!(verified, export);
+
+        // Another key's signature doesn't verify
+        let other = nostr_sdk::Keys::generate();
+        let other_path = dir.path().join("other.json");
+        generate_registry_file(&pool, &other_path, &other, Utc::now())
+            .await
+            .unwrap();
+        assert!(verify_registry_file(&other_path, &export.signer).is_err());
+
+        // Editing a node and recomputing the digest still breaks the signature
+        let mut tampered = export.clone();
+        tampered.nodes[0].active = false;
+        tampered.digest = format!(
+            "sha256:{}",
+            hex::encode(content_digest(&tampered.header, &tampered.nodes).unwrap())
+        );
+        fs::write(&path, serde_json::to_vec(&tampered).unwrap()).unwrap();
+        let err = verify_registry_file(&path, &export.signer).unwrap_err();
+        assert!(err.to_string().contains("signature"));
+
+        // Editing without recomputing the digest is caught by the digest
+        tampered.digest = export.digest.clone();
+        fs::write(&path, serde_json::to_vec(&tampered).unwrap()).unwrap();
+        let err = verify_registry_file(&path, &export.signer).unwrap_err();
+        assert!(err.to_string().contains("digest"));
+    }
+}
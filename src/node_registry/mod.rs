@@ -3,13 +3,41 @@
 //! Simple registry system for nodes/miners to register for fee forwarding attribution.
 //! Nodes register with their Bitcoin addresses, and the system maps transactions to nodes.
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
+use secp256k1::{ecdsa::Signature, PublicKey};
 use serde::{Deserialize, Serialize};
-use sqlx::SqlitePool;
-use tracing::info;
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::crypto::address_proof::verify_address_signature;
+use crate::crypto::blockchain_verifier::{
+    verify_hashpower_proof, BlockchainVerifier, HashpowerProofStatus,
+};
+use crate::crypto::signatures::SignatureManager;
+use crate::database::slow_query::time_query;
+use crate::governance::{DecayConfig, WeightCalculator};
+use crate::validation::bitcoin_address::validate_bitcoin_address;
 
 pub mod api;
+pub mod export;
+pub mod tokens;
+
+/// Maximum age (seconds) of a signed deregistration, key rotation or token request
+const SIGNED_REQUEST_MAX_AGE_SECS: i64 = 300;
+
+/// How long (seconds) a registration challenge nonce can be used
+pub const REGISTRATION_CHALLENGE_TTL_SECS: i64 = 300;
+
+/// Default maximum age (seconds) of a holdings proof challenge
+pub const DEFAULT_HOLDINGS_PROOF_MAX_AGE_SECS: i64 = 3600;
+
+/// Fraction by which the measured balance may fall short of the claimed total
+const HOLDINGS_BALANCE_TOLERANCE: f64 = 0.01;
+
+const SATS_PER_BTC: f64 = 100_000_000.0;
 
 /// Node type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -43,6 +71,19 @@ impl NodeType {
     }
 }
 
+/// Registration of a node ID owned by another key (or by no proven key), or
+/// a change to a node whose key changed concurrently
+#[derive(Debug)]
+pub struct RegistrationConflict(pub String);
+
+impl std::fmt::Display for RegistrationConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for RegistrationConflict {}
+
 /// Node registration record
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeRegistration {
@@ -54,20 +95,270 @@ pub struct NodeRegistration {
     pub last_seen: DateTime<Utc>,
     pub active: bool,
     pub metadata: Option<serde_json::Value>,
+    /// Hex-encoded secp256k1 public key controlling this registration
+    pub public_key: Option<String>,
+    /// Set once the operator has voluntarily deregistered the node
+    pub deregistered_at: Option<DateTime<Utc>>,
+    /// On-chain balance measured when the holdings proof was last verified;
+    /// this is the node's base weight
+    pub verified_balance_btc: Option<f64>,
+    pub balance_verified_at: Option<DateTime<Utc>>,
+    /// Base weight after decay since `balance_verified_at` (see `recalculate_all_weights`)
+    pub effective_weight: Option<f64>,
+}
+
+/// Recorded rotation of a node's public key
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct NodeKeyRotation {
+    pub node_id: String,
+    pub old_public_key: String,
+    pub new_public_key: String,
+    pub rotated_at: DateTime<Utc>,
+}
+
+/// Hashpower proof carried in a miner/pool registration's metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HashpowerProof {
+    /// Hashes of blocks the pool claims to have mined
+    pub blocks_mined: Vec<String>,
+    /// Coinbase tag, payout address, or public key commitment identifying the pool
+    pub pool_tag: String,
+}
+
+/// Holdings proof carried in an exchange registration's metadata
+///
+/// Each address signs `holdings_proof:{node_id}:{timestamp}`, either with a
+/// legacy `signmessage` signature or a BIP-322 simple signature.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoldingsProof {
+    /// Addresses holding the claimed funds; must match the registered addresses
+    pub addresses: Vec<String>,
+    /// Claimed total balance across all addresses
+    pub total_btc: f64,
+    /// Unix timestamp included in the signed challenge
+    pub timestamp: i64,
+    /// Base64 signature for each address, in the same order
+    pub signatures: Vec<String>,
+}
+
+impl HoldingsProof {
+    /// Challenge message each address must sign
+    pub fn challenge_message(node_id: &str, timestamp: i64) -> String {
+        format!("holdings_proof:{}:{}", node_id, timestamp)
+    }
+}
+
+/// Single-use nonce a registration must sign to claim a public key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistrationChallenge {
+    /// 32 random bytes, hex
+    pub nonce: String,
+    pub public_key: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl RegistrationChallenge {
+    /// Message the public key must sign to use the nonce
+    pub fn challenge_message(nonce: &str) -> String {
+        format!("register:{}", nonce)
+    }
+}
+
+/// Outcome of verifying a holdings proof that passed its signature checks
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HoldingsProofStatus {
+    /// Measured on-chain balance covers the claimed total
+    Verified { balance_btc: f64 },
+    /// Signatures valid, but no blockchain verifier is configured to measure balances
+    Unmeasured,
+    /// The verification backend was unreachable; retry later
+    Pending,
+}
+
+/// Registration state used to filter node listings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeStatus {
+    Active,
+    /// Registered but inactive (e.g. proof verification pending)
+    Inactive,
+    Deregistered,
+}
+
+/// Filter and pagination for node listings
+#[derive(Debug, Clone, Default)]
+pub struct NodeFilter {
+    pub node_type: Option<NodeType>,
+    pub status: Option<NodeStatus>,
+    pub registered_after: Option<DateTime<Utc>>,
+    /// Case-insensitive substring match on the node name
+    pub search: Option<String>,
+    /// Maximum number of results (None = unlimited)
+    pub limit: Option<i64>,
+    pub offset: i64,
+}
+
+impl NodeFilter {
+    fn push_conditions(&self, query: &mut QueryBuilder<'_, Sqlite>) {
+        query.push(" WHERE 1 = 1");
+        if let Some(node_type) = self.node_type {
+            query
+                .push(" AND node_type = ")
+                .push_bind(node_type.as_str());
+        }
+        match self.status {
+            Some(NodeStatus::Active) => {
+                query.push(" AND active = TRUE");
+            }
+            Some(NodeStatus::Inactive) => {
+                query.push(" AND active = FALSE AND deregistered_at IS NULL");
+            }
+            Some(NodeStatus::Deregistered) => {
+                query.push(" AND deregistered_at IS NOT NULL");
+            }
+            None => {}
+        }
+        if let Some(registered_after) = self.registered_after {
+            query
+                .push(" AND datetime(registered_at) > datetime(")
+                .push_bind(registered_after.to_rfc3339())
+                .push(")");
+        }
+        if let Some(search) = self.search.as_deref().filter(|s| !s.is_empty()) {
+            let escaped = search
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            query
+                .push(" AND node_name LIKE ")
+                .push_bind(format!("%{}%", escaped))
+                .push(" ESCAPE '\\'");
+        }
+    }
+}
+
+/// One page of results plus the total number of matches
+#[derive(Debug, Clone, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub limit: Option<i64>,
+    pub offset: i64,
+}
+
+/// Columns selected into `NodeRow`
+const NODE_COLUMNS: &str = "node_id, node_name, node_type, bitcoin_addresses, registered_at, last_seen, active, metadata, public_key, deregistered_at, verified_balance_btc, balance_verified_at, effective_weight";
+
+#[derive(sqlx::FromRow)]
+struct NodeRow {
+    node_id: String,
+    node_name: String,
+    node_type: String,
+    bitcoin_addresses: String,
+    registered_at: DateTime<Utc>,
+    last_seen: DateTime<Utc>,
+    active: bool,
+    metadata: Option<String>,
+    public_key: Option<String>,
+    deregistered_at: Option<DateTime<Utc>>,
+    verified_balance_btc: Option<f64>,
+    balance_verified_at: Option<DateTime<Utc>>,
+    effective_weight: Option<f64>,
+}
+
+impl NodeRow {
+    fn into_registration(self) -> Result<NodeRegistration> {
+        let addresses: Vec<String> = serde_json::from_str(&self.bitcoin_addresses)?;
+        let metadata = self
+            .metadata
+            .as_ref()
+            .and_then(|m| serde_json::from_str(m).ok());
+
+        Ok(NodeRegistration {
+            node_id: self.node_id,
+            node_name: self.node_name,
+            node_type: NodeType::from_str(&self.node_type),
+            bitcoin_addresses: addresses,
+            registered_at: self.registered_at,
+            last_seen: self.last_seen,
+            active: self.active,
+            metadata,
+            public_key: self.public_key,
+            deregistered_at: self.deregistered_at,
+            verified_balance_btc: self.verified_balance_btc,
+            balance_verified_at: self.balance_verified_at,
+            effective_weight: self.effective_weight,
+        })
+    }
 }
 
 /// Node registry manager
 pub struct NodeRegistry {
     pool: SqlitePool,
+    blockchain_verifier: Option<Arc<dyn BlockchainVerifier>>,
+    holdings_proof_max_age_secs: i64,
+    /// Network registered addresses must belong to
+    network: bitcoin::Network,
 }
 
 impl NodeRegistry {
     /// Create a new node registry
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            blockchain_verifier: None,
+            holdings_proof_max_age_secs: DEFAULT_HOLDINGS_PROOF_MAX_AGE_SECS,
+            network: bitcoin::Network::Bitcoin,
+        }
+    }
+
+    /// Create a node registry that verifies hashpower proofs against the blockchain
+    pub fn with_blockchain_verifier(
+        pool: SqlitePool,
+        blockchain_verifier: Arc<dyn BlockchainVerifier>,
+    ) -> Self {
+        Self {
+            pool,
+            blockchain_verifier: Some(blockchain_verifier),
+            holdings_proof_max_age_secs: DEFAULT_HOLDINGS_PROOF_MAX_AGE_SECS,
+            network: bitcoin::Network::Bitcoin,
+        }
+    }
+
+    /// Override how old a holdings proof challenge may be
+    pub fn with_holdings_proof_max_age(mut self, max_age_secs: i64) -> Self {
+        self.holdings_proof_max_age_secs = max_age_secs;
+        self
     }
 
-    /// Register a new node
+    /// Accept addresses for `network` instead of mainnet
+    pub fn with_network(mut self, network: bitcoin::Network) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Reject any address that isn't a well-formed address for the registry's network
+    fn validate_addresses(&self, addresses: &[String]) -> Result<()> {
+        for address in addresses {
+            validate_bitcoin_address(address, self.network)
+                .map_err(|e| anyhow!("Invalid Bitcoin address {}: {}", address, e))?;
+        }
+        Ok(())
+    }
+
+    /// Register a new node, or re-register one with its stored key
+    ///
+    /// Callers must have proven control of `public_key` (a registration
+    /// challenge, or the node's API token). An existing node can only be
+    /// re-registered with the key it already has; otherwise this fails with
+    /// [`RegistrationConflict`], so nobody can claim or rewrite another
+    /// operator's node.
+    ///
+    /// Returns whether the node is active. Miners and pools without a
+    /// hashpower proof are stored inactive, as are miners and pools whose
+    /// hashpower proof, and exchanges whose holdings proof, could not be
+    /// checked because the blockchain backend was unreachable; they stay
+    /// inactive until they re-register with a proof that verifies.
     pub async fn register_node(
         &self,
         node_id: &str,
@@ -75,37 +366,161 @@ impl NodeRegistry {
         node_type: NodeType,
         bitcoin_addresses: Vec<String>,
         metadata: Option<serde_json::Value>,
-    ) -> Result<()> {
-        // Insert or update node registration
-        sqlx::query(
-            r#"
+        public_key: Option<&str>,
+    ) -> Result<bool> {
+        if let Some(public_key) = public_key {
+            PublicKey::from_str(public_key).map_err(|e| anyhow!("Invalid public key: {}", e))?;
+        }
+        self.validate_addresses(&bitcoin_addresses)?;
+
+        // A deregistered node ID cannot be reused, and a registered one only
+        // by the holder of its key
+        let sql =
+            "SELECT deregistered_at IS NOT NULL, public_key FROM node_registry WHERE node_id = ?";
+        let timing = time_query(sql);
+        let existing: Option<(bool, Option<String>)> = sqlx::query_as(sql)
+            .bind(node_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        timing.finish(existing.is_some() as u64);
+        if let Some((deregistered, stored_key)) = &existing {
+            if *deregistered {
+                return Err(anyhow!("Node {} has been deregistered", node_id));
+            }
+            match (stored_key.as_deref(), public_key) {
+                (Some(stored), Some(presented)) if same_public_key(stored, presented) => {}
+                (Some(_), Some(_)) => {
+                    return Err(RegistrationConflict(format!(
+                        "Node {} is registered to a different public key",
+                        node_id
+                    ))
+                    .into());
+                }
+                _ => {
+                    return Err(RegistrationConflict(format!(
+                        "Node {} is already registered; re-registering requires a challenge signed by its key",
+                        node_id
+                    ))
+                    .into());
+                }
+            }
+        }
+
+        // Miners and pools claiming hashpower must back it with mined blocks
+        let mut active = true;
+        if matches!(node_type, NodeType::Miner | NodeType::Pool) {
+            match metadata.as_ref().and_then(|m| m.get("hashpower_proof")) {
+                Some(proof) => {
+                    let proof: HashpowerProof = serde_json::from_value(proof.clone())
+                        .map_err(|e| anyhow!("Invalid hashpower proof: {}", e))?;
+                    match self.verify_hashpower_proof(&proof).await {
+                        HashpowerProofStatus::Verified => {}
+                        HashpowerProofStatus::Rejected => {
+                            return Err(anyhow!(
+                                "Hashpower proof verification failed for pool tag {}",
+                                proof.pool_tag
+                            ));
+                        }
+                        HashpowerProofStatus::Pending => {
+                            warn!(
+                                "Hashpower proof for node {} could not be verified yet - registering inactive",
+                                node_id
+                            );
+                            active = false;
+                        }
+                    }
+                }
+                None => {
+                    warn!(
+                        "Node {} registered as {} without a hashpower proof - registering inactive",
+                        node_id,
+                        node_type.as_str()
+                    );
+                    active = false;
+                }
+            }
+        }
+
+        // Exchanges claiming holdings must prove control and on-chain balance
+        let mut verified_balance_btc = None;
+        if node_type == NodeType::Exchange {
+            if let Some(proof) = metadata.as_ref().and_then(|m| m.get("holdings_proof")) {
+                let proof: HoldingsProof = serde_json::from_value(proof.clone())
+                    .map_err(|e| anyhow!("Invalid holdings proof: {}", e))?;
+                match self
+                    .verify_holdings_proof(node_id, &bitcoin_addresses, &proof)
+                    .await?
+                {
+                    HoldingsProofStatus::Verified { balance_btc } => {
+                        verified_balance_btc = Some(balance_btc);
+                    }
+                    HoldingsProofStatus::Unmeasured => {}
+                    HoldingsProofStatus::Pending => {
+                        warn!(
+                            "Holdings proof for node {} could not be verified yet - registering inactive",
+                            node_id
+                        );
+                        active = false;
+                    }
+                }
+            }
+        }
+
+        // Insert or update node registration. The owner check above ran
+        // before proof verification, so the update only applies while the row
+        // still has the key it checked against; a concurrent registration or
+        // key rotation makes this a conflict instead of an overwrite.
+        let stored_key = existing.and_then(|(_, stored_key)| stored_key);
+        let mut tx = self.pool.begin().await?;
+        let sql = r#"
             INSERT INTO node_registry
-            (node_id, node_name, node_type, bitcoin_addresses, metadata, active, last_seen)
-            VALUES (?, ?, ?, ?, ?, TRUE, CURRENT_TIMESTAMP)
+            (node_id, node_name, node_type, bitcoin_addresses, metadata, public_key, active,
+             verified_balance_btc, balance_verified_at, effective_weight, last_seen)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, CASE WHEN ? IS NULL THEN NULL ELSE CURRENT_TIMESTAMP END, ?, CURRENT_TIMESTAMP)
             ON CONFLICT(node_id) DO UPDATE SET
                 node_name = excluded.node_name,
                 node_type = excluded.node_type,
                 bitcoin_addresses = excluded.bitcoin_addresses,
                 metadata = excluded.metadata,
-                active = TRUE,
+                active = excluded.active,
+                verified_balance_btc = excluded.verified_balance_btc,
+                balance_verified_at = excluded.balance_verified_at,
+                effective_weight = excluded.effective_weight,
                 last_seen = CURRENT_TIMESTAMP
-            "#,
-        )
-        .bind(node_id)
-        .bind(node_name)
-        .bind(node_type.as_str())
-        .bind(serde_json::to_string(&bitcoin_addresses)?)
-        .bind(
-            metadata
-                .as_ref()
-                .map(|m| serde_json::to_string(m).unwrap_or_default()),
-        )
-        .execute(&self.pool)
-        .await?;
+            WHERE node_registry.deregistered_at IS NULL
+              AND node_registry.public_key = ?
+            "#;
+        let timing = time_query(sql);
+        let result = sqlx::query(sql)
+            .bind(node_id)
+            .bind(node_name)
+            .bind(node_type.as_str())
+            .bind(serde_json::to_string(&bitcoin_addresses)?)
+            .bind(
+                metadata
+                    .as_ref()
+                    .map(|m| serde_json::to_string(m).unwrap_or_default()),
+            )
+            .bind(public_key)
+            .bind(active)
+            .bind(verified_balance_btc)
+            .bind(verified_balance_btc)
+            .bind(verified_balance_btc)
+            .bind(stored_key.as_deref())
+            .execute(&mut *tx)
+            .await?;
+        timing.finish(result.rows_affected());
+        if result.rows_affected() != 1 {
+            return Err(RegistrationConflict(format!(
+                "Node {} was registered or changed concurrently",
+                node_id
+            ))
+            .into());
+        }
 
         // Update address mappings
-        self.update_address_mappings(node_id, &bitcoin_addresses)
-            .await?;
+        Self::update_address_mappings(&mut tx, node_id, &bitcoin_addresses).await?;
+        tx.commit().await?;
 
         info!(
             "Registered node: {} ({}) with {} addresses",
@@ -113,24 +528,246 @@ impl NodeRegistry {
             node_name,
             bitcoin_addresses.len()
         );
+        Ok(active)
+    }
+
+    /// Issue a registration challenge for a public key
+    ///
+    /// A registration claiming `public_key` must sign
+    /// `register:{nonce}` with it, within [`REGISTRATION_CHALLENGE_TTL_SECS`].
+    pub async fn create_registration_challenge(
+        &self,
+        public_key: &str,
+    ) -> Result<RegistrationChallenge> {
+        PublicKey::from_str(public_key).map_err(|e| anyhow!("Invalid public key: {}", e))?;
+
+        let now = Utc::now();
+        let sql = "DELETE FROM registration_challenges WHERE expires_at < ?";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql).bind(now).execute(&self.pool).await?;
+        timing.finish(result.rows_affected());
+
+        let challenge = RegistrationChallenge {
+            nonce: hex::encode(rand::random::<[u8; 32]>()),
+            public_key: public_key.to_string(),
+            expires_at: now + chrono::Duration::seconds(REGISTRATION_CHALLENGE_TTL_SECS),
+        };
+        let sql =
+            "INSERT INTO registration_challenges (nonce, public_key, expires_at) VALUES (?, ?, ?)";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql)
+            .bind(&challenge.nonce)
+            .bind(&challenge.public_key)
+            .bind(challenge.expires_at)
+            .execute(&self.pool)
+            .await?;
+        timing.finish(result.rows_affected());
+        Ok(challenge)
+    }
+
+    /// Use up a registration challenge, proving control of `public_key`
+    ///
+    /// Fails if the nonce is unknown, was issued for another key, has expired
+    /// or been used, or `signature` isn't the key's signature over it.
+    pub async fn consume_registration_challenge(
+        &self,
+        public_key: &str,
+        nonce: &str,
+        signature: &str,
+    ) -> Result<()> {
+        let sql = "SELECT public_key, expires_at, consumed_at FROM registration_challenges WHERE nonce = ?";
+        let timing = time_query(sql);
+        let challenge: Option<(String, DateTime<Utc>, Option<DateTime<Utc>>)> = sqlx::query_as(sql)
+            .bind(nonce)
+            .fetch_optional(&self.pool)
+            .await?;
+        timing.finish(challenge.is_some() as u64);
+        let Some((issued_for, expires_at, consumed_at)) = challenge else {
+            return Err(anyhow!("Unknown registration challenge"));
+        };
+        if issued_for != public_key {
+            return Err(anyhow!(
+                "Registration challenge was issued for a different public key"
+            ));
+        }
+        if consumed_at.is_some() {
+            return Err(anyhow!("Registration challenge has already been used"));
+        }
+        if expires_at <= Utc::now() {
+            return Err(anyhow!("Registration challenge has expired"));
+        }
+        let message = RegistrationChallenge::challenge_message(nonce);
+        if !verify_node_signature(&message, signature, public_key)? {
+            return Err(anyhow!("Invalid registration challenge signature"));
+        }
+
+        // Two registrations racing on one nonce: only the first consumes it
+        let sql = "UPDATE registration_challenges SET consumed_at = ? WHERE nonce = ? AND consumed_at IS NULL";
+        let timing = time_query(sql);
+        let consumed = sqlx::query(sql)
+            .bind(Utc::now())
+            .bind(nonce)
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+        timing.finish(consumed);
+        if consumed == 0 {
+            return Err(anyhow!("Registration challenge has already been used"));
+        }
         Ok(())
     }
 
+    /// Delete registrations still pending verification that haven't been
+    /// seen since `cutoff`
+    ///
+    /// Frees the node IDs and public keys they were holding. Returns the
+    /// number of nodes removed.
+    pub async fn purge_pending_nodes(&self, cutoff: DateTime<Utc>) -> Result<u64> {
+        const PENDING: &str =
+            "active = FALSE AND deregistered_at IS NULL AND datetime(last_seen) < datetime(?)";
+
+        let mut tx = self.pool.begin().await?;
+        let sql = format!(
+            "DELETE FROM address_to_node WHERE node_id IN (SELECT node_id FROM node_registry WHERE {})",
+            PENDING
+        );
+        let timing = time_query(&sql);
+        let result = sqlx::query(&sql)
+            .bind(cutoff.to_rfc3339())
+            .execute(&mut *tx)
+            .await?;
+        timing.finish(result.rows_affected());
+        let sql = format!("DELETE FROM node_registry WHERE {}", PENDING);
+        let timing = time_query(&sql);
+        let purged = sqlx::query(&sql)
+            .bind(cutoff.to_rfc3339())
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        timing.finish(purged);
+        tx.commit().await?;
+
+        if purged > 0 {
+            info!("Purged {} pending node registrations", purged);
+        }
+        Ok(purged)
+    }
+
+    /// Verify a hashpower proof's blocks exist on chain and are attributable to the pool
+    ///
+    /// Falls back to structural validation when no blockchain verifier is configured.
+    pub async fn verify_hashpower_proof(&self, proof: &HashpowerProof) -> HashpowerProofStatus {
+        verify_hashpower_proof(
+            self.blockchain_verifier.as_deref(),
+            &proof.blocks_mined,
+            &proof.pool_tag,
+        )
+        .await
+    }
+
+    /// Verify a holdings proof
+    ///
+    /// Rejects (with an error) proofs whose challenge timestamp is outside the
+    /// freshness window, whose addresses differ from the registered addresses,
+    /// whose signatures don't verify, or whose measured balance falls short of
+    /// the claimed total by more than the tolerance.
+    pub async fn verify_holdings_proof(
+        &self,
+        node_id: &str,
+        registered_addresses: &[String],
+        proof: &HoldingsProof,
+    ) -> Result<HoldingsProofStatus> {
+        let age = Utc::now().timestamp() - proof.timestamp;
+        if age < -SIGNED_REQUEST_MAX_AGE_SECS || age > self.holdings_proof_max_age_secs {
+            return Err(anyhow!(
+                "Holdings proof timestamp is outside the {}s freshness window",
+                self.holdings_proof_max_age_secs
+            ));
+        }
+
+        let mut claimed: Vec<&String> = proof.addresses.iter().collect();
+        let mut registered: Vec<&String> = registered_addresses.iter().collect();
+        claimed.sort();
+        claimed.dedup();
+        registered.sort();
+        registered.dedup();
+        if claimed.is_empty() || claimed != registered {
+            return Err(anyhow!(
+                "Holdings proof addresses do not match the registered addresses"
+            ));
+        }
+        self.validate_addresses(&proof.addresses)?;
+        if proof.signatures.len() != proof.addresses.len() {
+            return Err(anyhow!(
+                "Holdings proof has {} signatures for {} addresses",
+                proof.signatures.len(),
+                proof.addresses.len()
+            ));
+        }
+
+        let message = HoldingsProof::challenge_message(node_id, proof.timestamp);
+        for (address, signature) in proof.addresses.iter().zip(&proof.signatures) {
+            if !verify_address_signature(address, &message, signature)? {
+                return Err(anyhow!("Invalid holdings proof signature for {}", address));
+            }
+        }
+
+        let verifier = match &self.blockchain_verifier {
+            Some(verifier) => verifier,
+            None => {
+                warn!("No blockchain verifier configured - holdings balance not measured");
+                return Ok(HoldingsProofStatus::Unmeasured);
+            }
+        };
+
+        let mut balance_sats = 0u64;
+        for address in claimed {
+            match verifier.get_address_balance(address).await {
+                Ok(balance) => balance_sats += balance,
+                Err(e) => {
+                    warn!(
+                        "Blockchain verification unavailable ({}) - holdings proof left pending",
+                        e
+                    );
+                    return Ok(HoldingsProofStatus::Pending);
+                }
+            }
+        }
+
+        let balance_btc = balance_sats as f64 / SATS_PER_BTC;
+        if balance_btc < proof.total_btc * (1.0 - HOLDINGS_BALANCE_TOLERANCE) {
+            return Err(anyhow!(
+                "Measured balance {} BTC is below claimed holdings of {} BTC",
+                balance_btc,
+                proof.total_btc
+            ));
+        }
+
+        Ok(HoldingsProofStatus::Verified { balance_btc })
+    }
+
     /// Update address mappings for a node
-    async fn update_address_mappings(&self, node_id: &str, addresses: &[String]) -> Result<()> {
+    async fn update_address_mappings(
+        tx: &mut sqlx::Transaction<'_, Sqlite>,
+        node_id: &str,
+        addresses: &[String],
+    ) -> Result<()> {
         // Delete old mappings
-        sqlx::query("DELETE FROM address_to_node WHERE node_id = ?")
-            .bind(node_id)
-            .execute(&self.pool)
-            .await?;
+        let sql = "DELETE FROM address_to_node WHERE node_id = ?";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql).bind(node_id).execute(&mut **tx).await?;
+        timing.finish(result.rows_affected());
 
         // Insert new mappings
         for address in addresses {
-            sqlx::query("INSERT OR REPLACE INTO address_to_node (address, node_id) VALUES (?, ?)")
+            let sql = "INSERT OR REPLACE INTO address_to_node (address, node_id) VALUES (?, ?)";
+            let timing = time_query(sql);
+            let result = sqlx::query(sql)
                 .bind(address)
                 .bind(node_id)
-                .execute(&self.pool)
+                .execute(&mut **tx)
                 .await?;
+            timing.finish(result.rows_affected());
         }
 
         Ok(())
@@ -138,117 +775,1280 @@ impl NodeRegistry {
 
     /// Get node ID for a Bitcoin address
     pub async fn get_node_for_address(&self, address: &str) -> Result<Option<String>> {
-        let node_id: Option<String> =
-            sqlx::query_scalar("SELECT node_id FROM address_to_node WHERE address = ?")
-                .bind(address)
-                .fetch_optional(&self.pool)
-                .await?;
+        let sql = "SELECT node_id FROM address_to_node WHERE address = ?";
+        let timing = time_query(sql);
+        let node_id: Option<String> = sqlx::query_scalar(sql)
+            .bind(address)
+            .fetch_optional(&self.pool)
+            .await?;
+        timing.finish(node_id.is_some() as u64);
 
         Ok(node_id)
     }
 
     /// Get node registration by ID
     pub async fn get_node(&self, node_id: &str) -> Result<Option<NodeRegistration>> {
-        #[derive(sqlx::FromRow)]
-        struct NodeRow {
-            node_id: String,
-            node_name: String,
-            node_type: String,
-            bitcoin_addresses: String,
-            registered_at: DateTime<Utc>,
-            last_seen: DateTime<Utc>,
-            active: bool,
-            metadata: Option<String>,
-        }
-
-        let row: Option<NodeRow> = sqlx::query_as::<_, NodeRow>(
-            "SELECT node_id, node_name, node_type, bitcoin_addresses, registered_at, last_seen, active, metadata FROM node_registry WHERE node_id = ?"
-        )
-        .bind(node_id)
-        .fetch_optional(&self.pool)
-        .await?;
-
-        if let Some(row) = row {
-            let addresses: Vec<String> = serde_json::from_str(&row.bitcoin_addresses)?;
-            let metadata = row
-                .metadata
-                .as_ref()
-                .and_then(|m| serde_json::from_str(m).ok());
-
-            Ok(Some(NodeRegistration {
-                node_id: row.node_id,
-                node_name: row.node_name,
-                node_type: NodeType::from_str(&row.node_type),
-                bitcoin_addresses: addresses,
-                registered_at: row.registered_at,
-                last_seen: row.last_seen,
-                active: row.active,
-                metadata,
-            }))
-        } else {
-            Ok(None)
-        }
+        let _timer = crate::metrics::query_timer("node_registry.get_node");
+        let sql = format!(
+            "SELECT {} FROM node_registry WHERE node_id = ?",
+            NODE_COLUMNS
+        );
+        let timing = time_query(&sql);
+        let row: Option<NodeRow> = sqlx::query_as::<_, NodeRow>(&sql)
+            .bind(node_id)
+            .fetch_optional(&self.pool)
+            .await?;
+        timing.finish(row.is_some() as u64);
+
+        row.map(NodeRow::into_registration).transpose()
     }
 
     /// Update last seen timestamp for a node
     pub async fn update_last_seen(&self, node_id: &str) -> Result<()> {
-        sqlx::query("UPDATE node_registry SET last_seen = CURRENT_TIMESTAMP WHERE node_id = ?")
-            .bind(node_id)
-            .execute(&self.pool)
-            .await?;
+        let sql = "UPDATE node_registry SET last_seen = CURRENT_TIMESTAMP WHERE node_id = ?";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql).bind(node_id).execute(&self.pool).await?;
+        timing.finish(result.rows_affected());
         Ok(())
     }
 
     /// Deactivate a node
     pub async fn deactivate_node(&self, node_id: &str) -> Result<()> {
-        sqlx::query("UPDATE node_registry SET active = FALSE WHERE node_id = ?")
+        let sql = "UPDATE node_registry SET active = FALSE WHERE node_id = ?";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql).bind(node_id).execute(&self.pool).await?;
+        timing.finish(result.rows_affected());
+        info!("Deactivated node: {}", node_id);
+        Ok(())
+    }
+
+    /// Check a request signed by a node's current key over `message`
+    ///
+    /// `message` must embed `timestamp`, which has to be within
+    /// [`SIGNED_REQUEST_MAX_AGE_SECS`] of now.
+    pub async fn verify_signed_request(
+        &self,
+        node_id: &str,
+        message: &str,
+        timestamp: i64,
+        signature: &str,
+    ) -> Result<()> {
+        let node = self
+            .get_node(node_id)
+            .await?
+            .ok_or_else(|| anyhow!("Node {} not found", node_id))?;
+        if node.deregistered_at.is_some() {
+            return Err(anyhow!("Node {} has been deregistered", node_id));
+        }
+        let public_key = node
+            .public_key
+            .ok_or_else(|| anyhow!("Node {} has no registered public key", node_id))?;
+
+        check_request_timestamp(timestamp)?;
+        if !verify_node_signature(message, signature, &public_key)? {
+            return Err(anyhow!("Invalid signature for node {}", node_id));
+        }
+        Ok(())
+    }
+
+    /// Voluntarily deregister a node
+    ///
+    /// Requires a signature by the node's current key over
+    /// `deregister:{node_id}:{timestamp}`, with `timestamp` in Unix seconds.
+    pub async fn deregister_node(
+        &self,
+        node_id: &str,
+        timestamp: i64,
+        signature: &str,
+    ) -> Result<()> {
+        let node = self
+            .get_node(node_id)
+            .await?
+            .ok_or_else(|| anyhow!("Node {} not found", node_id))?;
+        if node.deregistered_at.is_some() {
+            return Err(anyhow!("Node {} is already deregistered", node_id));
+        }
+        let public_key = node
+            .public_key
+            .ok_or_else(|| anyhow!("Node {} has no registered public key", node_id))?;
+
+        check_request_timestamp(timestamp)?;
+        let message = format!("deregister:{}:{}", node_id, timestamp);
+        if !verify_node_signature(&message, signature, &public_key)? {
+            return Err(anyhow!(
+                "Invalid deregistration signature for node {}",
+                node_id
+            ));
+        }
+
+        let sql = "UPDATE node_registry SET active = FALSE, deregistered_at = CURRENT_TIMESTAMP WHERE node_id = ?";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql).bind(node_id).execute(&self.pool).await?;
+        timing.finish(result.rows_affected());
+
+        info!("Deregistered node: {}", node_id);
+        Ok(())
+    }
+
+    /// Rotate a node's public key
+    ///
+    /// Both the current and the new key must sign
+    /// `rotate_key:{node_id}:{new_public_key}:{timestamp}`. Previous keys are
+    /// kept in `node_key_rotations` so actions signed under them stay attributable,
+    /// and cannot be rotated back into use.
+    pub async fn rotate_public_key(
+        &self,
+        node_id: &str,
+        new_public_key: &str,
+        timestamp: i64,
+        old_key_signature: &str,
+        new_key_signature: &str,
+    ) -> Result<()> {
+        let node = self
+            .get_node(node_id)
+            .await?
+            .ok_or_else(|| anyhow!("Node {} not found", node_id))?;
+        if node.deregistered_at.is_some() {
+            return Err(anyhow!("Node {} has been deregistered", node_id));
+        }
+        let old_public_key = node
+            .public_key
+            .ok_or_else(|| anyhow!("Node {} has no registered public key", node_id))?;
+
+        PublicKey::from_str(new_public_key).map_err(|e| anyhow!("Invalid public key: {}", e))?;
+        if new_public_key == old_public_key {
+            return Err(anyhow!("New public key matches the current key"));
+        }
+        let sql =
+            "SELECT COUNT(*) FROM node_key_rotations WHERE node_id = ? AND old_public_key = ?";
+        let timing = time_query(sql);
+        let previously_used: i64 = sqlx::query_scalar(sql)
             .bind(node_id)
-            .execute(&self.pool)
+            .bind(new_public_key)
+            .fetch_one(&self.pool)
             .await?;
-        info!("Deactivated node: {}", node_id);
+        timing.finish(1);
+        if previously_used > 0 {
+            return Err(anyhow!(
+                "Public key was previously rotated out for node {}",
+                node_id
+            ));
+        }
+
+        check_request_timestamp(timestamp)?;
+        let message = format!("rotate_key:{}:{}:{}", node_id, new_public_key, timestamp);
+        if !verify_node_signature(&message, old_key_signature, &old_public_key)? {
+            return Err(anyhow!(
+                "Invalid signature from current key for node {}",
+                node_id
+            ));
+        }
+        if !verify_node_signature(&message, new_key_signature, new_public_key)? {
+            return Err(anyhow!(
+                "Invalid signature from new key for node {}",
+                node_id
+            ));
+        }
+
+        let mut tx = self.pool.begin().await?;
+        let sql = "UPDATE node_registry SET public_key = ? WHERE node_id = ? AND public_key = ? AND deregistered_at IS NULL";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql)
+            .bind(new_public_key)
+            .bind(node_id)
+            .bind(&old_public_key)
+            .execute(&mut *tx)
+            .await?;
+        timing.finish(result.rows_affected());
+        // The key was rotated or the node deregistered since it was read
+        if result.rows_affected() != 1 {
+            return Err(RegistrationConflict(format!(
+                "Public key for node {} changed concurrently",
+                node_id
+            ))
+            .into());
+        }
+        let sql = "INSERT INTO node_key_rotations (node_id, old_public_key, new_public_key) VALUES (?, ?, ?)";
+        let timing = time_query(sql);
+        let result = sqlx::query(sql)
+            .bind(node_id)
+            .bind(&old_public_key)
+            .bind(new_public_key)
+            .execute(&mut *tx)
+            .await?;
+        timing.finish(result.rows_affected());
+        tx.commit().await?;
+
+        info!("Rotated public key for node: {}", node_id);
         Ok(())
     }
 
+    /// Get the key rotation history for a node, oldest first
+    pub async fn get_key_rotations(&self, node_id: &str) -> Result<Vec<NodeKeyRotation>> {
+        let sql = "SELECT node_id, old_public_key, new_public_key, rotated_at FROM node_key_rotations WHERE node_id = ? ORDER BY id";
+        let timing = time_query(sql);
+        let rotations = sqlx::query_as::<_, NodeKeyRotation>(sql)
+            .bind(node_id)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(rotations.len() as u64);
+        Ok(rotations)
+    }
+
+    /// Find the node that owns, or previously owned, a public key
+    pub async fn get_node_for_public_key(&self, public_key: &str) -> Result<Option<String>> {
+        let _timer = crate::metrics::query_timer("node_registry.get_node_for_public_key");
+        let sql = r#"
+            SELECT node_id FROM node_registry WHERE public_key = ?
+            UNION
+            SELECT node_id FROM node_key_rotations WHERE old_public_key = ?
+            LIMIT 1
+            "#;
+        let timing = time_query(sql);
+        let node_id: Option<String> = sqlx::query_scalar(sql)
+            .bind(public_key)
+            .bind(public_key)
+            .fetch_optional(&self.pool)
+            .await?;
+        timing.finish(node_id.is_some() as u64);
+        Ok(node_id)
+    }
+
+    /// Recompute every node's effective weight from its base weight
+    ///
+    /// Weight halves every `decay_config.half_life_days` since the node last
+    /// verified its holdings; re-verifying resets the clock. The base weight
+    /// is left untouched. Returns the number of nodes updated.
+    pub async fn recalculate_all_weights(&self, decay_config: &DecayConfig) -> Result<usize> {
+        let sql = "SELECT node_id, verified_balance_btc, balance_verified_at FROM node_registry WHERE verified_balance_btc IS NOT NULL AND balance_verified_at IS NOT NULL";
+        let timing = time_query(sql);
+        let rows: Vec<(String, f64, DateTime<Utc>)> =
+            sqlx::query_as(sql).fetch_all(&self.pool).await?;
+        timing.finish(rows.len() as u64);
+
+        let mut tx = self.pool.begin().await?;
+        for (node_id, base_weight, verified_at) in &rows {
+            let effective_weight =
+                WeightCalculator::apply_decay(*base_weight, *verified_at, decay_config);
+            let sql = "UPDATE node_registry SET effective_weight = ? WHERE node_id = ?";
+            let timing = time_query(sql);
+            let result = sqlx::query(sql)
+                .bind(effective_weight)
+                .bind(node_id)
+                .execute(&mut *tx)
+                .await?;
+            timing.finish(result.rows_affected());
+        }
+        tx.commit().await?;
+
+        info!("Recalculated effective weight for {} nodes", rows.len());
+        Ok(rows.len())
+    }
+
     /// Get all active nodes
     pub async fn get_active_nodes(&self) -> Result<Vec<NodeRegistration>> {
-        #[derive(sqlx::FromRow)]
-        struct NodeRow {
-            node_id: String,
-            node_name: String,
-            node_type: String,
-            bitcoin_addresses: String,
-            registered_at: DateTime<Utc>,
-            last_seen: DateTime<Utc>,
-            active: bool,
-            metadata: Option<String>,
-        }
-
-        let rows: Vec<NodeRow> = sqlx::query_as::<_, NodeRow>(
-            "SELECT node_id, node_name, node_type, bitcoin_addresses, registered_at, last_seen, active, metadata FROM node_registry WHERE active = TRUE ORDER BY node_name"
+        let page = self
+            .list_nodes(&NodeFilter {
+                status: Some(NodeStatus::Active),
+                ..Default::default()
+            })
+            .await?;
+        Ok(page.items)
+    }
+
+    /// Registered nodes counted by `(node_type, status)`
+    pub async fn count_by_type_and_status(&self) -> Result<Vec<(String, String, i64)>> {
+        let sql = r#"
+            SELECT node_type,
+                   CASE
+                       WHEN deregistered_at IS NOT NULL THEN 'deregistered'
+                       WHEN active THEN 'active'
+                       ELSE 'inactive'
+                   END AS status,
+                   COUNT(*)
+            FROM node_registry
+            GROUP BY 1, 2
+            "#;
+        let timing = time_query(sql);
+        let counts: Vec<(String, String, i64)> = sqlx::query_as(sql).fetch_all(&self.pool).await?;
+        timing.finish(counts.len() as u64);
+        Ok(counts)
+    }
+
+    /// Active nodes counted by `(node_type, count, total effective weight)`
+    pub async fn active_weight_by_type(&self) -> Result<Vec<(String, i64, f64)>> {
+        let sql = r#"
+            SELECT node_type, COUNT(*), COALESCE(SUM(effective_weight), 0.0)
+            FROM node_registry
+            WHERE active = TRUE
+            GROUP BY node_type
+            ORDER BY node_type
+            "#;
+        let timing = time_query(sql);
+        let totals: Vec<(String, i64, f64)> = sqlx::query_as(sql).fetch_all(&self.pool).await?;
+        timing.finish(totals.len() as u64);
+        Ok(totals)
+    }
+
+    /// Up to `limit` active nodes as `(node_id, node_type, effective weight)`,
+    /// heaviest first
+    pub async fn heaviest_active_nodes(&self, limit: i64) -> Result<Vec<(String, String, f64)>> {
+        let sql = r#"
+            SELECT node_id, node_type, COALESCE(effective_weight, 0.0) AS weight
+            FROM node_registry
+            WHERE active = TRUE
+            ORDER BY weight DESC, node_id
+            LIMIT ?
+            "#;
+        let timing = time_query(sql);
+        let nodes: Vec<(String, String, f64)> = sqlx::query_as(sql)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+        timing.finish(nodes.len() as u64);
+        Ok(nodes)
+    }
+
+    /// List nodes matching a filter, ordered by name, with the total match count
+    pub async fn list_nodes(&self, filter: &NodeFilter) -> Result<Page<NodeRegistration>> {
+        let _timer = crate::metrics::query_timer("node_registry.list_nodes");
+        let mut count_query = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM node_registry");
+        filter.push_conditions(&mut count_query);
+        let timing = time_query(count_query.sql());
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+        timing.finish(1);
+
+        let mut query =
+            QueryBuilder::<Sqlite>::new(format!("SELECT {} FROM node_registry", NODE_COLUMNS));
+        filter.push_conditions(&mut query);
+        query.push(" ORDER BY node_name, node_id LIMIT ");
+        // SQLite treats a negative limit as no limit
+        query.push_bind(filter.limit.unwrap_or(-1));
+        query.push(" OFFSET ");
+        query.push_bind(filter.offset);
+
+        let timing = time_query(query.sql());
+        let rows: Vec<NodeRow> = query.build_query_as().fetch_all(&self.pool).await?;
+        timing.finish(rows.len() as u64);
+        let items = rows
+            .into_iter()
+            .map(NodeRow::into_registration)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Page {
+            items,
+            total,
+            limit: filter.limit,
+            offset: filter.offset,
+        })
+    }
+}
+
+/// Whether two hex public keys are the same key (in any encoding)
+fn same_public_key(a: &str, b: &str) -> bool {
+    matches!(
+        (PublicKey::from_str(a), PublicKey::from_str(b)),
+        (Ok(a), Ok(b)) if a == b
+    )
+}
+
+/// Reject signed requests whose timestamp is outside the freshness window
+fn check_request_timestamp(timestamp: i64) -> Result<()> {
+    let age = Utc::now().timestamp() - timestamp;
+    if age.abs() > SIGNED_REQUEST_MAX_AGE_SECS {
+        return Err(anyhow!(
+            "Signed request timestamp is outside the {}s window",
+            SIGNED_REQUEST_MAX_AGE_SECS
+        ));
+    }
+    Ok(())
+}
+
+/// Verify a hex DER ECDSA signature over a message with a hex public key
+fn verify_node_signature(message: &str, signature: &str, public_key: &str) -> Result<bool> {
+    let signature =
+        Signature::from_str(signature).map_err(|e| anyhow!("Invalid signature: {}", e))?;
+    let public_key =
+        PublicKey::from_str(public_key).map_err(|e| anyhow!("Invalid public key: {}", e))?;
+    SignatureManager::new()
+        .verify_signature(message, &signature, &public_key)
+        .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use secp256k1::SecretKey;
+
+    async fn setup() -> (NodeRegistry, SignatureManager) {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.get_sqlite_pool().unwrap();
+        (NodeRegistry::new(pool), SignatureManager::new())
+    }
+
+    fn keypair(manager: &SignatureManager) -> (SecretKey, String) {
+        let keypair = manager.generate_keypair().unwrap();
+        (keypair.secret_key, keypair.public_key.to_string())
+    }
+
+    fn sign(manager: &SignatureManager, message: &str, secret_key: &SecretKey) -> String {
+        manager
+            .create_signature(message, secret_key)
+            .unwrap()
+            .to_string()
+    }
+
+    /// A valid mainnet P2WSH address, distinct for each `seed`
+    fn test_address(seed: u8) -> String {
+        bitcoin::Address::p2wsh(
+            &bitcoin::ScriptBuf::from_bytes(vec![seed]),
+            bitcoin::Network::Bitcoin,
         )
-        .fetch_all(&self.pool)
-        .await?;
-
-        let mut nodes = Vec::new();
-        for row in rows {
-            let addresses: Vec<String> = serde_json::from_str(&row.bitcoin_addresses)?;
-            let metadata = row
-                .metadata
-                .as_ref()
-                .and_then(|m| serde_json::from_str(m).ok());
-
-            nodes.push(NodeRegistration {
-                node_id: row.node_id,
-                node_name: row.node_name,
-                node_type: NodeType::from_str(&row.node_type),
-                bitcoin_addresses: addresses,
-                registered_at: row.registered_at,
-                last_seen: row.last_seen,
-                active: row.active,
-                metadata,
-            });
+        .to_string()
+    }
+
+    async fn register(registry: &NodeRegistry, node_id: &str, public_key: &str) {
+        registry
+            .register_node(
+                node_id,
+                "Test Node",
+                NodeType::Node,
+                vec![test_address(0)],
+                None,
+                Some(public_key),
+            )
+            .await
+            .unwrap();
+    }
+
+    /// Register 50 nodes cycling through the five node types; every seventh
+    /// is deactivated and the first ten are backdated by 30 days
+    async fn seed_nodes(registry: &NodeRegistry) {
+        let types = [
+            NodeType::Miner,
+            NodeType::Node,
+            NodeType::Pool,
+            NodeType::Exchange,
+            NodeType::Other,
+        ];
+        for i in 0..50 {
+            let name = if i % 10 == 0 {
+                format!("Satoshi Relay {:02}", i)
+            } else {
+                format!("Node {:02}", i)
+            };
+            registry
+                .register_node(
+                    &format!("node-{:02}", i),
+                    &name,
+                    types[i % 5],
+                    vec![test_address(i as u8)],
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
         }
+        // Miners and pools register inactive without a hashpower proof
+        sqlx::query("UPDATE node_registry SET active = TRUE")
+            .execute(&registry.pool)
+            .await
+            .unwrap();
+        for i in (0..50).step_by(7) {
+            registry
+                .deactivate_node(&format!("node-{:02}", i))
+                .await
+                .unwrap();
+        }
+        sqlx::query(
+            "UPDATE node_registry SET registered_at = datetime('now', '-30 days') WHERE node_id < 'node-10'",
+        )
+        .execute(&registry.pool)
+        .await
+        .unwrap();
+    }
 
-        Ok(nodes)
+    #[tokio::test]
+    async fn test_list_nodes_pagination() {
+        let (registry, _) = setup().await;
+        seed_nodes(&registry).await;
+
+        let mut filter = NodeFilter {
+            limit: Some(20),
+            ..Default::default()
+        };
+        let first = registry.list_nodes(&filter).await.unwrap();
+        assert_eq!(first.total, 50);
+        assert_eq!(first.items.len(), 20);
+
+        filter.offset = 40;
+        let last = registry.list_nodes(&filter).await.unwrap();
+        assert_eq!(last.total, 50);
+        assert_eq!(last.items.len(), 10);
+
+        filter.offset = 50;
+        assert!(registry.list_nodes(&filter).await.unwrap().items.is_empty());
+
+        // Pages don't overlap and cover every node once
+        let mut seen = std::collections::HashSet::new();
+        for offset in (0..50).step_by(20) {
+            filter.offset = offset;
+            for node in registry.list_nodes(&filter).await.unwrap().items {
+                assert!(seen.insert(node.node_id));
+            }
+        }
+        assert_eq!(seen.len(), 50);
+    }
+
+    #[tokio::test]
+    async fn test_list_nodes_filters() {
+        let (registry, _) = setup().await;
+        seed_nodes(&registry).await;
+
+        // 8 nodes (0, 7, ..., 49) are inactive
+        let active = registry.get_active_nodes().await.unwrap();
+        assert_eq!(active.len(), 42);
+
+        let inactive = registry
+            .list_nodes(&NodeFilter {
+                status: Some(NodeStatus::Inactive),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(inactive.total, 8);
+
+        // Pools are i % 5 == 2; of those, 7 and 42 are inactive
+        let pools = registry
+            .list_nodes(&NodeFilter {
+                node_type: Some(NodeType::Pool),
+                status: Some(NodeStatus::Active),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(pools.total, 8);
+        assert!(pools.items.iter().all(|n| n.node_type == NodeType::Pool));
+
+        // Name search is case-insensitive
+        let relays = registry
+            .list_nodes(&NodeFilter {
+                search: Some("satoshi".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(relays.total, 5);
+
+        // LIKE wildcards in the search term are matched literally
+        let wildcard = registry
+            .list_nodes(&NodeFilter {
+                search: Some("%".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(wildcard.total, 0);
+
+        // Only nodes 10..49 were registered in the last week
+        let recent = registry
+            .list_nodes(&NodeFilter {
+                registered_after: Some(Utc::now() - chrono::Duration::days(7)),
+                node_type: Some(NodeType::Miner),
+                limit: Some(3),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(recent.total, 8);
+        assert_eq!(recent.items.len(), 3);
+        assert_eq!(recent.items[0].node_id, "node-15");
+    }
+
+    /// Blockchain backend that is always unreachable
+    struct OfflineVerifier;
+
+    #[async_trait::async_trait]
+    impl BlockchainVerifier for OfflineVerifier {
+        async fn verify_coinbase_attribution(
+            &self,
+            _block_hash: &str,
+            _expected_pool_tag: &str,
+        ) -> Result<bool, crate::error::GovernanceError> {
+            Err(crate::error::GovernanceError::CryptoError(
+                "backend offline".to_string(),
+            ))
+        }
+
+        async fn get_address_balance(
+            &self,
+            _address: &str,
+        ) -> Result<u64, crate::error::GovernanceError> {
+            Err(crate::error::GovernanceError::CryptoError(
+                "backend offline".to_string(),
+            ))
+        }
+    }
+
+    /// Mock chain with fixed per-address balances (satoshis)
+    struct MockChain(std::collections::HashMap<String, u64>);
+
+    #[async_trait::async_trait]
+    impl BlockchainVerifier for MockChain {
+        async fn verify_coinbase_attribution(
+            &self,
+            _block_hash: &str,
+            _expected_pool_tag: &str,
+        ) -> Result<bool, crate::error::GovernanceError> {
+            Ok(false)
+        }
+
+        async fn get_address_balance(
+            &self,
+            address: &str,
+        ) -> Result<u64, crate::error::GovernanceError> {
+            Ok(self.0.get(address).copied().unwrap_or(0))
+        }
+    }
+
+    /// Generate a P2PKH address and sign the holdings challenge with its key
+    fn signed_address(message: &str) -> (String, String) {
+        use base64::{engine::general_purpose::STANDARD, Engine};
+        use sha2::{Digest, Sha256};
+
+        let secp = secp256k1::Secp256k1::new();
+        let (secret_key, public_key) = secp.generate_keypair(&mut secp256k1::rand::thread_rng());
+        let address = bitcoin::Address::p2pkh(
+            &bitcoin::PublicKey::new(public_key),
+            bitcoin::Network::Bitcoin,
+        )
+        .to_string();
+
+        let mut data = vec![24u8];
+        data.extend_from_slice(b"Bitcoin Signed Message:\n");
+        data.push(message.len() as u8);
+        data.extend_from_slice(message.as_bytes());
+        let digest =
+            secp256k1::Message::from_digest_slice(&Sha256::digest(Sha256::digest(&data))).unwrap();
+        let (recovery_id, compact) = secp
+            .sign_ecdsa_recoverable(&digest, &secret_key)
+            .serialize_compact();
+        let mut signature = vec![31 + recovery_id.to_i32() as u8];
+        signature.extend_from_slice(&compact);
+
+        (address, STANDARD.encode(signature))
+    }
+
+    async fn holdings_registry(balances: &[(&str, u64)]) -> NodeRegistry {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.get_sqlite_pool().unwrap();
+        let chain = MockChain(
+            balances
+                .iter()
+                .map(|(address, sats)| (address.to_string(), *sats))
+                .collect(),
+        );
+        NodeRegistry::with_blockchain_verifier(pool, Arc::new(chain))
+    }
+
+    #[tokio::test]
+    async fn test_holdings_proof_records_measured_balance() {
+        let now = Utc::now().timestamp();
+        let message = HoldingsProof::challenge_message("exchange-1", now);
+        let (address_a, signature_a) = signed_address(&message);
+        let (address_b, signature_b) = signed_address(&message);
+        let registry = holdings_registry(&[
+            (&address_a, 600 * 100_000_000),
+            (&address_b, 500 * 100_000_000),
+        ])
+        .await;
+
+        let metadata = serde_json::json!({
+            "holdings_proof": {
+                "addresses": [address_a, address_b],
+                "total_btc": 1000.0,
+                "timestamp": now,
+                "signatures": [signature_a, signature_b]
+            }
+        });
+        let active = registry
+            .register_node(
+                "exchange-1",
+                "Test Exchange",
+                NodeType::Exchange,
+                vec![address_a.clone(), address_b.clone()],
+                Some(metadata),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(active);
+
+        let node = registry.get_node("exchange-1").await.unwrap().unwrap();
+        assert_eq!(node.verified_balance_btc, Some(1100.0));
+        assert!(node.balance_verified_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_holdings_proof_rejects_stale_timestamp() {
+        let stale = Utc::now().timestamp() - DEFAULT_HOLDINGS_PROOF_MAX_AGE_SECS - 60;
+        let (address, signature) =
+            signed_address(&HoldingsProof::challenge_message("exchange-1", stale));
+        let registry = holdings_registry(&[(&address, 2000 * 100_000_000)]).await;
+
+        let proof = HoldingsProof {
+            addresses: vec![address.clone()],
+            total_btc: 1000.0,
+            timestamp: stale,
+            signatures: vec![signature],
+        };
+        assert!(registry
+            .verify_holdings_proof("exchange-1", &[address.clone()], &proof)
+            .await
+            .is_err());
+
+        // A longer configured window accepts the same proof
+        let registry =
+            registry.with_holdings_proof_max_age(2 * DEFAULT_HOLDINGS_PROOF_MAX_AGE_SECS);
+        assert_eq!(
+            registry
+                .verify_holdings_proof("exchange-1", &[address], &proof)
+                .await
+                .unwrap(),
+            HoldingsProofStatus::Verified {
+                balance_btc: 2000.0
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_holdings_proof_rejects_balance_shortfall() {
+        let now = Utc::now().timestamp();
+        let (address, signature) =
+            signed_address(&HoldingsProof::challenge_message("exchange-1", now));
+        let registry = holdings_registry(&[(&address, 900 * 100_000_000)]).await;
+
+        let proof = HoldingsProof {
+            addresses: vec![address.clone()],
+            total_btc: 1000.0,
+            timestamp: now,
+            signatures: vec![signature],
+        };
+        assert!(registry
+            .verify_holdings_proof("exchange-1", &[address], &proof)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_holdings_proof_rejects_address_mismatch() {
+        let now = Utc::now().timestamp();
+        let message = HoldingsProof::challenge_message("exchange-1", now);
+        let (address_a, signature_a) = signed_address(&message);
+        let (address_b, signature_b) = signed_address(&message);
+        let registry = holdings_registry(&[(&address_a, 2000 * 100_000_000)]).await;
+
+        // Proof covers an address that is not registered
+        let proof = HoldingsProof {
+            addresses: vec![address_a.clone(), address_b.clone()],
+            total_btc: 1000.0,
+            timestamp: now,
+            signatures: vec![signature_a.clone(), signature_b.clone()],
+        };
+        assert!(registry
+            .verify_holdings_proof("exchange-1", &[address_a.clone()], &proof)
+            .await
+            .is_err());
+
+        // Signature made by a different address's key
+        let proof = HoldingsProof {
+            addresses: vec![address_a.clone()],
+            total_btc: 1000.0,
+            timestamp: now,
+            signatures: vec![signature_b],
+        };
+        assert!(registry
+            .verify_holdings_proof("exchange-1", &[address_a], &proof)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_holdings_proof_pending_when_backend_offline() {
+        let now = Utc::now().timestamp();
+        let (address, signature) =
+            signed_address(&HoldingsProof::challenge_message("exchange-1", now));
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.get_sqlite_pool().unwrap();
+        let registry = NodeRegistry::with_blockchain_verifier(pool, Arc::new(OfflineVerifier));
+
+        let proof = HoldingsProof {
+            addresses: vec![address.clone()],
+            total_btc: 1000.0,
+            timestamp: now,
+            signatures: vec![signature],
+        };
+        assert_eq!(
+            registry
+                .verify_holdings_proof("exchange-1", &[address], &proof)
+                .await
+                .unwrap(),
+            HoldingsProofStatus::Pending
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pool_registration_pending_when_backend_offline() {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.get_sqlite_pool().unwrap();
+        let registry = NodeRegistry::with_blockchain_verifier(pool, Arc::new(OfflineVerifier));
+
+        let metadata = serde_json::json!({
+            "hashpower_proof": {
+                "blocks_mined": ["00000000000000000001a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0a0"],
+                "pool_tag": "/TestPool/"
+            }
+        });
+        let active = registry
+            .register_node(
+                "pool-1",
+                "Test Pool",
+                NodeType::Pool,
+                vec![],
+                Some(metadata),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(!active);
+        assert!(!registry.get_node("pool-1").await.unwrap().unwrap().active);
+    }
+
+    #[tokio::test]
+    async fn test_miner_registration_inactive_without_proof() {
+        let (registry, _) = setup().await;
+
+        let active = registry
+            .register_node("miner-1", "Test Miner", NodeType::Miner, vec![], None, None)
+            .await
+            .unwrap();
+
+        assert!(!active);
+        assert!(!registry.get_node("miner-1").await.unwrap().unwrap().active);
+    }
+
+    /// Key the test exchange re-registers with (the secp256k1 generator)
+    const EXCHANGE_PUBLIC_KEY: &str =
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    /// Register an exchange with a verified 100 BTC holdings proof
+    async fn register_exchange(registry: &NodeRegistry, address: &str, signature: &str, ts: i64) {
+        let metadata = serde_json::json!({
+            "holdings_proof": {
+                "addresses": [address],
+                "total_btc": 100.0,
+                "timestamp": ts,
+                "signatures": [signature]
+            }
+        });
+        let active = registry
+            .register_node(
+                "exchange-1",
+                "Test Exchange",
+                NodeType::Exchange,
+                vec![address.to_string()],
+                Some(metadata),
+                Some(EXCHANGE_PUBLIC_KEY),
+            )
+            .await
+            .unwrap();
+        assert!(active);
+    }
+
+    async fn backdate_verification(registry: &NodeRegistry, days: i64) {
+        sqlx::query(
+            "UPDATE node_registry SET balance_verified_at = ? WHERE node_id = 'exchange-1'",
+        )
+        .bind(Utc::now() - chrono::Duration::days(days))
+        .execute(&registry.pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_weight_decay_half_lives() {
+        let now = Utc::now().timestamp();
+        let (address, signature) =
+            signed_address(&HoldingsProof::challenge_message("exchange-1", now));
+        let registry = holdings_registry(&[(&address, 100 * 100_000_000)]).await;
+        register_exchange(&registry, &address, &signature, now).await;
+
+        let decay = DecayConfig {
+            half_life_days: 30.0,
+            minimum_weight: 0.0,
+            grace_period_days: 0.0,
+        };
+        for (days, expected) in [(0, 100.0), (30, 50.0), (60, 25.0)] {
+            backdate_verification(&registry, days).await;
+            assert_eq!(registry.recalculate_all_weights(&decay).await.unwrap(), 1);
+
+            let node = registry.get_node("exchange-1").await.unwrap().unwrap();
+            assert_eq!(node.verified_balance_btc, Some(100.0));
+            let effective = node.effective_weight.unwrap();
+            assert!(
+                (effective - expected).abs() < 0.01,
+                "after {} days expected {}, got {}",
+                days,
+                expected,
+                effective
+            );
+        }
+
+        // Decay disabled: effective weight is the base weight
+        let disabled = DecayConfig {
+            half_life_days: 0.0,
+            ..decay
+        };
+        registry.recalculate_all_weights(&disabled).await.unwrap();
+        let node = registry.get_node("exchange-1").await.unwrap().unwrap();
+        assert_eq!(node.effective_weight, Some(100.0));
+    }
+
+    #[tokio::test]
+    async fn test_reverification_resets_weight_decay() {
+        let now = Utc::now().timestamp();
+        let (address, signature) =
+            signed_address(&HoldingsProof::challenge_message("exchange-1", now));
+        let registry = holdings_registry(&[(&address, 100 * 100_000_000)]).await;
+        register_exchange(&registry, &address, &signature, now).await;
+
+        let decay = DecayConfig {
+            half_life_days: 30.0,
+            minimum_weight: 0.0,
+            grace_period_days: 0.0,
+        };
+        backdate_verification(&registry, 60).await;
+        registry.recalculate_all_weights(&decay).await.unwrap();
+        let node = registry.get_node("exchange-1").await.unwrap().unwrap();
+        assert!(node.effective_weight.unwrap() < 26.0);
+
+        // A fresh holdings proof restores full weight
+        register_exchange(&registry, &address, &signature, now).await;
+        registry.recalculate_all_weights(&decay).await.unwrap();
+        let node = registry.get_node("exchange-1").await.unwrap().unwrap();
+        assert!((node.effective_weight.unwrap() - 100.0).abs() < 0.01);
+    }
+
+    #[tokio::test]
+    async fn test_deregister_node() {
+        let (registry, manager) = setup().await;
+        let (secret_key, public_key) = keypair(&manager);
+        register(&registry, "node-1", &public_key).await;
+
+        let now = Utc::now().timestamp();
+        let signature = sign(&manager, &format!("deregister:node-1:{}", now), &secret_key);
+        registry
+            .deregister_node("node-1", now, &signature)
+            .await
+            .unwrap();
+
+        let node = registry.get_node("node-1").await.unwrap().unwrap();
+        assert!(!node.active);
+        assert!(node.deregistered_at.is_some());
+
+        // A deregistered node cannot come back under the same ID
+        let result = registry
+            .register_node(
+                "node-1",
+                "Test Node",
+                NodeType::Node,
+                vec![],
+                None,
+                Some(&public_key),
+            )
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_deregister_rejects_bad_signature() {
+        let (registry, manager) = setup().await;
+        let (_, public_key) = keypair(&manager);
+        let (other_secret, _) = keypair(&manager);
+        register(&registry, "node-1", &public_key).await;
+
+        let now = Utc::now().timestamp();
+        let signature = sign(
+            &manager,
+            &format!("deregister:node-1:{}", now),
+            &other_secret,
+        );
+        assert!(registry
+            .deregister_node("node-1", now, &signature)
+            .await
+            .is_err());
+
+        // Stale timestamps are rejected even with a valid signature
+        let stale = now - SIGNED_REQUEST_MAX_AGE_SECS - 60;
+        let (secret_key, public_key) = keypair(&manager);
+        register(&registry, "node-2", &public_key).await;
+        let signature = sign(
+            &manager,
+            &format!("deregister:node-2:{}", stale),
+            &secret_key,
+        );
+        assert!(registry
+            .deregister_node("node-2", stale, &signature)
+            .await
+            .is_err());
+
+        assert!(registry.get_node("node-1").await.unwrap().unwrap().active);
+    }
+
+    #[tokio::test]
+    async fn test_rotate_public_key() {
+        let (registry, manager) = setup().await;
+        let (old_secret, old_public) = keypair(&manager);
+        let (new_secret, new_public) = keypair(&manager);
+        register(&registry, "node-1", &old_public).await;
+
+        let now = Utc::now().timestamp();
+        let message = format!("rotate_key:node-1:{}:{}", new_public, now);
+        registry
+            .rotate_public_key(
+                "node-1",
+                &new_public,
+                now,
+                &sign(&manager, &message, &old_secret),
+                &sign(&manager, &message, &new_secret),
+            )
+            .await
+            .unwrap();
+
+        let node = registry.get_node("node-1").await.unwrap().unwrap();
+        assert_eq!(node.public_key.as_deref(), Some(new_public.as_str()));
+
+        let rotations = registry.get_key_rotations("node-1").await.unwrap();
+        assert_eq!(rotations.len(), 1);
+        assert_eq!(rotations[0].old_public_key, old_public);
+
+        // The old key remains attributable to the node
+        assert_eq!(
+            registry
+                .get_node_for_public_key(&old_public)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("node-1")
+        );
+
+        // The old key can no longer re-register the node
+        let result = registry
+            .register_node(
+                "node-1",
+                "Renamed",
+                NodeType::Node,
+                vec![],
+                None,
+                Some(&old_public),
+            )
+            .await;
+        assert!(result.unwrap_err().is::<RegistrationConflict>());
+        let node = registry.get_node("node-1").await.unwrap().unwrap();
+        assert_eq!(node.public_key.as_deref(), Some(new_public.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_reregistration_requires_stored_key() {
+        let (registry, manager) = setup().await;
+        let (_, owner_public) = keypair(&manager);
+        let (_, other_public) = keypair(&manager);
+        register(&registry, "node-1", &owner_public).await;
+
+        // Neither a different key nor no key can take the node over
+        for public_key in [Some(other_public.as_str()), None] {
+            let result = registry
+                .register_node(
+                    "node-1",
+                    "Hijacked",
+                    NodeType::Exchange,
+                    vec![],
+                    None,
+                    public_key,
+                )
+                .await;
+            assert!(result.unwrap_err().is::<RegistrationConflict>());
+        }
+        let node = registry.get_node("node-1").await.unwrap().unwrap();
+        assert_eq!(node.node_name, "Test Node");
+        assert_eq!(node.public_key.as_deref(), Some(owner_public.as_str()));
+
+        // A node registered without a key cannot be claimed later
+        registry
+            .register_node("node-2", "Keyless", NodeType::Node, vec![], None, None)
+            .await
+            .unwrap();
+        let result = registry
+            .register_node(
+                "node-2",
+                "Keyless",
+                NodeType::Node,
+                vec![],
+                None,
+                Some(&other_public),
+            )
+            .await;
+        assert!(result.unwrap_err().is::<RegistrationConflict>());
+        assert!(registry
+            .get_node("node-2")
+            .await
+            .unwrap()
+            .unwrap()
+            .public_key
+            .is_none());
+
+        // The owner can re-register
+        register(&registry, "node-1", &owner_public).await;
+    }
+
+    #[tokio::test]
+    async fn test_rotate_rejects_bad_and_duplicate_rotations() {
+        let (registry, manager) = setup().await;
+        let (old_secret, old_public) = keypair(&manager);
+        let (new_secret, new_public) = keypair(&manager);
+        register(&registry, "node-1", &old_public).await;
+
+        let now = Utc::now().timestamp();
+        let message = format!("rotate_key:node-1:{}:{}", new_public, now);
+
+        // Missing proof of control of the new key
+        assert!(registry
+            .rotate_public_key(
+                "node-1",
+                &new_public,
+                now,
+                &sign(&manager, &message, &old_secret),
+                &sign(&manager, &message, &old_secret),
+            )
+            .await
+            .is_err());
+
+        registry
+            .rotate_public_key(
+                "node-1",
+                &new_public,
+                now,
+                &sign(&manager, &message, &old_secret),
+                &sign(&manager, &message, &new_secret),
+            )
+            .await
+            .unwrap();
+
+        // Replaying the same rotation fails: the new key is already current
+        assert!(registry
+            .rotate_public_key(
+                "node-1",
+                &new_public,
+                now,
+                &sign(&manager, &message, &old_secret),
+                &sign(&manager, &message, &new_secret),
+            )
+            .await
+            .is_err());
+
+        // Rotating back to a retired key fails
+        let message = format!("rotate_key:node-1:{}:{}", old_public, now);
+        assert!(registry
+            .rotate_public_key(
+                "node-1",
+                &old_public,
+                now,
+                &sign(&manager, &message, &new_secret),
+                &sign(&manager, &message, &old_secret),
+            )
+            .await
+            .is_err());
+
+        assert_eq!(registry.get_key_rotations("node-1").await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_purge_pending_nodes() {
+        let (registry, manager) = setup().await;
+        let (_, public_key) = keypair(&manager);
+        register(&registry, "stale-pending", &public_key).await;
+        register(&registry, "fresh-pending", &keypair(&manager).1).await;
+        register(&registry, "stale-active", &keypair(&manager).1).await;
+        registry.deactivate_node("stale-pending").await.unwrap();
+        registry.deactivate_node("fresh-pending").await.unwrap();
+        sqlx::query(
+            "UPDATE node_registry SET last_seen = datetime('now', '-8 days') WHERE node_id LIKE 'stale-%'",
+        )
+        .execute(&registry.pool)
+        .await
+        .unwrap();
+
+        let purged = registry
+            .purge_pending_nodes(Utc::now() - chrono::Duration::days(7))
+            .await
+            .unwrap();
+        assert_eq!(purged, 1);
+        assert!(registry.get_node("stale-pending").await.unwrap().is_none());
+        assert!(registry.get_node("fresh-pending").await.unwrap().is_some());
+        assert!(registry.get_node("stale-active").await.unwrap().is_some());
+        assert!(registry
+            .get_node_for_address(&test_address(0))
+            .await
+            .unwrap()
+            .is_some());
+
+        // The squatted key is free again
+        assert!(registry
+            .get_node_for_public_key(&public_key)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_register_rejects_invalid_addresses() {
+        let (registry, _) = setup().await;
+        for address in [
+            "bc1qtest",
+            // Testnet address on a mainnet registry
+            "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx",
+        ] {
+            let result = registry
+                .register_node(
+                    "node-1",
+                    "Test Node",
+                    NodeType::Node,
+                    vec![test_address(1), address.to_string()],
+                    None,
+                    None,
+                )
+                .await;
+            assert!(result.is_err(), "{} accepted", address);
+        }
+        assert!(registry.get_node("node-1").await.unwrap().is_none());
+
+        let testnet =
+            NodeRegistry::new(registry.pool.clone()).with_network(bitcoin::Network::Testnet);
+        testnet
+            .register_node(
+                "node-1",
+                "Test Node",
+                NodeType::Node,
+                vec!["tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".to_string()],
+                None,
+                None,
+            )
+            .await
+            .unwrap();
     }
 }
@@ -0,0 +1,271 @@
+//! Node API tokens
+//!
+//! Bearer tokens scoped to a single registered node. They authenticate the
+//! self-service endpoints under `/nodes/me` and never grant access to another
+//! node's data or to the shared internal API. Only the SHA-256 of a token is
+//! stored; the token itself is shown once, when issued.
+//!
+//! Tokens are issued on a registration that proves control of its public key,
+//! on a request signed by the node's key, or by an operator through the
+//! internal API.
+
+use anyhow::{anyhow, Result};
+use axum::{
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use tracing::{info, warn};
+
+use crate::api_auth::presented_key;
+use crate::config::AppConfig;
+use crate::database::Database;
+
+/// Prefix that makes node tokens recognizable in logs and secret scanners
+const TOKEN_PREFIX: &str = "bnt_";
+
+/// Node authenticated by a node API token, added to the request extensions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeIdentity(pub String);
+
+/// Issued token metadata (the token itself is never stored)
+#[derive(Debug, Clone, Serialize, sqlx::FromRow)]
+pub struct NodeApiToken {
+    pub id: i64,
+    pub node_id: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// A newly issued token; `token` is only available here
+#[derive(Debug, Clone, Serialize)]
+pub struct IssuedNodeToken {
+    pub id: i64,
+    pub node_id: String,
+    pub token: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Issues, checks and revokes node API tokens
+pub struct NodeTokenStore {
+    pool: SqlitePool,
+}
+
+impl NodeTokenStore {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Issue a token for a registered node; `ttl` of None never expires
+    pub async fn issue(
+        &self,
+        node_id: &str,
+        ttl: Option<chrono::Duration>,
+    ) -> Result<IssuedNodeToken> {
+        let registered: Option<bool> = sqlx::query_scalar(
+            "SELECT deregistered_at IS NULL FROM node_registry WHERE node_id = ?",
+        )
+        .bind(node_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        match registered {
+            None => return Err(anyhow!("Node {} not found", node_id)),
+            Some(false) => return Err(anyhow!("Node {} has been deregistered", node_id)),
+            Some(true) => {}
+        }
+
+        let token = format!(
+            "{}{}",
+            TOKEN_PREFIX,
+            hex::encode(rand::random::<[u8; 32]>())
+        );
+        let expires_at = ttl.map(|ttl| Utc::now() + ttl);
+        let id = sqlx::query(
+            "INSERT INTO node_api_tokens (node_id, token_hash, expires_at) VALUES (?, ?, ?)",
+        )
+        .bind(node_id)
+        .bind(hash_token(&token))
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await?
+        .last_insert_rowid();
+
+        info!("Issued API token {} for node {}", id, node_id);
+        Ok(IssuedNodeToken {
+            id,
+            node_id: node_id.to_string(),
+            token,
+            expires_at,
+        })
+    }
+
+    /// The node a token belongs to, if it is current and the node is still registered
+    pub async fn authenticate(&self, token: &str) -> Result<Option<String>> {
+        if !token.starts_with(TOKEN_PREFIX) {
+            return Ok(None);
+        }
+        let now = Utc::now();
+        let found: Option<(i64, String)> = sqlx::query_as(
+            r#"
+            SELECT t.id, t.node_id FROM node_api_tokens t
+            JOIN node_registry n ON n.node_id = t.node_id
+            WHERE t.token_hash = ?
+              AND t.revoked_at IS NULL
+              AND (t.expires_at IS NULL OR t.expires_at > ?)
+              AND n.deregistered_at IS NULL
+            "#,
+        )
+        .bind(hash_token(token))
+        .bind(now)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some((id, node_id)) = found else {
+            return Ok(None);
+        };
+        sqlx::query("UPDATE node_api_tokens SET last_used_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        Ok(Some(node_id))
+    }
+
+    /// Tokens issued for a node, newest first
+    pub async fn list(&self, node_id: &str) -> Result<Vec<NodeApiToken>> {
+        let tokens = sqlx::query_as::<_, NodeApiToken>(
+            r#"
+            SELECT id, node_id, created_at, expires_at, revoked_at, last_used_at
+            FROM node_api_tokens WHERE node_id = ? ORDER BY id DESC
+            "#,
+        )
+        .bind(node_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(tokens)
+    }
+
+    /// Revoke one of a node's tokens; returns false if it doesn't exist or is already revoked
+    pub async fn revoke(&self, node_id: &str, token_id: i64) -> Result<bool> {
+        let revoked = sqlx::query(
+            "UPDATE node_api_tokens SET revoked_at = ? WHERE id = ? AND node_id = ? AND revoked_at IS NULL",
+        )
+        .bind(Utc::now())
+        .bind(token_id)
+        .bind(node_id)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        if revoked > 0 {
+            info!("Revoked API token {} for node {}", token_id, node_id);
+        }
+        Ok(revoked > 0)
+    }
+}
+
+/// Reject requests without a current node API token; adds [`NodeIdentity`]
+pub async fn require_node_token(
+    State((_, database)): State<(AppConfig, Database)>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(pool) = database.get_sqlite_pool() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({"error": "Database pool not available"})),
+        )
+            .into_response();
+    };
+
+    let node_id = match presented_key(request.headers()) {
+        Some(token) => NodeTokenStore::new(pool)
+            .authenticate(token)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Failed to check node API token: {}", e);
+                None
+            }),
+        None => None,
+    };
+    match node_id {
+        Some(node_id) => {
+            request.extensions_mut().insert(NodeIdentity(node_id));
+            next.run(request).await
+        }
+        None => {
+            warn!(
+                "Rejected request to {} without a valid node token",
+                request.uri().path()
+            );
+            (
+                StatusCode::UNAUTHORIZED,
+                Json(serde_json::json!({"error": "invalid or missing node token"})),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node_registry::{NodeRegistry, NodeType};
+
+    async fn setup() -> NodeTokenStore {
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.get_sqlite_pool().unwrap();
+        let registry = NodeRegistry::new(pool.clone());
+        for node_id in ["node-1", "node-2"] {
+            registry
+                .register_node(node_id, "Test Node", NodeType::Node, vec![], None, None)
+                .await
+                .unwrap();
+        }
+        NodeTokenStore::new(pool)
+    }
+
+    #[tokio::test]
+    async fn test_token_lifecycle() {
+        let store = setup().await;
+        let issued = store.issue("node-1", None).await.unwrap();
+        assert!(issued.token.starts_with(TOKEN_PREFIX));
+        assert_eq!(
+            store.authenticate(&issued.token).await.unwrap(),
+            Some("node-1".to_string())
+        );
+        assert_eq!(store.authenticate("bnt_unknown").await.unwrap(), None);
+
+        // Another node can't revoke it
+        assert!(!store.revoke("node-2", issued.id).await.unwrap());
+        assert!(store.revoke("node-1", issued.id).await.unwrap());
+        assert_eq!(store.authenticate(&issued.token).await.unwrap(), None);
+
+        let tokens = store.list("node-1").await.unwrap();
+        assert_eq!(tokens.len(), 1);
+        assert!(tokens[0].revoked_at.is_some());
+        assert!(tokens[0].last_used_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_rejected() {
+        let store = setup().await;
+        let issued = store
+            .issue("node-1", Some(chrono::Duration::seconds(-1)))
+            .await
+            .unwrap();
+        assert_eq!(store.authenticate(&issued.token).await.unwrap(), None);
+        assert!(store.issue("missing", None).await.is_err());
+    }
+}
@@ -0,0 +1,81 @@
+//! Internal Nostr relay management endpoints
+
+use axum::{extract::Query, http::StatusCode, middleware, response::Json, routing::get, Router};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::api_auth::require_internal_api_key;
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::nostr::client::{shared_client, NostrClient, RelayHealth};
+
+/// Relay health response
+#[derive(Debug, Serialize)]
+pub struct RelaysResponse {
+    pub relays: Vec<RelayHealth>,
+}
+
+/// Add or remove relay request
+#[derive(Debug, Deserialize)]
+pub struct RelayRequest {
+    pub url: String,
+}
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+fn nostr_client() -> Result<&'static NostrClient, ApiError> {
+    shared_client()
+        .ok_or_else(|| api_error(StatusCode::SERVICE_UNAVAILABLE, "Nostr is not enabled"))
+}
+
+/// Get the health of every relay
+pub async fn list_relays() -> Result<Json<RelaysResponse>, ApiError> {
+    let client = nostr_client()?;
+    Ok(Json(RelaysResponse {
+        relays: client.relay_health().await,
+    }))
+}
+
+/// Add a relay and connect to it
+pub async fn add_relay(
+    Json(request): Json<RelayRequest>,
+) -> Result<Json<RelaysResponse>, ApiError> {
+    let client = nostr_client()?;
+    if let Err(e) = client.add_relay(&request.url).await {
+        warn!("Failed to add relay {}: {}", request.url, e);
+        return Err(api_error(StatusCode::BAD_REQUEST, e));
+    }
+    list_relays().await
+}
+
+/// Disconnect and remove a relay (`?url=`)
+pub async fn remove_relay(
+    Query(request): Query<RelayRequest>,
+) -> Result<Json<RelaysResponse>, ApiError> {
+    let client = nostr_client()?;
+    if let Err(e) = client.remove_relay(&request.url).await {
+        warn!("Failed to remove relay {}: {}", request.url, e);
+        return Err(api_error(StatusCode::BAD_REQUEST, e));
+    }
+    list_relays().await
+}
+
+/// Create router for relay management; all routes require the internal API key
+pub fn create_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route(
+            "/internal/nostr/relays",
+            get(list_relays).post(add_relay).delete(remove_relay),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ))
+}
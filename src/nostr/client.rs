@@ -4,20 +4,95 @@
 //! governance status updates with proper error handling and retry logic.
 
 use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
 use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::future::Future;
+use std::path::Path;
 use std::str::FromStr;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use tracing::{debug, error, info, warn};
 
+use crate::execution_mode::{record_suppressed, ExecutionMode, Subsystem};
+use crate::resilience::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+
+/// How long to wait for a relay's NIP-42 challenge, and for its reply to our AUTH
+const AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often the relay monitor reconnects dropped relays
+pub const RELAY_RECONNECT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// The server's Nostr client, for API handlers that report on or manage relays
+static SHARED_CLIENT: OnceLock<NostrClient> = OnceLock::new();
+
+/// Register the server's Nostr client (only the first registration is kept)
+pub fn set_shared_client(client: NostrClient) {
+    let _ = SHARED_CLIENT.set(client);
+}
+
+/// The server's Nostr client, if Nostr is enabled
+pub fn shared_client() -> Option<&'static NostrClient> {
+    SHARED_CLIENT.get()
+}
+
+/// NIP-42 authentication state of a relay connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RelayAuthStatus {
+    Authenticated,
+    /// Connected without authenticating (relay sent no challenge)
+    Unauthenticated,
+    AuthFailed(String),
+}
+
+/// Per-relay outcome of publishing an event
+#[derive(Debug, Clone, Default)]
+pub struct PublishResult {
+    /// Relays that acknowledged the event with an OK (NIP-20)
+    pub confirmed: Vec<String>,
+    /// Relays that rejected the event or could not be reached, with the reason
+    pub failed: Vec<(String, String)>,
+}
+
+impl PublishResult {
+    fn failed_urls(&self) -> Vec<String> {
+        self.failed.iter().map(|(url, _)| url.clone()).collect()
+    }
+}
+
+/// Health of one relay, updated on every publish
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RelayHealth {
+    pub url: String,
+    /// Whether the relay connection is currently up
+    pub connected: bool,
+    pub last_success: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub consecutive_failures: u32,
+    /// Round-trip time of the last accepted publish (send to OK)
+    pub latency_ms: Option<u64>,
+    /// Publish circuit breaker; relays with an open circuit are skipped
+    #[serde(default)]
+    pub circuit_state: CircuitState,
+}
+
+/// Outcome of sending an event to one relay: round-trip time or error
+type RelayOutcome = (String, std::result::Result<Duration, String>);
+
 /// Nostr client managing multiple relay connections
 #[derive(Clone)]
 pub struct NostrClient {
     client: Arc<Client>,
     pub keys: Keys,
-    relay_status: Arc<Mutex<HashMap<String, bool>>>,
+    relay_health: Arc<Mutex<HashMap<String, RelayHealth>>>,
+    /// Relays connected through `connect_with_auth`, by auth outcome
+    relay_auth: Arc<Mutex<HashMap<String, RelayAuthStatus>>>,
+    /// Per-relay publish circuit breakers, created on first send
+    relay_breakers: Arc<Mutex<HashMap<String, Arc<CircuitBreaker>>>>,
+    breaker_config: CircuitBreakerConfig,
+    execution_mode: ExecutionMode,
 }
 
 impl NostrClient {
@@ -42,70 +117,617 @@ impl NostrClient {
         // Start client
         client.connect().await;
 
-        let relay_status = Arc::new(Mutex::new(HashMap::new()));
-
         Ok(Self {
             client: Arc::new(client),
             keys,
-            relay_status,
+            relay_health: Arc::new(Mutex::new(HashMap::new())),
+            relay_auth: Arc::new(Mutex::new(HashMap::new())),
+            relay_breakers: Arc::new(Mutex::new(HashMap::new())),
+            breaker_config: relay_breaker_config(5, Duration::from_secs(300)),
+            execution_mode: ExecutionMode::Live,
         })
     }
 
-    /// Publish event to all connected relays
-    pub async fn publish_event(&self, event: Event) -> Result<()> {
-        let mut successful_relays = 0;
-        let mut failed_relays = Vec::new();
+    /// In dry-run mode events are built and signed but never sent to relays
+    pub fn with_execution_mode(mut self, execution_mode: ExecutionMode) -> Self {
+        self.execution_mode = execution_mode;
+        self
+    }
 
-        // Get list of connected relays
-        let relays = (*self.client).relays().await;
+    /// Stop publishing to a relay after `failure_threshold` consecutive
+    /// failures, and send one trial publish after `reset_timeout`
+    pub fn with_relay_circuit_breaker(
+        mut self,
+        failure_threshold: u32,
+        reset_timeout: Duration,
+    ) -> Self {
+        self.breaker_config = relay_breaker_config(failure_threshold, reset_timeout);
+        self
+    }
 
-        for (relay_url, relay) in &relays {
-            match relay
-                .send_event(event.clone(), RelaySendOptions::new())
-                .await
-            {
-                Ok(_) => {
-                    debug!("Published event to relay: {}", relay_url);
-                    successful_relays += 1;
+    async fn relay_breaker(&self, url: &str) -> Arc<CircuitBreaker> {
+        self.relay_breakers
+            .lock()
+            .await
+            .entry(url.to_string())
+            .or_insert_with(|| {
+                Arc::new(CircuitBreaker::with_config(
+                    format!("nostr-relay {}", url),
+                    self.breaker_config.clone(),
+                ))
+            })
+            .clone()
+    }
+
+    /// Rotate the server key to the nsec stored at `new_nsec_path`
+    ///
+    /// Publishes, from the old key, its NIP-02 contact list with the new key
+    /// added (petname "key_rotation") and a text note announcing the rotation,
+    /// then reconnects with the new key and returns its public key. Steps
+    /// after the new key is loaded are best-effort and only logged on failure.
+    /// Other clones of this client keep using the old key, and persisting the
+    /// new path (see [`crate::config::update_nsec_path`]) is up to the caller.
+    pub async fn rotate_key(&mut self, new_nsec_path: &Path) -> Result<XOnlyPublicKey> {
+        let nsec = std::fs::read_to_string(new_nsec_path).map_err(|e| {
+            anyhow!(
+                "Failed to read new nsec from {}: {}",
+                new_nsec_path.display(),
+                e
+            )
+        })?;
+        let new_keys =
+            Keys::from_sk_str(nsec.trim()).map_err(|e| anyhow!("Invalid nsec key: {}", e))?;
+        let old_pubkey = self.keys.public_key();
+        let new_pubkey = new_keys.public_key();
+        if new_pubkey == old_pubkey {
+            return Err(anyhow!("New key matches the current key"));
+        }
+        info!("Rotating Nostr key {} -> {}", old_pubkey, new_pubkey);
 
-                    // Update relay status
-                    let mut status = self.relay_status.lock().await;
-                    status.insert(relay_url.to_string(), true);
+        // The old key's contact list, with the new key added. A kind-3 event
+        // replaces the previous one, so without the current list it isn't
+        // published rather than wiping the existing contacts.
+        match self.fetch_contact_list(old_pubkey).await {
+            Ok(current) => {
+                let (content, mut tags) = match current {
+                    Some(event) => (event.content, event.tags),
+                    None => (String::new(), Vec::new()),
+                };
+                let new_contact = new_pubkey.to_string();
+                tags.retain(|tag| match tag.as_vec().as_slice() {
+                    [kind, pubkey, ..] => !(kind == "p" && *pubkey == new_contact),
+                    _ => true,
+                });
+                tags.push(Tag::Generic(
+                    TagKind::P,
+                    vec![new_contact, String::new(), "key_rotation".to_string()],
+                ));
+                match EventBuilder::new(Kind::ContactList, content, tags).to_event(&self.keys) {
+                    Ok(event) => {
+                        if let Err(e) = self.publish_event(event).await {
+                            error!("Failed to publish key rotation contact list: {}", e);
+                        }
+                    }
+                    Err(e) => error!("Failed to sign key rotation contact list: {}", e),
                 }
-                Err(e) => {
-                    error!("Failed to publish to relay {}: {}", relay_url, e);
-                    failed_relays.push(relay_url.to_string());
+            }
+            Err(e) => error!(
+                "Failed to fetch the current contact list, not publishing key rotation contact list: {}",
+                e
+            ),
+        }
 
-                    // Update relay status
-                    let mut status = self.relay_status.lock().await;
-                    status.insert(relay_url.to_string(), false);
+        // Human-readable announcement from the old key
+        let announcement = EventBuilder::new(
+            Kind::TextNote,
+            format!(
+                "This governance server key is being rotated. New key: {}",
+                new_pubkey
+            ),
+            [Tag::Generic(TagKind::P, vec![new_pubkey.to_string()])],
+        )
+        .to_event(&self.keys);
+        match announcement {
+            Ok(event) => {
+                if let Err(e) = self.publish_event(event).await {
+                    error!("Failed to publish key rotation announcement: {}", e);
                 }
             }
+            Err(e) => error!("Failed to sign key rotation announcement: {}", e),
+        }
+
+        // Reconnect to the same relays with the new key
+        let relay_urls: Vec<String> = (*self.client)
+            .relays()
+            .await
+            .keys()
+            .map(|url| url.to_string())
+            .collect();
+        if let Err(e) = (*self.client).disconnect().await {
+            warn!("Failed to disconnect old Nostr client: {}", e);
+        }
+        let client = Client::new(&new_keys);
+        for relay_url in &relay_urls {
+            if let Err(e) = client.add_relay(relay_url.clone()).await {
+                warn!("Failed to connect to relay {}: {}", relay_url, e);
+            }
+        }
+        client.connect().await;
+
+        self.client = Arc::new(client);
+        self.keys = new_keys;
+        self.relay_health = Arc::new(Mutex::new(HashMap::new()));
+        self.relay_auth = Arc::new(Mutex::new(HashMap::new()));
+
+        info!("Nostr key rotated to {}", self.public_key());
+        Ok(new_pubkey)
+    }
+
+    /// The newest NIP-02 contact list published by `author`, if any
+    async fn fetch_contact_list(&self, author: XOnlyPublicKey) -> Result<Option<Event>> {
+        let filter = Filter::new()
+            .author(author)
+            .kind(Kind::ContactList)
+            .limit(1);
+        let events = self
+            .client
+            .get_events_of(vec![filter], Some(Duration::from_secs(10)))
+            .await
+            .map_err(|e| anyhow!("Failed to query contact list: {}", e))?;
+        Ok(events
+            .into_iter()
+            .filter(|event| event.kind == Kind::ContactList)
+            .max_by_key(|event| event.created_at))
+    }
+
+    /// Connect to a relay and complete a NIP-42 AUTH handshake if it asks for one
+    ///
+    /// Waits briefly for an AUTH challenge; the handler turns the challenge into
+    /// a signed kind-22242 event (see `auth_event`), which is sent back and must
+    /// be accepted by the relay. Relays that never challenge are recorded as
+    /// `Unauthenticated`.
+    pub async fn connect_with_auth(
+        &self,
+        relay_url: &str,
+        challenge_handler: impl Fn(&str) -> Result<Event>,
+    ) -> Result<RelayAuthStatus> {
+        let url = Url::parse(relay_url).map_err(|e| anyhow!("Invalid relay URL: {}", e))?;
+
+        // Subscribe before connecting so the challenge isn't missed
+        let mut notifications = self.client.notifications();
+        self.client
+            .add_relay(relay_url)
+            .await
+            .map_err(|e| anyhow!("Failed to add relay {}: {}", relay_url, e))?;
+        self.client
+            .connect_relay(relay_url)
+            .await
+            .map_err(|e| anyhow!("Failed to connect to relay {}: {}", relay_url, e))?;
+
+        let challenge = tokio::time::timeout(AUTH_TIMEOUT, async {
+            while let Ok(notification) = notifications.recv().await {
+                if let RelayPoolNotification::Message {
+                    relay_url: from,
+                    message: RelayMessage::Auth { challenge },
+                } = notification
+                {
+                    if from == url {
+                        return Some(challenge);
+                    }
+                }
+            }
+            None
+        })
+        .await
+        .ok()
+        .flatten();
+
+        let status = match challenge {
+            None => {
+                debug!("Relay {} did not request authentication", relay_url);
+                RelayAuthStatus::Unauthenticated
+            }
+            Some(challenge) => match challenge_handler(&challenge) {
+                Err(e) => RelayAuthStatus::AuthFailed(format!("Failed to sign challenge: {}", e)),
+                Ok(auth_event) => {
+                    let event_id = auth_event.id;
+                    self.client
+                        .send_msg_to(relay_url, ClientMessage::Auth(Box::new(auth_event)))
+                        .await
+                        .map_err(|e| anyhow!("Failed to send AUTH to {}: {}", relay_url, e))?;
+
+                    let reply = tokio::time::timeout(AUTH_TIMEOUT, async {
+                        while let Ok(notification) = notifications.recv().await {
+                            if let RelayPoolNotification::Message {
+                                relay_url: from,
+                                message:
+                                    RelayMessage::Ok {
+                                        event_id: acked,
+                                        status,
+                                        message,
+                                    },
+                            } = notification
+                            {
+                                if from == url && acked == event_id {
+                                    return Some((status, message));
+                                }
+                            }
+                        }
+                        None
+                    })
+                    .await
+                    .ok()
+                    .flatten();
+
+                    match reply {
+                        Some((true, _)) => RelayAuthStatus::Authenticated,
+                        Some((false, message)) => RelayAuthStatus::AuthFailed(message),
+                        None => RelayAuthStatus::AuthFailed("No response to AUTH".to_string()),
+                    }
+                }
+            },
+        };
+
+        match &status {
+            RelayAuthStatus::Authenticated => info!("Authenticated to relay: {}", relay_url),
+            RelayAuthStatus::AuthFailed(reason) => {
+                warn!("Authentication to relay {} failed: {}", relay_url, reason)
+            }
+            RelayAuthStatus::Unauthenticated => info!("Connected to relay: {}", relay_url),
+        }
+        self.relay_auth
+            .lock()
+            .await
+            .insert(url.to_string(), status.clone());
+        Ok(status)
+    }
+
+    /// Build a NIP-42 AUTH event for a relay challenge, signed with the server key
+    pub fn auth_event(&self, relay_url: &str, challenge: &str) -> Result<Event> {
+        let url = Url::parse(relay_url).map_err(|e| anyhow!("Invalid relay URL: {}", e))?;
+        EventBuilder::auth(challenge, url)
+            .to_event(&self.keys)
+            .map_err(|e| anyhow!("Failed to sign AUTH event: {}", e))
+    }
+
+    /// Get NIP-42 authentication status of relays connected via `connect_with_auth`
+    pub async fn get_relay_auth_status(&self) -> HashMap<String, RelayAuthStatus> {
+        self.relay_auth.lock().await.clone()
+    }
+
+    /// Send a NIP-04 encrypted direct message (kind 4) to an npub or hex pubkey
+    ///
+    /// `extra_tags` are added alongside the recipient's `p` tag.
+    pub async fn send_direct_message(
+        &self,
+        recipient: &str,
+        content: &str,
+        extra_tags: Vec<Tag>,
+    ) -> Result<()> {
+        let recipient_key = XOnlyPublicKey::from_bech32(recipient)
+            .or_else(|_| XOnlyPublicKey::from_str(recipient))
+            .map_err(|e| anyhow!("Invalid recipient pubkey {}: {}", recipient, e))?;
+        let secret_key = self
+            .keys
+            .secret_key()
+            .map_err(|e| anyhow!("Server key cannot sign: {}", e))?;
+        let encrypted = nip04::encrypt(&secret_key, &recipient_key, content)
+            .map_err(|e| anyhow!("Failed to encrypt direct message: {}", e))?;
+
+        let mut tags = vec![Tag::Generic(TagKind::P, vec![recipient_key.to_string()])];
+        tags.extend(extra_tags);
+        let event = EventBuilder::new(Kind::EncryptedDirectMessage, encrypted, tags)
+            .to_event(&self.keys)
+            .map_err(|e| anyhow!("Failed to sign direct message: {}", e))?;
+
+        self.publish_event(event).await
+    }
+
+    /// Publish event to all connected relays
+    pub async fn publish_event(&self, event: Event) -> Result<()> {
+        self.publish_with_quorum(event, 1, 0).await.map(|_| ())
+    }
+
+    /// Publish event to all connected relays, retrying relays that failed
+    ///
+    /// Failed relays are retried up to `max_retries` times with exponential
+    /// backoff (in the background once the server is running, see
+    /// [`Self::publish_with_quorum`]). Succeeds if at least one relay accepted
+    /// the event.
+    pub async fn publish_event_with_retry(&self, event: Event, max_retries: u32) -> Result<()> {
+        self.publish_with_quorum(event, 1, max_retries)
+            .await
+            .map(|_| ())
+    }
+
+    /// Publish event to all connected relays and require `min_confirmations` OKs
+    ///
+    /// Relays are sent to concurrently; failed relays are retried up to
+    /// `max_retries` times with exponential backoff. Errors if fewer than
+    /// `min_confirmations` relays confirmed the event.
+    ///
+    /// When the server's shutdown coordinator is registered, only the first
+    /// attempt counts towards the quorum and this returns right after it;
+    /// relays that failed are retried by a background task, so webhook
+    /// processing doesn't wait out the backoff. Without one (tools, tests)
+    /// the retries run inline.
+    ///
+    /// Every publish goes through here, so dry-run mode is enforced here: the
+    /// event is recorded as suppressed and an empty result is returned.
+    pub async fn publish_with_quorum(
+        &self,
+        event: Event,
+        min_confirmations: usize,
+        max_retries: u32,
+    ) -> Result<PublishResult> {
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::Nostr,
+                "publish_event",
+                Some(&event.id.to_hex()),
+                serde_json::json!({
+                    "kind": event.kind.as_u64(),
+                    "content": event.content,
+                }),
+            )
+            .await;
+            return Ok(PublishResult::default());
         }
 
-        if successful_relays == 0 {
-            return Err(anyhow!("Failed to publish to any relay"));
+        let background = crate::shutdown::shared().filter(|_| max_retries > 0);
+        let inline_retries = if background.is_some() { 0 } else { max_retries };
+        let result = {
+            let event = &event;
+            publish_with_retries(
+                &self.relay_health,
+                min_confirmations,
+                inline_retries,
+                |only| self.send_to_relays(event, only),
+            )
+            .await
+        };
+        match (&result, background) {
+            (Ok(published), Some(shutdown)) if !published.failed.is_empty() => {
+                self.spawn_relay_retries(shutdown, event, published.failed_urls(), max_retries);
+            }
+            (Err(_), _) => {
+                crate::metrics::inc_counter(crate::metrics::NOSTR_PUBLISH_ERRORS, &[]);
+            }
+            _ => {}
         }
+        result
+    }
 
-        if !failed_relays.is_empty() {
+    /// Retry relays that failed to accept `event` in a tracked background task
+    ///
+    /// Stops early on shutdown; relays still failing are logged.
+    fn spawn_relay_retries(
+        &self,
+        shutdown: &crate::shutdown::Shutdown,
+        event: Event,
+        mut failed: Vec<String>,
+        max_retries: u32,
+    ) {
+        let client = self.clone();
+        shutdown.spawn("nostr_publish_retry", move |token| async move {
+            for attempt in 0..max_retries {
+                let delay = retry_backoff(attempt);
+                warn!(
+                    "Retrying {} failed relays in {:?} (retry {}/{})",
+                    failed.len(),
+                    delay,
+                    attempt + 1,
+                    max_retries
+                );
+                if !crate::shutdown::sleep(&token, delay).await {
+                    break;
+                }
+                let outcomes = client.send_to_relays(&event, Some(failed)).await;
+                failed = record_outcomes(&client.relay_health, outcomes)
+                    .await
+                    .failed_urls();
+                if failed.is_empty() {
+                    info!(
+                        "Published event {} to every relay after retrying",
+                        event.id.to_hex()
+                    );
+                    return;
+                }
+            }
             warn!(
-                "Failed to publish to {} relays: {:?}",
-                failed_relays.len(),
-                failed_relays
+                "Failed to publish event {} to {} relays: {:?}",
+                event.id.to_hex(),
+                failed.len(),
+                failed
             );
+        });
+    }
+
+    /// Number of relays added to this client
+    pub async fn relay_count(&self) -> usize {
+        (*self.client).relays().await.len()
+    }
+
+    /// Send an event concurrently to connected relays (optionally only the given URLs)
+    ///
+    /// Each send waits for the relay's OK (NIP-20). Authenticated relays are
+    /// sent to first; relays that rejected our AUTH are skipped since they
+    /// won't accept writes.
+    async fn send_to_relays(&self, event: &Event, only: Option<Vec<String>>) -> Vec<RelayOutcome> {
+        let mut sends = tokio::task::JoinSet::new();
+
+        // Get list of connected relays, authenticated ones first
+        let auth = self.relay_auth.lock().await.clone();
+        let mut relays: Vec<_> = (*self.client).relays().await.into_iter().collect();
+        relays.sort_by_key(|(relay_url, _)| {
+            auth.get(&relay_url.to_string()) != Some(&RelayAuthStatus::Authenticated)
+        });
+
+        for (relay_url, relay) in &relays {
+            let url = relay_url.to_string();
+            if let Some(only) = &only {
+                if !only.contains(&url) {
+                    continue;
+                }
+            }
+            if let Some(RelayAuthStatus::AuthFailed(reason)) = auth.get(&url) {
+                debug!("Skipping relay {} (auth failed: {})", url, reason);
+                continue;
+            }
+            if self.relay_breaker(&url).await.is_open().await {
+                debug!("Skipping relay {} (circuit open)", url);
+                continue;
+            }
+
+            let relay = relay.clone();
+            let event = event.clone();
+            sends.spawn(async move {
+                let started = Instant::now();
+                let outcome = relay.send_event(event, RelaySendOptions::new()).await;
+                (
+                    url,
+                    outcome
+                        .map(|_| started.elapsed())
+                        .map_err(|e| e.to_string()),
+                )
+            });
         }
 
-        info!(
-            "Published event to {}/{} relays",
-            successful_relays,
-            relays.len()
-        );
-        Ok(())
+        let mut outcomes = Vec::new();
+        while let Some(joined) = sends.join_next().await {
+            match joined {
+                Ok(outcome) => outcomes.push(outcome),
+                Err(e) => error!("Relay send task failed: {}", e),
+            }
+        }
+
+        for (url, outcome) in &outcomes {
+            let breaker = self.relay_breaker(url).await;
+            match outcome {
+                Ok(_) => breaker.record_success().await,
+                Err(_) => breaker.record_failure().await,
+            }
+        }
+        outcomes
     }
 
-    /// Get current relay status
+    /// Get current relay status (whether the last publish to each relay succeeded)
     pub async fn get_relay_status(&self) -> HashMap<String, bool> {
-        self.relay_status.lock().await.clone()
+        self.relay_health
+            .lock()
+            .await
+            .values()
+            .filter(|health| health.last_success.is_some() || health.last_error.is_some())
+            .map(|health| (health.url.clone(), health.consecutive_failures == 0))
+            .collect()
+    }
+
+    /// Health of every relay added to this client, sorted by URL
+    pub async fn relay_health(&self) -> Vec<RelayHealth> {
+        let mut connected = Vec::new();
+        for (relay_url, relay) in (*self.client).relays().await {
+            connected.push((
+                relay_url.to_string(),
+                relay.status().await == RelayStatus::Connected,
+            ));
+        }
+
+        let mut circuit_states = HashMap::new();
+        for (url, _) in &connected {
+            let state = match self.relay_breakers.lock().await.get(url).cloned() {
+                Some(breaker) => breaker.state().await,
+                None => CircuitState::Closed,
+            };
+            circuit_states.insert(url.clone(), state);
+        }
+
+        let mut health = self.relay_health.lock().await;
+        let mut report: Vec<RelayHealth> = connected
+            .into_iter()
+            .map(|(url, connected)| {
+                let circuit_state = circuit_states[&url];
+                let entry = health.entry(url.clone()).or_insert_with(|| RelayHealth {
+                    url,
+                    ..Default::default()
+                });
+                entry.connected = connected;
+                entry.circuit_state = circuit_state;
+                entry.clone()
+            })
+            .collect();
+        report.sort_by(|a, b| a.url.cmp(&b.url));
+        report
+    }
+
+    /// Add a relay at runtime and connect to it
+    pub async fn add_relay(&self, relay_url: &str) -> Result<()> {
+        let url = Url::parse(relay_url).map_err(|e| anyhow!("Invalid relay URL: {}", e))?;
+        (*self.client)
+            .add_relay(url.clone())
+            .await
+            .map_err(|e| anyhow!("Failed to add relay {}: {}", url, e))?;
+        (*self.client)
+            .connect_relay(url.clone())
+            .await
+            .map_err(|e| anyhow!("Failed to connect to relay {}: {}", url, e))?;
+
+        self.relay_health
+            .lock()
+            .await
+            .entry(url.to_string())
+            .or_insert_with(|| RelayHealth {
+                url: url.to_string(),
+                ..Default::default()
+            });
+        info!("Added relay: {}", url);
+        Ok(())
+    }
+
+    /// Disconnect and remove a relay at runtime
+    pub async fn remove_relay(&self, relay_url: &str) -> Result<()> {
+        let url = Url::parse(relay_url).map_err(|e| anyhow!("Invalid relay URL: {}", e))?;
+        (*self.client)
+            .remove_relay(url.clone())
+            .await
+            .map_err(|e| anyhow!("Failed to remove relay {}: {}", url, e))?;
+
+        self.relay_health.lock().await.remove(&url.to_string());
+        self.relay_auth.lock().await.remove(&url.to_string());
+        info!("Removed relay: {}", url);
+        Ok(())
+    }
+
+    /// Reconnect relays whose connection dropped; returns how many were retried
+    pub async fn reconnect_dropped_relays(&self) -> usize {
+        let mut reconnected = 0;
+        for (relay_url, relay) in (*self.client).relays().await {
+            if !matches!(
+                relay.status().await,
+                RelayStatus::Disconnected | RelayStatus::Terminated
+            ) {
+                continue;
+            }
+
+            match (*self.client).connect_relay(relay_url.clone()).await {
+                Ok(()) => {
+                    info!("Reconnecting to relay: {}", relay_url);
+                    reconnected += 1;
+                }
+                Err(e) => warn!("Failed to reconnect to relay {}: {}", relay_url, e),
+            }
+        }
+        reconnected
+    }
+
+    /// Reconnect dropped relays every `interval` in the background
+    pub fn spawn_relay_monitor(&self, interval: Duration, shutdown: &crate::shutdown::Shutdown) {
+        let client = self.clone();
+        shutdown.spawn("nostr_relay_monitor", |token| async move {
+            let mut ticker = tokio::time::interval(interval);
+            while crate::shutdown::next_tick(&token, &mut ticker).await {
+                client.reconnect_dropped_relays().await;
+            }
+        });
     }
 
     /// Close all relay connections
@@ -183,11 +805,159 @@ impl NostrClient {
         info!("Subscribed to zap events for pubkey: {}", recipient_pubkey);
         Ok(rx)
     }
+
+    /// Query relays for historical zap receipts to a recipient (npub or hex)
+    ///
+    /// `since` and `until` are unix timestamps and both bounds are inclusive.
+    pub async fn fetch_zap_receipts(
+        &self,
+        recipient_pubkey: &str,
+        since: Option<i64>,
+        until: Option<i64>,
+    ) -> Result<Vec<ZapEvent>> {
+        let recipient_key = XOnlyPublicKey::from_bech32(recipient_pubkey)
+            .or_else(|_| XOnlyPublicKey::from_str(recipient_pubkey))
+            .map_err(|e| anyhow!("Invalid recipient pubkey {}: {}", recipient_pubkey, e))?;
+
+        let mut filter = Filter::new().kind(Kind::ZapReceipt).pubkey(recipient_key);
+        if let Some(since) = since {
+            filter = filter.since(Timestamp::from(since.max(0) as u64));
+        }
+        if let Some(until) = until {
+            filter = filter.until(Timestamp::from(until.max(0) as u64));
+        }
+
+        let events = self
+            .client
+            .get_events_of(vec![filter], Some(Duration::from_secs(30)))
+            .await
+            .map_err(|e| anyhow!("Failed to query zap receipts: {}", e))?;
+
+        Ok(events
+            .iter()
+            .filter(|event| event.kind == Kind::ZapReceipt)
+            .filter_map(|event| parse_zap_event(event).ok())
+            .collect())
+    }
+}
+
+/// Delay before the given retry (1s, 2s, 4s, ...)
+fn retry_backoff(attempt: u32) -> Duration {
+    Duration::from_secs(1u64 << attempt.min(6))
+}
+
+/// Send through `send`, retrying failed relays, and record each relay's health
+///
+/// `send` is given the relays to retry (`None` for all) and returns each
+/// relay's outcome. Errors if fewer than `min_confirmations` relays accepted.
+async fn publish_with_retries<F, Fut>(
+    relay_health: &Mutex<HashMap<String, RelayHealth>>,
+    min_confirmations: usize,
+    max_retries: u32,
+    send: F,
+) -> Result<PublishResult>
+where
+    F: Fn(Option<Vec<String>>) -> Fut,
+    Fut: Future<Output = Vec<RelayOutcome>>,
+{
+    let mut result = record_outcomes(relay_health, send(None).await).await;
+    let total_relays = result.confirmed.len() + result.failed.len();
+    if total_relays == 0 {
+        return Err(anyhow!(
+            "No relays available (none configured, or all circuits open)"
+        ));
+    }
+
+    let mut attempt = 0;
+    while !result.failed.is_empty() && attempt < max_retries {
+        let delay = retry_backoff(attempt);
+        warn!(
+            "Retrying {} failed relays in {:?} (retry {}/{})",
+            result.failed.len(),
+            delay,
+            attempt + 1,
+            max_retries
+        );
+        tokio::time::sleep(delay).await;
+
+        let retry = record_outcomes(relay_health, send(Some(result.failed_urls())).await).await;
+        result.confirmed.extend(retry.confirmed);
+        result.failed = retry.failed;
+        attempt += 1;
+    }
+
+    if !result.failed.is_empty() {
+        warn!(
+            "Failed to publish to {} relays: {:?}",
+            result.failed.len(),
+            result.failed
+        );
+    }
+
+    if result.confirmed.len() < min_confirmations {
+        return Err(anyhow!(
+            "Publish quorum not met: {}/{} relays confirmed (need {})",
+            result.confirmed.len(),
+            total_relays,
+            min_confirmations
+        ));
+    }
+
+    info!(
+        "Published event to {}/{} relays",
+        result.confirmed.len(),
+        total_relays
+    );
+    Ok(result)
+}
+
+/// Breaker settings for a relay: consecutive failures, one trial publish to recover
+fn relay_breaker_config(failure_threshold: u32, reset_timeout: Duration) -> CircuitBreakerConfig {
+    CircuitBreakerConfig {
+        failure_threshold,
+        success_threshold: 1,
+        timeout: reset_timeout,
+        window_duration: Duration::MAX,
+        reset_failures_on_success: true,
+    }
+}
+
+/// Update relay health from send outcomes and collect them into a result
+async fn record_outcomes(
+    relay_health: &Mutex<HashMap<String, RelayHealth>>,
+    outcomes: Vec<RelayOutcome>,
+) -> PublishResult {
+    let mut health = relay_health.lock().await;
+    let mut result = PublishResult::default();
+    for (url, outcome) in outcomes {
+        let entry = health.entry(url.clone()).or_insert_with(|| RelayHealth {
+            url: url.clone(),
+            ..Default::default()
+        });
+
+        match outcome {
+            Ok(latency) => {
+                debug!("Published event to relay: {} ({:?})", url, latency);
+                entry.last_success = Some(Utc::now());
+                entry.consecutive_failures = 0;
+                entry.latency_ms = Some(latency.as_millis() as u64);
+                result.confirmed.push(url);
+            }
+            Err(e) => {
+                error!("Failed to publish to relay {}: {}", url, e);
+                entry.last_error = Some(e.clone());
+                entry.consecutive_failures += 1;
+                result.failed.push((url, e));
+            }
+        }
+    }
+    result
 }
 
 /// Parsed zap event from Nostr (NIP-57)
 #[derive(Debug, Clone)]
 pub struct ZapEvent {
+    pub event_id: String, // Zap receipt event id
     pub recipient_pubkey: String,
     pub sender_pubkey: Option<String>,
     pub amount_msat: u64,
@@ -195,6 +965,7 @@ pub struct ZapEvent {
     pub invoice: Option<String>,
     pub message: Option<String>,
     pub zapped_event_id: Option<String>, // Event being zapped (for proposal zaps)
+    pub preimage: Option<String>,        // Payment preimage, if the receipt includes one
 }
 
 /// Parse a Nostr event into a ZapEvent
@@ -279,7 +1050,21 @@ fn parse_zap_event(event: &nostr_sdk::prelude::Event) -> Result<ZapEvent> {
             vec.get(1).map(|s| s.to_string())
         });
 
+    // Extract payment preimage (optional preimage tag)
+    let preimage = event
+        .tags
+        .iter()
+        .find(|tag| {
+            let vec = tag.as_vec();
+            vec.first().map(|s| s.as_str()) == Some("preimage")
+        })
+        .and_then(|tag| {
+            let vec = tag.as_vec();
+            vec.get(1).map(|s| s.to_string())
+        });
+
     Ok(ZapEvent {
+        event_id: event.id.to_string(),
         recipient_pubkey: recipient,
         sender_pubkey,
         amount_msat,
@@ -287,6 +1072,7 @@ fn parse_zap_event(event: &nostr_sdk::prelude::Event) -> Result<ZapEvent> {
         invoice,
         message,
         zapped_event_id,
+        preimage,
     })
 }
 
@@ -306,9 +1092,291 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_retry_backoff() {
+        assert_eq!(retry_backoff(0), Duration::from_secs(1));
+        assert_eq!(retry_backoff(1), Duration::from_secs(2));
+        assert_eq!(retry_backoff(2), Duration::from_secs(4));
+    }
+
+    #[tokio::test]
+    async fn test_publish_with_retry_without_relays() {
+        let keys = Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        let client = NostrClient::new(nsec, vec![]).await.unwrap();
+
+        let event = EventBuilder::new(Kind::TextNote, "test", [])
+            .to_event(&client.keys)
+            .unwrap();
+        assert!(client.publish_event_with_retry(event, 3).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_publish_is_suppressed() {
+        let keys = Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        // A live publish would fail: nothing listens on this port
+        let client = NostrClient::new(nsec, vec!["ws://127.0.0.1:1".to_string()])
+            .await
+            .unwrap()
+            .with_execution_mode(ExecutionMode::DryRun);
+
+        let event = EventBuilder::new(Kind::TextNote, "dry-run status", [])
+            .to_event(&client.keys)
+            .unwrap();
+        let event_id = event.id.to_hex();
+        client.publish_event(event).await.unwrap();
+
+        let recorded = crate::execution_mode::suppressed_for(&event_id);
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].action, "publish_event");
+    }
+
+    #[tokio::test]
+    async fn test_quorum_not_met_with_failing_relay() {
+        let keys = Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        // Nothing listens on this port, so the relay always fails
+        let client = NostrClient::new(nsec, vec!["ws://127.0.0.1:1".to_string()])
+            .await
+            .unwrap();
+
+        let event = EventBuilder::new(Kind::TextNote, "test", [])
+            .to_event(&client.keys)
+            .unwrap();
+        let result = client.publish_with_quorum(event, 1, 0).await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Publish quorum not met"));
+        assert_eq!(
+            client.get_relay_status().await.get("ws://127.0.0.1:1/"),
+            Some(&false)
+        );
+    }
+
+    /// Mock relay layer: relay `i` rejects its first `failures[i]` sends
+    struct MockRelays {
+        urls: Vec<String>,
+        failures: Vec<usize>,
+        sends: std::sync::Mutex<Vec<usize>>,
+    }
+
+    impl MockRelays {
+        fn new(failures: &[usize]) -> Self {
+            Self {
+                urls: (0..failures.len())
+                    .map(|i| format!("wss://relay{}.example.com/", i))
+                    .collect(),
+                failures: failures.to_vec(),
+                sends: std::sync::Mutex::new(vec![0; failures.len()]),
+            }
+        }
+
+        async fn send(&self, only: Option<Vec<String>>) -> Vec<RelayOutcome> {
+            let mut sends = self.sends.lock().unwrap();
+            let mut outcomes = Vec::new();
+            for (i, url) in self.urls.iter().enumerate() {
+                if only.as_ref().is_some_and(|only| !only.contains(url)) {
+                    continue;
+                }
+                sends[i] += 1;
+                let outcome = if sends[i] > self.failures[i] {
+                    Ok(Duration::from_millis(20))
+                } else {
+                    Err("connection refused".to_string())
+                };
+                outcomes.push((url.clone(), outcome));
+            }
+            outcomes
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_quorum_met_with_one_relay_down() {
+        let relays = MockRelays::new(&[0, 0, usize::MAX]);
+        let health = Mutex::new(HashMap::new());
+
+        let result = publish_with_retries(&health, 2, 0, |only| relays.send(only))
+            .await
+            .unwrap();
+        assert_eq!(result.confirmed.len(), 2);
+        assert_eq!(result.failed.len(), 1);
+
+        let health = health.lock().await;
+        let up = &health["wss://relay0.example.com/"];
+        assert!(up.last_success.is_some());
+        assert_eq!(up.latency_ms, Some(20));
+        let down = &health["wss://relay2.example.com/"];
+        assert_eq!(down.consecutive_failures, 1);
+        assert_eq!(down.last_error.as_deref(), Some("connection refused"));
+    }
+
+    #[tokio::test]
+    async fn test_publish_fails_when_every_relay_fails() {
+        let relays = MockRelays::new(&[usize::MAX, usize::MAX]);
+        let health = Mutex::new(HashMap::new());
+
+        let result = publish_with_retries(&health, 1, 1, |only| relays.send(only)).await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Publish quorum not met"));
+
+        // Both the first send and the retry failed
+        let health = health.lock().await;
+        assert!(health
+            .values()
+            .all(|relay| relay.consecutive_failures == 2 && relay.last_success.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_relay_recovers_on_retry() {
+        // Relay 1 is down for its first two sends, then comes back
+        let relays = MockRelays::new(&[0, 2]);
+        let health = Mutex::new(HashMap::new());
+
+        // Quorum of 2 can't be met while relay 1 is down
+        assert!(
+            publish_with_retries(&health, 2, 0, |only| relays.send(only))
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            health.lock().await["wss://relay1.example.com/"].consecutive_failures,
+            1
+        );
+
+        // It fails once more, then accepts the retry
+        let result = publish_with_retries(&health, 2, 1, |only| relays.send(only))
+            .await
+            .unwrap();
+        assert_eq!(result.confirmed.len(), 2);
+        assert!(result.failed.is_empty());
+
+        let health = health.lock().await;
+        let recovered = &health["wss://relay1.example.com/"];
+        assert_eq!(recovered.consecutive_failures, 0);
+        assert!(recovered.last_success.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_auth_event() {
+        let keys = Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        let client = NostrClient::new(nsec, vec![]).await.unwrap();
+
+        let event = client
+            .auth_event("wss://relay.example.com", "challenge-123")
+            .unwrap();
+        assert_eq!(event.kind, Kind::Authentication);
+        assert_eq!(event.pubkey, keys.public_key());
+        assert!(event.verify().is_ok());
+
+        let tags: Vec<Vec<String>> = event.tags.iter().map(|t| t.as_vec()).collect();
+        assert!(tags.contains(&vec!["challenge".to_string(), "challenge-123".to_string()]));
+        assert!(tags
+            .iter()
+            .any(|t| t[0] == "relay" && t[1].starts_with("wss://relay.example.com")));
+
+        assert!(client.get_relay_auth_status().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rotate_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let new_keys = Keys::generate();
+        let new_nsec_path = dir.path().join("new.nsec");
+        std::fs::write(
+            &new_nsec_path,
+            new_keys.secret_key().unwrap().display_secret().to_string(),
+        )
+        .unwrap();
+        let config_path = dir.path().join("app.toml");
+        std::fs::write(
+            &config_path,
+            "[server]\nport = 3000\n\n[nostr]\n# Server key\nserver_nsec_path = \"/etc/old.nsec\"\nrelays = []\n",
+        )
+        .unwrap();
+
+        let keys = Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        let mut client = NostrClient::new(nsec, vec![]).await.unwrap();
+
+        // Publishing fails without relays, but rotation still completes
+        let new_pubkey = client.rotate_key(&new_nsec_path).await.unwrap();
+        assert_eq!(new_pubkey, new_keys.public_key());
+        assert_eq!(client.public_key(), new_keys.public_key().to_string());
+
+        // The caller records the new key's path
+        crate::config::update_nsec_path(&config_path, &new_nsec_path).unwrap();
+        let config = std::fs::read_to_string(&config_path).unwrap();
+        assert!(config.contains("# Server key"));
+        let config: toml::Table = toml::from_str(&config).unwrap();
+        assert_eq!(
+            config["nostr"]["server_nsec_path"].as_str(),
+            Some(new_nsec_path.to_str().unwrap())
+        );
+        assert_eq!(config["server"]["port"].as_integer(), Some(3000));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_key_rejects_missing_or_invalid_key() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let keys = Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        let mut client = NostrClient::new(nsec, vec![]).await.unwrap();
+
+        assert!(client
+            .rotate_key(&dir.path().join("missing.nsec"))
+            .await
+            .is_err());
+
+        let invalid_path = dir.path().join("invalid.nsec");
+        std::fs::write(&invalid_path, "not-a-key").unwrap();
+        assert!(client.rotate_key(&invalid_path).await.is_err());
+
+        assert_eq!(client.public_key(), keys.public_key().to_string());
+    }
+
     #[tokio::test]
     async fn test_invalid_key() {
         let result = NostrClient::new("invalid_key".to_string(), vec![]).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_relay_circuit_opens_and_recovers() {
+        let keys = Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        let client = NostrClient::new(nsec, vec![])
+            .await
+            .unwrap()
+            .with_relay_circuit_breaker(2, Duration::from_millis(50));
+        let breaker = client.relay_breaker("wss://relay.example").await;
+
+        breaker.record_failure().await;
+        breaker.record_success().await;
+        breaker.record_failure().await;
+        assert_eq!(breaker.state().await, CircuitState::Closed);
+        breaker.record_failure().await;
+        assert!(breaker.is_open().await);
+
+        // After the reset timeout one trial publish is let through
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(!breaker.is_open().await);
+        assert_eq!(breaker.state().await, CircuitState::HalfOpen);
+        breaker.record_failure().await;
+        assert!(breaker.is_open().await);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(!breaker.is_open().await);
+        breaker.record_success().await;
+        assert_eq!(breaker.state().await, CircuitState::Closed);
+        assert!(Arc::ptr_eq(
+            &breaker,
+            &client.relay_breaker("wss://relay.example").await
+        ));
+    }
 }
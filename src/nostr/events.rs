@@ -3,12 +3,34 @@
 //! Defines the structure of governance status events published to Nostr.
 //! Includes governance actions, keyholder announcements, and node telemetry.
 
+use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
+use nostr_sdk::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::nostr::client::RelayHealth;
+
+/// Event kind for governance status (NIP-33 parameterized replaceable, keyed by
+/// the `d` tag, which is the server id)
+pub const STATUS_EVENT_KIND: u64 = 30078;
+
+/// Current `GovernanceStatus` schema version
+pub const STATUS_SCHEMA_VERSION: u32 = 3;
+
+/// Oldest `GovernanceStatus` schema version still accepted; fields added since
+/// then default to empty
+pub const MIN_STATUS_SCHEMA_VERSION: u32 = 2;
+
+/// Largest status event content published; relays reject oversized events
+pub const MAX_STATUS_CONTENT_BYTES: usize = 32 * 1024;
+
+/// Most entries kept in any list in a status event
+pub const MAX_STATUS_LIST_ITEMS: usize = 50;
+
 /// Governance status event published to Nostr
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GovernanceStatus {
+    pub schema_version: u32,
     pub server_id: String,
     pub timestamp: DateTime<Utc>,
     pub hashes: Hashes,
@@ -16,6 +38,48 @@ pub struct GovernanceStatus {
     pub next_ots_anchor: DateTime<Utc>,
     pub audit_log_head: Option<String>,
     pub audit_log_length: Option<u64>,
+    /// Governance state (schema version 3+)
+    #[serde(default)]
+    pub governance: GovernanceSummary,
+    /// Set when lists were cut to fit the size budget
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+/// Governance state gathered from the database
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GovernanceSummary {
+    /// Most recently recorded governance phase
+    pub phase: Option<String>,
+    /// Active registered nodes by node type
+    pub node_types: Vec<NodeTypeSummary>,
+    /// Active registered nodes, heaviest first
+    pub top_nodes: Vec<NodeWeightSummary>,
+    /// Newest governance registry and whether it has an OTS proof
+    pub registry_anchor: Option<RegistryAnchorSummary>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeTypeSummary {
+    pub node_type: String,
+    pub active_nodes: i64,
+    /// Sum of the nodes' effective weights
+    pub total_weight: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeWeightSummary {
+    pub node_id: String,
+    pub node_type: String,
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryAnchorSummary {
+    pub registry_hash: String,
+    pub month_year: String,
+    pub timestamp: DateTime<Utc>,
+    pub anchored: bool,
 }
 
 /// File hashes for verification
@@ -23,6 +87,12 @@ pub struct GovernanceStatus {
 pub struct Hashes {
     pub binary: String, // sha256:...
     pub config: String, // sha256:...
+    /// Hash of the newest audit log entry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_chain_head: Option<String>,
+    /// Hash of the newest database backup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest_backup: Option<String>,
 }
 
 /// Server health information
@@ -33,6 +103,8 @@ pub struct ServerHealth {
     pub last_merge: Option<DateTime<Utc>>,
     pub merges_today: i64,
     pub relay_status: std::collections::HashMap<String, bool>,
+    #[serde(default)]
+    pub relay_health: Vec<RelayHealth>,
 }
 
 impl GovernanceStatus {
@@ -51,11 +123,14 @@ impl GovernanceStatus {
         audit_log_length: Option<u64>,
     ) -> Self {
         Self {
+            schema_version: STATUS_SCHEMA_VERSION,
             server_id,
             timestamp: Utc::now(),
             hashes: Hashes {
                 binary: binary_hash,
                 config: config_hash,
+                audit_chain_head: None,
+                latest_backup: None,
             },
             health: ServerHealth {
                 uptime_hours,
@@ -63,10 +138,42 @@ impl GovernanceStatus {
                 last_merge,
                 merges_today,
                 relay_status,
+                relay_health: Vec::new(),
             },
             next_ots_anchor,
             audit_log_head,
             audit_log_length,
+            governance: GovernanceSummary::default(),
+            truncated: false,
+        }
+    }
+
+    /// Cut lists until the serialized status fits in `max_bytes`
+    ///
+    /// Lists are first capped at [`MAX_STATUS_LIST_ITEMS`], then the longest
+    /// list is halved until the content fits. Sets `truncated` if anything
+    /// was dropped.
+    pub fn apply_size_budget(&mut self, max_bytes: usize) {
+        let nodes = &mut self.governance.top_nodes;
+        let relays = &mut self.health.relay_health;
+        if nodes.len() > MAX_STATUS_LIST_ITEMS || relays.len() > MAX_STATUS_LIST_ITEMS {
+            nodes.truncate(MAX_STATUS_LIST_ITEMS);
+            relays.truncate(MAX_STATUS_LIST_ITEMS);
+            self.truncated = true;
+        }
+
+        while self.to_json().is_ok_and(|json| json.len() > max_bytes) {
+            let nodes = self.governance.top_nodes.len();
+            let relays = self.health.relay_health.len();
+            if nodes == 0 && relays == 0 {
+                break;
+            }
+            if nodes >= relays {
+                self.governance.top_nodes.truncate(nodes / 2);
+            } else {
+                self.health.relay_health.truncate(relays / 2);
+            }
+            self.truncated = true;
         }
     }
 
@@ -87,6 +194,48 @@ impl GovernanceStatus {
     }
 }
 
+/// Verify a published governance status event and return its status
+///
+/// Checks the event id and signature, the kind, the schema version, and that the
+/// `d` tag matches the server id in the content.
+pub fn verify_status_event(event_json: &str) -> Result<GovernanceStatus> {
+    let event = Event::from_json(event_json).map_err(|e| anyhow!("Invalid status event: {}", e))?;
+    event
+        .verify()
+        .map_err(|e| anyhow!("Status event failed verification: {}", e))?;
+
+    if event.kind.as_u64() != STATUS_EVENT_KIND {
+        return Err(anyhow!(
+            "Unexpected status event kind: {}",
+            event.kind.as_u64()
+        ));
+    }
+
+    let status: GovernanceStatus = serde_json::from_str(&event.content)
+        .map_err(|e| anyhow!("Invalid status event content: {}", e))?;
+    if !(MIN_STATUS_SCHEMA_VERSION..=STATUS_SCHEMA_VERSION).contains(&status.schema_version) {
+        return Err(anyhow!(
+            "Unsupported status schema version: {}",
+            status.schema_version
+        ));
+    }
+
+    let d_tag = event
+        .tags
+        .iter()
+        .map(|tag| tag.as_vec())
+        .find(|values| values.first().map(String::as_str) == Some("d"))
+        .and_then(|values| values.get(1).cloned());
+    if d_tag.as_deref() != Some(status.server_id.as_str()) {
+        return Err(anyhow!(
+            "Status event d tag does not match server {}",
+            status.server_id
+        ));
+    }
+
+    Ok(status)
+}
+
 /// Governance action event (Kind 30078)
 /// Published when governance actions occur (merges, releases, etc.)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -258,4 +407,115 @@ mod tests {
         assert!(summary.contains("48h uptime"));
         assert!(summary.contains("3 merges today"));
     }
+
+    fn signed_status_event(status: &GovernanceStatus, keys: &Keys) -> Event {
+        let tags = vec![Tag::Generic(
+            TagKind::Custom("d".into()),
+            vec![status.server_id.clone()],
+        )];
+        EventBuilder::new(
+            Kind::Custom(STATUS_EVENT_KIND),
+            status.to_json().unwrap(),
+            tags,
+        )
+        .to_event(keys)
+        .unwrap()
+    }
+
+    #[test]
+    fn test_verify_status_event_round_trip() {
+        let keys = Keys::generate();
+        let mut status = GovernanceStatus::new(
+            "governance-01".to_string(),
+            "sha256:abc".to_string(),
+            "sha256:def".to_string(),
+            12,
+            None,
+            None,
+            0,
+            Utc::now(),
+            HashMap::new(),
+            None,
+            None,
+        );
+        status.hashes.audit_chain_head = Some("sha256:head".to_string());
+        status.hashes.latest_backup = Some("sha256:backup".to_string());
+
+        let event_json = signed_status_event(&status, &keys).as_json();
+        let verified = verify_status_event(&event_json).unwrap();
+        assert_eq!(verified.schema_version, STATUS_SCHEMA_VERSION);
+        assert_eq!(verified.server_id, "governance-01");
+        assert_eq!(
+            verified.hashes.audit_chain_head.as_deref(),
+            Some("sha256:head")
+        );
+        assert_eq!(
+            verified.hashes.latest_backup.as_deref(),
+            Some("sha256:backup")
+        );
+
+        // Any change to the signed content invalidates the event
+        let mut tampered: serde_json::Value = serde_json::from_str(&event_json).unwrap();
+        let content = tampered["content"]
+            .as_str()
+            .unwrap()
+            .replace("sha256:abc", "sha256:evil");
+        tampered["content"] = serde_json::Value::String(content);
+        assert!(verify_status_event(&tampered.to_string()).is_err());
+
+        // Version 2 statuses, without the governance summary, still verify
+        let mut content: serde_json::Value =
+            serde_json::from_str(&status.to_json().unwrap()).unwrap();
+        content["schema_version"] = serde_json::json!(MIN_STATUS_SCHEMA_VERSION);
+        content.as_object_mut().unwrap().remove("governance");
+        content.as_object_mut().unwrap().remove("truncated");
+        let tags = vec![Tag::Generic(
+            TagKind::Custom("d".into()),
+            vec![status.server_id.clone()],
+        )];
+        let event = EventBuilder::new(Kind::Custom(STATUS_EVENT_KIND), content.to_string(), tags)
+            .to_event(&keys)
+            .unwrap();
+        let verified = verify_status_event(&event.as_json()).unwrap();
+        assert!(verified.governance.top_nodes.is_empty());
+        assert!(!verified.truncated);
+
+        // Unknown schema versions are rejected, even when correctly signed
+        status.schema_version = STATUS_SCHEMA_VERSION + 1;
+        let event_json = signed_status_event(&status, &keys).as_json();
+        assert!(verify_status_event(&event_json).is_err());
+    }
+
+    #[test]
+    fn test_apply_size_budget() {
+        let mut status = GovernanceStatus::new(
+            "governance-01".to_string(),
+            "sha256:abc".to_string(),
+            "sha256:def".to_string(),
+            1,
+            None,
+            None,
+            0,
+            Utc::now(),
+            HashMap::new(),
+            None,
+            None,
+        );
+        status.governance.top_nodes = (0..10)
+            .map(|i| NodeWeightSummary {
+                node_id: format!("node-{:03}", i),
+                node_type: "miner".to_string(),
+                weight: 1.0,
+            })
+            .collect();
+
+        // Small enough already: nothing dropped
+        status.apply_size_budget(MAX_STATUS_CONTENT_BYTES);
+        assert!(!status.truncated);
+        assert_eq!(status.governance.top_nodes.len(), 10);
+
+        status.apply_size_budget(status.to_json().unwrap().len() - 1);
+        assert!(status.truncated);
+        assert_eq!(status.governance.top_nodes.len(), 5);
+    }
 }
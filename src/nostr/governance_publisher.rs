@@ -8,17 +8,27 @@ use chrono::Utc;
 use nostr_sdk::prelude::*;
 use tracing::info;
 
+use crate::execution_mode::ExecutionMode;
 use crate::nostr::client::NostrClient;
 use crate::nostr::events::{
     CombinedRequirement, EconomicVetoStatus, GovernanceActionEvent, KeyholderSignature,
     LayerRequirement, TierRequirement,
 };
 
+/// Retries for relays that fail to accept a governance action event
+const PUBLISH_MAX_RETRIES: u32 = 3;
+
+/// Default quorum for governance action events: half of the relays (at least one)
+fn default_action_quorum(relay_count: usize) -> usize {
+    relay_count.div_ceil(2).max(1)
+}
+
 /// Publisher for governance action events
 pub struct GovernanceActionPublisher {
     client: NostrClient,
     governance_config: String,   // e.g., "commons_mainnet"
     zap_address: Option<String>, // Lightning address for donations
+    min_quorum: Option<usize>,   // Defaults to half of the relays
 }
 
 impl GovernanceActionPublisher {
@@ -32,9 +42,22 @@ impl GovernanceActionPublisher {
             client,
             governance_config,
             zap_address,
+            min_quorum: None,
         }
     }
 
+    /// In dry-run mode events are built and signed but not sent to relays
+    pub fn with_execution_mode(mut self, execution_mode: ExecutionMode) -> Self {
+        self.client = self.client.with_execution_mode(execution_mode);
+        self
+    }
+
+    /// Override how many relays must confirm each governance action event
+    pub fn with_min_quorum(mut self, min_quorum: usize) -> Self {
+        self.min_quorum = Some(min_quorum);
+        self
+    }
+
     /// Publish a governance action event (merge, release, etc.)
     pub async fn publish_action(
         &self,
@@ -87,8 +110,14 @@ impl GovernanceActionPublisher {
             &action_event,
         )?;
 
-        // Publish to relays
-        self.client.publish_event(event).await?;
+        // Publish to relays, retrying relays that fail, and require a quorum
+        let min_quorum = match self.min_quorum {
+            Some(min_quorum) => min_quorum,
+            None => default_action_quorum(self.client.relay_count().await),
+        };
+        self.client
+            .publish_with_quorum(event, min_quorum, PUBLISH_MAX_RETRIES)
+            .await?;
 
         info!("Successfully published governance action event");
         Ok(())
@@ -165,10 +194,10 @@ impl GovernanceActionPublisher {
             .to_event(&self.client.keys)
             .map_err(|e| anyhow!("Failed to create proposal event: {}", e))?;
 
+        let event_id = event.id.to_string();
         // Publish to relays
         self.client.publish_event(event.clone()).await?;
 
-        let event_id = event.id.to_string();
         info!(
             "Published governance proposal {} (event ID: {}) with zap-to-vote",
             pr_id, event_id
@@ -283,6 +312,14 @@ mod tests {
         GovernanceActionPublisher::new(client, "test-config".to_string(), None)
     }
 
+    #[test]
+    fn test_default_action_quorum() {
+        assert_eq!(default_action_quorum(0), 1);
+        assert_eq!(default_action_quorum(1), 1);
+        assert_eq!(default_action_quorum(4), 2);
+        assert_eq!(default_action_quorum(5), 3);
+    }
+
     #[tokio::test]
     async fn test_governance_action_publisher_new() {
         let publisher = create_test_publisher().await;
@@ -308,6 +345,78 @@ mod tests {
         assert_eq!(publisher.zap_address, Some("zap@example.com".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_publish_action_dry_run() {
+        // No relays are configured, so a live publish could not reach quorum
+        let publisher = create_test_publisher()
+            .await
+            .with_execution_mode(ExecutionMode::DryRun);
+        let repository = format!("dry-run-{}", uuid::Uuid::new_v4());
+
+        publisher
+            .publish_action(
+                "merge",
+                2,
+                1,
+                &repository,
+                "3-of-5",
+                30,
+                None,
+                Some(1),
+                "Dry-run merge",
+                LayerRequirement {
+                    layer: 1,
+                    signatures: "3-of-5".to_string(),
+                    review_days: 30,
+                },
+                TierRequirement {
+                    tier: 2,
+                    signatures: "3-of-5".to_string(),
+                    review_days: 30,
+                    economic_veto: false,
+                },
+                CombinedRequirement {
+                    signatures: "3-of-5".to_string(),
+                    review_days: 30,
+                    economic_veto: false,
+                    source: "tier".to_string(),
+                },
+                vec![],
+                EconomicVetoStatus::NotRequired,
+                None,
+            )
+            .await
+            .unwrap();
+
+        // Suppressed by the client, with the signed event's content
+        let recorded: Vec<_> = crate::execution_mode::recent_suppressed()
+            .into_iter()
+            .filter(|action| {
+                action.details["content"]
+                    .as_str()
+                    .is_some_and(|content| content.contains(&repository))
+            })
+            .collect();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].action, "publish_event");
+    }
+
+    #[tokio::test]
+    async fn test_publish_proposal_dry_run() {
+        let publisher = create_test_publisher()
+            .await
+            .with_execution_mode(ExecutionMode::DryRun);
+
+        let event_id = publisher
+            .publish_proposal(7, 2, "org/repo", "Title", "Description", 7)
+            .await
+            .unwrap();
+
+        let recorded = crate::execution_mode::suppressed_for(&event_id);
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].action, "publish_event");
+    }
+
     #[tokio::test]
     async fn test_governance_action_publisher_governance_config() {
         let publisher = create_test_publisher().await;
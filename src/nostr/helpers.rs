@@ -93,7 +93,8 @@ pub async fn publish_merge_action(
         client,
         config.nostr.governance_config.clone(),
         config.nostr.zap_address.clone(),
-    );
+    )
+    .with_execution_mode(config.execution_mode());
 
     // Publish action
     publisher
@@ -200,7 +201,9 @@ pub async fn publish_review_period_notification(
     let nsec = std::fs::read_to_string(&config.nostr.server_nsec_path)
         .map_err(|e| anyhow::anyhow!("Failed to read Nostr key: {}", e))?;
 
-    let client = NostrClient::new(nsec, config.nostr.relays.clone()).await?;
+    let client = NostrClient::new(nsec, config.nostr.relays.clone())
+        .await?
+        .with_execution_mode(config.execution_mode());
     let keys = &client.keys;
 
     // Create review period notification event (Kind 30023 - Long-form)
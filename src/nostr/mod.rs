@@ -3,6 +3,7 @@
 //! This module provides real-time transparency for governance operations
 //! by publishing status updates to the Nostr protocol.
 
+pub mod api;
 pub mod bot_manager;
 pub mod client;
 pub mod events;
@@ -13,11 +14,13 @@ pub mod zap_tracker;
 pub mod zap_voting;
 
 pub use bot_manager::NostrBotManager;
-pub use client::{NostrClient, ZapEvent};
+pub use client::{NostrClient, PublishResult, RelayAuthStatus, RelayHealth, ZapEvent};
 pub use events::{
-    CombinedRequirement, EconomicVetoStatus, GovernanceActionEvent, GovernanceStatus, Hashes,
-    KeyholderAnnouncement, KeyholderSignature, LayerRequirement, NodeStatusReport, ServerHealth,
-    TierRequirement,
+    verify_status_event, CombinedRequirement, EconomicVetoStatus, GovernanceActionEvent,
+    GovernanceStatus, GovernanceSummary, Hashes, KeyholderAnnouncement, KeyholderSignature,
+    LayerRequirement, NodeStatusReport, NodeTypeSummary, NodeWeightSummary, RegistryAnchorSummary,
+    ServerHealth, TierRequirement, MAX_STATUS_CONTENT_BYTES, MAX_STATUS_LIST_ITEMS,
+    MIN_STATUS_SCHEMA_VERSION, STATUS_EVENT_KIND, STATUS_SCHEMA_VERSION,
 };
 pub use governance_publisher::GovernanceActionPublisher;
 pub use helpers::{
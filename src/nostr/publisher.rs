@@ -1,7 +1,8 @@
 //! Nostr Status Publisher
 //!
 //! Publishes hourly governance status updates to Nostr relays
-//! with server health, audit log information, and verification hashes.
+//! with server health, audit log information, verification hashes, and a
+//! summary of governance state (phase, registered nodes, registry anchor).
 
 use ::hex;
 use anyhow::{anyhow, Result};
@@ -9,12 +10,18 @@ use chrono::{DateTime, Datelike, Timelike, Utc};
 use nostr_sdk::prelude::*;
 use sha2::{Digest, Sha256};
 use std::fs;
+use std::path::PathBuf;
 use tracing::{info, warn};
 
 use crate::audit::logger::AuditLogger;
 use crate::database::Database;
-use crate::nostr::client::NostrClient;
-use crate::nostr::events::{GovernanceStatus, ServerHealth};
+use crate::governance::GovernancePhaseCalculator;
+use crate::node_registry::NodeRegistry;
+use crate::nostr::client::{NostrClient, PublishResult};
+use crate::nostr::events::{
+    GovernanceStatus, GovernanceSummary, NodeTypeSummary, NodeWeightSummary, RegistryAnchorSummary,
+    ServerHealth, MAX_STATUS_CONTENT_BYTES, MAX_STATUS_LIST_ITEMS, STATUS_EVENT_KIND,
+};
 
 /// Status publisher for governance infrastructure
 pub struct StatusPublisher {
@@ -24,7 +31,11 @@ pub struct StatusPublisher {
     binary_path: String,
     config_path: String,
     audit_log_path: Option<String>,
+    /// Directory holding database backups; its newest backup is hashed
+    backup_dir: Option<PathBuf>,
     start_time: DateTime<Utc>,
+    /// Relays that must confirm each status event
+    min_quorum: usize,
 }
 
 impl StatusPublisher {
@@ -44,10 +55,35 @@ impl StatusPublisher {
             binary_path,
             config_path,
             audit_log_path,
+            backup_dir: None,
             start_time: Utc::now(),
+            min_quorum: 1,
         }
     }
 
+    /// Include the hash of the newest backup in `backup_dir` in each status
+    pub fn with_backup_dir(mut self, backup_dir: PathBuf) -> Self {
+        self.backup_dir = Some(backup_dir);
+        self
+    }
+
+    /// Require at least `min_quorum` relays to confirm each status event
+    pub fn with_min_quorum(mut self, min_quorum: usize) -> Self {
+        self.min_quorum = min_quorum;
+        self
+    }
+
+    /// Publish an event to all relays, failing unless `min_confirmations` confirm it
+    pub async fn publish_with_quorum(
+        &self,
+        event: Event,
+        min_confirmations: usize,
+    ) -> Result<PublishResult> {
+        self.client
+            .publish_with_quorum(event, min_confirmations, 0)
+            .await
+    }
+
     /// Publish current governance status
     pub async fn publish_status(&self) -> Result<()> {
         info!(
@@ -55,6 +91,23 @@ impl StatusPublisher {
             self.server_id
         );
 
+        let status = self.build_status().await?;
+        if status.truncated {
+            warn!("Governance status truncated to fit relay size limits");
+        }
+
+        // Create Nostr event
+        let event = self.create_nostr_event(status)?;
+
+        // Publish to relays
+        self.publish_with_quorum(event, self.min_quorum).await?;
+
+        info!("Successfully published governance status");
+        Ok(())
+    }
+
+    /// Gather the current governance status, cut to the relay size budget
+    pub async fn build_status(&self) -> Result<GovernanceStatus> {
         // Calculate file hashes
         let binary_hash = self.calculate_file_hash(&self.binary_path)?;
         let config_hash = self.calculate_file_hash(&self.config_path)?;
@@ -63,13 +116,14 @@ impl StatusPublisher {
         let health = self.get_server_health().await?;
 
         // Get audit log information
-        let (audit_log_head, audit_log_length) = self.get_audit_log_info().await?;
+        let (audit_log_head, audit_log_length, audit_chain_head) =
+            self.get_audit_log_info().await?;
 
         // Calculate next OTS anchor date (first day of next month)
         let next_ots_anchor = self.calculate_next_ots_anchor();
 
         // Create status event
-        let status = GovernanceStatus::new(
+        let mut status = GovernanceStatus::new(
             self.server_id.clone(),
             binary_hash,
             config_hash,
@@ -82,15 +136,13 @@ impl StatusPublisher {
             audit_log_head,
             audit_log_length,
         );
+        status.health.relay_health = health.relay_health;
+        status.hashes.audit_chain_head = audit_chain_head;
+        status.hashes.latest_backup = self.latest_backup_hash();
+        status.governance = self.get_governance_summary().await;
+        status.apply_size_budget(MAX_STATUS_CONTENT_BYTES);
 
-        // Create Nostr event
-        let event = self.create_nostr_event(status)?;
-
-        // Publish to relays
-        self.client.publish_event(event).await?;
-
-        info!("Successfully published governance status");
-        Ok(())
+        Ok(status)
     }
 
     /// Calculate SHA256 hash of a file
@@ -105,6 +157,44 @@ impl StatusPublisher {
         Ok(format!("sha256:{}", hex::encode(hash)))
     }
 
+    /// Hash of the newest backup file, if a backup directory is configured
+    fn latest_backup_hash(&self) -> Option<String> {
+        let backup_dir = self.backup_dir.as_ref()?;
+        let entries = match fs::read_dir(backup_dir) {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!(
+                    "Failed to read backup directory {}: {}",
+                    backup_dir.display(),
+                    e
+                );
+                return None;
+            }
+        };
+
+        let latest = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with("governance_backup_")
+            })
+            .filter_map(|entry| {
+                let modified = entry.metadata().ok()?.modified().ok()?;
+                Some((modified, entry.path()))
+            })
+            .max_by_key(|(modified, _)| *modified)?;
+
+        match self.calculate_file_hash(&latest.1.to_string_lossy()) {
+            Ok(hash) => Some(hash),
+            Err(e) => {
+                warn!("Failed to hash latest backup: {}", e);
+                None
+            }
+        }
+    }
+
     /// Get server health information
     async fn get_server_health(&self) -> Result<ServerHealth> {
         // Calculate uptime
@@ -130,8 +220,8 @@ impl StatusPublisher {
         };
 
         // Get relay status from Nostr client
-        // Note: Relay status tracking is already implemented in NostrClient
         let relay_status = self.client.get_relay_status().await;
+        let relay_health = self.client.relay_health().await;
 
         Ok(ServerHealth {
             uptime_hours,
@@ -139,16 +229,98 @@ impl StatusPublisher {
             last_merge: last_merge_time,
             merges_today: merges_today as i64,
             relay_status,
+            relay_health,
         })
     }
 
+    /// Summarize governance state: phase, registered nodes and registry anchor
+    ///
+    /// Parts that can't be read are left empty rather than failing the status.
+    async fn get_governance_summary(&self) -> GovernanceSummary {
+        let mut summary = GovernanceSummary::default();
+        let pool = match self.database.get_sqlite_pool() {
+            Some(pool) => pool,
+            None => {
+                warn!("Governance summary unavailable: database is not SQLite");
+                return summary;
+            }
+        };
+
+        match GovernancePhaseCalculator::new(pool.clone())
+            .get_last_recorded_phase()
+            .await
+        {
+            Ok(phase) => summary.phase = phase.map(|phase| phase.as_str().to_string()),
+            Err(e) => warn!("Failed to get governance phase: {}", e),
+        }
+
+        let registry = NodeRegistry::new(pool.clone());
+        match registry.active_weight_by_type().await {
+            Ok(totals) => {
+                summary.node_types = totals
+                    .into_iter()
+                    .map(|(node_type, active_nodes, total_weight)| NodeTypeSummary {
+                        node_type,
+                        active_nodes,
+                        total_weight,
+                    })
+                    .collect()
+            }
+            Err(e) => warn!("Failed to summarize registered nodes: {}", e),
+        }
+        // One past the cap so the size budget knows the list was cut
+        match registry
+            .heaviest_active_nodes(MAX_STATUS_LIST_ITEMS as i64 + 1)
+            .await
+        {
+            Ok(nodes) => {
+                summary.top_nodes = nodes
+                    .into_iter()
+                    .map(|(node_id, node_type, weight)| NodeWeightSummary {
+                        node_id,
+                        node_type,
+                        weight,
+                    })
+                    .collect()
+            }
+            Err(e) => warn!("Failed to list registered nodes: {}", e),
+        }
+
+        let anchor = sqlx::query_as::<_, (String, String, DateTime<Utc>, bool)>(
+            r#"
+            SELECT registry_hash, month_year, timestamp, ots_proof_path IS NOT NULL
+            FROM governance_registries
+            ORDER BY timestamp DESC
+            LIMIT 1
+            "#,
+        )
+        .fetch_optional(&pool)
+        .await;
+        match anchor {
+            Ok(anchor) => {
+                summary.registry_anchor =
+                    anchor.map(|(registry_hash, month_year, timestamp, anchored)| {
+                        RegistryAnchorSummary {
+                            registry_hash,
+                            month_year,
+                            timestamp,
+                            anchored,
+                        }
+                    })
+            }
+            Err(e) => warn!("Failed to get latest registry anchor: {}", e),
+        }
+
+        summary
+    }
+
     /// Get audit log information
-    /// Returns (merkle_root, entry_count) for the audit log
-    async fn get_audit_log_info(&self) -> Result<(Option<String>, Option<u64>)> {
+    /// Returns (merkle_root, entry_count, chain_head) for the audit log
+    async fn get_audit_log_info(&self) -> Result<(Option<String>, Option<u64>, Option<String>)> {
         // If audit logging is not enabled or path not configured, return None
         let log_path = match &self.audit_log_path {
             Some(path) => path,
-            None => return Ok((None, None)),
+            None => return Ok((None, None, None)),
         };
 
         // Create audit logger to read entries
@@ -159,7 +331,7 @@ impl StatusPublisher {
                     "Failed to create audit logger: {}. Audit log info unavailable.",
                     e
                 );
-                return Ok((None, None));
+                return Ok((None, None, None));
             }
         };
 
@@ -171,7 +343,7 @@ impl StatusPublisher {
                     "Failed to read audit log entries: {}. Audit log info unavailable.",
                     e
                 );
-                return Ok((None, None));
+                return Ok((None, None, None));
             }
         };
 
@@ -183,14 +355,16 @@ impl StatusPublisher {
                         .to_string(),
                 ),
                 Some(0),
+                None,
             ));
         }
 
         // Calculate Merkle root from all entries
         let merkle_root = Self::calculate_merkle_root(&entries)?;
         let entry_count = entries.len() as u64;
+        let chain_head = entries.last().map(|entry| entry.this_log_hash.clone());
 
-        Ok((Some(merkle_root), Some(entry_count)))
+        Ok((Some(merkle_root), Some(entry_count), chain_head))
     }
 
     /// Calculate Merkle root from audit log entries
@@ -284,10 +458,7 @@ impl StatusPublisher {
         let current_month = Utc::now().format("%Y-%m").to_string();
 
         let tags = vec![
-            Tag::Generic(
-                TagKind::Custom("d".into()),
-                vec!["governance-status".to_string()],
-            ),
+            Tag::Generic(TagKind::Custom("d".into()), vec![self.server_id.clone()]),
             Tag::Generic(
                 TagKind::Custom("server".into()),
                 vec![self.server_id.clone()],
@@ -306,7 +477,7 @@ impl StatusPublisher {
             ),
         ];
 
-        let event = EventBuilder::new(Kind::Custom(30078), content, tags)
+        let event = EventBuilder::new(Kind::Custom(STATUS_EVENT_KIND), content, tags)
             .to_event(&self.client.keys)
             .map_err(|e| anyhow!("Failed to create Nostr event: {}", e))?;
 
@@ -337,7 +508,9 @@ mod tests {
             binary_path: test_file.to_string_lossy().to_string(),
             config_path: "".to_string(),
             audit_log_path: None,
+            backup_dir: None,
             start_time: Utc::now(),
+            min_quorum: 1,
         };
 
         let hash = publisher
@@ -360,7 +533,9 @@ mod tests {
             binary_path: "".to_string(),
             config_path: "".to_string(),
             audit_log_path: None,
+            backup_dir: None,
             start_time: Utc::now(),
+            min_quorum: 1,
         };
 
         let next_anchor = publisher.calculate_next_ots_anchor();
@@ -369,4 +544,102 @@ mod tests {
         assert_eq!(next_anchor.minute(), 0);
         assert_eq!(next_anchor.second(), 0);
     }
+
+    async fn seeded_publisher(dir: &std::path::Path, nodes: usize) -> StatusPublisher {
+        let binary = dir.join("binary");
+        let config = dir.join("config.toml");
+        fs::write(&binary, "binary").unwrap();
+        fs::write(&config, "config").unwrap();
+
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = &database.get_sqlite_pool().unwrap();
+        for i in 0..nodes {
+            let node_type = if i % 2 == 0 { "miner" } else { "exchange" };
+            sqlx::query(
+                "INSERT INTO node_registry (node_id, node_name, node_type, active, effective_weight) VALUES (?, ?, ?, TRUE, ?)",
+            )
+            .bind(format!("node-{:03}", i))
+            .bind(format!("Node {}", i))
+            .bind(node_type)
+            .bind(i as f64)
+            .execute(pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query(
+            "INSERT INTO node_registry (node_id, node_name, node_type, active, effective_weight) VALUES ('retired', 'Retired', 'miner', FALSE, 100.0)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO phase_transitions (old_phase, new_phase, block_height, economic_nodes, contributors) VALUES ('early', 'growth', 60000, 12, 15)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO governance_registries (registry_hash, registry_path, timestamp, month_year, ots_proof_path) VALUES ('sha256:reg', 'r.json', ?, '2026-10', 'r.json.ots')",
+        )
+        .bind(Utc::now())
+        .execute(pool)
+        .await
+        .unwrap();
+
+        let keys = Keys::generate();
+        let nsec = keys.secret_key().unwrap().display_secret().to_string();
+        StatusPublisher::new(
+            NostrClient::new(nsec, vec![]).await.unwrap(),
+            database,
+            "test".to_string(),
+            binary.to_string_lossy().to_string(),
+            config.to_string_lossy().to_string(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_build_status_includes_governance_summary() {
+        let temp_dir = tempdir().unwrap();
+        let publisher = seeded_publisher(temp_dir.path(), 4).await;
+
+        let status = publisher.build_status().await.unwrap();
+        let governance = &status.governance;
+        assert_eq!(governance.phase.as_deref(), Some("growth"));
+
+        // Inactive nodes are left out
+        let types: Vec<(&str, i64, f64)> = governance
+            .node_types
+            .iter()
+            .map(|t| (t.node_type.as_str(), t.active_nodes, t.total_weight))
+            .collect();
+        assert_eq!(types, vec![("exchange", 2, 4.0), ("miner", 2, 2.0)]);
+        assert_eq!(governance.top_nodes.len(), 4);
+        assert_eq!(governance.top_nodes[0].node_id, "node-003");
+
+        let anchor = governance.registry_anchor.as_ref().unwrap();
+        assert_eq!(anchor.registry_hash, "sha256:reg");
+        assert!(anchor.anchored);
+        assert!(!status.truncated);
+    }
+
+    #[tokio::test]
+    async fn test_build_status_truncates_many_nodes() {
+        let temp_dir = tempdir().unwrap();
+        let publisher = seeded_publisher(temp_dir.path(), 200).await;
+
+        let status = publisher.build_status().await.unwrap();
+        assert!(status.truncated);
+        assert_eq!(status.governance.top_nodes.len(), MAX_STATUS_LIST_ITEMS);
+        assert_eq!(status.governance.top_nodes[0].node_id, "node-199");
+        // Type totals still cover every active node
+        let total: i64 = status
+            .governance
+            .node_types
+            .iter()
+            .map(|t| t.active_nodes)
+            .sum();
+        assert_eq!(total, 200);
+        assert!(status.to_json().unwrap().len() <= MAX_STATUS_CONTENT_BYTES);
+    }
 }
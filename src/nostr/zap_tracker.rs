@@ -3,46 +3,146 @@
 //! Tracks Lightning zaps (NIP-57) for transparency/reporting purposes only.
 //! Zaps do NOT affect governance decisions (governance is maintainer-only multisig).
 //! Subscribes to zap receipt events from Nostr relays and records them in the database.
+//! Zaps only count as contributions once the configured Lightning node confirms
+//! their bolt11 invoice was paid; without one, zaps are recorded unverified.
+//!
+//! Receipts are recorded once per receipt event id. On start the tracker backfills
+//! receipts sent while it was down, and a periodic reconciliation pass re-queries
+//! a recent window to pick up receipts that reached relays late.
 
 use crate::governance::ContributionTracker;
 use crate::nostr::{NostrClient, ZapEvent};
+use crate::services::PaymentVerifier;
+use crate::shutdown::Shutdown;
 use anyhow::Result;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use sqlx::SqlitePool;
 use std::sync::Arc;
+use tokio::sync::mpsc::Receiver;
 use tracing::{info, warn};
 
+/// Source of zap receipts (the Nostr client, or a stub in tests)
+#[async_trait::async_trait]
+pub trait ZapSource: Send + Sync {
+    /// Live zap receipts to a recipient
+    async fn subscribe_to_zaps(&self, recipient_pubkey: &str) -> Result<Receiver<ZapEvent>>;
+
+    /// Historical zap receipts to a recipient between two unix timestamps
+    async fn fetch_zap_receipts(
+        &self,
+        recipient_pubkey: &str,
+        since: Option<i64>,
+        until: Option<i64>,
+    ) -> Result<Vec<ZapEvent>>;
+}
+
+#[async_trait::async_trait]
+impl ZapSource for NostrClient {
+    async fn subscribe_to_zaps(&self, recipient_pubkey: &str) -> Result<Receiver<ZapEvent>> {
+        NostrClient::subscribe_to_zaps(self, recipient_pubkey).await
+    }
+
+    async fn fetch_zap_receipts(
+        &self,
+        recipient_pubkey: &str,
+        since: Option<i64>,
+        until: Option<i64>,
+    ) -> Result<Vec<ZapEvent>> {
+        NostrClient::fetch_zap_receipts(self, recipient_pubkey, since, until).await
+    }
+}
+
 /// Zap tracker service that monitors and records zap contributions
 pub struct ZapTracker {
     pool: SqlitePool,
-    nostr_client: Arc<NostrClient>,
+    nostr_client: Arc<dyn ZapSource>,
     bot_pubkeys: Vec<String>, // All bot pubkeys to track
+    payment_verifier: Option<Arc<dyn PaymentVerifier>>,
 }
 
 impl ZapTracker {
     /// Create a new zap tracker
-    pub fn new(pool: SqlitePool, nostr_client: Arc<NostrClient>, bot_pubkeys: Vec<String>) -> Self {
+    pub fn new(
+        pool: SqlitePool,
+        nostr_client: Arc<dyn ZapSource>,
+        bot_pubkeys: Vec<String>,
+    ) -> Self {
         Self {
             pool,
             nostr_client,
             bot_pubkeys,
+            payment_verifier: None,
+        }
+    }
+
+    /// Verify zap payments against a Lightning node
+    pub fn with_payment_verifier(mut self, payment_verifier: Arc<dyn PaymentVerifier>) -> Self {
+        self.payment_verifier = Some(payment_verifier);
+        self
+    }
+
+    /// Verify that a zap receipt's bolt11 invoice was actually paid
+    ///
+    /// Asks the configured Lightning node about the invoice's payment hash. A
+    /// preimage carried in the receipt is not enough: whoever created the
+    /// invoice can always produce it without paying.
+    pub async fn verify_zap_payment(&self, zap_event: &ZapEvent) -> Result<bool> {
+        Self::verify_payment(self.payment_verifier.as_deref(), zap_event).await
+    }
+
+    async fn verify_payment(
+        payment_verifier: Option<&dyn PaymentVerifier>,
+        zap: &ZapEvent,
+    ) -> Result<bool> {
+        let payment_hash = match zap
+            .invoice
+            .as_ref()
+            .and_then(|i| Self::extract_payment_hash(i))
+        {
+            Some(hash) => hash,
+            None => return Ok(false),
+        };
+
+        match payment_verifier {
+            Some(verifier) => verifier.is_invoice_paid(&payment_hash).await,
+            None => Ok(false),
         }
     }
 
     /// Start tracking zaps for all bot pubkeys
-    pub async fn start_tracking(&self) -> Result<()> {
+    ///
+    /// Receipts sent since the last recorded zap are backfilled first, so zaps
+    /// sent while the tracker was down still count. The live subscriptions are
+    /// processed by tasks on `shutdown`.
+    pub async fn start_tracking(&self, shutdown: &Shutdown) -> Result<()> {
+        match self.backfill().await {
+            Ok(recorded) if recorded > 0 => info!("Backfilled {} missed zaps", recorded),
+            Ok(_) => {}
+            Err(e) => warn!("Zap backfill failed: {}", e),
+        }
+
         // Subscribe to zaps for each bot pubkey
         for pubkey in &self.bot_pubkeys {
-            let mut zap_rx = Arc::as_ref(&self.nostr_client)
-                .subscribe_to_zaps(pubkey)
-                .await?;
+            let mut zap_rx = self.nostr_client.subscribe_to_zaps(pubkey).await?;
 
             // Spawn task to process zaps for this pubkey
             let pool = self.pool.clone();
             let pubkey_clone = pubkey.clone();
-            tokio::spawn(async move {
-                while let Some(zap) = zap_rx.recv().await {
-                    if let Err(e) = Self::process_zap(&pool, &pubkey_clone, zap).await {
+            let payment_verifier = self.payment_verifier.clone();
+            shutdown.spawn("zap_tracker", |token| async move {
+                loop {
+                    let zap = tokio::select! {
+                        biased;
+                        _ = token.cancelled() => break,
+                        zap = zap_rx.recv() => match zap {
+                            Some(zap) => zap,
+                            None => break,
+                        },
+                    };
+                    if let Err(e) =
+                        Self::process_zap(&pool, payment_verifier.as_deref(), &pubkey_clone, zap)
+                            .await
+                    {
                         warn!("Failed to process zap: {}", e);
                     }
                 }
@@ -56,14 +156,128 @@ impl ZapTracker {
         Ok(())
     }
 
+    /// Record receipts sent since each bot's last recorded zap
+    ///
+    /// Bots with no recorded zaps are backfilled from their full history.
+    /// Returns the number of zaps newly recorded.
+    pub async fn backfill(&self) -> Result<usize> {
+        let mut recorded = 0;
+        for pubkey in &self.bot_pubkeys {
+            let since = self.get_last_zap_at(pubkey).await?;
+            let zaps = self
+                .nostr_client
+                .fetch_zap_receipts(pubkey, since.map(|since| since.timestamp()), None)
+                .await?;
+            recorded += self.record_zaps(pubkey, zaps).await?;
+        }
+        Ok(recorded)
+    }
+
+    /// Re-query the last `window` of receipts and record any that were missed
+    ///
+    /// Catches receipts that reached relays after the live subscription polled
+    /// them. Returns the number of zaps newly recorded.
+    pub async fn reconcile(&self, window: Duration) -> Result<usize> {
+        let now = Utc::now();
+        let since = (now - window).timestamp();
+        let mut recorded = 0;
+        for pubkey in &self.bot_pubkeys {
+            let zaps = self
+                .nostr_client
+                .fetch_zap_receipts(pubkey, Some(since), Some(now.timestamp()))
+                .await?;
+            recorded += self.record_zaps(pubkey, zaps).await?;
+        }
+
+        if recorded > 0 {
+            info!("Zap reconciliation recorded {} missed zaps", recorded);
+        }
+        Ok(recorded)
+    }
+
+    /// Timestamp of the latest zap recorded for a bot
+    pub async fn get_last_zap_at(&self, recipient_pubkey: &str) -> Result<Option<DateTime<Utc>>> {
+        Ok(sqlx::query_scalar(
+            "SELECT last_zap_at FROM zap_tracker_cursors WHERE recipient_pubkey = ?",
+        )
+        .bind(recipient_pubkey)
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    async fn record_zaps(&self, recipient_pubkey: &str, zaps: Vec<ZapEvent>) -> Result<usize> {
+        let mut recorded = 0;
+        for zap in zaps {
+            match Self::process_zap(
+                &self.pool,
+                self.payment_verifier.as_deref(),
+                recipient_pubkey,
+                zap,
+            )
+            .await
+            {
+                Ok(true) => recorded += 1,
+                Ok(false) => {}
+                Err(e) => warn!("Failed to process zap: {}", e),
+            }
+        }
+        Ok(recorded)
+    }
+
     /// Process a zap event and record it in the database
-    async fn process_zap(pool: &SqlitePool, recipient_pubkey: &str, zap: ZapEvent) -> Result<()> {
+    ///
+    /// Returns false if the receipt was already recorded.
+    async fn process_zap(
+        pool: &SqlitePool,
+        payment_verifier: Option<&dyn PaymentVerifier>,
+        recipient_pubkey: &str,
+        zap: ZapEvent,
+    ) -> Result<bool> {
+        let already_recorded: bool =
+            sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM zap_contributions WHERE event_id = ?)")
+                .bind(&zap.event_id)
+                .fetch_one(pool)
+                .await?;
+        if already_recorded {
+            return Ok(false);
+        }
+
         // Convert millisatoshis to BTC
         let amount_btc = zap.amount_msat as f64 / 100_000_000_000.0;
 
         // Convert timestamp to DateTime
         let timestamp = DateTime::from_timestamp(zap.timestamp, 0).unwrap_or_else(Utc::now);
 
+        // Zaps recorded before receipts were keyed by event id have none; a
+        // receipt matching one is that zap, so it is claimed, not recorded again
+        let claimed_legacy = sqlx::query(
+            r#"
+            UPDATE zap_contributions SET event_id = ?
+            WHERE id = (
+                SELECT id FROM zap_contributions
+                WHERE event_id IS NULL
+                  AND recipient_pubkey = ?
+                  AND sender_pubkey IS ?
+                  AND amount_msat = ?
+                  AND timestamp = ?
+                ORDER BY id
+                LIMIT 1
+            )
+            "#,
+        )
+        .bind(&zap.event_id)
+        .bind(recipient_pubkey)
+        .bind(zap.sender_pubkey.as_deref())
+        .bind(zap.amount_msat as i64)
+        .bind(timestamp)
+        .execute(pool)
+        .await?
+        .rows_affected()
+            > 0;
+        if claimed_legacy {
+            return Ok(false);
+        }
+
         // Determine if this is a proposal zap (has zapped_event_id)
         let is_proposal_zap = zap.zapped_event_id.is_some();
 
@@ -73,13 +287,28 @@ impl ZapTracker {
             .as_ref()
             .and_then(|i| Self::extract_payment_hash(i));
         let governance_event_id = zap.zapped_event_id.clone();
-        sqlx::query(
+
+        let verified = match Self::verify_payment(payment_verifier, &zap).await {
+            Ok(verified) => verified,
+            Err(e) => {
+                warn!(
+                    "Zap payment verification failed, recording unverified: {}",
+                    e
+                );
+                false
+            }
+        };
+        let verification_status = if verified { "verified" } else { "unverified" };
+
+        // The unique event id index makes a concurrent duplicate a no-op
+        let inserted = sqlx::query(
             r#"
-            INSERT INTO zap_contributions
-            (recipient_pubkey, sender_pubkey, amount_msat, amount_btc, timestamp, invoice_hash, message, zapped_event_id, is_proposal_zap, governance_event_id)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            INSERT OR IGNORE INTO zap_contributions
+            (event_id, recipient_pubkey, sender_pubkey, amount_msat, amount_btc, timestamp, invoice_hash, message, zapped_event_id, is_proposal_zap, governance_event_id, verification_status, verified_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CASE WHEN ? THEN CURRENT_TIMESTAMP END)
             "#,
         )
+        .bind(&zap.event_id)
         .bind(recipient_pubkey)
         .bind(zap.sender_pubkey.as_deref())
         .bind(zap.amount_msat as i64)
@@ -90,17 +319,42 @@ impl ZapTracker {
         .bind(zap.zapped_event_id.as_deref())
         .bind(is_proposal_zap)
         .bind(governance_event_id.as_deref())
+        .bind(verification_status)
+        .bind(verified)
+        .execute(pool)
+        .await?;
+        if inserted.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO zap_tracker_cursors (recipient_pubkey, last_zap_at, updated_at)
+            VALUES (?, ?, CURRENT_TIMESTAMP)
+            ON CONFLICT(recipient_pubkey) DO UPDATE SET
+                last_zap_at = MAX(last_zap_at, excluded.last_zap_at),
+                updated_at = CURRENT_TIMESTAMP
+            "#,
+        )
+        .bind(recipient_pubkey)
+        .bind(timestamp)
         .execute(pool)
         .await?;
 
         info!(
-            "Recorded zap: {} msat ({:.8} BTC) to {} from {}",
+            "Recorded {} zap: {} msat ({:.8} BTC) to {} from {}",
+            verification_status,
             zap.amount_msat,
             amount_btc,
             recipient_pubkey,
             zap.sender_pubkey.as_deref().unwrap_or("unknown")
         );
 
+        // Only verified zaps count as contributions
+        if !verified {
+            return Ok(true);
+        }
+
         // Also record in unified contributions if we have sender pubkey
         if let Some(ref sender_pubkey) = zap.sender_pubkey {
             let tracker = ContributionTracker::new(pool.clone());
@@ -112,7 +366,64 @@ impl ZapTracker {
             }
         }
 
-        Ok(())
+        Ok(true)
+    }
+
+    /// Re-check unverified zaps against the Lightning node
+    ///
+    /// Zaps confirmed as paid are marked verified and recorded as contributions.
+    /// Returns the number of zaps newly verified.
+    pub async fn verify_pending_zaps(&self) -> Result<usize> {
+        let verifier = match &self.payment_verifier {
+            Some(verifier) => verifier,
+            None => return Ok(0),
+        };
+
+        let pending: Vec<(i64, String, Option<String>, f64, DateTime<Utc>, bool)> = sqlx::query_as(
+            r#"
+                SELECT id, invoice_hash, sender_pubkey, amount_btc, timestamp, is_proposal_zap
+                FROM zap_contributions
+                WHERE verification_status = 'unverified' AND invoice_hash IS NOT NULL
+                "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let tracker = ContributionTracker::new(self.pool.clone());
+        let mut verified_count = 0;
+        for (id, payment_hash, sender_pubkey, amount_btc, timestamp, is_proposal_zap) in pending {
+            match verifier.is_invoice_paid(&payment_hash).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    warn!("Failed to verify zap {}: {}", id, e);
+                    continue;
+                }
+            }
+
+            // Only the pass that flips the status records the contribution
+            let updated = sqlx::query(
+                "UPDATE zap_contributions SET verification_status = 'verified', verified_at = CURRENT_TIMESTAMP WHERE id = ? AND verification_status = 'unverified'",
+            )
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+            if updated.rows_affected() == 0 {
+                continue;
+            }
+
+            if let Some(sender_pubkey) = sender_pubkey {
+                tracker
+                    .record_zap_contribution(&sender_pubkey, amount_btc, timestamp, is_proposal_zap)
+                    .await?;
+            }
+            verified_count += 1;
+        }
+
+        if verified_count > 0 {
+            info!("Verified {} previously unverified zaps", verified_count);
+        }
+        Ok(verified_count)
     }
 
     /// Extract payment hash from invoice (for verification)
@@ -132,7 +443,7 @@ impl ZapTracker {
         Some(hex::encode(hash_bytes))
     }
 
-    /// Get total zaps for a pubkey in time period
+    /// Get total verified zaps for a pubkey in time period
     pub async fn get_total_zaps(
         &self,
         pubkey: &str,
@@ -143,9 +454,10 @@ impl ZapTracker {
             r#"
             SELECT SUM(amount_btc) as total
             FROM zap_contributions
-            WHERE recipient_pubkey = ? 
-              AND timestamp >= ? 
+            WHERE recipient_pubkey = ?
+              AND timestamp >= ?
               AND timestamp <= ?
+              AND verification_status = 'verified'
             "#,
         )
         .bind(pubkey)
@@ -157,7 +469,7 @@ impl ZapTracker {
         Ok(result.unwrap_or(0.0))
     }
 
-    /// Get zaps by sender (for contributor qualification)
+    /// Get verified zaps by sender (for contributor qualification)
     pub async fn get_zaps_by_sender(
         &self,
         sender_pubkey: &str,
@@ -186,6 +498,7 @@ impl ZapTracker {
             WHERE sender_pubkey = ?
               AND timestamp >= ?
               AND timestamp <= ?
+              AND verification_status = 'verified'
             ORDER BY timestamp DESC
             "#,
         )
@@ -213,7 +526,7 @@ impl ZapTracker {
             .collect())
     }
 
-    /// Get proposal zaps (zaps to governance events)
+    /// Get verified proposal zaps (zaps to governance events)
     pub async fn get_proposal_zaps(
         &self,
         governance_event_id: &str,
@@ -238,6 +551,7 @@ impl ZapTracker {
             SELECT id, recipient_pubkey, sender_pubkey, amount_msat, amount_btc, timestamp, invoice_hash, message, zapped_event_id, is_proposal_zap, governance_event_id
             FROM zap_contributions
             WHERE governance_event_id = ?
+              AND verification_status = 'verified'
             ORDER BY timestamp DESC
             "#,
         )
@@ -279,3 +593,278 @@ pub struct ZapContribution {
     pub is_proposal_zap: bool,
     pub governance_event_id: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lightning node that reports every invoice as paid
+    struct PaidVerifier;
+
+    #[async_trait::async_trait]
+    impl PaymentVerifier for PaidVerifier {
+        async fn is_invoice_paid(&self, _payment_hash: &str) -> Result<bool> {
+            Ok(true)
+        }
+    }
+
+    fn zap(invoice: Option<&str>) -> ZapEvent {
+        ZapEvent {
+            event_id: "receipt".to_string(),
+            recipient_pubkey: "recipient".to_string(),
+            sender_pubkey: Some("sender".to_string()),
+            amount_msat: 21_000,
+            timestamp: Utc::now().timestamp(),
+            invoice: invoice.map(|i| i.to_string()),
+            message: None,
+            zapped_event_id: None,
+            preimage: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zap_without_valid_invoice_is_unverified() {
+        let verifier = PaidVerifier;
+
+        // Even a node that reports everything paid can't verify a missing invoice
+        assert!(!ZapTracker::verify_payment(Some(&verifier), &zap(None))
+            .await
+            .unwrap());
+        assert!(
+            !ZapTracker::verify_payment(Some(&verifier), &zap(Some("lnbc-garbage")))
+                .await
+                .unwrap()
+        );
+    }
+
+    /// Relay stub: fixed history plus a live feed that repeats part of it
+    struct StubZapSource {
+        history: Vec<ZapEvent>,
+        live: Vec<ZapEvent>,
+    }
+
+    #[async_trait::async_trait]
+    impl ZapSource for StubZapSource {
+        async fn subscribe_to_zaps(&self, _recipient_pubkey: &str) -> Result<Receiver<ZapEvent>> {
+            let (tx, rx) = tokio::sync::mpsc::channel(self.live.len().max(1));
+            for zap in &self.live {
+                tx.send(zap.clone()).await?;
+            }
+            Ok(rx)
+        }
+
+        async fn fetch_zap_receipts(
+            &self,
+            _recipient_pubkey: &str,
+            since: Option<i64>,
+            until: Option<i64>,
+        ) -> Result<Vec<ZapEvent>> {
+            Ok(self
+                .history
+                .iter()
+                .filter(|zap| since.unwrap_or(i64::MIN) <= zap.timestamp)
+                .filter(|zap| until.unwrap_or(i64::MAX) >= zap.timestamp)
+                .cloned()
+                .collect())
+        }
+    }
+
+    fn receipt(event_id: &str, timestamp: DateTime<Utc>) -> ZapEvent {
+        ZapEvent {
+            event_id: event_id.to_string(),
+            timestamp: timestamp.timestamp(),
+            ..zap(None)
+        }
+    }
+
+    async fn setup_zap_db() -> SqlitePool {
+        // One connection so every task sees the same in-memory database
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::query(
+            r#"
+            CREATE TABLE zap_contributions (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                event_id TEXT,
+                recipient_pubkey TEXT NOT NULL,
+                sender_pubkey TEXT,
+                amount_msat INTEGER NOT NULL,
+                amount_btc REAL NOT NULL,
+                timestamp DATETIME NOT NULL,
+                invoice_hash TEXT,
+                message TEXT,
+                zapped_event_id TEXT,
+                is_proposal_zap BOOLEAN DEFAULT FALSE,
+                governance_event_id TEXT,
+                verification_status TEXT NOT NULL DEFAULT 'unverified',
+                verified_at TIMESTAMP
+            );
+            CREATE UNIQUE INDEX idx_zap_event_id ON zap_contributions(event_id) WHERE event_id IS NOT NULL;
+            CREATE TABLE zap_tracker_cursors (
+                recipient_pubkey TEXT PRIMARY KEY,
+                last_zap_at TIMESTAMP NOT NULL,
+                updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            );
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool
+    }
+
+    async fn recorded_event_ids(pool: &SqlitePool) -> Vec<String> {
+        sqlx::query_scalar("SELECT event_id FROM zap_contributions ORDER BY event_id")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_backfill_and_live_overlap_without_duplicates() {
+        let pool = setup_zap_db().await;
+        let now = Utc::now();
+        let a = receipt("a", now - Duration::hours(2));
+        let b = receipt("b", now - Duration::hours(1));
+        let c = receipt("c", now - Duration::minutes(1));
+
+        // Zaps a and b arrived while down; the live feed re-sends b and adds c
+        let source = StubZapSource {
+            history: vec![a.clone(), b.clone()],
+            live: vec![b, c.clone()],
+        };
+        let tracker = ZapTracker::new(pool.clone(), Arc::new(source), vec!["bot".to_string()]);
+        let shutdown = Shutdown::new();
+        tracker.start_tracking(&shutdown).await.unwrap();
+
+        for _ in 0..100 {
+            if recorded_event_ids(&pool).await.len() >= 3 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        assert_eq!(recorded_event_ids(&pool).await, vec!["a", "b", "c"]);
+
+        // Backfilled zaps keep their original timestamps
+        let oldest: DateTime<Utc> =
+            sqlx::query_scalar("SELECT timestamp FROM zap_contributions WHERE event_id = 'a'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(oldest.timestamp(), a.timestamp);
+
+        // The cursor tracks the latest recorded zap
+        let last_zap_at = tracker.get_last_zap_at("bot").await.unwrap().unwrap();
+        assert_eq!(last_zap_at.timestamp(), c.timestamp);
+
+        let report = shutdown.drain(std::time::Duration::from_secs(5)).await;
+        assert!(report.completed);
+        assert!(report.terminated.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_records_late_receipts_once() {
+        let pool = setup_zap_db().await;
+        let now = Utc::now();
+        let a = receipt("a", now - Duration::hours(2));
+        let b = receipt("b", now - Duration::hours(1));
+
+        // d reached the relays late; e is outside the reconciliation window
+        let source = StubZapSource {
+            history: vec![
+                a.clone(),
+                b.clone(),
+                receipt("d", now - Duration::minutes(30)),
+                receipt("e", now - Duration::hours(72)),
+            ],
+            live: vec![],
+        };
+        let tracker = ZapTracker::new(pool.clone(), Arc::new(source), vec!["bot".to_string()]);
+
+        for zap in [a, b] {
+            assert!(ZapTracker::process_zap(&pool, None, "bot", zap)
+                .await
+                .unwrap());
+        }
+
+        assert_eq!(tracker.reconcile(Duration::hours(24)).await.unwrap(), 1);
+        assert_eq!(tracker.reconcile(Duration::hours(24)).await.unwrap(), 0);
+        assert_eq!(recorded_event_ids(&pool).await, vec!["a", "b", "d"]);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_claims_legacy_zaps() {
+        let pool = setup_zap_db().await;
+        let now = Utc::now();
+        let a = receipt("a", now - Duration::hours(2));
+        let b = receipt("b", now - Duration::hours(1));
+
+        // a was recorded before receipts carried their event id
+        sqlx::query(
+            "INSERT INTO zap_contributions (recipient_pubkey, sender_pubkey, amount_msat, amount_btc, timestamp, verification_status) VALUES ('bot', ?, ?, 0.00000021, ?, 'verified')",
+        )
+        .bind(a.sender_pubkey.as_deref())
+        .bind(a.amount_msat as i64)
+        .bind(DateTime::from_timestamp(a.timestamp, 0).unwrap())
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let source = StubZapSource {
+            history: vec![a, b],
+            live: vec![],
+        };
+        let tracker = ZapTracker::new(pool.clone(), Arc::new(source), vec!["bot".to_string()]);
+        assert_eq!(tracker.backfill().await.unwrap(), 1);
+        assert_eq!(recorded_event_ids(&pool).await, vec!["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_only_verified_zaps_count() {
+        let pool = setup_zap_db().await;
+        let now = Utc::now();
+        for (event_id, invoice_hash) in [("a", Some("aa")), ("b", None)] {
+            sqlx::query(
+                "INSERT INTO zap_contributions (event_id, recipient_pubkey, amount_msat, amount_btc, timestamp, invoice_hash) VALUES (?, 'bot', 100000, 0.000001, ?, ?)",
+            )
+            .bind(event_id)
+            .bind(now)
+            .bind(invoice_hash)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        let tracker = ZapTracker::new(
+            pool.clone(),
+            Arc::new(StubZapSource {
+                history: vec![],
+                live: vec![],
+            }),
+            vec!["bot".to_string()],
+        )
+        .with_payment_verifier(Arc::new(PaidVerifier));
+        let window = (now - Duration::hours(1), now + Duration::hours(1));
+
+        assert_eq!(
+            tracker
+                .get_total_zaps("bot", window.0, window.1)
+                .await
+                .unwrap(),
+            0.0
+        );
+
+        // Each zap is verified (and counted) once
+        assert_eq!(tracker.verify_pending_zaps().await.unwrap(), 1);
+        assert_eq!(tracker.verify_pending_zaps().await.unwrap(), 0);
+        assert_eq!(
+            tracker
+                .get_total_zaps("bot", window.0, window.1)
+                .await
+                .unwrap(),
+            0.000001
+        );
+    }
+}
@@ -14,7 +14,15 @@ use std::path::{Path, PathBuf};
 use tracing::{info, warn};
 
 use crate::database::Database;
-use crate::ots::client::{OtsClient, VerificationResult};
+use crate::execution_mode::{record_suppressed, ExecutionMode, Subsystem};
+use crate::node_registry::export::generate_registry_file;
+use crate::ots::client::{OtsClient, OtsVerificationResult, VerificationResult};
+
+/// Blocks to wait after anchoring before checking the registry proof's confirmation
+pub const VERIFICATION_DELAY_BLOCKS: u64 = 144;
+
+/// Expected Bitcoin block interval, used to turn block counts into wall-clock delays
+pub const BLOCK_INTERVAL_SECS: u64 = 600;
 
 /// Registry anchorer for monthly governance anchoring
 pub struct RegistryAnchorer {
@@ -22,6 +30,9 @@ pub struct RegistryAnchorer {
     database: Database,
     registry_path: PathBuf,
     proofs_path: PathBuf,
+    execution_mode: ExecutionMode,
+    /// Signs the node registry export; without it no export is written
+    signing_keys: Option<nostr_sdk::Keys>,
 }
 
 /// Governance registry structure
@@ -34,6 +45,9 @@ pub struct GovernanceRegistry {
     pub authorized_servers: Vec<AuthorizedServer>,
     pub audit_logs: HashMap<String, AuditLogSummary>,
     pub multisig_config: MultisigConfig,
+    /// Digest of the signed node registry export anchored with this registry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_registry_hash: Option<String>,
 }
 
 /// Maintainer information
@@ -89,6 +103,19 @@ pub struct AuditLogSummary {
     pub merkle_root: String,
 }
 
+/// OTS proof for a single governance event
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct OtsProofRecord {
+    pub id: i64,
+    pub event_type: String,
+    pub event_id: String,
+    pub digest_hex: String,
+    pub proof_bytes: Vec<u8>,
+    pub submitted_at: DateTime<Utc>,
+    /// Set once the proof has been upgraded to a Bitcoin-confirmed timestamp
+    pub upgraded_at: Option<DateTime<Utc>>,
+}
+
 /// Multisig configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultisigConfig {
@@ -109,18 +136,67 @@ impl RegistryAnchorer {
             database,
             registry_path: PathBuf::from(registry_path),
             proofs_path: PathBuf::from(proofs_path),
+            execution_mode: ExecutionMode::Live,
+            signing_keys: None,
         }
     }
 
+    /// In dry-run mode nothing is submitted to the aggregator or stored
+    pub fn with_execution_mode(mut self, execution_mode: ExecutionMode) -> Self {
+        self.execution_mode = execution_mode;
+        self
+    }
+
+    /// Export and sign the node registry with these keys before each anchor
+    pub fn with_signing_keys(mut self, keys: nostr_sdk::Keys) -> Self {
+        self.signing_keys = Some(keys);
+        self
+    }
+
     /// Generate and anchor monthly registry
-    pub async fn anchor_registry(&self) -> Result<()> {
+    ///
+    /// Returns the `governance_registries` id of the anchored registry (0 in
+    /// dry-run mode, where the registry is generated but not saved or stamped).
+    pub async fn anchor_registry(&self) -> Result<i64> {
         let now = Utc::now();
         let month_key = now.format("%Y-%m").to_string();
 
         info!("Generating monthly registry for {}", month_key);
 
         // Generate registry
-        let registry = self.generate_registry().await?;
+        let mut registry = self.generate_registry().await?;
+
+        if self.execution_mode.is_dry_run() {
+            let registry_data = serde_json::to_vec(&registry)
+                .map_err(|e| anyhow!("Failed to serialize registry: {}", e))?;
+            let registry_hash = format!("sha256:{}", hex::encode(Sha256::digest(&registry_data)));
+            record_suppressed(
+                Subsystem::Ots,
+                "anchor_registry",
+                Some(&month_key),
+                serde_json::json!({
+                    "registry_hash": registry_hash,
+                    "maintainers": registry.maintainers.len(),
+                }),
+            )
+            .await;
+            return Ok(0);
+        }
+
+        // Export the node registry so the proof commits to it
+        match &self.signing_keys {
+            Some(keys) => {
+                let pool = &self
+                    .database
+                    .get_sqlite_pool()
+                    .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
+                let export_file = self.registry_path.join(format!("{}-nodes.json", month_key));
+                let export =
+                    generate_registry_file(pool, &export_file, keys, registry.timestamp).await?;
+                registry.node_registry_hash = Some(export.digest);
+            }
+            None => warn!("No registry signing key; anchoring without a node registry export"),
+        }
 
         // Save registry JSON
         let registry_file = self.registry_path.join(format!("{}.json", month_key));
@@ -137,14 +213,15 @@ impl RegistryAnchorer {
         self.save_proof(&proof_data, &proof_file).await?;
 
         // Store in database
-        self.store_registry_info(&month_key, &registry_file, &proof_file)
+        let registry_id = self
+            .store_registry_info(&month_key, &registry_file, &proof_file)
             .await?;
 
         info!(
             "Successfully anchored registry for {} to Bitcoin",
             month_key
         );
-        Ok(())
+        Ok(registry_id)
     }
 
     /// Generate governance registry from database
@@ -175,6 +252,7 @@ impl RegistryAnchorer {
             authorized_servers,
             audit_logs,
             multisig_config,
+            node_registry_hash: None,
         })
     }
 
@@ -182,7 +260,7 @@ impl RegistryAnchorer {
     async fn get_previous_registry_hash(&self) -> Result<String> {
         use sqlx::Row;
 
-        let pool = self
+        let pool = &self
             .database
             .get_sqlite_pool()
             .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
@@ -206,7 +284,7 @@ impl RegistryAnchorer {
     async fn get_maintainers(&self) -> Result<Vec<Maintainer>> {
         use crate::database::queries::Queries;
 
-        let pool = self
+        let pool = &self
             .database
             .get_sqlite_pool()
             .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
@@ -442,7 +520,7 @@ impl RegistryAnchorer {
         month_key: &str,
         registry_file: &Path,
         proof_file: &Path,
-    ) -> Result<()> {
+    ) -> Result<i64> {
         use sqlx::Row;
 
         // Calculate registry hash
@@ -453,7 +531,7 @@ impl RegistryAnchorer {
         let hash = hasher.finalize();
         let registry_hash = format!("sha256:{}", hex::encode(hash));
 
-        let pool = self
+        let pool = &self
             .database
             .get_sqlite_pool()
             .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
@@ -462,7 +540,7 @@ impl RegistryAnchorer {
         let proof_path_str = proof_file.to_string_lossy().to_string();
         let registry_path_str = registry_file.to_string_lossy().to_string();
 
-        sqlx::query(
+        let registry_id = sqlx::query(
             r#"
             INSERT INTO governance_registries 
             (registry_hash, registry_path, timestamp, month_year, ots_proof_path)
@@ -475,7 +553,8 @@ impl RegistryAnchorer {
         .bind(month_key)
         .bind(&proof_path_str)
         .execute(pool)
-        .await?;
+        .await?
+        .last_insert_rowid();
 
         info!(
             "Stored registry info for {}: {} -> {} (hash: {})",
@@ -484,7 +563,207 @@ impl RegistryAnchorer {
             proof_file.display(),
             registry_hash
         );
-        Ok(())
+        Ok(registry_id)
+    }
+
+    /// Anchor a single governance event immediately
+    ///
+    /// The proof is written to `<proofs_path>/events/<event_type>/<event_id>.ots`
+    /// and stored in the database. Re-anchoring an event replaces its proof.
+    /// In dry-run mode an unsaved record with id 0 and no proof is returned.
+    pub async fn anchor_event(
+        &self,
+        event_type: &str,
+        event_id: &str,
+        digest: &[u8; 32],
+    ) -> Result<OtsProofRecord> {
+        let proof_file = self.event_proof_path(event_type, event_id)?;
+        info!("Anchoring {} event {}", event_type, event_id);
+
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::Ots,
+                "anchor_event",
+                Some(&format!("{}/{}", event_type, event_id)),
+                serde_json::json!({ "digest": hex::encode(digest) }),
+            )
+            .await;
+            return Ok(OtsProofRecord {
+                id: 0,
+                event_type: event_type.to_string(),
+                event_id: event_id.to_string(),
+                digest_hex: hex::encode(digest),
+                proof_bytes: Vec::new(),
+                submitted_at: Utc::now(),
+                upgraded_at: None,
+            });
+        }
+
+        let proof_data = self.ots_client.stamp(digest).await?;
+        self.save_proof(&proof_data, &proof_file).await?;
+
+        let pool = &self
+            .database
+            .get_sqlite_pool()
+            .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
+        let record = sqlx::query_as::<_, OtsProofRecord>(
+            r#"
+            INSERT INTO ots_event_proofs (event_type, event_id, digest_hex, proof_bytes, submitted_at)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(event_type, event_id) DO UPDATE SET
+                digest_hex = excluded.digest_hex,
+                proof_bytes = excluded.proof_bytes,
+                submitted_at = excluded.submitted_at,
+                upgraded_at = NULL
+            RETURNING id, event_type, event_id, digest_hex, proof_bytes, submitted_at, upgraded_at
+            "#,
+        )
+        .bind(event_type)
+        .bind(event_id)
+        .bind(hex::encode(digest))
+        .bind(&proof_data)
+        .bind(Utc::now())
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Look up the stored proof for a governance event
+    pub async fn get_event_proof(
+        &self,
+        event_type: &str,
+        event_id: &str,
+    ) -> Result<Option<OtsProofRecord>> {
+        let pool = &self
+            .database
+            .get_sqlite_pool()
+            .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
+        let record = sqlx::query_as::<_, OtsProofRecord>(
+            r#"
+            SELECT id, event_type, event_id, digest_hex, proof_bytes, submitted_at, upgraded_at
+            FROM ots_event_proofs
+            WHERE event_type = ? AND event_id = ?
+            "#,
+        )
+        .bind(event_type)
+        .bind(event_id)
+        .fetch_optional(pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// Try to upgrade an event's proof to a Bitcoin-confirmed timestamp
+    ///
+    /// Sets `upgraded_at` once the upgraded proof verifies as confirmed;
+    /// a still-pending proof is left untouched.
+    pub async fn upgrade_event_proof(
+        &self,
+        event_type: &str,
+        event_id: &str,
+    ) -> Result<Option<OtsProofRecord>> {
+        let Some(record) = self.get_event_proof(event_type, event_id).await? else {
+            return Ok(None);
+        };
+        if record.upgraded_at.is_some() {
+            return Ok(Some(record));
+        }
+
+        let digest =
+            hex::decode(&record.digest_hex).map_err(|e| anyhow!("Invalid stored digest: {}", e))?;
+        let upgraded = self.ots_client.upgrade(&record.proof_bytes).await?;
+        if !self
+            .ots_client
+            .verify(&digest, &upgraded)
+            .await?
+            .is_confirmed()
+        {
+            return Ok(Some(record));
+        }
+
+        if self.execution_mode.is_dry_run() {
+            record_suppressed(
+                Subsystem::Ots,
+                "upgrade_event_proof",
+                Some(&format!("{}/{}", event_type, event_id)),
+                serde_json::json!({ "proof_id": record.id }),
+            )
+            .await;
+            return Ok(Some(record));
+        }
+
+        self.save_proof(&upgraded, &self.event_proof_path(event_type, event_id)?)
+            .await?;
+        let pool = &self
+            .database
+            .get_sqlite_pool()
+            .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
+        let record = sqlx::query_as::<_, OtsProofRecord>(
+            r#"
+            UPDATE ots_event_proofs
+            SET proof_bytes = ?, upgraded_at = ?
+            WHERE id = ?
+            RETURNING id, event_type, event_id, digest_hex, proof_bytes, submitted_at, upgraded_at
+            "#,
+        )
+        .bind(&upgraded)
+        .bind(Utc::now())
+        .bind(record.id)
+        .fetch_one(pool)
+        .await?;
+
+        info!("Upgraded OTS proof for {} event {}", event_type, event_id);
+        Ok(Some(record))
+    }
+
+    /// Try to upgrade every event proof that isn't confirmed yet
+    ///
+    /// Returns the number of proofs upgraded.
+    pub async fn upgrade_pending_event_proofs(&self) -> Result<usize> {
+        let pool = &self
+            .database
+            .get_sqlite_pool()
+            .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
+        let pending: Vec<(String, String)> = sqlx::query_as(
+            "SELECT event_type, event_id FROM ots_event_proofs WHERE upgraded_at IS NULL",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut upgraded = 0;
+        for (event_type, event_id) in pending {
+            match self.upgrade_event_proof(&event_type, &event_id).await {
+                Ok(Some(record)) if record.upgraded_at.is_some() => upgraded += 1,
+                Ok(_) => {}
+                Err(e) => warn!(
+                    "Failed to upgrade OTS proof for {} event {}: {}",
+                    event_type, event_id, e
+                ),
+            }
+        }
+        Ok(upgraded)
+    }
+
+    /// On-disk location of an event proof; rejects ids that could escape `proofs_path`
+    fn event_proof_path(&self, event_type: &str, event_id: &str) -> Result<PathBuf> {
+        let is_safe = |s: &str| {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+                && !s.starts_with('.')
+        };
+        if !is_safe(event_type) || !is_safe(event_id) {
+            return Err(anyhow!(
+                "Invalid event type or id: {}/{}",
+                event_type,
+                event_id
+            ));
+        }
+        Ok(self
+            .proofs_path
+            .join("events")
+            .join(event_type)
+            .join(format!("{}.ots", event_id)))
     }
 
     /// Verify a registry against its OTS proof
@@ -504,6 +783,61 @@ impl RegistryAnchorer {
         // Verify timestamp
         self.ots_client.verify(&registry_data, &proof_data).await
     }
+
+    /// Check a monthly registry's OTS proof with the aggregator
+    ///
+    /// `registry_id` is the `governance_registries` row returned by
+    /// `anchor_registry`. Returns `None` if there is no such registry or it
+    /// was never anchored.
+    pub async fn verify_registry_proof(
+        &self,
+        registry_id: i64,
+    ) -> Result<Option<OtsVerificationResult>> {
+        let pool = &self
+            .database
+            .get_sqlite_pool()
+            .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))?;
+        let proof_path: Option<Option<String>> =
+            sqlx::query_scalar("SELECT ots_proof_path FROM governance_registries WHERE id = ?")
+                .bind(registry_id)
+                .fetch_optional(pool)
+                .await?;
+        let Some(proof_path) = proof_path.flatten() else {
+            return Ok(None);
+        };
+
+        let result = self.ots_client.verify_proof(Path::new(&proof_path)).await?;
+        Ok(Some(result))
+    }
+
+    /// Verify a registry proof once it should have `VERIFICATION_DELAY_BLOCKS` confirmations
+    ///
+    /// Runs in the background so the monthly anchor task isn't held up; the
+    /// outcome is only logged.
+    pub fn schedule_verification(self: &std::sync::Arc<Self>, registry_id: i64) {
+        if self.execution_mode.is_dry_run() {
+            return;
+        }
+        let anchorer = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_secs(
+                VERIFICATION_DELAY_BLOCKS * BLOCK_INTERVAL_SECS,
+            ))
+            .await;
+            match anchorer.verify_registry_proof(registry_id).await {
+                Ok(Some(result)) if result.is_verified => info!(
+                    "Registry {} proof confirmed in block {:?} ({:?} confirmations)",
+                    registry_id, result.bitcoin_block_height, result.confirmations
+                ),
+                Ok(Some(_)) => warn!(
+                    "Registry {} proof still unconfirmed {} blocks after anchoring",
+                    registry_id, VERIFICATION_DELAY_BLOCKS
+                ),
+                Ok(None) => warn!("Registry {} has no OTS proof to verify", registry_id),
+                Err(e) => warn!("Failed to verify registry {} proof: {}", registry_id, e),
+            }
+        });
+    }
 }
 
 #[cfg(test)]
@@ -535,6 +869,146 @@ mod tests {
         assert!(anchorer.proofs_path.exists() || anchorer.proofs_path.parent().unwrap().exists());
     }
 
+    #[tokio::test]
+    async fn test_anchor_event_and_upgrade() {
+        let temp_dir = tempdir().unwrap();
+        let anchorer = RegistryAnchorer::new(
+            OtsClient::new("https://alice.btc.calendar.opentimestamps.org".to_string()),
+            Database::new_in_memory().await.unwrap(),
+            temp_dir
+                .path()
+                .join("registries")
+                .to_string_lossy()
+                .to_string(),
+            temp_dir.path().join("proofs").to_string_lossy().to_string(),
+        );
+
+        let digest = [7u8; 32];
+        let record = anchorer
+            .anchor_event("pr-merge", "repo-42", &digest)
+            .await
+            .unwrap();
+        assert_eq!(record.digest_hex, hex::encode(digest));
+        assert!(record.upgraded_at.is_none());
+        assert!(temp_dir
+            .path()
+            .join("proofs/events/pr-merge/repo-42.ots")
+            .exists());
+
+        let stored = anchorer
+            .get_event_proof("pr-merge", "repo-42")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.proof_bytes, record.proof_bytes);
+
+        // Mock proofs verify as confirmed, so the upgrade completes
+        let upgraded = anchorer
+            .upgrade_event_proof("pr-merge", "repo-42")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(upgraded.upgraded_at.is_some());
+
+        assert!(anchorer
+            .get_event_proof("pr-merge", "missing")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(anchorer
+            .anchor_event("../etc", "passwd", &digest)
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_anchor_event_dry_run() {
+        let temp_dir = tempdir().unwrap();
+        let anchorer = RegistryAnchorer::new(
+            OtsClient::new("https://alice.btc.calendar.opentimestamps.org".to_string()),
+            Database::new_in_memory().await.unwrap(),
+            temp_dir
+                .path()
+                .join("registries")
+                .to_string_lossy()
+                .to_string(),
+            temp_dir.path().join("proofs").to_string_lossy().to_string(),
+        )
+        .with_execution_mode(ExecutionMode::DryRun);
+
+        let event_id = format!("dry-run-{}", uuid::Uuid::new_v4());
+        let record = anchorer
+            .anchor_event("pr-merge", &event_id, &[7u8; 32])
+            .await
+            .unwrap();
+        assert_eq!(record.id, 0);
+        assert!(!temp_dir.path().join("proofs").exists());
+        assert!(anchorer
+            .get_event_proof("pr-merge", &event_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        assert_eq!(anchorer.anchor_registry().await.unwrap(), 0);
+        assert!(!temp_dir.path().join("registries").exists());
+
+        let recorded = crate::execution_mode::suppressed_for(&format!("pr-merge/{}", event_id));
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].action, "anchor_event");
+    }
+
+    #[tokio::test]
+    async fn test_verify_registry_proof() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/verify"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "verified": true,
+                "block_height": 850000,
+                "confirmations": 144
+            })))
+            .mount(&server)
+            .await;
+
+        let temp_dir = tempdir().unwrap();
+        let database = Database::new_in_memory().await.unwrap();
+        let proof_file = temp_dir.path().join("2026-01.json.ots");
+        fs::write(&proof_file, b"proof").unwrap();
+        let registry_id = sqlx::query(
+            "INSERT INTO governance_registries (registry_hash, registry_path, timestamp, month_year, ots_proof_path) VALUES ('sha256:00', 'r.json', ?, '2026-01', ?)",
+        )
+        .bind(Utc::now())
+        .bind(proof_file.to_string_lossy().to_string())
+        .execute(&database.get_sqlite_pool().unwrap())
+        .await
+        .unwrap()
+        .last_insert_rowid();
+
+        let anchorer = RegistryAnchorer::new(
+            OtsClient::new(server.uri()),
+            database,
+            temp_dir.path().to_string_lossy().to_string(),
+            temp_dir.path().to_string_lossy().to_string(),
+        );
+        let result = anchorer
+            .verify_registry_proof(registry_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(result.is_verified);
+        assert_eq!(result.bitcoin_block_height, Some(850000));
+        assert_eq!(result.confirmations, Some(VERIFICATION_DELAY_BLOCKS));
+
+        assert!(anchorer
+            .verify_registry_proof(registry_id + 1)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn test_governance_registry_creation() {
         let registry = GovernanceRegistry {
@@ -550,6 +1024,7 @@ mod tests {
                 required_signatures: 3,
                 total_maintainers: 5,
             },
+            node_registry_hash: None,
         };
 
         assert_eq!(registry.version, "2025-01");
@@ -0,0 +1,161 @@
+//! Internal OpenTimestamps endpoints for on-demand event anchoring
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::api_auth::require_internal_api_key;
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::ots::anchor::{OtsProofRecord, RegistryAnchorer};
+use crate::ots::client::{OtsClient, OtsVerificationResult};
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AnchorEventRequest {
+    pub event_type: String,
+    pub event_id: String,
+    /// SHA256 digest of the event, hex-encoded
+    pub digest: String,
+}
+
+/// Stored proof with the proof bytes base64-encoded
+#[derive(Debug, Serialize)]
+pub struct OtsProofResponse {
+    pub id: i64,
+    pub event_type: String,
+    pub event_id: String,
+    pub digest_hex: String,
+    pub proof: String,
+    pub submitted_at: DateTime<Utc>,
+    pub upgraded_at: Option<DateTime<Utc>>,
+}
+
+impl From<OtsProofRecord> for OtsProofResponse {
+    fn from(record: OtsProofRecord) -> Self {
+        Self {
+            id: record.id,
+            event_type: record.event_type,
+            event_id: record.event_id,
+            digest_hex: record.digest_hex,
+            proof: general_purpose::STANDARD.encode(&record.proof_bytes),
+            submitted_at: record.submitted_at,
+            upgraded_at: record.upgraded_at,
+        }
+    }
+}
+
+fn anchorer(config: &AppConfig, database: Database) -> Result<RegistryAnchorer, ApiError> {
+    if !config.ots.enabled {
+        return Err(api_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "OpenTimestamps anchoring is not enabled",
+        ));
+    }
+    Ok(RegistryAnchorer::new(
+        OtsClient::new(config.ots.aggregator_url.clone()),
+        database,
+        config.ots.registry_path.clone(),
+        config.ots.proofs_path.clone(),
+    )
+    .with_execution_mode(config.execution_mode()))
+}
+
+/// Anchor a single governance event now instead of waiting for the monthly run
+pub async fn anchor_event(
+    State((config, database)): State<(AppConfig, Database)>,
+    Json(request): Json<AnchorEventRequest>,
+) -> Result<Json<OtsProofResponse>, ApiError> {
+    let digest: [u8; 32] = hex::decode(&request.digest)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::BAD_REQUEST,
+                "digest must be a hex-encoded 32-byte SHA256 hash",
+            )
+        })?;
+
+    anchorer(&config, database)?
+        .anchor_event(&request.event_type, &request.event_id, &digest)
+        .await
+        .map(|record| Json(record.into()))
+        .map_err(|e| {
+            warn!(
+                "Failed to anchor {} event {}: {}",
+                request.event_type, request.event_id, e
+            );
+            api_error(StatusCode::INTERNAL_SERVER_ERROR, e)
+        })
+}
+
+/// Fetch the stored proof for a governance event
+pub async fn get_event_proof(
+    State((config, database)): State<(AppConfig, Database)>,
+    Path((event_type, event_id)): Path<(String, String)>,
+) -> Result<Json<OtsProofResponse>, ApiError> {
+    anchorer(&config, database)?
+        .get_event_proof(&event_type, &event_id)
+        .await
+        .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e))?
+        .map(|record| Json(record.into()))
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::NOT_FOUND,
+                format!("No proof for {} event {}", event_type, event_id),
+            )
+        })
+}
+
+/// Check a monthly registry proof's Bitcoin confirmation with the aggregator
+pub async fn verify_proof(
+    State((config, database)): State<(AppConfig, Database)>,
+    Path(proof_id): Path<i64>,
+) -> Result<Json<OtsVerificationResult>, ApiError> {
+    anchorer(&config, database)?
+        .verify_registry_proof(proof_id)
+        .await
+        .map_err(|e| {
+            warn!("Failed to verify OTS proof {}: {}", proof_id, e);
+            api_error(StatusCode::BAD_GATEWAY, e)
+        })?
+        .map(Json)
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::NOT_FOUND,
+                format!("No anchored registry with id {}", proof_id),
+            )
+        })
+}
+
+/// Create router for OTS API; all routes require the internal API key
+pub fn create_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/internal/ots/anchor", post(anchor_event))
+        .route(
+            "/internal/ots/proof/:event_type/:event_id",
+            get(get_event_proof),
+        )
+        .route("/internal/ots/verify/:proof_id", get(verify_proof))
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ))
+}
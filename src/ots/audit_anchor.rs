@@ -0,0 +1,407 @@
+//! Audit Log Anchorer for OTS Anchoring
+//!
+//! Timestamps the audit log hash chain head to Bitcoin using OpenTimestamps,
+//! on the monthly schedule and after high-tier governance actions.
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
+
+use crate::audit::logger::AuditLogger;
+use crate::database::Database;
+use crate::ots::client::{TimestampClient, VerificationResult};
+
+/// Minimum tier whose governance actions trigger an immediate anchor
+const IMMEDIATE_ANCHOR_MIN_TIER: i64 = 4;
+
+/// Audit log anchorer
+pub struct AuditAnchorer {
+    ots_client: Box<dyn TimestampClient>,
+    database: Database,
+    audit_logger: AuditLogger,
+    proofs_path: PathBuf,
+}
+
+/// Stored audit anchor record
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AuditAnchor {
+    pub id: i64,
+    pub head_hash: String,
+    pub entry_count: i64,
+    pub proof_path: String,
+    pub trigger: String,
+    pub status: String,
+    pub block_height: Option<i64>,
+    /// Highest governance event id at anchor time
+    pub last_event_id: i64,
+    pub anchored_at: DateTime<Utc>,
+    pub upgraded_at: Option<DateTime<Utc>>,
+}
+
+impl AuditAnchor {
+    /// Check if the proof carries a Bitcoin attestation
+    pub fn is_complete(&self) -> bool {
+        self.status == "complete"
+    }
+}
+
+impl AuditAnchorer {
+    /// Create new audit anchorer
+    pub fn new(
+        ots_client: Box<dyn TimestampClient>,
+        database: Database,
+        audit_logger: AuditLogger,
+        proofs_path: String,
+    ) -> Self {
+        Self {
+            ots_client,
+            database,
+            audit_logger,
+            proofs_path: PathBuf::from(proofs_path),
+        }
+    }
+
+    /// Anchor the current audit log head
+    ///
+    /// `trigger` records why the anchor was made ("monthly" or "tier_action").
+    pub async fn anchor_head(&self, trigger: &str) -> Result<AuditAnchor> {
+        let pool = &self.pool()?;
+        // Read before the log so any event logged meanwhile is left for the next anchor
+        let last_event_id: i64 =
+            sqlx::query_scalar("SELECT COALESCE(MAX(id), 0) FROM governance_events")
+                .fetch_one(pool)
+                .await?;
+
+        let entries = self.audit_logger.get_all_entries().await?;
+        let head_hash = entries
+            .last()
+            .map(|e| e.this_log_hash.clone())
+            .ok_or_else(|| anyhow!("Audit log is empty - nothing to anchor"))?;
+        let entry_count = entries.len() as i64;
+
+        info!(
+            "Anchoring audit log head {} ({} entries, trigger: {})",
+            head_hash, entry_count, trigger
+        );
+
+        let proof_data = self.ots_client.stamp(head_hash.as_bytes()).await?;
+
+        // Microseconds keep anchors made within the same second apart
+        let proof_file = self.proofs_path.join(format!(
+            "audit-head-{}-{}.ots",
+            Utc::now().format("%Y%m%dT%H%M%S%.6fZ"),
+            entry_count
+        ));
+        write_proof(&proof_data, &proof_file)?;
+
+        let id = sqlx::query(
+            r#"
+            INSERT INTO audit_anchors (head_hash, entry_count, proof_path, trigger, status, last_event_id)
+            VALUES (?, ?, ?, ?, 'pending', ?)
+            "#,
+        )
+        .bind(&head_hash)
+        .bind(entry_count)
+        .bind(proof_file.to_string_lossy().to_string())
+        .bind(trigger)
+        .bind(last_event_id)
+        .execute(pool)
+        .await?
+        .last_insert_rowid();
+
+        let anchor = self
+            .get_anchor(id)
+            .await?
+            .ok_or_else(|| anyhow!("Audit anchor {} not found after insert", id))?;
+
+        info!(
+            "Audit log head anchored, pending proof saved to: {}",
+            anchor.proof_path
+        );
+        Ok(anchor)
+    }
+
+    /// Anchor the audit log head if a Tier 4/5 governance action was
+    /// recorded since the last anchor
+    pub async fn anchor_if_tier_action(&self) -> Result<Option<AuditAnchor>> {
+        let pool = &self.pool()?;
+
+        let pending_actions: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM governance_events
+            WHERE CAST(json_extract(details, '$.tier') AS INTEGER) >= ?
+              AND id > COALESCE((SELECT MAX(last_event_id) FROM audit_anchors), 0)
+            "#,
+        )
+        .bind(IMMEDIATE_ANCHOR_MIN_TIER)
+        .fetch_one(pool)
+        .await?;
+
+        if pending_actions == 0 {
+            debug!("No new Tier 4/5 governance actions since last audit anchor");
+            return Ok(None);
+        }
+
+        info!(
+            "{} Tier 4/5 governance action(s) recorded since last audit anchor",
+            pending_actions
+        );
+        self.anchor_head("tier_action").await.map(Some)
+    }
+
+    /// Upgrade pending proofs once the Bitcoin attestation is available
+    ///
+    /// Returns the number of proofs that became complete.
+    pub async fn upgrade_pending_proofs(&self) -> Result<usize> {
+        let pool = &self.pool()?;
+
+        let pending: Vec<AuditAnchor> = sqlx::query_as(
+            "SELECT id, head_hash, entry_count, proof_path, trigger, status, block_height, last_event_id, anchored_at, upgraded_at FROM audit_anchors WHERE status = 'pending' ORDER BY anchored_at",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut completed = 0;
+        for anchor in pending {
+            let proof_file = Path::new(&anchor.proof_path);
+            let proof_data = match fs::read(proof_file) {
+                Ok(data) => data,
+                Err(e) => {
+                    warn!(
+                        "Failed to read pending audit proof {}: {}",
+                        anchor.proof_path, e
+                    );
+                    continue;
+                }
+            };
+
+            let upgraded = self.ots_client.upgrade(&proof_data).await?;
+            if upgraded != proof_data {
+                write_proof(&upgraded, proof_file)?;
+            }
+
+            match self
+                .ots_client
+                .verify(anchor.head_hash.as_bytes(), &upgraded)
+                .await?
+            {
+                VerificationResult::Confirmed(block_height) => {
+                    sqlx::query(
+                        "UPDATE audit_anchors SET status = 'complete', block_height = ?, upgraded_at = CURRENT_TIMESTAMP WHERE id = ?",
+                    )
+                    .bind(block_height as i64)
+                    .bind(anchor.id)
+                    .execute(pool)
+                    .await?;
+
+                    info!(
+                        "Audit anchor {} confirmed at Bitcoin block height {}",
+                        anchor.id, block_height
+                    );
+                    completed += 1;
+                }
+                VerificationResult::Pending => {
+                    debug!("Audit anchor {} still pending attestation", anchor.id);
+                }
+            }
+        }
+
+        Ok(completed)
+    }
+
+    /// Get the most recent audit anchor
+    pub async fn latest_anchor(&self) -> Result<Option<AuditAnchor>> {
+        let pool = &self.pool()?;
+
+        let anchor = sqlx::query_as(
+            "SELECT id, head_hash, entry_count, proof_path, trigger, status, block_height, last_event_id, anchored_at, upgraded_at FROM audit_anchors ORDER BY anchored_at DESC, id DESC LIMIT 1",
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(anchor)
+    }
+
+    async fn get_anchor(&self, id: i64) -> Result<Option<AuditAnchor>> {
+        let pool = &self.pool()?;
+
+        let anchor = sqlx::query_as(
+            "SELECT id, head_hash, entry_count, proof_path, trigger, status, block_height, last_event_id, anchored_at, upgraded_at FROM audit_anchors WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(anchor)
+    }
+
+    fn pool(&self) -> Result<SqlitePool> {
+        self.database
+            .get_sqlite_pool()
+            .ok_or_else(|| anyhow!("Database pool not available or not SQLite"))
+    }
+}
+
+/// Save OTS proof to file
+fn write_proof(proof: &[u8], path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| anyhow!("Failed to create directory: {}", e))?;
+    }
+
+    fs::write(path, proof).map_err(|e| anyhow!("Failed to write proof file: {}", e))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::entry::AuditLogEntry;
+    use std::collections::HashMap;
+    use tempfile::tempdir;
+
+    /// Mock OTS client: stamps produce pending proofs, upgrades attach an attestation
+    struct MockTimestampClient;
+
+    #[async_trait::async_trait]
+    impl TimestampClient for MockTimestampClient {
+        async fn stamp(&self, data: &[u8]) -> Result<Vec<u8>> {
+            Ok([b"PENDING:".as_slice(), data].concat())
+        }
+
+        async fn verify(&self, data: &[u8], proof: &[u8]) -> Result<VerificationResult> {
+            if proof == [b"CONFIRMED:".as_slice(), data].concat().as_slice() {
+                Ok(VerificationResult::Confirmed(840000))
+            } else {
+                Ok(VerificationResult::Pending)
+            }
+        }
+
+        async fn upgrade(&self, proof: &[u8]) -> Result<Vec<u8>> {
+            match proof.strip_prefix(b"PENDING:".as_slice()) {
+                Some(data) => Ok([b"CONFIRMED:".as_slice(), data].concat()),
+                None => Ok(proof.to_vec()),
+            }
+        }
+    }
+
+    async fn setup() -> (tempfile::TempDir, AuditAnchorer, Database, String) {
+        let temp_dir = tempdir().unwrap();
+        let log_path = temp_dir
+            .path()
+            .join("audit.log")
+            .to_string_lossy()
+            .to_string();
+
+        let logger = AuditLogger::new(log_path).unwrap();
+        let genesis = crate::audit::entry::create_genesis_entry("governance-01".to_string());
+        logger.append_entry(genesis).await.unwrap();
+
+        let entry = AuditLogEntry::new(
+            "job-1".to_string(),
+            "test_type".to_string(),
+            "governance-01".to_string(),
+            "sha256:input".to_string(),
+            "sha256:output".to_string(),
+            logger.get_head_hash().await,
+            HashMap::new(),
+        );
+        let head_hash = entry.this_log_hash.clone();
+        logger.append_entry(entry).await.unwrap();
+
+        let database = Database::new_in_memory().await.unwrap();
+        let anchorer = AuditAnchorer::new(
+            Box::new(MockTimestampClient),
+            database.clone(),
+            logger,
+            temp_dir.path().join("proofs").to_string_lossy().to_string(),
+        );
+
+        (temp_dir, anchorer, database, head_hash)
+    }
+
+    #[tokio::test]
+    async fn test_anchor_head_writes_pending_proof() {
+        let (_temp_dir, anchorer, _database, head_hash) = setup().await;
+
+        let anchor = anchorer.anchor_head("monthly").await.unwrap();
+
+        assert_eq!(anchor.head_hash, head_hash);
+        assert_eq!(anchor.entry_count, 2);
+        assert_eq!(anchor.trigger, "monthly");
+        assert!(!anchor.is_complete());
+
+        let proof = fs::read(&anchor.proof_path).unwrap();
+        assert_eq!(proof, format!("PENDING:{}", head_hash).into_bytes());
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_pending_proofs() {
+        let (_temp_dir, anchorer, _database, head_hash) = setup().await;
+
+        let anchor = anchorer.anchor_head("monthly").await.unwrap();
+        let completed = anchorer.upgrade_pending_proofs().await.unwrap();
+        assert_eq!(completed, 1);
+
+        let proof = fs::read(&anchor.proof_path).unwrap();
+        assert_eq!(proof, format!("CONFIRMED:{}", head_hash).into_bytes());
+
+        let latest = anchorer.latest_anchor().await.unwrap().unwrap();
+        assert!(latest.is_complete());
+        assert_eq!(latest.block_height, Some(840000));
+        assert!(latest.upgraded_at.is_some());
+
+        // Nothing left to upgrade
+        assert_eq!(anchorer.upgrade_pending_proofs().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_anchor_if_tier_action() {
+        let (_temp_dir, anchorer, database, _head_hash) = setup().await;
+
+        // Low-tier actions do not trigger an anchor
+        database
+            .log_governance_event(
+                "pr_opened",
+                Some("test/repo"),
+                Some(1),
+                None,
+                &serde_json::json!({"tier": 2}),
+            )
+            .await
+            .unwrap();
+        assert!(anchorer.anchor_if_tier_action().await.unwrap().is_none());
+
+        database
+            .log_governance_event(
+                "pr_opened",
+                Some("test/repo"),
+                Some(2),
+                None,
+                &serde_json::json!({"tier": 5}),
+            )
+            .await
+            .unwrap();
+        let anchor = anchorer.anchor_if_tier_action().await.unwrap().unwrap();
+        assert_eq!(anchor.trigger, "tier_action");
+        assert!(anchorer.anchor_if_tier_action().await.unwrap().is_none());
+
+        // An action logged within the same second as the anchor is still picked up
+        database
+            .log_governance_event(
+                "pr_merged",
+                Some("test/repo"),
+                Some(2),
+                None,
+                &serde_json::json!({"tier": 4}),
+            )
+            .await
+            .unwrap();
+        let next = anchorer.anchor_if_tier_action().await.unwrap().unwrap();
+        assert!(next.last_event_id > anchor.last_event_id);
+        assert_ne!(next.proof_path, anchor.proof_path);
+    }
+}
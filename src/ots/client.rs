@@ -4,16 +4,102 @@
 //! for creating and verifying Bitcoin-anchored timestamps.
 
 use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose, Engine as _};
+use chrono::{DateTime, Utc};
 use reqwest::Client;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
 use tracing::{debug, info, warn};
 
+/// Trait for timestamping operations needed by anchorers
+/// This allows for easy mocking in tests
+#[async_trait::async_trait]
+pub trait TimestampClient: Send + Sync {
+    async fn stamp(&self, data: &[u8]) -> Result<Vec<u8>>;
+
+    async fn verify(&self, data: &[u8], proof: &[u8]) -> Result<VerificationResult>;
+
+    async fn upgrade(&self, proof: &[u8]) -> Result<Vec<u8>>;
+}
+
 /// OpenTimestamps client for creating and verifying timestamps
 pub struct OtsClient {
     aggregator_url: String,
     http_client: Client,
     calendars: HashMap<String, String>, // Calendar server URLs
+    /// Batch items the aggregator returned no receipt for, retried on the next batch
+    retry_queue: Mutex<Vec<OtsItem>>,
+}
+
+/// A governance event digest to include in a batch submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtsItem {
+    /// Caller-chosen identifier used to match receipts back to items
+    pub id: String,
+    pub digest: [u8; 32],
+}
+
+/// Timestamp proof returned by the aggregator for one batch item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtsReceipt {
+    pub id: String,
+    pub proof: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct BatchRequest<'a> {
+    items: Vec<BatchRequestItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct BatchRequestItem<'a> {
+    id: &'a str,
+    digest: String,
+}
+
+#[derive(Deserialize)]
+struct BatchResponse {
+    #[serde(default)]
+    receipts: Vec<BatchResponseReceipt>,
+}
+
+#[derive(Deserialize)]
+struct BatchResponseReceipt {
+    id: String,
+    /// Base64-encoded OTS proof
+    proof: String,
+}
+
+/// Aggregator verdict on a stored OTS proof
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OtsVerificationResult {
+    pub is_verified: bool,
+    /// Bitcoin block the timestamp commits to, once confirmed
+    pub bitcoin_block_height: Option<u64>,
+    /// Block time of `bitcoin_block_height`
+    pub timestamp: Option<DateTime<Utc>>,
+    pub confirmations: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct VerifyRequest {
+    /// Base64-encoded OTS proof
+    proof: String,
+}
+
+#[derive(Deserialize)]
+struct VerifyResponse {
+    verified: bool,
+    #[serde(default)]
+    block_height: Option<u64>,
+    /// Unix time of the attesting block
+    #[serde(default)]
+    timestamp: Option<i64>,
+    #[serde(default)]
+    confirmations: Option<u64>,
 }
 
 impl OtsClient {
@@ -36,9 +122,158 @@ impl OtsClient {
             aggregator_url,
             http_client,
             calendars,
+            retry_queue: Mutex::new(Vec::new()),
         }
     }
 
+    /// Submit several digests to the aggregator in one request
+    ///
+    /// Returns a receipt for every item the aggregator timestamped. Items it
+    /// returned no receipt for are queued and retried individually at the
+    /// start of the next call, so their receipts may arrive one batch late.
+    pub async fn submit_batch(&self, items: Vec<OtsItem>) -> Result<Vec<OtsReceipt>> {
+        let retries = std::mem::take(&mut *self.retry_queue.lock().unwrap());
+        let mut receipts = Vec::new();
+        let mut missing = Vec::new();
+
+        for item in retries {
+            match self.post_timestamps(std::slice::from_ref(&item)).await {
+                Ok(mut retried) if !retried.is_empty() => receipts.append(&mut retried),
+                Ok(_) => missing.push(item),
+                Err(e) => {
+                    warn!("Retry of OTS item {} failed: {}", item.id, e);
+                    missing.push(item);
+                }
+            }
+        }
+
+        if !items.is_empty() {
+            info!(
+                "Submitting batch of {} digests for timestamping",
+                items.len()
+            );
+            let batch_receipts = match self.post_timestamps(&items).await {
+                Ok(batch_receipts) => batch_receipts,
+                Err(e) => {
+                    // Keep earlier retries queued; the new items are the caller's to resubmit
+                    self.retry_queue.lock().unwrap().extend(missing);
+                    return Err(e);
+                }
+            };
+            for item in items {
+                if !batch_receipts.iter().any(|r| r.id == item.id) {
+                    warn!("No OTS receipt for item {}, will retry", item.id);
+                    missing.push(item);
+                }
+            }
+            receipts.extend(batch_receipts);
+        }
+
+        self.retry_queue.lock().unwrap().extend(missing);
+        Ok(receipts)
+    }
+
+    /// Items waiting to be retried on the next batch submission
+    pub fn pending_retries(&self) -> Vec<OtsItem> {
+        self.retry_queue.lock().unwrap().clone()
+    }
+
+    /// POST digests to the aggregator, returning receipts for the submitted ids
+    async fn post_timestamps(&self, items: &[OtsItem]) -> Result<Vec<OtsReceipt>> {
+        let url = format!(
+            "{}/api/v1/timestamp",
+            self.aggregator_url.trim_end_matches('/')
+        );
+        let request = BatchRequest {
+            items: items
+                .iter()
+                .map(|item| BatchRequestItem {
+                    id: &item.id,
+                    digest: hex::encode(item.digest),
+                })
+                .collect(),
+        };
+
+        let response = self
+            .http_client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| anyhow!("OTS aggregator request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "OTS aggregator returned status {}",
+                response.status()
+            ));
+        }
+        let body: BatchResponse = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Invalid OTS aggregator response: {}", e))?;
+
+        let mut receipts = Vec::new();
+        for receipt in body.receipts {
+            if !items.iter().any(|item| item.id == receipt.id) {
+                warn!("Ignoring OTS receipt for unknown item {}", receipt.id);
+                continue;
+            }
+            let proof = general_purpose::STANDARD
+                .decode(&receipt.proof)
+                .map_err(|e| anyhow!("Invalid proof for item {}: {}", receipt.id, e))?;
+            receipts.push(OtsReceipt {
+                id: receipt.id,
+                proof,
+            });
+        }
+        Ok(receipts)
+    }
+
+    /// Verify a stored .ots proof file with the aggregator
+    ///
+    /// Returns the Bitcoin block the proof is anchored in and its current
+    /// confirmation depth; an unconfirmed proof comes back with
+    /// `is_verified: false` rather than an error.
+    pub async fn verify_proof(&self, proof_path: &Path) -> Result<OtsVerificationResult> {
+        let proof = tokio::fs::read(proof_path)
+            .await
+            .map_err(|e| anyhow!("Failed to read proof file {}: {}", proof_path.display(), e))?;
+        let url = format!(
+            "{}/api/v1/verify",
+            self.aggregator_url.trim_end_matches('/')
+        );
+        debug!("Verifying {} with {}", proof_path.display(), url);
+
+        let response = self
+            .http_client
+            .post(&url)
+            .json(&VerifyRequest {
+                proof: general_purpose::STANDARD.encode(&proof),
+            })
+            .send()
+            .await
+            .map_err(|e| anyhow!("OTS aggregator request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "OTS aggregator returned status {}",
+                response.status()
+            ));
+        }
+        let body: VerifyResponse = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Invalid OTS aggregator response: {}", e))?;
+
+        Ok(OtsVerificationResult {
+            is_verified: body.verified && body.block_height.is_some(),
+            bitcoin_block_height: body.block_height,
+            timestamp: body
+                .timestamp
+                .and_then(|secs| DateTime::from_timestamp(secs, 0)),
+            confirmations: body.confirmations,
+        })
+    }
+
     /// Submit data for timestamping
     pub async fn stamp(&self, data: &[u8]) -> Result<Vec<u8>> {
         info!("Submitting {} bytes for timestamping", data.len());
@@ -107,6 +342,22 @@ impl OtsClient {
     }
 }
 
+/// Implement the trait for OtsClient
+#[async_trait::async_trait]
+impl TimestampClient for OtsClient {
+    async fn stamp(&self, data: &[u8]) -> Result<Vec<u8>> {
+        OtsClient::stamp(self, data).await
+    }
+
+    async fn verify(&self, data: &[u8], proof: &[u8]) -> Result<VerificationResult> {
+        OtsClient::verify(self, data, proof).await
+    }
+
+    async fn upgrade(&self, proof: &[u8]) -> Result<Vec<u8>> {
+        OtsClient::upgrade(self, proof).await
+    }
+}
+
 /// Result of timestamp verification
 #[derive(Debug, Clone)]
 pub enum VerificationResult {
@@ -146,6 +397,111 @@ mod tests {
         assert_eq!(confirmed.block_height(), Some(12345));
     }
 
+    fn item(id: &str, byte: u8) -> OtsItem {
+        OtsItem {
+            id: id.to_string(),
+            digest: [byte; 32],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_batch_retries_missing_receipts() {
+        use wiremock::matchers::{body_partial_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        let proof = general_purpose::STANDARD.encode(b"proof");
+
+        // Single-item retry of "config-change"
+        Mock::given(method("POST"))
+            .and(path("/api/v1/timestamp"))
+            .and(body_partial_json(serde_json::json!({
+                "items": [{ "id": "config-change" }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "receipts": [{ "id": "config-change", "proof": proof }]
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        // Batch responses omit "config-change"
+        Mock::given(method("POST"))
+            .and(path("/api/v1/timestamp"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "receipts": [
+                    { "id": "pr-1", "proof": proof },
+                    { "id": "pr-2", "proof": proof },
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let client = OtsClient::new(server.uri());
+        let receipts = client
+            .submit_batch(vec![item("pr-1", 1), item("config-change", 2)])
+            .await
+            .unwrap();
+        assert_eq!(receipts.len(), 1);
+        assert_eq!(receipts[0].id, "pr-1");
+        assert_eq!(receipts[0].proof, b"proof");
+        assert_eq!(client.pending_retries(), vec![item("config-change", 2)]);
+
+        let receipts = client.submit_batch(vec![item("pr-2", 3)]).await.unwrap();
+        let ids: Vec<&str> = receipts.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["config-change", "pr-2"]);
+        assert!(client.pending_retries().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_verify_proof() {
+        use wiremock::matchers::{body_json, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/verify"))
+            .and(body_json(serde_json::json!({
+                "proof": general_purpose::STANDARD.encode(b"confirmed")
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "verified": true,
+                "block_height": 850000,
+                "timestamp": 1719792000,
+                "confirmations": 150
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/verify"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "verified": false })),
+            )
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let confirmed = dir.path().join("confirmed.ots");
+        let pending = dir.path().join("pending.ots");
+        std::fs::write(&confirmed, b"confirmed").unwrap();
+        std::fs::write(&pending, b"pending").unwrap();
+
+        let client = OtsClient::new(server.uri());
+        let result = client.verify_proof(&confirmed).await.unwrap();
+        assert!(result.is_verified);
+        assert_eq!(result.bitcoin_block_height, Some(850000));
+        assert_eq!(result.confirmations, Some(150));
+        assert_eq!(result.timestamp, DateTime::from_timestamp(1719792000, 0));
+
+        let result = client.verify_proof(&pending).await.unwrap();
+        assert!(!result.is_verified);
+        assert_eq!(result.bitcoin_block_height, None);
+
+        assert!(client
+            .verify_proof(&dir.path().join("missing.ots"))
+            .await
+            .is_err());
+    }
+
     #[tokio::test]
     async fn test_client_creation() {
         let client = OtsClient::new("https://alice.btc.calendar.opentimestamps.org".to_string());
@@ -4,9 +4,12 @@
 //! by anchoring monthly registries to the Bitcoin blockchain.
 
 pub mod anchor;
+pub mod api;
+pub mod audit_anchor;
 pub mod client;
 pub mod verify;
 
-pub use anchor::RegistryAnchorer;
-pub use client::OtsClient;
+pub use anchor::{OtsProofRecord, RegistryAnchorer};
+pub use audit_anchor::AuditAnchorer;
+pub use client::{OtsClient, OtsItem, OtsReceipt, OtsVerificationResult, TimestampClient};
 pub use verify::verify_registry;
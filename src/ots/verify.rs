@@ -202,6 +202,7 @@ mod tests {
                 required_signatures: 3,
                 total_maintainers: 5,
             },
+            node_registry_hash: None,
         };
 
         let json = serde_json::to_string_pretty(&registry).unwrap();
@@ -3,15 +3,18 @@
 //! Prevents cascading failures by temporarily stopping requests to failing services.
 //! Implements three states: Closed (normal), Open (failing), HalfOpen (testing recovery).
 
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::Mutex;
 use tracing::{info, warn};
 
 /// Circuit breaker state
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CircuitState {
     /// Circuit is closed - requests are allowed
+    #[default]
     Closed,
     /// Circuit is open - requests are rejected immediately
     Open,
@@ -30,6 +33,9 @@ pub struct CircuitBreakerConfig {
     pub timeout: Duration,
     /// Window duration - time window for counting failures
     pub window_duration: Duration,
+    /// Clear the failure count on every success, so only consecutive failures
+    /// open the circuit
+    pub reset_failures_on_success: bool,
 }
 
 impl Default for CircuitBreakerConfig {
@@ -39,6 +45,7 @@ impl Default for CircuitBreakerConfig {
             success_threshold: 2,
             timeout: Duration::from_secs(60),
             window_duration: Duration::from_secs(60),
+            reset_failures_on_success: false,
         }
     }
 }
@@ -115,7 +122,11 @@ impl CircuitBreaker {
                 // Release state and successes locks first to avoid potential deadlock
                 drop(state);
                 drop(successes);
-                self.cleanup_old_failures().await;
+                if self.config.reset_failures_on_success {
+                    self.failures.lock().await.clear();
+                } else {
+                    self.cleanup_old_failures().await;
+                }
             }
             CircuitState::HalfOpen => {
                 *successes += 1;
@@ -298,6 +309,7 @@ mod tests {
                 success_threshold: 2,
                 timeout: Duration::from_secs(1),
                 window_duration: Duration::from_secs(60),
+                reset_failures_on_success: false,
             },
         );
 
@@ -324,6 +336,7 @@ mod tests {
                 success_threshold: 2,
                 timeout: Duration::from_millis(100),
                 window_duration: Duration::from_secs(60),
+                reset_failures_on_success: false,
             },
         );
 
@@ -346,4 +359,25 @@ mod tests {
         // Should be closed now
         assert_eq!(cb.state().await, CircuitState::Closed);
     }
+
+    #[tokio::test]
+    async fn test_reset_failures_on_success() {
+        let cb = CircuitBreaker::with_config(
+            "test",
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                reset_failures_on_success: true,
+                ..Default::default()
+            },
+        );
+
+        // A success between failures means they are not consecutive
+        cb.record_failure().await;
+        cb.record_success().await;
+        cb.record_failure().await;
+        assert_eq!(cb.state().await, CircuitState::Closed);
+
+        cb.record_failure().await;
+        assert_eq!(cb.state().await, CircuitState::Open);
+    }
 }
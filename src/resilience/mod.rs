@@ -4,7 +4,9 @@
 //! to prevent cascading failures and improve system reliability.
 
 pub mod circuit_breaker;
+pub mod retry;
 
 pub use circuit_breaker::{
     CircuitBreaker, CircuitBreakerConfig, CircuitBreakerError, CircuitState,
 };
+pub use retry::{retry_with_backoff, RetryAction, RetryConfig};
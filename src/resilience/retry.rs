@@ -0,0 +1,191 @@
+//! Retry with Exponential Backoff
+//!
+//! Retries transient failures with exponentially growing, jittered delays.
+//! Callers classify each error, optionally passing along a server-provided
+//! delay (e.g. from `Retry-After`) which takes precedence over the backoff.
+
+use rand::Rng;
+use std::future::Future;
+use std::time::Duration;
+use tracing::warn;
+
+/// Retry configuration
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Total attempts, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound on any single delay, including server-provided ones
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Delay before retry number `attempt` (1-based)
+    ///
+    /// A server-provided hint is used as-is (capped at `max_delay`); otherwise
+    /// the exponential delay is jittered into its upper half so concurrent
+    /// callers don't retry in lockstep.
+    pub fn delay_for(&self, attempt: u32, hint: Option<Duration>) -> Duration {
+        if let Some(hint) = hint {
+            return hint.min(self.max_delay);
+        }
+
+        let exponent = attempt.saturating_sub(1).min(16);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+        let half = backoff / 2;
+        let jitter_ms = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter_ms)
+    }
+}
+
+/// How a failed attempt should be handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryAction {
+    /// Retry, optionally after a server-provided delay
+    Retry(Option<Duration>),
+    /// Give up and return the error
+    Fail,
+}
+
+/// Run `op` until it succeeds, `classify` says to stop, or attempts run out
+///
+/// `on_retry` is invoked before each retry, e.g. to record metrics.
+pub async fn retry_with_backoff<F, Fut, T, E, C, R>(
+    config: &RetryConfig,
+    mut op: F,
+    classify: C,
+    mut on_retry: R,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    C: Fn(&E) -> RetryAction,
+    R: FnMut(u32, &E),
+    E: std::fmt::Display,
+{
+    let mut attempt = 1;
+    loop {
+        match op().await {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                let hint = match classify(&e) {
+                    RetryAction::Retry(hint) if attempt < config.max_attempts => hint,
+                    _ => return Err(e),
+                };
+                let delay = config.delay_for(attempt, hint);
+                warn!(
+                    "Attempt {}/{} failed: {} - retrying in {:?}",
+                    attempt, config.max_attempts, e, delay
+                );
+                on_retry(attempt, &e);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn fast_config(max_attempts: u32) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+        }
+    }
+
+    #[test]
+    fn test_delay_bounds() {
+        let config = RetryConfig {
+            max_attempts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+        };
+
+        for attempt in 1..=10 {
+            let delay = config.delay_for(attempt, None);
+            assert!(delay <= config.max_delay);
+        }
+        let third = config.delay_for(3, None);
+        assert!(third >= Duration::from_millis(200) && third <= Duration::from_millis(400));
+
+        // Server hints win but are still capped
+        assert_eq!(
+            config.delay_for(1, Some(Duration::from_millis(250))),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            config.delay_for(1, Some(Duration::from_secs(60))),
+            config.max_delay
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retries_until_success() {
+        let calls = AtomicU32::new(0);
+        let result: Result<u32, String> = retry_with_backoff(
+            &fast_config(3),
+            || async {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 | 1 => Err("transient".to_string()),
+                    n => Ok(n),
+                }
+            },
+            |_| RetryAction::Retry(None),
+            |_, _| {},
+        )
+        .await;
+
+        assert_eq!(result, Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fatal_errors_and_exhaustion() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), String> = retry_with_backoff(
+            &fast_config(3),
+            || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err("not found".to_string())
+            },
+            |_| RetryAction::Fail,
+            |_, _| {},
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        let calls = AtomicU32::new(0);
+        let result: Result<(), String> = retry_with_backoff(
+            &fast_config(3),
+            || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err("unavailable".to_string())
+            },
+            |_| RetryAction::Retry(None),
+            |_, _| {},
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
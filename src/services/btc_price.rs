@@ -1,14 +1,21 @@
 //! BTC Price Service with Moving Average
 //!
-//! Provides BTC price with rolling average to smooth volatility
-//! Prevents contributors from being penalized by sudden price movements
+//! Fetches BTC/USD spot prices from configurable HTTP sources and keeps a
+//! rolling history in `btc_price_history`. USD amounts are converted with the
+//! moving average so contributors aren't penalized by sudden price movements.
 
-use chrono::{DateTime, Utc};
-use std::collections::VecDeque;
-use tracing::{info, warn};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+use tracing::{debug, info, warn};
+
+use crate::config::{BtcPriceConfig, PriceSource};
+use crate::error::GovernanceError;
+
+/// Extra days of history kept beyond the moving average window
+const HISTORY_RETENTION_EXTRA_DAYS: i64 = 7;
 
 /// BTC price with timestamp
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, sqlx::FromRow)]
 pub struct BtcPrice {
     pub price_usd: f64,
     pub timestamp: DateTime<Utc>,
@@ -16,150 +23,311 @@ pub struct BtcPrice {
 
 /// BTC Price Service with Moving Average
 pub struct BtcPriceService {
-    /// Historical prices (timestamp, price_usd)
-    prices: VecDeque<BtcPrice>,
+    pool: SqlitePool,
+    sources: Vec<PriceSource>,
     /// Moving average window in days (default: 30)
     ma_window_days: u32,
-    /// Maximum number of price points to keep
-    max_price_points: usize,
+    http_client: reqwest::Client,
 }
 
 impl BtcPriceService {
     /// Create a new BTC price service
-    pub fn new(ma_window_days: u32) -> Self {
+    pub fn new(pool: SqlitePool, config: &BtcPriceConfig) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(config.timeout_secs))
+            .build()
+            .unwrap_or_default();
+
         Self {
-            prices: VecDeque::new(),
-            ma_window_days,
-            max_price_points: 1000, // Keep up to 1000 price points
+            pool,
+            sources: config.sources.clone(),
+            ma_window_days: config.moving_average_days,
+            http_client,
         }
     }
 
-    /// Add a new price point
-    pub fn add_price(&mut self, price_usd: f64, timestamp: DateTime<Utc>) {
-        // Add new price
-        self.prices.push_back(BtcPrice {
-            price_usd,
-            timestamp,
-        });
-
-        // Trim old prices beyond window
-        let cutoff = Utc::now() - chrono::Duration::days(self.ma_window_days as i64 + 7); // Keep 7 extra days
-        while let Some(front) = self.prices.front() {
-            if front.timestamp < cutoff {
-                self.prices.pop_front();
-            } else {
-                break;
+    /// Query a single source for the current price
+    async fn fetch_from_source(&self, source: &PriceSource) -> Result<f64, GovernanceError> {
+        let body: serde_json::Value = self
+            .http_client
+            .get(&source.url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| GovernanceError::PriceUnavailable(format!("{}: {}", source.url, e)))?
+            .json()
+            .await
+            .map_err(|e| GovernanceError::PriceUnavailable(format!("{}: {}", source.url, e)))?;
+
+        body.pointer(&source.json_pointer)
+            .and_then(|value| {
+                value
+                    .as_f64()
+                    .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+            })
+            .filter(|price| *price > 0.0)
+            .ok_or_else(|| {
+                GovernanceError::PriceUnavailable(format!(
+                    "{}: no price at {}",
+                    source.url, source.json_pointer
+                ))
+            })
+    }
+
+    /// Fetch the current spot price from all sources, returning the median
+    pub async fn fetch_spot(&self) -> Result<f64, GovernanceError> {
+        let mut prices = Vec::new();
+        for source in &self.sources {
+            match self.fetch_from_source(source).await {
+                Ok(price) => {
+                    debug!("BTC price from {}: ${:.2}", source.url, price);
+                    prices.push(price);
+                }
+                Err(e) => warn!("Failed to fetch BTC price: {}", e),
             }
         }
 
-        // Trim if too many points
-        while self.prices.len() > self.max_price_points {
-            self.prices.pop_front();
+        if prices.is_empty() {
+            return Err(GovernanceError::PriceUnavailable(
+                "no price source responded".to_string(),
+            ));
         }
+
+        prices.sort_by(|a, b| a.total_cmp(b));
+        let mid = prices.len() / 2;
+        Ok(if prices.len() % 2 == 0 {
+            (prices[mid - 1] + prices[mid]) / 2.0
+        } else {
+            prices[mid]
+        })
     }
 
-    /// Get current moving average price
-    pub fn get_moving_average(&self) -> f64 {
-        let cutoff = Utc::now() - chrono::Duration::days(self.ma_window_days as i64);
-
-        let recent_prices: Vec<f64> = self
-            .prices
-            .iter()
-            .filter(|p| p.timestamp >= cutoff)
-            .map(|p| p.price_usd)
-            .collect();
-
-        if recent_prices.is_empty() {
-            warn!(
-                "No price data in {} day window, using latest price or default",
-                self.ma_window_days
-            );
-            // Fallback to latest price if available
-            return self.prices.back().map(|p| p.price_usd).unwrap_or(50000.0); // Default fallback
-        }
+    /// Fetch the spot price and record it in the price history
+    pub async fn refresh(&self) -> Result<f64, GovernanceError> {
+        let price = self.fetch_spot().await?;
+        self.add_price(price, Utc::now()).await?;
+        info!("Recorded BTC price: ${:.2}", price);
+        Ok(price)
+    }
 
-        let sum: f64 = recent_prices.iter().sum();
-        let avg = sum / recent_prices.len() as f64;
+    /// Add a new price point, pruning history beyond the retention window
+    pub async fn add_price(
+        &self,
+        price_usd: f64,
+        timestamp: DateTime<Utc>,
+    ) -> Result<(), GovernanceError> {
+        sqlx::query("INSERT INTO btc_price_history (price_usd, timestamp) VALUES (?, ?)")
+            .bind(price_usd)
+            .bind(timestamp)
+            .execute(&self.pool)
+            .await?;
 
-        info!(
-            "BTC price MA ({} days): ${:.2} (from {} price points)",
-            self.ma_window_days,
-            avg,
-            recent_prices.len()
-        );
+        let cutoff =
+            Utc::now() - Duration::days(self.ma_window_days as i64 + HISTORY_RETENTION_EXTRA_DAYS);
+        sqlx::query("DELETE FROM btc_price_history WHERE timestamp < ?")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await?;
 
-        avg
+        Ok(())
     }
 
-    /// Get latest price (not averaged)
-    pub fn get_latest_price(&self) -> Option<f64> {
-        self.prices.back().map(|p| p.price_usd)
+    /// Get current moving average price
+    ///
+    /// Fails with `PriceUnavailable` when there is no price in the window.
+    pub async fn get_moving_average(&self) -> Result<f64, GovernanceError> {
+        let cutoff = Utc::now() - Duration::days(self.ma_window_days as i64);
+        let average: Option<f64> =
+            sqlx::query_scalar("SELECT AVG(price_usd) FROM btc_price_history WHERE timestamp >= ?")
+                .bind(cutoff)
+                .fetch_one(&self.pool)
+                .await?;
+
+        average.ok_or_else(|| {
+            GovernanceError::PriceUnavailable(format!(
+                "no price data in {} day window",
+                self.ma_window_days
+            ))
+        })
     }
 
-    /// Convert USD to BTC using moving average price
-    pub fn usd_to_btc(&self, usd_amount: f64) -> f64 {
-        let ma_price = self.get_moving_average();
-        if ma_price <= 0.0 {
-            warn!("Invalid BTC price, using default conversion");
-            return usd_amount / 50000.0; // Fallback
-        }
-        usd_amount / ma_price
+    /// Get latest recorded price (not averaged)
+    pub async fn get_spot(&self) -> Result<BtcPrice, GovernanceError> {
+        sqlx::query_as::<_, BtcPrice>(
+            "SELECT price_usd, timestamp FROM btc_price_history ORDER BY timestamp DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| GovernanceError::PriceUnavailable("no price recorded".to_string()))
     }
 
-    /// Get number of price points in window
-    pub fn price_point_count(&self) -> usize {
-        let cutoff = Utc::now() - chrono::Duration::days(self.ma_window_days as i64);
-        self.prices.iter().filter(|p| p.timestamp >= cutoff).count()
+    /// Convert USD to BTC using moving average price
+    pub async fn usd_to_btc(&self, usd_amount: f64) -> Result<f64, GovernanceError> {
+        Ok(usd_amount / self.get_moving_average().await?)
     }
-}
 
-impl Default for BtcPriceService {
-    fn default() -> Self {
-        Self::new(30) // Default 30-day moving average
+    /// Get number of price points in window
+    pub async fn price_point_count(&self) -> Result<i64, GovernanceError> {
+        let cutoff = Utc::now() - Duration::days(self.ma_window_days as i64);
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM btc_price_history WHERE timestamp >= ?")
+                .bind(cutoff)
+                .fetch_one(&self.pool)
+                .await?;
+        Ok(count)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::database::Database;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn service(sources: Vec<PriceSource>) -> BtcPriceService {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.get_sqlite_pool().unwrap();
+        BtcPriceService::new(
+            pool,
+            &BtcPriceConfig {
+                sources,
+                moving_average_days: 30,
+                refresh_interval_secs: 3600,
+                timeout_secs: 5,
+            },
+        )
+    }
 
-    #[test]
-    fn test_moving_average() {
-        let mut service = BtcPriceService::new(30);
+    #[tokio::test]
+    async fn test_moving_average() {
+        let service = service(vec![]).await;
 
         // Add prices over 30 days
-        let base_time = Utc::now() - chrono::Duration::days(30);
+        let base_time = Utc::now() - Duration::days(29);
         for i in 0..30 {
             let price = 50000.0 + (i as f64 * 100.0); // Increasing prices
-            let timestamp = base_time + chrono::Duration::days(i);
-            service.add_price(price, timestamp);
+            service
+                .add_price(price, base_time + Duration::days(i))
+                .await
+                .unwrap();
         }
 
         // Moving average should be around middle of range
-        let ma = service.get_moving_average();
+        let ma = service.get_moving_average().await.unwrap();
         assert!(ma > 50000.0 && ma < 53000.0);
+        assert_eq!(service.price_point_count().await.unwrap(), 30);
+        assert_eq!(service.get_spot().await.unwrap().price_usd, 52900.0);
     }
 
-    #[test]
-    fn test_usd_to_btc_conversion() {
-        let mut service = BtcPriceService::new(30);
+    #[tokio::test]
+    async fn test_usd_to_btc_conversion() {
+        let service = service(vec![]).await;
 
-        // Add some prices
         for i in 0..10 {
-            service.add_price(50000.0, Utc::now() - chrono::Duration::days(i));
+            service
+                .add_price(50000.0, Utc::now() - Duration::days(i))
+                .await
+                .unwrap();
         }
 
         // $50,000 should convert to 1.0 BTC
-        let btc = service.usd_to_btc(50000.0);
+        let btc = service.usd_to_btc(50000.0).await.unwrap();
         assert!((btc - 1.0).abs() < 0.01);
     }
 
-    #[test]
-    fn test_empty_price_data() {
-        let service = BtcPriceService::new(30);
+    #[tokio::test]
+    async fn test_empty_price_data() {
+        let service = service(vec![]).await;
+
+        // No silent fallback price when there is no data
+        assert!(matches!(
+            service.get_moving_average().await,
+            Err(GovernanceError::PriceUnavailable(_))
+        ));
+        assert!(matches!(
+            service.usd_to_btc(50000.0).await,
+            Err(GovernanceError::PriceUnavailable(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stale_history_is_not_used() {
+        let service = service(vec![]).await;
+        service
+            .add_price(50000.0, Utc::now() - Duration::days(35))
+            .await
+            .unwrap();
+
+        assert!(service.get_moving_average().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_uses_median_of_sources() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/coingecko"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"bitcoin": {"usd": 60000.0}})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/mempool"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"USD": 61000})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/coinbase"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": {"amount": "70000.00"}})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/down"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let source = |route: &str, pointer: &str| PriceSource {
+            url: format!("{}{}", server.uri(), route),
+            json_pointer: pointer.to_string(),
+        };
+        let service = service(vec![
+            source("/coingecko", "/bitcoin/usd"),
+            source("/mempool", "/USD"),
+            source("/coinbase", "/data/amount"),
+            source("/down", "/USD"),
+        ])
+        .await;
+
+        assert_eq!(service.refresh().await.unwrap(), 61000.0);
+        assert_eq!(service.get_spot().await.unwrap().price_usd, 61000.0);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_fails_when_all_sources_down() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&server)
+            .await;
+
+        let service = service(vec![PriceSource {
+            url: server.uri(),
+            json_pointer: "/USD".to_string(),
+        }])
+        .await;
 
-        // Should return default when no data
-        let ma = service.get_moving_average();
-        assert_eq!(ma, 50000.0); // Default fallback
+        assert!(matches!(
+            service.refresh().await,
+            Err(GovernanceError::PriceUnavailable(_))
+        ));
+        assert!(service.get_spot().await.is_err());
     }
 }
@@ -0,0 +1,186 @@
+//! Lightning Node Client
+//!
+//! Minimal LND / CLN REST client used to confirm that zap invoices issued
+//! by our node were actually paid.
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+use tracing::debug;
+
+use crate::config::{LightningBackend, LightningNodeConfig};
+
+/// Looks up whether a Lightning invoice has been paid
+#[async_trait::async_trait]
+pub trait PaymentVerifier: Send + Sync {
+    /// Returns true if the invoice with this payment hash (hex) was settled
+    async fn is_invoice_paid(&self, payment_hash: &str) -> Result<bool>;
+}
+
+/// LND / CLN REST client
+pub struct LightningNodeClient {
+    backend: LightningBackend,
+    base_url: String,
+    auth_token: Option<String>,
+    http_client: reqwest::Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct LndInvoice {
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    settled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClnListInvoices {
+    invoices: Vec<ClnInvoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ClnInvoice {
+    status: String,
+}
+
+impl LightningNodeClient {
+    /// Create a new Lightning node client
+    pub fn new(config: &LightningNodeConfig) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.timeout_secs))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            backend: config.backend,
+            base_url: config.url.trim_end_matches('/').to_string(),
+            auth_token: config.auth_token.clone(),
+            http_client,
+        }
+    }
+
+    /// LND: GET /v1/invoice/{r_hash}
+    async fn lnd_invoice_paid(&self, payment_hash: &str) -> Result<bool> {
+        let mut request = self
+            .http_client
+            .get(format!("{}/v1/invoice/{}", self.base_url, payment_hash));
+        if let Some(macaroon) = &self.auth_token {
+            request = request.header("Grpc-Metadata-macaroon", macaroon);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("LND request failed: {}", e))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        if !response.status().is_success() {
+            return Err(anyhow!("LND invoice lookup returned {}", response.status()));
+        }
+
+        let invoice: LndInvoice = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Invalid LND response: {}", e))?;
+        Ok(invoice.settled || invoice.state.as_deref() == Some("SETTLED"))
+    }
+
+    /// CLN (clnrest): POST /v1/listinvoices
+    async fn cln_invoice_paid(&self, payment_hash: &str) -> Result<bool> {
+        let mut request = self
+            .http_client
+            .post(format!("{}/v1/listinvoices", self.base_url))
+            .json(&json!({ "payment_hash": payment_hash }));
+        if let Some(rune) = &self.auth_token {
+            request = request.header("Rune", rune);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("CLN request failed: {}", e))?;
+        if !response.status().is_success() {
+            return Err(anyhow!("CLN listinvoices returned {}", response.status()));
+        }
+
+        let list: ClnListInvoices = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Invalid CLN response: {}", e))?;
+        Ok(list.invoices.iter().any(|invoice| invoice.status == "paid"))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentVerifier for LightningNodeClient {
+    async fn is_invoice_paid(&self, payment_hash: &str) -> Result<bool> {
+        debug!(
+            "Checking invoice {} on {:?} node",
+            payment_hash, self.backend
+        );
+        match self.backend {
+            LightningBackend::Lnd => self.lnd_invoice_paid(payment_hash).await,
+            LightningBackend::Cln => self.cln_invoice_paid(payment_hash).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn config(backend: LightningBackend, url: String) -> LightningNodeConfig {
+        LightningNodeConfig {
+            backend,
+            url,
+            auth_token: Some("token".to_string()),
+            timeout_secs: 5,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_lnd_settled_invoice() {
+        let server = MockServer::start().await;
+        let hash = "aa".repeat(32);
+        Mock::given(method("GET"))
+            .and(path(format!("/v1/invoice/{}", hash)))
+            .and(header("Grpc-Metadata-macaroon", "token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({"state": "SETTLED"})))
+            .mount(&server)
+            .await;
+
+        let client = LightningNodeClient::new(&config(LightningBackend::Lnd, server.uri()));
+        assert!(client.is_invoice_paid(&hash).await.unwrap());
+        assert!(!client.is_invoice_paid(&"bb".repeat(32)).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_cln_paid_invoice() {
+        let server = MockServer::start().await;
+        let hash = "aa".repeat(32);
+        Mock::given(method("POST"))
+            .and(path("/v1/listinvoices"))
+            .and(body_json(json!({"payment_hash": hash})))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({"invoices": [{"status": "paid"}]})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/listinvoices"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"invoices": [{"status": "unpaid"}]})),
+            )
+            .mount(&server)
+            .await;
+
+        let client = LightningNodeClient::new(&config(LightningBackend::Cln, server.uri()));
+        assert!(client.is_invoice_paid(&hash).await.unwrap());
+        assert!(!client.is_invoice_paid(&"bb".repeat(32)).await.unwrap());
+    }
+}
@@ -3,5 +3,7 @@
 //! Provides various services for the governance system
 
 pub mod btc_price;
+pub mod lightning;
 
 pub use btc_price::BtcPriceService;
+pub use lightning::{LightningNodeClient, PaymentVerifier};
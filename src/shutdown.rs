@@ -0,0 +1,317 @@
+//! Graceful shutdown
+//!
+//! Background tasks are spawned through [`Shutdown`] and stop at their next
+//! loop iteration once SIGTERM or SIGINT arrives, so a backup or weight batch
+//! in progress is finished rather than abandoned. `main` then stops the HTTP
+//! server (in-flight webhook requests complete first), drains the tasks,
+//! flushes the audit log and closes the database. Tasks still running when
+//! the drain timeout passes are aborted and named in the log.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use tokio::task::AbortHandle;
+use tokio::time::Interval;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+use tracing::{error, info, warn};
+
+/// How long background tasks get to finish their current work
+pub const DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long aborted tasks get to unwind after the drain timeout
+const ABORT_GRACE: Duration = Duration::from_secs(1);
+
+/// The server's coordinator, for work spawned outside `main` (e.g. from a webhook)
+static SHARED: OnceLock<Shutdown> = OnceLock::new();
+
+/// Register the server's coordinator (only the first registration is kept)
+pub fn set_shared(shutdown: Shutdown) {
+    let _ = SHARED.set(shutdown);
+}
+
+/// The server's coordinator, if one was registered
+pub fn shared() -> Option<&'static Shutdown> {
+    SHARED.get()
+}
+
+/// Cancellation and tracking for every background task
+#[derive(Clone, Default)]
+pub struct Shutdown {
+    token: CancellationToken,
+    tracker: TaskTracker,
+    panicked: Arc<AtomicUsize>,
+    next_id: Arc<AtomicU64>,
+    /// Tasks still running, by spawn order
+    running: Arc<Mutex<BTreeMap<u64, (&'static str, AbortHandle)>>>,
+    /// Tasks that exited on their own after shutdown began
+    stopped: Arc<Mutex<Vec<&'static str>>>,
+}
+
+/// Outcome of [`Shutdown::drain`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrainReport {
+    /// Every task exited before the timeout
+    pub completed: bool,
+    /// Tasks that finished their current work and exited
+    pub stopped: Vec<&'static str>,
+    /// Tasks aborted because they were still running at the timeout
+    pub terminated: Vec<&'static str>,
+    /// Tasks that panicked
+    pub panicked: usize,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Token that is cancelled when shutdown begins
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
+    pub fn is_shutting_down(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Ask every task to stop after its current unit of work
+    pub fn trigger(&self) {
+        self.token.cancel();
+    }
+
+    /// Spawn a tracked task; `task` receives the shutdown token
+    pub fn spawn<F, Fut>(&self, name: &'static str, task: F)
+    where
+        F: FnOnce(CancellationToken) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let handle = tokio::spawn(task(self.token()));
+        lock(&self.running).insert(id, (name, handle.abort_handle()));
+
+        let token = self.token();
+        let panicked = self.panicked.clone();
+        let running = self.running.clone();
+        let stopped = self.stopped.clone();
+        self.tracker.spawn(async move {
+            let result = handle.await;
+            // Aborted tasks were already taken out of `running` by drain
+            if lock(&running).remove(&id).is_some() && token.is_cancelled() {
+                lock(&stopped).push(name);
+            }
+            if let Err(e) = result {
+                if e.is_panic() {
+                    panicked.fetch_add(1, Ordering::Relaxed);
+                    if token.is_cancelled() {
+                        warn!("Background task {} panicked after shutdown", name);
+                    } else {
+                        error!("Background task {} panicked", name);
+                    }
+                }
+            }
+        });
+    }
+
+    /// Cancel all tasks and wait up to `timeout` for them to exit
+    ///
+    /// Tasks still running after `timeout` are aborted.
+    pub async fn drain(&self, timeout: Duration) -> DrainReport {
+        self.trigger();
+        self.tracker.close();
+        let completed = tokio::time::timeout(timeout, self.tracker.wait())
+            .await
+            .is_ok();
+
+        let terminated: Vec<&'static str> = std::mem::take(&mut *lock(&self.running))
+            .into_values()
+            .map(|(name, abort)| {
+                abort.abort();
+                name
+            })
+            .collect();
+        if !terminated.is_empty() {
+            let _ = tokio::time::timeout(ABORT_GRACE, self.tracker.wait()).await;
+        }
+
+        let report = DrainReport {
+            completed,
+            stopped: lock(&self.stopped).clone(),
+            terminated,
+            panicked: self.panicked.load(Ordering::Relaxed),
+        };
+        info!(
+            "Background tasks stopped cleanly: {}",
+            report.stopped.join(", ")
+        );
+        if !report.terminated.is_empty() {
+            warn!(
+                "Background tasks terminated after {:?}: {}",
+                timeout,
+                report.terminated.join(", ")
+            );
+        }
+        report
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Wait for the next tick of `interval`; false once shutdown has begun
+///
+/// Background loops use `while next_tick(&token, &mut interval).await` in
+/// place of a bare `interval.tick()`.
+pub async fn next_tick(token: &CancellationToken, interval: &mut Interval) -> bool {
+    tokio::select! {
+        biased;
+        _ = token.cancelled() => false,
+        _ = interval.tick() => true,
+    }
+}
+
+/// Sleep for `duration`; false if shutdown began first
+pub async fn sleep(token: &CancellationToken, duration: Duration) -> bool {
+    tokio::select! {
+        biased;
+        _ = token.cancelled() => false,
+        _ = tokio::time::sleep(duration) => true,
+    }
+}
+
+/// Resolve on SIGINT (Ctrl+C) or, on Unix, SIGTERM
+pub async fn wait_for_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!("Received SIGINT, shutting down"),
+        _ = terminate => info!("Received SIGTERM, shutting down"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backup::{BackupConfig, BackupManager};
+    use crate::config::AppConfig;
+    use crate::database::Database;
+    use crate::enforcement::emergency_manager::{spawn_expiry_task, EmergencyManager};
+    use crate::governance::time_lock::{
+        migrate_time_lock_tables, spawn_auto_activation_task, TimeLockConfig, TimeLockManager,
+    };
+    use crate::governance_review::deadline_notifications::{
+        spawn_reminder_task, DeadlineNotificationManager,
+    };
+    use crate::webhooks::queue::{spawn_worker, WebhookQueue};
+
+    #[tokio::test]
+    async fn test_drain_stops_background_tasks() {
+        let db = Database::new_in_memory().await.unwrap();
+        let pool = db.get_sqlite_pool().unwrap();
+        migrate_time_lock_tables(&db).await.unwrap();
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let shutdown = Shutdown::new();
+
+        let backups = BackupManager::new(
+            db.clone(),
+            BackupConfig {
+                directory: temp_dir.path().to_path_buf(),
+                retention_days: 30,
+                compression: false,
+                interval: Duration::from_millis(50),
+                enabled: true,
+            },
+        )
+        .with_shutdown(shutdown.token());
+        Arc::new(backups).start_backup_task(&shutdown);
+        spawn_expiry_task(Arc::new(EmergencyManager::new(db.clone())), &shutdown);
+        let time_locks = TimeLockManager::new(
+            db.clone(),
+            TimeLockConfig {
+                auto_activate: true,
+                ..Default::default()
+            },
+        );
+        spawn_auto_activation_task(Arc::new(time_locks), &shutdown);
+        spawn_reminder_task(
+            Arc::new(DeadlineNotificationManager::new(pool.clone(), None)),
+            vec![7, 1],
+            &shutdown,
+        );
+        spawn_worker(
+            WebhookQueue::new(pool),
+            AppConfig::default(),
+            db.clone(),
+            &shutdown,
+        );
+        shutdown.spawn("ticker", |token| async move {
+            let mut interval = tokio::time::interval(Duration::from_millis(10));
+            while next_tick(&token, &mut interval).await {}
+        });
+
+        // Let every task get through at least one iteration
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let report = shutdown.drain(Duration::from_secs(5)).await;
+        assert!(report.completed);
+        assert!(report.terminated.is_empty());
+        assert!(report.stopped.contains(&"backup"));
+        assert!(report.stopped.contains(&"webhook_queue"));
+        assert_eq!(report.panicked, 0);
+        assert!(shutdown.is_shutting_down());
+    }
+
+    #[tokio::test]
+    async fn test_drain_terminates_tasks_that_ignore_shutdown() {
+        let shutdown = Shutdown::new();
+        shutdown.spawn("stubborn", |_token| async move {
+            tokio::time::sleep(Duration::from_secs(3600)).await;
+        });
+        shutdown.spawn("cooperative", |token| async move {
+            token.cancelled().await;
+        });
+
+        let report = shutdown.drain(Duration::from_millis(100)).await;
+        assert!(!report.completed);
+        assert_eq!(report.stopped, vec!["cooperative"]);
+        assert_eq!(report.terminated, vec!["stubborn"]);
+        assert_eq!(report.panicked, 0);
+    }
+
+    #[tokio::test]
+    async fn test_drain_counts_panics() {
+        let shutdown = Shutdown::new();
+        shutdown.spawn("panics", |token| async move {
+            token.cancelled().await;
+            panic!("boom");
+        });
+
+        let report = shutdown.drain(Duration::from_secs(5)).await;
+        assert!(report.completed);
+        assert_eq!(report.panicked, 1);
+    }
+}
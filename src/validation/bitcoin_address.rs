@@ -0,0 +1,136 @@
+//! Bitcoin address validation
+//!
+//! Addresses supplied with node registrations and holdings proofs are checked
+//! to be well-formed (including their base58 or bech32/bech32m checksum), of a
+//! standard output type, and for the network the server governs.
+
+use bitcoin::address::{Address, NetworkUnchecked};
+use bitcoin::Network;
+use serde::Serialize;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Standard output types an address may encode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AddressType {
+    P2pkh,
+    P2sh,
+    P2wpkh,
+    P2wsh,
+    /// Taproot (bech32m)
+    P2tr,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Not base58check or bech32/bech32m, or the checksum doesn't match
+    #[error("malformed address: {0}")]
+    Malformed(String),
+    #[error("address is not valid for {0}")]
+    WrongNetwork(Network),
+    /// Well-formed but not one of the standard types, e.g. a future witness version
+    #[error("unsupported address type")]
+    Unsupported,
+}
+
+/// Parse a configured network name ("mainnet", "testnet", "signet", "regtest")
+pub fn parse_network(name: &str) -> Option<Network> {
+    match name.trim().to_lowercase().as_str() {
+        "mainnet" | "main" | "bitcoin" => Some(Network::Bitcoin),
+        "testnet" | "test" | "testnet3" => Some(Network::Testnet),
+        "signet" => Some(Network::Signet),
+        "regtest" => Some(Network::Regtest),
+        _ => None,
+    }
+}
+
+/// Check that `addr` is a well-formed standard address for `network`
+pub fn validate_bitcoin_address(
+    addr: &str,
+    network: Network,
+) -> Result<AddressType, ValidationError> {
+    let unchecked = Address::<NetworkUnchecked>::from_str(addr.trim())
+        .map_err(|e| ValidationError::Malformed(e.to_string()))?;
+    let address = unchecked
+        .require_network(network)
+        .map_err(|_| ValidationError::WrongNetwork(network))?;
+
+    match address.address_type() {
+        Some(bitcoin::AddressType::P2pkh) => Ok(AddressType::P2pkh),
+        Some(bitcoin::AddressType::P2sh) => Ok(AddressType::P2sh),
+        Some(bitcoin::AddressType::P2wpkh) => Ok(AddressType::P2wpkh),
+        Some(bitcoin::AddressType::P2wsh) => Ok(AddressType::P2wsh),
+        Some(bitcoin::AddressType::P2tr) => Ok(AddressType::P2tr),
+        _ => Err(ValidationError::Unsupported),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_standard_mainnet_types() {
+        let cases = [
+            ("1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa", AddressType::P2pkh),
+            ("3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy", AddressType::P2sh),
+            (
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+                AddressType::P2wpkh,
+            ),
+            (
+                "bc1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qccfmv3",
+                AddressType::P2wsh,
+            ),
+            (
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                AddressType::P2tr,
+            ),
+        ];
+        for (address, expected) in cases {
+            assert_eq!(
+                validate_bitcoin_address(address, Network::Bitcoin),
+                Ok(expected),
+                "{}",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn test_rejects_bad_checksums() {
+        // Last character changed
+        for address in [
+            "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNb",
+            "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5",
+            "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj1",
+        ] {
+            assert!(matches!(
+                validate_bitcoin_address(address, Network::Bitcoin),
+                Err(ValidationError::Malformed(_))
+            ));
+        }
+        assert!(validate_bitcoin_address("bc1qtest", Network::Bitcoin).is_err());
+    }
+
+    #[test]
+    fn test_rejects_wrong_network() {
+        let testnet = "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx";
+        assert_eq!(
+            validate_bitcoin_address(testnet, Network::Bitcoin),
+            Err(ValidationError::WrongNetwork(Network::Bitcoin))
+        );
+        assert_eq!(
+            validate_bitcoin_address(testnet, Network::Testnet),
+            Ok(AddressType::P2wpkh)
+        );
+    }
+
+    #[test]
+    fn test_parse_network() {
+        assert_eq!(parse_network("mainnet"), Some(Network::Bitcoin));
+        assert_eq!(parse_network("Regtest"), Some(Network::Regtest));
+        assert_eq!(parse_network("litecoin"), None);
+    }
+}
@@ -0,0 +1,412 @@
+//! PGP commit signature verification
+//!
+//! Maintainers can approve a merge by PGP-signing the commit instead of
+//! posting a signed comment. GitHub verifies the signature itself; we check
+//! its verdict, that the issuer fingerprint in the signature's hashed (signed)
+//! subpackets is in the maintainer keyring, and that the key belongs to the
+//! commit's GitHub author.
+
+use crate::config::loader::{MaintainerKey, MaintainerKeysConfig};
+use base64::{engine::general_purpose, Engine as _};
+use serde_json::Value;
+
+/// Outcome of checking a commit's signature against the maintainer keyring
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommitSignatureCheck {
+    /// Signed by an active maintainer key
+    Verified { signer: String, fingerprint: String },
+    /// GitHub did not verify the signature (or the commit is unsigned)
+    Unverified { reason: String },
+    /// Valid signature from a key that isn't in the keyring
+    UnknownKey { key_id: String },
+    /// Valid signature from a key the maintainer has revoked
+    RevokedKey { signer: String, fingerprint: String },
+    /// Maintainer key signing a commit authored by someone else
+    NotAuthor {
+        signer: String,
+        author: Option<String>,
+    },
+}
+
+impl CommitSignatureCheck {
+    /// Whether the signature counts as a maintainer approval
+    pub fn is_valid(&self) -> bool {
+        matches!(self, CommitSignatureCheck::Verified { .. })
+    }
+
+    /// Human-readable status line for status checks
+    pub fn status_message(&self, commit_sha: &str) -> String {
+        let short_sha = &commit_sha[..commit_sha.len().min(7)];
+        match self {
+            CommitSignatureCheck::Verified {
+                signer,
+                fingerprint,
+            } => format!(
+                "✅ Commit {} signed by {} (key {})",
+                short_sha, signer, fingerprint
+            ),
+            CommitSignatureCheck::Unverified { reason } => {
+                format!("❌ Commit {} signature not verified: {}", short_sha, reason)
+            }
+            CommitSignatureCheck::UnknownKey { key_id } => format!(
+                "❌ Commit {} signed with unknown key {} (not in maintainer keyring)",
+                short_sha, key_id
+            ),
+            CommitSignatureCheck::RevokedKey {
+                signer,
+                fingerprint,
+            } => format!(
+                "❌ Commit {} signed with revoked key {} ({})",
+                short_sha, fingerprint, signer
+            ),
+            CommitSignatureCheck::NotAuthor { signer, author } => format!(
+                "❌ Commit {} signed by {} but authored by {}",
+                short_sha,
+                signer,
+                author.as_deref().unwrap_or("an unknown GitHub user")
+            ),
+        }
+    }
+}
+
+/// Checks GitHub commit verification data against the maintainer keyring
+pub struct CommitSignatureVerifier {
+    keyring: Vec<MaintainerKey>,
+}
+
+impl CommitSignatureVerifier {
+    pub fn new(keyring: Vec<MaintainerKey>) -> Self {
+        Self { keyring }
+    }
+
+    pub fn from_config(config: &MaintainerKeysConfig) -> Self {
+        Self::new(config.maintainer_keys.clone())
+    }
+
+    /// Check a commit as returned by `GET /repos/{owner}/{repo}/commits/{sha}`
+    pub fn verify_commit(&self, commit: &Value) -> CommitSignatureCheck {
+        let Some(verification) = commit
+            .pointer("/commit/verification")
+            .or_else(|| commit.get("verification"))
+        else {
+            return CommitSignatureCheck::Unverified {
+                reason: "no verification data".to_string(),
+            };
+        };
+
+        if verification.get("verified").and_then(|v| v.as_bool()) != Some(true) {
+            let reason = verification
+                .get("reason")
+                .and_then(|r| r.as_str())
+                .unwrap_or("unknown");
+            return CommitSignatureCheck::Unverified {
+                reason: reason.to_string(),
+            };
+        }
+
+        let Some(issuer) = verification
+            .get("signature")
+            .and_then(|s| s.as_str())
+            .and_then(parse_issuer_fingerprint)
+        else {
+            return CommitSignatureCheck::Unverified {
+                reason: "signature has no signed issuer fingerprint".to_string(),
+            };
+        };
+
+        let Some(key) = self.find_key(&issuer) else {
+            return CommitSignatureCheck::UnknownKey { key_id: issuer };
+        };
+        if key.revoked {
+            return CommitSignatureCheck::RevokedKey {
+                signer: key.github_username.clone(),
+                fingerprint: normalize_fingerprint(&key.fingerprint),
+            };
+        }
+
+        // A maintainer's signature approves their own commit, not one they re-signed
+        let author = commit
+            .pointer("/author/login")
+            .and_then(|login| login.as_str());
+        if !author.is_some_and(|author| author.eq_ignore_ascii_case(&key.github_username)) {
+            return CommitSignatureCheck::NotAuthor {
+                signer: key.github_username.clone(),
+                author: author.map(str::to_string),
+            };
+        }
+
+        CommitSignatureCheck::Verified {
+            signer: key.github_username.clone(),
+            fingerprint: normalize_fingerprint(&key.fingerprint),
+        }
+    }
+
+    /// Match by full fingerprint only; 64-bit key IDs can be forged
+    fn find_key(&self, fingerprint: &str) -> Option<&MaintainerKey> {
+        self.keyring
+            .iter()
+            .find(|key| normalize_fingerprint(&key.fingerprint) == fingerprint)
+    }
+}
+
+fn normalize_fingerprint(fingerprint: &str) -> String {
+    fingerprint
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_uppercase()
+}
+
+/// Subpacket type carrying the issuer fingerprint (RFC 9580 §5.2.3.35)
+const SUBPACKET_ISSUER_FINGERPRINT: u8 = 33;
+
+/// Extract the issuer fingerprint, as uppercase hex, from an ASCII-armored
+/// OpenPGP signature
+///
+/// Only the hashed subpacket area is read: the unhashed area isn't covered by
+/// the signature, so anyone can put any issuer there.
+fn parse_issuer_fingerprint(armored: &str) -> Option<String> {
+    let packet = dearmor(armored)?;
+    let body = signature_packet_body(&packet)?;
+
+    // v4/v5 layout: version, type, pubkey algo, hash algo, then the hashed subpackets
+    if !matches!(body.first()?, 4 | 5) {
+        return None;
+    }
+    let len = u16::from_be_bytes([*body.get(4)?, *body.get(5)?]) as usize;
+    let hashed = body.get(6..6 + len)?;
+    subpackets(hashed)?
+        .into_iter()
+        .find(|(kind, data)| *kind == SUBPACKET_ISSUER_FINGERPRINT && data.len() > 1)
+        .map(|(_, data)| hex::encode_upper(&data[1..]))
+}
+
+/// Decode the base64 body of an ASCII-armored block
+fn dearmor(armored: &str) -> Option<Vec<u8>> {
+    let mut lines = armored.lines().map(str::trim);
+    lines.find(|line| line.starts_with("-----BEGIN PGP SIGNATURE-----"))?;
+
+    // Skip armor headers (e.g. "Comment:") up to the blank separator line
+    let body: String = lines
+        .skip_while(|line| line.contains(':'))
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.starts_with("-----END"))
+        .filter(|line| !line.starts_with('='))
+        .collect();
+    general_purpose::STANDARD.decode(body).ok()
+}
+
+/// Body of the first packet, which must be a signature packet (tag 2)
+fn signature_packet_body(packet: &[u8]) -> Option<&[u8]> {
+    let header = *packet.first()?;
+    if header & 0x80 == 0 {
+        return None;
+    }
+
+    let (tag, len, offset) = if header & 0x40 != 0 {
+        // New format
+        let first = *packet.get(1)? as usize;
+        let (len, size) = match first {
+            0..=191 => (first, 1),
+            192..=223 => (((first - 192) << 8) + *packet.get(2)? as usize + 192, 2),
+            255 => (
+                u32::from_be_bytes(packet.get(2..6)?.try_into().ok()?) as usize,
+                5,
+            ),
+            _ => return None, // Partial body lengths aren't used for signatures
+        };
+        (header & 0x3f, len, 1 + size)
+    } else {
+        // Old format
+        let (len, size) = match header & 0x03 {
+            0 => (*packet.get(1)? as usize, 1),
+            1 => (
+                u16::from_be_bytes([*packet.get(1)?, *packet.get(2)?]) as usize,
+                2,
+            ),
+            2 => (
+                u32::from_be_bytes(packet.get(1..5)?.try_into().ok()?) as usize,
+                4,
+            ),
+            _ => (packet.len().saturating_sub(1), 0),
+        };
+        ((header >> 2) & 0x0f, len, 1 + size)
+    };
+
+    if tag != 2 {
+        return None;
+    }
+    packet.get(offset..offset + len)
+}
+
+/// Split a subpacket area into (type, data) pairs
+fn subpackets(mut area: &[u8]) -> Option<Vec<(u8, &[u8])>> {
+    let mut packets = Vec::new();
+    while !area.is_empty() {
+        let first = area[0] as usize;
+        let (len, size) = match first {
+            0..=191 => (first, 1),
+            192..=254 => (((first - 192) << 8) + *area.get(1)? as usize + 192, 2),
+            _ => (
+                u32::from_be_bytes(area.get(1..5)?.try_into().ok()?) as usize,
+                5,
+            ),
+        };
+        let packet = area.get(size..size + len)?;
+        let (kind, data) = packet.split_first()?;
+        // The high bit marks the subpacket as critical
+        packets.push((kind & 0x7f, data));
+        area = &area[size + len..];
+    }
+    Some(packets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const ALICE_FPR: &str = "0123 4567 89AB CDEF 0123  4567 89AB CDEF 0123 4567";
+    const BOB_FPR: &str = "FEDCBA9876543210FEDCBA9876543210FEDCBA98";
+
+    /// Armor a minimal v4 signature packet whose hashed area holds the issuer fingerprint
+    fn armored_signature(fingerprint_hex: &str) -> String {
+        let fingerprint = hex::decode(normalize_fingerprint(fingerprint_hex)).unwrap();
+        let mut hashed = vec![22, SUBPACKET_ISSUER_FINGERPRINT, 4];
+        hashed.extend_from_slice(&fingerprint);
+
+        let mut body = vec![4, 0x00, 1, 8];
+        body.extend_from_slice(&(hashed.len() as u16).to_be_bytes());
+        body.extend_from_slice(&hashed);
+        body.extend_from_slice(&0u16.to_be_bytes());
+        body.extend_from_slice(&[0xab, 0xcd]);
+
+        let mut packet = vec![0xc2, body.len() as u8];
+        packet.extend_from_slice(&body);
+
+        format!(
+            "-----BEGIN PGP SIGNATURE-----\n\n{}\n=abcd\n-----END PGP SIGNATURE-----\n",
+            general_purpose::STANDARD.encode(packet)
+        )
+    }
+
+    fn commit(verified: bool, reason: &str, signature: Option<String>) -> Value {
+        json!({
+            "sha": "1234567890abcdef1234567890abcdef12345678",
+            "author": {"login": "alice"},
+            "commit": {
+                "message": "Merge pull request #1",
+                "verification": {
+                    "verified": verified,
+                    "reason": reason,
+                    "signature": signature,
+                    "payload": "tree ...",
+                }
+            }
+        })
+    }
+
+    fn verifier() -> CommitSignatureVerifier {
+        CommitSignatureVerifier::new(vec![
+            MaintainerKey {
+                github_username: "alice".to_string(),
+                fingerprint: ALICE_FPR.to_string(),
+                revoked: false,
+            },
+            MaintainerKey {
+                github_username: "bob".to_string(),
+                fingerprint: BOB_FPR.to_string(),
+                revoked: true,
+            },
+        ])
+    }
+
+    #[test]
+    fn test_verified_maintainer_commit() {
+        let check =
+            verifier().verify_commit(&commit(true, "valid", Some(armored_signature(ALICE_FPR))));
+        assert!(check.is_valid());
+        assert_eq!(
+            check,
+            CommitSignatureCheck::Verified {
+                signer: "alice".to_string(),
+                fingerprint: normalize_fingerprint(ALICE_FPR),
+            }
+        );
+    }
+
+    #[test]
+    fn test_unverified_commit() {
+        let check = verifier().verify_commit(&commit(false, "unsigned", None));
+        assert!(!check.is_valid());
+        assert!(check.status_message("1234567890").contains("unsigned"));
+    }
+
+    #[test]
+    fn test_unknown_and_revoked_keys_rejected() {
+        let unknown = "AAAABBBBCCCCDDDDEEEEFFFF0000111122223333";
+        let check =
+            verifier().verify_commit(&commit(true, "valid", Some(armored_signature(unknown))));
+        assert_eq!(
+            check,
+            CommitSignatureCheck::UnknownKey {
+                key_id: unknown.to_string()
+            }
+        );
+        assert!(check
+            .status_message("1234567890")
+            .contains("not in maintainer keyring"));
+
+        let check =
+            verifier().verify_commit(&commit(true, "valid", Some(armored_signature(BOB_FPR))));
+        assert!(!check.is_valid());
+        assert!(check.status_message("1234567890").contains("revoked key"));
+    }
+
+    #[test]
+    fn test_unhashed_or_key_id_issuer_rejected() {
+        // The issuer fingerprint in the unhashed area isn't signed
+        let mut fingerprint = vec![22, SUBPACKET_ISSUER_FINGERPRINT, 4];
+        fingerprint.extend_from_slice(&hex::decode(normalize_fingerprint(ALICE_FPR)).unwrap());
+        // A 64-bit issuer key ID (subpacket 16) matching alice's key
+        let mut key_id = vec![9, 16];
+        key_id.extend_from_slice(&hex::decode("89ABCDEF01234567").unwrap());
+
+        for (hashed, unhashed) in [(vec![], fingerprint), (key_id, vec![])] {
+            let mut body = vec![4, 0x00, 1, 8];
+            body.extend_from_slice(&(hashed.len() as u16).to_be_bytes());
+            body.extend_from_slice(&hashed);
+            body.extend_from_slice(&(unhashed.len() as u16).to_be_bytes());
+            body.extend_from_slice(&unhashed);
+            // Old-format header with a one-byte length
+            let mut packet = vec![0x88, body.len() as u8];
+            packet.extend_from_slice(&body);
+            let armored = format!(
+                "-----BEGIN PGP SIGNATURE-----\nComment: test\n\n{}\n-----END PGP SIGNATURE-----",
+                general_purpose::STANDARD.encode(packet)
+            );
+
+            let check = verifier().verify_commit(&commit(true, "valid", Some(armored)));
+            assert!(!check.is_valid());
+        }
+    }
+
+    #[test]
+    fn test_maintainer_key_on_other_authors_commit_rejected() {
+        let mut other = commit(true, "valid", Some(armored_signature(ALICE_FPR)));
+        other["author"] = json!({"login": "mallory"});
+        let check = verifier().verify_commit(&other);
+        assert_eq!(
+            check,
+            CommitSignatureCheck::NotAuthor {
+                signer: "alice".to_string(),
+                author: Some("mallory".to_string()),
+            }
+        );
+        assert!(check.status_message("1234567890").contains("mallory"));
+
+        // No linked GitHub author
+        other["author"] = Value::Null;
+        assert!(!verifier().verify_commit(&other).is_valid());
+    }
+}
@@ -1,9 +1,13 @@
+pub mod bitcoin_address;
+pub mod commit_signatures;
 pub mod content_hash;
 pub mod cross_layer;
 pub mod diff_parser;
 pub mod emergency;
 pub mod equivalence_proof;
 pub mod nested_multisig;
+pub mod pr_title;
+pub mod review_overlap;
 pub mod review_period;
 pub mod security_controls;
 pub mod signatures;
@@ -13,6 +17,7 @@ pub mod tier_classification;
 pub mod verification_check;
 pub mod version_pinning;
 
+pub use commit_signatures::{CommitSignatureCheck, CommitSignatureVerifier};
 pub use nested_multisig::{
     NestedMultisigResult, NestedMultisigVerifier, Team, TeamApprovalStatus, TeamMaintainer,
 };
@@ -0,0 +1,253 @@
+//! Governance PR title format
+//!
+//! Governance PRs are titled `[Tier N] Short description` or
+//! `[Emergency] Description`. The tier prefix lets reviewers see the
+//! signature and review-period requirements at a glance; emergency titles skip
+//! the tier and length rules but must still explain themselves. The rules can
+//! be overridden by a YAML file (see [`PrTitleRules::load`]).
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use thiserror::Error;
+
+use crate::error::GovernanceError;
+
+/// Status check context posted for title validation
+pub const TITLE_FORMAT_CONTEXT: &str = "governance/title-format";
+
+/// Parsed governance PR title
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PrTitleInfo {
+    /// Declared tier; None for emergency PRs
+    pub tier: Option<u8>,
+    pub is_emergency: bool,
+    pub description: String,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TitleValidationError {
+    #[error("title must start with a tier or emergency prefix; expected {expected}")]
+    MissingPrefix { expected: String },
+    #[error("tier {tier} is not allowed; expected {expected}")]
+    UnknownTier { tier: String, expected: String },
+    #[error("description is {length} characters, the maximum is {max}; expected {expected}")]
+    DescriptionTooLong {
+        length: usize,
+        max: usize,
+        expected: String,
+    },
+    #[error(
+        "emergency description is {length} characters, the minimum is {min}; expected {expected}"
+    )]
+    EmergencyDescriptionTooShort {
+        length: usize,
+        min: usize,
+        expected: String,
+    },
+    #[error("description is empty; expected {expected}")]
+    EmptyDescription { expected: String },
+}
+
+/// Title rules, loadable from YAML
+///
+/// ```yaml
+/// tier_prefix: "Tier"
+/// emergency_prefix: "Emergency"
+/// allowed_tiers: [1, 2, 3, 4, 5]
+/// max_description_length: 72
+/// min_emergency_description_length: 20
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PrTitleRules {
+    /// Word inside the brackets before the tier number
+    pub tier_prefix: String,
+    /// Word inside the brackets marking an emergency PR
+    pub emergency_prefix: String,
+    pub allowed_tiers: Vec<u8>,
+    pub max_description_length: usize,
+    pub min_emergency_description_length: usize,
+}
+
+impl Default for PrTitleRules {
+    fn default() -> Self {
+        Self {
+            tier_prefix: "Tier".to_string(),
+            emergency_prefix: "Emergency".to_string(),
+            allowed_tiers: vec![1, 2, 3, 4, 5],
+            max_description_length: 72,
+            min_emergency_description_length: 20,
+        }
+    }
+}
+
+impl PrTitleRules {
+    /// Load rules from a YAML file; missing keys take their defaults
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, GovernanceError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            GovernanceError::ConfigError(format!("Failed to read {:?}: {}", path, e))
+        })?;
+        serde_yaml::from_str(&content)
+            .map_err(|e| GovernanceError::ConfigError(format!("Failed to parse {:?}: {}", path, e)))
+    }
+
+    /// Human-readable description of the accepted formats
+    pub fn expected_format(&self) -> String {
+        format!(
+            "\"[{} N] Short description (max {} chars)\" or \"[{}] Description (min {} chars)\"",
+            self.tier_prefix,
+            self.max_description_length,
+            self.emergency_prefix,
+            self.min_emergency_description_length
+        )
+    }
+
+    pub fn validate(&self, title: &str) -> Result<PrTitleInfo, TitleValidationError> {
+        let expected = self.expected_format();
+        let (tag, description) = title
+            .trim()
+            .strip_prefix('[')
+            .and_then(|rest| rest.split_once(']'))
+            .map(|(tag, description)| (tag.trim(), description.trim()))
+            .ok_or_else(|| TitleValidationError::MissingPrefix {
+                expected: expected.clone(),
+            })?;
+        let length = description.chars().count();
+
+        if tag.eq_ignore_ascii_case(&self.emergency_prefix) {
+            if length < self.min_emergency_description_length {
+                return Err(TitleValidationError::EmergencyDescriptionTooShort {
+                    length,
+                    min: self.min_emergency_description_length,
+                    expected,
+                });
+            }
+            return Ok(PrTitleInfo {
+                tier: None,
+                is_emergency: true,
+                description: description.to_string(),
+            });
+        }
+
+        let tier = tag
+            .split_once(char::is_whitespace)
+            .filter(|(prefix, _)| prefix.eq_ignore_ascii_case(&self.tier_prefix))
+            .map(|(_, tier)| tier.trim())
+            .ok_or_else(|| TitleValidationError::MissingPrefix {
+                expected: expected.clone(),
+            })?;
+        let tier = tier
+            .parse::<u8>()
+            .ok()
+            .filter(|tier| self.allowed_tiers.contains(tier))
+            .ok_or_else(|| TitleValidationError::UnknownTier {
+                tier: tier.to_string(),
+                expected: expected.clone(),
+            })?;
+
+        if length == 0 {
+            return Err(TitleValidationError::EmptyDescription { expected });
+        }
+        if length > self.max_description_length {
+            return Err(TitleValidationError::DescriptionTooLong {
+                length,
+                max: self.max_description_length,
+                expected,
+            });
+        }
+        Ok(PrTitleInfo {
+            tier: Some(tier),
+            is_emergency: false,
+            description: description.to_string(),
+        })
+    }
+}
+
+/// Validate a governance PR title against the default rules
+pub fn validate_governance_pr_title(title: &str) -> Result<PrTitleInfo, TitleValidationError> {
+    PrTitleRules::default().validate(title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_tier_titles() {
+        let info =
+            validate_governance_pr_title("[Tier 3] Raise review period for layer 4").unwrap();
+        assert_eq!(info.tier, Some(3));
+        assert!(!info.is_emergency);
+        assert_eq!(info.description, "Raise review period for layer 4");
+
+        assert_eq!(
+            validate_governance_pr_title("[tier 1]  Fix typo")
+                .unwrap()
+                .tier,
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_conforming_titles() {
+        assert!(matches!(
+            validate_governance_pr_title("Raise review period"),
+            Err(TitleValidationError::MissingPrefix { .. })
+        ));
+        assert!(matches!(
+            validate_governance_pr_title("[Feature] Raise review period"),
+            Err(TitleValidationError::MissingPrefix { .. })
+        ));
+        assert!(matches!(
+            validate_governance_pr_title("[Tier 9] Raise review period"),
+            Err(TitleValidationError::UnknownTier { .. })
+        ));
+        assert!(matches!(
+            validate_governance_pr_title("[Tier 2]"),
+            Err(TitleValidationError::EmptyDescription { .. })
+        ));
+        let long = format!("[Tier 2] {}", "x".repeat(73));
+        assert!(matches!(
+            validate_governance_pr_title(&long),
+            Err(TitleValidationError::DescriptionTooLong {
+                length: 73,
+                max: 72,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_emergency_titles() {
+        let long = format!("[Emergency] {}", "Patch consensus bug ".repeat(5));
+        let info = validate_governance_pr_title(&long).unwrap();
+        assert!(info.is_emergency);
+        assert_eq!(info.tier, None);
+
+        let err = validate_governance_pr_title("[Emergency] Fix it").unwrap_err();
+        assert!(matches!(
+            err,
+            TitleValidationError::EmergencyDescriptionTooShort {
+                length: 6,
+                min: 20,
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("[Tier N] Short description"));
+    }
+
+    #[test]
+    fn test_load_rules_from_yaml() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("pr-title-rules.yml");
+        std::fs::write(&path, "allowed_tiers: [1, 2]\nmax_description_length: 10\n").unwrap();
+
+        let rules = PrTitleRules::load(&path).unwrap();
+        assert_eq!(rules.emergency_prefix, "Emergency");
+        assert!(rules.validate("[Tier 2] Short one").is_ok());
+        assert!(rules.validate("[Tier 3] Short one").is_err());
+        assert!(rules.validate("[Tier 1] Much too long").is_err());
+        assert!(PrTitleRules::load(dir.path().join("missing.yml")).is_err());
+    }
+}
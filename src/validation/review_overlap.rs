@@ -0,0 +1,287 @@
+//! Review period overlap detection
+//!
+//! Two Tier 3+ PRs in the same layer under review at the same time split the
+//! maintainers' attention, and neither may get a thorough review. Governance
+//! PRs are tracked in `governance_prs` from pull request webhooks; a PR's
+//! review period is the combined layer/tier period from
+//! [`ThresholdValidator::get_combined_requirements`], starting when it opened.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+use crate::error::GovernanceError;
+use crate::validation::threshold::ThresholdValidator;
+
+/// Lowest tier whose concurrent reviews are flagged
+pub const MIN_OVERLAP_TIER: u32 = 3;
+
+/// Tracked governance PR
+#[derive(Debug, Clone, PartialEq, Serialize, sqlx::FromRow)]
+pub struct GovernancePr {
+    pub id: i64,
+    pub repo_name: String,
+    pub pr_number: i64,
+    pub layer: i64,
+    pub tier: i64,
+    pub opened_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+impl GovernancePr {
+    /// End of the review period
+    pub fn review_ends_at(&self) -> DateTime<Utc> {
+        review_ends_at(self.layer as i32, self.tier as u32, self.opened_at)
+    }
+}
+
+/// Another open PR whose review period overlaps
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OverlapWarning {
+    pub repo_name: String,
+    pub pr_number: i64,
+    pub tier: i64,
+    pub layer: i64,
+    pub opened_at: DateTime<Utc>,
+    pub review_ends_at: DateTime<Utc>,
+}
+
+impl OverlapWarning {
+    fn from_pr(pr: GovernancePr) -> Self {
+        Self {
+            review_ends_at: pr.review_ends_at(),
+            repo_name: pr.repo_name,
+            pr_number: pr.pr_number,
+            tier: pr.tier,
+            layer: pr.layer,
+            opened_at: pr.opened_at,
+        }
+    }
+}
+
+fn review_ends_at(layer: i32, tier: u32, opened_at: DateTime<Utc>) -> DateTime<Utc> {
+    let days = ThresholdValidator::get_combined_requirements(layer, tier).2;
+    opened_at + Duration::days(days)
+}
+
+/// Record an opened (or reopened, or reclassified) PR; returns its row ID
+pub async fn record_pr_opened(
+    db_pool: &SqlitePool,
+    repo_name: &str,
+    pr_number: i32,
+    layer: i32,
+    tier: u32,
+    opened_at: DateTime<Utc>,
+) -> Result<i32, GovernanceError> {
+    let id: i32 = sqlx::query_scalar(
+        r#"
+        INSERT INTO governance_prs (repo_name, pr_number, layer, tier, opened_at)
+        VALUES (?, ?, ?, ?, ?)
+        ON CONFLICT(repo_name, pr_number) DO UPDATE SET
+            layer = excluded.layer,
+            tier = excluded.tier,
+            closed_at = NULL,
+            updated_at = CURRENT_TIMESTAMP
+        RETURNING id
+        "#,
+    )
+    .bind(repo_name)
+    .bind(pr_number)
+    .bind(layer)
+    .bind(tier)
+    .bind(opened_at)
+    .fetch_one(db_pool)
+    .await?;
+    Ok(id)
+}
+
+/// Mark a PR closed (merged or not)
+pub async fn record_pr_closed(
+    db_pool: &SqlitePool,
+    repo_name: &str,
+    pr_number: i32,
+    closed_at: DateTime<Utc>,
+) -> Result<(), GovernanceError> {
+    sqlx::query(
+        "UPDATE governance_prs SET closed_at = ?, updated_at = CURRENT_TIMESTAMP WHERE repo_name = ? AND pr_number = ?",
+    )
+    .bind(closed_at)
+    .bind(repo_name)
+    .bind(pr_number)
+    .execute(db_pool)
+    .await?;
+    Ok(())
+}
+
+/// Open Tier 3+ PRs
+pub async fn list_open_reviews(db_pool: &SqlitePool) -> Result<Vec<GovernancePr>, GovernanceError> {
+    let prs = sqlx::query_as::<_, GovernancePr>(
+        r#"
+        SELECT id, repo_name, pr_number, layer, tier, opened_at, closed_at
+        FROM governance_prs
+        WHERE closed_at IS NULL AND tier >= ?
+        ORDER BY opened_at, id
+        "#,
+    )
+    .bind(MIN_OVERLAP_TIER)
+    .fetch_all(db_pool)
+    .await?;
+    Ok(prs)
+}
+
+/// Other open Tier 3+ PRs in the same layer whose review period overlaps
+/// that of `pr_id`, a `governance_prs` row opened at `opened_at`
+///
+/// PRs below Tier 3 never produce warnings.
+pub async fn detect_concurrent_reviews(
+    tier: u32,
+    pr_id: i32,
+    opened_at: DateTime<Utc>,
+    db_pool: &SqlitePool,
+) -> Result<Vec<OverlapWarning>, GovernanceError> {
+    if tier < MIN_OVERLAP_TIER {
+        return Ok(Vec::new());
+    }
+    let layer: i32 = sqlx::query_scalar("SELECT layer FROM governance_prs WHERE id = ?")
+        .bind(pr_id)
+        .fetch_optional(db_pool)
+        .await?
+        .ok_or_else(|| {
+            GovernanceError::ValidationError(format!("Governance PR {} not found", pr_id))
+        })?;
+    let ends_at = review_ends_at(layer, tier, opened_at);
+
+    Ok(list_open_reviews(db_pool)
+        .await?
+        .into_iter()
+        .filter(|other| {
+            other.id != pr_id as i64
+                && other.layer == layer as i64
+                && other.opened_at < ends_at
+                && opened_at < other.review_ends_at()
+        })
+        .map(OverlapWarning::from_pr)
+        .collect())
+}
+
+/// Open Tier 3+ PR and the reviews it overlaps
+#[derive(Debug, Clone, Serialize)]
+pub struct ConcurrentReview {
+    #[serde(flatten)]
+    pub pr: GovernancePr,
+    pub review_ends_at: DateTime<Utc>,
+    pub overlaps: Vec<OverlapWarning>,
+}
+
+/// Every open Tier 3+ PR whose review overlaps another in its layer
+pub async fn find_concurrent_reviews(
+    db_pool: &SqlitePool,
+) -> Result<Vec<ConcurrentReview>, GovernanceError> {
+    let open = list_open_reviews(db_pool).await?;
+    Ok(open
+        .iter()
+        .filter_map(|pr| {
+            let overlaps: Vec<OverlapWarning> = open
+                .iter()
+                .filter(|other| {
+                    other.id != pr.id
+                        && other.layer == pr.layer
+                        && other.opened_at < pr.review_ends_at()
+                        && pr.opened_at < other.review_ends_at()
+                })
+                .cloned()
+                .map(OverlapWarning::from_pr)
+                .collect();
+            (!overlaps.is_empty()).then(|| ConcurrentReview {
+                review_ends_at: pr.review_ends_at(),
+                pr: pr.clone(),
+                overlaps,
+            })
+        })
+        .collect())
+}
+
+/// PR comment describing the overlapping reviews
+pub fn overlap_comment(tier: u32, warnings: &[OverlapWarning]) -> String {
+    let mut comment = format!(
+        "⚠️ **Concurrent review warning**\n\nThis Tier {} PR's review period overlaps with other open Tier {}+ PRs in the same layer:\n\n",
+        tier, MIN_OVERLAP_TIER
+    );
+    for warning in warnings {
+        comment.push_str(&format!(
+            "- {}#{} (Tier {}, review period ends {})\n",
+            warning.repo_name,
+            warning.pr_number,
+            warning.tier,
+            warning.review_ends_at.format("%Y-%m-%d")
+        ));
+    }
+    comment.push_str(
+        "\nMaintainers' review attention may be split; consider sequencing these changes.",
+    );
+    comment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+
+    async fn pool() -> SqlitePool {
+        Database::new_in_memory()
+            .await
+            .unwrap()
+            .get_sqlite_pool()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_detects_same_layer_tier3_overlap() {
+        let pool = pool().await;
+        let now = Utc::now();
+        let repo = "BTCDecoded/blvm-consensus";
+        record_pr_opened(&pool, repo, 1, 2, 3, now - Duration::days(10))
+            .await
+            .unwrap();
+        // Lower tier, other layer, and closed PRs don't count
+        record_pr_opened(&pool, repo, 2, 2, 2, now).await.unwrap();
+        record_pr_opened(&pool, "BTCDecoded/blvm-node", 3, 4, 5, now)
+            .await
+            .unwrap();
+        record_pr_opened(&pool, repo, 4, 2, 5, now).await.unwrap();
+        record_pr_closed(&pool, repo, 4, now).await.unwrap();
+
+        let id = record_pr_opened(&pool, repo, 5, 2, 5, now).await.unwrap();
+        let warnings = detect_concurrent_reviews(5, id, now, &pool).await.unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].pr_number, 1);
+        assert!(overlap_comment(5, &warnings).contains("BTCDecoded/blvm-consensus#1"));
+
+        // Tier 2 PRs are not checked
+        let id = record_pr_opened(&pool, repo, 2, 2, 2, now).await.unwrap();
+        assert!(detect_concurrent_reviews(2, id, now, &pool)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let concurrent = find_concurrent_reviews(&pool).await.unwrap();
+        let numbers: Vec<i64> = concurrent.iter().map(|r| r.pr.pr_number).collect();
+        assert_eq!(numbers, vec![1, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_expired_review_period_does_not_overlap() {
+        let pool = pool().await;
+        let now = Utc::now();
+        let repo = "BTCDecoded/blvm-node";
+        // Layer 4 Tier 3: 90-day review period, long over
+        record_pr_opened(&pool, repo, 1, 4, 3, now - Duration::days(200))
+            .await
+            .unwrap();
+        let id = record_pr_opened(&pool, repo, 2, 4, 3, now).await.unwrap();
+        assert!(detect_concurrent_reviews(3, id, now, &pool)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -1,9 +1,209 @@
+//! Review period validation
+//!
+//! A PR's review period counts from when it opened. Its timeline of pause,
+//! resume and reset events (see [`ReviewTimeline`]) stops the clock while
+//! changes are requested, and restarts it after a major revision when that is
+//! enabled; without events the period is plain calendar time.
+
 use crate::error::GovernanceError;
 use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::BTreeSet;
+
+/// Reason recorded for a clock reset after a major revision
+pub const MAJOR_REVISION_REASON: &str = "major_revision";
+
+/// Smallest diff size change (lines) that counts as a major revision
+pub const MAJOR_REVISION_MIN_LINES: i64 = 100;
+
+/// Whether a push changing the diff from `previous_lines` to `current_lines`
+/// (additions + deletions) is a major revision: at least
+/// [`MAJOR_REVISION_MIN_LINES`] and at least half the previous diff
+pub fn is_major_revision(previous_lines: i64, current_lines: i64) -> bool {
+    let change = (current_lines - previous_lines).abs();
+    change >= MAJOR_REVISION_MIN_LINES && change * 2 >= previous_lines
+}
+
+/// Kind of review timeline event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineEventKind {
+    /// Stop the clock until the same reason is resumed
+    Pause,
+    /// Lift the pause with the same reason
+    Resume,
+    /// Discard the time accumulated so far
+    Reset,
+}
+
+impl TimelineEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimelineEventKind::Pause => "pause",
+            TimelineEventKind::Resume => "resume",
+            TimelineEventKind::Reset => "reset",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pause" => Some(TimelineEventKind::Pause),
+            "resume" => Some(TimelineEventKind::Resume),
+            "reset" => Some(TimelineEventKind::Reset),
+            _ => None,
+        }
+    }
+}
+
+/// Event on a PR's review timeline
+///
+/// Pauses are keyed by `reason` (e.g. `changes_requested:alice`), so the clock
+/// stays stopped until every outstanding pause has been resumed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimelineEvent {
+    pub kind: TimelineEventKind,
+    pub reason: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Interval of a review timeline during which the clock ran or was paused
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReviewSegment {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub paused: bool,
+}
+
+/// A PR's review timeline: when it opened and what happened since
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewTimeline {
+    pub opened_at: DateTime<Utc>,
+    pub events: Vec<TimelineEvent>,
+}
+
+impl ReviewTimeline {
+    /// Timeline without events, i.e. plain calendar time
+    pub fn new(opened_at: DateTime<Utc>) -> Self {
+        Self {
+            opened_at,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn with_events(opened_at: DateTime<Utc>, mut events: Vec<TimelineEvent>) -> Self {
+        events.sort_by_key(|e| e.occurred_at);
+        Self { opened_at, events }
+    }
+
+    /// Replay the events up to `now`: the segments since the last reset, and
+    /// the pauses still outstanding
+    fn replay(&self, now: DateTime<Utc>) -> (Vec<ReviewSegment>, BTreeSet<&str>) {
+        let mut segments: Vec<ReviewSegment> = Vec::new();
+        let mut pauses = BTreeSet::new();
+        let mut cursor = self.opened_at;
+
+        // Adjacent intervals in the same state are merged
+        fn close(
+            segments: &mut Vec<ReviewSegment>,
+            start: DateTime<Utc>,
+            end: DateTime<Utc>,
+            paused: bool,
+        ) {
+            if end <= start {
+                return;
+            }
+            match segments.last_mut() {
+                Some(last) if last.paused == paused && last.end == start => last.end = end,
+                _ => segments.push(ReviewSegment { start, end, paused }),
+            }
+        }
+
+        for event in self.events.iter().filter(|e| e.occurred_at <= now) {
+            let at = event.occurred_at.max(cursor);
+            close(&mut segments, cursor, at, !pauses.is_empty());
+            cursor = at;
+            match event.kind {
+                TimelineEventKind::Pause => {
+                    pauses.insert(event.reason.as_str());
+                }
+                TimelineEventKind::Resume => {
+                    pauses.remove(event.reason.as_str());
+                }
+                TimelineEventKind::Reset => segments.clear(),
+            }
+        }
+        close(&mut segments, cursor, now, !pauses.is_empty());
+        (segments, pauses)
+    }
+
+    /// Running and paused intervals since the last reset, up to `now`
+    pub fn segments(&self, now: DateTime<Utc>) -> Vec<ReviewSegment> {
+        self.replay(now).0
+    }
+
+    /// Reasons the clock is paused at `now`; empty while it runs
+    pub fn active_pauses(&self, now: DateTime<Utc>) -> Vec<String> {
+        self.replay(now).1.into_iter().map(String::from).collect()
+    }
+
+    pub fn is_paused(&self, now: DateTime<Utc>) -> bool {
+        !self.replay(now).1.is_empty()
+    }
+
+    /// Unpaused time accumulated since the last reset
+    pub fn accumulated(&self, now: DateTime<Utc>) -> Duration {
+        self.segments(now)
+            .iter()
+            .filter(|s| !s.paused)
+            .fold(Duration::zero(), |total, s| total + (s.end - s.start))
+    }
+
+    /// Whole unpaused days still required at `now`
+    pub fn remaining_days(&self, required: Duration, now: DateTime<Utc>) -> i64 {
+        (required - self.accumulated(now)).num_days().max(0)
+    }
+
+    /// When `required` unpaused time is (or, if the clock keeps running, will
+    /// be) reached; None while paused short of it, since the date depends on
+    /// when the pause ends
+    pub fn earliest_merge_date(
+        &self,
+        required: Duration,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let (segments, pauses) = self.replay(now);
+        let outcome =
+            segments
+                .iter()
+                .filter(|s| !s.paused)
+                .try_fold(required, |needed, segment| {
+                    let length = segment.end - segment.start;
+                    if length >= needed {
+                        Err(segment.start + needed)
+                    } else {
+                        Ok(needed - length)
+                    }
+                });
+        match outcome {
+            Err(reached_at) => Some(reached_at),
+            Ok(needed) => pauses.is_empty().then_some(now + needed),
+        }
+    }
+}
 
 pub struct ReviewPeriodValidator;
 
 impl ReviewPeriodValidator {
+    /// Review period, reduced to 30 days in emergency mode
+    fn required_duration(required_days: i64, emergency_mode: bool) -> Duration {
+        if emergency_mode {
+            Duration::try_days(30).unwrap_or_default()
+        } else {
+            Duration::try_days(required_days).unwrap_or_default()
+        }
+    }
+
     pub fn validate_review_period(
         opened_at: DateTime<Utc>,
         required_days: i64,
@@ -13,11 +213,7 @@ impl ReviewPeriodValidator {
         let elapsed = now - opened_at;
 
         // Emergency mode reduces review period to 30 days
-        let required_duration = if emergency_mode {
-            Duration::try_days(30).unwrap_or_default()
-        } else {
-            Duration::try_days(required_days).unwrap_or_default()
-        };
+        let required_duration = Self::required_duration(required_days, emergency_mode);
 
         if elapsed >= required_duration {
             Ok(true)
@@ -37,13 +233,7 @@ impl ReviewPeriodValidator {
         required_days: i64,
         emergency_mode: bool,
     ) -> DateTime<Utc> {
-        let required_duration = if emergency_mode {
-            Duration::try_days(30).unwrap_or_default()
-        } else {
-            Duration::try_days(required_days).unwrap_or_default()
-        };
-
-        opened_at + required_duration
+        opened_at + Self::required_duration(required_days, emergency_mode)
     }
 
     pub fn get_remaining_days(
@@ -54,13 +244,342 @@ impl ReviewPeriodValidator {
         let now = Utc::now();
         let elapsed = now - opened_at;
 
-        let required_duration = if emergency_mode {
-            Duration::try_days(30).unwrap_or_default()
-        } else {
-            Duration::try_days(required_days).unwrap_or_default()
-        };
+        let required_duration = Self::required_duration(required_days, emergency_mode);
 
         let remaining = required_duration - elapsed;
         remaining.num_days().max(0)
     }
+
+    /// Like [`Self::validate_review_period`], counting only unpaused time
+    pub fn validate_review_period_with_timeline(
+        timeline: &ReviewTimeline,
+        required_days: i64,
+        emergency_mode: bool,
+    ) -> Result<bool, GovernanceError> {
+        let now = Utc::now();
+        let accumulated = timeline.accumulated(now);
+        let required_duration = Self::required_duration(required_days, emergency_mode);
+
+        if accumulated >= required_duration {
+            Ok(true)
+        } else {
+            let paused = if timeline.is_paused(now) {
+                " (paused)"
+            } else {
+                ""
+            };
+            Err(GovernanceError::ReviewPeriodError(format!(
+                "Review period not met. Required: {} unpaused days, Accumulated: {} days, Remaining: {} days{}",
+                required_days,
+                accumulated.num_days(),
+                (required_duration - accumulated).num_days(),
+                paused
+            )))
+        }
+    }
+
+    /// Like [`Self::get_earliest_merge_date`], counting only unpaused time;
+    /// None while the clock is paused
+    pub fn get_earliest_merge_date_with_timeline(
+        timeline: &ReviewTimeline,
+        required_days: i64,
+        emergency_mode: bool,
+    ) -> Option<DateTime<Utc>> {
+        timeline.earliest_merge_date(
+            Self::required_duration(required_days, emergency_mode),
+            Utc::now(),
+        )
+    }
+
+    /// Like [`Self::get_remaining_days`], counting only unpaused time
+    pub fn get_remaining_days_with_timeline(
+        timeline: &ReviewTimeline,
+        required_days: i64,
+        emergency_mode: bool,
+    ) -> i64 {
+        timeline.remaining_days(
+            Self::required_duration(required_days, emergency_mode),
+            Utc::now(),
+        )
+    }
+}
+
+/// Record an event on a PR's review timeline
+pub async fn record_timeline_event(
+    db_pool: &SqlitePool,
+    repo_name: &str,
+    pr_number: i32,
+    kind: TimelineEventKind,
+    reason: &str,
+    occurred_at: DateTime<Utc>,
+) -> Result<(), GovernanceError> {
+    sqlx::query(
+        r#"
+        INSERT INTO pr_timeline_events (repo_name, pr_number, event_type, reason, occurred_at)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(repo_name)
+    .bind(pr_number)
+    .bind(kind.as_str())
+    .bind(reason)
+    .bind(occurred_at)
+    .execute(db_pool)
+    .await?;
+    Ok(())
+}
+
+/// A PR's review timeline from its recorded events
+pub async fn load_timeline(
+    db_pool: &SqlitePool,
+    repo_name: &str,
+    pr_number: i32,
+    opened_at: DateTime<Utc>,
+) -> Result<ReviewTimeline, GovernanceError> {
+    let rows: Vec<(String, String, DateTime<Utc>)> = sqlx::query_as(
+        r#"
+        SELECT event_type, reason, occurred_at
+        FROM pr_timeline_events
+        WHERE repo_name = ? AND pr_number = ?
+        ORDER BY occurred_at, id
+        "#,
+    )
+    .bind(repo_name)
+    .bind(pr_number)
+    .fetch_all(db_pool)
+    .await?;
+
+    let events = rows
+        .into_iter()
+        .filter_map(|(kind, reason, occurred_at)| {
+            Some(TimelineEvent {
+                kind: TimelineEventKind::parse(&kind)?,
+                reason,
+                occurred_at,
+            })
+        })
+        .collect();
+    Ok(ReviewTimeline::with_events(opened_at, events))
+}
+
+/// Store a tracked governance PR's current diff size; returns the previous
+/// size, if one was recorded
+pub async fn record_diff_size(
+    db_pool: &SqlitePool,
+    repo_name: &str,
+    pr_number: i32,
+    diff_lines: i64,
+) -> Result<Option<i64>, GovernanceError> {
+    let previous: Option<Option<i64>> = sqlx::query_scalar(
+        "SELECT diff_lines FROM governance_prs WHERE repo_name = ? AND pr_number = ?",
+    )
+    .bind(repo_name)
+    .bind(pr_number)
+    .fetch_optional(db_pool)
+    .await?;
+    sqlx::query(
+        "UPDATE governance_prs SET diff_lines = ?, updated_at = CURRENT_TIMESTAMP WHERE repo_name = ? AND pr_number = ?",
+    )
+    .bind(diff_lines)
+    .bind(repo_name)
+    .bind(pr_number)
+    .execute(db_pool)
+    .await?;
+    Ok(previous.flatten())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: TimelineEventKind, reason: &str, at: DateTime<Utc>) -> TimelineEvent {
+        TimelineEvent {
+            kind,
+            reason: reason.to_string(),
+            occurred_at: at,
+        }
+    }
+
+    #[test]
+    fn test_timeline_without_events_is_calendar_time() {
+        let opened = Utc::now() - Duration::days(40);
+        let now = opened + Duration::days(40);
+        let timeline = ReviewTimeline::new(opened);
+
+        assert_eq!(timeline.accumulated(now), Duration::days(40));
+        assert_eq!(timeline.remaining_days(Duration::days(90), now), 50);
+        assert_eq!(
+            timeline.earliest_merge_date(Duration::days(90), now),
+            Some(opened + Duration::days(90))
+        );
+    }
+
+    #[test]
+    fn test_pause_and_resume() {
+        let opened = Utc::now() - Duration::days(60);
+        let now = opened + Duration::days(60);
+        // Changes requested by two reviewers; the clock resumes only once both are resolved
+        let timeline = ReviewTimeline::with_events(
+            opened,
+            vec![
+                event(
+                    TimelineEventKind::Resume,
+                    "changes_requested:bob",
+                    opened + Duration::days(30),
+                ),
+                event(
+                    TimelineEventKind::Pause,
+                    "changes_requested:alice",
+                    opened + Duration::days(10),
+                ),
+                event(
+                    TimelineEventKind::Pause,
+                    "changes_requested:bob",
+                    opened + Duration::days(15),
+                ),
+                event(
+                    TimelineEventKind::Resume,
+                    "changes_requested:alice",
+                    opened + Duration::days(20),
+                ),
+            ],
+        );
+
+        let segments = timeline.segments(now);
+        assert_eq!(
+            segments
+                .iter()
+                .map(|s| ((s.end - s.start).num_days(), s.paused))
+                .collect::<Vec<_>>(),
+            vec![(10, false), (20, true), (30, false)]
+        );
+        assert_eq!(timeline.accumulated(now), Duration::days(40));
+        assert!(!timeline.is_paused(now));
+        assert_eq!(timeline.remaining_days(Duration::days(90), now), 50);
+        assert_eq!(
+            timeline.earliest_merge_date(Duration::days(90), now),
+            Some(now + Duration::days(50))
+        );
+        // Met within the last running segment
+        assert_eq!(
+            timeline.earliest_merge_date(Duration::days(30), now),
+            Some(opened + Duration::days(50))
+        );
+    }
+
+    #[test]
+    fn test_paused_clock_has_no_merge_date() {
+        let opened = Utc::now() - Duration::days(20);
+        let now = opened + Duration::days(20);
+        let timeline = ReviewTimeline::with_events(
+            opened,
+            vec![event(
+                TimelineEventKind::Pause,
+                "changes_requested:alice",
+                opened + Duration::days(5),
+            )],
+        );
+
+        assert!(timeline.is_paused(now));
+        assert_eq!(
+            timeline.active_pauses(now),
+            vec!["changes_requested:alice".to_string()]
+        );
+        assert_eq!(timeline.accumulated(now), Duration::days(5));
+        assert_eq!(timeline.earliest_merge_date(Duration::days(30), now), None);
+        // A period already met while running keeps its date
+        assert_eq!(
+            timeline.earliest_merge_date(Duration::days(3), now),
+            Some(opened + Duration::days(3))
+        );
+    }
+
+    #[test]
+    fn test_reset_discards_accumulated_time() {
+        let opened = Utc::now() - Duration::days(50);
+        let now = opened + Duration::days(50);
+        let timeline = ReviewTimeline::with_events(
+            opened,
+            vec![
+                event(
+                    TimelineEventKind::Pause,
+                    "changes_requested:alice",
+                    opened + Duration::days(20),
+                ),
+                event(
+                    TimelineEventKind::Reset,
+                    MAJOR_REVISION_REASON,
+                    opened + Duration::days(25),
+                ),
+                event(
+                    TimelineEventKind::Resume,
+                    "changes_requested:alice",
+                    opened + Duration::days(30),
+                ),
+            ],
+        );
+
+        // The pause outlives the reset; the clock restarts at day 30
+        let segments = timeline.segments(now);
+        assert_eq!(segments[0].start, opened + Duration::days(25));
+        assert!(segments[0].paused);
+        assert_eq!(timeline.accumulated(now), Duration::days(20));
+        assert_eq!(timeline.remaining_days(Duration::days(30), now), 10);
+        // Events after `now` are ignored
+        assert_eq!(
+            timeline.accumulated(opened + Duration::days(10)),
+            Duration::days(10)
+        );
+    }
+
+    #[test]
+    fn test_is_major_revision() {
+        assert!(is_major_revision(100, 250));
+        assert!(is_major_revision(400, 200));
+        assert!(!is_major_revision(1000, 1200));
+        assert!(!is_major_revision(10, 80));
+    }
+
+    #[tokio::test]
+    async fn test_timeline_round_trip() {
+        let database = crate::database::Database::new_in_memory().await.unwrap();
+        let pool = &database.get_sqlite_pool().unwrap();
+        let repo = "BTCDecoded/blvm-consensus";
+        let opened = Utc::now() - Duration::days(10);
+
+        record_timeline_event(
+            pool,
+            repo,
+            7,
+            TimelineEventKind::Pause,
+            "changes_requested:alice",
+            opened + Duration::days(2),
+        )
+        .await
+        .unwrap();
+        record_timeline_event(
+            pool,
+            repo,
+            8,
+            TimelineEventKind::Reset,
+            MAJOR_REVISION_REASON,
+            opened,
+        )
+        .await
+        .unwrap();
+
+        let timeline = load_timeline(pool, repo, 7, opened).await.unwrap();
+        assert_eq!(timeline.events.len(), 1);
+        assert_eq!(timeline.events[0].kind, TimelineEventKind::Pause);
+        assert!(timeline.is_paused(Utc::now()));
+
+        crate::validation::review_overlap::record_pr_opened(pool, repo, 7, 2, 3, opened)
+            .await
+            .unwrap();
+        assert_eq!(record_diff_size(pool, repo, 7, 120).await.unwrap(), None);
+        assert_eq!(
+            record_diff_size(pool, repo, 7, 300).await.unwrap(),
+            Some(120)
+        );
+    }
 }
@@ -2,6 +2,32 @@ use crate::error::GovernanceError;
 
 pub struct ThresholdValidator;
 
+/// Maintainer approvals, split by how they were given
+#[derive(Debug, Clone, Default)]
+pub struct SignatureApprovals {
+    /// Maintainers who posted a signed approval comment
+    pub comment_signers: Vec<String>,
+    /// Maintainers who PGP-signed a commit on the PR
+    pub commit_signers: Vec<String>,
+}
+
+impl SignatureApprovals {
+    /// Distinct maintainers across both sources
+    pub fn signers(&self) -> Vec<String> {
+        let mut signers: Vec<String> = Vec::new();
+        for signer in self.comment_signers.iter().chain(&self.commit_signers) {
+            if !signers.contains(signer) {
+                signers.push(signer.clone());
+            }
+        }
+        signers
+    }
+
+    pub fn count(&self) -> usize {
+        self.signers().len()
+    }
+}
+
 impl ThresholdValidator {
     pub fn validate_threshold(
         current_signatures: usize,
@@ -60,6 +86,29 @@ impl ThresholdValidator {
         )
     }
 
+    /// Same as `format_threshold_status`, listing each approval's source
+    pub fn format_threshold_status_with_sources(
+        approvals: &SignatureApprovals,
+        required: usize,
+        total: usize,
+        pending: &[String],
+    ) -> String {
+        let signers: Vec<String> = approvals
+            .signers()
+            .into_iter()
+            .map(|signer| {
+                let via_comment = approvals.comment_signers.contains(&signer);
+                let via_commit = approvals.commit_signers.contains(&signer);
+                match (via_comment, via_commit) {
+                    (true, true) => format!("{} (via comment, signed commit)", signer),
+                    (false, true) => format!("{} (via signed commit)", signer),
+                    _ => format!("{} (via comment)", signer),
+                }
+            })
+            .collect();
+        Self::format_threshold_status(approvals.count(), required, total, &signers, pending)
+    }
+
     /// Validate threshold with economic node veto check for Tier 3+ PRs
     /// Note: Economic node veto integration will be added in a future update
     pub async fn validate_threshold_with_veto(
@@ -33,6 +33,9 @@ pub struct TierRule {
     pub file_patterns: Vec<String>,
     pub keywords: Vec<String>,
     pub exclude_patterns: Option<Vec<String>>,
+    /// PR labels that put a PR in at least this tier
+    #[serde(default)]
+    pub labels: Vec<String>,
     pub require_specification: Option<bool>,
     pub require_audit: Option<bool>,
     pub require_equivalence_proof: Option<bool>,
@@ -122,6 +125,7 @@ async fn load_tier_classification_config() -> Result<TierClassificationConfig, G
             file_patterns: rule.file_patterns.clone(),
             keywords: rule.keywords.title.clone(),
             exclude_patterns: None,
+            labels: rule.labels.clone(),
             require_specification: None,
             require_audit: None,
             require_equivalence_proof: None,
@@ -181,28 +185,198 @@ async fn load_tier_classification_config() -> Result<TierClassificationConfig, G
     Ok(config)
 }
 
-/// Classify PR tier based on file patterns and content
-pub async fn classify_pr_tier(payload: &Value) -> u32 {
-    let config = load_tier_classification_config().await.unwrap_or_else(|e| {
+/// Load the classification rules, falling back to the built-in defaults
+async fn load_config_or_default() -> TierClassificationConfig {
+    load_tier_classification_config().await.unwrap_or_else(|e| {
         warn!(
             "Failed to load tier classification config: {}, using default",
             e
         );
         get_default_config()
-    });
+    })
+}
+
+/// Classify PR tier based on file patterns and content
+pub async fn classify_pr_tier(payload: &Value) -> u32 {
+    let config = load_config_or_default().await;
 
     let result = classify_pr_tier_detailed(payload, &config).await;
     result.tier
 }
 
+/// Classify PR tier from its content and an explicit list of changed files
+///
+/// The changed paths set a floor on the tier (e.g. `consensus/**` is at least
+/// Tier 3), while a PR that only touches documentation is Tier 1 unless its
+/// labels say otherwise.
+pub async fn classify_pr_tier_with_files(payload: &Value, changed_files: &[String]) -> u32 {
+    TierClassifier::new(load_config_or_default().await)
+        .classify(payload, changed_files, &extract_labels(payload))
+        .await
+        .tier
+}
+
+/// A PR's tier and the rules that decided it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TierClassification {
+    pub tier: u32,
+    /// Rules that matched, e.g. `tier_3_consensus_adjacent: consensus/block.rs`
+    pub matched_rules: Vec<String>,
+    pub explanation: String,
+    /// Set by a maintainer's tier override rather than the rules
+    pub overridden: bool,
+}
+
+/// Classifies PRs by changed paths, labels and content
+///
+/// Every rule whose file patterns match a changed file, or whose labels are on
+/// the PR, sets a floor on the tier; a mixed PR takes the highest. A PR that
+/// only touches documentation skips the content keywords and is Tier 1 unless
+/// a label raises it.
+pub struct TierClassifier {
+    config: TierClassificationConfig,
+}
+
+impl TierClassifier {
+    pub fn new(config: TierClassificationConfig) -> Self {
+        Self { config }
+    }
+
+    /// Classifier with the rules from `governance/config`, or the built-in rules
+    pub async fn load() -> Self {
+        Self::new(load_config_or_default().await)
+    }
+
+    pub async fn classify(
+        &self,
+        payload: &Value,
+        changed_files: &[String],
+        labels: &[String],
+    ) -> TierClassification {
+        let mut rules: Vec<(u32, &String, &TierRule)> = self
+            .config
+            .classification_rules
+            .iter()
+            .map(|(name, rule)| (rule_tier(name), name, rule))
+            .collect();
+        rules.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(b.1)));
+
+        let mut matched_rules = Vec::new();
+        let mut path_tier = None;
+        let mut label_tier = None;
+        for (tier, name, rule) in &rules {
+            let excluded = |file: &str| {
+                rule.exclude_patterns
+                    .as_ref()
+                    .is_some_and(|patterns| patterns.iter().any(|p| matches_pattern(file, p)))
+            };
+            if let Some(file) = changed_files.iter().find(|file| {
+                !excluded(file) && rule.file_patterns.iter().any(|p| matches_pattern(file, p))
+            }) {
+                matched_rules.push(format!("{}: {}", name, file));
+                path_tier = path_tier.max(Some(*tier));
+            }
+            if let Some(label) = labels
+                .iter()
+                .find(|label| rule.labels.iter().any(|l| l.eq_ignore_ascii_case(label)))
+            {
+                matched_rules.push(format!("{}: label {}", name, label));
+                label_tier = label_tier.max(Some(*tier));
+            }
+        }
+
+        let mut reasons = Vec::new();
+        let mut tier = if is_docs_only(changed_files) && path_tier.is_none_or(|tier| tier <= 1) {
+            debug!("PR only touches documentation, classifying as Tier 1");
+            reasons.push("only documentation changed".to_string());
+            1
+        } else {
+            let content = classify_pr_tier_detailed(payload, &self.config).await;
+            if !content.matched_keywords.is_empty() {
+                matched_rules.push(format!("content: {}", content.matched_keywords.join(", ")));
+            }
+            reasons.push(format!("content suggests Tier {}", content.tier));
+            if let Some(path_tier) = path_tier {
+                reasons.push(format!("changed paths require Tier {}", path_tier));
+            }
+            content.tier.max(path_tier.unwrap_or(0))
+        };
+        if let Some(label_tier) = label_tier {
+            reasons.push(format!("labels require Tier {}", label_tier));
+            tier = tier.max(label_tier);
+        }
+
+        TierClassification {
+            tier,
+            matched_rules,
+            explanation: format!("Tier {}: {}", tier, reasons.join("; ")),
+            overridden: false,
+        }
+    }
+}
+
+/// Classify a change set purely by the paths it touches
+///
+/// Returns the highest tier whose file patterns match any changed file, or
+/// `None` if no rule matches.
+pub fn classify_tier_from_paths(
+    changed_files: &[String],
+    config: &TierClassificationConfig,
+) -> Option<u32> {
+    config
+        .classification_rules
+        .iter()
+        .filter(|(_, rule)| {
+            changed_files.iter().any(|file| {
+                let excluded = rule
+                    .exclude_patterns
+                    .as_ref()
+                    .is_some_and(|patterns| patterns.iter().any(|p| matches_pattern(file, p)));
+                !excluded && rule.file_patterns.iter().any(|p| matches_pattern(file, p))
+            })
+        })
+        .map(|(name, _)| rule_tier(name))
+        .max()
+}
+
+/// Whether every changed file is documentation
+fn is_docs_only(changed_files: &[String]) -> bool {
+    const DOC_PATTERNS: [&str; 3] = ["docs/**", "*.md", "README*"];
+
+    !changed_files.is_empty()
+        && changed_files
+            .iter()
+            .all(|file| DOC_PATTERNS.iter().any(|p| matches_pattern(file, p)))
+}
+
+/// GitHub label recording a PR's governance tier
+pub fn tier_label(tier: u32) -> String {
+    format!("tier-{}", tier)
+}
+
 /// Classify PR tier with database override checking
 /// Checks for tier override first, then falls back to automated classification
+/// using the PR content and its changed files
 pub async fn classify_pr_tier_with_db(
     database: &crate::database::Database,
     payload: &Value,
+    changed_files: &[String],
     repo_name: &str,
     pr_number: i32,
 ) -> u32 {
+    classify_pr_with_db(database, payload, changed_files, repo_name, pr_number)
+        .await
+        .tier
+}
+
+/// Like [`classify_pr_tier_with_db`], with the matched rules and explanation
+pub async fn classify_pr_with_db(
+    database: &crate::database::Database,
+    payload: &Value,
+    changed_files: &[String],
+    repo_name: &str,
+    pr_number: i32,
+) -> TierClassification {
     // Check for tier override first
     match database.get_tier_override(repo_name, pr_number).await {
         Ok(Some(override_record)) => {
@@ -210,7 +384,11 @@ pub async fn classify_pr_tier_with_db(
                 "Using tier override for PR #{} in {}: Tier {} (justification: {})",
                 pr_number, repo_name, override_record.override_tier, override_record.justification
             );
-            return override_record.override_tier;
+            return override_classification(
+                override_record.override_tier,
+                &override_record.overridden_by,
+                &override_record.justification,
+            );
         }
         Ok(None) => {
             // No override, proceed with automated classification
@@ -224,7 +402,122 @@ pub async fn classify_pr_tier_with_db(
     }
 
     // Fall back to automated classification
-    classify_pr_tier(payload).await
+    TierClassifier::load()
+        .await
+        .classify(payload, changed_files, &extract_labels(payload))
+        .await
+}
+
+/// Classification recording a maintainer's tier override
+pub fn override_classification(
+    tier: u32,
+    overridden_by: &str,
+    justification: &str,
+) -> TierClassification {
+    TierClassification {
+        tier,
+        matched_rules: vec![format!("override: {}", overridden_by)],
+        explanation: format!(
+            "Tier {}: overridden by {} ({})",
+            tier, overridden_by, justification
+        ),
+        overridden: true,
+    }
+}
+
+/// Stored classification of a PR, with when it was last classified
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredTierClassification {
+    #[serde(flatten)]
+    pub classification: TierClassification,
+    pub head_sha: Option<String>,
+    pub classified_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Persist a PR's classification, replacing the previous one; returns the
+/// previous tier, if the PR had been classified before
+pub async fn record_classification(
+    db_pool: &sqlx::SqlitePool,
+    repo_name: &str,
+    pr_number: i32,
+    head_sha: Option<&str>,
+    classification: &TierClassification,
+) -> Result<Option<u32>, GovernanceError> {
+    let previous: Option<i64> = sqlx::query_scalar(
+        "SELECT tier FROM pr_tier_classifications WHERE repo_name = ? AND pr_number = ?",
+    )
+    .bind(repo_name)
+    .bind(pr_number)
+    .fetch_optional(db_pool)
+    .await?;
+    let matched_rules = serde_json::to_string(&classification.matched_rules)
+        .map_err(|e| GovernanceError::ConfigError(e.to_string()))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO pr_tier_classifications
+            (repo_name, pr_number, tier, matched_rules, explanation, overridden, head_sha, classified_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(repo_name, pr_number) DO UPDATE SET
+            tier = excluded.tier,
+            matched_rules = excluded.matched_rules,
+            explanation = excluded.explanation,
+            overridden = excluded.overridden,
+            head_sha = COALESCE(excluded.head_sha, head_sha),
+            classified_at = excluded.classified_at
+        "#,
+    )
+    .bind(repo_name)
+    .bind(pr_number)
+    .bind(classification.tier)
+    .bind(matched_rules)
+    .bind(&classification.explanation)
+    .bind(classification.overridden)
+    .bind(head_sha)
+    .bind(chrono::Utc::now())
+    .execute(db_pool)
+    .await?;
+    Ok(previous.map(|tier| tier as u32))
+}
+
+#[derive(sqlx::FromRow)]
+struct ClassificationRow {
+    tier: i64,
+    matched_rules: String,
+    explanation: String,
+    overridden: bool,
+    head_sha: Option<String>,
+    classified_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A PR's stored classification
+pub async fn load_classification(
+    db_pool: &sqlx::SqlitePool,
+    repo_name: &str,
+    pr_number: i32,
+) -> Result<Option<StoredTierClassification>, GovernanceError> {
+    let row = sqlx::query_as::<_, ClassificationRow>(
+        r#"
+        SELECT tier, matched_rules, explanation, overridden, head_sha, classified_at
+        FROM pr_tier_classifications
+        WHERE repo_name = ? AND pr_number = ?
+        "#,
+    )
+    .bind(repo_name)
+    .bind(pr_number)
+    .fetch_optional(db_pool)
+    .await?;
+
+    Ok(row.map(|row| StoredTierClassification {
+        classification: TierClassification {
+            tier: row.tier as u32,
+            matched_rules: serde_json::from_str(&row.matched_rules).unwrap_or_default(),
+            explanation: row.explanation,
+            overridden: row.overridden,
+        },
+        head_sha: row.head_sha,
+        classified_at: row.classified_at,
+    }))
 }
 
 /// Classify PR tier with detailed results
@@ -283,22 +576,7 @@ pub async fn classify_pr_tier_detailed(
     let mut tier_rules: Vec<(u32, String, &TierRule)> = config
         .classification_rules
         .iter()
-        .map(|(name, rule)| {
-            // Extract tier number from name like "tier_1_routine" or "tier_5_governance"
-            let tier_num = if name.starts_with("tier_") {
-                name.split('_')
-                    .nth(1)
-                    .and_then(|s| s.parse::<u32>().ok())
-                    .unwrap_or(1)
-            } else {
-                // Fallback: try to parse from last segment
-                name.split('_')
-                    .next_back()
-                    .and_then(|s| s.parse::<u32>().ok())
-                    .unwrap_or(1)
-            };
-            (tier_num, name.clone(), rule)
-        })
+        .map(|(name, rule)| (rule_tier(name), name.clone(), rule))
         .collect();
     tier_rules.sort_by(|a, b| b.0.cmp(&a.0)); // Sort descending (5, 4, 3, 2, 1)
 
@@ -451,6 +729,22 @@ pub async fn classify_pr_tier_detailed(
     }
 }
 
+/// Extract the tier number from a rule name like "tier_1_routine" or "tier_5_governance"
+fn rule_tier(name: &str) -> u32 {
+    if name.starts_with("tier_") {
+        name.split('_')
+            .nth(1)
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(1)
+    } else {
+        // Fallback: try to parse from last segment
+        name.split('_')
+            .next_back()
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(1)
+    }
+}
+
 /// Get default tier classification configuration
 fn get_default_config() -> TierClassificationConfig {
     let mut rules = HashMap::new();
@@ -475,6 +769,7 @@ fn get_default_config() -> TierClassificationConfig {
                 "[governance]".to_string(),
             ],
             exclude_patterns: None,
+            labels: vec!["governance".to_string()],
             require_specification: Some(false),
             require_audit: Some(false),
             require_equivalence_proof: Some(false),
@@ -502,6 +797,7 @@ fn get_default_config() -> TierClassificationConfig {
                 "emergency:".to_string(),
             ],
             exclude_patterns: None,
+            labels: vec!["emergency".to_string()],
             require_specification: Some(false),
             require_audit: Some(false),
             require_equivalence_proof: Some(false),
@@ -533,6 +829,7 @@ fn get_default_config() -> TierClassificationConfig {
                 "[consensus-adjacent]".to_string(),
             ],
             exclude_patterns: None,
+            labels: vec!["consensus".to_string(), "consensus-adjacent".to_string()],
             require_specification: Some(true),
             require_audit: Some(true),
             require_equivalence_proof: Some(true),
@@ -554,6 +851,7 @@ fn get_default_config() -> TierClassificationConfig {
                 "wallet/**".to_string(),
                 "p2p/**".to_string(),
                 "api/**".to_string(),
+                "src/enforcement/**".to_string(),
             ],
             keywords: vec![
                 "feature".to_string(),
@@ -563,6 +861,7 @@ fn get_default_config() -> TierClassificationConfig {
                 "addition".to_string(), // Match "Feature addition" in test
             ],
             exclude_patterns: None,
+            labels: vec!["feature".to_string()],
             require_specification: Some(true),
             require_audit: Some(false),
             require_equivalence_proof: Some(false),
@@ -596,6 +895,7 @@ fn get_default_config() -> TierClassificationConfig {
                 "consensus/**".to_string(),
                 "validation/**".to_string(),
             ]),
+            labels: vec![],
             require_specification: Some(false),
             require_audit: Some(false),
             require_equivalence_proof: Some(false),
@@ -716,7 +1016,7 @@ fn matches_pattern(file: &str, pattern: &str) -> bool {
 }
 
 /// Extract list of changed files from GitHub webhook payload
-fn extract_changed_files(payload: &Value) -> Vec<String> {
+pub fn extract_changed_files(payload: &Value) -> Vec<String> {
     let mut files = Vec::new();
 
     // Try to get files from pull_request.files (if available)
@@ -737,6 +1037,22 @@ fn extract_changed_files(payload: &Value) -> Vec<String> {
     files
 }
 
+/// Extract label names from a pull request webhook payload
+pub fn extract_labels(payload: &Value) -> Vec<String> {
+    payload
+        .get("pull_request")
+        .and_then(|pr| pr.get("labels"))
+        .and_then(|labels| labels.as_array())
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| label.get("name").and_then(|n| n.as_str()))
+                .map(String::from)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Extract PR title from payload
 fn extract_title(payload: &Value) -> String {
     payload
@@ -878,4 +1194,133 @@ mod tests {
         ));
         assert!(matches_pattern("any/path/to/rpc/server.rs", "**/rpc/**"));
     }
+
+    #[test]
+    fn test_classify_tier_from_paths() {
+        let config = get_default_config();
+        let files = |paths: &[&str]| paths.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            classify_tier_from_paths(&files(&["governance/config/action-tiers.yml"]), &config),
+            Some(5)
+        );
+        assert_eq!(
+            classify_tier_from_paths(&files(&["consensus/block.rs", "docs/notes.md"]), &config),
+            Some(3)
+        );
+        assert_eq!(
+            classify_tier_from_paths(&files(&["src/enforcement/merge_block.rs"]), &config),
+            Some(2)
+        );
+        assert_eq!(
+            classify_tier_from_paths(&files(&["src/lib.rs"]), &config),
+            None
+        );
+        assert!(is_docs_only(&files(&["docs/guide.md", "README.md"])));
+        assert!(!is_docs_only(&files(&["docs/guide.md", "src/lib.rs"])));
+        assert_eq!(tier_label(3), "tier-3");
+    }
+
+    #[tokio::test]
+    async fn test_changed_paths_set_tier_floor() {
+        let payload = json!({
+            "pull_request": {
+                "title": "Add new feature",
+                "body": "Implements a new feature",
+                "files": []
+            }
+        });
+
+        let consensus = vec!["consensus/script.rs".to_string()];
+        assert_eq!(classify_pr_tier_with_files(&payload, &consensus).await, 3);
+
+        let docs = vec!["docs/feature.md".to_string()];
+        assert_eq!(classify_pr_tier_with_files(&payload, &docs).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_classifier_takes_highest_matching_rule() {
+        let classifier = TierClassifier::new(get_default_config());
+        let payload = json!({ "pull_request": { "title": "Tidy up", "body": "" } });
+        let files = vec![
+            "docs/notes.md".to_string(),
+            "src/enforcement/merge_block.rs".to_string(),
+            "consensus/block.rs".to_string(),
+        ];
+
+        let result = classifier.classify(&payload, &files, &[]).await;
+        assert_eq!(result.tier, 3);
+        assert!(!result.overridden);
+        assert!(result
+            .matched_rules
+            .contains(&"tier_3_consensus_adjacent: consensus/block.rs".to_string()));
+        assert!(result
+            .matched_rules
+            .contains(&"tier_2_features: src/enforcement/merge_block.rs".to_string()));
+        assert!(result.explanation.contains("changed paths require Tier 3"));
+    }
+
+    #[tokio::test]
+    async fn test_labels_raise_docs_only_tier() {
+        let classifier = TierClassifier::new(get_default_config());
+        let payload = json!({
+            "pull_request": {
+                "title": "Clarify wording",
+                "body": "",
+                "labels": [{ "name": "Governance" }, { "name": "tier-1" }]
+            }
+        });
+        let docs = vec!["docs/process.md".to_string()];
+
+        let unlabeled = classifier.classify(&payload, &docs, &[]).await;
+        assert_eq!(unlabeled.tier, 1);
+        assert!(unlabeled.explanation.contains("only documentation changed"));
+
+        let labeled = classifier
+            .classify(&payload, &docs, &extract_labels(&payload))
+            .await;
+        assert_eq!(labeled.tier, 5);
+        assert!(labeled
+            .matched_rules
+            .contains(&"tier_5_governance: label Governance".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_reclassification_when_files_change() {
+        let database = crate::database::Database::new_in_memory().await.unwrap();
+        let pool = &database.get_sqlite_pool().unwrap();
+        let classifier = TierClassifier::new(get_default_config());
+        let payload = json!({ "pull_request": { "title": "Update", "body": "" } });
+        let repo = "BTCDecoded/blvm-consensus";
+
+        let first = classifier
+            .classify(&payload, &["docs/a.md".to_string()], &[])
+            .await;
+        assert_eq!(
+            record_classification(pool, repo, 1, Some("aaa"), &first)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let second = classifier
+            .classify(&payload, &["consensus/script.rs".to_string()], &[])
+            .await;
+        assert_eq!(
+            record_classification(pool, repo, 1, Some("bbb"), &second)
+                .await
+                .unwrap(),
+            Some(1)
+        );
+
+        // An override keeps the last head commit
+        let overridden = override_classification(2, "alice", "Test-only consensus change");
+        record_classification(pool, repo, 1, None, &overridden)
+            .await
+            .unwrap();
+        let stored = load_classification(pool, repo, 1).await.unwrap().unwrap();
+        assert_eq!(stored.classification, overridden);
+        assert_eq!(stored.head_sha.as_deref(), Some("bbb"));
+        assert!(load_classification(pool, repo, 2).await.unwrap().is_none());
+    }
 }
@@ -0,0 +1,96 @@
+//! Internal webhook queue endpoints
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::Json,
+    routing::{get, post},
+    Router,
+};
+use serde::Serialize;
+use tracing::warn;
+
+use crate::api_auth::require_internal_api_key;
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::webhooks::queue::{WebhookEvent, WebhookQueue};
+
+/// Dead-lettered events response
+#[derive(Debug, Serialize)]
+pub struct FailedEventsResponse {
+    pub events: Vec<WebhookEvent>,
+}
+
+type ApiError = (StatusCode, Json<serde_json::Value>);
+
+fn api_error(status: StatusCode, message: impl std::fmt::Display) -> ApiError {
+    (
+        status,
+        Json(serde_json::json!({ "error": message.to_string() })),
+    )
+}
+
+fn webhook_queue(database: &Database) -> Result<WebhookQueue, ApiError> {
+    database
+        .get_sqlite_pool()
+        .map(WebhookQueue::new)
+        .ok_or_else(|| {
+            api_error(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Database pool not available",
+            )
+        })
+}
+
+/// List webhook events that exhausted their retries
+pub async fn list_failed_events(
+    State((_, database)): State<(AppConfig, Database)>,
+) -> Result<Json<FailedEventsResponse>, ApiError> {
+    let queue = webhook_queue(&database)?;
+    match queue.list_failed().await {
+        Ok(events) => Ok(Json(FailedEventsResponse { events })),
+        Err(e) => {
+            warn!("Failed to list failed webhook events: {}", e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+/// Put a dead-lettered webhook event back on the queue
+pub async fn retry_event(
+    State((_, database)): State<(AppConfig, Database)>,
+    Path(id): Path<i64>,
+) -> Result<Json<WebhookEvent>, ApiError> {
+    let queue = webhook_queue(&database)?;
+    let result = async {
+        if !queue.requeue(id).await? {
+            return Ok(None);
+        }
+        queue.get_event(id).await
+    }
+    .await;
+
+    match result {
+        Ok(Some(event)) => Ok(Json(event)),
+        Ok(None) => Err(api_error(
+            StatusCode::NOT_FOUND,
+            format!("No failed webhook event {}", id),
+        )),
+        Err(e) => {
+            warn!("Failed to requeue webhook event {}: {}", id, e);
+            Err(api_error(StatusCode::INTERNAL_SERVER_ERROR, e))
+        }
+    }
+}
+
+/// Create router for the webhook queue API; all routes require the internal API key
+pub fn create_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/internal/webhooks/failed", get(list_failed_events))
+        .route("/internal/webhooks/events/:id/retry", post(retry_event))
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ))
+}
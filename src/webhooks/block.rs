@@ -1,23 +1,37 @@
 //! Block webhook handler
 //!
-//! Receives block notifications from blvm-node (fee forwarding removed)
+//! Receives block notifications from blvm-node (fee forwarding removed).
+//! Notifications that carry the raw block header are verified locally
+//! (header hash and proof of work against the network's limit) and advance
+//! the tracked chain tip; notifications without a header are acknowledged but
+//! not trusted. The endpoint requires the internal API key.
 
-use axum::{extract::State, response::Json};
+use axum::{extract::State, http::StatusCode, middleware, response::Json, routing::post, Router};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tracing::{error, info};
+use tracing::{info, warn};
 
+use crate::api_auth::require_internal_api_key;
 use crate::config::AppConfig;
+use crate::crypto::block_header::verify_block_header;
 use crate::database::Database;
+use crate::error::GovernanceError;
+use crate::webhooks::chain_tips::{BlockOutcome, ChainReorg, ChainTipTracker};
 
 /// Block notification payload
 /// Block should be provided as JSON object that can be deserialized to blvm_protocol::Block
 #[derive(Debug, Deserialize)]
 pub struct BlockNotification {
     pub block_hash: String,
-    pub block_height: i32,
+    /// Informational; the tracked height is derived from the parent block
+    #[serde(default)]
+    pub block_height: Option<i32>,
+    #[serde(default)]
     pub block: Value, // Block data as JSON - will be converted to blvm_protocol::Block
     pub contributor_id: Option<String>, // Optional: node/miner identifier
+    /// Raw 80-byte block header (hex); when present the block is verified
+    #[serde(default)]
+    pub header: Option<String>,
 }
 
 /// Block notification response
@@ -26,18 +40,117 @@ pub struct BlockNotificationResponse {
     pub success: bool,
     pub message: String,
     pub contributions_found: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reorg: Option<ChainReorg>,
+}
+
+fn response(
+    status: StatusCode,
+    success: bool,
+    message: impl Into<String>,
+    reorg: Option<ChainReorg>,
+) -> (StatusCode, Json<BlockNotificationResponse>) {
+    (
+        status,
+        Json(BlockNotificationResponse {
+            success,
+            message: message.into(),
+            contributions_found: 0,
+            reorg,
+        }),
+    )
 }
 
 /// Handle block notification webhook
-/// Fee forwarding removed - this is now a placeholder
 pub async fn handle_block_notification(
-    State((_config, _database)): State<(AppConfig, Database)>,
-    Json(_payload): Json<BlockNotification>,
-) -> Json<BlockNotificationResponse> {
-    // Fee forwarding removed - no longer processing blocks
-    Json(BlockNotificationResponse {
-        success: true,
-        message: "Fee forwarding removed - block notification received but not processed".to_string(),
-        contributions_found: 0,
-    })
+    State((config, database)): State<(AppConfig, Database)>,
+    Json(payload): Json<BlockNotification>,
+) -> (StatusCode, Json<BlockNotificationResponse>) {
+    let Some(header_hex) = payload.header.as_deref() else {
+        return response(
+            StatusCode::OK,
+            true,
+            "Block notification received without a header; not verified",
+            None,
+        );
+    };
+
+    let network = config.governance.bitcoin_network();
+    let verified = match verify_block_header(header_hex, &payload.block_hash, network) {
+        Ok(verified) => verified,
+        Err(e) => {
+            warn!("Rejected block notification {}: {}", payload.block_hash, e);
+            return response(StatusCode::BAD_REQUEST, false, e.to_string(), None);
+        }
+    };
+
+    let Some(pool) = database.get_sqlite_pool() else {
+        return response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            false,
+            "Database pool not available",
+            None,
+        );
+    };
+    let (checkpoint_hash, checkpoint_height) = config.governance.chain_checkpoint();
+    match ChainTipTracker::new(pool)
+        .with_checkpoint(checkpoint_hash, checkpoint_height)
+        .record_block(&verified)
+        .await
+    {
+        Ok(BlockOutcome::Reorg(reorg)) => {
+            warn!(
+                "Chain reorg: tip {} (height {}) replaced by {} (height {})",
+                reorg.old_tip_hash, reorg.old_height, reorg.new_tip_hash, reorg.new_height
+            );
+            response(
+                StatusCode::OK,
+                true,
+                "Block verified; chain reorganized",
+                Some(reorg),
+            )
+        }
+        Ok(BlockOutcome::Extended) => {
+            info!("Chain tip advanced to {}", verified.block_hash);
+            response(
+                StatusCode::OK,
+                true,
+                "Block verified; chain tip advanced",
+                None,
+            )
+        }
+        Ok(BlockOutcome::Stale) => response(
+            StatusCode::OK,
+            true,
+            "Block verified; not on the best chain",
+            None,
+        ),
+        Ok(BlockOutcome::Known) => response(StatusCode::OK, true, "Block already known", None),
+        Err(GovernanceError::ValidationError(message)) => {
+            warn!(
+                "Rejected block notification {}: {}",
+                payload.block_hash, message
+            );
+            response(StatusCode::BAD_REQUEST, false, message, None)
+        }
+        Err(e) => {
+            warn!("Failed to record block {}: {}", payload.block_hash, e);
+            response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                false,
+                e.to_string(),
+                None,
+            )
+        }
+    }
+}
+
+/// Create router for block notifications; requires the internal API key
+pub fn create_router(state: (AppConfig, Database)) -> Router<(AppConfig, Database)> {
+    Router::new()
+        .route("/webhooks/block", post(handle_block_notification))
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            require_internal_api_key,
+        ))
 }
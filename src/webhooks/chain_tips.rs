@@ -0,0 +1,409 @@
+//! Chain Tip Tracking
+//!
+//! Records blocks from verified header notifications, keeps the best known tip
+//! (most cumulative work), and records a reorg whenever a new best block does
+//! not build on the previous tip. Every block must build on one already
+//! recorded, back to a trusted checkpoint; its height is derived from its
+//! parent rather than taken from the notification. Height consumers read the
+//! tip from here rather than trusting individual notifications.
+
+use bitcoin::Work;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use std::collections::HashMap;
+
+use crate::crypto::block_header::VerifiedHeader;
+use crate::error::GovernanceError;
+
+/// How far back to walk the stored chain when looking for a fork point
+const MAX_FORK_SEARCH_DEPTH: usize = 1000;
+
+/// Stored block
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ChainTip {
+    pub block_hash: String,
+    pub height: i64,
+    pub prev_block_hash: String,
+    /// Work since the checkpoint, as 64 big-endian hex digits
+    pub chainwork: String,
+    pub received_at: DateTime<Utc>,
+}
+
+/// Recorded chain reorganization
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ChainReorg {
+    pub id: i64,
+    pub old_tip_hash: String,
+    pub old_height: i64,
+    pub new_tip_hash: String,
+    pub new_height: i64,
+    pub fork_height: Option<i64>,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// What recording a block did to the chain tip
+#[derive(Debug, Clone)]
+pub enum BlockOutcome {
+    /// The block built on the tip (or was the first block seen)
+    Extended,
+    /// The block became the tip on a competing branch
+    Reorg(ChainReorg),
+    /// The block is on a branch with no more work than the current tip
+    Stale,
+    /// The block was already recorded
+    Known,
+}
+
+fn encode_work(work: Work) -> String {
+    hex::encode(work.to_be_bytes())
+}
+
+fn decode_work(chainwork: &str) -> Result<Work, GovernanceError> {
+    let bytes: [u8; 32] = hex::decode(chainwork)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| {
+            GovernanceError::DatabaseError(format!("Invalid stored chainwork {:?}", chainwork))
+        })?;
+    Ok(Work::from_be_bytes(bytes))
+}
+
+/// Chain tip tracker backed by the `chain_tips` and `chain_reorgs` tables
+#[derive(Clone)]
+pub struct ChainTipTracker {
+    pool: SqlitePool,
+    checkpoint: Option<(String, i64)>,
+}
+
+impl ChainTipTracker {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            checkpoint: None,
+        }
+    }
+
+    /// Trusted block (hash, height) the recorded chain starts from
+    ///
+    /// Only a tracker with a checkpoint records blocks; it is stored on first use.
+    pub fn with_checkpoint(mut self, block_hash: impl Into<String>, height: i64) -> Self {
+        self.checkpoint = Some((block_hash.into(), height));
+        self
+    }
+
+    /// Store the checkpoint, and make it the tip if there is none yet
+    async fn ensure_checkpoint(&self) -> Result<(), GovernanceError> {
+        let Some((block_hash, height)) = &self.checkpoint else {
+            return Err(GovernanceError::ConfigError(
+                "Chain tip tracker has no checkpoint".to_string(),
+            ));
+        };
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO chain_tips (block_hash, height, prev_block_hash, chainwork, is_tip, received_at)
+            VALUES (?, ?, '', ?, FALSE, ?)
+            "#,
+        )
+        .bind(block_hash)
+        .bind(height)
+        .bind(encode_work(Work::from_be_bytes([0; 32])))
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query(
+            r#"
+            UPDATE chain_tips SET is_tip = TRUE
+            WHERE block_hash = ? AND NOT EXISTS (SELECT 1 FROM chain_tips WHERE is_tip = TRUE)
+            "#,
+        )
+        .bind(block_hash)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Record a verified block and move the tip if it is now the best block
+    ///
+    /// The block's parent must already be recorded (or be the checkpoint).
+    pub async fn record_block(
+        &self,
+        header: &VerifiedHeader,
+    ) -> Result<BlockOutcome, GovernanceError> {
+        let block_hash = header.block_hash.as_str();
+        let prev_block_hash = header.prev_block_hash.as_str();
+        self.ensure_checkpoint().await?;
+        if self.get_block(block_hash).await?.is_some() {
+            return Ok(BlockOutcome::Known);
+        }
+        let parent = self.get_block(prev_block_hash).await?.ok_or_else(|| {
+            GovernanceError::ValidationError(format!(
+                "Block {} does not build on a known block ({} is unknown)",
+                block_hash, prev_block_hash
+            ))
+        })?;
+        let height = parent.height + 1;
+        let chainwork = decode_work(&parent.chainwork)? + header.work;
+
+        // The checkpoint is the tip until a block builds on it
+        let tip = self.best_tip().await?.ok_or_else(|| {
+            GovernanceError::DatabaseError("Chain tip tracker has no tip".to_string())
+        })?;
+        let extends_tip = tip.block_hash == prev_block_hash;
+        let overtakes = chainwork > decode_work(&tip.chainwork)?;
+        let fork_height = if !extends_tip && overtakes {
+            self.find_fork_height(&tip.block_hash, prev_block_hash)
+                .await?
+        } else {
+            None
+        };
+
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            r#"
+            INSERT INTO chain_tips (block_hash, height, prev_block_hash, chainwork, is_tip, received_at)
+            VALUES (?, ?, ?, ?, FALSE, ?)
+            "#,
+        )
+        .bind(block_hash)
+        .bind(height)
+        .bind(prev_block_hash)
+        .bind(encode_work(chainwork))
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+
+        let outcome = if extends_tip {
+            BlockOutcome::Extended
+        } else if !overtakes {
+            BlockOutcome::Stale
+        } else {
+            let id = sqlx::query(
+                r#"
+                INSERT INTO chain_reorgs
+                    (old_tip_hash, old_height, new_tip_hash, new_height, fork_height, detected_at)
+                VALUES (?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&tip.block_hash)
+            .bind(tip.height)
+            .bind(block_hash)
+            .bind(height)
+            .bind(fork_height)
+            .bind(now)
+            .execute(&mut *tx)
+            .await?
+            .last_insert_rowid();
+
+            BlockOutcome::Reorg(ChainReorg {
+                id,
+                old_tip_hash: tip.block_hash.clone(),
+                old_height: tip.height,
+                new_tip_hash: block_hash.to_string(),
+                new_height: height,
+                fork_height,
+                detected_at: now,
+            })
+        };
+
+        if !matches!(outcome, BlockOutcome::Stale) {
+            sqlx::query("UPDATE chain_tips SET is_tip = FALSE WHERE is_tip = TRUE")
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query("UPDATE chain_tips SET is_tip = TRUE WHERE block_hash = ?")
+                .bind(block_hash)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+
+        Ok(outcome)
+    }
+
+    pub async fn get_block(&self, block_hash: &str) -> Result<Option<ChainTip>, GovernanceError> {
+        Ok(sqlx::query_as::<_, ChainTip>(
+            "SELECT block_hash, height, prev_block_hash, chainwork, received_at FROM chain_tips WHERE block_hash = ?",
+        )
+        .bind(block_hash)
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    /// Current best block
+    pub async fn best_tip(&self) -> Result<Option<ChainTip>, GovernanceError> {
+        Ok(sqlx::query_as::<_, ChainTip>(
+            "SELECT block_hash, height, prev_block_hash, chainwork, received_at FROM chain_tips WHERE is_tip = TRUE",
+        )
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    /// Most recent reorgs, newest first
+    pub async fn recent_reorgs(&self, limit: i64) -> Result<Vec<ChainReorg>, GovernanceError> {
+        Ok(sqlx::query_as::<_, ChainReorg>(
+            "SELECT * FROM chain_reorgs ORDER BY detected_at DESC, id DESC LIMIT ?",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    /// Height of the last common ancestor of two stored branches, if known
+    async fn find_fork_height(
+        &self,
+        old_tip_hash: &str,
+        new_parent_hash: &str,
+    ) -> Result<Option<i64>, GovernanceError> {
+        let mut old_branch = HashMap::new();
+        let mut cursor = self.get_block(old_tip_hash).await?;
+        while let Some(block) = cursor {
+            if old_branch.len() >= MAX_FORK_SEARCH_DEPTH {
+                break;
+            }
+            cursor = self.get_block(&block.prev_block_hash).await?;
+            old_branch.insert(block.block_hash, block.height);
+        }
+
+        let mut cursor = self.get_block(new_parent_hash).await?;
+        for _ in 0..MAX_FORK_SEARCH_DEPTH {
+            let Some(block) = cursor else {
+                break;
+            };
+            if let Some(height) = old_branch.get(&block.block_hash) {
+                return Ok(Some(*height));
+            }
+            cursor = self.get_block(&block.prev_block_hash).await?;
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::block_header::verify_block_header;
+    use crate::database::Database;
+    use bitcoin::block::{Header, Version};
+    use bitcoin::hashes::Hash;
+    use bitcoin::{BlockHash, CompactTarget, Network, TxMerkleNode};
+
+    /// Mine a header at the regtest minimum difficulty on top of `prev`
+    fn mine(prev: BlockHash, time: u32) -> (String, String) {
+        let mut header = Header {
+            version: Version::ONE,
+            prev_blockhash: prev,
+            merkle_root: TxMerkleNode::all_zeros(),
+            time,
+            bits: CompactTarget::from_consensus(0x207fffff),
+            nonce: 0,
+        };
+        while header.validate_pow(header.target()).is_err() {
+            header.nonce += 1;
+        }
+        (
+            bitcoin::consensus::encode::serialize_hex(&header),
+            header.block_hash().to_string(),
+        )
+    }
+
+    async fn submit(tracker: &ChainTipTracker, header_hex: &str, hash: &str) -> BlockOutcome {
+        let verified = verify_block_header(header_hex, hash, Network::Regtest).unwrap();
+        tracker.record_block(&verified).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_competing_chain_records_reorg() {
+        let database = Database::new_in_memory().await.unwrap();
+        let tracker = ChainTipTracker::new(database.get_sqlite_pool().unwrap())
+            .with_checkpoint(BlockHash::all_zeros().to_string(), 0);
+
+        let (h1, b1) = mine(BlockHash::all_zeros(), 1);
+        let (h2, b2) = mine(b1.parse().unwrap(), 2);
+        assert!(matches!(
+            submit(&tracker, &h1, &b1).await,
+            BlockOutcome::Extended
+        ));
+        assert!(matches!(
+            submit(&tracker, &h2, &b2).await,
+            BlockOutcome::Extended
+        ));
+        assert!(matches!(
+            submit(&tracker, &h2, &b2).await,
+            BlockOutcome::Known
+        ));
+        assert_eq!(tracker.best_tip().await.unwrap().unwrap().block_hash, b2);
+
+        // A competing block with the same work doesn't move the tip
+        let (h2b, b2b) = mine(b1.parse().unwrap(), 3);
+        assert!(matches!(
+            submit(&tracker, &h2b, &b2b).await,
+            BlockOutcome::Stale
+        ));
+        assert_eq!(tracker.best_tip().await.unwrap().unwrap().block_hash, b2);
+
+        // Extending the competing branch overtakes the tip
+        let (h3b, b3b) = mine(b2b.parse().unwrap(), 4);
+        match submit(&tracker, &h3b, &b3b).await {
+            BlockOutcome::Reorg(reorg) => {
+                assert_eq!(reorg.old_tip_hash, b2);
+                assert_eq!(reorg.new_tip_hash, b3b);
+                assert_eq!(reorg.fork_height, Some(1));
+            }
+            outcome => panic!("expected a reorg, got {:?}", outcome),
+        }
+        let tip = tracker.best_tip().await.unwrap().unwrap();
+        assert_eq!((tip.block_hash.as_str(), tip.height), (b3b.as_str(), 3));
+        assert_eq!(tracker.recent_reorgs(5).await.unwrap().len(), 1);
+
+        // Blocks must build on a known block
+        let unknown_parent = mine(BlockHash::all_zeros(), 99).1.parse().unwrap();
+        let (orphan_hex, orphan) = mine(unknown_parent, 5);
+        let verified = verify_block_header(&orphan_hex, &orphan, Network::Regtest).unwrap();
+        assert!(tracker.record_block(&verified).await.is_err());
+        assert_eq!(tracker.best_tip().await.unwrap().unwrap().block_hash, b3b);
+    }
+
+    #[tokio::test]
+    async fn test_tip_follows_most_work_not_longest_chain() {
+        let database = Database::new_in_memory().await.unwrap();
+        let tracker = ChainTipTracker::new(database.get_sqlite_pool().unwrap())
+            .with_checkpoint(BlockHash::all_zeros().to_string(), 100);
+
+        // Two easy blocks
+        let (h1, b1) = mine(BlockHash::all_zeros(), 1);
+        let (h2, b2) = mine(b1.parse().unwrap(), 2);
+        submit(&tracker, &h1, &b1).await;
+        submit(&tracker, &h2, &b2).await;
+        let tip = tracker.best_tip().await.unwrap().unwrap();
+        assert_eq!((tip.block_hash.as_str(), tip.height), (b2.as_str(), 102));
+
+        // A single harder block from the checkpoint has more work than both
+        let mut header = Header {
+            version: Version::ONE,
+            prev_blockhash: BlockHash::all_zeros(),
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: 3,
+            bits: CompactTarget::from_consensus(0x1f7fffff),
+            nonce: 0,
+        };
+        while header.validate_pow(header.target()).is_err() {
+            header.nonce += 1;
+        }
+        let hard_hash = header.block_hash().to_string();
+        let outcome = submit(
+            &tracker,
+            &bitcoin::consensus::encode::serialize_hex(&header),
+            &hard_hash,
+        )
+        .await;
+        assert!(matches!(outcome, BlockOutcome::Reorg(_)));
+        let tip = tracker.best_tip().await.unwrap().unwrap();
+        assert_eq!(
+            (tip.block_hash.as_str(), tip.height),
+            (hard_hash.as_str(), 101)
+        );
+    }
+}
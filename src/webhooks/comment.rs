@@ -5,6 +5,7 @@ use crate::crypto::signatures::SignatureManager;
 use crate::database::Database;
 use crate::governance_review::models::policy;
 use crate::governance_review::GovernanceReviewCaseManager;
+use crate::validation::tier_classification;
 
 pub async fn handle_comment_event(
     database: &Database,
@@ -274,6 +275,26 @@ async fn handle_tier_override(
                 )
                 .await;
 
+            // The override replaces the stored classification right away
+            if let Some(pool) = &database.get_sqlite_pool() {
+                let classification = tier_classification::override_classification(
+                    override_tier,
+                    commenter,
+                    justification,
+                );
+                if let Err(e) = tier_classification::record_classification(
+                    pool,
+                    repo_name,
+                    pr_number as i32,
+                    None,
+                    &classification,
+                )
+                .await
+                {
+                    warn!("Failed to store overridden tier classification: {}", e);
+                }
+            }
+
             Ok(axum::response::Json(serde_json::json!({
                 "status": "tier_override_set",
                 "override_tier": override_tier,
@@ -391,7 +412,7 @@ async fn handle_governance_review_case(
     };
 
     // Create case (on-platform only per policy)
-    let pool = database
+    let pool = &database
         .get_sqlite_pool()
         .ok_or_else(|| axum::http::StatusCode::INTERNAL_SERVER_ERROR)?;
 
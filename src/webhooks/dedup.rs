@@ -0,0 +1,357 @@
+//! Replay detection for GitHub webhook deliveries
+//!
+//! Accepted `X-GitHub-Delivery` GUIDs are written through to
+//! `github_webhook_deliveries` and also kept in a bounded in-memory set, so a
+//! redelivery of a recent event is rejected without a database round trip.
+//! The set is warmed from the table on startup, so replays are still caught
+//! across restarts. Rows past the retention window are pruned hourly rather
+//! than on every request.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::SqlitePool;
+use tracing::{error, info};
+
+use crate::config::WebhookConfig;
+use crate::metrics::{self, WEBHOOK_DEDUP_LOOKUPS};
+use crate::shutdown::{self, Shutdown};
+
+/// How long a delivery ID is remembered for replay detection, by default
+pub const DEFAULT_RETENTION_HOURS: u64 = 72;
+
+/// Delivery IDs kept in memory, by default
+pub const DEFAULT_CACHE_CAPACITY: usize = 10_000;
+
+/// How often expired delivery IDs are pruned
+pub const CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// The server's deduplicator, shared by webhook handlers
+static SHARED_DEDUPLICATOR: OnceLock<Arc<DeliveryDeduplicator>> = OnceLock::new();
+
+/// Register the server's deduplicator (only the first registration is kept)
+pub fn set_shared_deduplicator(deduplicator: Arc<DeliveryDeduplicator>) {
+    let _ = SHARED_DEDUPLICATOR.set(deduplicator);
+}
+
+/// The server's deduplicator, if one has been registered
+pub fn shared_deduplicator() -> Option<&'static Arc<DeliveryDeduplicator>> {
+    SHARED_DEDUPLICATOR.get()
+}
+
+/// Lookup counters since start
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DedupStats {
+    /// Replays rejected from memory
+    pub hits: u64,
+    /// Lookups that went to the database
+    pub misses: u64,
+    /// Remembered IDs that had aged out of the window and were not treated
+    /// as replays
+    pub false_duplicates: u64,
+    /// Delivery IDs currently held in memory
+    pub cached: usize,
+}
+
+/// Recently seen delivery IDs, oldest evicted first
+#[derive(Default)]
+struct RecentDeliveries {
+    order: VecDeque<String>,
+    seen: HashMap<String, DateTime<Utc>>,
+}
+
+impl RecentDeliveries {
+    fn insert(&mut self, delivery_id: &str, received_at: DateTime<Utc>, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        if self
+            .seen
+            .insert(delivery_id.to_string(), received_at)
+            .is_some()
+        {
+            self.order.retain(|id| id != delivery_id);
+        }
+        self.order.push_back(delivery_id.to_string());
+        while self.order.len() > capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.seen.remove(&evicted);
+            }
+        }
+    }
+
+    fn prune(&mut self, cutoff: DateTime<Utc>) {
+        self.seen.retain(|_, received_at| *received_at >= cutoff);
+        let seen = &self.seen;
+        self.order.retain(|id| seen.contains_key(id));
+    }
+}
+
+/// Memory-fronted record of accepted webhook deliveries
+pub struct DeliveryDeduplicator {
+    pool: SqlitePool,
+    retention: chrono::Duration,
+    capacity: usize,
+    recent: Mutex<RecentDeliveries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    false_duplicates: AtomicU64,
+}
+
+impl DeliveryDeduplicator {
+    pub fn new(pool: SqlitePool, config: &WebhookConfig) -> Self {
+        Self {
+            pool,
+            retention: chrono::Duration::hours(config.dedup_retention_hours as i64),
+            capacity: config.dedup_cache_capacity,
+            recent: Mutex::new(RecentDeliveries::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            false_duplicates: AtomicU64::new(0),
+        }
+    }
+
+    fn recent(&self) -> std::sync::MutexGuard<'_, RecentDeliveries> {
+        self.recent.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn count(counter: &AtomicU64, result: &'static str) {
+        counter.fetch_add(1, Ordering::Relaxed);
+        metrics::inc_counter(WEBHOOK_DEDUP_LOOKUPS, &[("result", result)]);
+    }
+
+    /// Load the most recent delivery IDs still inside the window
+    pub async fn warm(&self) -> Result<usize, sqlx::Error> {
+        let rows: Vec<(String, DateTime<Utc>)> = sqlx::query_as(
+            r#"
+            SELECT delivery_id, received_at FROM github_webhook_deliveries
+            WHERE received_at >= ?
+            ORDER BY received_at DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(Utc::now() - self.retention)
+        .bind(self.capacity as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let loaded = rows.len();
+        let mut recent = self.recent();
+        for (delivery_id, received_at) in rows.into_iter().rev() {
+            recent.insert(&delivery_id, received_at, self.capacity);
+        }
+        Ok(loaded)
+    }
+
+    /// Whether the delivery was seen within the window, from memory only
+    ///
+    /// A quick replay check before the event is queued; the queue's unique
+    /// delivery ID is what makes acceptance exact.
+    pub fn seen_recently(&self, delivery_id: &str) -> bool {
+        let cutoff = Utc::now() - self.retention;
+        let seen = matches!(
+            self.recent().seen.get(delivery_id),
+            Some(received_at) if *received_at >= cutoff
+        );
+        if seen {
+            Self::count(&self.hits, "hit");
+        }
+        seen
+    }
+
+    /// Record a delivery; returns false if the ID was already seen within the window
+    pub async fn record(&self, delivery_id: &str, event_type: &str) -> Result<bool, sqlx::Error> {
+        let now = Utc::now();
+        let cutoff = now - self.retention;
+
+        let remembered = self.recent().seen.get(delivery_id).copied();
+        match remembered {
+            Some(received_at) if received_at >= cutoff => {
+                Self::count(&self.hits, "hit");
+                return Ok(false);
+            }
+            Some(_) => Self::count(&self.false_duplicates, "false_duplicate"),
+            None => Self::count(&self.misses, "miss"),
+        }
+
+        // A row past the window counts as new even if cleanup hasn't removed it yet
+        let accepted = sqlx::query(
+            r#"
+            INSERT INTO github_webhook_deliveries (delivery_id, event_type, received_at)
+            VALUES (?, ?, ?)
+            ON CONFLICT(delivery_id) DO UPDATE SET
+                event_type = excluded.event_type,
+                received_at = excluded.received_at
+            WHERE github_webhook_deliveries.received_at < ?
+            "#,
+        )
+        .bind(delivery_id)
+        .bind(event_type)
+        .bind(now)
+        .bind(cutoff)
+        .execute(&self.pool)
+        .await?
+        .rows_affected()
+            == 1;
+
+        let received_at = if accepted {
+            now
+        } else {
+            sqlx::query_scalar(
+                "SELECT received_at FROM github_webhook_deliveries WHERE delivery_id = ?",
+            )
+            .bind(delivery_id)
+            .fetch_one(&self.pool)
+            .await?
+        };
+        self.recent()
+            .insert(delivery_id, received_at, self.capacity);
+        Ok(accepted)
+    }
+
+    /// Forget deliveries older than the window; returns the rows deleted
+    pub async fn cleanup(&self) -> Result<u64, sqlx::Error> {
+        let cutoff = Utc::now() - self.retention;
+        let deleted = sqlx::query("DELETE FROM github_webhook_deliveries WHERE received_at < ?")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+        self.recent().prune(cutoff);
+        Ok(deleted)
+    }
+
+    pub fn stats(&self) -> DedupStats {
+        DedupStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            false_duplicates: self.false_duplicates.load(Ordering::Relaxed),
+            cached: self.recent().seen.len(),
+        }
+    }
+}
+
+/// Prune expired delivery IDs every [`CLEANUP_INTERVAL`] until shutdown
+pub fn spawn_cleanup_task(deduplicator: Arc<DeliveryDeduplicator>, shutdown: &Shutdown) {
+    shutdown.spawn("webhook_dedup_cleanup", |token| async move {
+        let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+        while shutdown::next_tick(&token, &mut interval).await {
+            match deduplicator.cleanup().await {
+                Ok(0) => {}
+                Ok(deleted) => info!("Pruned {} expired webhook delivery IDs", deleted),
+                Err(e) => error!("Failed to prune webhook delivery IDs: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+
+    fn config(capacity: usize) -> WebhookConfig {
+        WebhookConfig {
+            dedup_cache_capacity: capacity,
+            ..Default::default()
+        }
+    }
+
+    async fn backdate(pool: &SqlitePool, delivery_id: &str, hours: i64) {
+        sqlx::query("UPDATE github_webhook_deliveries SET received_at = ? WHERE delivery_id = ?")
+            .bind(Utc::now() - chrono::Duration::hours(hours))
+            .bind(delivery_id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_replayed_delivery_rejected_from_memory() {
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.get_sqlite_pool().unwrap();
+        let dedup = DeliveryDeduplicator::new(pool, &config(100));
+
+        assert!(dedup.record("delivery-1", "pull_request").await.unwrap());
+        assert!(dedup.record("delivery-2", "pull_request").await.unwrap());
+        for _ in 0..3 {
+            assert!(!dedup.record("delivery-1", "pull_request").await.unwrap());
+        }
+
+        // Only the two first sightings went to the database
+        let stats = dedup.stats();
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.hits, 3);
+        assert_eq!(stats.cached, 2);
+    }
+
+    #[tokio::test]
+    async fn test_replay_detected_after_restart() {
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.get_sqlite_pool().unwrap();
+        let before = DeliveryDeduplicator::new(pool.clone(), &config(100));
+        assert!(before.record("delivery-1", "push").await.unwrap());
+        assert!(before.record("delivery-2", "push").await.unwrap());
+
+        let after = DeliveryDeduplicator::new(pool.clone(), &config(100));
+        assert_eq!(after.warm().await.unwrap(), 2);
+        assert!(!after.record("delivery-1", "push").await.unwrap());
+        assert_eq!(after.stats().hits, 1);
+        assert_eq!(after.stats().misses, 0);
+
+        // Evicted or never warmed IDs are still caught by the database
+        let cold = DeliveryDeduplicator::new(pool, &config(0));
+        assert!(!cold.record("delivery-2", "push").await.unwrap());
+        assert_eq!(cold.stats().misses, 1);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_uses_retention_window() {
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.get_sqlite_pool().unwrap();
+        let dedup = DeliveryDeduplicator::new(
+            pool.clone(),
+            &WebhookConfig {
+                dedup_retention_hours: 24,
+                dedup_cache_capacity: 100,
+            },
+        );
+        for id in ["old", "recent", "new"] {
+            assert!(dedup.record(id, "push").await.unwrap());
+        }
+        backdate(&pool, "old", 25).await;
+        backdate(&pool, "recent", 23).await;
+
+        assert_eq!(dedup.cleanup().await.unwrap(), 1);
+        let remaining: Vec<String> = sqlx::query_scalar(
+            "SELECT delivery_id FROM github_webhook_deliveries ORDER BY delivery_id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(remaining, vec!["new".to_string(), "recent".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_expired_delivery_accepted_again() {
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.get_sqlite_pool().unwrap();
+        let dedup = DeliveryDeduplicator::new(pool.clone(), &config(100));
+        assert!(dedup.record("delivery-1", "push").await.unwrap());
+
+        // Aged out in both layers before cleanup has run
+        backdate(&pool, "delivery-1", DEFAULT_RETENTION_HOURS as i64 + 1).await;
+        dedup.recent().seen.insert(
+            "delivery-1".to_string(),
+            Utc::now() - chrono::Duration::hours(DEFAULT_RETENTION_HOURS as i64 + 1),
+        );
+
+        assert!(dedup.record("delivery-1", "push").await.unwrap());
+        assert_eq!(dedup.stats().false_duplicates, 1);
+        assert!(!dedup.record("delivery-1", "push").await.unwrap());
+    }
+}
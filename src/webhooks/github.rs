@@ -1,4 +1,5 @@
 use axum::{
+    body::Bytes,
     extract::State,
     http::{HeaderMap, StatusCode},
     response::Json,
@@ -7,27 +8,136 @@ use serde_json::Value;
 use tracing::{info, warn};
 
 use crate::build::orchestrator::BuildOrchestrator;
+use crate::config::AppConfig;
+use crate::database::Database;
 use crate::github::client::GitHubClient;
+use crate::metrics::{self, WEBHOOK_EVENTS, WEBHOOK_REJECTED};
+use crate::webhooks::dedup::{self, DeliveryDeduplicator};
+use crate::webhooks::queue::WebhookQueue;
+use crate::webhooks::signature::{verify_signature, DELIVERY_HEADER, SIGNATURE_HEADER};
 use crate::webhooks::{comment, pull_request, release, review};
 
 pub async fn handle_webhook(
-    State((config, database)): State<(crate::config::AppConfig, crate::database::Database)>,
+    State((config, database)): State<(AppConfig, Database)>,
     headers: HeaderMap,
-    Json(payload): Json<Value>,
+    body: Bytes,
 ) -> (StatusCode, Json<Value>) {
+    // Without a real secret anyone could sign webhooks, so accept none
+    let Some(secret) = config.webhook_secret() else {
+        warn!("Rejected webhook: no webhook secret is configured");
+        metrics::inc_counter(WEBHOOK_REJECTED, &[("reason", "no_secret")]);
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({"error": "webhook secret not configured"})),
+        );
+    };
+
+    // Authenticate the raw body before anything parses it
+    let signature = headers.get(SIGNATURE_HEADER).and_then(|v| v.to_str().ok());
+    if !verify_signature(secret, &body, signature) {
+        warn!("Rejected webhook with missing or invalid signature");
+        metrics::inc_counter(WEBHOOK_REJECTED, &[("reason", "invalid_signature")]);
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(serde_json::json!({"error": "invalid signature"})),
+        );
+    }
+
     // Get event type from GitHub webhook header
     let event_type = headers
         .get("x-github-event")
         .and_then(|v| v.to_str().ok())
         .unwrap_or("unknown");
 
+    let Some(delivery_id) = headers.get(DELIVERY_HEADER).and_then(|v| v.to_str().ok()) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({"error": "missing delivery id"})),
+        );
+    };
+    if dedup::shared_deduplicator().is_some_and(|d| d.seen_recently(delivery_id)) {
+        return duplicate_delivery(delivery_id);
+    }
+
+    let payload: Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!("Rejected webhook with malformed body: {}", e);
+            metrics::inc_counter(WEBHOOK_REJECTED, &[("reason", "invalid_payload")]);
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "invalid payload"})),
+            );
+        }
+    };
+
+    metrics::inc_counter(WEBHOOK_EVENTS, &[("event", event_type)]);
+
+    // Queue the event for the worker; without SQLite, process it inline
+    let Some(pool) = database.get_sqlite_pool() else {
+        return process_event(&config, &database, event_type, &payload).await;
+    };
+    match WebhookQueue::new(pool.clone())
+        .enqueue(delivery_id, event_type, &payload)
+        .await
+    {
+        Ok(Some(id)) => {
+            info!(
+                "Queued webhook {}: event_type={}, delivery={}",
+                id, event_type, delivery_id
+            );
+            // Only a queued event is remembered, so a delivery that failed
+            // to queue is accepted when GitHub redelivers it
+            let recorded = match dedup::shared_deduplicator() {
+                Some(deduplicator) => deduplicator.record(delivery_id, event_type).await,
+                None => {
+                    DeliveryDeduplicator::new(pool.clone(), &config.webhooks)
+                        .record(delivery_id, event_type)
+                        .await
+                }
+            };
+            if let Err(e) = recorded {
+                warn!("Failed to record webhook delivery {}: {}", delivery_id, e);
+            }
+            (
+                StatusCode::ACCEPTED,
+                Json(serde_json::json!({"status": "queued", "id": id})),
+            )
+        }
+        Ok(None) => duplicate_delivery(delivery_id),
+        Err(e) => {
+            warn!("Failed to queue webhook delivery {}: {}", delivery_id, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": "failed"})),
+            )
+        }
+    }
+}
+
+fn duplicate_delivery(delivery_id: &str) -> (StatusCode, Json<Value>) {
+    warn!("Rejected replayed webhook delivery {}", delivery_id);
+    metrics::inc_counter(WEBHOOK_REJECTED, &[("reason", "duplicate")]);
+    (
+        StatusCode::CONFLICT,
+        Json(serde_json::json!({"error": "duplicate delivery"})),
+    )
+}
+
+/// Run the processing logic for one webhook event
+pub async fn process_event(
+    config: &AppConfig,
+    database: &Database,
+    event_type: &str,
+    payload: &Value,
+) -> (StatusCode, Json<Value>) {
     let action = payload
         .get("action")
         .and_then(|v| v.as_str())
         .unwrap_or("unknown");
 
     info!(
-        "Received webhook: event_type={}, action={}",
+        "Processing webhook: event_type={}, action={}",
         event_type, action
     );
 
@@ -35,14 +145,18 @@ pub async fn handle_webhook(
         "pull_request" => {
             match action {
                 "opened" | "synchronize" | "reopened" => {
-                    match pull_request::handle_pull_request_event(&config, &database, &payload)
-                        .await
-                    {
+                    match pull_request::handle_pull_request_event(config, database, payload).await {
                         Ok(response) => (StatusCode::OK, response),
                         Err(status) => (status, Json(serde_json::json!({"error": "failed"}))),
                     }
                 }
+                "edited" => (
+                    StatusCode::OK,
+                    pull_request::handle_pull_request_edited(config, payload).await,
+                ),
                 "closed" => {
+                    pull_request::handle_pr_closed(database, payload).await;
+
                     // Check if PR was merged
                     let merged = payload
                         .get("pull_request")
@@ -53,7 +167,7 @@ pub async fn handle_webhook(
                     if merged {
                         // PR was merged - publish to Nostr
                         if let Err(e) =
-                            pull_request::handle_pr_merged(&config, &database, &payload).await
+                            pull_request::handle_pr_merged(config, database, payload).await
                         {
                             warn!("Failed to publish merge to Nostr: {}", e);
                         }
@@ -73,11 +187,11 @@ pub async fn handle_webhook(
                 }
             }
         }
-        "pull_request_review" => match review::handle_review_event(&database, &payload).await {
+        "pull_request_review" => match review::handle_review_event(database, payload).await {
             Ok(response) => (StatusCode::OK, response),
             Err(status) => (status, Json(serde_json::json!({"error": "failed"}))),
         },
-        "issue_comment" => match comment::handle_comment_event(&database, &payload).await {
+        "issue_comment" => match comment::handle_comment_event(database, payload).await {
             Ok(response) => (StatusCode::OK, response),
             Err(status) => (status, Json(serde_json::json!({"error": "failed"}))),
         },
@@ -85,7 +199,7 @@ pub async fn handle_webhook(
             // Initialize build orchestrator
             let github_client =
                 match GitHubClient::new(config.github_app_id, &config.github_private_key_path) {
-                    Ok(client) => client,
+                    Ok(client) => client.with_execution_mode(config.execution_mode()),
                     Err(e) => {
                         warn!("Failed to create GitHub client: {}", e);
                         return (
@@ -103,9 +217,10 @@ pub async fn handle_webhook(
                 .unwrap_or("BTCDecoded")
                 .to_string();
 
-            let orchestrator = BuildOrchestrator::new(github_client, database, organization);
+            let orchestrator =
+                BuildOrchestrator::new(github_client, database.clone(), organization);
 
-            match release::handle_release_event(&payload, &orchestrator).await {
+            match release::handle_release_event(payload, &orchestrator).await {
                 Ok((status, response)) => (status, Json(response)),
                 Err(e) => {
                     warn!("Failed to handle release event: {}", e);
@@ -120,7 +235,7 @@ pub async fn handle_webhook(
             // Initialize build orchestrator
             let github_client =
                 match GitHubClient::new(config.github_app_id, &config.github_private_key_path) {
-                    Ok(client) => client,
+                    Ok(client) => client.with_execution_mode(config.execution_mode()),
                     Err(e) => {
                         warn!("Failed to create GitHub client: {}", e);
                         return (
@@ -143,8 +258,7 @@ pub async fn handle_webhook(
             let orchestrator =
                 BuildOrchestrator::new(github_client, database_clone.clone(), organization);
 
-            match release::handle_repository_dispatch(&payload, &orchestrator, &database_clone)
-                .await
+            match release::handle_repository_dispatch(payload, &orchestrator, &database_clone).await
             {
                 Ok((status, response)) => (status, Json(response)),
                 Err(e) => {
@@ -165,3 +279,113 @@ pub async fn handle_webhook(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    const SECRET: &str = "webhook-secret";
+
+    fn headers(body: &[u8], secret: &str, delivery_id: &str) -> HeaderMap {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        let signature = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-github-event", HeaderValue::from_static("ping"));
+        headers.insert(SIGNATURE_HEADER, HeaderValue::from_str(&signature).unwrap());
+        headers.insert(DELIVERY_HEADER, HeaderValue::from_str(delivery_id).unwrap());
+        headers
+    }
+
+    #[tokio::test]
+    async fn test_webhook_signature_and_replay() {
+        let config = AppConfig {
+            github_webhook_secret: SECRET.to_string(),
+            ..Default::default()
+        };
+        let database = Database::new_in_memory().await.unwrap();
+        let state = || State((config.clone(), database.clone()));
+        let body = Bytes::from_static(br#"{"zen":"Keep it logically awesome."}"#);
+
+        // Signed with the wrong secret: rejected even though the body is fine
+        let (status, _) =
+            handle_webhook(state(), headers(&body, "wrong", "delivery-1"), body.clone()).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // Malformed bodies are rejected by the signature check, not the parser
+        let malformed = Bytes::from_static(b"{not json");
+        let mut forged = headers(&body, SECRET, "delivery-1");
+        forged.remove(SIGNATURE_HEADER);
+        let (status, _) = handle_webhook(state(), forged, malformed).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        // A verified event is queued for the worker
+        let (status, _) =
+            handle_webhook(state(), headers(&body, SECRET, "delivery-1"), body.clone()).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        let queue = WebhookQueue::new(database.get_sqlite_pool().unwrap());
+        assert_eq!(queue.stats().await.unwrap().depth, 1);
+
+        // Replaying the same delivery is rejected
+        let (status, _) =
+            handle_webhook(state(), headers(&body, SECRET, "delivery-1"), body.clone()).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_rejected_without_secret() {
+        let database = Database::new_in_memory().await.unwrap();
+        let body = Bytes::from_static(br#"{"zen":"Keep it logically awesome."}"#);
+        for secret in ["", crate::config::PLACEHOLDER_WEBHOOK_SECRET] {
+            let config = AppConfig {
+                github_webhook_secret: secret.to_string(),
+                ..Default::default()
+            };
+            // Correctly signed with the (known) secret, still rejected
+            let (status, _) = handle_webhook(
+                State((config, database.clone())),
+                headers(&body, secret, "delivery-1"),
+                body.clone(),
+            )
+            .await;
+            assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        }
+    }
+    #[tokio::test]
+    async fn test_failed_enqueue_leaves_delivery_unrecorded() {
+        let config = AppConfig {
+            github_webhook_secret: SECRET.to_string(),
+            ..Default::default()
+        };
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = database.get_sqlite_pool().unwrap();
+        let state = || State((config.clone(), database.clone()));
+        let body = Bytes::from_static(br#"{"zen":"Keep it logically awesome."}"#);
+
+        sqlx::query("ALTER TABLE webhook_events RENAME TO webhook_events_offline")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let (status, _) =
+            handle_webhook(state(), headers(&body, SECRET, "delivery-1"), body.clone()).await;
+        assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        let recorded: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM github_webhook_deliveries")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(recorded, 0);
+
+        // GitHub's redelivery is accepted once the queue is back
+        sqlx::query("ALTER TABLE webhook_events_offline RENAME TO webhook_events")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let (status, _) =
+            handle_webhook(state(), headers(&body, SECRET, "delivery-1"), body.clone()).await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+    }
+}
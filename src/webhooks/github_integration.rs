@@ -4,16 +4,20 @@
 
 use chrono::Utc;
 use serde_json::Value;
+use std::collections::HashMap;
 use tracing::{info, warn};
 
+use crate::config::loader::MaintainerKey;
 use crate::database::Database;
 use crate::enforcement::decision_log::DecisionLogger;
+use crate::enforcement::emergency_manager::EmergencyManager;
 use crate::enforcement::merge_block::MergeBlocker;
 use crate::enforcement::status_checks::StatusCheckGenerator;
 use crate::error::GovernanceError;
 use crate::github::client::GitHubClient;
-use crate::validation::review_period::ReviewPeriodValidator;
-use crate::validation::threshold::ThresholdValidator;
+use crate::validation::commit_signatures::{CommitSignatureCheck, CommitSignatureVerifier};
+use crate::validation::review_period::{self, ReviewPeriodValidator, ReviewTimeline};
+use crate::validation::threshold::{SignatureApprovals, ThresholdValidator};
 use crate::validation::tier_classification;
 
 pub struct GitHubIntegration {
@@ -21,6 +25,10 @@ pub struct GitHubIntegration {
     database: Database,
     merge_blocker: MergeBlocker,
     decision_logger: DecisionLogger,
+    /// Maintainer governance public key -> GitHub username
+    maintainer_handles: HashMap<String, String>,
+    /// Counts PGP-signed head commits as approvals when set
+    commit_verifier: Option<CommitSignatureVerifier>,
 }
 
 impl GitHubIntegration {
@@ -35,9 +43,99 @@ impl GitHubIntegration {
             database,
             merge_blocker,
             decision_logger,
+            maintainer_handles: HashMap::new(),
+            commit_verifier: None,
         }
     }
 
+    /// Set the mapping from maintainer public keys to GitHub usernames
+    pub fn with_maintainer_handles(mut self, maintainer_handles: HashMap<String, String>) -> Self {
+        self.maintainer_handles = maintainer_handles;
+        self
+    }
+
+    /// Accept PGP-signed commits from keys in this keyring as approvals
+    pub fn with_maintainer_keyring(mut self, keyring: Vec<MaintainerKey>) -> Self {
+        self.commit_verifier = Some(CommitSignatureVerifier::new(keyring));
+        self
+    }
+
+    /// Request reviews from required maintainers who haven't signed yet
+    ///
+    /// Only applies to Tier 2+ PRs that are still short of their signature
+    /// threshold. `current_signers` may hold public keys or GitHub usernames.
+    /// Returns the usernames that reviews were requested from.
+    pub async fn request_required_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        tier: u32,
+        current_signers: Vec<String>,
+    ) -> Result<Vec<String>, GovernanceError> {
+        let reviewers = Self::pending_reviewers(&self.maintainer_handles, tier, &current_signers);
+        if reviewers.is_empty() {
+            return Ok(reviewers);
+        }
+
+        if let Err(e) = self
+            .github_client
+            .request_reviewers(owner, repo, pr_number, reviewers.clone())
+            .await
+        {
+            if e.to_string().to_lowercase().contains("rate limit") {
+                warn!(
+                    "Review requests for {}/{}#{} hit the GitHub API rate limit: {}",
+                    owner, repo, pr_number, e
+                );
+            }
+            return Err(e);
+        }
+
+        info!(
+            "Requested reviews for {}/{}#{} (Tier {}) from {:?}",
+            owner, repo, pr_number, tier, reviewers
+        );
+        Ok(reviewers)
+    }
+
+    /// Maintainers who still need to review a PR, sorted by username
+    ///
+    /// Empty for Tier 1 PRs and once the tier's signature threshold is met.
+    pub fn pending_reviewers(
+        maintainer_handles: &HashMap<String, String>,
+        tier: u32,
+        current_signers: &[String],
+    ) -> Vec<String> {
+        if tier < 2 {
+            return Vec::new();
+        }
+
+        let has_signed = |public_key: &String, username: &String| {
+            current_signers
+                .iter()
+                .any(|signer| signer == public_key || signer.eq_ignore_ascii_case(username))
+        };
+        let signed = maintainer_handles
+            .iter()
+            .filter(|(public_key, username)| has_signed(public_key, username))
+            .count();
+
+        let (required, _) = ThresholdValidator::get_tier_threshold(tier);
+        if signed >= required {
+            return Vec::new();
+        }
+
+        let mut reviewers: Vec<String> = maintainer_handles
+            .iter()
+            .filter(|(public_key, username)| !has_signed(public_key, username))
+            .map(|(_, username)| username.clone())
+            .collect();
+        reviewers.sort();
+        reviewers.dedup();
+        reviewers
+    }
+
     /// Handle pull request opened event
     pub async fn handle_pr_opened(&self, payload: &Value) -> Result<(), GovernanceError> {
         let repo_name = self.extract_repo_name(payload)?;
@@ -146,12 +244,12 @@ impl GitHubIntegration {
         // Get PR information from database
         let pr_info = self
             .database
-            .get_pull_request(owner, pr_number as i32)
+            .get_pull_request(&format!("{}/{}", owner, repo), pr_number as i32)
             .await?;
 
         if let Some(pr) = pr_info {
             let layer = pr.layer;
-            let tier = tier_classification::classify_pr_tier(payload).await;
+            let tier = self.stored_tier(&pr, payload).await;
             let tier_name = self.get_tier_name(tier);
 
             // Get combined requirements (Layer + Tier)
@@ -159,13 +257,21 @@ impl GitHubIntegration {
                 ThresholdValidator::get_combined_requirements(layer, tier);
             let _source = ThresholdValidator::get_requirement_source(layer, tier);
 
+            // An active emergency shortens the review period until it expires
+            let emergency = EmergencyManager::new(self.database.clone())
+                .active_emergency()
+                .await?;
+            let review_days =
+                EmergencyManager::effective_review_days(review_days, emergency.as_ref());
+
             // Check review period
             let review_period_met = self.check_review_period(&pr, review_days).await?;
             let review_period_status = self.generate_review_period_status(&pr, review_days).await?;
 
             // Check signatures
-            let (signatures_met, signature_status) =
-                self.check_signatures(&pr, sigs_req, sigs_total).await?;
+            let (signatures_met, signature_status) = self
+                .check_signatures(owner, repo, sha, &pr, sigs_req, sigs_total)
+                .await?;
 
             // Post individual status checks
             self.post_review_period_status(owner, repo, sha, &review_period_status)
@@ -207,14 +313,59 @@ impl GitHubIntegration {
         Ok(())
     }
 
-    /// Check review period requirements
+    /// The PR's stored tier classification, or its tier from the payload
+    /// if it hasn't been classified
+    async fn stored_tier(&self, pr: &crate::database::models::PullRequest, payload: &Value) -> u32 {
+        let stored = match &self.database.get_sqlite_pool() {
+            Some(pool) => {
+                tier_classification::load_classification(pool, &pr.repo_name, pr.pr_number)
+                    .await
+                    .unwrap_or_else(|e| {
+                        warn!(
+                            "Failed to load tier classification for PR #{}: {}",
+                            pr.pr_number, e
+                        );
+                        None
+                    })
+            }
+            None => None,
+        };
+        match stored {
+            Some(stored) => stored.classification.tier,
+            None => tier_classification::classify_pr_tier(payload).await,
+        }
+    }
+
+    /// The PR's review timeline; calendar time from `opened_at` if its events
+    /// can't be loaded
+    async fn review_timeline(&self, pr: &crate::database::models::PullRequest) -> ReviewTimeline {
+        let Some(pool) = self.database.get_sqlite_pool() else {
+            return ReviewTimeline::new(pr.opened_at);
+        };
+        review_period::load_timeline(&pool, &pr.repo_name, pr.pr_number, pr.opened_at)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(
+                    "Failed to load review timeline for PR #{}: {}",
+                    pr.pr_number, e
+                );
+                ReviewTimeline::new(pr.opened_at)
+            })
+    }
+
+    /// Check review period requirements, counting only unpaused time
     async fn check_review_period(
         &self,
         pr: &crate::database::models::PullRequest,
         required_days: i64,
     ) -> Result<bool, GovernanceError> {
-        let opened_at = pr.opened_at;
-        Ok(ReviewPeriodValidator::validate_review_period(opened_at, required_days, false).is_ok())
+        let timeline = self.review_timeline(pr).await;
+        Ok(ReviewPeriodValidator::validate_review_period_with_timeline(
+            &timeline,
+            required_days,
+            false,
+        )
+        .is_ok())
     }
 
     /// Generate review period status message
@@ -223,33 +374,58 @@ impl GitHubIntegration {
         pr: &crate::database::models::PullRequest,
         required_days: i64,
     ) -> Result<String, GovernanceError> {
-        let opened_at = pr.opened_at;
-        Ok(StatusCheckGenerator::generate_review_period_status(
-            opened_at,
+        let timeline = self.review_timeline(pr).await;
+        Ok(StatusCheckGenerator::generate_review_clock_status(
+            &timeline,
             required_days,
             false,
+            false,
         ))
     }
 
     /// Check signature requirements
+    ///
+    /// Counts signed approval comments recorded for the PR plus, when a
+    /// maintainer keyring is configured, a PGP-signed head commit.
     async fn check_signatures(
         &self,
-        _pr: &crate::database::models::PullRequest,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        pr: &crate::database::models::PullRequest,
         required: usize,
         total: usize,
     ) -> Result<(bool, String), GovernanceError> {
-        // TODO: Get actual signature count from database
-        let current_signatures = 0; // Placeholder
-        let signers = vec![]; // Placeholder
-        let pending = vec![]; // Placeholder
-
-        let signatures_met = current_signatures >= required;
-        let status = StatusCheckGenerator::generate_signature_status(
-            current_signatures,
+        let mut approvals = SignatureApprovals {
+            comment_signers: pr.signatures.iter().map(|s| s.signer.clone()).collect(),
+            commit_signers: Vec::new(),
+        };
+        let mut rejected_commits = Vec::new();
+
+        if let Some(verifier) = &self.commit_verifier {
+            match self.github_client.get_commit(owner, repo, sha).await {
+                Ok(commit) => {
+                    let check = verifier.verify_commit(&commit);
+                    match &check {
+                        CommitSignatureCheck::Verified { signer, .. } => {
+                            approvals.commit_signers.push(signer.clone());
+                        }
+                        // Unsigned commits are normal; only surface failed signatures
+                        CommitSignatureCheck::Unverified { reason } if reason == "unsigned" => {}
+                        _ => rejected_commits.push(check.status_message(sha)),
+                    }
+                }
+                Err(e) => warn!("Failed to check commit signature for {}: {}", sha, e),
+            }
+        }
+
+        let signatures_met = approvals.count() >= required;
+        let status = StatusCheckGenerator::generate_signature_status_with_sources(
+            &approvals,
             required,
             total,
-            &signers,
-            &pending,
+            &[],
+            &rejected_commits,
         );
 
         Ok((signatures_met, status))
@@ -398,3 +574,39 @@ impl GitHubIntegration {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_reviewers() {
+        let handles: HashMap<String, String> = [
+            ("pk-alice", "alice"),
+            ("pk-bob", "bob"),
+            ("pk-carol", "carol"),
+            ("pk-dave", "dave"),
+            ("pk-erin", "erin"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        // Tier 1 PRs don't trigger review requests
+        assert!(GitHubIntegration::pending_reviewers(&handles, 1, &[]).is_empty());
+
+        // Signers are matched by public key or username
+        let signers = vec!["pk-alice".to_string(), "Bob".to_string()];
+        assert_eq!(
+            GitHubIntegration::pending_reviewers(&handles, 2, &signers),
+            vec!["carol", "dave", "erin"]
+        );
+
+        // Nothing to request once the Tier 2 threshold (4-of-5) is met
+        let signers: Vec<String> = ["alice", "bob", "carol", "dave"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert!(GitHubIntegration::pending_reviewers(&handles, 2, &signers).is_empty());
+    }
+}
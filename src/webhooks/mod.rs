@@ -1,8 +1,13 @@
+pub mod api;
 pub mod block;
+pub mod chain_tips;
 pub mod comment;
+pub mod dedup;
 pub mod github;
 pub mod github_integration;
 pub mod pull_request;
 pub mod push;
+pub mod queue;
 pub mod release;
 pub mod review;
+pub mod signature;
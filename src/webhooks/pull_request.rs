@@ -3,9 +3,15 @@ use tracing::{info, warn};
 
 use crate::config::AppConfig;
 use crate::database::Database;
+use crate::enforcement::decision_log::DecisionLogger;
+use crate::github::client::GitHubClient;
 use crate::nostr::publish_merge_action;
+use crate::validation::pr_title::{PrTitleInfo, TitleValidationError, TITLE_FORMAT_CONTEXT};
+use crate::validation::review_overlap::{self, OverlapWarning};
+use crate::validation::review_period::{self, TimelineEventKind};
 use crate::validation::threshold::ThresholdValidator;
-use crate::validation::tier_classification;
+use crate::validation::tier_classification::{self, TierClassification};
+use crate::webhooks::github_integration::GitHubIntegration;
 
 pub async fn handle_pull_request_event(
     config: &AppConfig,
@@ -54,15 +60,62 @@ pub async fn handle_pull_request_event(
         }
     };
 
-    // Classify PR tier based on file changes (check for override first)
-    let tier = tier_classification::classify_pr_tier_with_db(
+    // GitHub client for fetching changed files and labelling (optional - webhook still
+    // succeeds without it)
+    let github_client = github_client(config);
+    let (owner, repo) = repo_name.split_once('/').unwrap_or((repo_name, ""));
+
+    let title_format = check_title_format(config, github_client.as_ref(), payload).await;
+
+    // Pull request webhooks don't carry the file list, so fetch it if needed
+    let mut changed_files = tier_classification::extract_changed_files(payload);
+    if changed_files.is_empty() {
+        if let Some(client) = &github_client {
+            match client.list_pull_request_files(owner, repo, pr_number).await {
+                Ok(files) => changed_files = files,
+                Err(e) => warn!("Failed to fetch changed files for PR #{}: {}", pr_number, e),
+            }
+        }
+    }
+
+    // Classify PR tier based on file changes and labels (check for override first)
+    let classification = tier_classification::classify_pr_with_db(
         database,
         payload,
+        &changed_files,
         repo_name,
         pr_number as i32,
     )
     .await;
-    info!("PR #{} classified as Tier {}", pr_number, tier);
+    let tier = classification.tier;
+    info!(
+        "PR #{} classified as {}",
+        pr_number, classification.explanation
+    );
+
+    if let Some(client) = &github_client {
+        if let Err(e) = client.set_tier_label(owner, repo, pr_number, tier).await {
+            warn!("Failed to set tier label on PR #{}: {}", pr_number, e);
+        }
+
+        // Ask required maintainers to review newly opened PRs
+        let action = payload.get("action").and_then(|a| a.as_str());
+        if action == Some("opened") {
+            let current_signers = database
+                .get_pull_request(repo_name, pr_number as i32)
+                .await
+                .ok()
+                .flatten()
+                .map(|pr| pr.signatures.into_iter().map(|s| s.signer).collect())
+                .unwrap_or_default();
+            if let Err(e) = github_integration(config, database, client)
+                .request_required_reviews(owner, repo, pr_number, tier, current_signers)
+                .await
+            {
+                warn!("Failed to request reviews on PR #{}: {}", pr_number, e);
+            }
+        }
+    }
 
     // Store PR in database
     match database
@@ -72,6 +125,32 @@ pub async fn handle_pull_request_event(
         Ok(_) => {
             info!("PR #{} stored in database", pr_number);
 
+            let concurrent_reviews = check_concurrent_reviews(
+                database,
+                github_client.as_ref(),
+                payload,
+                pr_number,
+                layer,
+                tier,
+            )
+            .await;
+            let review_reset = check_major_revision(config, database, payload, pr_number).await;
+            store_tier_classification(database, repo_name, pr_number, head_sha, &classification)
+                .await;
+
+            // Post the review period, signature and tier status checks
+            if let Some(client) = &github_client {
+                if let Err(e) = github_integration(config, database, client)
+                    .handle_pr_updated(payload)
+                    .await
+                {
+                    warn!(
+                        "Failed to update status checks for PR #{}: {}",
+                        pr_number, e
+                    );
+                }
+            }
+
             // Log governance event
             let _ = database
                 .log_governance_event(
@@ -116,7 +195,12 @@ pub async fn handle_pull_request_event(
             Ok(axum::response::Json(serde_json::json!({
                 "status": "stored",
                 "tier": tier,
-                "layer": layer
+                "tier_label": tier_classification::tier_label(tier),
+                "tier_classification": classification,
+                "layer": layer,
+                "title_format": title_format_json(&title_format),
+                "concurrent_reviews": concurrent_reviews,
+                "review_period_reset": review_reset
             })))
         }
         Err(e) => {
@@ -126,6 +210,333 @@ pub async fn handle_pull_request_event(
     }
 }
 
+/// Status check and review request integration for a configured client
+fn github_integration(
+    config: &AppConfig,
+    database: &Database,
+    client: &GitHubClient,
+) -> GitHubIntegration {
+    GitHubIntegration::new(
+        client.clone(),
+        database.clone(),
+        DecisionLogger::new(
+            config.dry_run_mode,
+            config.log_enforcement_decisions,
+            config.enforcement_log_path.clone(),
+        ),
+    )
+    .with_maintainer_handles(config.maintainer_github_handles.clone())
+}
+
+/// Persist the PR's tier classification, logging a governance event when the
+/// tier changes (e.g. after a push changes the files touched)
+async fn store_tier_classification(
+    database: &Database,
+    repo_name: &str,
+    pr_number: u64,
+    head_sha: &str,
+    classification: &TierClassification,
+) {
+    let Some(pool) = database.get_sqlite_pool() else {
+        return;
+    };
+    let previous = match tier_classification::record_classification(
+        &pool,
+        repo_name,
+        pr_number as i32,
+        Some(head_sha),
+        classification,
+    )
+    .await
+    {
+        Ok(previous) => previous,
+        Err(e) => {
+            warn!(
+                "Failed to store tier classification for PR #{}: {}",
+                pr_number, e
+            );
+            return;
+        }
+    };
+    if let Some(previous) = previous.filter(|previous| *previous != classification.tier) {
+        info!(
+            "PR #{} in {} reclassified from Tier {} to Tier {}",
+            pr_number, repo_name, previous, classification.tier
+        );
+        let _ = database
+            .log_governance_event(
+                "tier_reclassified",
+                Some(repo_name),
+                Some(pr_number as i32),
+                None,
+                &serde_json::json!({
+                    "previous_tier": previous,
+                    "tier": classification.tier,
+                    "matched_rules": classification.matched_rules,
+                    "explanation": classification.explanation,
+                    "head_sha": head_sha
+                }),
+            )
+            .await;
+    }
+}
+
+/// GitHub client, if an app is configured and its key can be loaded
+fn github_client(config: &AppConfig) -> Option<GitHubClient> {
+    (config.github_app_id != 0)
+        .then(|| GitHubClient::new(config.github_app_id, &config.github_private_key_path))
+        .and_then(|result| {
+            result
+                .map_err(|e| warn!("Failed to create GitHub client: {}", e))
+                .ok()
+        })
+        .map(|client| client.with_execution_mode(config.execution_mode()))
+}
+
+/// Validate the PR title and post the `governance/title-format` status
+///
+/// A non-conforming title gets a failed status whose description is the
+/// expected format.
+pub async fn check_title_format(
+    config: &AppConfig,
+    github_client: Option<&GitHubClient>,
+    payload: &Value,
+) -> Result<PrTitleInfo, TitleValidationError> {
+    let pull_request = payload.get("pull_request");
+    let title = pull_request
+        .and_then(|pr| pr.get("title"))
+        .and_then(|t| t.as_str())
+        .unwrap_or("");
+    let rules = config.governance.pr_title_rules();
+    let result = rules.validate(title);
+
+    let (state, description) = match &result {
+        Ok(info) if info.is_emergency => ("success", "Emergency PR title".to_string()),
+        Ok(info) => (
+            "success",
+            format!("Title format OK (Tier {})", info.tier.unwrap_or_default()),
+        ),
+        Err(e) => {
+            info!("Non-conforming PR title {:?}: {}", title, e);
+            ("failure", format!("Expected {}", rules.expected_format()))
+        }
+    };
+
+    let repo_name = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("");
+    let head_sha = pull_request
+        .and_then(|pr| pr.get("head").and_then(|h| h.get("sha")))
+        .and_then(|s| s.as_str())
+        .unwrap_or("");
+    if let (Some(client), Some((owner, repo))) = (github_client, repo_name.split_once('/')) {
+        if let Err(e) = client
+            .post_status_check(
+                owner,
+                repo,
+                head_sha,
+                state,
+                &description,
+                TITLE_FORMAT_CONTEXT,
+            )
+            .await
+        {
+            warn!("Failed to post title format status: {}", e);
+        }
+    }
+    result
+}
+
+/// Track the PR in `governance_prs` and warn on overlapping Tier 3+ reviews
+///
+/// The warning is commented when the PR is opened or reopened, not on every push.
+async fn check_concurrent_reviews(
+    database: &Database,
+    github_client: Option<&GitHubClient>,
+    payload: &Value,
+    pr_number: u64,
+    layer: i32,
+    tier: u32,
+) -> Vec<OverlapWarning> {
+    let Some(pool) = database.get_sqlite_pool() else {
+        return Vec::new();
+    };
+    let repo_name = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("unknown");
+    let opened_at = payload
+        .get("pull_request")
+        .and_then(|pr| pr.get("created_at"))
+        .and_then(|t| t.as_str())
+        .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| t.with_timezone(&chrono::Utc))
+        .unwrap_or_else(chrono::Utc::now);
+
+    let result = async {
+        let id = review_overlap::record_pr_opened(
+            &pool,
+            repo_name,
+            pr_number as i32,
+            layer,
+            tier,
+            opened_at,
+        )
+        .await?;
+        review_overlap::detect_concurrent_reviews(tier, id, opened_at, &pool).await
+    }
+    .await;
+    let warnings = match result {
+        Ok(warnings) => warnings,
+        Err(e) => {
+            warn!(
+                "Failed to check concurrent reviews for PR #{}: {}",
+                pr_number, e
+            );
+            return Vec::new();
+        }
+    };
+    if warnings.is_empty() {
+        return warnings;
+    }
+
+    info!(
+        "PR #{} in {} overlaps {} other Tier {}+ reviews",
+        pr_number,
+        repo_name,
+        warnings.len(),
+        review_overlap::MIN_OVERLAP_TIER
+    );
+    let action = payload.get("action").and_then(|a| a.as_str());
+    if let (Some(client), Some((owner, repo)), Some("opened" | "reopened")) =
+        (github_client, repo_name.split_once('/'), action)
+    {
+        let comment = review_overlap::overlap_comment(tier, &warnings);
+        if let Err(e) = client
+            .create_issue_comment(owner, repo, pr_number, &comment)
+            .await
+        {
+            warn!(
+                "Failed to post concurrent review warning on PR #{}: {}",
+                pr_number, e
+            );
+        }
+    }
+    warnings
+}
+
+/// Record the PR's diff size and, if enabled, restart its review period when a
+/// push changes the diff substantially; returns whether the period was reset
+async fn check_major_revision(
+    config: &AppConfig,
+    database: &Database,
+    payload: &Value,
+    pr_number: u64,
+) -> bool {
+    let Some(pool) = database.get_sqlite_pool() else {
+        return false;
+    };
+    let repo_name = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("unknown");
+    let diff_lines = ["additions", "deletions"]
+        .iter()
+        .filter_map(|key| {
+            payload
+                .get("pull_request")
+                .and_then(|pr| pr.get(*key))
+                .and_then(|n| n.as_i64())
+        })
+        .sum::<i64>();
+
+    let previous =
+        match review_period::record_diff_size(&pool, repo_name, pr_number as i32, diff_lines).await
+        {
+            Ok(previous) => previous,
+            Err(e) => {
+                warn!("Failed to record diff size for PR #{}: {}", pr_number, e);
+                return false;
+            }
+        };
+    let action = payload.get("action").and_then(|a| a.as_str());
+    let major = action == Some("synchronize")
+        && previous.is_some_and(|previous| review_period::is_major_revision(previous, diff_lines));
+    if !major || !config.governance.reset_review_on_major_revision {
+        return false;
+    }
+
+    info!(
+        "PR #{} in {} revised from {} to {} changed lines; restarting its review period",
+        pr_number,
+        repo_name,
+        previous.unwrap_or_default(),
+        diff_lines
+    );
+    match review_period::record_timeline_event(
+        &pool,
+        repo_name,
+        pr_number as i32,
+        TimelineEventKind::Reset,
+        review_period::MAJOR_REVISION_REASON,
+        chrono::Utc::now(),
+    )
+    .await
+    {
+        Ok(()) => true,
+        Err(e) => {
+            warn!("Failed to reset review period for PR #{}: {}", pr_number, e);
+            false
+        }
+    }
+}
+
+/// Stop counting a closed PR's review period toward overlaps
+pub async fn handle_pr_closed(database: &Database, payload: &Value) {
+    let Some(pool) = database.get_sqlite_pool() else {
+        return;
+    };
+    let repo_name = payload
+        .get("repository")
+        .and_then(|r| r.get("full_name"))
+        .and_then(|n| n.as_str())
+        .unwrap_or("unknown");
+    let pr_number = payload
+        .get("pull_request")
+        .and_then(|pr| pr.get("number"))
+        .and_then(|n| n.as_u64())
+        .unwrap_or(0);
+    if let Err(e) =
+        review_overlap::record_pr_closed(&pool, repo_name, pr_number as i32, chrono::Utc::now())
+            .await
+    {
+        warn!("Failed to record PR #{} as closed: {}", pr_number, e);
+    }
+}
+
+/// Re-check the title format after a PR is edited
+pub async fn handle_pull_request_edited(
+    config: &AppConfig,
+    payload: &Value,
+) -> axum::response::Json<serde_json::Value> {
+    let result = check_title_format(config, github_client(config).as_ref(), payload).await;
+    axum::response::Json(serde_json::json!({
+        "status": "processed",
+        "title_format": title_format_json(&result)
+    }))
+}
+
+fn title_format_json(result: &Result<PrTitleInfo, TitleValidationError>) -> serde_json::Value {
+    match result {
+        Ok(info) => serde_json::json!({ "valid": true, "title": info }),
+        Err(e) => serde_json::json!({ "valid": false, "error": e.to_string() }),
+    }
+}
+
 /// Handle PR merge event - publish to Nostr
 pub async fn handle_pr_merged(
     config: &AppConfig,
@@ -161,12 +572,29 @@ pub async fn handle_pr_merged(
     if let Some(pr) = pr_info {
         let layer = pr.layer;
 
-        // Get tier from database or re-classify
-        // For now, we'll need to get it from the PR details or re-classify
-        // This is a simplified version - in practice, tier should be stored with PR
-        let tier =
-            tier_classification::classify_pr_tier_with_db(database, payload, repo_name, pr_number)
-                .await;
+        // Use the stored classification, re-classifying if there is none
+        let stored = match &database.get_sqlite_pool() {
+            Some(pool) => tier_classification::load_classification(pool, repo_name, pr_number)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Failed to load tier classification: {}", e);
+                    None
+                }),
+            None => None,
+        };
+        let tier = match stored {
+            Some(stored) => stored.classification.tier,
+            None => {
+                tier_classification::classify_pr_tier_with_db(
+                    database,
+                    payload,
+                    &tier_classification::extract_changed_files(payload),
+                    repo_name,
+                    pr_number,
+                )
+                .await
+            }
+        };
 
         // Publish merge action to Nostr
         publish_merge_action(
@@ -218,6 +646,70 @@ pub fn determine_layer(repo_name: &str) -> Option<i32> {
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_check_title_format_uses_configured_rules() {
+        let payload = serde_json::json!({
+            "repository": { "full_name": "BTCDecoded/blvm-consensus" },
+            "pull_request": { "title": "[Tier 4] Tighten signature rules", "head": { "sha": "abc" } }
+        });
+        let mut config = AppConfig::default();
+        assert_eq!(
+            check_title_format(&config, None, &payload)
+                .await
+                .unwrap()
+                .tier,
+            Some(4)
+        );
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("pr-title-rules.yml");
+        std::fs::write(&path, "allowed_tiers: [1, 2, 3]\n").unwrap();
+        config.governance.pr_title_rules_path = Some(path.to_string_lossy().into_owned());
+        assert!(matches!(
+            check_title_format(&config, None, &payload).await,
+            Err(TitleValidationError::UnknownTier { .. })
+        ));
+
+        // The file is read once; later edits need a restart
+        std::fs::write(&path, "allowed_tiers: [4]\n").unwrap();
+        assert!(matches!(
+            check_title_format(&config, None, &payload).await,
+            Err(TitleValidationError::UnknownTier { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_major_revision_resets_review_period_when_enabled() {
+        let database = Database::new_in_memory().await.unwrap();
+        let pool = &database.get_sqlite_pool().unwrap();
+        let repo = "BTCDecoded/blvm-consensus";
+        review_overlap::record_pr_opened(pool, repo, 9, 2, 3, chrono::Utc::now())
+            .await
+            .unwrap();
+        let push = |action: &str, additions: i64| {
+            serde_json::json!({
+                "action": action,
+                "repository": { "full_name": repo },
+                "pull_request": { "number": 9, "additions": additions, "deletions": 20 }
+            })
+        };
+
+        let mut config = AppConfig::default();
+        assert!(!check_major_revision(&config, &database, &push("opened", 100), 9).await);
+        // Major, but resetting is disabled
+        assert!(!check_major_revision(&config, &database, &push("synchronize", 400), 9).await);
+
+        config.governance.reset_review_on_major_revision = true;
+        assert!(!check_major_revision(&config, &database, &push("synchronize", 420), 9).await);
+        assert!(check_major_revision(&config, &database, &push("synchronize", 900), 9).await);
+
+        let timeline = review_period::load_timeline(pool, repo, 9, chrono::Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(timeline.events.len(), 1);
+        assert_eq!(timeline.events[0].kind, TimelineEventKind::Reset);
+    }
+
     #[test]
     fn test_determine_layer_spec() {
         assert_eq!(determine_layer("BTCDecoded/blvm-spec"), Some(1));
@@ -0,0 +1,435 @@
+//! Persisted GitHub webhook event queue
+//!
+//! The webhook handler stores each verified event and returns 202; a worker
+//! processes events oldest first. Failures are retried with exponential backoff
+//! and dead-lettered after `MAX_ATTEMPTS`. Events left `processing` by a crash
+//! are returned to `pending` when the worker starts.
+
+use axum::response::Json;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, SqlitePool};
+use std::future::Future;
+use tracing::{error, info, warn};
+
+use crate::config::AppConfig;
+use crate::database::Database;
+use crate::shutdown::{self, Shutdown};
+use crate::webhooks::github::process_event;
+
+pub const STATUS_PENDING: &str = "pending";
+pub const STATUS_PROCESSING: &str = "processing";
+pub const STATUS_DONE: &str = "done";
+pub const STATUS_DEAD: &str = "dead";
+
+/// Attempts before an event is dead-lettered
+pub const MAX_ATTEMPTS: i64 = 5;
+
+/// How often the worker polls for due events when the queue is idle
+pub const WORKER_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Delay before the first retry; doubles with each further attempt
+const RETRY_BASE_DELAY_SECS: i64 = 30;
+
+/// Longest delay between retries
+const RETRY_MAX_DELAY_SECS: i64 = 3600;
+
+/// Queued webhook event
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct WebhookEvent {
+    pub id: i64,
+    pub delivery_id: String,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i64,
+    pub last_error: Option<String>,
+    pub received_at: DateTime<Utc>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub processed_at: Option<DateTime<Utc>>,
+}
+
+/// Queue depth for /status
+#[derive(Debug, Clone, Serialize)]
+pub struct QueueStats {
+    /// Events waiting to be processed (pending or in progress)
+    pub depth: i64,
+    pub dead: i64,
+    pub oldest_pending_age_secs: Option<i64>,
+}
+
+/// Delay before retrying an event that has failed `attempts` times
+pub fn retry_delay(attempts: i64) -> Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 20) as u32;
+    Duration::seconds(
+        RETRY_BASE_DELAY_SECS
+            .saturating_mul(2i64.saturating_pow(exponent))
+            .min(RETRY_MAX_DELAY_SECS),
+    )
+}
+
+/// Webhook event queue backed by the `webhook_events` table
+#[derive(Clone)]
+pub struct WebhookQueue {
+    pool: SqlitePool,
+}
+
+impl WebhookQueue {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Store an event; returns its ID, or None if the delivery is already queued
+    pub async fn enqueue(
+        &self,
+        delivery_id: &str,
+        event_type: &str,
+        payload: &serde_json::Value,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        let now = Utc::now();
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO webhook_events
+                (delivery_id, event_type, payload, status, received_at, next_attempt_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(delivery_id)
+        .bind(event_type)
+        .bind(payload)
+        .bind(STATUS_PENDING)
+        .bind(now)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok((result.rows_affected() == 1).then(|| result.last_insert_rowid()))
+    }
+
+    pub async fn get_event(&self, id: i64) -> Result<Option<WebhookEvent>, sqlx::Error> {
+        sqlx::query_as::<_, WebhookEvent>("SELECT * FROM webhook_events WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+    }
+
+    /// Claim the oldest event that is due, marking it `processing`
+    pub async fn claim_next(&self) -> Result<Option<WebhookEvent>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+        let id: Option<i64> = sqlx::query_scalar(
+            r#"
+            SELECT id FROM webhook_events
+            WHERE status = ? AND next_attempt_at <= ?
+            ORDER BY id ASC
+            LIMIT 1
+            "#,
+        )
+        .bind(STATUS_PENDING)
+        .bind(Utc::now())
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(id) = id else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            "UPDATE webhook_events SET status = ?, attempts = attempts + 1 WHERE id = ? AND status = ?",
+        )
+        .bind(STATUS_PROCESSING)
+        .bind(id)
+        .bind(STATUS_PENDING)
+        .execute(&mut *tx)
+        .await?;
+        let event = sqlx::query_as::<_, WebhookEvent>("SELECT * FROM webhook_events WHERE id = ?")
+            .bind(id)
+            .fetch_one(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        Ok(Some(event))
+    }
+
+    pub async fn mark_done(&self, id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE webhook_events SET status = ?, last_error = NULL, processed_at = ? WHERE id = ?",
+        )
+        .bind(STATUS_DONE)
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Record a failed attempt; schedules a retry, or dead-letters the event
+    /// once it has used `MAX_ATTEMPTS`. Returns the new status.
+    pub async fn mark_failed(&self, id: i64, error: &str) -> Result<&'static str, sqlx::Error> {
+        let attempts: i64 = sqlx::query_scalar("SELECT attempts FROM webhook_events WHERE id = ?")
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await?;
+        let status = if attempts >= MAX_ATTEMPTS {
+            STATUS_DEAD
+        } else {
+            STATUS_PENDING
+        };
+
+        sqlx::query(
+            "UPDATE webhook_events SET status = ?, last_error = ?, next_attempt_at = ? WHERE id = ?",
+        )
+        .bind(status)
+        .bind(error)
+        .bind(Utc::now() + retry_delay(attempts))
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+        Ok(status)
+    }
+
+    /// Return events left `processing` by a crashed worker to the queue
+    pub async fn recover_interrupted(&self) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query("UPDATE webhook_events SET status = ? WHERE status = ?")
+            .bind(STATUS_PENDING)
+            .bind(STATUS_PROCESSING)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Dead-lettered events, oldest first
+    pub async fn list_failed(&self) -> Result<Vec<WebhookEvent>, sqlx::Error> {
+        sqlx::query_as::<_, WebhookEvent>(
+            "SELECT * FROM webhook_events WHERE status = ? ORDER BY id ASC",
+        )
+        .bind(STATUS_DEAD)
+        .fetch_all(&self.pool)
+        .await
+    }
+
+    /// Put a dead-lettered event back on the queue with a fresh attempt budget
+    pub async fn requeue(&self, id: i64) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE webhook_events
+            SET status = ?, attempts = 0, next_attempt_at = ?
+            WHERE id = ? AND status = ?
+            "#,
+        )
+        .bind(STATUS_PENDING)
+        .bind(Utc::now())
+        .bind(id)
+        .bind(STATUS_DEAD)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    pub async fn stats(&self) -> Result<QueueStats, sqlx::Error> {
+        let (depth, dead, oldest_pending): (i64, i64, Option<DateTime<Utc>>) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE status IN ('pending', 'processing')),
+                COUNT(*) FILTER (WHERE status = 'dead'),
+                MIN(received_at) FILTER (WHERE status IN ('pending', 'processing'))
+            FROM webhook_events
+            "#,
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(QueueStats {
+            depth,
+            dead,
+            oldest_pending_age_secs: oldest_pending
+                .map(|received_at| (Utc::now() - received_at).num_seconds()),
+        })
+    }
+
+    /// Process the next due event with `process`; returns false if none was due
+    pub async fn process_next<F, Fut>(&self, process: F) -> Result<bool, sqlx::Error>
+    where
+        F: FnOnce(WebhookEvent) -> Fut,
+        Fut: Future<Output = Result<(), String>>,
+    {
+        let Some(event) = self.claim_next().await? else {
+            return Ok(false);
+        };
+
+        let id = event.id;
+        match process(event).await {
+            Ok(()) => self.mark_done(id).await?,
+            Err(e) => {
+                self.mark_failed(id, &e).await?;
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Start the background worker that processes queued webhook events
+///
+/// On shutdown the event being processed is finished; anything still pending
+/// stays queued for the next start.
+pub fn spawn_worker(
+    queue: WebhookQueue,
+    config: AppConfig,
+    database: Database,
+    shutdown: &Shutdown,
+) {
+    shutdown.spawn("webhook_queue", |token| async move {
+        match queue.recover_interrupted().await {
+            Ok(0) => {}
+            Ok(recovered) => info!("Requeued {} interrupted webhook event(s)", recovered),
+            Err(e) => error!("Failed to recover interrupted webhook events: {}", e),
+        }
+
+        let (config, database) = (&config, &database);
+        while !token.is_cancelled() {
+            let processed = queue
+                .process_next(|event| async move {
+                    let (status, Json(response)) =
+                        process_event(config, database, &event.event_type, &event.payload).await;
+                    if status.is_success() {
+                        Ok(())
+                    } else {
+                        warn!(
+                            "Webhook event {} ({}) failed with {}",
+                            event.id, event.delivery_id, status
+                        );
+                        Err(format!("{}: {}", status, response))
+                    }
+                })
+                .await;
+
+            match processed {
+                Ok(true) => {}
+                Ok(false) => {
+                    shutdown::sleep(&token, WORKER_POLL_INTERVAL).await;
+                }
+                Err(e) => {
+                    error!("Webhook queue error: {}", e);
+                    shutdown::sleep(&token, WORKER_POLL_INTERVAL).await;
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::Database;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    async fn queue() -> (Database, WebhookQueue) {
+        let database = Database::new_in_memory().await.unwrap();
+        let queue = WebhookQueue::new(database.get_sqlite_pool().unwrap());
+        (database, queue)
+    }
+
+    #[test]
+    fn test_retry_delay_backoff() {
+        assert_eq!(retry_delay(1), Duration::seconds(30));
+        assert_eq!(retry_delay(2), Duration::seconds(60));
+        assert_eq!(retry_delay(4), Duration::seconds(240));
+        assert_eq!(retry_delay(30), Duration::seconds(RETRY_MAX_DELAY_SECS));
+    }
+
+    #[tokio::test]
+    async fn test_crash_recovery_processes_each_event_once() {
+        let (_database, queue) = queue().await;
+        for delivery in ["d-1", "d-2", "d-3"] {
+            let payload = serde_json::json!({ "delivery": delivery });
+            assert!(queue
+                .enqueue(delivery, "pull_request", &payload)
+                .await
+                .unwrap()
+                .is_some());
+        }
+        // Redelivery of a stored event doesn't queue it twice
+        assert!(queue
+            .enqueue("d-1", "pull_request", &serde_json::json!({}))
+            .await
+            .unwrap()
+            .is_none());
+
+        // The worker crashes after claiming d-1 but before processing it
+        let claimed = queue.claim_next().await.unwrap().unwrap();
+        assert_eq!(claimed.delivery_id, "d-1");
+        assert_eq!(queue.stats().await.unwrap().depth, 3);
+        assert_eq!(queue.recover_interrupted().await.unwrap(), 1);
+
+        let processed = Arc::new(Mutex::new(Vec::new()));
+        loop {
+            let processed = processed.clone();
+            let more = queue
+                .process_next(|event| async move {
+                    processed.lock().unwrap().push(event.delivery_id);
+                    Ok(())
+                })
+                .await
+                .unwrap();
+            if !more {
+                break;
+            }
+        }
+
+        // In order, each exactly once, and nothing left to do
+        assert_eq!(*processed.lock().unwrap(), vec!["d-1", "d-2", "d-3"]);
+        let stats = queue.stats().await.unwrap();
+        assert_eq!(stats.depth, 0);
+        assert_eq!(stats.oldest_pending_age_secs, None);
+        assert!(!queue.process_next(|_| async { Ok(()) }).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_failures_retry_then_dead_letter() {
+        let (_database, queue) = queue().await;
+        let id = queue
+            .enqueue("d-1", "issue_comment", &serde_json::json!({}))
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut statuses = HashMap::new();
+        for attempt in 1..=MAX_ATTEMPTS {
+            // Make the retry due immediately
+            sqlx::query("UPDATE webhook_events SET next_attempt_at = ? WHERE id = ?")
+                .bind(Utc::now() - Duration::seconds(1))
+                .bind(id)
+                .execute(&queue.pool)
+                .await
+                .unwrap();
+            assert!(queue
+                .process_next(|_| async { Err("database unavailable".to_string()) })
+                .await
+                .unwrap());
+            let event = queue.get_event(id).await.unwrap().unwrap();
+            assert_eq!(event.attempts, attempt);
+            statuses.insert(attempt, event.status);
+
+            // The retry isn't due until its backoff has passed
+            if attempt == 1 {
+                assert!(!queue.process_next(|_| async { Ok(()) }).await.unwrap());
+            }
+        }
+        assert_eq!(statuses[&1], STATUS_PENDING);
+        assert_eq!(statuses[&MAX_ATTEMPTS], STATUS_DEAD);
+
+        let failed = queue.list_failed().await.unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(
+            failed[0].last_error.as_deref(),
+            Some("database unavailable")
+        );
+        assert_eq!(queue.stats().await.unwrap().dead, 1);
+
+        assert!(queue.requeue(id).await.unwrap());
+        assert!(!queue.requeue(id).await.unwrap());
+        assert!(queue.process_next(|_| async { Ok(()) }).await.unwrap());
+        let event = queue.get_event(id).await.unwrap().unwrap();
+        assert_eq!(event.status, STATUS_DONE);
+        assert_eq!(event.attempts, 1);
+    }
+}
@@ -2,6 +2,7 @@ use serde_json::Value;
 use tracing::{info, warn};
 
 use crate::database::Database;
+use crate::validation::review_period::{self, TimelineEventKind};
 
 pub async fn handle_review_event(
     database: &Database,
@@ -37,6 +38,8 @@ pub async fn handle_review_event(
         state, reviewer, pr_number, repo_name
     );
 
+    record_review_timeline(database, payload, repo_name, pr_number, reviewer, state).await;
+
     // Update review status in database
     match database
         .update_review_status(repo_name, pr_number as i32, reviewer, state)
@@ -55,6 +58,77 @@ pub async fn handle_review_event(
     }
 }
 
+/// Whether a review's `author_association` gives its author a say in the
+/// review period: repository owners, organization members and collaborators
+pub fn can_pause_review(author_association: &str) -> bool {
+    matches!(author_association, "OWNER" | "MEMBER" | "COLLABORATOR")
+}
+
+/// Timeline event for a review: a maintainer requesting changes pauses the
+/// review period until they approve or their review is dismissed
+///
+/// Reviews from anyone else (e.g. drive-by accounts) don't affect the timeline.
+pub fn review_timeline_event(
+    state: &str,
+    reviewer: &str,
+    author_association: &str,
+) -> Option<(TimelineEventKind, String)> {
+    if !can_pause_review(author_association) {
+        return None;
+    }
+    let kind = match state {
+        "changes_requested" => TimelineEventKind::Pause,
+        "approved" | "dismissed" => TimelineEventKind::Resume,
+        _ => return None,
+    };
+    Some((kind, format!("changes_requested:{}", reviewer)))
+}
+
+async fn record_review_timeline(
+    database: &Database,
+    payload: &Value,
+    repo_name: &str,
+    pr_number: u64,
+    reviewer: &str,
+    state: &str,
+) {
+    let author_association = payload
+        .get("review")
+        .and_then(|r| r.get("author_association"))
+        .and_then(|a| a.as_str())
+        .unwrap_or("NONE");
+    let (Some(pool), Some((kind, reason))) = (
+        database.get_sqlite_pool(),
+        review_timeline_event(state, reviewer, author_association),
+    ) else {
+        return;
+    };
+    let occurred_at = payload
+        .get("review")
+        .and_then(|r| r.get("submitted_at"))
+        .and_then(|t| t.as_str())
+        .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| t.with_timezone(&chrono::Utc))
+        .filter(|_| state != "dismissed")
+        .unwrap_or_else(chrono::Utc::now);
+
+    if let Err(e) = review_period::record_timeline_event(
+        &pool,
+        repo_name,
+        pr_number as i32,
+        kind,
+        &reason,
+        occurred_at,
+    )
+    .await
+    {
+        warn!(
+            "Failed to record review timeline event for PR #{}: {}",
+            pr_number, e
+        );
+    }
+}
+
 /// Check if review state is valid
 pub fn is_valid_review_state(state: &str) -> bool {
     matches!(
@@ -87,6 +161,33 @@ mod tests {
         assert!(is_valid_review_state("dismissed"));
     }
 
+    #[test]
+    fn test_review_timeline_event() {
+        assert_eq!(
+            review_timeline_event("changes_requested", "alice", "MEMBER"),
+            Some((
+                TimelineEventKind::Pause,
+                "changes_requested:alice".to_string()
+            ))
+        );
+        assert_eq!(
+            review_timeline_event("dismissed", "alice", "OWNER").map(|(kind, _)| kind),
+            Some(TimelineEventKind::Resume)
+        );
+        assert_eq!(review_timeline_event("commented", "alice", "MEMBER"), None);
+    }
+
+    #[test]
+    fn test_outside_reviews_do_not_pause() {
+        assert!(review_timeline_event("changes_requested", "bob", "COLLABORATOR").is_some());
+        for association in ["CONTRIBUTOR", "FIRST_TIME_CONTRIBUTOR", "NONE", ""] {
+            assert_eq!(
+                review_timeline_event("changes_requested", "mallory", association),
+                None
+            );
+        }
+    }
+
     #[test]
     fn test_is_valid_review_state_invalid() {
         assert!(!is_valid_review_state("invalid"));
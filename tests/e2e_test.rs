@@ -31,7 +31,7 @@ async fn test_tier_1_routine_approval_flow() -> Result<(), Box<dyn std::error::E
 
     // Setup
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool.clone());
     let veto_manager = VetoManager::new(pool);
 
@@ -103,7 +103,7 @@ async fn test_tier_3_economic_node_veto_scenario() -> Result<(), Box<dyn std::er
 
     // Setup
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool.clone());
     let veto_manager = VetoManager::new(pool);
 
@@ -213,7 +213,7 @@ async fn test_tier_3_economic_node_veto_scenario() -> Result<(), Box<dyn std::er
     let mining_pubkey_hex = hex::encode(mining_keypair.public_key().to_bytes());
     let exchange_pubkey_hex = hex::encode(exchange_keypair.public_key().to_bytes());
 
-    let pool = db.pool().expect("Database should have SQLite pool");
+    let pool = &db.pool().expect("Database should have SQLite pool");
     sqlx::query("UPDATE economic_nodes SET public_key = ? WHERE id = ?")
         .bind(&mining_pubkey_hex)
         .bind(mining_node_id)
@@ -379,7 +379,7 @@ async fn test_tier_5_governance_change_with_fork() -> Result<(), Box<dyn std::er
     // Setup
     let db = Database::new_in_memory().await?;
     let versioning = RulesetVersioning::new();
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let tracker = AdoptionTracker::new(pool);
 
     // 1. Create governance change PR
@@ -465,7 +465,7 @@ tiers:
     println!("✅ New governance ruleset created: {}", new_ruleset.id);
 
     // Ensure governance_rulesets table exists (migration might not have run)
-    let pool = db.pool().expect("Database should have SQLite pool");
+    let pool = &db.pool().expect("Database should have SQLite pool");
     let table_exists: bool = sqlx::query_scalar(
         "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name='governance_rulesets')"
     )
@@ -680,7 +680,7 @@ async fn test_complete_governance_lifecycle() -> Result<(), Box<dyn std::error::
 
     // Setup
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool.clone());
     let veto_manager = VetoManager::new(pool);
 
@@ -860,7 +860,7 @@ async fn test_complete_governance_lifecycle() -> Result<(), Box<dyn std::error::
 
     // 3. Test governance fork scenario
     let versioning = RulesetVersioning::new();
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let tracker = AdoptionTracker::new(pool);
 
     // Create ruleset
@@ -884,7 +884,7 @@ async fn test_complete_governance_lifecycle() -> Result<(), Box<dyn std::error::
     )?;
 
     // Ensure governance_rulesets table exists (migration might not have run)
-    let pool_for_ruleset = db.pool().expect("Database should have SQLite pool");
+    let pool_for_ruleset = &db.pool().expect("Database should have SQLite pool");
     let table_exists: bool = sqlx::query_scalar(
         "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type='table' AND name='governance_rulesets')"
     )
@@ -1062,7 +1062,7 @@ async fn test_error_handling_and_edge_cases() -> Result<(), Box<dyn std::error::
     println!("🧪 Testing error handling and edge cases...");
 
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool.clone());
     let veto_manager = VetoManager::new(pool);
 
@@ -1212,7 +1212,7 @@ async fn test_error_handling_and_edge_cases() -> Result<(), Box<dyn std::error::
     let public_key = keypair.public_key().to_string();
 
     // Update the node's public key to match the generated keypair
-    let pool = db.pool().expect("Database should have SQLite pool");
+    let pool = &db.pool().expect("Database should have SQLite pool");
     sqlx::query("UPDATE economic_nodes SET public_key = ? WHERE id = ?")
         .bind(&public_key)
         .bind(node_id)
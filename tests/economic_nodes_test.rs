@@ -15,7 +15,7 @@ use sqlx::SqlitePool;
 async fn test_economic_node_registration() -> Result<(), Box<dyn std::error::Error>> {
     // Setup in-memory database
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool);
 
     // Test mining pool registration
@@ -127,7 +127,7 @@ async fn test_economic_node_registration() -> Result<(), Box<dyn std::error::Err
 #[tokio::test]
 async fn test_qualification_verification() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool);
 
     // Test insufficient mining pool qualification
@@ -206,7 +206,7 @@ async fn test_qualification_verification() -> Result<(), Box<dyn std::error::Err
 #[tokio::test]
 async fn test_weight_calculation() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool);
 
     // Test mining pool weight calculation
@@ -273,7 +273,7 @@ async fn test_weight_calculation() -> Result<(), Box<dyn std::error::Error>> {
 #[tokio::test]
 async fn test_veto_signal_collection() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool.clone());
     let veto_manager = VetoManager::new(pool);
 
@@ -318,7 +318,7 @@ async fn test_veto_signal_collection() -> Result<(), Box<dyn std::error::Error>>
     let public_key = keypair.public_key().to_string();
 
     // Update the node's public key to match the generated keypair
-    let pool = db.pool().expect("Database should have SQLite pool");
+    let pool = &db.pool().expect("Database should have SQLite pool");
     sqlx::query("UPDATE economic_nodes SET public_key = ? WHERE id = ?")
         .bind(&public_key)
         .bind(node_id)
@@ -365,7 +365,7 @@ async fn test_veto_signal_collection() -> Result<(), Box<dyn std::error::Error>>
 #[tokio::test]
 async fn test_veto_threshold_calculation() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool.clone());
     let veto_manager = VetoManager::new(pool);
 
@@ -448,7 +448,7 @@ async fn test_veto_threshold_calculation() -> Result<(), Box<dyn std::error::Err
     let exchange_public_key = exchange_keypair.public_key().to_string();
 
     // Update nodes' public keys to match generated keypairs
-    let pool = db.pool().expect("Database should have SQLite pool");
+    let pool = &db.pool().expect("Database should have SQLite pool");
     sqlx::query("UPDATE economic_nodes SET public_key = ? WHERE id = ?")
         .bind(&mining_public_key)
         .bind(mining_node_id)
@@ -504,7 +504,7 @@ async fn test_veto_threshold_calculation() -> Result<(), Box<dyn std::error::Err
 #[tokio::test]
 async fn test_node_status_management() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool);
 
     // Register a node
@@ -563,7 +563,7 @@ async fn test_node_status_management() -> Result<(), Box<dyn std::error::Error>>
 #[tokio::test]
 async fn test_weight_recalculation() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool);
 
     // Register multiple nodes
@@ -635,7 +635,7 @@ async fn test_weight_recalculation() -> Result<(), Box<dyn std::error::Error>> {
 #[tokio::test]
 async fn test_veto_statistics() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
     let registry = EconomicNodeRegistry::new(pool.clone());
     let veto_manager = VetoManager::new(pool);
 
@@ -718,7 +718,7 @@ async fn test_veto_statistics() -> Result<(), Box<dyn std::error::Error>> {
     let exchange_public_key = exchange_keypair.public_key().to_string();
 
     // Update nodes' public keys to match generated keypairs
-    let pool = db.pool().expect("Database should have SQLite pool");
+    let pool = &db.pool().expect("Database should have SQLite pool");
     sqlx::query("UPDATE economic_nodes SET public_key = ? WHERE id = ?")
         .bind(&mining_public_key)
         .bind(mining_node_id)
@@ -202,7 +202,7 @@ async fn test_ruleset_versioning() -> Result<(), Box<dyn std::error::Error>> {
 #[tokio::test]
 async fn test_adoption_tracking() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
 
     // Enable foreign key constraints first
     sqlx::query("PRAGMA foreign_keys = ON")
@@ -503,7 +503,7 @@ async fn test_ruleset_status_update() -> Result<(), Box<dyn std::error::Error>>
 #[tokio::test]
 async fn test_adoption_history() -> Result<(), Box<dyn std::error::Error>> {
     let db = Database::new_in_memory().await?;
-    let pool = db.pool().expect("Database should have SQLite pool").clone();
+    let pool = db.pool().expect("Database should have SQLite pool");
 
     // Enable foreign key constraints first
     sqlx::query("PRAGMA foreign_keys = ON")
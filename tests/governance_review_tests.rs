@@ -1,9 +1,11 @@
 //! Tests for governance review system
 
+use blvm_commons::config::GovernanceReviewConfig;
+use blvm_commons::governance_review::lifecycle::{Case, Closed, Draft, Open};
 use blvm_commons::governance_review::{
     get_database_url, get_github_token, get_governance_repo, is_github_actions, AppealManager,
-    GovernanceReviewCaseManager, MediationManager, RemovalManager, SanctionManager,
-    TimeLimitManager,
+    DeadlineNotificationManager, DeadlineType, GovernanceReviewCaseManager, MediationManager,
+    NotificationLevel, ProtectionManager, RemovalManager, SanctionManager, TimeLimitManager,
 };
 use chrono::{Duration, Utc};
 use sqlx::SqlitePool;
@@ -342,3 +344,534 @@ async fn test_removal_deactivation() {
     // Check maintainer is now inactive
     assert!(!removal_manager.is_maintainer_active(1).await.unwrap());
 }
+
+#[tokio::test]
+async fn test_case_lifecycle_transitions() {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS governance_review_cases (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_number TEXT UNIQUE NOT NULL,
+            subject_maintainer_id INTEGER NOT NULL,
+            reporter_maintainer_id INTEGER NOT NULL,
+            case_type TEXT NOT NULL,
+            severity TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'open',
+            description TEXT NOT NULL,
+            evidence TEXT NOT NULL DEFAULT '{}',
+            on_platform BOOLEAN NOT NULL DEFAULT true,
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            response_deadline TEXT,
+            resolution_deadline TEXT,
+            resolved_at TEXT,
+            resolution_reason TEXT,
+            github_issue_number INTEGER
+        );
+        CREATE TABLE IF NOT EXISTS governance_review_time_limits (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_id INTEGER NOT NULL,
+            limit_type TEXT NOT NULL,
+            deadline TEXT NOT NULL,
+            extended BOOLEAN DEFAULT false,
+            extension_approved_by INTEGER,
+            extension_reason TEXT,
+            extension_until TEXT
+        );
+        CREATE TABLE IF NOT EXISTS case_transitions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_id INTEGER NOT NULL,
+            old_state TEXT NOT NULL,
+            new_state TEXT NOT NULL,
+            transitioned_by TEXT NOT NULL,
+            reason TEXT,
+            transitioned_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let draft = Case::<Draft>::draft(
+        pool.clone(),
+        1,
+        2,
+        "technical_errors",
+        "moderate",
+        "Repeatedly merged without review",
+        serde_json::json!({}),
+        true,
+    );
+    let case = draft
+        .open("reporter", "Reported via comment")
+        .await
+        .unwrap();
+    assert_eq!(case.record().status, "open");
+    assert!(case.record().response_deadline.is_some());
+    let case_id = case.id();
+
+    let case = case
+        .request_response("team", "Subject asked to respond")
+        .await
+        .unwrap();
+    assert_eq!(case.record().status, "under_review");
+
+    // Loading enforces the stored state
+    assert!(Case::<Open>::load(pool.clone(), case_id).await.is_err());
+
+    let case = case
+        .begin_mediation("team", "Both parties agreed to mediation")
+        .await
+        .unwrap();
+    let case = case
+        .close("mediator", "Resolved in mediation")
+        .await
+        .unwrap();
+    assert_eq!(case.record().status, "resolved");
+    assert_eq!(
+        case.record().resolution_reason.as_deref(),
+        Some("Resolved in mediation")
+    );
+    assert!(case.record().resolved_at.is_some());
+
+    let case = Case::<Closed>::load(pool.clone(), case_id).await.unwrap();
+    let case = case.appeal("subject", "New evidence").await.unwrap();
+    assert_eq!(case.record().status, "appealed");
+    let case = case.close("teams", "Appeal denied").await.unwrap();
+
+    let transitions = case.transitions().await.unwrap();
+    let states: Vec<(&str, &str)> = transitions
+        .iter()
+        .map(|t| (t.old_state.as_str(), t.new_state.as_str()))
+        .collect();
+    assert_eq!(
+        states,
+        vec![
+            ("draft", "open"),
+            ("open", "under_review"),
+            ("under_review", "mediation"),
+            ("mediation", "resolved"),
+            ("resolved", "appealed"),
+            ("appealed", "resolved"),
+        ]
+    );
+    assert_eq!(transitions[4].transitioned_by, "subject");
+    assert_eq!(transitions[4].reason.as_deref(), Some("New evidence"));
+}
+
+#[tokio::test]
+async fn test_deadline_reminders_pending_and_urgent() {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS maintainers (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            github_username TEXT NOT NULL UNIQUE,
+            public_key TEXT NOT NULL,
+            layer INTEGER NOT NULL,
+            active BOOLEAN DEFAULT true,
+            last_updated TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE TABLE IF NOT EXISTS governance_review_cases (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_number TEXT UNIQUE NOT NULL,
+            subject_maintainer_id INTEGER NOT NULL,
+            reporter_maintainer_id INTEGER NOT NULL,
+            case_type TEXT NOT NULL,
+            severity TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'open',
+            description TEXT NOT NULL,
+            evidence TEXT NOT NULL DEFAULT '{}',
+            on_platform BOOLEAN NOT NULL DEFAULT true,
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            response_deadline TEXT,
+            resolution_deadline TEXT,
+            resolved_at TEXT,
+            resolution_reason TEXT,
+            github_issue_number INTEGER
+        );
+        CREATE TABLE IF NOT EXISTS governance_review_responses (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_id INTEGER NOT NULL,
+            maintainer_id INTEGER NOT NULL,
+            response_text TEXT NOT NULL,
+            counter_evidence TEXT DEFAULT '{}',
+            submitted_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE TABLE IF NOT EXISTS governance_review_appeals (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_id INTEGER NOT NULL,
+            maintainer_id INTEGER NOT NULL,
+            appeal_reason TEXT NOT NULL,
+            new_evidence TEXT DEFAULT '{}',
+            submitted_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            appeal_deadline TEXT,
+            status TEXT NOT NULL DEFAULT 'pending',
+            reviewed_at TEXT,
+            review_decision TEXT,
+            teams_approval_count INTEGER
+        );
+        CREATE TABLE IF NOT EXISTS deadline_notifications_sent (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            deadline_type TEXT NOT NULL,
+            record_id INTEGER NOT NULL,
+            maintainer_id INTEGER NOT NULL,
+            level TEXT NOT NULL,
+            deadline TEXT NOT NULL,
+            sent_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(deadline_type, record_id, maintainer_id, level, deadline)
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    for username in ["subject", "reporter"] {
+        sqlx::query(
+            "INSERT INTO maintainers (github_username, public_key, layer) VALUES (?, 'key', 1)",
+        )
+        .bind(username)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    let now = Utc::now();
+    let insert_case = |case_number: &'static str, response_hours: i64, resolution_hours: i64| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query(
+                "INSERT INTO governance_review_cases (case_number, subject_maintainer_id, reporter_maintainer_id, case_type, severity, description, response_deadline, resolution_deadline) \
+                 VALUES (?, 1, 2, 'abuse', 'minor', 'Test case', ?, ?)",
+            )
+            .bind(case_number)
+            .bind(now + Duration::hours(response_hours))
+            .bind(now + Duration::hours(resolution_hours))
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+    };
+    // Case 1: response due in 30h, resolution in 12h; case 2: nothing within 48h
+    insert_case("GR-TEST-0001", 30, 12).await;
+    insert_case("GR-TEST-0002", 100, 1000).await;
+    sqlx::query(
+        "INSERT INTO governance_review_appeals (case_id, maintainer_id, appeal_reason, appeal_deadline) VALUES (2, 1, 'Appeal', ?)",
+    )
+    .bind(now + Duration::hours(40))
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let manager = DeadlineNotificationManager::new(pool.clone(), None);
+
+    let upcoming = manager.get_upcoming_deadlines(48).await.unwrap();
+    let mut summary: Vec<(DeadlineType, i32, Option<String>)> = upcoming
+        .iter()
+        .map(|d| (d.deadline_type, d.maintainer_id, d.github_username.clone()))
+        .collect();
+    summary
+        .sort_by_key(|(deadline_type, maintainer_id, _)| (deadline_type.as_str(), *maintainer_id));
+    assert_eq!(
+        summary,
+        vec![
+            (DeadlineType::Appeal, 1, Some("subject".to_string())),
+            (DeadlineType::Resolution, 1, Some("subject".to_string())),
+            (DeadlineType::Resolution, 2, Some("reporter".to_string())),
+            (DeadlineType::Response, 1, Some("subject".to_string())),
+        ]
+    );
+
+    // Inside 24h the reminder is urgent
+    let pending = manager.pending_notifications(48, 24).await.unwrap();
+    assert_eq!(pending.len(), 4);
+    for (deadline, level) in &pending {
+        let expected = if deadline.deadline_type == DeadlineType::Resolution {
+            NotificationLevel::Urgent
+        } else {
+            NotificationLevel::Upcoming
+        };
+        assert_eq!(*level, expected);
+    }
+
+    // Reminders are only sent once per level
+    for (deadline, level) in &pending {
+        manager.mark_notified(deadline, *level).await.unwrap();
+    }
+    assert!(manager
+        .pending_notifications(48, 24)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // A response stops the response reminders
+    sqlx::query(
+        "INSERT INTO governance_review_responses (case_id, maintainer_id, response_text) VALUES (1, 1, 'Response')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    assert_eq!(manager.get_upcoming_deadlines(48).await.unwrap().len(), 3);
+
+    // The same deadline gets a second, urgent reminder once inside 24h
+    let appeal = upcoming
+        .iter()
+        .find(|d| d.deadline_type == DeadlineType::Appeal)
+        .unwrap();
+    assert_eq!(
+        NotificationLevel::for_deadline(appeal.deadline, now, 24),
+        NotificationLevel::Upcoming
+    );
+    assert_eq!(
+        NotificationLevel::for_deadline(appeal.deadline, now + Duration::hours(17), 24),
+        NotificationLevel::Urgent
+    );
+}
+
+#[tokio::test]
+async fn test_anonymous_report_identity_escrow() {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS anonymous_reports (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            encrypted_identity_blob BLOB NOT NULL,
+            report_content TEXT NOT NULL,
+            escrowed_for_maintainers TEXT NOT NULL DEFAULT '[]',
+            escrow_threshold INTEGER NOT NULL,
+            status TEXT NOT NULL DEFAULT 'submitted',
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            escalated_at TEXT,
+            escalated_by TEXT
+        );
+        CREATE TABLE IF NOT EXISTS anonymous_report_unlock_requests (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            report_id INTEGER NOT NULL,
+            requesting_maintainer TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            requested_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(report_id, requesting_maintainer)
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let config = GovernanceReviewConfig {
+        anonymous_escrow_threshold: 2,
+        ..Default::default()
+    };
+    let protections = ProtectionManager::from_config(pool, &config);
+    let escrowed = vec!["alice".to_string(), "bob".to_string(), "carol".to_string()];
+
+    // Too few escrow maintainers to ever reach the threshold
+    let result = protections
+        .create_anonymous_report(b"sealed", "Harassment in review", vec!["alice".into()])
+        .await;
+    assert!(result.is_err());
+
+    let report = protections
+        .create_anonymous_report(b"sealed", "Harassment in review", escrowed)
+        .await
+        .unwrap();
+    assert_eq!(report.status, "submitted");
+    assert_eq!(report.escrow_threshold, 2);
+
+    // Unlocking requires escalation first
+    let result = protections
+        .escrow_unlock_request(report.id, "alice", "Needed to proceed")
+        .await;
+    assert!(result.is_err());
+
+    protections
+        .escalate_anonymous_report(report.id, "carol")
+        .await
+        .unwrap();
+
+    // Only escrow maintainers count, and each counts once
+    let result = protections
+        .escrow_unlock_request(report.id, "mallory", "Curious")
+        .await;
+    assert!(result.is_err());
+    assert_eq!(
+        protections
+            .escrow_unlock_request(report.id, "alice", "Needed to proceed")
+            .await
+            .unwrap(),
+        1
+    );
+    assert_eq!(
+        protections
+            .escrow_unlock_request(report.id, "alice", "Asking again")
+            .await
+            .unwrap(),
+        1
+    );
+    assert!(protections
+        .reveal_identity(report.id)
+        .await
+        .unwrap()
+        .is_none());
+
+    assert_eq!(
+        protections
+            .escrow_unlock_request(report.id, "bob", "Agreed")
+            .await
+            .unwrap(),
+        2
+    );
+    assert_eq!(
+        protections.reveal_identity(report.id).await.unwrap(),
+        Some(b"sealed".to_vec())
+    );
+}
+
+#[tokio::test]
+async fn test_public_record_redacts_closed_cases() {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS governance_review_cases (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_number TEXT UNIQUE NOT NULL,
+            subject_maintainer_id INTEGER NOT NULL,
+            reporter_maintainer_id INTEGER NOT NULL,
+            case_type TEXT NOT NULL,
+            severity TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'open',
+            description TEXT NOT NULL,
+            evidence TEXT NOT NULL DEFAULT '{}',
+            on_platform BOOLEAN NOT NULL DEFAULT true,
+            created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            response_deadline TEXT,
+            resolution_deadline TEXT,
+            resolved_at TEXT,
+            resolution_reason TEXT,
+            github_issue_number INTEGER
+        );
+        CREATE TABLE IF NOT EXISTS governance_review_time_limits (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_id INTEGER NOT NULL,
+            limit_type TEXT NOT NULL,
+            deadline TEXT NOT NULL,
+            extended BOOLEAN DEFAULT false,
+            extension_approved_by INTEGER,
+            extension_reason TEXT,
+            extension_until TEXT
+        );
+        CREATE TABLE IF NOT EXISTS governance_review_warnings (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_id INTEGER NOT NULL,
+            maintainer_id INTEGER NOT NULL,
+            warning_level INTEGER NOT NULL,
+            warning_type TEXT NOT NULL,
+            issued_by_team_approval INTEGER NOT NULL,
+            issued_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            improvement_deadline TEXT,
+            improvement_extended BOOLEAN DEFAULT false,
+            improvement_extended_until TEXT,
+            resolved BOOLEAN DEFAULT false,
+            resolved_at TEXT,
+            warning_file_path TEXT
+        );
+        CREATE TABLE IF NOT EXISTS governance_review_responses (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            case_id INTEGER NOT NULL,
+            maintainer_id INTEGER NOT NULL,
+            response_text TEXT NOT NULL,
+            counter_evidence TEXT DEFAULT '{}',
+            submitted_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let case_manager = GovernanceReviewCaseManager::new(pool.clone());
+    let closed = case_manager
+        .create_case(
+            1,
+            2,
+            "harassment",
+            "moderate",
+            "Abusive review comments",
+            serde_json::json!({ "links": ["https://example.com/pr/1"] }),
+            true,
+        )
+        .await
+        .unwrap();
+    let open = case_manager
+        .create_case(
+            3,
+            2,
+            "abuse",
+            "minor",
+            "Still under review",
+            serde_json::json!({}),
+            true,
+        )
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "UPDATE governance_review_cases SET status = 'resolved', resolved_at = ?, resolution_reason = 'Private warning issued' WHERE id = ?",
+    )
+    .bind(Utc::now())
+    .bind(closed.id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO governance_review_warnings (case_id, maintainer_id, warning_level, warning_type, issued_by_team_approval) VALUES (?, 1, 1, 'private_warning', 4)",
+    )
+    .bind(closed.id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO governance_review_responses (case_id, maintainer_id, response_text) VALUES (?, 1, 'I disagree')",
+    )
+    .bind(closed.id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Open cases are not public
+    assert!(case_manager.get_public_record(open.id).await.is_err());
+
+    let record = case_manager.get_public_record(closed.id).await.unwrap();
+    assert_eq!(record.case_type, "harassment");
+    assert_eq!(record.sanction_level, "private_warning");
+    assert!(record.closed_at.is_some());
+    assert_eq!(record.subject, serde_json::json!(1));
+    assert_eq!(record.reporter, serde_json::json!("[redacted]"));
+    assert_eq!(record.description, serde_json::json!("[redacted]"));
+    assert_eq!(record.evidence, serde_json::json!("[redacted]"));
+    assert_eq!(record.responses, vec![serde_json::json!("[redacted]")]);
+    assert_eq!(
+        record.outcome_summary,
+        serde_json::json!("Private warning issued")
+    );
+
+    // Redaction follows the configured fields
+    let records = GovernanceReviewCaseManager::new(pool)
+        .with_public_record_redact_fields(vec!["subject".to_string()])
+        .get_public_records()
+        .await
+        .unwrap();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].subject, serde_json::json!("[redacted]"));
+    assert_eq!(records[0].reporter, serde_json::json!(2));
+    assert_eq!(
+        records[0].description,
+        serde_json::json!("Abusive review comments")
+    );
+}